@@ -0,0 +1,251 @@
+//! Benchmarks the [`WebSocketSerdeParser`] Binance PublicTrade parse path before and after
+//! formatting [`SubscriptionId`]s straight into a `SmolStr`.
+//!
+//! Both paths use the same parser, which deserialises directly from the frame bytes, so there is
+//! no intermediate deserialisation buffer to size (a configurable buffer size is out of scope).
+//! They only differ in how the `SubscriptionId` is built:
+//! - Previous: `SubscriptionId` formatted via a heap allocated `String`
+//! - Current: [`BinanceTrade`] deserialisation, formatting the `SubscriptionId` into a `SmolStr`
+//!
+//! Wall time is measured by the "WebSocket PublicTrade Parser" group, and allocations by the
+//! "WebSocket PublicTrade Parser Allocations" group (reported as allocations per message).
+
+use barter_data::{
+    event::MarketIter,
+    exchange::binance::{
+        channel::BinanceChannel,
+        trade::{BinanceTrade, de_side_from_buyer_is_maker},
+    },
+    subscription::trade::PublicTrade,
+};
+use barter_instrument::{Side, exchange::ExchangeId};
+use barter_integration::{
+    protocol::{
+        StreamParser,
+        websocket::{WebSocketSerdeParser, WsMessage},
+    },
+    subscription::SubscriptionId,
+};
+use chrono::{DateTime, Utc};
+use criterion::{
+    Criterion, Throughput,
+    measurement::{Measurement, ValueFormatter},
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+criterion::criterion_main!(benchmark_ws_parser, benchmark_ws_parser_allocations);
+
+criterion::criterion_group!(benchmark_ws_parser, bench_ws_parser_public_trades);
+
+criterion::criterion_group! {
+    name = benchmark_ws_parser_allocations;
+    config = Criterion::default().with_measurement(Allocations);
+    targets = bench_ws_parser_public_trades_allocations
+}
+
+/// Number of Binance trade messages parsed per benchmark iteration.
+const NUM_MESSAGES: usize = 10_000;
+
+/// Global allocator that counts allocations, used by the [`Allocations`] measurement.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Criterion [`Measurement`] of the number of heap allocations made by the benchmarked routine.
+struct Allocations;
+
+impl Measurement for Allocations {
+    type Intermediate = usize;
+    type Value = usize;
+
+    fn start(&self) -> Self::Intermediate {
+        ALLOCATIONS.load(Ordering::SeqCst)
+    }
+
+    fn end(&self, start: Self::Intermediate) -> Self::Value {
+        ALLOCATIONS.load(Ordering::SeqCst) - start
+    }
+
+    fn add(&self, v1: &Self::Value, v2: &Self::Value) -> Self::Value {
+        v1 + v2
+    }
+
+    fn zero(&self) -> Self::Value {
+        0
+    }
+
+    fn to_f64(&self, value: &Self::Value) -> f64 {
+        *value as f64
+    }
+
+    fn formatter(&self) -> &dyn ValueFormatter {
+        &AllocationsFormatter
+    }
+}
+
+struct AllocationsFormatter;
+
+impl ValueFormatter for AllocationsFormatter {
+    fn scale_values(&self, _: f64, _: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+
+    fn scale_throughputs(
+        &self,
+        _: f64,
+        throughput: &Throughput,
+        values: &mut [f64],
+    ) -> &'static str {
+        let elements = match throughput {
+            Throughput::Elements(elements) => *elements as f64,
+            Throughput::Bytes(bytes) | Throughput::BytesDecimal(bytes) => *bytes as f64,
+        };
+
+        values.iter_mut().for_each(|value| *value /= elements);
+
+        "allocs/message"
+    }
+
+    fn scale_for_machines(&self, _: &mut [f64]) -> &'static str {
+        "allocs"
+    }
+}
+
+/// [`BinanceTrade`] as deserialised before formatting [`SubscriptionId`]s straight into a
+/// `SmolStr`.
+#[derive(Deserialize)]
+struct PreviousBinanceTrade {
+    #[serde(alias = "s", deserialize_with = "de_previous_trade_subscription_id")]
+    subscription_id: SubscriptionId,
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    time: DateTime<Utc>,
+    #[serde(alias = "t")]
+    id: u64,
+    #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
+    price: Decimal,
+    #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
+    amount: Decimal,
+    #[serde(alias = "m", deserialize_with = "de_side_from_buyer_is_maker")]
+    side: Side,
+}
+
+fn de_previous_trade_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer).map(|market| {
+        SubscriptionId::from(format!("{}|{}", BinanceChannel::TRADES.as_ref(), market))
+    })
+}
+
+impl From<PreviousBinanceTrade> for BinanceTrade {
+    fn from(trade: PreviousBinanceTrade) -> Self {
+        Self {
+            subscription_id: trade.subscription_id,
+            time: trade.time,
+            id: trade.id,
+            price: trade.price,
+            amount: trade.amount,
+            side: trade.side,
+        }
+    }
+}
+
+fn trade_messages() -> Vec<WsMessage> {
+    (0..NUM_MESSAGES)
+        .map(|id| {
+            let message = format!(
+                r#"{{"e":"trade","E":1649324825173,"s":"ETHUSDT","t":{id},"p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,"T":1749354825200,"m":{},"M":true}}"#,
+                id % 2 == 0
+            );
+
+            if id % 10 == 0 {
+                WsMessage::binary(message.into_bytes())
+            } else {
+                WsMessage::text(message)
+            }
+        })
+        .collect()
+}
+
+/// Previous path: [`WebSocketSerdeParser`] with a heap allocated `SubscriptionId`.
+fn parse_public_trades_previous(messages: &[WsMessage]) -> usize {
+    messages
+        .iter()
+        .filter_map(|message| {
+            <WebSocketSerdeParser as StreamParser<PreviousBinanceTrade>>::parse(
+                Ok(message.clone()),
+            )?
+            .ok()
+        })
+        .map(BinanceTrade::from)
+        .map(public_trades)
+        .sum()
+}
+
+/// Current path: [`WebSocketSerdeParser`] deserialising a [`BinanceTrade`].
+fn parse_public_trades_current(messages: &[WsMessage]) -> usize {
+    messages
+        .iter()
+        .filter_map(|message| {
+            <WebSocketSerdeParser as StreamParser<BinanceTrade>>::parse(Ok(message.clone()))?.ok()
+        })
+        .map(public_trades)
+        .sum()
+}
+
+fn public_trades(trade: BinanceTrade) -> usize {
+    MarketIter::<(), PublicTrade>::from((ExchangeId::BinanceSpot, (), trade))
+        .0
+        .len()
+}
+
+fn bench_ws_parser_public_trades(c: &mut Criterion) {
+    bench_parse_paths(c, "WebSocket PublicTrade Parser");
+}
+
+fn bench_ws_parser_public_trades_allocations(c: &mut Criterion<Allocations>) {
+    bench_parse_paths(c, "WebSocket PublicTrade Parser Allocations");
+}
+
+fn bench_parse_paths<M>(c: &mut Criterion<M>, group: &str)
+where
+    M: Measurement,
+{
+    let messages = trade_messages();
+
+    let mut group = c.benchmark_group(group);
+    group.throughput(Throughput::Elements(NUM_MESSAGES as u64));
+
+    group.bench_function("Previous", |b| {
+        b.iter(|| parse_public_trades_previous(std::hint::black_box(&messages)))
+    });
+
+    group.bench_function("Current", |b| {
+        b.iter(|| parse_public_trades_current(std::hint::black_box(&messages)))
+    });
+
+    group.finish();
+}
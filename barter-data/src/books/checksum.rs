@@ -0,0 +1,243 @@
+use crate::books::{Level, OrderBook};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// CRC32 (IEEE 802.3) lookup table for the reflected polynomial `0xEDB88320`.
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut index = 0;
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+};
+
+/// Compute the CRC32 (IEEE 802.3) checksum of the provided bytes.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(u32::MAX, |crc, byte| {
+        CRC32_TABLE[((crc ^ u32::from(*byte)) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Order in which the bid & ask [`Level`]s are concatenated into the canonical checksum string.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum ChecksumLevelOrder {
+    /// `bid:ask` pairs per depth, eg/ OKX & Bitget. If one side has fewer levels, the remaining
+    /// levels of the other side are appended alone.
+    Interleaved,
+
+    /// All ask levels followed by all bid levels, eg/ Kraken.
+    AsksThenBids,
+}
+
+/// How each price & amount [`Decimal`] is formatted in the canonical checksum string.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum ChecksumDecimalFormat {
+    /// [`Decimal`] `Display`, retaining the scale the value was parsed with (eg/ "0.0100").
+    Raw,
+
+    /// [`Decimal`] with trailing zeros removed (eg/ "0.01").
+    Normalized,
+
+    /// [`Decimal`] `Display` with the decimal point and any leading zeros removed
+    /// (eg/ "0.0100" -> "100"), as used by Kraken.
+    DigitsOnly,
+}
+
+/// Per-venue options for building the canonical [`OrderBook`] checksum string.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct ChecksumConfig {
+    /// Number of top-of-book levels from each side included in the checksum.
+    pub levels: usize,
+
+    /// Separator between each price & amount in the canonical string.
+    pub separator: &'static str,
+
+    pub order: ChecksumLevelOrder,
+    pub decimal_format: ChecksumDecimalFormat,
+}
+
+impl ChecksumConfig {
+    /// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-checksum>
+    pub const OKX: Self = Self {
+        levels: 25,
+        separator: ":",
+        order: ChecksumLevelOrder::Interleaved,
+        decimal_format: ChecksumDecimalFormat::Raw,
+    };
+
+    /// See docs: <https://www.bitget.com/api-doc/common/websocket-intro>
+    pub const BITGET: Self = Self {
+        levels: 25,
+        separator: ":",
+        order: ChecksumLevelOrder::Interleaved,
+        decimal_format: ChecksumDecimalFormat::Raw,
+    };
+
+    /// See docs: <https://docs.kraken.com/api/docs/guides/spot-ws-book-v2>
+    pub const KRAKEN: Self = Self {
+        levels: 10,
+        separator: "",
+        order: ChecksumLevelOrder::AsksThenBids,
+        decimal_format: ChecksumDecimalFormat::DigitsOnly,
+    };
+
+    /// Build the canonical `price{sep}amount{sep}...` string for the top
+    /// [`levels`](Self::levels) of the provided [`OrderBook`].
+    pub fn canonical_string(&self, book: &OrderBook) -> String {
+        let bids = &book.bids().levels()[..self.levels.min(book.bids().levels().len())];
+        let asks = &book.asks().levels()[..self.levels.min(book.asks().levels().len())];
+
+        let levels: Vec<&Level> = match self.order {
+            ChecksumLevelOrder::Interleaved => (0..bids.len().max(asks.len()))
+                .flat_map(|depth| bids.get(depth).into_iter().chain(asks.get(depth)))
+                .collect(),
+            ChecksumLevelOrder::AsksThenBids => asks.iter().chain(bids).collect(),
+        };
+
+        let mut canonical = String::new();
+        for level in levels {
+            for value in [level.price, level.amount] {
+                if !canonical.is_empty() {
+                    canonical.push_str(self.separator);
+                }
+                self.write_decimal(&mut canonical, value);
+            }
+        }
+
+        canonical
+    }
+
+    /// Compute the CRC32 checksum of the canonical string of the provided [`OrderBook`].
+    pub fn checksum(&self, book: &OrderBook) -> u32 {
+        crc32(self.canonical_string(book).as_bytes())
+    }
+
+    /// Returns `true` if the provided exchange checksum matches the [`OrderBook`] checksum.
+    ///
+    /// Venues publishing a signed 32-bit checksum (eg/ OKX) can pass `checksum as u32`.
+    pub fn verify(&self, book: &OrderBook, checksum: u32) -> bool {
+        self.checksum(book) == checksum
+    }
+
+    fn write_decimal(&self, canonical: &mut String, value: Decimal) {
+        match self.decimal_format {
+            ChecksumDecimalFormat::Raw => {
+                let _ = write!(canonical, "{value}");
+            }
+            ChecksumDecimalFormat::Normalized => {
+                let _ = write!(canonical, "{}", value.normalize());
+            }
+            ChecksumDecimalFormat::DigitsOnly => {
+                let digits = value.to_string().replace('.', "");
+                let digits = digits.trim_start_matches('0');
+                canonical.push_str(if digits.is_empty() { "0" } else { digits });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_checksum_okx_documented_sample() {
+        // See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-checksum>
+        let book = OrderBook::new(
+            0,
+            None,
+            [
+                Level::new(dec!(3366.1), dec!(7)),
+                Level::new(dec!(3366), dec!(6)),
+            ],
+            [
+                Level::new(dec!(3366.8), dec!(9)),
+                Level::new(dec!(3368), dec!(8)),
+            ],
+        );
+
+        assert_eq!(
+            ChecksumConfig::OKX.canonical_string(&book),
+            "3366.1:7:3366.8:9:3366:6:3368:8"
+        );
+        assert!(ChecksumConfig::OKX.verify(&book, -1881014294_i32 as u32));
+    }
+
+    #[test]
+    fn test_checksum_canonical_string() {
+        struct TestCase {
+            name: &'static str,
+            config: ChecksumConfig,
+            expected: &'static str,
+        }
+
+        let book = OrderBook::new(
+            0,
+            None,
+            [
+                Level::new(dec!(0.0500), dec!(1.50)),
+                Level::new(dec!(0.0400), dec!(2)),
+                Level::new(dec!(0.0300), dec!(3)),
+            ],
+            [Level::new(dec!(0.0600), dec!(0.25))],
+        );
+
+        let tests = vec![
+            TestCase {
+                name: "interleaved with unequal sides appends remaining bids",
+                config: ChecksumConfig::OKX,
+                expected: "0.0500:1.50:0.0600:0.25:0.0400:2:0.0300:3",
+            },
+            TestCase {
+                name: "levels limit truncates each side",
+                config: ChecksumConfig {
+                    levels: 1,
+                    ..ChecksumConfig::OKX
+                },
+                expected: "0.0500:1.50:0.0600:0.25",
+            },
+            TestCase {
+                name: "normalized decimal format removes trailing zeros",
+                config: ChecksumConfig {
+                    decimal_format: ChecksumDecimalFormat::Normalized,
+                    ..ChecksumConfig::OKX
+                },
+                expected: "0.05:1.5:0.06:0.25:0.04:2:0.03:3",
+            },
+            TestCase {
+                name: "kraken asks then bids digits only",
+                config: ChecksumConfig::KRAKEN,
+                expected: "6002550015040023003",
+            },
+        ];
+
+        for test in tests {
+            assert_eq!(
+                test.config.canonical_string(&book),
+                test.expected,
+                "TC '{}' failed",
+                test.name
+            );
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use crate::books::{Level, OrderBook};
+use chrono::{DateTime, Utc};
+use fnv::{FnvHashMap, FnvHashSet};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Minimal set of [`Level`] changes that transforms a previous [`OrderBook`] into a current one.
+///
+/// Generated by [`OrderBook::diff`] and applied with [`OrderBook::apply`], enabling maintained
+/// [`OrderBook`]s to be republished as bandwidth-efficient deltas rather than full snapshots.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub struct OrderBookDelta {
+    pub sequence: u64,
+    pub time_engine: Option<DateTime<Utc>>,
+    pub bids: OrderBookSideDelta,
+    pub asks: OrderBookSideDelta,
+}
+
+impl OrderBookDelta {
+    /// Returns `true` if the [`OrderBookDelta`] contains no [`Level`] changes.
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+}
+
+/// [`Level`] changes for one side of an [`OrderBookDelta`].
+#[derive(Clone, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub struct OrderBookSideDelta {
+    /// [`Level`]s at prices that did not previously exist.
+    pub added: Vec<Level>,
+
+    /// [`Level`]s at previously existing prices, with a new amount.
+    pub changed: Vec<Level>,
+
+    /// Prices of previously existing [`Level`]s that have been removed.
+    pub removed: Vec<Decimal>,
+}
+
+impl OrderBookSideDelta {
+    /// Returns `true` if the [`OrderBookSideDelta`] contains no [`Level`] changes.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+
+    /// Generate the [`OrderBookSideDelta`] that transforms the `previous` [`Level`]s into the
+    /// `current` [`Level`]s.
+    pub fn diff(current: &[Level], previous: &[Level]) -> Self {
+        let previous_amounts = previous
+            .iter()
+            .map(|level| (level.price, level.amount))
+            .collect::<FnvHashMap<_, _>>();
+
+        let mut delta = Self::default();
+        for level in current {
+            match previous_amounts.get(&level.price) {
+                None => delta.added.push(*level),
+                Some(amount) if *amount != level.amount => delta.changed.push(*level),
+                Some(_) => {}
+            }
+        }
+
+        let current_prices = current
+            .iter()
+            .map(|level| level.price)
+            .collect::<FnvHashSet<_>>();
+
+        delta.removed = previous
+            .iter()
+            .filter(|level| !current_prices.contains(&level.price))
+            .map(|level| level.price)
+            .collect();
+
+        delta
+    }
+
+    /// Express the [`OrderBookSideDelta`] as [`Level`] upserts, where removals have a zero amount.
+    pub fn upserts(&self) -> Vec<Level> {
+        self.removed
+            .iter()
+            .map(|price| Level::new(*price, Decimal::ZERO))
+            .chain(self.added.iter().copied())
+            .chain(self.changed.iter().copied())
+            .collect()
+    }
+}
+
+impl OrderBook {
+    /// Generate the [`OrderBookDelta`] that transforms the `previous` [`OrderBook`] into this
+    /// [`OrderBook`].
+    ///
+    /// Applying the delta to `previous` via [`OrderBook::apply`] reproduces this [`OrderBook`].
+    pub fn diff(&self, previous: &OrderBook) -> OrderBookDelta {
+        OrderBookDelta {
+            sequence: self.sequence,
+            time_engine: self.time_engine,
+            bids: OrderBookSideDelta::diff(self.bids.levels(), previous.bids.levels()),
+            asks: OrderBookSideDelta::diff(self.asks.levels(), previous.asks.levels()),
+        }
+    }
+
+    /// Apply an [`OrderBookDelta`] generated by [`OrderBook::diff`] to this [`OrderBook`].
+    pub fn apply(&mut self, delta: &OrderBookDelta) {
+        self.sequence = delta.sequence;
+        self.time_engine = delta.time_engine;
+        self.bids.upsert(&delta.bids.upserts());
+        self.asks.upsert(&delta.asks.upserts());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn book(
+        sequence: u64,
+        bids: Vec<(Decimal, Decimal)>,
+        asks: Vec<(Decimal, Decimal)>,
+    ) -> OrderBook {
+        OrderBook::new(sequence, None, bids, asks)
+    }
+
+    #[test]
+    fn test_order_book_apply_diff_reproduces_order_book() {
+        struct TestCase {
+            previous: OrderBook,
+            current: OrderBook,
+        }
+
+        let base = book(
+            1,
+            vec![
+                (dec!(100), dec!(1)),
+                (dec!(99), dec!(2)),
+                (dec!(98), dec!(3)),
+            ],
+            vec![
+                (dec!(101), dec!(1)),
+                (dec!(102), dec!(2)),
+                (dec!(103), dec!(3)),
+            ],
+        );
+
+        let tests = vec![
+            // TC0: no changes
+            TestCase {
+                previous: base.clone(),
+                current: base.clone(),
+            },
+            // TC1: level additions, including at the top of book
+            TestCase {
+                previous: base.clone(),
+                current: book(
+                    2,
+                    vec![
+                        (dec!(100.5), dec!(1)),
+                        (dec!(100), dec!(1)),
+                        (dec!(99), dec!(2)),
+                        (dec!(98), dec!(3)),
+                        (dec!(97), dec!(4)),
+                    ],
+                    vec![
+                        (dec!(101), dec!(1)),
+                        (dec!(102), dec!(2)),
+                        (dec!(102.5), dec!(5)),
+                        (dec!(103), dec!(3)),
+                    ],
+                ),
+            },
+            // TC2: size changes
+            TestCase {
+                previous: base.clone(),
+                current: book(
+                    3,
+                    vec![
+                        (dec!(100), dec!(5)),
+                        (dec!(99), dec!(2)),
+                        (dec!(98), dec!(0.5)),
+                    ],
+                    vec![
+                        (dec!(101), dec!(10)),
+                        (dec!(102), dec!(2)),
+                        (dec!(103), dec!(3)),
+                    ],
+                ),
+            },
+            // TC3: removals, including emptying a side
+            TestCase {
+                previous: base.clone(),
+                current: book(4, vec![(dec!(99), dec!(2))], vec![]),
+            },
+            // TC4: populating an empty book
+            TestCase {
+                previous: OrderBook::default(),
+                current: base.clone(),
+            },
+            // TC5: mixed additions, size changes & removals
+            TestCase {
+                previous: base.clone(),
+                current: book(
+                    5,
+                    vec![(dec!(100), dec!(1.5)), (dec!(97), dec!(1))],
+                    vec![
+                        (dec!(100.5), dec!(1)),
+                        (dec!(102), dec!(2)),
+                        (dec!(103), dec!(0.1)),
+                    ],
+                ),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let delta = test.current.diff(&test.previous);
+
+            let mut reconstructed = test.previous.clone();
+            reconstructed.apply(&delta);
+            assert_eq!(reconstructed, test.current, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_order_book_diff_is_minimal() {
+        let previous = book(
+            1,
+            vec![(dec!(100), dec!(1)), (dec!(99), dec!(2))],
+            vec![(dec!(101), dec!(1))],
+        );
+        let current = book(
+            2,
+            vec![(dec!(100), dec!(3)), (dec!(98), dec!(1))],
+            vec![(dec!(101), dec!(1))],
+        );
+
+        let delta = current.diff(&previous);
+
+        assert_eq!(
+            delta,
+            OrderBookDelta {
+                sequence: 2,
+                time_engine: None,
+                bids: OrderBookSideDelta {
+                    added: vec![Level::new(dec!(98), dec!(1))],
+                    changed: vec![Level::new(dec!(100), dec!(3))],
+                    removed: vec![dec!(99)],
+                },
+                asks: OrderBookSideDelta::default(),
+            }
+        );
+        assert!(delta.asks.is_empty());
+        assert!(current.diff(&current).is_empty());
+    }
+}
@@ -0,0 +1,300 @@
+use crate::books::{Level, OrderBook};
+use barter_instrument::Side;
+use chrono::{DateTime, Utc};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+use std::collections::BTreeMap;
+use tracing::debug;
+
+/// Individual resting order in an [`OrderBookL3`].
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct OrderL3 {
+    pub side: Side,
+    pub price: Decimal,
+    pub amount: Decimal,
+}
+
+/// Normalised Barter L3 (per-order) order book, keyed by exchange order id.
+///
+/// Aggregated price levels are maintained incrementally alongside the individual orders, so
+/// collapsing to an L2 [`OrderBook`] view via [`OrderBookL3::l2`] is cheap.
+#[derive(Clone, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub struct OrderBookL3 {
+    sequence: u64,
+    time_engine: Option<DateTime<Utc>>,
+    orders: FnvHashMap<SmolStr, OrderL3>,
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+}
+
+impl OrderBookL3 {
+    /// Construct a new [`OrderBookL3`] from an iterator of resting orders.
+    pub fn new<Iter, Id>(sequence: u64, time_engine: Option<DateTime<Utc>>, orders: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = (Id, OrderL3)>,
+        Id: Into<SmolStr>,
+    {
+        let mut book = Self {
+            sequence,
+            time_engine,
+            ..Default::default()
+        };
+
+        orders.into_iter().for_each(|(id, order)| {
+            book.open(id, order);
+        });
+
+        book
+    }
+
+    /// Current `u64` sequence number associated with the [`OrderBookL3`].
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Current engine time associated with the [`OrderBookL3`].
+    pub fn time_engine(&self) -> Option<DateTime<Utc>> {
+        self.time_engine
+    }
+
+    /// Set the sequence number and engine time associated with the latest applied update.
+    pub fn set_sequence(&mut self, sequence: u64, time_engine: Option<DateTime<Utc>>) {
+        self.sequence = sequence;
+        self.time_engine = time_engine;
+    }
+
+    /// Find the resting [`OrderL3`] associated with the provided order id.
+    pub fn order(&self, id: &str) -> Option<&OrderL3> {
+        self.orders.get(id)
+    }
+
+    /// Number of resting orders in the [`OrderBookL3`].
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    /// Returns `true` if the [`OrderBookL3`] contains no resting orders.
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+    /// Add a new resting order to the book, replacing any existing order with the same id.
+    ///
+    /// Returns the [`Side`] and new aggregated [`Level`] at the order's price.
+    pub fn open<Id>(&mut self, id: Id, order: OrderL3) -> (Side, Level)
+    where
+        Id: Into<SmolStr>,
+    {
+        if let Some(replaced) = self.orders.insert(id.into(), order) {
+            self.apply_level_delta(replaced.side, replaced.price, -replaced.amount);
+        }
+
+        (
+            order.side,
+            self.apply_level_delta(order.side, order.price, order.amount),
+        )
+    }
+
+    /// Change the remaining amount of an existing resting order, removing it if the new amount
+    /// is zero.
+    ///
+    /// Returns the [`Side`] and new aggregated [`Level`] at the order's price, or `None` if the
+    /// order id is not found.
+    pub fn change(&mut self, id: &str, amount: Decimal) -> Option<(Side, Level)> {
+        let Some(order) = self.orders.get_mut(id) else {
+            debug!(id, %amount, "received OrderBookL3 change for an order that was not found");
+            return None;
+        };
+
+        let delta = amount - order.amount;
+        let (side, price) = (order.side, order.price);
+
+        if amount.is_zero() {
+            self.orders.remove(id);
+        } else {
+            order.amount = amount;
+        }
+
+        Some((side, self.apply_level_delta(side, price, delta)))
+    }
+
+    /// Reduce the remaining amount of an existing resting order (eg/ maker side of a match).
+    ///
+    /// See [`OrderBookL3::change`].
+    pub fn reduce(&mut self, id: &str, amount: Decimal) -> Option<(Side, Level)> {
+        let remaining = self.orders.get(id)?.amount - amount;
+        self.change(id, remaining.max(Decimal::ZERO))
+    }
+
+    /// Remove a resting order from the book (eg/ filled or cancelled).
+    ///
+    /// Returns the [`Side`] and new aggregated [`Level`] at the order's price, or `None` if the
+    /// order id is not found.
+    pub fn done(&mut self, id: &str) -> Option<(Side, Level)> {
+        let order = self.orders.remove(id)?;
+        Some((
+            order.side,
+            self.apply_level_delta(order.side, order.price, -order.amount),
+        ))
+    }
+
+    /// Best (highest price) aggregated bid [`Level`].
+    pub fn best_bid(&self) -> Option<Level> {
+        self.bids
+            .last_key_value()
+            .map(|(price, amount)| Level::new(*price, *amount))
+    }
+
+    /// Best (lowest price) aggregated ask [`Level`].
+    pub fn best_ask(&self) -> Option<Level> {
+        self.asks
+            .first_key_value()
+            .map(|(price, amount)| Level::new(*price, *amount))
+    }
+
+    /// Collapse the [`OrderBookL3`] into an aggregated L2 [`OrderBook`] view.
+    pub fn l2(&self) -> OrderBook {
+        OrderBook::new(
+            self.sequence,
+            self.time_engine,
+            self.bids
+                .iter()
+                .map(|(price, amount)| Level::new(*price, *amount)),
+            self.asks
+                .iter()
+                .map(|(price, amount)| Level::new(*price, *amount)),
+        )
+    }
+
+    fn apply_level_delta(&mut self, side: Side, price: Decimal, delta: Decimal) -> Level {
+        let levels = match side {
+            Side::Buy => &mut self.bids,
+            Side::Sell => &mut self.asks,
+        };
+
+        let amount = levels.entry(price).or_default();
+        *amount += delta;
+
+        let amount = *amount;
+        if amount <= Decimal::ZERO {
+            levels.remove(&price);
+            Level::new(price, Decimal::ZERO)
+        } else {
+            Level::new(price, amount)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_order_book_l3_open_change_done() {
+        struct TestCase {
+            name: &'static str,
+            apply: fn(&mut OrderBookL3),
+            expected_best_bid: Option<Level>,
+            expected_best_ask: Option<Level>,
+        }
+
+        let tests = vec![
+            TestCase {
+                name: "open orders aggregate into levels",
+                apply: |book| {
+                    book.open("b1", OrderL3::new(Side::Buy, dec!(100), dec!(1)));
+                    book.open("b2", OrderL3::new(Side::Buy, dec!(100), dec!(2)));
+                    book.open("b3", OrderL3::new(Side::Buy, dec!(99), dec!(5)));
+                    book.open("a1", OrderL3::new(Side::Sell, dec!(101), dec!(3)));
+                    book.open("a2", OrderL3::new(Side::Sell, dec!(102), dec!(4)));
+                },
+                expected_best_bid: Some(Level::new(dec!(100), dec!(3))),
+                expected_best_ask: Some(Level::new(dec!(101), dec!(3))),
+            },
+            TestCase {
+                name: "modify order amount updates aggregated level",
+                apply: |book| {
+                    book.change("b1", dec!(0.5));
+                    book.reduce("a1", dec!(1));
+                },
+                expected_best_bid: Some(Level::new(dec!(100), dec!(2.5))),
+                expected_best_ask: Some(Level::new(dec!(101), dec!(2))),
+            },
+            TestCase {
+                name: "cancel last orders at best levels moves top-of-book",
+                apply: |book| {
+                    book.done("b1");
+                    book.done("b2");
+                    book.reduce("a1", dec!(2));
+                },
+                expected_best_bid: Some(Level::new(dec!(99), dec!(5))),
+                expected_best_ask: Some(Level::new(dec!(102), dec!(4))),
+            },
+            TestCase {
+                name: "unknown order ids are ignored",
+                apply: |book| {
+                    assert!(book.change("unknown", dec!(1)).is_none());
+                    assert!(book.done("unknown").is_none());
+                },
+                expected_best_bid: Some(Level::new(dec!(99), dec!(5))),
+                expected_best_ask: Some(Level::new(dec!(102), dec!(4))),
+            },
+            TestCase {
+                name: "re-open existing order id replaces it",
+                apply: |book| {
+                    book.open("b3", OrderL3::new(Side::Sell, dec!(103), dec!(1)));
+                },
+                expected_best_bid: None,
+                expected_best_ask: Some(Level::new(dec!(102), dec!(4))),
+            },
+        ];
+
+        let mut book = OrderBookL3::default();
+
+        for test in tests {
+            (test.apply)(&mut book);
+
+            assert_eq!(
+                book.best_bid(),
+                test.expected_best_bid,
+                "TC '{}' failed",
+                test.name
+            );
+            assert_eq!(
+                book.best_ask(),
+                test.expected_best_ask,
+                "TC '{}' failed",
+                test.name
+            );
+
+            let l2 = book.l2();
+            assert_eq!(
+                l2.bids().best().copied(),
+                test.expected_best_bid,
+                "TC '{}' failed",
+                test.name
+            );
+            assert_eq!(
+                l2.asks().best().copied(),
+                test.expected_best_ask,
+                "TC '{}' failed",
+                test.name
+            );
+        }
+
+        assert_eq!(book.len(), 2);
+        assert_eq!(
+            book.l2().asks().levels(),
+            &[
+                Level::new(dec!(102), dec!(4)),
+                Level::new(dec!(103), dec!(1))
+            ]
+        );
+    }
+}
@@ -1,11 +1,12 @@
 use crate::{
-    Identifier,
+    Identifier, SnapshotFetcher,
     books::{
         OrderBook,
         map::{OrderBookMap, OrderBookMapMulti},
     },
     error::DataError,
-    exchange::StreamSelector,
+    event::MarketEvent,
+    exchange::{Connector, StreamSelector},
     instrument::InstrumentData,
     streams::{Streams, consumer::MarketStreamEvent, reconnect::stream::ReconnectingStream},
     subscription::{
@@ -20,9 +21,11 @@ use parking_lot::RwLock;
 use std::{
     fmt::{Debug, Display},
     hash::Hash,
+    marker::PhantomData,
     sync::Arc,
 };
-use tracing::warn;
+use tokio::sync::mpsc;
+use tracing::{debug, info, warn};
 
 /// Maintains a set of local L2 [`OrderBook`]s by applying streamed [`OrderBookEvent`]s to the
 /// associated [`OrderBook`] in the [`OrderBookMap`].
@@ -41,30 +44,240 @@ where
     /// Manage local L2 [`OrderBook`]s.
     pub async fn run(mut self) {
         while let Some(stream_event) = self.stream.next().await {
-            // Extract MarketEvent<InstrumentKey, OrderBookEvent>
-            let event = match stream_event {
-                MarketStreamEvent::Reconnecting(exchange) => {
-                    warn!(%exchange, "OrderBook manager input stream disconnected");
-                    continue;
+            self.update(stream_event);
+        }
+    }
+
+    /// Apply the next [`MarketStreamEvent`] to the associated local [`OrderBook`].
+    fn update(&mut self, stream_event: MarketStreamEvent<BookMap::Key, OrderBookEvent>) {
+        // Extract MarketEvent<InstrumentKey, OrderBookEvent>
+        let event = match stream_event {
+            MarketStreamEvent::Reconnecting(exchange) => {
+                warn!(%exchange, "OrderBook manager input stream disconnected");
+                return;
+            }
+            MarketStreamEvent::Item(event) => event,
+        };
+
+        // Find OrderBook associated with the MarketEvent InstrumentKey
+        let Some(book) = self.books.find(&event.instrument) else {
+            warn!(
+                instrument = ?event.instrument,
+                "consumed MarketStreamEvent<_, OrderBookEvent> for non-configured instrument"
+            );
+            return;
+        };
+
+        let mut book_lock = book.write();
+        book_lock.update(&event.kind);
+    }
+}
+
+impl<St, BookMap> OrderBookL2Manager<St, BookMap>
+where
+    St: Stream<Item = MarketStreamEvent<BookMap::Key, OrderBookEvent>> + Unpin,
+    BookMap: OrderBookMap,
+    BookMap::Key: Debug + Clone + PartialEq,
+{
+    /// Manage local L2 [`OrderBook`]s, resyncing books on demand via [`OrderBookResync`]
+    /// control messages.
+    ///
+    /// See [`Self::resync`] for how each resync is performed.
+    pub async fn run_with_resync<Fetcher>(
+        mut self,
+        mut resync_rx: mpsc::UnboundedReceiver<OrderBookResync<BookMap::Key>>,
+        fetcher: Fetcher,
+    ) where
+        Fetcher: OrderBookResyncFetcher<BookMap::Key>,
+    {
+        // Latest resync snapshot sequence of each resynced instrument
+        let mut resynced = Vec::new();
+
+        loop {
+            tokio::select! {
+                biased;
+
+                Some(resync) = resync_rx.recv() => {
+                    if let Some(sequence) = self.resync(&fetcher, resync.instrument.clone()).await {
+                        resynced.retain(|(instrument, _)| instrument != &resync.instrument);
+                        resynced.push((resync.instrument, sequence));
+                    }
                 }
-                MarketStreamEvent::Item(event) => event,
-            };
+                stream_event = self.stream.next() => match stream_event {
+                    // Events at or before a resync snapshot sequence are already reflected in it
+                    Some(MarketStreamEvent::Item(event)) if is_stale(&event, &resynced) => {
+                        debug!(
+                            instrument = ?event.instrument,
+                            sequence = event_sequence(&event.kind),
+                            "dropped OrderBookEvent already reflected in resync snapshot"
+                        );
+                    }
+                    Some(stream_event) => self.update(stream_event),
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// Fetch a fresh snapshot of the local [`OrderBook`] associated with the `instrument`, and
+    /// atomically replace the local book with it, logging the [`OrderBookDelta`] between the old
+    /// & new books for diagnostics.
+    ///
+    /// Deltas are not applied during the resync since the input stream is not polled until the
+    /// swap completes. Returns the snapshot sequence if the book was replaced, so subsequent
+    /// events already reflected in the snapshot can be dropped.
+    ///
+    /// [`OrderBookDelta`]: super::delta::OrderBookDelta
+    pub async fn resync<Fetcher>(
+        &mut self,
+        fetcher: &Fetcher,
+        instrument: BookMap::Key,
+    ) -> Option<u64>
+    where
+        Fetcher: OrderBookResyncFetcher<BookMap::Key>,
+    {
+        let Some(book) = self.books.find(&instrument) else {
+            warn!(
+                ?instrument,
+                "cannot resync OrderBook for non-configured instrument"
+            );
+            return None;
+        };
 
-            // Find OrderBook associated with the MarketEvent InstrumentKey
-            let Some(book) = self.books.find(&event.instrument) else {
+        let snapshot = match fetcher.fetch_snapshot(&instrument).await {
+            Ok(snapshot) => snapshot,
+            Err(error) => {
                 warn!(
-                    instrument = ?event.instrument,
-                    "consumed MarketStreamEvent<_, OrderBookEvent> for non-configured instrument"
+                    ?instrument,
+                    ?error,
+                    "failed to fetch OrderBook resync snapshot"
                 );
-                continue;
-            };
+                return None;
+            }
+        };
+
+        let sequence = snapshot.sequence();
+        let previous = std::mem::replace(&mut *book.write(), snapshot.clone());
+        let delta = snapshot.diff(&previous);
+
+        if delta.is_empty() {
+            info!(
+                ?instrument,
+                sequence_previous = previous.sequence(),
+                sequence,
+                "OrderBook resync verified local book matches snapshot"
+            );
+        } else {
+            warn!(
+                ?instrument,
+                sequence_previous = previous.sequence(),
+                sequence,
+                bids_added = delta.bids.added.len(),
+                bids_changed = delta.bids.changed.len(),
+                bids_removed = delta.bids.removed.len(),
+                asks_added = delta.asks.added.len(),
+                asks_changed = delta.asks.changed.len(),
+                asks_removed = delta.asks.removed.len(),
+                "OrderBook resync replaced desynced local book"
+            );
+            debug!(?instrument, ?delta, "OrderBook resync delta");
+        }
+
+        Some(sequence)
+    }
+}
+
+/// Control message instructing an [`OrderBookL2Manager`] to re-snapshot the local [`OrderBook`]
+/// of an instrument (see [`OrderBookL2Manager::run_with_resync`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderBookResync<InstrumentKey> {
+    pub instrument: InstrumentKey,
+}
+
+/// Fetches a fresh L2 [`OrderBook`] snapshot used to resync a local [`OrderBook`].
+///
+/// See [`SnapshotFetcherResync`] for a [`SnapshotFetcher`] backed implementation.
+pub trait OrderBookResyncFetcher<InstrumentKey> {
+    fn fetch_snapshot(
+        &self,
+        instrument: &InstrumentKey,
+    ) -> impl Future<Output = Result<OrderBook, DataError>> + Send;
+}
+
+/// [`OrderBookResyncFetcher`] that fetches snapshots using the exchange [`SnapshotFetcher`] of
+/// the associated [`OrderBooksL2`] [`Subscription`].
+#[derive(Debug)]
+pub struct SnapshotFetcherResync<Exchange, Instrument, SnapFetcher> {
+    pub subscriptions: Vec<Subscription<Exchange, Instrument, OrderBooksL2>>,
+    phantom: PhantomData<SnapFetcher>,
+}
 
-            let mut book_lock = book.write();
-            book_lock.update(&event.kind);
+impl<Exchange, Instrument, SnapFetcher> SnapshotFetcherResync<Exchange, Instrument, SnapFetcher> {
+    /// Construct a new [`Self`] for the provided [`OrderBooksL2`] [`Subscription`]s.
+    pub fn new(subscriptions: Vec<Subscription<Exchange, Instrument, OrderBooksL2>>) -> Self {
+        Self {
+            subscriptions,
+            phantom: PhantomData,
         }
     }
 }
 
+impl<Exchange, Instrument, SnapFetcher> OrderBookResyncFetcher<Instrument::Key>
+    for SnapshotFetcherResync<Exchange, Instrument, SnapFetcher>
+where
+    Exchange: Connector + Sync,
+    Instrument: InstrumentData + Sync,
+    Instrument::Key: Debug + PartialEq + Sync,
+    SnapFetcher: SnapshotFetcher<Exchange, OrderBooksL2> + Sync,
+    Subscription<Exchange, Instrument, OrderBooksL2>: Identifier<Exchange::Market>,
+{
+    async fn fetch_snapshot(&self, instrument: &Instrument::Key) -> Result<OrderBook, DataError> {
+        let subscription = self
+            .subscriptions
+            .iter()
+            .find(|subscription| subscription.instrument.key() == instrument)
+            .ok_or_else(|| {
+                DataError::InitialSnapshotInvalid(format!(
+                    "no OrderBooksL2 Subscription to resync: {instrument:?}"
+                ))
+            })?;
+
+        SnapFetcher::fetch_snapshots(std::slice::from_ref(subscription))
+            .await?
+            .into_iter()
+            .find_map(|snapshot| match snapshot.kind {
+                OrderBookEvent::Snapshot(book) => Some(book),
+                OrderBookEvent::Update(_) => None,
+            })
+            .ok_or_else(|| {
+                DataError::InitialSnapshotInvalid(format!(
+                    "expected OrderBookEvent::Snapshot to resync: {instrument:?}"
+                ))
+            })
+    }
+}
+
+/// Determine if the [`OrderBookEvent`] is at or before the resync snapshot sequence of its
+/// instrument.
+fn is_stale<InstrumentKey>(
+    event: &MarketEvent<InstrumentKey, OrderBookEvent>,
+    resynced: &[(InstrumentKey, u64)],
+) -> bool
+where
+    InstrumentKey: PartialEq,
+{
+    resynced.iter().any(|(instrument, sequence)| {
+        instrument == &event.instrument && event_sequence(&event.kind) <= *sequence
+    })
+}
+
+/// Sequence of the [`OrderBook`] contained in an [`OrderBookEvent`].
+fn event_sequence(event: &OrderBookEvent) -> u64 {
+    match event {
+        OrderBookEvent::Snapshot(book) | OrderBookEvent::Update(book) => book.sequence(),
+    }
+}
+
 /// Initialise a [`OrderBookL2Manager`] using the provided batches of [`OrderBooksL2`]
 /// [`Subscription`]s.
 ///
@@ -124,3 +337,124 @@ where
         books: OrderBookMapMulti::new(books),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::map::OrderBookMapSingle;
+    use barter_instrument::exchange::ExchangeId;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    struct StubResyncFetcher {
+        snapshot: OrderBook,
+    }
+
+    impl OrderBookResyncFetcher<&'static str> for StubResyncFetcher {
+        async fn fetch_snapshot(&self, _: &&'static str) -> Result<OrderBook, DataError> {
+            Ok(self.snapshot.clone())
+        }
+    }
+
+    fn market_event(kind: OrderBookEvent) -> MarketStreamEvent<&'static str, OrderBookEvent> {
+        MarketStreamEvent::Item(MarketEvent {
+            time_exchange: Utc::now(),
+            time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: "btc_usdt",
+            kind,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_order_book_l2_manager_resync_replaces_book_with_snapshot() {
+        // Desynced local book, eg/ missing the 100 bid level
+        let local = OrderBook::new(
+            8,
+            None,
+            vec![(dec!(99), dec!(1))],
+            vec![(dec!(101), dec!(1))],
+        );
+        let snapshot = OrderBook::new(
+            10,
+            None,
+            vec![(dec!(100), dec!(2)), (dec!(99), dec!(1))],
+            vec![(dec!(101), dec!(3))],
+        );
+
+        let books = OrderBookMapSingle::new("btc_usdt", Arc::new(RwLock::new(local)));
+        let stream = futures::stream::iter(vec![
+            // Stale update already reflected in the snapshot, which should be dropped
+            market_event(OrderBookEvent::Update(OrderBook::new(
+                9,
+                None,
+                vec![(dec!(98), dec!(5))],
+                Vec::<(Decimal, Decimal)>::new(),
+            ))),
+        ]);
+
+        let (resync_tx, resync_rx) = mpsc::unbounded_channel();
+        resync_tx
+            .send(OrderBookResync {
+                instrument: "btc_usdt",
+            })
+            .unwrap();
+        drop(resync_tx);
+
+        let manager = OrderBookL2Manager {
+            stream,
+            books: books.clone(),
+        };
+        manager
+            .run_with_resync(
+                resync_rx,
+                StubResyncFetcher {
+                    snapshot: snapshot.clone(),
+                },
+            )
+            .await;
+
+        assert_eq!(*books.book.read(), snapshot);
+    }
+
+    #[tokio::test]
+    async fn test_order_book_l2_manager_applies_updates_after_resync() {
+        let snapshot = OrderBook::new(
+            10,
+            None,
+            vec![(dec!(100), dec!(2))],
+            vec![(dec!(101), dec!(3))],
+        );
+        let books =
+            OrderBookMapSingle::new("btc_usdt", Arc::new(RwLock::new(OrderBook::default())));
+
+        let mut manager = OrderBookL2Manager {
+            stream: futures::stream::empty(),
+            books: books.clone(),
+        };
+
+        let fetcher = StubResyncFetcher {
+            snapshot: snapshot.clone(),
+        };
+        assert_eq!(manager.resync(&fetcher, "btc_usdt").await, Some(10));
+        assert_eq!(manager.resync(&fetcher, "eth_usdt").await, None);
+
+        manager.update(market_event(OrderBookEvent::Update(OrderBook::new(
+            11,
+            None,
+            vec![(dec!(100), dec!(0))],
+            vec![(dec!(101), dec!(4))],
+        ))));
+
+        let expected = OrderBook::new(
+            11,
+            None,
+            Vec::<(Decimal, Decimal)>::new(),
+            vec![(dec!(101), dec!(4))],
+        );
+        assert_eq!(*books.book.read(), expected);
+    }
+}
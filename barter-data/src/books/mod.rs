@@ -6,6 +6,18 @@ use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use tracing::debug;
 
+/// Provides shared CRC32 [`OrderBook`] checksum utilities for venues that publish checksums over
+/// a canonical top-of-book string (eg/ OKX, Kraken, Bitget).
+pub mod checksum;
+
+/// Provides [`OrderBookDelta`](delta::OrderBookDelta) snapshot diffing, enabling maintained
+/// [`OrderBook`]s to be republished as minimal deltas.
+pub mod delta;
+
+/// Provides an L3 (per-order) [`OrderBookL3`](l3::OrderBookL3) that can be collapsed into an
+/// aggregated L2 [`OrderBook`] view.
+pub mod l3;
+
 /// Provides a [`OrderBookL2Manager`](manager::OrderBookL2Manager) for maintaining a set of local
 /// L2 [`OrderBook`]s.
 pub mod manager;
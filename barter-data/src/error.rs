@@ -1,8 +1,13 @@
 use crate::subscription::SubKind;
 use barter_instrument::{exchange::ExchangeId, index::error::IndexError};
 use barter_integration::{error::SocketError, subscription::SubscriptionId};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use tracing::debug;
+
+/// Maximum number of bytes of a raw exchange payload retained by [`DataError::UnknownMessage`].
+pub const UNKNOWN_MESSAGE_RAW_MAX_LEN: usize = 1024;
 
 /// All errors generated in `barter-data`.
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
@@ -25,12 +30,34 @@ pub enum DataError {
     #[error("SocketError: {0}")]
     Socket(String),
 
+    #[error("MarketStream consumer channel of capacity {capacity} overflowed, dropped: {dropped}")]
+    BufferOverflow { capacity: usize, dropped: usize },
+
+    #[error("unknown message from exchange: {exchange}, raw: {raw}")]
+    UnknownMessage { exchange: ExchangeId, raw: String },
+
+    #[error("exchange: {exchange} is undergoing maintenance: {message}")]
+    Maintenance {
+        exchange: ExchangeId,
+        message: String,
+    },
+
     #[error("unsupported dynamic Subscription for exchange: {exchange}, kind: {sub_kind}")]
     Unsupported {
         exchange: ExchangeId,
         sub_kind: SubKind,
     },
 
+    #[error(
+        "MarketEvent from exchange: {exchange} has time_exchange {time_exchange} outside the \
+         tolerance of time_received {time_received}"
+    )]
+    EventTimeOutOfTolerance {
+        exchange: ExchangeId,
+        time_exchange: DateTime<Utc>,
+        time_received: DateTime<Utc>,
+    },
+
     #[error(
         "\
         InvalidSequence: first_update_id {first_update_id} does not follow on from the \
@@ -52,11 +79,52 @@ impl DataError {
             _ => false,
         }
     }
+
+    /// Construct a [`DataError::UnknownMessage`] for an exchange frame that is neither expected
+    /// data nor a known control message.
+    ///
+    /// The raw payload is truncated to [`UNKNOWN_MESSAGE_RAW_MAX_LEN`] bytes.
+    pub fn unknown_message(exchange: ExchangeId, raw: &str) -> Self {
+        let raw = if raw.len() > UNKNOWN_MESSAGE_RAW_MAX_LEN {
+            let end = (0..=UNKNOWN_MESSAGE_RAW_MAX_LEN)
+                .rev()
+                .find(|index| raw.is_char_boundary(*index))
+                .unwrap_or_default();
+            format!("{}...", &raw[..end])
+        } else {
+            raw.to_string()
+        };
+
+        debug!(%exchange, %raw, "received unknown message from exchange");
+
+        Self::UnknownMessage { exchange, raw }
+    }
+
+    /// Associate a [`DataError::UnknownMessage`] with the provided [`ExchangeId`].
+    ///
+    /// Errors converted from a [`SocketError`] have no knowledge of the originating exchange, so
+    /// they default to [`ExchangeId::Other`] until annotated by the `MarketStream` consumer.
+    /// All other errors are returned unchanged.
+    pub fn with_exchange(self, exchange: ExchangeId) -> Self {
+        match self {
+            Self::UnknownMessage { raw, .. } => Self::UnknownMessage { exchange, raw },
+            other => other,
+        }
+    }
 }
 
 impl From<SocketError> for DataError {
     fn from(value: SocketError) -> Self {
-        Self::Socket(value.to_string())
+        match value {
+            SocketError::Deserialise { payload, .. } => {
+                Self::unknown_message(ExchangeId::Other, &payload)
+            }
+            SocketError::DeserialiseBinary { payload, .. }
+            | SocketError::DeserialiseProtobuf { payload, .. } => {
+                Self::unknown_message(ExchangeId::Other, &String::from_utf8_lossy(&payload))
+            }
+            other => Self::Socket(other.to_string()),
+        }
     }
 }
 
@@ -92,4 +160,75 @@ mod tests {
             assert_eq!(actual, test.expected, "TC{} failed", index);
         }
     }
+
+    #[test]
+    fn test_data_error_unknown_message_truncates_raw() {
+        let raw = "ü".repeat(UNKNOWN_MESSAGE_RAW_MAX_LEN);
+
+        let DataError::UnknownMessage { exchange, raw } =
+            DataError::unknown_message(ExchangeId::Okx, &raw)
+        else {
+            panic!("expected DataError::UnknownMessage")
+        };
+
+        assert_eq!(exchange, ExchangeId::Okx);
+        assert_eq!(
+            raw,
+            format!("{}...", "ü".repeat(UNKNOWN_MESSAGE_RAW_MAX_LEN / 2))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exchange_stream_yields_unknown_message_and_continues() {
+        use crate::{
+            exchange::binance::{spot::BinanceSpot, trade::BinanceTrade},
+            subscription::{Map, trade::PublicTrades},
+            transformer::{ExchangeTransformer, stateless::StatelessTransformer},
+        };
+        use barter_integration::{
+            protocol::websocket::{WebSocketSerdeParser, WsError, WsMessage},
+            stream::ExchangeStream,
+        };
+        use futures::StreamExt;
+
+        let trade = |id: u64| {
+            WsMessage::text(format!(
+                r#"{{"e":"trade","E":1649324825173,"s":"ETHUSDT","t":{id},"p":"10000.19",
+                "q":"0.239000","b":10108767791,"a":10108764858,"T":1749354825200,"m":false,
+                "M":true}}"#
+            ))
+        };
+        let unexpected = r#"{"unexpected":"frame"}"#;
+
+        let transformer = StatelessTransformer::<BinanceSpot, _, PublicTrades, BinanceTrade>::init(
+            Map::from_iter([(SubscriptionId::from("@trade|ETHUSDT"), "eth_usdt")]),
+            &[],
+            tokio::sync::mpsc::unbounded_channel().0,
+        )
+        .await
+        .unwrap();
+
+        let stream = ExchangeStream::<WebSocketSerdeParser, _, _>::new(
+            futures::stream::iter(
+                [trade(1), WsMessage::text(unexpected), trade(2)].map(Ok::<_, WsError>),
+            ),
+            transformer,
+            Default::default(),
+        )
+        .map(|result| result.map_err(|error| error.with_exchange(ExchangeId::BinanceSpot)));
+
+        let output = stream.collect::<Vec<_>>().await;
+
+        assert_eq!(output.len(), 3);
+        assert_eq!(output[0].as_ref().unwrap().kind.id, "1");
+        assert_eq!(
+            output[1],
+            Err(DataError::UnknownMessage {
+                exchange: ExchangeId::BinanceSpot,
+                raw: unexpected.to_string(),
+            })
+        );
+        assert!(!output[1].as_ref().unwrap_err().is_terminal());
+        assert_eq!(output[2].as_ref().unwrap().kind.id, "2");
+    }
 }
@@ -2,14 +2,14 @@ use crate::{
     error::DataError,
     streams::consumer::MarketStreamResult,
     subscription::{
-        book::{OrderBookEvent, OrderBookL1},
+        book::{Bbo, OrderBookEvent, OrderBookL1},
         candle::Candle,
         liquidation::Liquidation,
         trade::PublicTrade,
     },
 };
 use barter_instrument::{exchange::ExchangeId, instrument::market_data::MarketDataInstrument};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use derive_more::From;
 use serde::{Deserialize, Serialize};
 
@@ -42,12 +42,56 @@ impl<InstrumentKey, T> FromIterator<Result<MarketEvent<InstrumentKey, T>, DataEr
 pub struct MarketEvent<InstrumentKey = MarketDataInstrument, T = DataKind> {
     pub time_exchange: DateTime<Utc>,
     pub time_received: DateTime<Utc>,
+    /// Optional time the event was processed, eg/ stamped by a consumer such as a trading engine.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_processed: Option<DateTime<Utc>>,
+    /// Optional per-(exchange, instrument, kind) sequence number used to detect dropped events,
+    /// eg/ stamped by a [`MarketEventSequencer`](crate::streams::sequence::MarketEventSequencer).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<u64>,
     pub exchange: ExchangeId,
     pub instrument: InstrumentKey,
     pub kind: T,
 }
 
 impl<InstrumentKey, T> MarketEvent<InstrumentKey, T> {
+    /// Return [`Self`] with the provided `time_processed`.
+    pub fn with_time_processed(self, time_processed: DateTime<Utc>) -> Self {
+        Self {
+            time_processed: Some(time_processed),
+            ..self
+        }
+    }
+
+    /// Return [`Self`] with the provided `sequence`.
+    pub fn with_sequence(self, sequence: u64) -> Self {
+        Self {
+            sequence: Some(sequence),
+            ..self
+        }
+    }
+
+    /// Transit latency between the exchange generating the event and it being received.
+    pub fn latency_transit(&self) -> TimeDelta {
+        self.time_received - self.time_exchange
+    }
+
+    /// Processing latency between the event being received and processed.
+    ///
+    /// Returns `None` if the event has not been stamped with a `time_processed`.
+    pub fn latency_processing(&self) -> Option<TimeDelta> {
+        self.time_processed
+            .map(|time_processed| time_processed - self.time_received)
+    }
+
+    /// [`MarketEventLatency`] breakdown of the exchange -> received -> processed pipeline.
+    pub fn latency(&self) -> MarketEventLatency {
+        MarketEventLatency {
+            transit: self.latency_transit(),
+            processing: self.latency_processing(),
+        }
+    }
+
     pub fn map_kind<F, O>(self, op: F) -> MarketEvent<InstrumentKey, O>
     where
         F: FnOnce(T) -> O,
@@ -55,6 +99,8 @@ impl<InstrumentKey, T> MarketEvent<InstrumentKey, T> {
         MarketEvent {
             time_exchange: self.time_exchange,
             time_received: self.time_received,
+            time_processed: self.time_processed,
+            sequence: self.sequence,
             exchange: self.exchange,
             instrument: self.instrument,
             kind: op(self.kind),
@@ -77,6 +123,13 @@ impl<InstrumentKey> MarketEvent<InstrumentKey, DataKind> {
         }
     }
 
+    pub fn as_bbo(&self) -> Option<MarketEvent<&InstrumentKey, &Bbo>> {
+        match &self.kind {
+            DataKind::Bbo(bbo) => Some(self.as_event(bbo)),
+            _ => None,
+        }
+    }
+
     pub fn as_order_book(&self) -> Option<MarketEvent<&InstrumentKey, &OrderBookEvent>> {
         match &self.kind {
             DataKind::OrderBook(orderbook) => Some(self.as_event(orderbook)),
@@ -102,6 +155,8 @@ impl<InstrumentKey> MarketEvent<InstrumentKey, DataKind> {
         MarketEvent {
             time_exchange: self.time_exchange,
             time_received: self.time_received,
+            time_processed: self.time_processed,
+            sequence: self.sequence,
             exchange: self.exchange,
             instrument: &self.instrument,
             kind,
@@ -109,6 +164,22 @@ impl<InstrumentKey> MarketEvent<InstrumentKey, DataKind> {
     }
 }
 
+/// Latency breakdown of a [`MarketEvent`] through the exchange -> received -> processed pipeline.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct MarketEventLatency {
+    /// Time between the exchange generating the event and it being received.
+    pub transit: TimeDelta,
+    /// Time between the event being received and processed, if it has been stamped.
+    pub processing: Option<TimeDelta>,
+}
+
+impl MarketEventLatency {
+    /// Total latency between the exchange generating the event and it being processed.
+    pub fn total(&self) -> Option<TimeDelta> {
+        self.processing.map(|processing| self.transit + processing)
+    }
+}
+
 /// Available kinds of normalised Barter [`MarketEvent<T>`](MarketEvent).
 ///
 /// ### Notes
@@ -124,16 +195,18 @@ impl<InstrumentKey> MarketEvent<InstrumentKey, DataKind> {
 pub enum DataKind {
     Trade(PublicTrade),
     OrderBookL1(OrderBookL1),
+    Bbo(Bbo),
     OrderBook(OrderBookEvent),
     Candle(Candle),
     Liquidation(Liquidation),
 }
 
 impl DataKind {
-    pub fn kind_name(&self) -> &str {
+    pub fn kind_name(&self) -> &'static str {
         match self {
             DataKind::Trade(_) => "public_trade",
             DataKind::OrderBookL1(_) => "l1",
+            DataKind::Bbo(_) => "bbo",
             DataKind::OrderBook(_) => "l2",
             DataKind::Candle(_) => "candle",
             DataKind::Liquidation(_) => "liquidation",
@@ -173,6 +246,20 @@ impl<InstrumentKey> From<MarketEvent<InstrumentKey, OrderBookL1>>
     }
 }
 
+impl<InstrumentKey> From<MarketStreamResult<InstrumentKey, Bbo>>
+    for MarketStreamResult<InstrumentKey, DataKind>
+{
+    fn from(value: MarketStreamResult<InstrumentKey, Bbo>) -> Self {
+        value.map_ok(MarketEvent::from)
+    }
+}
+
+impl<InstrumentKey> From<MarketEvent<InstrumentKey, Bbo>> for MarketEvent<InstrumentKey, DataKind> {
+    fn from(value: MarketEvent<InstrumentKey, Bbo>) -> Self {
+        value.map_kind(Bbo::into)
+    }
+}
+
 impl<InstrumentKey> From<MarketStreamResult<InstrumentKey, OrderBookEvent>>
     for MarketStreamResult<InstrumentKey, DataKind>
 {
@@ -220,3 +307,48 @@ impl<InstrumentKey> From<MarketEvent<InstrumentKey, Liquidation>>
         value.map_kind(Liquidation::into)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::market_event_trade_buy;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_market_event_latency() {
+        let time_exchange = DateTime::<Utc>::MIN_UTC;
+        let time_received = time_exchange + TimeDelta::milliseconds(25);
+        let time_processed = time_received + TimeDelta::microseconds(150);
+
+        let event = market_event_trade_buy(
+            time_exchange,
+            time_received,
+            "instrument",
+            Decimal::ONE,
+            Decimal::ONE,
+        );
+
+        assert_eq!(event.latency_transit(), TimeDelta::milliseconds(25));
+        assert_eq!(event.latency_processing(), None);
+        assert_eq!(event.latency().total(), None);
+
+        let event = event.with_time_processed(time_processed);
+
+        assert_eq!(event.time_processed, Some(time_processed));
+        assert_eq!(
+            event.latency(),
+            MarketEventLatency {
+                transit: TimeDelta::milliseconds(25),
+                processing: Some(TimeDelta::microseconds(150)),
+            }
+        );
+        assert_eq!(
+            event.latency().total(),
+            Some(TimeDelta::microseconds(25_150))
+        );
+
+        // time_processed is propagated when mapping the event kind
+        let mapped = event.map_kind(|_| ());
+        assert_eq!(mapped.time_processed, Some(time_processed));
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     books::Level,
     event::{MarketEvent, MarketIter},
     exchange::{binance::channel::BinanceChannel, subscription::ExchangeSub},
-    subscription::book::OrderBookL1,
+    subscription::book::{Bbo, OrderBookL1},
 };
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::subscription::SubscriptionId;
@@ -86,6 +86,8 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceOrderBookL1)>
         Self(vec![Ok(MarketEvent {
             time_exchange: book.time,
             time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
             exchange: exchange_id,
             instrument,
             kind: OrderBookL1 {
@@ -97,6 +99,30 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceOrderBookL1)>
     }
 }
 
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceOrderBookL1)>
+    for MarketIter<InstrumentKey, Bbo>
+{
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, InstrumentKey, BinanceOrderBookL1),
+    ) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: book.time,
+            time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
+            exchange: exchange_id,
+            instrument,
+            kind: Bbo {
+                time: book.time,
+                bid: book.best_bid_price,
+                bid_qty: book.best_bid_amount,
+                ask: book.best_ask_price,
+                ask_qty: book.best_ask_amount,
+            },
+        })])
+    }
+}
+
 /// Deserialize a [`BinanceOrderBookL1`] "s" (eg/ "BTCUSDT") as the associated [`SubscriptionId`].
 ///
 /// eg/ "@bookTicker|BTCUSDT"
@@ -178,5 +204,45 @@ mod tests {
                 assert_eq!(actual, test.expected, "TC{} failed", index);
             }
         }
+
+        #[test]
+        fn test_binance_book_ticker_to_bbo() {
+            let input = r#"
+            {
+                "e":"bookTicker",
+                "u":2286618712950,
+                "s":"BTCUSDT",
+                "b":"16858.90",
+                "B":"13.692",
+                "a":"16859.00",
+                "A":"30.219",
+                "T":1671621244670,
+                "E":1671621244673
+            }"#;
+
+            let book = serde_json::from_str::<BinanceOrderBookL1>(input).unwrap();
+            assert_eq!(
+                book.subscription_id,
+                SubscriptionId::from("@bookTicker|BTCUSDT")
+            );
+
+            let MarketIter(events) =
+                MarketIter::<&str, Bbo>::from((ExchangeId::BinanceFuturesUsd, "btc_usdt", book));
+            let event = events.into_iter().next().unwrap().unwrap();
+
+            let time = DateTime::from_timestamp_millis(1671621244670).unwrap();
+            assert_eq!(event.time_exchange, time);
+            assert_eq!(
+                event.kind,
+                Bbo {
+                    time,
+                    bid: dec!(16858.90),
+                    bid_qty: dec!(13.692),
+                    ask: dec!(16859.00),
+                    ask_qty: dec!(30.219),
+                }
+            );
+            assert_eq!(event.kind.spread(), dec!(0.10));
+        }
     }
 }
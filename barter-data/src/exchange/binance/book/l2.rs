@@ -72,6 +72,8 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceOrderBookL2Snapshot)
         Self {
             time_exchange: snapshot.time_exchange.unwrap_or(time_received),
             time_received,
+            time_processed: None,
+            sequence: None,
             exchange,
             instrument,
             kind: OrderBookEvent::from(snapshot),
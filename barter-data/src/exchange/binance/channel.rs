@@ -3,7 +3,8 @@ use crate::{
     Identifier,
     subscription::{
         Subscription,
-        book::{OrderBooksL1, OrderBooksL2},
+        book::{BestBidOffer, OrderBooksL1, OrderBooksL2},
+        kline::{KlineInterval, Klines},
         liquidation::Liquidations,
         trade::PublicTrades,
     },
@@ -46,6 +47,21 @@ impl BinanceChannel {
     ///
     /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#liquidation-order-streams>
     pub const LIQUIDATIONS: Self = Self("@forceOrder");
+
+    /// [`Binance`] kline / candlestick channel name for the provided [`KlineInterval`].
+    ///
+    /// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#klinecandlestick-streams-for-utc>
+    /// See docs: <https://developers.binance.com/docs/derivatives/usds-margined-futures/websocket-market-streams/Kline-Candlestick-Streams>
+    pub fn kline(interval: KlineInterval) -> Self {
+        match interval {
+            KlineInterval::Minute1 => Self("@kline_1m"),
+            KlineInterval::Minute5 => Self("@kline_5m"),
+            KlineInterval::Minute15 => Self("@kline_15m"),
+            KlineInterval::Hour1 => Self("@kline_1h"),
+            KlineInterval::Hour4 => Self("@kline_4h"),
+            KlineInterval::Day1 => Self("@kline_1d"),
+        }
+    }
 }
 
 impl<Server, Instrument> Identifier<BinanceChannel>
@@ -64,6 +80,15 @@ impl<Server, Instrument> Identifier<BinanceChannel>
     }
 }
 
+/// Binance `bookTicker` is already the lightest best-bid-offer channel.
+impl<Server, Instrument> Identifier<BinanceChannel>
+    for Subscription<Binance<Server>, Instrument, BestBidOffer>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::ORDER_BOOK_L1
+    }
+}
+
 impl<Server, Instrument> Identifier<BinanceChannel>
     for Subscription<Binance<Server>, Instrument, OrderBooksL2>
 {
@@ -72,6 +97,14 @@ impl<Server, Instrument> Identifier<BinanceChannel>
     }
 }
 
+impl<Server, Instrument> Identifier<BinanceChannel>
+    for Subscription<Binance<Server>, Instrument, Klines>
+{
+    fn id(&self) -> BinanceChannel {
+        BinanceChannel::kline(self.kind.interval)
+    }
+}
+
 impl<Instrument> Identifier<BinanceChannel>
     for Subscription<BinanceFuturesUsd, Instrument, Liquidations>
 {
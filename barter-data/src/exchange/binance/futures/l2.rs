@@ -350,6 +350,8 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceFuturesOrderBookL2Up
         Self(vec![Ok(MarketEvent {
             time_exchange: update.time_exchange,
             time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
             exchange,
             instrument,
             kind: OrderBookEvent::Update(OrderBook::new(
@@ -90,6 +90,8 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceLiquidation)>
         Self(vec![Ok(MarketEvent {
             time_exchange: liquidation.order.time,
             time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
             exchange: exchange_id,
             instrument,
             kind: Liquidation {
@@ -0,0 +1,137 @@
+use barter_instrument::instrument::name::InstrumentNameExchange;
+use serde::{Deserialize, Serialize};
+
+/// [`Binance`](super::Binance) `exchangeInfo` symbol status of a market that is currently
+/// available to trade.
+pub const BINANCE_SYMBOL_STATUS_TRADING: &str = "TRADING";
+
+/// [`Binance`](super::Binance) `exchangeInfo` HTTP response, used to list the instruments
+/// available on an exchange server.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/general-endpoints#exchange-information>
+/// ```json
+/// {
+///     "timezone": "UTC",
+///     "serverTime": 1565246363776,
+///     "symbols": [
+///         {
+///             "symbol": "ETHBTC",
+///             "status": "TRADING",
+///             "baseAsset": "ETH",
+///             "quoteAsset": "BTC"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceExchangeInfo {
+    pub symbols: Vec<BinanceSymbolInfo>,
+}
+
+/// [`Binance`](super::Binance) `exchangeInfo` symbol.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceSymbolInfo {
+    pub symbol: InstrumentNameExchange,
+    pub status: String,
+}
+
+impl BinanceExchangeInfo {
+    /// Consume the [`BinanceExchangeInfo`], returning the [`InstrumentNameExchange`] of every
+    /// symbol currently available to trade.
+    pub fn into_instruments(self) -> Vec<InstrumentNameExchange> {
+        self.symbols
+            .into_iter()
+            .filter(|symbol| symbol.status == BINANCE_SYMBOL_STATUS_TRADING)
+            .map(|symbol| symbol.symbol)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_binance_exchange_info() {
+            // Recorded BinanceSpot & BinanceFuturesUsd exchangeInfo payloads (truncated)
+            struct TestCase {
+                input: &'static str,
+                expected: Vec<InstrumentNameExchange>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: BinanceSpot exchangeInfo
+                    input: r#"
+                    {
+                        "timezone":"UTC","serverTime":1727789187453,"rateLimits":[],
+                        "exchangeFilters":[],
+                        "symbols":[
+                            {
+                                "symbol":"ETHBTC","status":"TRADING","baseAsset":"ETH",
+                                "baseAssetPrecision":8,"quoteAsset":"BTC","quotePrecision":8,
+                                "orderTypes":["LIMIT","MARKET"],"isSpotTradingAllowed":true,
+                                "filters":[{"filterType":"PRICE_FILTER","minPrice":"0.00001000"}],
+                                "permissions":[]
+                            },
+                            {
+                                "symbol":"BCCBTC","status":"BREAK","baseAsset":"BCC",
+                                "baseAssetPrecision":8,"quoteAsset":"BTC","quotePrecision":8,
+                                "orderTypes":["LIMIT","MARKET"],"isSpotTradingAllowed":false,
+                                "filters":[],"permissions":[]
+                            },
+                            {
+                                "symbol":"BTCUSDT","status":"TRADING","baseAsset":"BTC",
+                                "baseAssetPrecision":8,"quoteAsset":"USDT","quotePrecision":8,
+                                "orderTypes":["LIMIT","MARKET"],"isSpotTradingAllowed":true,
+                                "filters":[],"permissions":[]
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: vec![
+                        InstrumentNameExchange::from("ETHBTC"),
+                        InstrumentNameExchange::from("BTCUSDT"),
+                    ],
+                },
+                TestCase {
+                    // TC1: BinanceFuturesUsd exchangeInfo
+                    input: r#"
+                    {
+                        "timezone":"UTC","serverTime":1727789187453,"futuresType":"U_MARGINED",
+                        "rateLimits":[],"exchangeFilters":[],"assets":[],
+                        "symbols":[
+                            {
+                                "symbol":"BTCUSDT","pair":"BTCUSDT","contractType":"PERPETUAL",
+                                "deliveryDate":4133404800000,"onboardDate":1569398400000,
+                                "status":"TRADING","baseAsset":"BTC","quoteAsset":"USDT",
+                                "marginAsset":"USDT","pricePrecision":2,"quantityPrecision":3,
+                                "filters":[],"orderTypes":["LIMIT","MARKET"]
+                            },
+                            {
+                                "symbol":"ETHUSDT_230929","pair":"ETHUSDT",
+                                "contractType":"CURRENT_QUARTER","deliveryDate":1695974400000,
+                                "onboardDate":1688112000000,"status":"SETTLING","baseAsset":"ETH",
+                                "quoteAsset":"USDT","marginAsset":"USDT","pricePrecision":2,
+                                "quantityPrecision":3,"filters":[],"orderTypes":["LIMIT"]
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: vec![InstrumentNameExchange::from("BTCUSDT")],
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BinanceExchangeInfo>(test.input)
+                    .unwrap()
+                    .into_instruments();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
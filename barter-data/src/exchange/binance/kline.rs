@@ -0,0 +1,232 @@
+use super::BinanceChannel;
+use crate::{
+    Identifier,
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
+    subscription::{candle::Candle, kline::KlineInterval},
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+
+/// Binance real-time kline / candlestick message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#klinecandlestick-streams-for-utc>
+/// ```json
+/// {
+///     "e": "kline",
+///     "E": 1672515782136,
+///     "s": "BNBBTC",
+///     "k": {
+///         "t": 1672515780000,
+///         "T": 1672515839999,
+///         "s": "BNBBTC",
+///         "i": "1m",
+///         "f": 100,
+///         "L": 200,
+///         "o": "0.0010",
+///         "c": "0.0020",
+///         "h": "0.0025",
+///         "l": "0.0015",
+///         "v": "1000",
+///         "n": 100,
+///         "x": false,
+///         "q": "1.0000",
+///         "V": "500",
+///         "Q": "0.500",
+///         "B": "123456"
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceKline {
+    #[serde(alias = "s")]
+    pub market: SmolStr,
+    #[serde(alias = "k")]
+    pub kline: BinanceKlineData,
+}
+
+/// Inner candle data of a [`BinanceKline`].
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct BinanceKlineData {
+    #[serde(
+        alias = "t",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time_open: DateTime<Utc>,
+    #[serde(
+        alias = "T",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time_close: DateTime<Utc>,
+    #[serde(alias = "i")]
+    pub interval: KlineInterval,
+    #[serde(alias = "o", deserialize_with = "barter_integration::de::de_str")]
+    pub open: f64,
+    #[serde(alias = "h", deserialize_with = "barter_integration::de::de_str")]
+    pub high: f64,
+    #[serde(alias = "l", deserialize_with = "barter_integration::de::de_str")]
+    pub low: f64,
+    #[serde(alias = "c", deserialize_with = "barter_integration::de::de_str")]
+    pub close: f64,
+    #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
+    pub volume: f64,
+    #[serde(alias = "n")]
+    pub trade_count: u64,
+    #[serde(alias = "x")]
+    pub is_closed: bool,
+}
+
+impl Identifier<Option<SubscriptionId>> for BinanceKline {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(
+            ExchangeSub::from((
+                BinanceChannel::kline(self.kline.interval),
+                self.market.as_str(),
+            ))
+            .id(),
+        )
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceKline)>
+    for MarketIter<InstrumentKey, Candle>
+{
+    fn from((exchange_id, instrument, kline): (ExchangeId, InstrumentKey, BinanceKline)) -> Self {
+        let BinanceKlineData {
+            time_close,
+            open,
+            high,
+            low,
+            close,
+            volume,
+            trade_count,
+            is_closed,
+            ..
+        } = kline.kline;
+
+        Self(vec![Ok(MarketEvent {
+            time_exchange: time_close,
+            time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
+            exchange: exchange_id,
+            instrument,
+            kind: Candle {
+                close_time: time_close,
+                open,
+                high,
+                low,
+                close,
+                volume,
+                trade_count,
+                is_closed,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorded_kline(is_closed: bool) -> String {
+        format!(
+            r#"{{
+                "e":"kline","E":1672515782136,"s":"BNBBTC",
+                "k":{{
+                    "t":1672515780000,"T":1672515839999,"s":"BNBBTC","i":"1m",
+                    "f":100,"L":200,"o":"0.0010","c":"0.0020","h":"0.0025","l":"0.0015",
+                    "v":"1000","n":100,"x":{is_closed},"q":"1.0000","V":"500","Q":"0.500",
+                    "B":"123456"
+                }}
+            }}"#
+        )
+    }
+
+    #[test]
+    fn test_binance_kline_closed_and_in_progress() {
+        struct TestCase {
+            input: String,
+            expected_is_closed: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: in-progress kline update
+                input: recorded_kline(false),
+                expected_is_closed: false,
+            },
+            TestCase {
+                // TC1: final closed kline update
+                input: recorded_kline(true),
+                expected_is_closed: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let kline = serde_json::from_str::<BinanceKline>(&test.input).unwrap();
+            assert_eq!(
+                kline.id(),
+                Some(SubscriptionId::from("@kline_1m|BNBBTC")),
+                "TC{index} failed"
+            );
+            assert_eq!(
+                kline.kline.interval,
+                KlineInterval::Minute1,
+                "TC{index} failed"
+            );
+
+            let MarketIter(events) =
+                MarketIter::<&str, Candle>::from((ExchangeId::BinanceSpot, "bnb_btc", kline));
+            let event = events.into_iter().next().unwrap().unwrap();
+
+            let expected_close_time =
+                DateTime::<Utc>::from_timestamp_millis(1672515839999).unwrap();
+            assert_eq!(event.time_exchange, expected_close_time, "TC{index} failed");
+            assert_eq!(
+                event.kind,
+                Candle {
+                    close_time: expected_close_time,
+                    open: 0.0010,
+                    high: 0.0025,
+                    low: 0.0015,
+                    close: 0.0020,
+                    volume: 1000.0,
+                    trade_count: 100,
+                    is_closed: test.expected_is_closed,
+                },
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_binance_kline_subscribe_payload_encodes_interval() {
+        use crate::exchange::{
+            Connector,
+            binance::{market::BinanceMarket, spot::BinanceSpot},
+        };
+
+        let requests = BinanceSpot::requests(vec![
+            ExchangeSub::from((
+                BinanceChannel::kline(KlineInterval::Minute5),
+                BinanceMarket("BTCUSDT".into()),
+            )),
+            ExchangeSub::from((
+                BinanceChannel::kline(KlineInterval::Hour1),
+                BinanceMarket("ETHUSDT".into()),
+            )),
+        ]);
+
+        let payload =
+            serde_json::from_str::<serde_json::Value>(requests[0].to_text().unwrap()).unwrap();
+        assert_eq!(
+            payload["params"],
+            serde_json::json!(["btcusdt@kline_5m", "ethusdt@kline_1h"])
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use self::{
-    book::l1::BinanceOrderBookL1, channel::BinanceChannel, market::BinanceMarket,
+    book::l1::BinanceOrderBookL1, channel::BinanceChannel, futures::BinanceFuturesUsd,
+    instrument::BinanceExchangeInfo, kline::BinanceKline, market::BinanceMarket, spot::BinanceSpot,
     subscription::BinanceSubResponse, trade::BinanceTrade,
 };
 use crate::{
@@ -7,10 +8,15 @@ use crate::{
     exchange::{Connector, ExchangeServer, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
     subscriber::{WebSocketSubscriber, validator::WebSocketSubValidator},
-    subscription::{Map, book::OrderBooksL1, trade::PublicTrades},
+    subscription::{
+        Map,
+        book::{BestBidOffer, OrderBooksL1},
+        kline::Klines,
+        trade::PublicTrades,
+    },
     transformer::stateless::StatelessTransformer,
 };
-use barter_instrument::exchange::ExchangeId;
+use barter_instrument::{exchange::ExchangeId, instrument::name::InstrumentNameExchange};
 use barter_integration::{
     error::SocketError,
     protocol::websocket::{WebSocketSerdeParser, WsMessage},
@@ -30,6 +36,14 @@ pub mod channel;
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod futures;
 
+/// Instrument listing types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd), used by [`Connector::fetch_instruments`].
+pub mod instrument;
+
+/// Kline / candlestick types common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod kline;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -47,6 +61,13 @@ pub mod subscription;
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod trade;
 
+/// Maximum number of streams sent in a single [`Binance`] `SUBSCRIBE` request.
+///
+/// Conservative batch size that keeps `SUBSCRIBE` payloads well within what the server accepts.
+///
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams>
+pub const SUBSCRIPTION_BATCH_LIMIT_BINANCE: usize = 200;
+
 /// Convenient type alias for a Binance [`ExchangeWsStream`] using [`WebSocketSerdeParser`].
 pub type BinanceWsStream<Transformer> = ExchangeWsStream<WebSocketSerdeParser, Transformer>;
 
@@ -71,6 +92,8 @@ where
     type SubValidator = WebSocketSubValidator;
     type SubResponse = BinanceSubResponse;
 
+    const SUBSCRIPTION_BATCH_LIMIT: usize = SUBSCRIPTION_BATCH_LIMIT_BINANCE;
+
     fn url() -> Result<Url, SocketError> {
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
@@ -100,8 +123,30 @@ where
         )]
     }
 
-    fn expected_responses<InstrumentKey>(_: &Map<InstrumentKey>) -> usize {
-        1
+    fn expected_responses<InstrumentKey>(map: &Map<InstrumentKey>) -> usize {
+        Self::subscription_batches(map)
+    }
+
+    async fn fetch_instruments() -> Result<Vec<InstrumentNameExchange>, SocketError> {
+        let url = match Self::ID {
+            BinanceSpot::ID => spot::HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT,
+            BinanceFuturesUsd::ID => futures::HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD,
+            exchange => {
+                return Err(SocketError::Unsupported {
+                    entity: exchange.to_string(),
+                    item: "fetch_instruments".to_string(),
+                });
+            }
+        };
+
+        let exchange_info = reqwest::get(url)
+            .await
+            .map_err(SocketError::Http)?
+            .json::<BinanceExchangeInfo>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        Ok(exchange_info.into_instruments())
     }
 }
 
@@ -126,6 +171,27 @@ where
     >;
 }
 
+impl<Instrument, Server> StreamSelector<Instrument, BestBidOffer> for Binance<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = BinanceWsStream<
+        StatelessTransformer<Self, Instrument::Key, BestBidOffer, BinanceOrderBookL1>,
+    >;
+}
+
+impl<Instrument, Server> StreamSelector<Instrument, Klines> for Binance<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        BinanceWsStream<StatelessTransformer<Self, Instrument::Key, Klines, BinanceKline>>;
+}
+
 impl<'de, Server> serde::Deserialize<'de> for Binance<Server>
 where
     Server: ExchangeServer,
@@ -334,6 +334,8 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceSpotOrderBookL2Updat
         Self(vec![Ok(MarketEvent {
             time_exchange: update.time_exchange,
             time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
             exchange: exchange_id,
             instrument,
             kind: OrderBookEvent::Update(OrderBook::new(
@@ -23,6 +23,11 @@ pub mod l2;
 /// See docs: <https://binance-docs.github.io/apidocs/spot/en/#websocket-market-streams>
 pub const WEBSOCKET_BASE_URL_BINANCE_SPOT: &str = "wss://stream.binance.com:9443/ws";
 
+/// [`BinanceSpot`] HTTP exchange information url, used to list the available instruments.
+///
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/rest-api/general-endpoints#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/exchangeInfo";
+
 /// [`Binance`] spot exchange.
 pub type BinanceSpot = Binance<BinanceServerSpot>;
 
@@ -8,6 +8,7 @@ use crate::{
 use barter_instrument::{Side, exchange::ExchangeId};
 use barter_integration::subscription::SubscriptionId;
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Binance real-time trade message.
@@ -63,9 +64,9 @@ pub struct BinanceTrade {
     #[serde(alias = "t")]
     pub id: u64,
     #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Decimal,
     #[serde(alias = "m", deserialize_with = "de_side_from_buyer_is_maker")]
     pub side: Side,
 }
@@ -83,6 +84,8 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BinanceTrade)>
         Self(vec![Ok(MarketEvent {
             time_exchange: trade.time,
             time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
             exchange: exchange_id,
             instrument,
             kind: PublicTrade {
@@ -126,6 +129,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use std::time::Duration;
@@ -158,8 +162,8 @@ mod tests {
                             1749354825200,
                         )),
                         id: 1000000000,
-                        price: 10000.19,
-                        amount: 0.239000,
+                        price: dec!(10000.19),
+                        amount: dec!(0.239000),
                         side: Side::Buy,
                     }),
                 },
@@ -189,8 +193,8 @@ mod tests {
                             1749354825200,
                         )),
                         id: 1000000000,
-                        price: 10000.19,
-                        amount: 0.239000,
+                        price: dec!(10000.19),
+                        amount: dec!(0.239000),
                         side: Side::Sell,
                     }),
                 },
@@ -208,8 +212,8 @@ mod tests {
                             1749354825200,
                         )),
                         id: 1000000000,
-                        price: 10000.19,
-                        amount: 0.239000,
+                        price: dec!(10000.19),
+                        amount: dec!(0.239000),
                         side: Side::Buy,
                     }),
                 },
@@ -225,8 +229,8 @@ mod tests {
                             1749354825200,
                         )),
                         id: 1000000000,
-                        price: 10000.19,
-                        amount: 0.239000,
+                        price: dec!(10000.19),
+                        amount: dec!(0.239000),
                         side: Side::Buy,
                     }),
                 },
@@ -251,4 +255,54 @@ mod tests {
             }
         }
     }
+
+    mod parse {
+        use super::*;
+        use barter_integration::protocol::{
+            StreamParser,
+            websocket::{WebSocketSerdeParser, WsMessage},
+        };
+
+        fn public_trade(trade: BinanceTrade) -> PublicTrade {
+            MarketIter::<(), PublicTrade>::from((ExchangeId::BinanceSpot, (), trade))
+                .0
+                .remove(0)
+                .unwrap()
+                .kind
+        }
+
+        #[test]
+        fn test_borrowed_and_owned_parse_paths_produce_identical_public_trades() {
+            let trade = |id: u64, buyer_is_maker: bool| {
+                format!(
+                    r#"{{"e":"trade","E":1649324825173,"s":"ETHUSDT","t":{id},"p":"10000.19","q":"0.239000","b":10108767791,"a":10108764858,"T":1749354825200,"m":{buyer_is_maker},"M":true}}"#
+                )
+            };
+
+            for (id, buyer_is_maker) in [(1, false), (2, true), (3, false)] {
+                let payload = trade(id, buyer_is_maker);
+
+                // Owned path: copy the payload into a String and deserialise
+                let owned = serde_json::from_str::<BinanceTrade>(&payload.to_owned()).unwrap();
+                assert_eq!(
+                    owned.subscription_id,
+                    SubscriptionId::from(format!("{}|{}", BinanceChannel::TRADES.0, "ETHUSDT"))
+                );
+
+                // Borrowed path: deserialise Text & Binary frames without copying the payload
+                for message in [
+                    WsMessage::text(payload.clone()),
+                    WsMessage::binary(payload.clone().into_bytes()),
+                ] {
+                    let borrowed =
+                        <WebSocketSerdeParser as StreamParser<BinanceTrade>>::parse(Ok(message))
+                            .unwrap()
+                            .unwrap();
+
+                    assert_eq!(borrowed, owned);
+                    assert_eq!(public_trade(borrowed), public_trade(owned.clone()));
+                }
+            }
+        }
+    }
 }
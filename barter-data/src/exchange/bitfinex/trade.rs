@@ -5,6 +5,7 @@ use crate::{
 use barter_instrument::{Side, exchange::ExchangeId};
 use barter_integration::de::{datetime_utc_from_epoch_duration, extract_next};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::Serialize;
 
 /// [`Bitfinex`](super::Bitfinex) real-time trade message.
@@ -38,8 +39,8 @@ pub struct BitfinexTrade {
     pub id: u64,
     pub time: DateTime<Utc>,
     pub side: Side,
-    pub price: f64,
-    pub amount: f64,
+    pub price: Decimal,
+    pub amount: Decimal,
 }
 
 impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BitfinexTrade)>
@@ -49,6 +50,8 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BitfinexTrade)>
         Self(vec![Ok(MarketEvent {
             time_exchange: trade.time,
             time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
             exchange: exchange_id,
             instrument,
             kind: PublicTrade {
@@ -85,7 +88,7 @@ impl<'de> serde::Deserialize<'de> for BitfinexTrade {
                 // Trade: [ID, TIME, AMOUNT,PRICE]
                 let id = extract_next(&mut seq, "id")?;
                 let time_millis = extract_next(&mut seq, "time")?;
-                let amount: f64 = extract_next(&mut seq, "amount")?;
+                let amount: Decimal = extract_next(&mut seq, "amount")?;
                 let price = extract_next(&mut seq, "price")?;
                 let side = match amount.is_sign_positive() {
                     true => Side::Buy,
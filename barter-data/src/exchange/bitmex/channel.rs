@@ -17,6 +17,12 @@ impl BitmexChannel {
     ///
     /// See docs: <https://www.bitmex.com/app/wsAPI>
     pub const TRADES: Self = Self("trade");
+
+    /// [`Bitmex`] real-time top of book channel name, subscribed to alongside
+    /// [`Self::TRADES`] to infer trade aggressor sides.
+    ///
+    /// See docs: <https://www.bitmex.com/app/wsAPI>
+    pub const QUOTES: Self = Self("quote");
 }
 
 impl<Instrument> Identifier<BitmexChannel> for Subscription<Bitmex, Instrument, PublicTrades> {
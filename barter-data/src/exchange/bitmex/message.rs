@@ -1,4 +1,7 @@
-use crate::{Identifier, exchange::bitmex::trade::BitmexTrade};
+use crate::{
+    Identifier,
+    exchange::bitmex::{channel::BitmexChannel, quote::BitmexQuote, trade::BitmexTrade},
+};
 use barter_integration::subscription::SubscriptionId;
 use serde::{Deserialize, Serialize};
 use smol_str::format_smolstr;
@@ -41,3 +44,17 @@ impl Identifier<Option<SubscriptionId>> for BitmexTrade {
             .or(None)
     }
 }
+
+impl Identifier<Option<SubscriptionId>> for BitmexQuote {
+    /// Quotes are subscribed to alongside trades, so are identified by the associated trades
+    /// [`SubscriptionId`].
+    fn id(&self) -> Option<SubscriptionId> {
+        self.data.first().map(|quote| {
+            SubscriptionId(format_smolstr!(
+                "{}|{}",
+                BitmexChannel::TRADES.as_ref(),
+                quote.symbol
+            ))
+        })
+    }
+}
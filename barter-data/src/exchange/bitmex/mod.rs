@@ -3,15 +3,15 @@ use crate::{
     exchange::{
         Connector, StreamSelector,
         bitmex::{
-            channel::BitmexChannel, market::BitmexMarket, subscription::BitmexSubResponse,
-            trade::BitmexTrade,
+            channel::BitmexChannel, market::BitmexMarket, quote::BitmexQuote,
+            subscription::BitmexSubResponse, trade::BitmexTrade,
         },
         subscription::ExchangeSub,
     },
     instrument::InstrumentData,
     subscriber::{WebSocketSubscriber, validator::WebSocketSubValidator},
     subscription::{Map, trade::PublicTrades},
-    transformer::stateless::StatelessTransformer,
+    transformer::aggressor::AggressorTransformer,
 };
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{
@@ -34,6 +34,9 @@ pub mod market;
 /// Generic [`BitmexMessage<T>`](message::BitmexMessage)
 pub mod message;
 
+/// Real-time top of book types for [`Bitmex`].
+pub mod quote;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration::Validator) for [`Bitmex`].
 pub mod subscription;
@@ -65,9 +68,16 @@ impl Connector for Bitmex {
     }
 
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        // Quotes are subscribed to alongside trades to infer trade aggressor sides
         let stream_names = exchange_subs
             .into_iter()
-            .map(|sub| format!("{}:{}", sub.channel.as_ref(), sub.market.as_ref(),))
+            .flat_map(|sub| {
+                let quotes = (sub.channel == BitmexChannel::TRADES)
+                    .then(|| format!("{}:{}", BitmexChannel::QUOTES.as_ref(), sub.market.as_ref()));
+
+                std::iter::once(format!("{}:{}", sub.channel.as_ref(), sub.market.as_ref()))
+                    .chain(quotes)
+            })
             .collect::<Vec<String>>();
 
         vec![WsMessage::text(
@@ -79,8 +89,8 @@ impl Connector for Bitmex {
         )]
     }
 
-    fn expected_responses<InstrumentKey>(_: &Map<InstrumentKey>) -> usize {
-        1
+    fn expected_responses<InstrumentKey>(map: &Map<InstrumentKey>) -> usize {
+        Self::subscription_batches(map)
     }
 }
 
@@ -90,7 +100,7 @@ where
 {
     type SnapFetcher = NoInitialSnapshots;
     type Stream =
-        BitmexWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, BitmexTrade>>;
+        BitmexWsStream<AggressorTransformer<Self, Instrument::Key, BitmexTrade, BitmexQuote>>;
 }
 
 impl<'de> serde::Deserialize<'de> for Bitmex {
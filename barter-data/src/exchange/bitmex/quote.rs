@@ -0,0 +1,181 @@
+use crate::{
+    books::Level,
+    event::{MarketEvent, MarketIter},
+    exchange::bitmex::message::BitmexMessage,
+    subscription::book::OrderBookL1,
+};
+use barter_instrument::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`BitmexQuote`](BitmexQuoteInner) real-time top of book WebSocket
+/// message.
+pub type BitmexQuote = BitmexMessage<BitmexQuoteInner>;
+
+/// ### Raw Payload Examples
+/// See docs: <https://www.bitmex.com/app/wsAPI#Response-Format>
+/// #### Quote payload
+/// ```json
+/// {
+///     "table": "quote",
+///     "action": "insert",
+///     "data": [
+///         {
+///             "timestamp": "2023-02-18T09:27:59.701Z",
+///             "symbol": "XBTUSD",
+///             "bidSize": 15900,
+///             "bidPrice": 24564,
+///             "askPrice": 24564.5,
+///             "askSize": 60200
+///         }
+///     ]
+/// }
+///```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BitmexQuoteInner {
+    pub timestamp: DateTime<Utc>,
+    pub symbol: String,
+    pub bid_price: Option<Decimal>,
+    pub bid_size: Option<Decimal>,
+    pub ask_price: Option<Decimal>,
+    pub ask_size: Option<Decimal>,
+}
+
+impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, BitmexQuote)>
+    for MarketIter<InstrumentKey, OrderBookL1>
+{
+    fn from((exchange, instrument, quotes): (ExchangeId, InstrumentKey, BitmexQuote)) -> Self {
+        Self(
+            quotes
+                .data
+                .into_iter()
+                .map(|quote| {
+                    Ok(MarketEvent {
+                        time_exchange: quote.timestamp,
+                        time_received: Utc::now(),
+                        time_processed: None,
+                        sequence: None,
+                        exchange,
+                        instrument: instrument.clone(),
+                        kind: OrderBookL1 {
+                            last_update_time: quote.timestamp,
+                            best_bid: quote
+                                .bid_price
+                                .zip(quote.bid_size)
+                                .map(|(price, amount)| Level::new(price, amount)),
+                            best_ask: quote
+                                .ask_price
+                                .zip(quote.ask_size)
+                                .map(|(price, amount)| Level::new(price, amount)),
+                        },
+                    })
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    mod de {
+        use super::*;
+        use barter_integration::error::SocketError;
+        use chrono::{Duration, TimeZone};
+
+        #[test]
+        fn test_bitmex_quote() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<BitmexQuote, SocketError>,
+            }
+
+            let tests = vec![
+                // TC0: input BitmexQuote is deserialised
+                TestCase {
+                    input: r#"
+                    {
+                        "table": "quote",
+                        "action": "insert",
+                        "data": [
+                            {
+                                "timestamp": "2023-02-18T09:27:59.701Z",
+                                "symbol": "XBTUSD",
+                                "bidSize": 15900,
+                                "bidPrice": 24564,
+                                "askPrice": 24564.5,
+                                "askSize": 60200
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(BitmexQuote {
+                        table: "quote".to_string(),
+                        data: vec![BitmexQuoteInner {
+                            timestamp: Utc.with_ymd_and_hms(2023, 2, 18, 9, 27, 59).unwrap()
+                                + Duration::milliseconds(701),
+                            symbol: "XBTUSD".to_string(),
+                            bid_price: Some(dec!(24564)),
+                            bid_size: Some(dec!(15900)),
+                            ask_price: Some(dec!(24564.5)),
+                            ask_size: Some(dec!(60200)),
+                        }],
+                    }),
+                },
+                // TC1: input BitmexQuote with an empty ask side is deserialised
+                TestCase {
+                    input: r#"
+                    {
+                        "table": "quote",
+                        "action": "insert",
+                        "data": [
+                            {
+                                "timestamp": "2023-02-18T09:27:59.701Z",
+                                "symbol": "XBTUSD",
+                                "bidSize": 15900,
+                                "bidPrice": 24564,
+                                "askPrice": null,
+                                "askSize": null
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Ok(BitmexQuote {
+                        table: "quote".to_string(),
+                        data: vec![BitmexQuoteInner {
+                            timestamp: Utc.with_ymd_and_hms(2023, 2, 18, 9, 27, 59).unwrap()
+                                + Duration::milliseconds(701),
+                            symbol: "XBTUSD".to_string(),
+                            bid_price: Some(dec!(24564)),
+                            bid_size: Some(dec!(15900)),
+                            ask_price: None,
+                            ask_size: None,
+                        }],
+                    }),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<BitmexQuote>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!(
+                            "TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,11 +1,12 @@
 use crate::{
     event::{MarketEvent, MarketIter},
     exchange::bitmex::message::BitmexMessage,
-    subscription::trade::PublicTrade,
+    transformer::aggressor::UnsidedPublicTrade,
 };
 use barter_instrument::{Side, exchange::ExchangeId};
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Terse type alias for an [`BitmexTrade`](BitmexTradeInner) real-time trades WebSocket message.
 pub type BitmexTrade = BitmexMessage<BitmexTradeInner>;
@@ -34,21 +35,38 @@ pub type BitmexTrade = BitmexMessage<BitmexTradeInner>;
 ///     ]
 /// }
 ///```
+///
+/// Note that the `side` may be empty, in which case the aggressor [`Side`] is inferred by the
+/// [`AggressorTransformer`](crate::transformer::aggressor::AggressorTransformer).
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct BitmexTradeInner {
     pub timestamp: DateTime<Utc>,
     pub symbol: String,
-    pub side: Side,
+    #[serde(deserialize_with = "de_side_optional")]
+    pub side: Option<Side>,
     #[serde(rename = "size")]
-    pub amount: f64,
-    pub price: f64,
+    pub amount: Decimal,
+    pub price: Decimal,
 
     #[serde(rename = "trdMatchID")]
     pub id: String,
 }
 
+/// Deserialize a [`BitmexTradeInner`] "side" field, mapping an empty or null side to `None`.
+fn de_side_optional<'de, D>(deserializer: D) -> Result<Option<Side>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)?.as_deref() {
+        None | Some("") => Ok(None),
+        Some(side) => {
+            Side::deserialize(serde::de::value::StrDeserializer::<D::Error>::new(side)).map(Some)
+        }
+    }
+}
+
 impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, BitmexTrade)>
-    for MarketIter<InstrumentKey, PublicTrade>
+    for MarketIter<InstrumentKey, UnsidedPublicTrade>
 {
     fn from((exchange, instrument, trades): (ExchangeId, InstrumentKey, BitmexTrade)) -> Self {
         Self(
@@ -59,9 +77,11 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, BitmexTrade)>
                     Ok(MarketEvent {
                         time_exchange: trade.timestamp,
                         time_received: Utc::now(),
+                        time_processed: None,
+                        sequence: None,
                         exchange,
                         instrument: instrument.clone(),
-                        kind: PublicTrade {
+                        kind: UnsidedPublicTrade {
                             id: trade.id,
                             price: trade.price,
                             amount: trade.amount,
@@ -77,6 +97,7 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, BitmexTrade)>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -112,12 +133,51 @@ mod tests {
                         timestamp: Utc.with_ymd_and_hms(2023, 2, 18, 9, 27, 59).unwrap()
                             + Duration::milliseconds(701),
                         symbol: "XBTUSD".to_string(),
-                        side: Side::Sell,
-                        amount: 200.0,
-                        price: 24564.5,
+                        side: Some(Side::Sell),
+                        amount: dec!(200.0),
+                        price: dec!(24564.5),
+                        id: "31e50cb7-e005-a44e-f354-86e88dff52eb".to_string(),
+                    }),
+                },
+                // TC1: input BitmexTrade with an empty side is deserialised
+                TestCase {
+                    input: r#"
+                    {
+                        "timestamp": "2023-02-18T09:27:59.701Z",
+                        "symbol": "XBTUSD",
+                        "side": "",
+                        "size": 200,
+                        "price": 24564.5,
+                        "trdMatchID": "31e50cb7-e005-a44e-f354-86e88dff52eb"
+                    }
+                    "#,
+                    expected: Ok(BitmexTradeInner {
+                        timestamp: Utc.with_ymd_and_hms(2023, 2, 18, 9, 27, 59).unwrap()
+                            + Duration::milliseconds(701),
+                        symbol: "XBTUSD".to_string(),
+                        side: None,
+                        amount: dec!(200.0),
+                        price: dec!(24564.5),
                         id: "31e50cb7-e005-a44e-f354-86e88dff52eb".to_string(),
                     }),
                 },
+                // TC2: input BitmexTrade with an invalid side is rejected
+                TestCase {
+                    input: r#"
+                    {
+                        "timestamp": "2023-02-18T09:27:59.701Z",
+                        "symbol": "XBTUSD",
+                        "side": "Sideways",
+                        "size": 200,
+                        "price": 24564.5,
+                        "trdMatchID": "31e50cb7-e005-a44e-f354-86e88dff52eb"
+                    }
+                    "#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "".to_string(),
+                        item: "".to_string(),
+                    }),
+                },
             ];
 
             for (index, test) in tests.into_iter().enumerate() {
@@ -176,9 +236,9 @@ mod tests {
                             timestamp: Utc.with_ymd_and_hms(2023, 2, 18, 9, 27, 59).unwrap()
                                 + Duration::milliseconds(701),
                             symbol: "XBTUSD".to_string(),
-                            side: Side::Sell,
-                            amount: 200.0,
-                            price: 24564.5,
+                            side: Some(Side::Sell),
+                            amount: dec!(200.0),
+                            price: dec!(24564.5),
                             id: "31e50cb7-e005-a44e-f354-86e88dff52eb".to_string(),
                         }],
                     }),
@@ -4,7 +4,7 @@ use chrono::Utc;
 use crate::{
     books::Level,
     event::{MarketEvent, MarketIter},
-    subscription::book::OrderBookL1,
+    subscription::book::{Bbo, OrderBookL1},
 };
 
 use super::BybitOrderBookMessage;
@@ -23,6 +23,8 @@ where
         Self(vec![Ok(MarketEvent {
             time_exchange: book.time,
             time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
             exchange,
             instrument,
             kind: OrderBookL1 {
@@ -33,3 +35,13 @@ where
         })])
     }
 }
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BybitOrderBookMessage)>
+    for MarketIter<InstrumentKey, Bbo>
+where
+    InstrumentKey: Clone,
+{
+    fn from(message: (ExchangeId, InstrumentKey, BybitOrderBookMessage)) -> Self {
+        MarketIter::<InstrumentKey, OrderBookL1>::from(message).into()
+    }
+}
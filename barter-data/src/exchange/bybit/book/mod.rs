@@ -55,6 +55,8 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, BybitOrderBookMessage)>
         Self(vec![Ok(MarketEvent {
             time_exchange: message.time,
             time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
             exchange,
             instrument,
             kind,
@@ -3,7 +3,7 @@ use crate::{
     exchange::bybit::Bybit,
     subscription::{
         Subscription,
-        book::{OrderBooksL1, OrderBooksL2},
+        book::{BestBidOffer, OrderBooksL1, OrderBooksL2},
         trade::PublicTrades,
     },
 };
@@ -49,6 +49,15 @@ impl<Server, Instrument> Identifier<BybitChannel>
     }
 }
 
+/// Bybit has no dedicated best-bid-offer channel, so [`BestBidOffer`] is derived from L1.
+impl<Server, Instrument> Identifier<BybitChannel>
+    for Subscription<Bybit<Server>, Instrument, BestBidOffer>
+{
+    fn id(&self) -> BybitChannel {
+        BybitChannel::ORDER_BOOK_L1
+    }
+}
+
 impl<Server, Instrument> Identifier<BybitChannel>
     for Subscription<Bybit<Server>, Instrument, OrderBooksL2>
 {
@@ -9,7 +9,7 @@ use crate::{
     subscriber::{WebSocketSubscriber, validator::WebSocketSubValidator},
     subscription::{
         Map,
-        book::{OrderBooksL1, OrderBooksL2},
+        book::{BestBidOffer, OrderBooksL1, OrderBooksL2},
         trade::PublicTrades,
     },
     transformer::stateless::StatelessTransformer,
@@ -59,6 +59,11 @@ pub mod trade;
 /// [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
 pub mod book;
 
+/// Maximum number of args sent in a single [`Bybit`] subscribe request.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#public-channel---args-limits>
+pub const SUBSCRIPTION_BATCH_LIMIT_BYBIT: usize = 10;
+
 /// Convenient type alias for a Bybit [`ExchangeWsStream`] using [`WebSocketSerdeParser`](barter_integration::protocol::websocket::WebSocketSerdeParser).
 pub type BybitWsStream<Transformer> = ExchangeWsStream<WebSocketSerdeParser, Transformer>;
 
@@ -83,6 +88,8 @@ where
     type SubValidator = WebSocketSubValidator;
     type SubResponse = BybitResponse;
 
+    const SUBSCRIPTION_BATCH_LIMIT: usize = SUBSCRIPTION_BATCH_LIMIT_BYBIT;
+
     fn url() -> Result<Url, SocketError> {
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
@@ -116,8 +123,8 @@ where
         )]
     }
 
-    fn expected_responses<InstrumentKey>(_: &Map<InstrumentKey>) -> usize {
-        1
+    fn expected_responses<InstrumentKey>(map: &Map<InstrumentKey>) -> usize {
+        Self::subscription_batches(map)
     }
 }
 
@@ -142,6 +149,17 @@ where
     >;
 }
 
+impl<Instrument, Server> StreamSelector<Instrument, BestBidOffer> for Bybit<Server>
+where
+    Instrument: InstrumentData,
+    Server: ExchangeServer + Debug + Send + Sync,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = BybitWsStream<
+        StatelessTransformer<Self, Instrument::Key, BestBidOffer, BybitOrderBookMessage>,
+    >;
+}
+
 impl<Instrument, Server> StreamSelector<Instrument, OrderBooksL2> for Bybit<Server>
 where
     Instrument: InstrumentData,
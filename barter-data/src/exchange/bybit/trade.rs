@@ -5,6 +5,7 @@ use crate::{
 };
 use barter_instrument::{Side, exchange::ExchangeId};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Terse type alias for an [`BybitTrade`](BybitTradeInner) real-time trades WebSocket message.
@@ -40,10 +41,10 @@ pub struct BybitTradeInner {
     pub side: Side,
 
     #[serde(alias = "v", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Decimal,
 
     #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Decimal,
 
     #[serde(rename = "i")]
     pub id: String,
@@ -61,6 +62,8 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, BybitTrade)>
                     Ok(MarketEvent {
                         time_exchange: trade.time,
                         time_received: Utc::now(),
+                        time_processed: None,
+                        sequence: None,
                         exchange,
                         instrument: instrument.clone(),
                         kind: PublicTrade {
@@ -79,6 +82,7 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, BybitTrade)>
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use crate::exchange::bybit::message::BybitPayloadKind;
@@ -118,8 +122,8 @@ mod tests {
                         )),
                         market: "BTCUSDT".to_string(),
                         side: Side::Buy,
-                        amount: 0.001,
-                        price: 16578.50,
+                        amount: dec!(0.001),
+                        price: dec!(16578.50),
                         id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
                     }),
                 },
@@ -143,8 +147,8 @@ mod tests {
                         )),
                         market: "BTCUSDT".to_string(),
                         side: Side::Sell,
-                        amount: 0.001,
-                        price: 16578.50,
+                        amount: dec!(0.001),
+                        price: dec!(16578.50),
                         id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
                     }),
                 },
@@ -240,8 +244,8 @@ mod tests {
                                 )),
                                 market: "BTCUSDT".to_string(),
                                 side: Side::Buy,
-                                amount: 0.001,
-                                price: 16578.50,
+                                amount: dec!(0.001),
+                                price: dec!(16578.50),
                                 id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
                             },
                             BybitTradeInner {
@@ -250,8 +254,8 @@ mod tests {
                                 )),
                                 market: "BTCUSDT".to_string(),
                                 side: Side::Sell,
-                                amount: 0.001,
-                                price: 16578.50,
+                                amount: dec!(0.001),
+                                price: dec!(16578.50),
                                 id: "20f43950-d8dd-5b31-9112-a178eb6023af".to_string(),
                             },
                         ],
@@ -0,0 +1,613 @@
+use crate::{
+    Identifier,
+    books::{
+        OrderBook,
+        l3::{OrderBookL3, OrderL3},
+    },
+    error::DataError,
+    event::MarketEvent,
+    exchange::{
+        Connector, ExchangeSub,
+        coinbase::{Coinbase, channel::CoinbaseChannel},
+    },
+    subscription::{
+        Map,
+        book::{OrderBookEvent, OrderBooksL3},
+    },
+    transformer::ExchangeTransformer,
+};
+use async_trait::async_trait;
+use barter_instrument::Side;
+use barter_integration::{
+    Transformer, error::SocketError, protocol::websocket::WsMessage, subscription::SubscriptionId,
+};
+use chrono::{DateTime, Utc};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use futures_util::future::try_join_all;
+use reqwest::header::USER_AGENT;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+use tokio::sync::mpsc;
+
+/// [`Coinbase`] HTTP products url, used to fetch the level 3 OrderBook snapshot of a product via
+/// `{HTTP_PRODUCTS_URL_COINBASE}/{product_id}/book?level=3`.
+///
+/// See docs: <https://docs.cdp.coinbase.com/exchange/reference/exchangerestapi_getproductbook>
+pub const HTTP_PRODUCTS_URL_COINBASE: &str = "https://api.exchange.coinbase.com/products";
+
+/// Fetches the full level 3 [`CoinbaseOrderBookL3Snapshot`] used to seed the local
+/// [`OrderBookL3`] of a [`CoinbaseOrderBooksL3Transformer`].
+///
+/// The per-order snapshot cannot be represented by an aggregated [`OrderBookEvent::Snapshot`], so
+/// it is fetched during [`CoinbaseOrderBooksL3Transformer`] initialisation rather than via the
+/// [`SnapshotFetcher`](crate::SnapshotFetcher) of the market stream.
+#[derive(Debug)]
+pub struct CoinbaseOrderBooksL3SnapshotFetcher;
+
+impl CoinbaseOrderBooksL3SnapshotFetcher {
+    /// Fetch the level 3 [`CoinbaseOrderBookL3Snapshot`] of the provided product (eg/ "BTC-USD").
+    pub async fn fetch_snapshot(
+        product_id: &str,
+    ) -> Result<CoinbaseOrderBookL3Snapshot, SocketError> {
+        // Coinbase rejects HTTP requests without a User-Agent header
+        reqwest::Client::new()
+            .get(format!(
+                "{HTTP_PRODUCTS_URL_COINBASE}/{product_id}/book?level=3"
+            ))
+            .header(USER_AGENT, "barter-data")
+            .send()
+            .await
+            .map_err(SocketError::Http)?
+            .json::<CoinbaseOrderBookL3Snapshot>()
+            .await
+            .map_err(SocketError::Http)
+    }
+}
+
+/// [`Coinbase`] level 3 OrderBook snapshot, containing every resting order of a product.
+///
+/// See docs: <https://docs.cdp.coinbase.com/exchange/reference/exchangerestapi_getproductbook>
+///
+/// #### Raw Payload Examples
+/// ```json
+/// {
+///     "sequence": 3,
+///     "bids": [["295.96", "0.05088265", "3b0f1225-7f84-490b-a29f-0faef9de823a"]],
+///     "asks": [["295.97", "5.72036512", "da863862-25f4-4868-ac41-005d11ab0a5f"]]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderBookL3Snapshot {
+    pub sequence: u64,
+    pub bids: Vec<CoinbaseOrderL3>,
+    pub asks: Vec<CoinbaseOrderL3>,
+}
+
+/// [`Coinbase`] resting order in a [`CoinbaseOrderBookL3Snapshot`].
+///
+/// eg/ ["295.96", "0.05088265", "3b0f1225-7f84-490b-a29f-0faef9de823a"]
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderL3 {
+    #[serde(with = "rust_decimal::serde::str")]
+    pub price: Decimal,
+    #[serde(with = "rust_decimal::serde::str")]
+    pub size: Decimal,
+    pub order_id: SmolStr,
+}
+
+impl From<CoinbaseOrderBookL3Snapshot> for OrderBookL3 {
+    fn from(snapshot: CoinbaseOrderBookL3Snapshot) -> Self {
+        let orders = |side, orders: Vec<CoinbaseOrderL3>| {
+            orders
+                .into_iter()
+                .map(move |order| (order.order_id, OrderL3::new(side, order.price, order.size)))
+        };
+
+        OrderBookL3::new(
+            snapshot.sequence,
+            None,
+            orders(Side::Buy, snapshot.bids).chain(orders(Side::Sell, snapshot.asks)),
+        )
+    }
+}
+
+/// [`Coinbase`] real-time full channel message, used to maintain an L3 [`OrderBookL3`].
+///
+/// See docs: <https://docs.cloud.coinbase.com/exchange/docs/websocket-channels#full-channel>
+///
+/// #### Raw Payload Examples
+/// ```json
+/// {
+///     "type": "open",
+///     "time": "2014-11-07T08:19:27.028459Z",
+///     "product_id": "BTC-USD",
+///     "sequence": 10,
+///     "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+///     "price": "200.2",
+///     "remaining_size": "1.00",
+///     "side": "sell"
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct CoinbaseOrderBookL3Message {
+    #[serde(alias = "product_id", deserialize_with = "de_ob_l3_subscription_id")]
+    pub subscription_id: SubscriptionId,
+    pub sequence: u64,
+    pub time: DateTime<Utc>,
+    #[serde(flatten)]
+    pub kind: CoinbaseOrderBookL3Kind,
+}
+
+/// [`Coinbase`] full channel message variants.
+///
+/// Only the variants that affect resting orders carry data, all other message types (eg/
+/// `received`) are still required to validate the message sequence.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum CoinbaseOrderBookL3Kind {
+    /// Order is now resting on the book.
+    Open {
+        order_id: SmolStr,
+        side: Side,
+        #[serde(with = "rust_decimal::serde::str")]
+        price: Decimal,
+        #[serde(with = "rust_decimal::serde::str")]
+        remaining_size: Decimal,
+    },
+    /// Order is no longer on the book (filled or cancelled).
+    Done { order_id: SmolStr },
+    /// Trade occurred between a resting maker order and a taker order.
+    Match {
+        maker_order_id: SmolStr,
+        #[serde(with = "rust_decimal::serde::str")]
+        size: Decimal,
+    },
+    /// Resting order size has been modified.
+    Change {
+        order_id: SmolStr,
+        #[serde(default, with = "rust_decimal::serde::str_option")]
+        new_size: Option<Decimal>,
+    },
+    /// Any other full channel message (eg/ `received`).
+    #[serde(other)]
+    Other,
+}
+
+impl Identifier<Option<SubscriptionId>> for CoinbaseOrderBookL3Message {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// Deserialize a [`CoinbaseOrderBookL3Message`] "product_id" as the associated
+/// [`SubscriptionId`] (eg/ "full|BTC-USD").
+pub fn de_ob_l3_subscription_id<'de, D>(deserializer: D) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    <&str as Deserialize>::deserialize(deserializer)
+        .map(|product_id| ExchangeSub::from((CoinbaseChannel::ORDER_BOOK_L3, product_id)).id())
+}
+
+/// Instrument metadata used by the [`CoinbaseOrderBooksL3Transformer`] to maintain a local
+/// [`OrderBookL3`] and validate the message sequence against the [`OrderBookL3::sequence`].
+#[derive(Debug, Constructor)]
+pub struct CoinbaseOrderBookL3Meta<InstrumentKey> {
+    pub key: InstrumentKey,
+    pub book: OrderBookL3,
+}
+
+/// [`Coinbase`] L3 [`ExchangeTransformer`] that maintains a local [`OrderBookL3`] per instrument
+/// from the full channel, and emits the aggregated L2 level changes as [`OrderBookEvent::Update`]s.
+///
+/// Each [`OrderBookL3`] is seeded from a [`CoinbaseOrderBookL3Snapshot`] fetched after
+/// subscribing, and an [`OrderBookEvent::Snapshot`] of it is emitted before any update. Full
+/// channel messages with a sequence at or below the snapshot sequence are dropped.
+#[derive(Debug)]
+pub struct CoinbaseOrderBooksL3Transformer<InstrumentKey> {
+    instrument_map: Map<CoinbaseOrderBookL3Meta<InstrumentKey>>,
+    init_events: Vec<MarketEvent<InstrumentKey, OrderBookEvent>>,
+}
+
+impl<InstrumentKey> CoinbaseOrderBooksL3Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone,
+{
+    /// Construct a new [`Self`] with each instrument [`OrderBookL3`] seeded from the provided
+    /// [`CoinbaseOrderBookL3Snapshot`].
+    pub fn from_snapshots<Iter>(snapshots: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = (SubscriptionId, InstrumentKey, CoinbaseOrderBookL3Snapshot)>,
+    {
+        let (instrument_map, init_events) = snapshots
+            .into_iter()
+            .map(|(sub_id, key, snapshot)| {
+                let book = OrderBookL3::from(snapshot);
+                let time_received = Utc::now();
+                let snapshot = MarketEvent {
+                    time_exchange: time_received,
+                    time_received,
+                    time_processed: None,
+                    sequence: None,
+                    exchange: Coinbase::ID,
+                    instrument: key.clone(),
+                    kind: OrderBookEvent::Snapshot(book.l2()),
+                };
+
+                ((sub_id, CoinbaseOrderBookL3Meta::new(key, book)), snapshot)
+            })
+            .unzip::<_, _, FnvHashMap<_, _>, Vec<_>>();
+
+        Self {
+            instrument_map: Map(instrument_map),
+            init_events,
+        }
+    }
+}
+
+impl<InstrumentKey> CoinbaseOrderBooksL3Transformer<InstrumentKey> {
+    /// Find the local [`OrderBookL3`] associated with the provided [`SubscriptionId`].
+    pub fn book(&self, subscription_id: &SubscriptionId) -> Option<&OrderBookL3> {
+        self.instrument_map
+            .find(subscription_id)
+            .ok()
+            .map(|instrument| &instrument.book)
+    }
+}
+
+#[async_trait]
+impl<InstrumentKey> ExchangeTransformer<Coinbase, InstrumentKey, OrderBooksL3>
+    for CoinbaseOrderBooksL3Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone + PartialEq + Send + Sync,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        _: &[MarketEvent<InstrumentKey, OrderBookEvent>],
+        _: mpsc::UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        let snapshot_futures = instrument_map
+            .0
+            .into_iter()
+            .map(|(sub_id, instrument_key)| {
+                async move {
+                    // SubscriptionId is "full|{product_id}", eg/ "full|BTC-USD"
+                    let product_id = sub_id
+                        .as_ref()
+                        .split_once('|')
+                        .map(|(_, product_id)| product_id)
+                        .unwrap_or(sub_id.as_ref());
+
+                    let snapshot =
+                        CoinbaseOrderBooksL3SnapshotFetcher::fetch_snapshot(product_id).await?;
+
+                    Ok::<_, DataError>((sub_id, instrument_key, snapshot))
+                }
+            });
+
+        Ok(Self::from_snapshots(try_join_all(snapshot_futures).await?))
+    }
+
+    fn take_init_events(&mut self) -> Vec<MarketEvent<InstrumentKey, OrderBookEvent>> {
+        std::mem::take(&mut self.init_events)
+    }
+}
+
+impl<InstrumentKey> Transformer for CoinbaseOrderBooksL3Transformer<InstrumentKey>
+where
+    InstrumentKey: Clone,
+{
+    type Error = DataError;
+    type Input = CoinbaseOrderBookL3Message;
+    type Output = MarketEvent<InstrumentKey, OrderBookEvent>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let subscription_id = match input.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        let instrument = match self.instrument_map.find_mut(&subscription_id) {
+            Ok(instrument) => instrument,
+            Err(unidentifiable) => return vec![Err(DataError::from(unidentifiable))],
+        };
+
+        // Drop messages already reflected in the snapshot (or stale), and validate the sequence
+        let book = &mut instrument.book;
+        let last_sequence = book.sequence();
+        if input.sequence <= last_sequence {
+            return vec![];
+        }
+        if input.sequence != last_sequence + 1 {
+            return vec![Err(DataError::InvalidSequence {
+                prev_last_update_id: last_sequence,
+                first_update_id: input.sequence,
+            })];
+        }
+
+        book.set_sequence(input.sequence, Some(input.time));
+
+        let level_change = match input.kind {
+            CoinbaseOrderBookL3Kind::Open {
+                order_id,
+                side,
+                price,
+                remaining_size,
+            } => Some(book.open(order_id, OrderL3::new(side, price, remaining_size))),
+            CoinbaseOrderBookL3Kind::Done { order_id } => book.done(&order_id),
+            CoinbaseOrderBookL3Kind::Match {
+                maker_order_id,
+                size,
+            } => book.reduce(&maker_order_id, size),
+            CoinbaseOrderBookL3Kind::Change {
+                order_id,
+                new_size: Some(new_size),
+            } => book.change(&order_id, new_size),
+            CoinbaseOrderBookL3Kind::Change { new_size: None, .. }
+            | CoinbaseOrderBookL3Kind::Other => None,
+        };
+
+        let Some((side, level)) = level_change else {
+            return vec![];
+        };
+
+        let (bids, asks) = match side {
+            Side::Buy => (vec![level], vec![]),
+            Side::Sell => (vec![], vec![level]),
+        };
+
+        vec![Ok(MarketEvent {
+            time_exchange: input.time,
+            time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
+            exchange: Coinbase::ID,
+            instrument: instrument.key.clone(),
+            kind: OrderBookEvent::Update(OrderBook::new(
+                input.sequence,
+                Some(input.time),
+                bids,
+                asks,
+            )),
+        })]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::Level;
+    use rust_decimal_macros::dec;
+
+    fn transformer(snapshot: &str) -> CoinbaseOrderBooksL3Transformer<&'static str> {
+        CoinbaseOrderBooksL3Transformer::from_snapshots(std::iter::once((
+            SubscriptionId::from("full|BTC-USD"),
+            "btc_usd",
+            serde_json::from_str::<CoinbaseOrderBookL3Snapshot>(snapshot).unwrap(),
+        )))
+    }
+
+    fn message(input: &str) -> CoinbaseOrderBookL3Message {
+        serde_json::from_str(input).unwrap()
+    }
+
+    #[test]
+    fn test_de_coinbase_order_book_l3_message() {
+        let input = r#"
+        {
+            "type": "open", "time": "2014-11-07T08:19:27.028459Z", "product_id": "BTC-USD",
+            "sequence": 10, "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+            "price": "200.2", "remaining_size": "1.00", "side": "sell"
+        }"#;
+
+        let actual = message(input);
+        assert_eq!(actual.subscription_id, SubscriptionId::from("full|BTC-USD"));
+        assert_eq!(actual.sequence, 10);
+        assert_eq!(
+            actual.kind,
+            CoinbaseOrderBookL3Kind::Open {
+                order_id: SmolStr::new("d50ec984-77a8-460a-b958-66f114b0de9b"),
+                side: Side::Sell,
+                price: dec!(200.2),
+                remaining_size: dec!(1.00),
+            }
+        );
+
+        let input = r#"
+        {
+            "type": "received", "time": "2014-11-07T08:19:27.028459Z", "product_id": "BTC-USD",
+            "sequence": 11, "order_id": "d50ec984-77a8-460a-b958-66f114b0de9b",
+            "size": "1.34", "price": "502.1", "side": "buy", "order_type": "limit"
+        }"#;
+        assert_eq!(message(input).kind, CoinbaseOrderBookL3Kind::Other);
+    }
+
+    #[test]
+    fn test_coinbase_order_books_l3_transformer() {
+        struct TestCase {
+            input: &'static str,
+            expected_update: Option<(Vec<Level>, Vec<Level>)>,
+            expected_best_bid: Option<Level>,
+            expected_best_ask: Option<Level>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: open bid
+                input: r#"{"type":"open","time":"2024-01-01T00:00:00Z","product_id":"BTC-USD","sequence":1,"order_id":"b1","price":"100","remaining_size":"1","side":"buy"}"#,
+                expected_update: Some((vec![Level::new(dec!(100), dec!(1))], vec![])),
+                expected_best_bid: Some(Level::new(dec!(100), dec!(1))),
+                expected_best_ask: None,
+            },
+            TestCase {
+                // TC1: open second bid at same price aggregates
+                input: r#"{"type":"open","time":"2024-01-01T00:00:01Z","product_id":"BTC-USD","sequence":2,"order_id":"b2","price":"100","remaining_size":"2","side":"buy"}"#,
+                expected_update: Some((vec![Level::new(dec!(100), dec!(3))], vec![])),
+                expected_best_bid: Some(Level::new(dec!(100), dec!(3))),
+                expected_best_ask: None,
+            },
+            TestCase {
+                // TC2: open ask
+                input: r#"{"type":"open","time":"2024-01-01T00:00:02Z","product_id":"BTC-USD","sequence":3,"order_id":"a1","price":"101","remaining_size":"5","side":"sell"}"#,
+                expected_update: Some((vec![], vec![Level::new(dec!(101), dec!(5))])),
+                expected_best_bid: Some(Level::new(dec!(100), dec!(3))),
+                expected_best_ask: Some(Level::new(dec!(101), dec!(5))),
+            },
+            TestCase {
+                // TC3: received message does not affect the book
+                input: r#"{"type":"received","time":"2024-01-01T00:00:03Z","product_id":"BTC-USD","sequence":4,"order_id":"t1","size":"1","price":"101","side":"buy","order_type":"limit"}"#,
+                expected_update: None,
+                expected_best_bid: Some(Level::new(dec!(100), dec!(3))),
+                expected_best_ask: Some(Level::new(dec!(101), dec!(5))),
+            },
+            TestCase {
+                // TC4: match reduces resting maker ask
+                input: r#"{"type":"match","time":"2024-01-01T00:00:04Z","product_id":"BTC-USD","sequence":5,"trade_id":1,"maker_order_id":"a1","taker_order_id":"t1","size":"1","price":"101","side":"sell"}"#,
+                expected_update: Some((vec![], vec![Level::new(dec!(101), dec!(4))])),
+                expected_best_bid: Some(Level::new(dec!(100), dec!(3))),
+                expected_best_ask: Some(Level::new(dec!(101), dec!(4))),
+            },
+            TestCase {
+                // TC5: change modifies resting bid size
+                input: r#"{"type":"change","time":"2024-01-01T00:00:05Z","product_id":"BTC-USD","sequence":6,"order_id":"b2","new_size":"0.5","old_size":"2","price":"100","side":"buy"}"#,
+                expected_update: Some((vec![Level::new(dec!(100), dec!(1.5))], vec![])),
+                expected_best_bid: Some(Level::new(dec!(100), dec!(1.5))),
+                expected_best_ask: Some(Level::new(dec!(101), dec!(4))),
+            },
+            TestCase {
+                // TC6: done (cancelled) removes resting bid
+                input: r#"{"type":"done","time":"2024-01-01T00:00:06Z","product_id":"BTC-USD","sequence":7,"order_id":"b1","reason":"canceled","price":"100","remaining_size":"1","side":"buy"}"#,
+                expected_update: Some((vec![Level::new(dec!(100), dec!(0.5))], vec![])),
+                expected_best_bid: Some(Level::new(dec!(100), dec!(0.5))),
+                expected_best_ask: Some(Level::new(dec!(101), dec!(4))),
+            },
+            TestCase {
+                // TC7: done on last order at level removes level
+                input: r#"{"type":"done","time":"2024-01-01T00:00:07Z","product_id":"BTC-USD","sequence":8,"order_id":"b2","reason":"canceled","price":"100","remaining_size":"0.5","side":"buy"}"#,
+                expected_update: Some((vec![Level::new(dec!(100), dec!(0))], vec![])),
+                expected_best_bid: None,
+                expected_best_ask: Some(Level::new(dec!(101), dec!(4))),
+            },
+            TestCase {
+                // TC8: stale sequence is ignored
+                input: r#"{"type":"done","time":"2024-01-01T00:00:08Z","product_id":"BTC-USD","sequence":8,"order_id":"a1","reason":"canceled","price":"101","remaining_size":"4","side":"sell"}"#,
+                expected_update: None,
+                expected_best_bid: None,
+                expected_best_ask: Some(Level::new(dec!(101), dec!(4))),
+            },
+        ];
+
+        let mut transformer = transformer(r#"{"sequence":0,"bids":[],"asks":[]}"#);
+        let subscription_id = SubscriptionId::from("full|BTC-USD");
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let output = transformer.transform(message(test.input));
+
+            match (output.as_slice(), test.expected_update) {
+                ([], None) => {}
+                ([Ok(event)], Some((bids, asks))) => {
+                    let OrderBookEvent::Update(update) = &event.kind else {
+                        panic!("TC{index} failed: expected OrderBookEvent::Update");
+                    };
+                    assert_eq!(update.bids().levels(), bids.as_slice(), "TC{index} failed");
+                    assert_eq!(update.asks().levels(), asks.as_slice(), "TC{index} failed");
+                }
+                (actual, expected) => {
+                    panic!("TC{index} failed. Actual: {actual:?}, Expected: {expected:?}")
+                }
+            }
+
+            let book = transformer.book(&subscription_id).unwrap();
+            assert_eq!(book.best_bid(), test.expected_best_bid, "TC{index} failed");
+            assert_eq!(book.best_ask(), test.expected_best_ask, "TC{index} failed");
+        }
+
+        // Sequence gap is an error
+        let output = transformer.transform(message(
+            r#"{"type":"done","time":"2024-01-01T00:00:09Z","product_id":"BTC-USD","sequence":10,"order_id":"a1","reason":"canceled","side":"sell"}"#,
+        ));
+        assert!(matches!(
+            output.as_slice(),
+            [Err(DataError::InvalidSequence {
+                prev_last_update_id: 8,
+                first_update_id: 10
+            })]
+        ));
+    }
+
+    #[test]
+    fn test_coinbase_order_books_l3_transformer_seeded_from_snapshot() {
+        let mut transformer = transformer(
+            r#"
+            {
+                "sequence": 10,
+                "bids": [["100", "1", "b1"], ["100", "2", "b2"], ["99", "4", "b3"]],
+                "asks": [["101", "5", "a1"]],
+                "auction_mode": false,
+                "auction": null
+            }"#,
+        );
+        let subscription_id = SubscriptionId::from("full|BTC-USD");
+
+        // Snapshot of the seeded book is emitted first, and only once
+        let init_events =
+            ExchangeTransformer::<Coinbase, _, OrderBooksL3>::take_init_events(&mut transformer);
+        let [
+            MarketEvent {
+                instrument: "btc_usd",
+                kind: OrderBookEvent::Snapshot(snapshot),
+                ..
+            },
+        ] = init_events.as_slice()
+        else {
+            panic!("expected a single OrderBookEvent::Snapshot, found: {init_events:?}");
+        };
+        assert_eq!(snapshot.sequence(), 10);
+        assert_eq!(
+            snapshot.bids().levels(),
+            &[
+                Level::new(dec!(100), dec!(3)),
+                Level::new(dec!(99), dec!(4))
+            ]
+        );
+        assert_eq!(snapshot.asks().levels(), &[Level::new(dec!(101), dec!(5))]);
+        assert!(
+            ExchangeTransformer::<Coinbase, _, OrderBooksL3>::take_init_events(&mut transformer)
+                .is_empty()
+        );
+
+        // Messages at or below the snapshot sequence are already reflected, so are dropped
+        for sequence in [9, 10] {
+            let output = transformer.transform(message(&format!(
+                r#"{{"type":"done","time":"2024-01-01T00:00:00Z","product_id":"BTC-USD","sequence":{sequence},"order_id":"a1","reason":"canceled","side":"sell"}}"#
+            )));
+            assert!(output.is_empty(), "sequence {sequence} should be dropped");
+        }
+        let book = transformer.book(&subscription_id).unwrap();
+        assert_eq!(book.len(), 4);
+        assert_eq!(book.best_ask(), Some(Level::new(dec!(101), dec!(5))));
+
+        // Next message modifies an order resting in the snapshot
+        let output = transformer.transform(message(
+            r#"{"type":"done","time":"2024-01-01T00:00:01Z","product_id":"BTC-USD","sequence":11,"order_id":"b1","reason":"canceled","side":"buy"}"#,
+        ));
+        let [
+            Ok(MarketEvent {
+                kind: OrderBookEvent::Update(update),
+                ..
+            }),
+        ] = output.as_slice()
+        else {
+            panic!("expected a single OrderBookEvent::Update, found: {output:?}");
+        };
+        assert_eq!(update.sequence(), 11);
+        assert_eq!(update.bids().levels(), &[Level::new(dec!(100), dec!(2))]);
+
+        let book = transformer.book(&subscription_id).unwrap();
+        assert_eq!(book.best_bid(), Some(Level::new(dec!(100), dec!(2))));
+        assert!(book.order("b1").is_none());
+    }
+}
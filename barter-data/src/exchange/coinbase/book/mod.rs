@@ -0,0 +1,2 @@
+/// Level 3 OrderBook types (per-order) maintained from the Coinbase `full` channel.
+pub mod l3;
@@ -1,24 +1,22 @@
 use self::{
-    channel::CoinbaseChannel, market::CoinbaseMarket, subscription::CoinbaseSubResponse,
-    trade::CoinbaseTrade,
+    book::l3::CoinbaseOrderBooksL3Transformer, channel::CoinbaseChannel, market::CoinbaseMarket,
+    subscription::CoinbaseSubResponse, trade::CoinbaseTrade,
 };
 use crate::{
     ExchangeWsStream, NoInitialSnapshots,
     exchange::{Connector, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
-    subscriber::{WebSocketSubscriber, validator::WebSocketSubValidator},
-    subscription::trade::PublicTrades,
+    subscription::{book::OrderBooksL3, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
 };
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::{
-    error::SocketError,
-    protocol::websocket::{WebSocketSerdeParser, WsMessage},
-};
-use barter_macro::{DeExchange, SerExchange};
+use barter_integration::protocol::websocket::{WebSocketSerdeParser, WsMessage};
+use barter_macro::{Connector, DeExchange, SerExchange};
 use derive_more::Display;
 use serde_json::json;
-use url::Url;
+
+/// OrderBook types for [`Coinbase`].
+pub mod book;
 
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
@@ -59,36 +57,34 @@ pub type CoinbaseWsStream<Transformer> = ExchangeWsStream<WebSocketSerdeParser,
     Display,
     DeExchange,
     SerExchange,
+    Connector,
+)]
+#[connector(
+    exchange = ExchangeId::Coinbase,
+    url = BASE_URL_COINBASE,
+    channel = CoinbaseChannel,
+    market = CoinbaseMarket,
+    sub_response = CoinbaseSubResponse,
+    requests = requests,
 )]
+#[exchange(alias = "coinbase_pro", alias = "gdax")]
 pub struct Coinbase;
 
-impl Connector for Coinbase {
-    const ID: ExchangeId = ExchangeId::Coinbase;
-    type Channel = CoinbaseChannel;
-    type Market = CoinbaseMarket;
-    type Subscriber = WebSocketSubscriber;
-    type SubValidator = WebSocketSubValidator;
-    type SubResponse = CoinbaseSubResponse;
-
-    fn url() -> Result<Url, SocketError> {
-        Url::parse(BASE_URL_COINBASE).map_err(SocketError::UrlParse)
-    }
-
-    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        exchange_subs
-            .into_iter()
-            .map(|ExchangeSub { channel, market }| {
-                WsMessage::text(
-                    json!({
-                        "type": "subscribe",
-                        "product_ids": [market.as_ref()],
-                        "channels": [channel.as_ref()],
-                    })
-                    .to_string(),
-                )
-            })
-            .collect()
-    }
+/// Translate each [`ExchangeSub`] into a distinct [`Coinbase`] subscription [`WsMessage`].
+fn requests(exchange_subs: Vec<ExchangeSub<CoinbaseChannel, CoinbaseMarket>>) -> Vec<WsMessage> {
+    exchange_subs
+        .into_iter()
+        .map(|ExchangeSub { channel, market }| {
+            WsMessage::text(
+                json!({
+                    "type": "subscribe",
+                    "product_ids": [market.as_ref()],
+                    "channels": [channel.as_ref()],
+                })
+                .to_string(),
+            )
+        })
+        .collect()
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Coinbase
@@ -99,3 +95,12 @@ where
     type Stream =
         CoinbaseWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, CoinbaseTrade>>;
 }
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL3> for Coinbase
+where
+    Instrument: InstrumentData,
+{
+    // Per-order snapshot is fetched by the CoinbaseOrderBooksL3Transformer itself
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = CoinbaseWsStream<CoinbaseOrderBooksL3Transformer<Instrument::Key>>;
+}
@@ -8,6 +8,7 @@ use crate::{
 use barter_instrument::{Side, exchange::ExchangeId};
 use barter_integration::subscription::SubscriptionId;
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Coinbase real-time trade WebSocket message.
@@ -37,9 +38,9 @@ pub struct CoinbaseTrade {
     pub id: u64,
     pub time: DateTime<Utc>,
     #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Decimal,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Decimal,
     pub side: Side,
 }
 
@@ -56,6 +57,8 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, CoinbaseTrade)>
         Self(vec![Ok(MarketEvent {
             time_exchange: trade.time,
             time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
             exchange: exchange_id,
             instrument,
             kind: PublicTrade {
@@ -83,6 +86,7 @@ mod tests {
     use super::*;
     use barter_integration::error::SocketError;
     use chrono::NaiveDateTime;
+    use rust_decimal_macros::dec;
     use serde::de::Error;
     use std::str::FromStr;
 
@@ -115,8 +119,8 @@ mod tests {
                 expected: Ok(CoinbaseTrade {
                     subscription_id: SubscriptionId::from("matches|BTC-USD"),
                     id: 10,
-                    price: 400.23,
-                    amount: 5.23512,
+                    price: dec!(400.23),
+                    amount: dec!(5.23512),
                     side: Side::Sell,
                     time: NaiveDateTime::from_str("2014-11-07T08:19:27.028459")
                         .unwrap()
@@ -8,6 +8,7 @@ use crate::{
 use barter_instrument::{Side, exchange::ExchangeId};
 use barter_integration::subscription::SubscriptionId;
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Terse type alias for a `GateioFuturesUsdt`, `GateioFuturesBtc`, `GateioPerpetualUsdt` and
@@ -43,6 +44,22 @@ pub type GateioFuturesTrades = GateioMessage<Vec<GateioFuturesTradeInner>>;
 ///   "contract": "BTC_USD"
 /// }
 /// ```
+///
+/// #### Option Buy Trade
+/// See docs: <https://www.gate.io/docs/developers/options/ws/en/#public-contract-trades-channel>
+/// ```json
+/// {
+///   "contract": "BTC_USDT-20211130-65000-C",
+///   "create_time": 1639144526,
+///   "id": 12279,
+///   "price": 997.5,
+///   "size": 100,
+///   "create_time_ms": 1639144526597,
+///   "underlying": "BTC_USDT"
+/// }
+/// ```
+///
+/// Note that options trades send the price as a number rather than a string.
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct GateioFuturesTradeInner {
     #[serde(rename = "contract")]
@@ -53,10 +70,10 @@ pub struct GateioFuturesTradeInner {
     )]
     pub time: DateTime<Utc>,
     pub id: u64,
-    #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    #[serde(deserialize_with = "barter_integration::de::de_decimal_flexible")]
+    pub price: Decimal,
     #[serde(rename = "size")]
-    pub amount: f64,
+    pub amount: Decimal,
 }
 
 impl Identifier<Option<SubscriptionId>> for GateioFuturesTrades {
@@ -80,6 +97,8 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, GateioFuturesTrades)
                 Ok(MarketEvent {
                     time_exchange: trade.time,
                     time_received: Utc::now(),
+                    time_processed: None,
+                    sequence: None,
                     exchange,
                     instrument: instrument.clone(),
                     kind: PublicTrade {
@@ -126,11 +145,45 @@ mod tests {
                   "create_time_ms": 1545136464123,
                   "price": "96.4",
                   "contract": "ETH_USDT_QUARTERLY_20201225"
+                },
+                {
+                  "size": -108,
+                  "id": 27753480,
+                  "create_time": 1545136464,
+                  "create_time_ms": 1545136464123,
+                  "price": 96.4,
+                  "contract": "ETH_USDT_QUARTERLY_20201225"
                 }
               ]
             }"#;
 
-            serde_json::from_str::<GateioFuturesTrades>(input).unwrap();
+            let trades = serde_json::from_str::<GateioFuturesTrades>(input).unwrap();
+            assert_eq!(trades.data[0].price, Decimal::new(964, 1));
+            assert_eq!(trades.data[1].price, Decimal::new(964, 1));
+        }
+
+        #[test]
+        fn test_gateio_message_options_trade() {
+            let input = r#"
+            {
+              "time": 1630576356,
+              "channel": "options.trades",
+              "event": "update",
+              "result": [
+                {
+                  "contract": "BTC_USDT-20211130-65000-C",
+                  "create_time": 1639144526,
+                  "id": 12279,
+                  "price": 997.5,
+                  "size": 100,
+                  "create_time_ms": 1639144526597,
+                  "underlying": "BTC_USDT"
+                }
+              ]
+            }"#;
+
+            let trades = serde_json::from_str::<GateioFuturesTrades>(input).unwrap();
+            assert_eq!(trades.data[0].price, Decimal::new(9975, 1));
         }
     }
 }
@@ -4,7 +4,7 @@ use crate::{
     books::Level,
     event::{MarketEvent, MarketIter},
     exchange::{kraken::channel::KrakenChannel, subscription::ExchangeSub},
-    subscription::book::OrderBookL1,
+    subscription::book::{Bbo, OrderBookL1},
 };
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{de::extract_next, subscription::SubscriptionId};
@@ -53,6 +53,14 @@ impl Identifier<Option<SubscriptionId>> for KrakenOrderBookL1Inner {
     }
 }
 
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, KrakenOrderBookL1)>
+    for MarketIter<InstrumentKey, Bbo>
+{
+    fn from(message: (ExchangeId, InstrumentKey, KrakenOrderBookL1)) -> Self {
+        MarketIter::<InstrumentKey, OrderBookL1>::from(message).into()
+    }
+}
+
 impl<InstrumentKey> From<(ExchangeId, InstrumentKey, KrakenOrderBookL1)>
     for MarketIter<InstrumentKey, OrderBookL1>
 {
@@ -82,6 +90,8 @@ impl<InstrumentKey> From<(ExchangeId, InstrumentKey, KrakenOrderBookL1)>
                 Self(vec![Ok(MarketEvent {
                     time_exchange: book.spread.time,
                     time_received: Utc::now(),
+                    time_processed: None,
+                    sequence: None,
                     exchange: exchange_id,
                     instrument,
                     kind: OrderBookL1 {
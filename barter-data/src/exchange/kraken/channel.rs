@@ -1,7 +1,11 @@
 use super::Kraken;
 use crate::{
     Identifier,
-    subscription::{Subscription, book::OrderBooksL1, trade::PublicTrades},
+    subscription::{
+        Subscription,
+        book::{BestBidOffer, OrderBooksL1},
+        trade::PublicTrades,
+    },
 };
 use serde::Serialize;
 
@@ -36,6 +40,13 @@ impl<Instrument> Identifier<KrakenChannel> for Subscription<Kraken, Instrument,
     }
 }
 
+/// Kraken `spread` is the lightest best-bid-offer channel, so [`BestBidOffer`] is derived from L1.
+impl<Instrument> Identifier<KrakenChannel> for Subscription<Kraken, Instrument, BestBidOffer> {
+    fn id(&self) -> KrakenChannel {
+        KrakenChannel::ORDER_BOOK_L1
+    }
+}
+
 impl AsRef<str> for KrakenChannel {
     fn as_ref(&self) -> &str {
         self.0
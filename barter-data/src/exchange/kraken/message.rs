@@ -61,6 +61,17 @@ use serde::{Deserialize, Serialize};
 /// }
 /// ```
 ///
+/// #### SystemStatus
+/// See docs: <https://docs.kraken.com/websockets/#message-systemStatus>
+/// ```json
+/// {
+///   "connectionID": 8628615390848610000,
+///   "event": "systemStatus",
+///   "status": "maintenance",
+///   "version": "1.0.0"
+/// }
+/// ```
+///
 /// #### KrakenError Generic
 /// See docs: <https://docs.kraken.com/websockets/#errortypes>
 /// ```json
@@ -100,9 +111,42 @@ where
 #[serde(tag = "event", rename_all = "camelCase")]
 pub enum KrakenEvent {
     Heartbeat,
+    SystemStatus(KrakenSystemStatus),
     Error(KrakenError),
 }
 
+/// [`Kraken`](super::Kraken) system status message, sent on connection and whenever the system
+/// status changes.
+///
+/// See [`KrakenMessage`] for full raw payload examples.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-systemStatus>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KrakenSystemStatus {
+    pub status: KrakenStatus,
+}
+
+impl KrakenSystemStatus {
+    /// Determine if the [`Kraken`](super::Kraken) system is undergoing maintenance, in which
+    /// case the WebSocket stays connected but all order requests are rejected.
+    pub fn is_maintenance(&self) -> bool {
+        self.status == KrakenStatus::Maintenance
+    }
+}
+
+/// [`Kraken`](super::Kraken) system status.
+///
+/// See docs: <https://docs.kraken.com/websockets/#message-systemStatus>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KrakenStatus {
+    Online,
+    Maintenance,
+    CancelOnly,
+    LimitOnly,
+    PostOnly,
+}
+
 /// [`Kraken`](super::Kraken) generic error message String received over the WebSocket.
 ///
 /// Note that since the [`KrakenError`] is only made up of a renamed message String field, it can
@@ -147,6 +191,15 @@ mod tests {
                         message: "Malformed request".to_string(),
                     }))),
                 },
+                TestCase {
+                    // TC2: valid KrakenTrades::Event(KrakenEvent::SystemStatus) w/ maintenance
+                    input: r#"{"connectionID":8628615390848610000,"event":"systemStatus","status":"maintenance","version":"1.0.0"}"#,
+                    expected: Ok(KrakenMessage::Event(KrakenEvent::SystemStatus(
+                        KrakenSystemStatus {
+                            status: KrakenStatus::Maintenance,
+                        },
+                    ))),
+                },
             ];
 
             for (index, test) in tests.into_iter().enumerate() {
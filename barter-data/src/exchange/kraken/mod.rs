@@ -1,14 +1,17 @@
 use self::{
-    book::l1::KrakenOrderBookL1, channel::KrakenChannel, market::KrakenMarket,
-    message::KrakenMessage, subscription::KrakenSubResponse, trade::KrakenTrades,
+    book::l1::KrakenOrderBookL1Inner, channel::KrakenChannel, market::KrakenMarket,
+    message::KrakenMessage, subscription::KrakenSubResponse, trade::KrakenTradesInner,
+    transformer::KrakenTransformer,
 };
 use crate::{
     ExchangeWsStream, NoInitialSnapshots,
     exchange::{Connector, ExchangeSub, StreamSelector},
     instrument::InstrumentData,
     subscriber::{WebSocketSubscriber, validator::WebSocketSubValidator},
-    subscription::{book::OrderBooksL1, trade::PublicTrades},
-    transformer::stateless::StatelessTransformer,
+    subscription::{
+        book::{BestBidOffer, OrderBooksL1},
+        trade::PublicTrades,
+    },
 };
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{
@@ -41,6 +44,11 @@ pub mod subscription;
 /// Public trade types for [`Kraken`].
 pub mod trade;
 
+/// [`ExchangeTransformer`](crate::transformer::ExchangeTransformer) for [`Kraken`] that
+/// translates maintenance system status messages into a
+/// [`DataError::Maintenance`](crate::error::DataError::Maintenance).
+pub mod transformer;
+
 /// [`Kraken`] server base url.
 ///
 /// See docs: <https://docs.kraken.com/websockets/#overview>
@@ -105,7 +113,7 @@ where
 {
     type SnapFetcher = NoInitialSnapshots;
     type Stream =
-        KrakenWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, KrakenTrades>>;
+        KrakenWsStream<KrakenTransformer<Instrument::Key, PublicTrades, KrakenTradesInner>>;
 }
 
 impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for Kraken
@@ -113,7 +121,15 @@ where
     Instrument: InstrumentData,
 {
     type SnapFetcher = NoInitialSnapshots;
-    type Stream = KrakenWsStream<
-        StatelessTransformer<Self, Instrument::Key, OrderBooksL1, KrakenOrderBookL1>,
-    >;
+    type Stream =
+        KrakenWsStream<KrakenTransformer<Instrument::Key, OrderBooksL1, KrakenOrderBookL1Inner>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, BestBidOffer> for Kraken
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        KrakenWsStream<KrakenTransformer<Instrument::Key, BestBidOffer, KrakenOrderBookL1Inner>>;
 }
@@ -0,0 +1,129 @@
+use super::{
+    Kraken,
+    message::{KrakenEvent, KrakenMessage},
+};
+use crate::{
+    Identifier,
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::Connector,
+    subscription::{Map, SubscriptionKind},
+    transformer::{ExchangeTransformer, stateless::StatelessTransformer},
+};
+use async_trait::async_trait;
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{
+    Transformer, protocol::websocket::WsMessage, subscription::SubscriptionId,
+};
+use serde::Deserialize;
+use tokio::sync::mpsc;
+
+/// [`Kraken`] [`ExchangeTransformer`] that translates [`KrakenMessage`]s into normalised Barter
+/// [`MarketEvent`]s using a [`StatelessTransformer`].
+///
+/// A [`KrakenEvent::SystemStatus`] indicating the system is undergoing maintenance is translated
+/// into a [`DataError::Maintenance`], since [`Kraken`] keeps the WebSocket connected during
+/// maintenance but rejects all order requests.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct KrakenTransformer<InstrumentKey, Kind, Data> {
+    inner: StatelessTransformer<Kraken, InstrumentKey, Kind, KrakenMessage<Data>>,
+}
+
+#[async_trait]
+impl<InstrumentKey, Kind, Data> ExchangeTransformer<Kraken, InstrumentKey, Kind>
+    for KrakenTransformer<InstrumentKey, Kind, Data>
+where
+    InstrumentKey: Clone + Send,
+    Kind: SubscriptionKind + Send,
+    Data: Send,
+    KrakenMessage<Data>: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    MarketIter<InstrumentKey, Kind::Event>: From<(ExchangeId, InstrumentKey, KrakenMessage<Data>)>,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        _: &[MarketEvent<InstrumentKey, Kind::Event>],
+        ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        StatelessTransformer::init(instrument_map, &[], ws_sink_tx)
+            .await
+            .map(|inner| Self { inner })
+    }
+}
+
+impl<InstrumentKey, Kind, Data> Transformer for KrakenTransformer<InstrumentKey, Kind, Data>
+where
+    InstrumentKey: Clone,
+    Kind: SubscriptionKind,
+    KrakenMessage<Data>: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    MarketIter<InstrumentKey, Kind::Event>: From<(ExchangeId, InstrumentKey, KrakenMessage<Data>)>,
+{
+    type Error = DataError;
+    type Input = KrakenMessage<Data>;
+    type Output = MarketEvent<InstrumentKey, Kind::Event>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        match input {
+            KrakenMessage::Event(KrakenEvent::SystemStatus(status)) if status.is_maintenance() => {
+                vec![Err(DataError::Maintenance {
+                    exchange: Kraken::ID,
+                    message: "received systemStatus maintenance".to_string(),
+                })]
+            }
+            input => self.inner.transform(input),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::kraken::trade::KrakenTradesInner, subscription::trade::PublicTrades};
+    use barter_instrument::Side;
+    use rust_decimal_macros::dec;
+
+    #[tokio::test]
+    async fn test_kraken_transformer_maintenance() {
+        let mut transformer = KrakenTransformer::<&str, PublicTrades, KrakenTradesInner>::init(
+            Map::from_iter([(SubscriptionId::from("trade|XBT/USD"), "xbt_usd")]),
+            &[],
+            mpsc::unbounded_channel().0,
+        )
+        .await
+        .unwrap();
+
+        let parse = |input: &str| serde_json::from_str::<KrakenMessage<KrakenTradesInner>>(input);
+
+        // Maintenance systemStatus frame is translated into a DataError::Maintenance
+        let maintenance = parse(
+            r#"{"connectionID":8628615390848610000,"event":"systemStatus","status":"maintenance","version":"1.0.0"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            transformer.transform(maintenance),
+            vec![Err(DataError::Maintenance {
+                exchange: ExchangeId::Kraken,
+                message: "received systemStatus maintenance".to_string(),
+            })]
+        );
+
+        // Online systemStatus frame is ignored
+        let online = parse(
+            r#"{"connectionID":8628615390848610000,"event":"systemStatus","status":"online","version":"1.0.0"}"#,
+        )
+        .unwrap();
+        assert!(transformer.transform(online).is_empty());
+
+        // Trades are still transformed as normal
+        let trades = parse(
+            r#"[0,[["5541.20000","0.15850568","1534614057.321597","s","l",""]],"trade","XBT/USD"]"#,
+        )
+        .unwrap();
+        let output = transformer.transform(trades);
+        assert_eq!(output.len(), 1);
+        let trade = &output[0].as_ref().unwrap().kind;
+        assert_eq!(trade.price, dec!(5541.20000));
+        assert_eq!(trade.amount, dec!(0.15850568));
+        assert_eq!(trade.side, Side::Sell);
+    }
+}
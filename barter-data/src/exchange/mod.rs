@@ -5,10 +5,10 @@ use crate::{
     subscriber::{Subscriber, validator::SubscriptionValidator},
     subscription::{Map, SubscriptionKind},
 };
-use barter_instrument::exchange::ExchangeId;
+use barter_instrument::{exchange::ExchangeId, instrument::name::InstrumentNameExchange};
 use barter_integration::{Validator, error::SocketError, protocol::websocket::WsMessage};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
-use std::{fmt::Debug, time::Duration};
+use std::{fmt::Debug, future::Future, time::Duration};
 use url::Url;
 
 /// `BinanceSpot` & `BinanceFuturesUsd` [`Connector`] and [`StreamSelector`] implementations.
@@ -117,8 +117,20 @@ where
         None
     }
 
+    /// Maximum number of `Subscription`s the exchange server accepts in a single subscription
+    /// batch. Larger batches may be silently dropped by the exchange server.
+    ///
+    /// The [`SubscriptionMapper`](crate::subscriber::mapper::SubscriptionMapper) chunks
+    /// `Subscription`s into batches of this size, and sends the [`Self::requests`] of every batch
+    /// over the same [`WebSocket`](barter_integration::protocol::websocket::WebSocket).
+    ///
+    /// Defaults to `usize::MAX`, meaning all `Subscription`s are sent in a single batch.
+    const SUBSCRIPTION_BATCH_LIMIT: usize = usize::MAX;
+
     /// Defines how to translate a collection of [`ExchangeSub`]s into the [`WsMessage`]
     /// subscription payloads sent to the exchange server.
+    ///
+    /// Called once per batch of at most [`Self::SUBSCRIPTION_BATCH_LIMIT`] [`ExchangeSub`]s.
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage>;
 
     /// Number of `Subscription` responses expected from the
@@ -128,11 +140,32 @@ where
         map.0.len()
     }
 
+    /// Number of subscription batches the `Subscription`s in the provided [`Map`] are chunked
+    /// into, given the [`Self::SUBSCRIPTION_BATCH_LIMIT`].
+    ///
+    /// Useful for exchange servers that send a single response per subscription batch.
+    fn subscription_batches<InstrumentKey>(map: &Map<InstrumentKey>) -> usize {
+        map.0.len().div_ceil(Self::SUBSCRIPTION_BATCH_LIMIT.max(1))
+    }
+
     /// Expected [`Duration`] the [`SubscriptionValidator`] will wait to receive all success
     /// responses to actioned `Subscription` requests.
     fn subscription_timeout() -> Duration {
         DEFAULT_SUBSCRIPTION_TIMEOUT
     }
+
+    /// Fetch the [`InstrumentNameExchange`]s currently available to trade on the exchange server.
+    ///
+    /// Useful for discovering the markets that can be subscribed to, rather than hard-coding them.
+    ///
+    /// Defaults to a [`SocketError::Unsupported`] for exchanges that do not implement it.
+    fn fetch_instruments()
+    -> impl Future<Output = Result<Vec<InstrumentNameExchange>, SocketError>> + Send {
+        std::future::ready(Err(SocketError::Unsupported {
+            entity: Self::ID.to_string(),
+            item: "fetch_instruments".to_string(),
+        }))
+    }
 }
 
 /// Used when an exchange has servers different
@@ -5,20 +5,15 @@ use crate::{
     ExchangeWsStream, NoInitialSnapshots,
     exchange::{Connector, ExchangeSub, PingInterval, StreamSelector},
     instrument::InstrumentData,
-    subscriber::{WebSocketSubscriber, validator::WebSocketSubValidator},
     subscription::trade::PublicTrades,
     transformer::stateless::StatelessTransformer,
 };
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::{
-    error::SocketError,
-    protocol::websocket::{WebSocketSerdeParser, WsMessage},
-};
-use barter_macro::{DeExchange, SerExchange};
+use barter_integration::protocol::websocket::{WebSocketSerdeParser, WsMessage};
+use barter_macro::{Connector, DeExchange, SerExchange};
 use derive_more::Display;
 use serde_json::json;
 use std::time::Duration;
-use url::Url;
 
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
@@ -45,6 +40,13 @@ pub const BASE_URL_OKX: &str = "wss://ws.okx.com:8443/ws/v5/public";
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-connect>
 pub const PING_INTERVAL_OKX: Duration = Duration::from_secs(29);
 
+/// Maximum number of args sent in a single [`Okx`] subscribe request.
+///
+/// Conservative batch size that keeps subscribe payloads within the 64 KB request length limit.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-subscribe>
+pub const SUBSCRIPTION_BATCH_LIMIT_OKX: usize = 100;
+
 /// Convenient type alias for an Okx [`ExchangeWsStream`] using [`WebSocketSerdeParser`](barter_integration::protocol::websocket::WebSocketSerdeParser).
 pub type OkxWsStream<Transformer> = ExchangeWsStream<WebSocketSerdeParser, Transformer>;
 
@@ -64,37 +66,38 @@ pub type OkxWsStream<Transformer> = ExchangeWsStream<WebSocketSerdeParser, Trans
     Display,
     DeExchange,
     SerExchange,
+    Connector,
 )]
+#[connector(
+    exchange = ExchangeId::Okx,
+    url = BASE_URL_OKX,
+    channel = OkxChannel,
+    market = OkxMarket,
+    sub_response = OkxSubResponse,
+    requests = requests,
+    ping_interval = ping_interval,
+    subscription_batch_limit = SUBSCRIPTION_BATCH_LIMIT_OKX,
+)]
+#[exchange(alias = "okex")]
 pub struct Okx;
 
-impl Connector for Okx {
-    const ID: ExchangeId = ExchangeId::Okx;
-    type Channel = OkxChannel;
-    type Market = OkxMarket;
-    type Subscriber = WebSocketSubscriber;
-    type SubValidator = WebSocketSubValidator;
-    type SubResponse = OkxSubResponse;
-
-    fn url() -> Result<Url, SocketError> {
-        Url::parse(BASE_URL_OKX).map_err(SocketError::UrlParse)
-    }
+/// Custom [`Okx`] application-level [`PingInterval`].
+fn ping_interval() -> Option<PingInterval> {
+    Some(PingInterval {
+        interval: tokio::time::interval(PING_INTERVAL_OKX),
+        ping: || WsMessage::text("ping"),
+    })
+}
 
-    fn ping_interval() -> Option<PingInterval> {
-        Some(PingInterval {
-            interval: tokio::time::interval(PING_INTERVAL_OKX),
-            ping: || WsMessage::text("ping"),
+/// Translate a batch of [`ExchangeSub`]s into a single [`Okx`] subscription [`WsMessage`].
+fn requests(exchange_subs: Vec<ExchangeSub<OkxChannel, OkxMarket>>) -> Vec<WsMessage> {
+    vec![WsMessage::text(
+        json!({
+            "op": "subscribe",
+            "args": &exchange_subs,
         })
-    }
-
-    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        vec![WsMessage::text(
-            json!({
-                "op": "subscribe",
-                "args": &exchange_subs,
-            })
-            .to_string(),
-        )]
-    }
+        .to_string(),
+    )]
 }
 
 impl<Instrument> StreamSelector<Instrument, PublicTrades> for Okx
@@ -7,6 +7,7 @@ use crate::{
 use barter_instrument::{Side, exchange::ExchangeId};
 use barter_integration::subscription::SubscriptionId;
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Terse type alias for an [`Okx`](super::Okx) real-time trades WebSocket message.
@@ -81,9 +82,9 @@ pub struct OkxTrade {
     #[serde(rename = "tradeId")]
     pub id: String,
     #[serde(rename = "px", deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Decimal,
     pub side: Side,
     #[serde(
         rename = "ts",
@@ -103,6 +104,8 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, OkxTrades)>
                 Ok(MarketEvent {
                     time_exchange: trade.time,
                     time_received: Utc::now(),
+                    time_processed: None,
+                    sequence: None,
                     exchange,
                     instrument: instrument.clone(),
                     kind: PublicTrade {
@@ -138,6 +141,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -170,8 +174,8 @@ mod tests {
                 subscription_id: SubscriptionId::from("trades|BTC-USDT"),
                 data: vec![OkxTrade {
                     id: "130639474".to_string(),
-                    price: 42219.9,
-                    amount: 0.12060306,
+                    price: dec!(42219.9),
+                    amount: dec!(0.12060306),
                     side: Side::Buy,
                     time: datetime_utc_from_epoch_duration(Duration::from_millis(1630048897897)),
                 }],
@@ -1,6 +1,7 @@
 use crate::{Identifier, subscription::Subscription};
 use barter_integration::subscription::SubscriptionId;
 use serde::Deserialize;
+use smol_str::format_smolstr;
 
 /// Defines an exchange specific market and channel combination used by an exchange
 /// [`Connector`](super::Connector) to build the
@@ -47,7 +48,9 @@ where
     Market: AsRef<str>,
 {
     fn id(&self) -> SubscriptionId {
-        SubscriptionId::from(format!(
+        // Hot path: called when deserialising most exchange messages, so format straight into a
+        // SmolStr to avoid a heap allocation for ids up to 23 bytes
+        SubscriptionId(format_smolstr!(
             "{}|{}",
             self.channel.as_ref(),
             self.market.as_ref()
@@ -96,6 +96,13 @@
 #[allow(unused_extern_crates)]
 use prost as _;
 
+// criterion 仅在基准测试中使用
+#[cfg(test)]
+use criterion as _;
+
+// 允许 barter-macro 生成的代码（例如 `#[derive(Connector)]`）在本 crate 内通过 `barter_data::` 路径引用
+extern crate self as barter_data;
+
 use crate::{
     error::DataError,
     event::MarketEvent,
@@ -114,7 +121,7 @@ use barter_integration::{
         StreamParser,
         websocket::{WsError, WsMessage, WsSink, WsStream},
     },
-    stream::ExchangeStream,
+    stream::{ExchangeStream, stats::StreamStats},
 };
 use futures::{SinkExt, Stream, StreamExt};
 
@@ -225,6 +232,37 @@ where
         SnapFetcher: SnapshotFetcher<Exchange, Kind>,
         Subscription<Exchange, Instrument, Kind>:
             Identifier<Exchange::Channel> + Identifier<Exchange::Market>;
+
+    /// 使用 [`MarketStreamConfig`] 从订阅列表初始化市场流。
+    ///
+    /// 默认实现忽略配置并委托给 [`init`](MarketStream::init)，因此现有实现无需修改。
+    ///
+    /// # 参数
+    ///
+    /// - `subscriptions`: 订阅列表
+    /// - `config`: 应用于市场流的运行时配置
+    async fn init_with_config<SnapFetcher>(
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+        config: MarketStreamConfig,
+    ) -> Result<Self, DataError>
+    where
+        SnapFetcher: SnapshotFetcher<Exchange, Kind>,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Sync,
+    {
+        let _ = config;
+        Self::init::<SnapFetcher>(subscriptions).await
+    }
+}
+
+/// 初始化 [`MarketStream`] 时应用的运行时配置。
+#[derive(Debug, Clone, Default)]
+pub struct MarketStreamConfig {
+    /// 在接收消息时更新的 [`StreamStats`] 句柄。
+    ///
+    /// 在同一逻辑流的多次重连之间共享同一句柄，可累积该流整个生命周期的运行时统计
+    /// （消息数、最后消息时间、解析错误数、重连次数）。
+    pub stats: StreamStats,
 }
 
 /// 定义如何为 [`Subscription`] 集合获取市场数据快照。
@@ -285,6 +323,18 @@ where
     async fn init<SnapFetcher>(
         subscriptions: &[Subscription<Exchange, Instrument, Kind>],
     ) -> Result<Self, DataError>
+    where
+        SnapFetcher: SnapshotFetcher<Exchange, Kind>,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        Self::init_with_config::<SnapFetcher>(subscriptions, MarketStreamConfig::default()).await
+    }
+
+    async fn init_with_config<SnapFetcher>(
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+        config: MarketStreamConfig,
+    ) -> Result<Self, DataError>
     where
         SnapFetcher: SnapshotFetcher<Exchange, Kind>,
         Subscription<Exchange, Instrument, Kind>:
@@ -324,16 +374,23 @@ where
         let mut transformer =
             Transformer::init(instrument_map, &initial_snapshots, ws_sink_tx).await?;
 
+        // Transformer 初始化期间生成的事件（例如其自行获取的快照）先于任何其他事件发出
+        let mut processed = transformer
+            .take_init_events()
+            .into_iter()
+            .map(Ok)
+            .collect::<VecDeque<_>>();
+
         // 处理在订阅验证期间接收到的任何缓冲的活动订阅事件
-        let mut processed = process_buffered_events::<Parser, Transformer>(
+        processed.extend(process_buffered_events::<Parser, Transformer>(
             &mut transformer,
             buffered_websocket_events,
-        );
+        ));
 
         // 使用任何初始快照事件扩展缓冲事件
         processed.extend(initial_snapshots.into_iter().map(Ok));
 
-        Ok(ExchangeWsStream::new(ws_stream, transformer, processed))
+        Ok(ExchangeWsStream::new(ws_stream, transformer, processed).with_stats(config.stats))
     }
 }
 
@@ -391,6 +448,10 @@ where
 /// ExchangeTransformer 在同步 Trait 上下文中运行，因此我们使用此单独任务
 /// 来避免向 transformer 添加 `#[async_trait]` - 这避免了分配。
 ///
+/// 该队列仅承载订阅、ping 和 pong 等控制消息，因此始终无界，永远不会因背压而丢弃控制消息。
+/// 市场数据的背压应用于消费者通道，参见
+/// [`StreamBuilder::with_backpressure`](streams::builder::StreamBuilder::with_backpressure)。
+///
 /// # 参数
 ///
 /// - `exchange`: 交易所标识
@@ -454,17 +515,20 @@ pub mod test_utils {
     };
     use barter_instrument::{Side, exchange::ExchangeId};
     use chrono::{DateTime, Utc};
+    use rust_decimal::Decimal;
 
     pub fn market_event_trade_buy<InstrumentKey>(
         time_exchange: DateTime<Utc>,
         time_received: DateTime<Utc>,
         instrument: InstrumentKey,
-        price: f64,
-        quantity: f64,
+        price: Decimal,
+        quantity: Decimal,
     ) -> MarketEvent<InstrumentKey, DataKind> {
         MarketEvent {
             time_exchange,
             time_received,
+            time_processed: None,
+            sequence: None,
             exchange: ExchangeId::BinanceSpot,
             instrument,
             kind: DataKind::Trade(PublicTrade {
@@ -0,0 +1,125 @@
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::{BroadcastStream, errors::BroadcastStreamRecvError};
+use tracing::{debug, warn};
+
+/// Event yielded to each [`MarketStreamBroadcast`] subscriber.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum BroadcastEvent<T> {
+    /// Market event forwarded from the source `Stream`.
+    Item(T),
+
+    /// Subscriber fell behind and the contained number of the oldest events were dropped.
+    Lagged(u64),
+}
+
+/// Fans a single market event [`Stream`] out to multiple independent consumers (eg/ a strategy,
+/// a logger, a UI) via a [`tokio::sync::broadcast`] channel, without re-subscribing to the
+/// exchange per consumer.
+///
+/// ## Lag / Backpressure Policy
+/// The source `Stream` is never blocked by slow subscribers. Each subscriber has a bounded
+/// buffer of `capacity` events - if a subscriber falls further behind, the oldest events are
+/// dropped (drop-oldest) and the subscriber observes a [`BroadcastEvent::Lagged`] with the
+/// number of skipped events before resuming from the oldest retained event.
+///
+/// Events forwarded while there are no active subscribers are discarded. Subscriber `Stream`s
+/// end once the source `Stream` ends.
+#[derive(Debug)]
+pub struct MarketStreamBroadcast<T> {
+    tx: broadcast::WeakSender<T>,
+}
+
+impl<T> MarketStreamBroadcast<T>
+where
+    T: Clone + Send + 'static,
+{
+    /// Spawn a task forwarding every event of the source [`Stream`] into a
+    /// [`tokio::sync::broadcast`] channel with the provided per-subscriber `capacity`.
+    ///
+    /// Must be called from within a `tokio` runtime.
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn new<St>(stream: St, capacity: usize) -> Self
+    where
+        St: Stream<Item = T> + Send + 'static,
+    {
+        let (tx, _) = broadcast::channel(capacity);
+        let weak_tx = tx.downgrade();
+
+        tokio::spawn(async move {
+            let mut stream = std::pin::pin!(stream);
+            while let Some(event) = stream.next().await {
+                if tx.send(event).is_err() {
+                    debug!("MarketStreamBroadcast has no active subscribers, discarding event");
+                }
+            }
+            debug!("MarketStreamBroadcast source Stream ended");
+        });
+
+        Self { tx: weak_tx }
+    }
+
+    /// Subscribe a new consumer, receiving all events forwarded after this call.
+    ///
+    /// Returns `None` if the source `Stream` has already ended.
+    pub fn subscribe(&self) -> Option<impl Stream<Item = BroadcastEvent<T>> + use<T>> {
+        let rx = self.tx.upgrade()?.subscribe();
+
+        Some(BroadcastStream::new(rx).map(|result| match result {
+            Ok(event) => BroadcastEvent::Item(event),
+            Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                warn!(
+                    skipped,
+                    "MarketStreamBroadcast subscriber lagged, dropped oldest events"
+                );
+                BroadcastEvent::Lagged(skipped)
+            }
+        }))
+    }
+
+    /// Number of active subscribers.
+    pub fn receiver_count(&self) -> usize {
+        self.tx.upgrade().map_or(0, |tx| tx.receiver_count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_stream::wrappers::UnboundedReceiverStream;
+
+    #[tokio::test]
+    async fn test_market_stream_broadcast_fan_out_and_lag() {
+        let (source_tx, source_rx) = tokio::sync::mpsc::unbounded_channel();
+        let broadcast = MarketStreamBroadcast::new(UnboundedReceiverStream::new(source_rx), 2);
+
+        let mut fast = broadcast.subscribe().unwrap();
+        let slow = broadcast.subscribe().unwrap();
+        assert_eq!(broadcast.receiver_count(), 2);
+
+        // Fast subscriber keeps up with every event
+        for event in 0..5 {
+            source_tx.send(event).unwrap();
+            assert_eq!(fast.next().await, Some(BroadcastEvent::Item(event)));
+        }
+
+        // Source Stream ends
+        drop(source_tx);
+        assert_eq!(fast.next().await, None);
+
+        // Slow subscriber observes the dropped-oldest lag signal, then the retained events
+        assert_eq!(
+            slow.collect::<Vec<_>>().await,
+            vec![
+                BroadcastEvent::Lagged(3),
+                BroadcastEvent::Item(3),
+                BroadcastEvent::Item(4),
+            ]
+        );
+
+        assert!(broadcast.subscribe().is_none());
+        assert_eq!(broadcast.receiver_count(), 0);
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     instrument::MarketInstrumentData,
     streams::{
         builder::dynamic::DynamicStreams,
-        consumer::{MarketStreamEvent, MarketStreamResult},
+        consumer::{MarketStreamEvent, MarketStreamResult, maintenance_as_reconnecting},
         reconnect::stream::ReconnectingStream,
     },
     subscription::{SubKind, Subscription},
@@ -15,7 +15,7 @@ use barter_instrument::{
     index::{IndexedInstruments, error::IndexError},
     instrument::{InstrumentIndex, market_data::MarketDataInstrument},
 };
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use itertools::Itertools;
 use tracing::warn;
 
@@ -27,7 +27,8 @@ use tracing::warn;
 /// 2. Initialise an indexed [`DynamicStreams`] .
 /// 3. Combines all market streams into a single `Stream` via
 ///    [`select_all`](futures_util::stream::select_all::select_all)
-/// 4. Handles recoverable errors by logging them at `warn` level.
+/// 4. Surfaces exchange maintenance as a `Reconnecting` event (see [`maintenance_as_reconnecting`]).
+/// 5. Handles recoverable errors by logging them at `warn` level.
 ///
 /// See [`generate_indexed_market_data_subscription_batches`] for how indexed `Subscriptions` can
 /// be conveniently generated from an [`IndexedInstruments`] collection.
@@ -45,6 +46,7 @@ pub async fn init_indexed_multi_exchange_market_stream(
     let stream = DynamicStreams::init(subscriptions)
         .await?
         .select_all::<MarketStreamResult<InstrumentIndex, DataKind>>()
+        .map(maintenance_as_reconnecting)
         .with_error_handler(|error| warn!(?error, "MarketStream generated error"));
 
     Ok(stream)
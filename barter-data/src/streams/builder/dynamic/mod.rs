@@ -20,7 +20,11 @@ use crate::{
     instrument::InstrumentData,
     streams::{
         consumer::{MarketStreamResult, STREAM_RECONNECTION_POLICY, init_market_stream},
-        reconnect::stream::ReconnectingStream,
+        reconnect,
+        reconnect::{
+            coordinator::{CoordinatedEvent, ReconnectCoordinator, coordinate_reconnections},
+            stream::ReconnectingStream,
+        },
     },
     subscription::{
         SubKind, Subscription,
@@ -36,14 +40,19 @@ use barter_integration::{
     error::SocketError,
 };
 use fnv::FnvHashMap;
-use futures::{Stream, stream::SelectAll};
+use futures::{
+    Stream,
+    stream::{BoxStream, SelectAll},
+};
 use futures_util::{StreamExt, future::try_join_all};
 use itertools::Itertools;
 use std::{
+    collections::BTreeSet,
     fmt::{Debug, Display},
     sync::Arc,
 };
 use tokio_stream::wrappers::UnboundedReceiverStream;
+use tracing::warn;
 use vecmap::VecMap;
 
 pub mod indexed;
@@ -777,8 +786,91 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
 
         futures_util::stream::select_all::select_all(all)
     }
+
+    /// Select and merge every exchange `Stream` for every data type, coordinating reconnections
+    /// so every data type of an exchange is re-established together.
+    ///
+    /// Rather than each data type `Stream` emitting an independent `Reconnecting` event, a single
+    /// [`CoordinatedEvent::Reconnecting`] and [`CoordinatedEvent::Reconnected`] boundary is
+    /// emitted per exchange, with no items of that exchange emitted in between. The boundary is
+    /// held until every data type `Stream` of the exchange has resumed. See
+    /// [`ReconnectCoordinator`] for more information.
+    ///
+    /// Note that using `Result<MarketEvent<InstrumentKey, DataKind>, DataError>` as the `Output`
+    /// is suitable for most use cases.
+    pub fn select_all_coordinated<Output>(
+        self,
+        max_buffered: usize,
+    ) -> impl Stream<Item = CoordinatedEvent<ExchangeId, Output>>
+    where
+        InstrumentKey: Send + 'static,
+        Output: 'static,
+        MarketStreamResult<InstrumentKey, PublicTrade>: Into<reconnect::Event<ExchangeId, Output>>,
+        MarketStreamResult<InstrumentKey, OrderBookL1>: Into<reconnect::Event<ExchangeId, Output>>,
+        MarketStreamResult<InstrumentKey, OrderBookEvent>:
+            Into<reconnect::Event<ExchangeId, Output>>,
+        MarketStreamResult<InstrumentKey, Liquidation>: Into<reconnect::Event<ExchangeId, Output>>,
+    {
+        let Self {
+            trades,
+            l1s,
+            l2s,
+            liquidations,
+        } = self;
+
+        fn tag<St, T, Output>(
+            streams: VecMap<ExchangeId, St>,
+            kind: SubKind,
+        ) -> impl Iterator<
+            Item = BoxStream<'static, (ExchangeId, SubKind, reconnect::Event<ExchangeId, Output>)>,
+        >
+        where
+            St: Stream<Item = T> + Send + 'static,
+            T: Into<reconnect::Event<ExchangeId, Output>>,
+        {
+            streams.into_iter().map(move |(exchange, stream)| {
+                stream
+                    .map(move |event| (exchange, kind, event.into()))
+                    .boxed()
+            })
+        }
+
+        let coordinator = ReconnectCoordinator::new(max_buffered).with_streams(
+            trades
+                .keys()
+                .map(|exchange| (*exchange, SubKind::PublicTrades))
+                .chain(
+                    l1s.keys()
+                        .map(|exchange| (*exchange, SubKind::OrderBooksL1)),
+                )
+                .chain(
+                    l2s.keys()
+                        .map(|exchange| (*exchange, SubKind::OrderBooksL2)),
+                )
+                .chain(
+                    liquidations
+                        .keys()
+                        .map(|exchange| (*exchange, SubKind::Liquidations)),
+                ),
+        );
+
+        let all = tag(trades, SubKind::PublicTrades)
+            .chain(tag(l1s, SubKind::OrderBooksL1))
+            .chain(tag(l2s, SubKind::OrderBooksL2))
+            .chain(tag(liquidations, SubKind::Liquidations));
+
+        coordinate_reconnections(
+            futures_util::stream::select_all::select_all(all),
+            coordinator,
+        )
+    }
 }
 
+/// Validate each [`Subscription`] batch, and collapse duplicate [`Subscription`]s across all
+/// batches into their first occurrence.
+///
+/// A warning is logged for each duplicate [`Subscription`], and batches left empty after
+/// deduplication are removed so no redundant connection is opened.
 pub fn validate_batches<SubBatchIter, SubIter, Sub, Instrument>(
     batches: SubBatchIter,
 ) -> Result<Vec<Vec<Subscription<ExchangeId, Instrument, SubKind>>>, DataError>
@@ -786,11 +878,30 @@ where
     SubBatchIter: IntoIterator<Item = SubIter>,
     SubIter: IntoIterator<Item = Sub>,
     Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
-    Instrument: InstrumentData + Ord,
+    Instrument: InstrumentData + Ord + Display,
 {
+    let mut seen = BTreeSet::new();
+
     batches
         .into_iter()
         .map(validate_subscriptions::<SubIter, Sub, Instrument>)
+        .filter_map_ok(|batch| {
+            let batch = batch
+                .into_iter()
+                .filter(|subscription| {
+                    let is_new = seen.insert(subscription.clone());
+                    if !is_new {
+                        warn!(
+                            %subscription,
+                            "DynamicStreams ignoring duplicate Subscription across batches"
+                        );
+                    }
+                    is_new
+                })
+                .collect::<Vec<_>>();
+
+            (!batch.is_empty()).then_some(batch)
+        })
         .collect()
 }
 
@@ -917,3 +1028,42 @@ impl<InstrumentKey> Default for Rxs<InstrumentKey> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::instrument::market_data::{
+        MarketDataInstrument, kind::MarketDataInstrumentKind,
+    };
+
+    fn sub(base: &str, kind: SubKind) -> Subscription<ExchangeId, MarketDataInstrument, SubKind> {
+        Subscription::from((
+            ExchangeId::BinanceSpot,
+            base,
+            "usdt",
+            MarketDataInstrumentKind::Spot,
+            kind,
+        ))
+    }
+
+    #[test]
+    fn test_validate_batches_deduplicates_subscriptions_across_batches() {
+        let batches = validate_batches([
+            vec![sub("btc", SubKind::PublicTrades)],
+            vec![
+                sub("btc", SubKind::PublicTrades),
+                sub("btc", SubKind::OrderBooksL1),
+            ],
+            vec![sub("btc", SubKind::OrderBooksL1)],
+        ])
+        .unwrap();
+
+        assert_eq!(
+            batches,
+            vec![
+                vec![sub("btc", SubKind::PublicTrades)],
+                vec![sub("btc", SubKind::OrderBooksL1)],
+            ]
+        );
+    }
+}
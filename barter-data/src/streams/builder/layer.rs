@@ -0,0 +1,175 @@
+use crate::{
+    event::MarketEvent,
+    streams::{consumer::MarketStreamResult, reconnect},
+};
+use std::{fmt::Debug, sync::Arc};
+
+/// Communicative type alias for a [`MarketEvent`] filter predicate. Events for which the
+/// predicate returns `false` are dropped.
+pub type MarketEventFilter<InstrumentKey, Kind> =
+    Arc<dyn Fn(&MarketEvent<InstrumentKey, Kind>) -> bool + Send + Sync>;
+
+/// Communicative type alias for a [`MarketEvent`] kind transformation.
+pub type MarketEventMap<Kind> = Arc<dyn Fn(Kind) -> Kind + Send + Sync>;
+
+/// Filter & transform layer applied to every [`MarketStreamResult`] produced by a `MarketStream`
+/// before it is forwarded downstream.
+///
+/// [`reconnect::Event::Reconnecting`] and errors are always passed through untouched.
+pub struct MarketEventLayer<InstrumentKey, Kind> {
+    filters: Vec<MarketEventFilter<InstrumentKey, Kind>>,
+    maps: Vec<MarketEventMap<Kind>>,
+}
+
+impl<InstrumentKey, Kind> MarketEventLayer<InstrumentKey, Kind> {
+    /// Add a [`MarketEventFilter`] predicate. All predicates must return `true` for an event to
+    /// be forwarded.
+    pub fn with_filter<FnFilter>(mut self, filter: FnFilter) -> Self
+    where
+        FnFilter: Fn(&MarketEvent<InstrumentKey, Kind>) -> bool + Send + Sync + 'static,
+    {
+        self.filters.push(Arc::new(filter));
+        self
+    }
+
+    /// Add a [`MarketEventMap`] transformation. Transformations are applied in the order they are
+    /// added, after all filters.
+    pub fn with_map<FnMap>(mut self, map: FnMap) -> Self
+    where
+        FnMap: Fn(Kind) -> Kind + Send + Sync + 'static,
+    {
+        self.maps.push(Arc::new(map));
+        self
+    }
+
+    /// Returns `true` if the layer has no filters or transformations.
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty() && self.maps.is_empty()
+    }
+
+    /// Apply the layer to a [`MarketStreamResult`], returning `None` if the event is filtered out.
+    pub fn apply(
+        &self,
+        event: MarketStreamResult<InstrumentKey, Kind>,
+    ) -> Option<MarketStreamResult<InstrumentKey, Kind>> {
+        match event {
+            reconnect::Event::Item(Ok(event)) => {
+                if !self.filters.iter().all(|filter| filter(&event)) {
+                    return None;
+                }
+
+                let event = self
+                    .maps
+                    .iter()
+                    .fold(event, |event, map| event.map_kind(|kind| map(kind)));
+
+                Some(reconnect::Event::Item(Ok(event)))
+            }
+            reconnecting_or_error => Some(reconnecting_or_error),
+        }
+    }
+}
+
+impl<InstrumentKey, Kind> Default for MarketEventLayer<InstrumentKey, Kind> {
+    fn default() -> Self {
+        Self {
+            filters: Vec::new(),
+            maps: Vec::new(),
+        }
+    }
+}
+
+impl<InstrumentKey, Kind> Clone for MarketEventLayer<InstrumentKey, Kind> {
+    fn clone(&self) -> Self {
+        Self {
+            filters: self.filters.clone(),
+            maps: self.maps.clone(),
+        }
+    }
+}
+
+impl<InstrumentKey, Kind> Debug for MarketEventLayer<InstrumentKey, Kind> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MarketEventLayer")
+            .field("num_filters", &self.filters.len())
+            .field("num_maps", &self.maps.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::DataError, subscription::trade::PublicTrade};
+    use barter_instrument::{Side, exchange::ExchangeId};
+    use chrono::Utc;
+    use futures::StreamExt;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn trade(
+        instrument: &'static str,
+        price: Decimal,
+    ) -> MarketStreamResult<&'static str, PublicTrade> {
+        reconnect::Event::Item(Ok(MarketEvent {
+            time_exchange: Utc::now(),
+            time_received: Utc::now(),
+            time_processed: None,
+            sequence: None,
+            exchange: ExchangeId::BinanceSpot,
+            instrument,
+            kind: PublicTrade {
+                id: "id".to_string(),
+                price,
+                amount: dec!(1),
+                side: Side::Buy,
+            },
+        }))
+    }
+
+    #[tokio::test]
+    async fn test_market_event_layer_filters_instrument_and_maps_kind() {
+        let layer = MarketEventLayer::default()
+            .with_filter(|event: &MarketEvent<&'static str, PublicTrade>| {
+                event.instrument != "eth_usdt"
+            })
+            .with_map(|mut trade: PublicTrade| {
+                trade.price *= dec!(2);
+                trade
+            });
+
+        let input = vec![
+            trade("btc_usdt", dec!(1)),
+            trade("eth_usdt", dec!(2)),
+            reconnect::Event::Reconnecting(ExchangeId::BinanceSpot),
+            trade("eth_usdt", dec!(3)),
+            reconnect::Event::Item(Err(DataError::Socket("error".to_string()))),
+            trade("btc_usdt", dec!(4)),
+        ];
+
+        let output = futures::stream::iter(input)
+            .filter_map(|event| std::future::ready(layer.apply(event)))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(output.len(), 4);
+        assert!(output.iter().all(|event| match event {
+            reconnect::Event::Item(Ok(event)) => event.instrument != "eth_usdt",
+            _ => true,
+        }));
+        assert!(matches!(
+            output[1],
+            reconnect::Event::Reconnecting(ExchangeId::BinanceSpot)
+        ));
+        assert!(matches!(output[2], reconnect::Event::Item(Err(_))));
+
+        let prices = output
+            .iter()
+            .filter_map(|event| match event {
+                reconnect::Event::Item(Ok(event)) => Some(event.kind.price),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(prices, vec![dec!(2), dec!(8)]);
+    }
+}
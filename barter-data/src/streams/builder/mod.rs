@@ -2,28 +2,43 @@ use super::Streams;
 use crate::{
     Identifier,
     error::DataError,
-    exchange::StreamSelector,
+    event::MarketEvent,
+    exchange::{StreamSelector, subscription::ExchangeSub},
     instrument::InstrumentData,
     streams::{
-        consumer::{MarketStreamResult, STREAM_RECONNECTION_POLICY, init_market_stream},
-        reconnect::stream::ReconnectingStream,
+        builder::layer::MarketEventLayer,
+        consumer::{
+            MarketStreamResult, STREAM_RECONNECTION_POLICY, forward_market_stream,
+            init_market_stream,
+        },
     },
     subscription::{Subscription, SubscriptionKind},
 };
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::{Validator, channel::Channel};
+use barter_integration::{
+    Validator,
+    channel::{Backpressure, BackpressureTx, mpsc_backpressure},
+    subscription::SubscriptionId,
+};
+use futures::StreamExt;
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::{Debug, Display},
     future::Future,
     pin::Pin,
+    sync::{Arc, OnceLock},
 };
+use tracing::warn;
 
 /// Defines the [`MultiStreamBuilder`](multi::MultiStreamBuilder) API for ergonomically
 /// initialising a common [`Streams<Output>`](Streams) from multiple
 /// [`StreamBuilder<SubscriptionKind>`](StreamBuilder)s.
 pub mod multi;
 
+/// Defines the [`MarketEventLayer`] used by [`StreamBuilder::with_filter`] and
+/// [`StreamBuilder::with_map`] to filter & transform produced [`MarketEvent`]s.
+pub mod layer;
+
 /// Defines the [`DynamicStreams`](dynamic::DynamicStreams) API for initialising an arbitrary number
 /// of `MarketStream`s from the [`ExchangeId`] and [`SubKind`](crate::subscription::SubKind) enums, rather than concrete
 /// types.
@@ -40,10 +55,19 @@ pub struct StreamBuilder<InstrumentKey, Kind>
 where
     Kind: SubscriptionKind,
 {
-    pub channels: HashMap<ExchangeId, Channel<MarketStreamResult<InstrumentKey, Kind::Event>>>,
     pub futures: Vec<SubscribeFuture>,
+    exchanges: HashSet<ExchangeId>,
+    subscription_ids: HashSet<(ExchangeId, SubscriptionId)>,
+    layer: MarketEventLayer<InstrumentKey, Kind::Event>,
+    layer_init: Arc<OnceLock<MarketEventLayer<InstrumentKey, Kind::Event>>>,
+    backpressure: Backpressure,
+    channels_init: Arc<OnceLock<ExchangeTxs<InstrumentKey, Kind::Event>>>,
 }
 
+/// Consumer channel [`BackpressureTx`] for each exchange of a [`StreamBuilder`].
+type ExchangeTxs<InstrumentKey, Kind> =
+    HashMap<ExchangeId, BackpressureTx<MarketStreamResult<InstrumentKey, Kind>>>;
+
 impl<InstrumentKey, Kind> Debug for StreamBuilder<InstrumentKey, Kind>
 where
     InstrumentKey: Debug,
@@ -51,8 +75,11 @@ where
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("StreamBuilder<InstrumentKey, SubscriptionKind>")
-            .field("channels", &self.channels)
+            .field("exchanges", &self.exchanges)
             .field("num_futures", &self.futures.len())
+            .field("num_subscriptions", &self.subscription_ids.len())
+            .field("layer", &self.layer)
+            .field("backpressure", &self.backpressure)
             .finish()
     }
 }
@@ -64,14 +91,65 @@ where
     /// Construct a new [`Self`].
     pub fn new() -> Self {
         Self {
-            channels: HashMap::new(),
             futures: Vec::new(),
+            exchanges: HashSet::new(),
+            subscription_ids: HashSet::new(),
+            layer: MarketEventLayer::default(),
+            layer_init: Arc::new(OnceLock::new()),
+            backpressure: Backpressure::default(),
+            channels_init: Arc::new(OnceLock::new()),
         }
     }
 
+    /// Configure the [`Backpressure`] policy applied to the consumer channel of each exchange
+    /// [`Stream`](futures::Stream) in the [`Streams`] produced by this [`StreamBuilder`],
+    /// regardless of whether [`subscribe`](StreamBuilder::subscribe) was invoked before or after.
+    ///
+    /// Only market events are subject to the policy - control messages sent back to the exchange
+    /// (eg/ pongs) are never dropped. With
+    /// [`OverflowPolicy::Block`](barter_integration::channel::OverflowPolicy::Block) the exchange
+    /// socket is not read whilst the consumer lags behind.
+    ///
+    /// Defaults to [`Backpressure::Unbounded`].
+    pub fn with_backpressure(mut self, backpressure: Backpressure) -> Self {
+        self.backpressure = backpressure;
+        self
+    }
+
+    /// Only forward [`MarketEvent`]s for which the provided predicate returns `true` (eg/ drop
+    /// events for instruments outside a whitelist).
+    ///
+    /// Applies to every `MarketStream` produced by this [`StreamBuilder`], regardless of whether
+    /// [`subscribe`](StreamBuilder::subscribe) was invoked before or after. Reconnecting events
+    /// and errors are always forwarded.
+    pub fn with_filter<FnFilter>(mut self, filter: FnFilter) -> Self
+    where
+        FnFilter: Fn(&MarketEvent<InstrumentKey, Kind::Event>) -> bool + Send + Sync + 'static,
+    {
+        self.layer = self.layer.with_filter(filter);
+        self
+    }
+
+    /// Transform the kind of every forwarded [`MarketEvent`] (eg/ normalise values) before it
+    /// reaches the consumer. Transformations are applied after any filters.
+    ///
+    /// Applies to every `MarketStream` produced by this [`StreamBuilder`], regardless of whether
+    /// [`subscribe`](StreamBuilder::subscribe) was invoked before or after.
+    pub fn with_map<FnMap>(mut self, map: FnMap) -> Self
+    where
+        FnMap: Fn(Kind::Event) -> Kind::Event + Send + Sync + 'static,
+    {
+        self.layer = self.layer.with_map(map);
+        self
+    }
+
     /// Add a collection of [`Subscription`]s to the [`StreamBuilder`] that will be actioned on
     /// a distinct [`WebSocket`](barter_integration::protocol::websocket::WebSocket) connection.
     ///
+    /// Duplicate [`Subscription`]s (ie/ same exchange [`SubscriptionId`]) across all batches are
+    /// collapsed into the first occurrence, and a warning is logged. If every [`Subscription`] in
+    /// the batch is a duplicate, no additional connection is opened.
+    ///
     /// Note that [`Subscription`]s are not actioned until the
     /// [`init()`](StreamBuilder::init()) method is invoked.
     pub fn subscribe<SubIter, Sub, Exchange, Instrument>(mut self, subscriptions: SubIter) -> Self
@@ -86,12 +164,42 @@ where
         Subscription<Exchange, Instrument, Kind>:
             Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
     {
-        // Construct Vec<Subscriptions> from input SubIter
+        // Construct Vec<Subscriptions> from input SubIter, collapsing duplicates of Subscriptions
+        // already added to this StreamBuilder
         let subscriptions = subscriptions.into_iter().map(Sub::into).collect::<Vec<_>>();
+        let num_subscriptions = subscriptions.len();
+        let subscriptions = subscriptions
+            .into_iter()
+            .filter(|subscription| {
+                let subscription_id =
+                    ExchangeSub::<Exchange::Channel, Exchange::Market>::new(subscription).id();
 
-        // Acquire channel Sender to send Market<Kind::Event> from consumer loop to user
-        // '--> Add ExchangeChannel Entry if this Exchange <--> SubscriptionKind combination is new
-        let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
+                let is_new = self
+                    .subscription_ids
+                    .insert((Exchange::ID, subscription_id.clone()));
+                if !is_new {
+                    warn!(
+                        exchange = %Exchange::ID,
+                        %subscription_id,
+                        "StreamBuilder ignoring duplicate Subscription"
+                    );
+                }
+                is_new
+            })
+            .collect::<Vec<_>>();
+
+        // Every Subscription in this batch is a duplicate, so no new connection is required
+        if num_subscriptions > 0 && subscriptions.is_empty() {
+            return self;
+        }
+
+        // Register Exchange so a consumer channel is constructed for it during init()
+        self.exchanges.insert(Exchange::ID);
+
+        // MarketEventLayer & consumer channels are finalised during init(), which is before this
+        // Future is awaited
+        let layer = Arc::clone(&self.layer_init);
+        let channels = Arc::clone(&self.channels_init);
 
         // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
         self.futures.push(Box::pin(async move {
@@ -105,11 +213,27 @@ where
             subscriptions.sort();
             subscriptions.dedup();
 
+            // Acquire channel Sender to send Market<Kind::Event> from consumer loop to user
+            let exchange_tx = channels
+                .get()
+                .and_then(|channels| channels.get(&Exchange::ID))
+                .cloned()
+                .expect("StreamBuilder consumer channels are constructed before awaiting futures");
+
             // Initialise a MarketEvent `ReconnectingStream`
             let stream = init_market_stream(STREAM_RECONNECTION_POLICY, subscriptions).await?;
 
-            // Forward MarketEvents to ExchangeTx
-            tokio::spawn(stream.forward_to(exchange_tx));
+            // Apply any MarketEventLayer filters & transformations, and forward to ExchangeTx
+            match layer.get().filter(|layer| !layer.is_empty()).cloned() {
+                Some(layer) => {
+                    let stream =
+                        stream.filter_map(move |event| std::future::ready(layer.apply(event)));
+                    tokio::spawn(forward_market_stream(Exchange::ID, stream, exchange_tx));
+                }
+                None => {
+                    tokio::spawn(forward_market_stream(Exchange::ID, stream, exchange_tx));
+                }
+            }
 
             Ok(())
         }));
@@ -126,16 +250,379 @@ where
     pub async fn init(
         self,
     ) -> Result<Streams<MarketStreamResult<InstrumentKey, Kind::Event>>, DataError> {
+        // Construct a consumer channel for each exchange, sized by the configured Backpressure
+        let (exchange_txs, exchange_rxs) = self
+            .exchanges
+            .into_iter()
+            .map(|exchange| {
+                let (tx, rx) = mpsc_backpressure(self.backpressure);
+                ((exchange, tx), (exchange, rx))
+            })
+            .unzip::<_, _, ExchangeTxs<_, _>, _>();
+
+        // Finalise the MarketEventLayer & consumer channels used by each subscription Future
+        let _ = self.layer_init.set(self.layer);
+        let _ = self.channels_init.set(exchange_txs);
+        drop(self.channels_init);
+
         // Await Stream initialisation perpetual and ensure success
         futures::future::try_join_all(self.futures).await?;
 
-        // Construct Streams using each ExchangeChannel receiver
+        // Construct Streams using each exchange consumer channel receiver
         Ok(Streams {
-            streams: self
-                .channels
-                .into_iter()
-                .map(|(exchange, channel)| (exchange, channel.rx))
-                .collect(),
+            streams: exchange_rxs,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{exchange::binance::spot::BinanceSpot, subscription::trade::PublicTrades};
+    use barter_instrument::instrument::market_data::kind::MarketDataInstrumentKind;
+
+    #[test]
+    fn test_stream_builder_deduplicates_subscriptions_across_batches() {
+        let builder = StreamBuilder::<_, PublicTrades>::new()
+            .subscribe([(
+                BinanceSpot::default(),
+                "btc",
+                "usdt",
+                MarketDataInstrumentKind::Spot,
+                PublicTrades,
+            )])
+            .subscribe([
+                (
+                    BinanceSpot::default(),
+                    "btc",
+                    "usdt",
+                    MarketDataInstrumentKind::Spot,
+                    PublicTrades,
+                ),
+                (
+                    BinanceSpot::default(),
+                    "btc",
+                    "usdt",
+                    MarketDataInstrumentKind::Spot,
+                    PublicTrades,
+                ),
+            ]);
+
+        assert_eq!(builder.subscription_ids.len(), 1);
+        assert_eq!(builder.futures.len(), 1);
+    }
+
+    mod backpressure {
+        use super::*;
+        use crate::{
+            ExchangeWsStream, NoInitialSnapshots,
+            exchange::{Connector, subscription::ExchangeSub},
+            streams::reconnect,
+            subscription::{Map, trade::PublicTrade},
+            transformer::ExchangeTransformer,
+        };
+        use async_trait::async_trait;
+        use barter_instrument::{Side, instrument::market_data::MarketDataInstrument};
+        use barter_integration::{
+            Transformer,
+            channel::OverflowPolicy,
+            error::SocketError,
+            protocol::websocket::{WebSocketSerdeParser, WsMessage},
+        };
+        use barter_macro::{Connector, DeExchange, SerExchange};
+        use chrono::Utc;
+        use futures::SinkExt;
+        use rust_decimal::Decimal;
+        use serde::Deserialize;
+        use std::num::NonZeroUsize;
+        use tokio::sync::mpsc;
+
+        static TEST_URL: OnceLock<String> = OnceLock::new();
+
+        fn test_url() -> &'static str {
+            TEST_URL.get().expect("test WebSocket server not started")
+        }
+
+        #[derive(Debug)]
+        struct TestChannel;
+
+        impl AsRef<str> for TestChannel {
+            fn as_ref(&self) -> &str {
+                "trades"
+            }
+        }
+
+        #[derive(Debug)]
+        struct TestMarket(String);
+
+        impl AsRef<str> for TestMarket {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct TestSubResponse {
+            success: bool,
+        }
+
+        impl Validator for TestSubResponse {
+            fn validate(self) -> Result<Self, SocketError> {
+                if self.success {
+                    Ok(self)
+                } else {
+                    Err(SocketError::Subscribe("subscription failed".to_string()))
+                }
+            }
+        }
+
+        #[derive(
+            Copy,
+            Clone,
+            Eq,
+            PartialEq,
+            Ord,
+            PartialOrd,
+            Hash,
+            Debug,
+            Default,
+            DeExchange,
+            SerExchange,
+            Connector,
+        )]
+        #[connector(
+            exchange = ExchangeId::Other,
+            url = test_url(),
+            channel = TestChannel,
+            market = TestMarket,
+            sub_response = TestSubResponse,
+            requests = requests,
+        )]
+        struct TestExchange;
+
+        fn requests(exchange_subs: Vec<ExchangeSub<TestChannel, TestMarket>>) -> Vec<WsMessage> {
+            exchange_subs
+                .into_iter()
+                .map(|ExchangeSub { channel, market }| {
+                    WsMessage::text(format!("{}:{}", channel.as_ref(), market.as_ref()))
+                })
+                .collect()
+        }
+
+        impl Identifier<TestChannel> for Subscription<TestExchange, MarketDataInstrument, PublicTrades> {
+            fn id(&self) -> TestChannel {
+                TestChannel
+            }
+        }
+
+        impl Identifier<TestMarket> for Subscription<TestExchange, MarketDataInstrument, PublicTrades> {
+            fn id(&self) -> TestMarket {
+                TestMarket(format!("{}{}", self.instrument.base, self.instrument.quote))
+            }
+        }
+
+        impl StreamSelector<MarketDataInstrument, PublicTrades> for TestExchange {
+            type SnapFetcher = NoInitialSnapshots;
+            type Stream = ExchangeWsStream<WebSocketSerdeParser, BurstTransformer>;
+        }
+
+        /// Exchange message transformed into a burst of `trades` [`PublicTrade`]s.
+        #[derive(Debug, Deserialize)]
+        struct Burst {
+            trades: u64,
+        }
+
+        #[derive(Debug)]
+        struct BurstTransformer {
+            instrument: MarketDataInstrument,
+            ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+        }
+
+        #[async_trait]
+        impl ExchangeTransformer<TestExchange, MarketDataInstrument, PublicTrades> for BurstTransformer {
+            async fn init(
+                instrument_map: Map<MarketDataInstrument>,
+                _: &[MarketEvent<MarketDataInstrument, PublicTrade>],
+                ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
+            ) -> Result<Self, DataError> {
+                let instrument = instrument_map
+                    .0
+                    .into_values()
+                    .next()
+                    .ok_or(DataError::SubscriptionsEmpty)?;
+
+                Ok(Self {
+                    instrument,
+                    ws_sink_tx,
+                })
+            }
+        }
+
+        impl Transformer for BurstTransformer {
+            type Error = DataError;
+            type Input = Burst;
+            type Output = MarketEvent<MarketDataInstrument, PublicTrade>;
+            type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+            fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+                // Answer every message with a control message, like exchanges requiring pongs
+                self.ws_sink_tx.send(WsMessage::text("pong")).unwrap();
+
+                (0..input.trades)
+                    .map(|id| {
+                        Ok(MarketEvent {
+                            time_exchange: Utc::now(),
+                            time_received: Utc::now(),
+                            time_processed: None,
+                            sequence: None,
+                            exchange: ExchangeId::Other,
+                            instrument: self.instrument.clone(),
+                            kind: PublicTrade {
+                                id: id.to_string(),
+                                price: Decimal::ONE,
+                                amount: Decimal::ONE,
+                                side: Side::Buy,
+                            },
+                        })
+                    })
+                    .collect()
+            }
+        }
+
+        #[tokio::test]
+        async fn test_stream_builder_backpressure_applied_to_consumer_channel() {
+            struct TestCase {
+                overflow: OverflowPolicy,
+                expected_first_burst: Vec<&'static str>,
+                expected_second_burst: Vec<&'static str>,
+            }
+
+            // Start exchange WebSocket server that sends each requested burst to the connection
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            TEST_URL
+                .set(format!("ws://{}", listener.local_addr().unwrap()))
+                .unwrap();
+
+            let (connection_tx, mut connection_rx) = mpsc::unbounded_channel();
+            let (control_tx, mut control_rx) = mpsc::unbounded_channel();
+            tokio::spawn(async move {
+                while let Ok((socket, _)) = listener.accept().await {
+                    let mut burst_rx: mpsc::UnboundedReceiver<u64> =
+                        connection_rx.recv().await.unwrap();
+                    let control_tx = control_tx.clone();
+
+                    tokio::spawn(async move {
+                        let mut websocket = tokio_tungstenite::accept_async(socket).await.unwrap();
+
+                        let request = websocket.next().await.unwrap().unwrap();
+                        assert_eq!(request, WsMessage::text("trades:btcusdt"));
+                        websocket
+                            .send(WsMessage::text(r#"{"success": true}"#))
+                            .await
+                            .unwrap();
+
+                        // Send requested bursts & forward control messages sent by the MarketStream
+                        loop {
+                            tokio::select! {
+                                Some(trades) = burst_rx.recv() => {
+                                    let burst = format!(r#"{{"trades": {trades}}}"#);
+                                    if websocket.send(WsMessage::text(burst)).await.is_err() {
+                                        break;
+                                    }
+                                }
+                                message = websocket.next() => match message {
+                                    Some(Ok(message)) => {
+                                        let _ = control_tx.send(message);
+                                    }
+                                    _ => break,
+                                },
+                            }
+                        }
+                    });
+                }
+            });
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Block retains every trade of the burst
+                    overflow: OverflowPolicy::Block,
+                    expected_first_burst: vec!["0", "1", "2", "3", "4"],
+                    expected_second_burst: vec!["0"],
+                },
+                TestCase {
+                    // TC1: DropOldest retains the newest 3 trades of the burst
+                    overflow: OverflowPolicy::DropOldest,
+                    expected_first_burst: vec!["2", "3", "4"],
+                    expected_second_burst: vec!["0"],
+                },
+                TestCase {
+                    // TC2: Error retains the oldest 3 trades of the burst, surfacing the overflow
+                    // once the consumer has made space
+                    overflow: OverflowPolicy::Error,
+                    expected_first_burst: vec!["0", "1", "2"],
+                    expected_second_burst: vec![
+                        "MarketStream consumer channel of capacity 3 overflowed, dropped: 2",
+                        "0",
+                    ],
+                },
+            ];
+
+            let event_to_string =
+                |event: MarketStreamResult<MarketDataInstrument, PublicTrade>| match event {
+                    reconnect::Event::Item(Ok(event)) => event.kind.id,
+                    reconnect::Event::Item(Err(error)) => error.to_string(),
+                    event => panic!("unexpected event: {event:?}"),
+                };
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let (burst_tx, burst_rx) = mpsc::unbounded_channel();
+                connection_tx.send(burst_rx).unwrap();
+                burst_tx.send(5).unwrap();
+
+                let mut streams = StreamBuilder::<MarketDataInstrument, PublicTrades>::new()
+                    .subscribe([(
+                        TestExchange,
+                        "btc",
+                        "usdt",
+                        MarketDataInstrumentKind::Spot,
+                        PublicTrades,
+                    )])
+                    .with_backpressure(Backpressure::Bounded {
+                        capacity: NonZeroUsize::new(3).unwrap(),
+                        overflow: test.overflow,
+                    })
+                    .init()
+                    .await
+                    .unwrap();
+                let mut stream = std::pin::pin!(streams.select(ExchangeId::Other).unwrap());
+
+                // Consumer lags behind the first burst
+                tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+                let actual = stream
+                    .as_mut()
+                    .take(test.expected_first_burst.len())
+                    .map(event_to_string)
+                    .collect::<Vec<_>>()
+                    .await;
+                assert_eq!(actual, test.expected_first_burst, "TC{index} failed");
+
+                // Consumer keeps up with the second burst
+                burst_tx.send(1).unwrap();
+                let actual = stream
+                    .as_mut()
+                    .take(test.expected_second_burst.len())
+                    .map(event_to_string)
+                    .collect::<Vec<_>>()
+                    .await;
+                assert_eq!(actual, test.expected_second_burst, "TC{index} failed");
+
+                // Control messages still reach the exchange
+                assert_eq!(
+                    control_rx.recv().await.unwrap(),
+                    WsMessage::text("pong"),
+                    "TC{index} failed"
+                );
+            }
+        }
+    }
+}
@@ -1,11 +1,11 @@
 use super::{StreamBuilder, Streams};
 use crate::{
-    error::DataError,
-    streams::{consumer::MarketStreamResult, reconnect::stream::ReconnectingStream},
-    subscription::SubscriptionKind,
+    error::DataError, streams::consumer::MarketStreamResult, subscription::SubscriptionKind,
 };
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::channel::Channel;
+use barter_integration::channel::{
+    Backpressure, BackpressureRx, BackpressureTx, OverflowPolicy, mpsc_backpressure,
+};
 use futures_util::StreamExt;
 use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
 
@@ -17,7 +17,7 @@ pub type BuilderInitFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>
 /// multiple [`StreamBuilder<SubscriptionKind>`](StreamBuilder)s.
 #[derive(Default)]
 pub struct MultiStreamBuilder<Output> {
-    pub channels: HashMap<ExchangeId, Channel<Output>>,
+    pub channels: HashMap<ExchangeId, (BackpressureTx<Output>, BackpressureRx<Output>)>,
     pub futures: Vec<BuilderInitFuture>,
 }
 
@@ -57,13 +57,27 @@ impl<Output> MultiStreamBuilder<Output> {
         Kind: SubscriptionKind + 'static,
         Kind::Event: Send,
     {
+        // Common channels block when full, so the StreamBuilder Backpressure applies upstream
+        let backpressure = match builder.backpressure {
+            Backpressure::Unbounded => Backpressure::Unbounded,
+            Backpressure::Bounded { capacity, .. } => Backpressure::Bounded {
+                capacity,
+                overflow: OverflowPolicy::Block,
+            },
+        };
+
         // Allocate HashMap to hold the exchange_tx<Output> for each StreamBuilder exchange present
-        let mut exchange_txs = HashMap::with_capacity(builder.channels.len());
+        let mut exchange_txs = HashMap::with_capacity(builder.exchanges.len());
 
         // Iterate over each StreamBuilder exchange present
-        for exchange in builder.channels.keys().cloned() {
+        for exchange in builder.exchanges.iter().copied() {
             // Insert ExchangeChannel<Output> Entry to Self for each exchange
-            let exchange_tx = self.channels.entry(exchange).or_default().tx.clone();
+            let exchange_tx = self
+                .channels
+                .entry(exchange)
+                .or_insert_with(|| mpsc_backpressure(backpressure))
+                .0
+                .clone();
 
             // Insert new exchange_tx<Output> into HashMap for each exchange
             exchange_txs.insert(exchange, exchange_tx);
@@ -84,12 +98,14 @@ impl<Output> MultiStreamBuilder<Output> {
                         .expect("all exchange_txs should be present here");
 
                     // Task to receive MarketStreamResult<SubscriptionKind::Event> and send Outputs via exchange_tx
-                    tokio::spawn(
-                        exchange_rx
-                            .into_stream()
-                            .map(Output::from)
-                            .forward_to(exchange_tx),
-                    );
+                    tokio::spawn(async move {
+                        let mut exchange_rx = exchange_rx.map(Output::from);
+                        while let Some(output) = exchange_rx.next().await {
+                            if exchange_tx.send(output).await.is_err() {
+                                break;
+                            }
+                        }
+                    });
                 });
 
             Ok(())
@@ -110,7 +126,7 @@ impl<Output> MultiStreamBuilder<Output> {
             streams: self
                 .channels
                 .into_iter()
-                .map(|(exchange, channel)| (exchange, channel.rx))
+                .map(|(exchange, (_, exchange_rx))| (exchange, exchange_rx))
                 .collect(),
         })
     }
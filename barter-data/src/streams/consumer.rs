@@ -1,5 +1,5 @@
 use crate::{
-    Identifier, MarketStream,
+    Identifier, MarketStream, MarketStreamConfig,
     error::DataError,
     event::MarketEvent,
     exchange::StreamSelector,
@@ -13,11 +13,15 @@ use crate::{
     subscription::{Subscription, SubscriptionKind, display_subscriptions_without_exchange},
 };
 use barter_instrument::exchange::ExchangeId;
+use barter_integration::{
+    channel::{Backpressure, BackpressureTx, OverflowPolicy},
+    stream::stats::StreamStats,
+};
 use derive_more::Constructor;
-use futures::Stream;
+use futures::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
-use tracing::info;
+use tracing::{info, warn};
 
 /// Default [`ReconnectionBackoffPolicy`] for a [`reconnecting`](`ReconnectingStream`) [`MarketStream`].
 pub const STREAM_RECONNECTION_POLICY: ReconnectionBackoffPolicy = ReconnectionBackoffPolicy {
@@ -50,7 +54,57 @@ where
     Instrument: InstrumentData + Display,
     Kind: SubscriptionKind + Display,
     Subscription<Exchange, Instrument, Kind>:
-        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Sync,
+{
+    init_market_stream_with_stats(policy, subscriptions)
+        .await
+        .map(|(stream, _)| stream)
+}
+
+/// Initialises a [`reconnecting`](`ReconnectingStream`) [`MarketStream`] using a collection of
+/// [`Subscription`]s, returning it alongside a [`StreamStats`] handle.
+///
+/// The [`StreamStats`] handle is shared by every reconnection of the [`MarketStream`], so it can
+/// be queried for data-plane telemetry (messages received, last message time, parse errors and
+/// reconnections) over the lifetime of the stream.
+pub async fn init_market_stream_with_stats<Exchange, Instrument, Kind>(
+    policy: ReconnectionBackoffPolicy,
+    subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
+) -> Result<
+    (
+        impl Stream<Item = MarketStreamResult<Instrument::Key, Kind::Event>>,
+        StreamStats,
+    ),
+    DataError,
+>
+where
+    Exchange: StreamSelector<Instrument, Kind>,
+    Instrument: InstrumentData + Display,
+    Kind: SubscriptionKind + Display,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Sync,
+{
+    let config = MarketStreamConfig::default();
+    let stats = config.stats.clone();
+
+    init_market_stream_with_config(policy, subscriptions, config)
+        .await
+        .map(|stream| (stream, stats))
+}
+
+/// Initialises a [`reconnecting`](`ReconnectingStream`) [`MarketStream`] using a collection of
+/// [`Subscription`]s, applying the provided [`MarketStreamConfig`] to every reconnection.
+pub async fn init_market_stream_with_config<Exchange, Instrument, Kind>(
+    policy: ReconnectionBackoffPolicy,
+    subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
+    config: MarketStreamConfig,
+) -> Result<impl Stream<Item = MarketStreamResult<Instrument::Key, Kind::Event>>, DataError>
+where
+    Exchange: StreamSelector<Instrument, Kind>,
+    Instrument: InstrumentData + Display,
+    Kind: SubscriptionKind + Display,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market> + Sync,
 {
     // Determine ExchangeId associated with these Subscriptions
     let exchange = Exchange::ID;
@@ -69,14 +123,102 @@ where
         "MarketStream with auto reconnect initialising"
     );
 
-    Ok(init_reconnecting_stream(move || {
+    let stats = config.stats.clone();
+
+    let stream = init_reconnecting_stream(move || {
         let subscriptions = subscriptions.clone();
-        async move { Exchange::Stream::init::<Exchange::SnapFetcher>(&subscriptions).await }
+        let config = config.clone();
+        async move {
+            Exchange::Stream::init_with_config::<Exchange::SnapFetcher>(&subscriptions, config)
+                .await
+        }
     })
     .await?
     .with_reconnect_backoff(policy, stream_key)
+    .with_reconnect_stats(stats)
     .with_termination_on_error(|error| error.is_terminal(), stream_key)
-    .with_reconnection_events(exchange))
+    .with_reconnection_events(exchange)
+    .map(move |event| event.map_err(|error| error.with_exchange(exchange)));
+
+    Ok(stream)
+}
+
+/// Forward a [`MarketStreamResult`] `Stream` to a consumer channel, applying the channel
+/// [`Backpressure`] whenever the consumer lags behind the exchange.
+///
+/// With [`OverflowPolicy::Error`], events that do not fit are dropped and a
+/// [`DataError::BufferOverflow`] is surfaced ahead of the next event that fits. Forwarding stops
+/// once the `Stream` ends or the consumer channel receiver is dropped.
+pub async fn forward_market_stream<St, InstrumentKey, Kind>(
+    exchange: ExchangeId,
+    stream: St,
+    exchange_tx: BackpressureTx<MarketStreamResult<InstrumentKey, Kind>>,
+) where
+    St: Stream<Item = MarketStreamResult<InstrumentKey, Kind>>,
+{
+    let backpressure = exchange_tx.backpressure();
+    let error_capacity = match backpressure {
+        Backpressure::Bounded {
+            capacity,
+            overflow: OverflowPolicy::Error,
+        } => Some(capacity.get()),
+        _ => None,
+    };
+
+    let mut stream = std::pin::pin!(stream);
+    let mut dropped = 0;
+
+    while let Some(event) = stream.next().await {
+        // Surface any events dropped by OverflowPolicy::Error once the consumer has made space
+        if let Some(capacity) = error_capacity.filter(|_| dropped > 0) {
+            let overflow =
+                reconnect::Event::Item(Err(DataError::BufferOverflow { capacity, dropped }));
+
+            match exchange_tx.send(overflow).await {
+                Ok(false) => dropped = 0,
+                Ok(true) => {
+                    // Consumer channel is still full, so this event is dropped too
+                    dropped += 1;
+                    continue;
+                }
+                Err(_) => break,
+            }
+        }
+
+        match exchange_tx.send(event).await {
+            Ok(false) => {
+                if dropped > 0 && error_capacity.is_none() {
+                    warn!(%exchange, ?backpressure, dropped, "MarketStream consumer recovered after dropping events");
+                    dropped = 0;
+                }
+            }
+            Ok(true) => {
+                if dropped == 0 {
+                    warn!(%exchange, ?backpressure, "MarketStream consumer lagging, dropping events");
+                }
+                dropped += 1;
+            }
+            Err(_) => break,
+        }
+    }
+}
+
+/// Translate a [`DataError::Maintenance`] into a [`reconnect::Event::Reconnecting`] for the
+/// exchange undergoing maintenance, passing through all other events.
+///
+/// Exchanges undergoing maintenance often keep the socket connected while rejecting all
+/// requests. Surfacing maintenance as a `Reconnecting` event allows consumers (eg/ the Barter
+/// `Engine`) to mark the exchange as unhealthy until market data resumes.
+pub fn maintenance_as_reconnecting<InstrumentKey, Kind>(
+    event: MarketStreamResult<InstrumentKey, Kind>,
+) -> MarketStreamResult<InstrumentKey, Kind> {
+    match event {
+        reconnect::Event::Item(Err(DataError::Maintenance { exchange, message })) => {
+            warn!(%exchange, %message, "MarketStream exchange undergoing maintenance");
+            reconnect::Event::Reconnecting(exchange)
+        }
+        event => event,
+    }
 }
 
 #[derive(
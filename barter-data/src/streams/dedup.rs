@@ -0,0 +1,231 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    streams::{consumer::MarketStreamEvent, reconnect},
+    subscription::trade::PublicTrade,
+};
+use fnv::{FnvHashMap, FnvHashSet};
+use futures::Stream;
+use futures_util::StreamExt;
+use std::{collections::VecDeque, hash::Hash};
+
+/// Default number of recently seen trade ids remembered per instrument by a
+/// [`TradeDeduplicator`].
+pub const DEFAULT_TRADE_DEDUP_CAPACITY: usize = 1_000;
+
+/// Market event kinds that may contain a [`PublicTrade`] id used for deduplication.
+pub trait PublicTradeId {
+    /// Returns the [`PublicTrade`] id if this kind is a public trade, otherwise `None`.
+    fn public_trade_id(&self) -> Option<&str>;
+}
+
+impl PublicTradeId for PublicTrade {
+    fn public_trade_id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+}
+
+impl PublicTradeId for DataKind {
+    fn public_trade_id(&self) -> Option<&str> {
+        match self {
+            DataKind::Trade(trade) => Some(&trade.id),
+            _ => None,
+        }
+    }
+}
+
+/// Drops [`PublicTrade`]s whose `(instrument, trade_id)` was recently seen.
+///
+/// Reconnections and overlapping snapshot & stream backfills can yield the same trade more than
+/// once, which would otherwise corrupt volume & VWAP calculations.
+///
+/// Each instrument remembers at most `capacity` trade ids, evicting the least recently seen id
+/// once full. Non-trade events are always passed through untouched.
+#[derive(Debug, Clone)]
+pub struct TradeDeduplicator<InstrumentKey> {
+    capacity: usize,
+    seen: FnvHashMap<InstrumentKey, RecentTradeIds>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct RecentTradeIds {
+    ids: FnvHashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl<InstrumentKey> Default for TradeDeduplicator<InstrumentKey> {
+    fn default() -> Self {
+        Self::new(DEFAULT_TRADE_DEDUP_CAPACITY)
+    }
+}
+
+impl<InstrumentKey> TradeDeduplicator<InstrumentKey> {
+    /// Construct a new [`Self`] that remembers at most `capacity` trade ids per instrument.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            seen: FnvHashMap::default(),
+        }
+    }
+}
+
+impl<InstrumentKey> TradeDeduplicator<InstrumentKey>
+where
+    InstrumentKey: Clone + Eq + Hash,
+{
+    /// Returns `true` if the trade id was recently seen for the instrument, otherwise records
+    /// it as seen and returns `false`.
+    pub fn is_duplicate(&mut self, instrument: &InstrumentKey, trade_id: &str) -> bool {
+        if self.capacity == 0 {
+            return false;
+        }
+
+        let recent = match self.seen.get_mut(instrument) {
+            Some(recent) => recent,
+            None => self.seen.entry(instrument.clone()).or_default(),
+        };
+
+        if recent.ids.contains(trade_id) {
+            // Refresh recency so frequently replayed ids are not evicted
+            if let Some(position) = recent.order.iter().position(|id| id == trade_id)
+                && let Some(id) = recent.order.remove(position)
+            {
+                recent.order.push_back(id);
+            }
+            return true;
+        }
+
+        if recent.order.len() >= self.capacity
+            && let Some(evicted) = recent.order.pop_front()
+        {
+            recent.ids.remove(&evicted);
+        }
+
+        recent.ids.insert(trade_id.to_string());
+        recent.order.push_back(trade_id.to_string());
+
+        false
+    }
+
+    /// Apply deduplication to a [`MarketStreamEvent`], returning `None` if it is a duplicate
+    /// trade.
+    pub fn apply<Kind>(
+        &mut self,
+        event: MarketStreamEvent<InstrumentKey, Kind>,
+    ) -> Option<MarketStreamEvent<InstrumentKey, Kind>>
+    where
+        Kind: PublicTradeId,
+    {
+        match event {
+            reconnect::Event::Item(event) => {
+                (!self.is_duplicate_event(&event)).then_some(reconnect::Event::Item(event))
+            }
+            reconnecting => Some(reconnecting),
+        }
+    }
+
+    fn is_duplicate_event<Kind>(&mut self, event: &MarketEvent<InstrumentKey, Kind>) -> bool
+    where
+        Kind: PublicTradeId,
+    {
+        event
+            .kind
+            .public_trade_id()
+            .is_some_and(|trade_id| self.is_duplicate(&event.instrument, trade_id))
+    }
+}
+
+/// Deduplicate the [`PublicTrade`]s of a [`MarketStreamEvent`] `Stream` using a
+/// [`TradeDeduplicator`] that remembers at most `capacity` trade ids per instrument.
+///
+/// See [`TradeDeduplicator`] for more information.
+pub fn dedup_trades<St, InstrumentKey, Kind>(
+    stream: St,
+    capacity: usize,
+) -> impl Stream<Item = MarketStreamEvent<InstrumentKey, Kind>>
+where
+    St: Stream<Item = MarketStreamEvent<InstrumentKey, Kind>>,
+    InstrumentKey: Clone + Eq + Hash,
+    Kind: PublicTradeId,
+{
+    stream
+        .scan(TradeDeduplicator::new(capacity), |dedup, event| {
+            std::future::ready(Some(dedup.apply(event)))
+        })
+        .filter_map(std::future::ready)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::book::OrderBookL1;
+    use barter_instrument::{Side, exchange::ExchangeId};
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn event(
+        instrument: &'static str,
+        kind: DataKind,
+    ) -> MarketStreamEvent<&'static str, DataKind> {
+        reconnect::Event::Item(MarketEvent {
+            time_exchange: DateTime::<Utc>::MIN_UTC,
+            time_received: DateTime::<Utc>::MIN_UTC,
+            time_processed: None,
+            sequence: None,
+            exchange: ExchangeId::BinanceSpot,
+            instrument,
+            kind,
+        })
+    }
+
+    fn trade(instrument: &'static str, id: &str) -> MarketStreamEvent<&'static str, DataKind> {
+        event(
+            instrument,
+            DataKind::Trade(PublicTrade {
+                id: id.to_string(),
+                price: dec!(100),
+                amount: dec!(1),
+                side: Side::Buy,
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_dedup_trades_drops_recently_seen_trade_ids() {
+        let l1 = event("btc_usdt", DataKind::OrderBookL1(OrderBookL1::default()));
+
+        let input = vec![
+            trade("btc_usdt", "1"),
+            trade("btc_usdt", "2"),
+            // Overlapping backfill after reconnecting
+            reconnect::Event::Reconnecting(ExchangeId::BinanceSpot),
+            trade("btc_usdt", "1"),
+            trade("btc_usdt", "2"),
+            // Same trade id of a different instrument is not a duplicate
+            trade("eth_usdt", "1"),
+            // Non-trade events are passed through untouched
+            l1.clone(),
+            l1.clone(),
+            trade("btc_usdt", "3"),
+            // Trade id "1" has been evicted from the dedup window of capacity 2
+            trade("btc_usdt", "1"),
+        ];
+
+        let output = dedup_trades(futures::stream::iter(input), 2)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            output,
+            vec![
+                trade("btc_usdt", "1"),
+                trade("btc_usdt", "2"),
+                reconnect::Event::Reconnecting(ExchangeId::BinanceSpot),
+                trade("eth_usdt", "1"),
+                l1.clone(),
+                l1,
+                trade("btc_usdt", "3"),
+                trade("btc_usdt", "1"),
+            ]
+        );
+    }
+}
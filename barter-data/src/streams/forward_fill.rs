@@ -0,0 +1,330 @@
+use crate::{
+    event::MarketEvent,
+    streams::{consumer::MarketStreamEvent, reconnect},
+    subscription::book::OrderBookL1,
+};
+use barter_instrument::exchange::ExchangeId;
+use chrono::{DurationRound, TimeDelta};
+use fnv::FnvHashMap;
+use futures::Stream;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+use tracing::warn;
+
+/// Default maximum number of interpolated snapshots an [`L1ForwardFiller`] emits to fill a single
+/// gap between two real updates.
+pub const DEFAULT_FORWARD_FILL_MAX_POINTS: usize = 3_600;
+
+/// [`OrderBookL1`] snapshot emitted by an [`L1ForwardFiller`], flagged as either a real exchange
+/// update or an interpolated (forward-filled) snapshot.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct ForwardFilledL1 {
+    pub l1: OrderBookL1,
+    pub interpolated: bool,
+}
+
+/// Forward-fills sparse [`OrderBookL1`] updates into evenly-spaced snapshots, per
+/// `(exchange, instrument)`.
+///
+/// Many exchanges only send L1 updates on-change. Between two real updates, an
+/// `L1ForwardFiller` emits the last known [`OrderBookL1`] at every `interval` aligned
+/// `time_exchange` (eg/ every whole second for a one second interval), flagged as
+/// `interpolated`. Interpolated snapshots carry the last real values and `time_received`, with
+/// the `time_exchange` set to the interpolated time.
+///
+/// Forward-filling is event driven (so it works for historical & live data alike), meaning the
+/// interpolated snapshots of a gap are emitted when the next real update arrives, rather than at
+/// the wall-clock cadence. At most `max_filled` interpolated snapshots are emitted per gap (eg/ a
+/// tiny `interval` over a long gap), after which the remainder of the gap is left unfilled.
+///
+/// A [`Reconnecting`](reconnect::Event::Reconnecting) event discards the last known values of
+/// every instrument of the reconnecting exchange, so the gap is never filled.
+#[derive(Debug, Clone)]
+pub struct L1ForwardFiller<InstrumentKey> {
+    pub interval: TimeDelta,
+    pub max_filled: usize,
+    last: FnvHashMap<(ExchangeId, InstrumentKey), MarketEvent<InstrumentKey, OrderBookL1>>,
+}
+
+impl<InstrumentKey> L1ForwardFiller<InstrumentKey>
+where
+    InstrumentKey: Clone + Eq + Hash,
+{
+    /// Construct a new [`Self`] that forward-fills at the provided `interval`, emitting at most
+    /// [`DEFAULT_FORWARD_FILL_MAX_POINTS`] interpolated snapshots per gap.
+    ///
+    /// A non-positive `interval` disables forward-filling.
+    pub fn new(interval: TimeDelta) -> Self {
+        Self {
+            interval,
+            max_filled: DEFAULT_FORWARD_FILL_MAX_POINTS,
+            last: FnvHashMap::default(),
+        }
+    }
+
+    /// Set the maximum number of interpolated snapshots emitted per gap.
+    pub fn with_max_filled(self, max_filled: usize) -> Self {
+        Self { max_filled, ..self }
+    }
+
+    /// Forward-fill the gap between the last known [`OrderBookL1`] of the `(exchange, instrument)`
+    /// and the provided real update, returning the interpolated snapshots followed by the real
+    /// update.
+    ///
+    /// At most `max_filled` interpolated snapshots are returned.
+    pub fn fill(
+        &mut self,
+        event: MarketEvent<InstrumentKey, OrderBookL1>,
+    ) -> Vec<MarketEvent<InstrumentKey, ForwardFilledL1>> {
+        let key = (event.exchange, event.instrument.clone());
+
+        let mut filled = match self.last.get(&key) {
+            Some(last) if self.interval > TimeDelta::zero() => {
+                let mut time = last
+                    .time_exchange
+                    .duration_trunc(self.interval)
+                    .unwrap_or(last.time_exchange)
+                    .checked_add_signed(self.interval);
+
+                let mut filled = Vec::new();
+                while let Some(next) = time.filter(|time| *time < event.time_exchange) {
+                    if filled.len() >= self.max_filled {
+                        warn!(
+                            exchange = %event.exchange,
+                            max_filled = self.max_filled,
+                            gap_start = %next,
+                            gap_end = %event.time_exchange,
+                            "L1ForwardFiller reached max filled snapshots, leaving remainder of gap unfilled"
+                        );
+                        break;
+                    }
+
+                    filled.push(MarketEvent {
+                        time_exchange: next,
+                        time_received: last.time_received,
+                        time_processed: None,
+                        sequence: None,
+                        exchange: last.exchange,
+                        instrument: last.instrument.clone(),
+                        kind: ForwardFilledL1 {
+                            l1: last.kind.clone(),
+                            interpolated: true,
+                        },
+                    });
+                    time = next.checked_add_signed(self.interval);
+                }
+                filled
+            }
+            _ => Vec::new(),
+        };
+
+        filled.push(event.clone().map_kind(|l1| ForwardFilledL1 {
+            l1,
+            interpolated: false,
+        }));
+        self.last.insert(key, event);
+
+        filled
+    }
+
+    /// Discard the last known [`OrderBookL1`] of every instrument of the provided exchange.
+    pub fn reset(&mut self, exchange: ExchangeId) {
+        self.last
+            .retain(|(filled_exchange, _), _| *filled_exchange != exchange);
+    }
+
+    /// Apply forward-filling to a [`MarketStreamEvent`].
+    ///
+    /// A [`Reconnecting`](reconnect::Event::Reconnecting) event stops forward-filling for the
+    /// reconnecting exchange, and is passed through untouched.
+    pub fn apply(
+        &mut self,
+        event: MarketStreamEvent<InstrumentKey, OrderBookL1>,
+    ) -> Vec<MarketStreamEvent<InstrumentKey, ForwardFilledL1>> {
+        match event {
+            reconnect::Event::Item(event) => self
+                .fill(event)
+                .into_iter()
+                .map(reconnect::Event::Item)
+                .collect(),
+            reconnect::Event::Reconnecting(exchange) => {
+                self.reset(exchange);
+                vec![reconnect::Event::Reconnecting(exchange)]
+            }
+        }
+    }
+}
+
+/// Forward-fill every [`OrderBookL1`] [`MarketStreamEvent`] `Stream` at the provided `interval`,
+/// emitting at most `max_filled` interpolated snapshots per gap, using an [`L1ForwardFiller`].
+///
+/// See [`L1ForwardFiller`] for more information.
+pub fn forward_fill_l1<St, InstrumentKey>(
+    stream: St,
+    interval: TimeDelta,
+    max_filled: usize,
+) -> impl Stream<Item = MarketStreamEvent<InstrumentKey, ForwardFilledL1>>
+where
+    St: Stream<Item = MarketStreamEvent<InstrumentKey, OrderBookL1>>,
+    InstrumentKey: Clone + Eq + Hash,
+{
+    stream
+        .scan(
+            L1ForwardFiller::new(interval).with_max_filled(max_filled),
+            |filler, event| std::future::ready(Some(futures::stream::iter(filler.apply(event)))),
+        )
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::Level;
+    use chrono::{DateTime, Utc};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn time(seconds: i64) -> DateTime<Utc> {
+        DateTime::<Utc>::from_timestamp(1_750_000_000 + seconds, 0).unwrap()
+    }
+
+    fn l1(seconds: i64, bid: Decimal, ask: Decimal) -> MarketEvent<&'static str, OrderBookL1> {
+        MarketEvent {
+            time_exchange: time(seconds),
+            time_received: time(seconds),
+            time_processed: None,
+            sequence: None,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: "btc_usdt",
+            kind: OrderBookL1::new(
+                time(seconds),
+                Some(Level::new(bid, dec!(1))),
+                Some(Level::new(ask, dec!(1))),
+            ),
+        }
+    }
+
+    fn filled(
+        seconds: i64,
+        last: &MarketEvent<&'static str, OrderBookL1>,
+        interpolated: bool,
+    ) -> MarketStreamEvent<&'static str, ForwardFilledL1> {
+        reconnect::Event::Item(MarketEvent {
+            time_exchange: time(seconds),
+            time_received: last.time_received,
+            time_processed: None,
+            sequence: None,
+            exchange: last.exchange,
+            instrument: last.instrument,
+            kind: ForwardFilledL1 {
+                l1: last.kind.clone(),
+                interpolated,
+            },
+        })
+    }
+
+    fn filled_at(
+        time: DateTime<Utc>,
+        last: &MarketEvent<&'static str, OrderBookL1>,
+    ) -> MarketStreamEvent<&'static str, ForwardFilledL1> {
+        reconnect::Event::Item(MarketEvent {
+            time_exchange: time,
+            time_received: last.time_received,
+            time_processed: None,
+            sequence: None,
+            exchange: last.exchange,
+            instrument: last.instrument,
+            kind: ForwardFilledL1 {
+                l1: last.kind.clone(),
+                interpolated: true,
+            },
+        })
+    }
+
+    #[tokio::test]
+    async fn test_forward_fill_l1_fills_sparse_updates_and_stops_on_reconnecting() {
+        let first = l1(0, dec!(99), dec!(100));
+        let second = l1(3, dec!(98), dec!(99));
+        let third = l1(10, dec!(97), dec!(98));
+        let fourth = l1(12, dec!(96), dec!(97));
+
+        let input = vec![
+            reconnect::Event::Item(first.clone()),
+            reconnect::Event::Item(second.clone()),
+            reconnect::Event::Reconnecting(ExchangeId::BinanceSpot),
+            reconnect::Event::Item(third.clone()),
+            reconnect::Event::Item(fourth.clone()),
+        ];
+
+        let output = forward_fill_l1(
+            futures::stream::iter(input),
+            TimeDelta::seconds(1),
+            DEFAULT_FORWARD_FILL_MAX_POINTS,
+        )
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(
+            output,
+            vec![
+                filled(0, &first, false),
+                // Gap between real updates is filled with the last real values
+                filled(1, &first, true),
+                filled(2, &first, true),
+                filled(3, &second, false),
+                // Reconnecting gap is not filled
+                reconnect::Event::Reconnecting(ExchangeId::BinanceSpot),
+                filled(10, &third, false),
+                filled(11, &third, true),
+                filled(12, &fourth, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_l1_forward_filler_aligns_interpolated_snapshots_to_interval() {
+        let mut filler = L1ForwardFiller::new(TimeDelta::seconds(5));
+
+        let first = l1(2, dec!(99), dec!(100));
+        let second = l1(16, dec!(98), dec!(99));
+
+        assert_eq!(filler.fill(first.clone()).len(), 1);
+
+        let output = filler
+            .fill(second)
+            .into_iter()
+            .filter(|event| event.kind.interpolated)
+            .map(|event| event.time_exchange)
+            .collect::<Vec<_>>();
+
+        // 1_750_000_000 is a multiple of 5 seconds, so snapshots are aligned to whole 5s
+        assert_eq!(output, vec![time(5), time(10), time(15)]);
+    }
+
+    #[test]
+    fn test_l1_forward_filler_caps_interpolated_snapshots_per_gap() {
+        let mut filler = L1ForwardFiller::new(TimeDelta::milliseconds(1)).with_max_filled(3);
+
+        let first = l1(0, dec!(99), dec!(100));
+        let second = l1(3_600, dec!(98), dec!(99));
+
+        assert_eq!(filler.fill(first.clone()).len(), 1);
+
+        let output = filler.fill(second.clone());
+
+        // Hour long gap at a 1ms interval is capped, followed by the real update
+        assert_eq!(
+            output
+                .into_iter()
+                .map(reconnect::Event::Item)
+                .collect::<Vec<_>>(),
+            vec![
+                filled_at(time(0) + TimeDelta::milliseconds(1), &first),
+                filled_at(time(0) + TimeDelta::milliseconds(2), &first),
+                filled_at(time(0) + TimeDelta::milliseconds(3), &first),
+                filled(3_600, &second, false),
+            ]
+        );
+    }
+}
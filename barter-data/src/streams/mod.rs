@@ -1,10 +1,14 @@
 use self::builder::{StreamBuilder, multi::MultiStreamBuilder};
 use crate::subscription::SubscriptionKind;
 use barter_instrument::exchange::ExchangeId;
-use barter_integration::channel::UnboundedRx;
+use barter_integration::channel::BackpressureRx;
 use fnv::FnvHashMap;
 use futures::Stream;
 
+/// Defines a [`MarketStreamBroadcast`](broadcast::MarketStreamBroadcast) for fanning market events
+/// out to multiple independent consumers.
+pub mod broadcast;
+
 /// Defines the [`StreamBuilder`] and [`MultiStreamBuilder`] APIs for ergonomically initialising
 /// [`MarketStream`](super::MarketStream) [`Streams`].
 pub mod builder;
@@ -13,14 +17,35 @@ pub mod builder;
 /// drive a re-connecting [`MarketStream`](super::MarketStream).
 pub mod consumer;
 
+/// Defines a [`TradeDeduplicator`](dedup::TradeDeduplicator) for dropping duplicate
+/// [`PublicTrade`](crate::subscription::trade::PublicTrade)s from a market `Stream`.
+pub mod dedup;
+
+/// Defines an [`L1ForwardFiller`](forward_fill::L1ForwardFiller) for forward-filling sparse
+/// [`OrderBookL1`](crate::subscription::book::OrderBookL1) updates into evenly-spaced snapshots.
+pub mod forward_fill;
+
+/// Defines a [`ParquetSink`](parquet::ParquetSink) for archiving market `Stream`s into
+/// partitioned Parquet files for offline analysis.
+#[cfg(feature = "parquet")]
+pub mod parquet;
+
 /// Defines a [`ReconnectingStream`](reconnect::stream::ReconnectingStream) and associated logic
 /// for generating an auto reconnecting `Stream`.
 pub mod reconnect;
 
+/// Defines a [`MarketEventSequencer`](sequence::MarketEventSequencer) for stamping a
+/// per-(exchange, instrument, kind) sequence on market events, enabling gap detection downstream.
+pub mod sequence;
+
+/// Defines an [`EventTimeGuard`](time_guard::EventTimeGuard) for rejecting or clamping
+/// [`MarketEvent`](crate::event::MarketEvent)s with implausible exchange timestamps.
+pub mod time_guard;
+
 /// Ergonomic collection of exchange market event receivers.
 #[derive(Debug)]
 pub struct Streams<T> {
-    pub streams: FnvHashMap<ExchangeId, UnboundedRx<T>>,
+    pub streams: FnvHashMap<ExchangeId, BackpressureRx<T>>,
 }
 
 impl<T> Streams<T> {
@@ -40,13 +65,24 @@ impl<T> Streams<T> {
 
     /// Remove an exchange market event [`Stream`] from the [`Streams`] `HashMap`.
     pub fn select(&mut self, exchange: ExchangeId) -> Option<impl Stream<Item = T> + '_> {
-        self.streams.remove(&exchange).map(UnboundedRx::into_stream)
+        self.streams.remove(&exchange)
     }
 
     /// Select and merge every exchange `Stream` using
     /// [`select_all`](futures_util::stream::select_all::select_all).
     pub fn select_all(self) -> impl Stream<Item = T> {
-        let all = self.streams.into_values().map(UnboundedRx::into_stream);
-        futures_util::stream::select_all::select_all(all)
+        futures_util::stream::select_all::select_all(self.streams.into_values())
+    }
+
+    /// Select and merge every exchange `Stream`, fanning the merged events out to multiple
+    /// consumers via a [`MarketStreamBroadcast`](broadcast::MarketStreamBroadcast) with the
+    /// provided per-subscriber `capacity`.
+    ///
+    /// See [`MarketStreamBroadcast`](broadcast::MarketStreamBroadcast) for the lag policy.
+    pub fn broadcast(self, capacity: usize) -> broadcast::MarketStreamBroadcast<T>
+    where
+        T: Clone + Send + 'static,
+    {
+        broadcast::MarketStreamBroadcast::new(self.select_all(), capacity)
     }
 }
@@ -0,0 +1,650 @@
+use crate::{
+    books::Level,
+    event::MarketEvent,
+    streams::{consumer::MarketStreamEvent, reconnect},
+    subscription::{book::OrderBookL1, trade::PublicTrade},
+};
+use arrow_array::{ArrayRef, RecordBatch, StringArray, TimestampMicrosecondArray};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef, TimeUnit};
+use barter_instrument::exchange::ExchangeId;
+use chrono::{DateTime, NaiveDate, Utc};
+use fnv::FnvHashMap;
+use futures::{Stream, StreamExt};
+use parquet::{arrow::ArrowWriter, errors::ParquetError};
+use rust_decimal::Decimal;
+use std::{
+    fmt::Display,
+    fs::File,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    sync::{Arc, LazyLock},
+    time::{Duration, Instant},
+};
+use thiserror::Error;
+use tracing::debug;
+
+/// Default maximum number of rows buffered per partition before a [`ParquetSink`] flushes them
+/// to a Parquet file.
+pub const DEFAULT_PARQUET_MAX_ROWS: usize = 100_000;
+
+/// Default maximum age of the oldest row buffered per partition before a [`ParquetSink`]
+/// flushes them to a Parquet file.
+pub const DEFAULT_PARQUET_MAX_AGE: Duration = Duration::from_secs(60);
+
+/// All errors generated by a [`ParquetSink`].
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+pub enum ParquetSinkError {
+    #[error("Io: {0}")]
+    Io(String),
+
+    #[error("Arrow: {0}")]
+    Arrow(String),
+
+    #[error("Parquet: {0}")]
+    Parquet(String),
+
+    #[error("blocking write task failed: {0}")]
+    Task(String),
+}
+
+impl From<std::io::Error> for ParquetSinkError {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value.to_string())
+    }
+}
+
+impl From<ArrowError> for ParquetSinkError {
+    fn from(value: ArrowError) -> Self {
+        Self::Arrow(value.to_string())
+    }
+}
+
+impl From<ParquetError> for ParquetSinkError {
+    fn from(value: ParquetError) -> Self {
+        Self::Parquet(value.to_string())
+    }
+}
+
+/// Market event kinds that can be archived by a [`ParquetSink`] using a stable columnar schema.
+///
+/// Every schema starts with the common `time_exchange`, `time_received`, `exchange` &
+/// `instrument` columns, followed by the kind specific columns.
+///
+/// [`Decimal`] values are written as exact decimal strings rather than lossy floats.
+pub trait ParquetRecord: Sized {
+    /// Name of the kind, used as the Parquet file name prefix (eg/ "trades").
+    const NAME: &'static str;
+
+    /// Arrow [`Schema`] of the archived kind.
+    fn schema() -> SchemaRef;
+
+    /// Kind specific columns of the provided [`MarketEvent`]s, in [`Self::schema`] order.
+    fn columns<InstrumentKey>(events: &[MarketEvent<InstrumentKey, Self>]) -> Vec<ArrayRef>;
+}
+
+fn common_fields() -> [Field; 4] {
+    [
+        Field::new(
+            "time_exchange",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            false,
+        ),
+        Field::new(
+            "time_received",
+            DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+            false,
+        ),
+        Field::new("exchange", DataType::Utf8, false),
+        Field::new("instrument", DataType::Utf8, false),
+    ]
+}
+
+fn timestamps(times: impl Iterator<Item = DateTime<Utc>>) -> ArrayRef {
+    Arc::new(
+        TimestampMicrosecondArray::from_iter_values(times.map(|time| time.timestamp_micros()))
+            .with_timezone("UTC"),
+    )
+}
+
+fn decimals(values: impl Iterator<Item = Option<Decimal>>) -> ArrayRef {
+    Arc::new(StringArray::from_iter(
+        values.map(|value| value.map(|value| value.normalize().to_string())),
+    ))
+}
+
+static TRADE_SCHEMA: LazyLock<SchemaRef> = LazyLock::new(|| {
+    Arc::new(Schema::new(
+        common_fields()
+            .into_iter()
+            .chain([
+                Field::new("id", DataType::Utf8, false),
+                Field::new("price", DataType::Utf8, false),
+                Field::new("amount", DataType::Utf8, false),
+                Field::new("side", DataType::Utf8, false),
+            ])
+            .collect::<Vec<_>>(),
+    ))
+});
+
+impl ParquetRecord for PublicTrade {
+    const NAME: &'static str = "trades";
+
+    fn schema() -> SchemaRef {
+        Arc::clone(&TRADE_SCHEMA)
+    }
+
+    fn columns<InstrumentKey>(events: &[MarketEvent<InstrumentKey, Self>]) -> Vec<ArrayRef> {
+        vec![
+            Arc::new(StringArray::from_iter_values(
+                events.iter().map(|event| event.kind.id.as_str()),
+            )),
+            decimals(events.iter().map(|event| Some(event.kind.price))),
+            decimals(events.iter().map(|event| Some(event.kind.amount))),
+            Arc::new(StringArray::from_iter_values(
+                events.iter().map(|event| event.kind.side.to_string()),
+            )),
+        ]
+    }
+}
+
+static L1_SCHEMA: LazyLock<SchemaRef> = LazyLock::new(|| {
+    Arc::new(Schema::new(
+        common_fields()
+            .into_iter()
+            .chain([
+                Field::new(
+                    "last_update_time",
+                    DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into())),
+                    false,
+                ),
+                Field::new("best_bid_price", DataType::Utf8, true),
+                Field::new("best_bid_amount", DataType::Utf8, true),
+                Field::new("best_ask_price", DataType::Utf8, true),
+                Field::new("best_ask_amount", DataType::Utf8, true),
+            ])
+            .collect::<Vec<_>>(),
+    ))
+});
+
+impl ParquetRecord for OrderBookL1 {
+    const NAME: &'static str = "l1";
+
+    fn schema() -> SchemaRef {
+        Arc::clone(&L1_SCHEMA)
+    }
+
+    fn columns<InstrumentKey>(events: &[MarketEvent<InstrumentKey, Self>]) -> Vec<ArrayRef> {
+        let level = |level: fn(&OrderBookL1) -> Option<Level>, value: fn(Level) -> Decimal| {
+            decimals(events.iter().map(|event| level(&event.kind).map(value)))
+        };
+
+        vec![
+            timestamps(events.iter().map(|event| event.kind.last_update_time)),
+            level(|book| book.best_bid, |level| level.price),
+            level(|book| book.best_bid, |level| level.amount),
+            level(|book| book.best_ask, |level| level.price),
+            level(|book| book.best_ask, |level| level.amount),
+        ]
+    }
+}
+
+/// Configuration of a [`ParquetSink`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParquetSinkConfig {
+    /// Root directory that partitioned Parquet files are written to.
+    pub root: PathBuf,
+
+    /// Maximum number of rows buffered per partition before flushing.
+    pub max_rows: usize,
+
+    /// Maximum age of the oldest row buffered per partition before flushing.
+    pub max_age: Duration,
+}
+
+impl ParquetSinkConfig {
+    /// Construct a new [`Self`] writing to the `root` directory, using the default flush
+    /// thresholds.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            max_rows: DEFAULT_PARQUET_MAX_ROWS,
+            max_age: DEFAULT_PARQUET_MAX_AGE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Partition {
+    exchange: ExchangeId,
+    instrument: String,
+    date: NaiveDate,
+}
+
+#[derive(Debug)]
+struct PartitionBuffer<InstrumentKey, Kind> {
+    events: Vec<MarketEvent<InstrumentKey, Kind>>,
+    time_first: Instant,
+}
+
+/// Sink that archives [`MarketEvent`]s into columnar Parquet files for offline analysis.
+///
+/// Events are buffered per partition and written to
+/// `{root}/exchange={exchange}/instrument={instrument}/date={YYYY-MM-DD}/{kind}-{sequence}.parquet`,
+/// where the date is that of the event `time_exchange`. Each flush of a partition writes a new
+/// Parquet file, and existing files are never overwritten - after a restart the `sequence`
+/// resumes after the highest existing sequence in the partition directory.
+///
+/// Parquet files are written on the tokio blocking thread pool, so the sink never blocks the
+/// async runtime on file I/O.
+///
+/// A partition is flushed once it buffers [`ParquetSinkConfig::max_rows`] rows, or once its
+/// oldest row is older than [`ParquetSinkConfig::max_age`]. All partitions are flushed when the
+/// consumed `Stream` ends (see [`Self::run`]), or via [`Self::flush`].
+#[derive(Debug)]
+pub struct ParquetSink<InstrumentKey, Kind> {
+    config: ParquetSinkConfig,
+    buffers: FnvHashMap<Partition, PartitionBuffer<InstrumentKey, Kind>>,
+    sequence: u64,
+}
+
+impl<InstrumentKey, Kind> ParquetSink<InstrumentKey, Kind>
+where
+    InstrumentKey: Display,
+    Kind: ParquetRecord,
+{
+    /// Construct a new [`Self`] using the provided [`ParquetSinkConfig`].
+    pub fn new(config: ParquetSinkConfig) -> Self {
+        Self {
+            config,
+            buffers: FnvHashMap::default(),
+            sequence: 0,
+        }
+    }
+
+    /// Buffer the next [`MarketEvent`], flushing its partition if a threshold is reached.
+    ///
+    /// Returns the paths of any Parquet files written.
+    pub async fn write(
+        &mut self,
+        event: MarketEvent<InstrumentKey, Kind>,
+    ) -> Result<Vec<PathBuf>, ParquetSinkError> {
+        let partition = Partition {
+            exchange: event.exchange,
+            instrument: sanitise(&event.instrument.to_string()),
+            date: event.time_exchange.date_naive(),
+        };
+
+        let buffer = self
+            .buffers
+            .entry(partition.clone())
+            .or_insert_with(|| PartitionBuffer {
+                events: Vec::new(),
+                time_first: Instant::now(),
+            });
+        buffer.events.push(event);
+
+        if buffer.events.len() >= self.config.max_rows
+            || buffer.time_first.elapsed() >= self.config.max_age
+        {
+            self.flush_partition(partition).await.map(|path| vec![path])
+        } else {
+            Ok(vec![])
+        }
+    }
+
+    /// Flush every partition whose oldest buffered row is older than
+    /// [`ParquetSinkConfig::max_age`].
+    pub async fn flush_expired(&mut self) -> Result<Vec<PathBuf>, ParquetSinkError> {
+        let expired = self
+            .buffers
+            .iter()
+            .filter(|(_, buffer)| buffer.time_first.elapsed() >= self.config.max_age)
+            .map(|(partition, _)| partition.clone())
+            .collect::<Vec<_>>();
+
+        self.flush_partitions(expired).await
+    }
+
+    /// Flush every buffered partition.
+    pub async fn flush(&mut self) -> Result<Vec<PathBuf>, ParquetSinkError> {
+        let partitions = self.buffers.keys().cloned().collect::<Vec<_>>();
+        self.flush_partitions(partitions).await
+    }
+
+    /// Consume a [`MarketStreamEvent`] `Stream`, archiving every [`MarketEvent`] until the
+    /// `Stream` ends, at which point all buffered partitions are flushed.
+    ///
+    /// Reconnection events are ignored. Returns the paths of every Parquet file written.
+    pub async fn run<St>(mut self, stream: St) -> Result<Vec<PathBuf>, ParquetSinkError>
+    where
+        St: Stream<Item = MarketStreamEvent<InstrumentKey, Kind>>,
+    {
+        let mut written = Vec::new();
+        let mut flush_interval = tokio::time::interval(self.config.max_age);
+        let mut stream = std::pin::pin!(stream);
+
+        loop {
+            tokio::select! {
+                event = stream.next() => match event {
+                    Some(reconnect::Event::Item(event)) => written.extend(self.write(event).await?),
+                    Some(reconnect::Event::Reconnecting(_)) => {}
+                    None => break,
+                },
+                _ = flush_interval.tick() => written.extend(self.flush_expired().await?),
+            }
+        }
+
+        written.extend(self.flush().await?);
+        Ok(written)
+    }
+
+    async fn flush_partitions(
+        &mut self,
+        partitions: Vec<Partition>,
+    ) -> Result<Vec<PathBuf>, ParquetSinkError> {
+        let mut written = Vec::with_capacity(partitions.len());
+        for partition in partitions {
+            written.push(self.flush_partition(partition).await?);
+        }
+        Ok(written)
+    }
+
+    async fn flush_partition(&mut self, partition: Partition) -> Result<PathBuf, ParquetSinkError> {
+        let events = self
+            .buffers
+            .remove(&partition)
+            .map(|buffer| buffer.events)
+            .unwrap_or_default();
+        let rows = events.len();
+        let batch = record_batch(&events)?;
+
+        let directory = self
+            .config
+            .root
+            .join(format!("exchange={}", partition.exchange.as_str()))
+            .join(format!("instrument={}", partition.instrument))
+            .join(format!("date={}", partition.date.format("%Y-%m-%d")));
+
+        let sequence = self.sequence;
+        let (path, sequence) = tokio::task::spawn_blocking(move || {
+            write_partition_file(&directory, Kind::NAME, sequence, &batch)
+        })
+        .await
+        .map_err(|error| ParquetSinkError::Task(error.to_string()))??;
+        self.sequence = sequence + 1;
+
+        debug!(path = %path.display(), rows, "ParquetSink flushed partition");
+        Ok(path)
+    }
+}
+
+/// Write the provided [`MarketEvent`]s to a Parquet file at `path` using the
+/// [`ParquetRecord`] schema.
+pub fn write_parquet<InstrumentKey, Kind>(
+    path: &Path,
+    events: &[MarketEvent<InstrumentKey, Kind>],
+) -> Result<(), ParquetSinkError>
+where
+    InstrumentKey: Display,
+    Kind: ParquetRecord,
+{
+    write_batch(File::create(path)?, &record_batch(events)?)
+}
+
+/// Build a [`RecordBatch`] of the provided [`MarketEvent`]s using the [`ParquetRecord`] schema.
+fn record_batch<InstrumentKey, Kind>(
+    events: &[MarketEvent<InstrumentKey, Kind>],
+) -> Result<RecordBatch, ParquetSinkError>
+where
+    InstrumentKey: Display,
+    Kind: ParquetRecord,
+{
+    let columns = [
+        timestamps(events.iter().map(|event| event.time_exchange)),
+        timestamps(events.iter().map(|event| event.time_received)),
+        Arc::new(StringArray::from_iter_values(
+            events.iter().map(|event| event.exchange.as_str()),
+        )),
+        Arc::new(StringArray::from_iter_values(
+            events.iter().map(|event| event.instrument.to_string()),
+        )),
+    ]
+    .into_iter()
+    .chain(Kind::columns(events))
+    .collect::<Vec<ArrayRef>>();
+
+    Ok(RecordBatch::try_new(Kind::schema(), columns)?)
+}
+
+fn write_batch(file: File, batch: &RecordBatch) -> Result<(), ParquetSinkError> {
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), None)?;
+    writer.write(batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+/// Write the [`RecordBatch`] to a new `{name}-{sequence}.parquet` file in `directory`, never
+/// overwriting an existing file.
+///
+/// The `sequence` is advanced past the highest existing sequence in `directory`. Returns the
+/// path written and the sequence used.
+fn write_partition_file(
+    directory: &Path,
+    name: &str,
+    sequence: u64,
+    batch: &RecordBatch,
+) -> Result<(PathBuf, u64), ParquetSinkError> {
+    std::fs::create_dir_all(directory)?;
+
+    let prefix = format!("{name}-");
+    let mut sequence = std::fs::read_dir(directory)?
+        .filter_map(|entry| {
+            entry
+                .ok()?
+                .file_name()
+                .to_str()?
+                .strip_prefix(&prefix)?
+                .strip_suffix(".parquet")?
+                .parse::<u64>()
+                .ok()
+        })
+        .map(|existing| existing + 1)
+        .fold(sequence, u64::max);
+
+    loop {
+        let path = directory.join(format!("{name}-{sequence:06}.parquet"));
+        match File::create_new(&path) {
+            Ok(file) => {
+                write_batch(file, batch)?;
+                return Ok((path, sequence));
+            }
+            Err(error) if error.kind() == ErrorKind::AlreadyExists => sequence += 1,
+            Err(error) => return Err(error.into()),
+        }
+    }
+}
+
+fn sanitise(name: &str) -> String {
+    name.chars()
+        .map(|char| match char {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => char,
+            _ => '_',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Array;
+    use barter_instrument::Side;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use rust_decimal_macros::dec;
+
+    fn trade(id: &str, price: Decimal, side: Side) -> MarketEvent<&'static str, PublicTrade> {
+        let time = DateTime::<Utc>::from_timestamp(1_750_000_000, 0).unwrap();
+        MarketEvent {
+            time_exchange: time,
+            time_received: time,
+            time_processed: None,
+            sequence: None,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: "btc_usdt",
+            kind: PublicTrade {
+                id: id.to_string(),
+                price,
+                amount: dec!(0.5),
+                side,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_parquet_sink_writes_trades_readable_with_schema() {
+        let root = std::env::temp_dir().join(format!(
+            "barter-parquet-sink-{}",
+            Utc::now().timestamp_nanos_opt().unwrap()
+        ));
+
+        let trades = [
+            trade("1", dec!(100.5), Side::Buy),
+            trade("2", dec!(101), Side::Sell),
+            trade("3", dec!(99.25), Side::Buy),
+        ];
+
+        let sink = ParquetSink::new(ParquetSinkConfig {
+            max_rows: 2,
+            ..ParquetSinkConfig::new(&root)
+        });
+        let input = trades
+            .iter()
+            .cloned()
+            .map(reconnect::Event::Item)
+            .chain([reconnect::Event::Reconnecting(ExchangeId::BinanceSpot)]);
+
+        // First file flushed on max_rows, second file flushed when the stream ends
+        let written = sink.run(futures::stream::iter(input)).await.unwrap();
+        assert_eq!(written.len(), 2);
+        assert_eq!(
+            written[0],
+            root.join("exchange=binance_spot")
+                .join("instrument=btc_usdt")
+                .join("date=2025-06-15")
+                .join("trades-000000.parquet")
+        );
+
+        let batches = written
+            .iter()
+            .flat_map(|path| {
+                ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap())
+                    .unwrap()
+                    .build()
+                    .unwrap()
+                    .map(Result::unwrap)
+            })
+            .collect::<Vec<_>>();
+
+        assert!(
+            batches
+                .iter()
+                .all(|batch| batch.schema() == PublicTrade::schema())
+        );
+        assert_eq!(batches.iter().map(RecordBatch::num_rows).sum::<usize>(), 3);
+
+        let column = |name: &str| {
+            batches
+                .iter()
+                .flat_map(|batch| {
+                    let array = batch.column_by_name(name).unwrap();
+                    (0..array.len())
+                        .map(|index| value_string(array.as_ref(), index))
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(column("id"), vec!["1", "2", "3"]);
+        assert_eq!(column("price"), vec!["100.5", "101", "99.25"]);
+        assert_eq!(column("amount"), vec!["0.5", "0.5", "0.5"]);
+        assert_eq!(column("side"), vec!["buy", "sell", "buy"]);
+        assert_eq!(column("exchange"), ["binance_spot"; 3]);
+        assert_eq!(column("instrument"), ["btc_usdt"; 3]);
+        assert_eq!(
+            column("time_exchange"),
+            vec![trades[0].time_exchange.timestamp_micros().to_string(); 3]
+        );
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_parquet_sink_restart_does_not_overwrite_existing_files() {
+        let root = std::env::temp_dir().join(format!(
+            "barter-parquet-sink-restart-{}",
+            Utc::now().timestamp_nanos_opt().unwrap()
+        ));
+
+        let trades = [
+            trade("1", dec!(100.5), Side::Buy),
+            trade("2", dec!(101), Side::Sell),
+            trade("3", dec!(99.25), Side::Buy),
+        ];
+
+        let run = || {
+            let sink = ParquetSink::new(ParquetSinkConfig {
+                max_rows: 2,
+                ..ParquetSinkConfig::new(&root)
+            });
+            sink.run(futures::stream::iter(
+                trades.clone().map(reconnect::Event::Item),
+            ))
+        };
+
+        let file_names = |written: &[PathBuf]| {
+            written
+                .iter()
+                .map(|path| path.file_name().unwrap().to_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // First run on the day
+        let first = run().await.unwrap();
+        assert_eq!(
+            file_names(&first),
+            ["trades-000000.parquet", "trades-000001.parquet"]
+        );
+
+        // Restart on the same day resumes after the highest existing sequence
+        let second = run().await.unwrap();
+        assert_eq!(
+            file_names(&second),
+            ["trades-000002.parquet", "trades-000003.parquet"]
+        );
+
+        // First run files are untouched
+        let rows = first
+            .iter()
+            .map(|path| {
+                ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap())
+                    .unwrap()
+                    .build()
+                    .unwrap()
+                    .map(|batch| batch.unwrap().num_rows())
+                    .sum::<usize>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(rows, [2, 1]);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+
+    fn value_string(array: &dyn Array, index: usize) -> String {
+        if let Some(array) = array.as_any().downcast_ref::<StringArray>() {
+            array.value(index).to_string()
+        } else if let Some(array) = array.as_any().downcast_ref::<TimestampMicrosecondArray>() {
+            array.value(index).to_string()
+        } else {
+            panic!("unexpected column type: {:?}", array.data_type())
+        }
+    }
+}
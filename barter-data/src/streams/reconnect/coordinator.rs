@@ -0,0 +1,333 @@
+use crate::streams::reconnect::Event;
+use fnv::FnvHashMap;
+use futures::Stream;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeSet, VecDeque},
+    hash::Hash,
+};
+use tracing::warn;
+
+/// Default maximum number of items buffered per `Origin` while waiting for every `Kind` to
+/// resume.
+pub const DEFAULT_RECONNECT_MAX_BUFFERED: usize = 10_000;
+
+/// [`ReconnectCoordinator`] output that communicates a single reconnection boundary per `Origin`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum CoordinatedEvent<Origin, T> {
+    /// At least one `Stream` of the `Origin` has disconnected and is attempting to reconnect.
+    Reconnecting(Origin),
+
+    /// Every `Stream` of the `Origin` has resumed. Items buffered since the associated
+    /// [`CoordinatedEvent::Reconnecting`] follow this boundary.
+    Reconnected(Origin),
+
+    Item(T),
+}
+
+/// Coordinates the reconnection of multiple `Kind` streams (eg/ trades & order books) that share
+/// an `Origin` (eg/ an exchange), so consumers observe one coherent reconnection boundary rather
+/// than independent flaps per `Kind`.
+///
+/// When the first `Kind` of an `Origin` disconnects, a single [`CoordinatedEvent::Reconnecting`]
+/// is emitted. Every subsequent item of that `Origin` (from any `Kind`) is buffered until every
+/// known `Kind` of the `Origin` has yielded an item since its most recent disconnection. A
+/// `Kind` that disconnects while the boundary is open (eg/ after a sibling `Kind` has already
+/// resumed) extends the existing boundary rather than opening a new one. A single
+/// [`CoordinatedEvent::Reconnected`] is then emitted, followed by the buffered items in arrival
+/// order.
+///
+/// `Kinds` are known if registered via [`Self::with_streams`], or once they have yielded any
+/// [`Event`].
+///
+/// The boundary is never closed early. If more than `max_buffered` items of an `Origin` are
+/// buffered (eg/ a quiet `Kind` is slow to yield an item), the oldest buffered items are dropped
+/// to bound memory usage.
+#[derive(Debug, Clone)]
+pub struct ReconnectCoordinator<Origin, Kind, T> {
+    max_buffered: usize,
+    streams: FnvHashMap<Origin, BTreeSet<Kind>>,
+    states: FnvHashMap<Origin, ReconnectState<Kind, T>>,
+}
+
+#[derive(Debug, Clone)]
+struct ReconnectState<Kind, T> {
+    pending: BTreeSet<Kind>,
+    buffer: VecDeque<T>,
+}
+
+impl<Origin, Kind, T> Default for ReconnectCoordinator<Origin, Kind, T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_RECONNECT_MAX_BUFFERED)
+    }
+}
+
+impl<Origin, Kind, T> ReconnectCoordinator<Origin, Kind, T> {
+    /// Construct a new [`Self`] that buffers at most `max_buffered` items per reconnecting
+    /// `Origin`.
+    pub fn new(max_buffered: usize) -> Self {
+        Self {
+            max_buffered,
+            streams: FnvHashMap::default(),
+            states: FnvHashMap::default(),
+        }
+    }
+}
+
+impl<Origin, Kind, T> ReconnectCoordinator<Origin, Kind, T>
+where
+    Origin: Clone + Eq + Hash + std::fmt::Debug,
+    Kind: Clone + Ord + std::fmt::Debug,
+{
+    /// Register the `(Origin, Kind)` streams that must all resume before a reconnection boundary
+    /// of their `Origin` is closed.
+    pub fn with_streams(mut self, streams: impl IntoIterator<Item = (Origin, Kind)>) -> Self {
+        for (origin, kind) in streams {
+            self.streams.entry(origin).or_default().insert(kind);
+        }
+        self
+    }
+
+    /// Returns `true` if the `Origin` is currently within a reconnection boundary.
+    pub fn is_reconnecting(&self, origin: &Origin) -> bool {
+        self.states.contains_key(origin)
+    }
+
+    /// Process the next [`Event`] yielded by the `Kind` stream of an `Origin`, returning the
+    /// [`CoordinatedEvent`]s that are ready to be emitted.
+    pub fn process(
+        &mut self,
+        origin: Origin,
+        kind: Kind,
+        event: Event<Origin, T>,
+    ) -> Vec<CoordinatedEvent<Origin, T>> {
+        let streams = self.streams.entry(origin.clone()).or_default();
+        if !streams.contains(&kind) {
+            streams.insert(kind.clone());
+        }
+
+        match event {
+            Event::Reconnecting(reconnecting) => match self.states.get_mut(&origin) {
+                Some(state) => {
+                    state.pending.insert(kind);
+                    vec![]
+                }
+                None => {
+                    self.states.insert(
+                        origin,
+                        ReconnectState {
+                            pending: streams.clone(),
+                            buffer: VecDeque::new(),
+                        },
+                    );
+                    vec![CoordinatedEvent::Reconnecting(reconnecting)]
+                }
+            },
+            Event::Item(item) => {
+                let Some(state) = self.states.get_mut(&origin) else {
+                    return vec![CoordinatedEvent::Item(item)];
+                };
+
+                state.pending.remove(&kind);
+                state.buffer.push_back(item);
+
+                if state.buffer.len() > self.max_buffered {
+                    state.buffer.pop_front();
+                    warn!(
+                        ?origin,
+                        pending = ?state.pending,
+                        max_buffered = self.max_buffered,
+                        "ReconnectCoordinator buffer full, dropping oldest buffered item"
+                    );
+                }
+
+                if !state.pending.is_empty() {
+                    return vec![];
+                }
+
+                let state = self
+                    .states
+                    .remove(&origin)
+                    .expect("ReconnectState exists as checked above");
+
+                std::iter::once(CoordinatedEvent::Reconnected(origin))
+                    .chain(state.buffer.into_iter().map(CoordinatedEvent::Item))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Coordinate the reconnections of a `Stream` of `(Origin, Kind, Event)` items using the
+/// provided [`ReconnectCoordinator`].
+///
+/// See [`ReconnectCoordinator`] for more information.
+pub fn coordinate_reconnections<St, Origin, Kind, T>(
+    stream: St,
+    coordinator: ReconnectCoordinator<Origin, Kind, T>,
+) -> impl Stream<Item = CoordinatedEvent<Origin, T>>
+where
+    St: Stream<Item = (Origin, Kind, Event<Origin, T>)>,
+    Origin: Clone + Eq + Hash + std::fmt::Debug,
+    Kind: Clone + Ord + std::fmt::Debug,
+{
+    stream
+        .scan(coordinator, |coordinator, (origin, kind, event)| {
+            std::future::ready(Some(futures::stream::iter(
+                coordinator.process(origin, kind, event),
+            )))
+        })
+        .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::exchange::ExchangeId;
+
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    enum Kind {
+        Trades,
+        Books,
+    }
+
+    #[tokio::test]
+    async fn test_coordinate_reconnections_emits_single_boundary_across_kinds() {
+        let okx = ExchangeId::Okx;
+        let binance = ExchangeId::BinanceSpot;
+
+        let events = vec![
+            (okx, Kind::Trades, Event::Item("trade_1")),
+            (okx, Kind::Books, Event::Item("book_1")),
+            // Okx disconnects, both kinds flap independently
+            (okx, Kind::Trades, Event::Reconnecting(okx)),
+            (okx, Kind::Books, Event::Reconnecting(okx)),
+            // Other exchanges are unaffected
+            (binance, Kind::Trades, Event::Item("binance_trade")),
+            // Trades resume before books, so are held back
+            (okx, Kind::Trades, Event::Item("trade_2")),
+            (okx, Kind::Trades, Event::Item("trade_3")),
+            (okx, Kind::Books, Event::Item("book_2")),
+            (okx, Kind::Trades, Event::Item("trade_4")),
+        ];
+
+        let outputs = coordinate_reconnections(
+            futures::stream::iter(events),
+            ReconnectCoordinator::new(100),
+        )
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(
+            outputs,
+            vec![
+                CoordinatedEvent::Item("trade_1"),
+                CoordinatedEvent::Item("book_1"),
+                CoordinatedEvent::Reconnecting(okx),
+                CoordinatedEvent::Item("binance_trade"),
+                CoordinatedEvent::Reconnected(okx),
+                CoordinatedEvent::Item("trade_2"),
+                CoordinatedEvent::Item("trade_3"),
+                CoordinatedEvent::Item("book_2"),
+                CoordinatedEvent::Item("trade_4"),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_coordinate_reconnections_holds_boundary_until_every_kind_resumes() {
+        let okx = ExchangeId::Okx;
+
+        let events = vec![
+            // Trades disconnects & resumes before books notices the disconnection
+            (okx, Kind::Trades, Event::Reconnecting(okx)),
+            (okx, Kind::Trades, Event::Item("trade_1")),
+            (okx, Kind::Books, Event::Reconnecting(okx)),
+            (okx, Kind::Trades, Event::Item("trade_2")),
+            // Trades flaps again while books is still re-subscribing
+            (okx, Kind::Trades, Event::Reconnecting(okx)),
+            (okx, Kind::Books, Event::Item("book_1")),
+            (okx, Kind::Trades, Event::Item("trade_3")),
+            (okx, Kind::Books, Event::Item("book_2")),
+        ];
+
+        let coordinator =
+            ReconnectCoordinator::new(100).with_streams([(okx, Kind::Trades), (okx, Kind::Books)]);
+
+        let outputs = coordinate_reconnections(futures::stream::iter(events), coordinator)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            outputs,
+            vec![
+                CoordinatedEvent::Reconnecting(okx),
+                CoordinatedEvent::Reconnected(okx),
+                CoordinatedEvent::Item("trade_1"),
+                CoordinatedEvent::Item("trade_2"),
+                CoordinatedEvent::Item("book_1"),
+                CoordinatedEvent::Item("trade_3"),
+                CoordinatedEvent::Item("book_2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reconnect_coordinator_waits_for_registered_streams() {
+        let okx = ExchangeId::Okx;
+        let mut coordinator =
+            ReconnectCoordinator::new(100).with_streams([(okx, Kind::Trades), (okx, Kind::Books)]);
+
+        // Books has not yielded anything yet, but is still required to resume
+        assert_eq!(
+            coordinator.process(okx, Kind::Trades, Event::Reconnecting(okx)),
+            vec![CoordinatedEvent::Reconnecting(okx)]
+        );
+        assert!(
+            coordinator
+                .process(okx, Kind::Trades, Event::Item(1))
+                .is_empty()
+        );
+        assert!(coordinator.is_reconnecting(&okx));
+        assert_eq!(
+            coordinator.process(okx, Kind::Books, Event::Item(2)),
+            vec![
+                CoordinatedEvent::Reconnected(okx),
+                CoordinatedEvent::Item(1),
+                CoordinatedEvent::Item(2),
+            ]
+        );
+        assert!(!coordinator.is_reconnecting(&okx));
+    }
+
+    #[test]
+    fn test_reconnect_coordinator_drops_oldest_items_when_buffer_full() {
+        let okx = ExchangeId::Okx;
+        let mut coordinator = ReconnectCoordinator::new(1);
+
+        assert_eq!(
+            coordinator.process(okx, Kind::Books, Event::Reconnecting(okx)),
+            vec![CoordinatedEvent::Reconnecting(okx)]
+        );
+        assert!(
+            coordinator
+                .process(okx, Kind::Trades, Event::Item(1))
+                .is_empty()
+        );
+        assert!(
+            coordinator
+                .process(okx, Kind::Trades, Event::Item(2))
+                .is_empty()
+        );
+        assert!(coordinator.is_reconnecting(&okx));
+        assert_eq!(
+            coordinator.process(okx, Kind::Books, Event::Item(3)),
+            vec![
+                CoordinatedEvent::Reconnected(okx),
+                CoordinatedEvent::Item(3)
+            ]
+        );
+        assert!(!coordinator.is_reconnecting(&okx));
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+pub mod coordinator;
 pub mod stream;
 
 /// [`ReconnectingStream`](stream::ReconnectingStream) `Event` that communicates either `Stream::Item`, or that the inner
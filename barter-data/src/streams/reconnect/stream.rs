@@ -1,5 +1,5 @@
 use crate::streams::{consumer::StreamKey, reconnect::Event};
-use barter_integration::channel::Tx;
+use barter_integration::{channel::Tx, stream::stats::StreamStats};
 use derive_more::Constructor;
 use futures::Stream;
 use futures_util::StreamExt;
@@ -53,6 +53,20 @@ where
             .filter_map(|result| future::ready(result.ok()))
     }
 
+    /// Records every re-initialised inner [`Stream`] (ie/ all but the first) as a reconnection
+    /// in the provided [`StreamStats`].
+    fn with_reconnect_stats<St>(self, stats: StreamStats) -> impl Stream<Item = St>
+    where
+        Self: Stream<Item = St>,
+    {
+        self.enumerate().map(move |(index, stream)| {
+            if index > 0 {
+                stats.record_reconnect();
+            }
+            stream
+        })
+    }
+
     /// Terminates the inner [`Stream`] if the encountered error is determined to be unrecoverable
     /// by the provided closure. This will cause the [`ReconnectingStream`] to re-initialise the
     /// inner [`Stream`].
@@ -204,3 +218,72 @@ impl ReconnectionState {
         tokio::time::sleep(sleep_duration)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{
+        Transformer, error::SocketError, protocol::StreamParser, stream::ExchangeStream,
+    };
+    use std::collections::VecDeque;
+
+    #[derive(Debug)]
+    struct TestParser;
+
+    impl StreamParser<u64> for TestParser {
+        type Stream = futures::stream::Iter<std::vec::IntoIter<Result<u64, SocketError>>>;
+        type Message = u64;
+        type Error = SocketError;
+
+        fn parse(input: Result<Self::Message, Self::Error>) -> Option<Result<u64, SocketError>> {
+            Some(input)
+        }
+    }
+
+    #[derive(Debug)]
+    struct TestTransformer;
+
+    impl Transformer for TestTransformer {
+        type Error = SocketError;
+        type Input = u64;
+        type Output = u64;
+        type OutputIter = Vec<Result<u64, SocketError>>;
+
+        fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+            vec![Ok(input)]
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reconnecting_stream_stats() {
+        let stats = StreamStats::default();
+        let stream_stats = stats.clone();
+
+        let streams = init_reconnecting_stream(move || {
+            let stats = stream_stats.clone();
+            async move {
+                Ok::<_, SocketError>(
+                    ExchangeStream::<TestParser, _, _>::new(
+                        futures::stream::iter(vec![Ok(1), Err(SocketError::Sink), Ok(2)]),
+                        TestTransformer,
+                        VecDeque::new(),
+                    )
+                    .with_stats(stats),
+                )
+            }
+        })
+        .await
+        .unwrap()
+        .filter_map(|result| future::ready(result.ok()))
+        .with_reconnect_stats(stats.clone());
+
+        // Initial stream plus one reconnection
+        let outputs = streams.take(2).flatten().collect::<Vec<_>>().await;
+        assert_eq!(outputs.len(), 6);
+
+        assert_eq!(stats.messages(), 6);
+        assert_eq!(stats.parse_errors(), 2);
+        assert_eq!(stats.reconnects(), 1);
+        assert!(stats.time_last_message().is_some());
+    }
+}
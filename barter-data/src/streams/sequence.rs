@@ -0,0 +1,256 @@
+use crate::{
+    event::{DataKind, MarketEvent},
+    streams::{consumer::MarketStreamEvent, reconnect},
+    subscription::{
+        book::{Bbo, OrderBookEvent, OrderBookL1},
+        candle::Candle,
+        liquidation::Liquidation,
+        trade::PublicTrade,
+    },
+};
+use barter_instrument::exchange::ExchangeId;
+use fnv::FnvHashMap;
+use futures::Stream;
+use futures_util::StreamExt;
+use std::hash::Hash;
+
+/// Market event kinds that are sequenced independently by a [`MarketEventSequencer`].
+pub trait SequenceKind {
+    /// Returns the name of the market event kind, used to key the sequence.
+    fn sequence_kind(&self) -> &'static str;
+}
+
+impl SequenceKind for DataKind {
+    fn sequence_kind(&self) -> &'static str {
+        self.kind_name()
+    }
+}
+
+impl SequenceKind for PublicTrade {
+    fn sequence_kind(&self) -> &'static str {
+        "public_trade"
+    }
+}
+
+impl SequenceKind for OrderBookL1 {
+    fn sequence_kind(&self) -> &'static str {
+        "l1"
+    }
+}
+
+impl SequenceKind for Bbo {
+    fn sequence_kind(&self) -> &'static str {
+        "bbo"
+    }
+}
+
+impl SequenceKind for OrderBookEvent {
+    fn sequence_kind(&self) -> &'static str {
+        "l2"
+    }
+}
+
+impl SequenceKind for Candle {
+    fn sequence_kind(&self) -> &'static str {
+        "candle"
+    }
+}
+
+impl SequenceKind for Liquidation {
+    fn sequence_kind(&self) -> &'static str {
+        "liquidation"
+    }
+}
+
+/// Stamps a monotonically increasing `sequence` on every [`MarketEvent`], per
+/// `(exchange, instrument, kind)`.
+///
+/// Persisting consumers can use the sequence to detect dropped events and request backfill.
+///
+/// Sequences start at `0`, and are reset for every instrument of an exchange when that exchange
+/// is [`Reconnecting`](reconnect::Event::Reconnecting). The `Reconnecting` event is passed
+/// through untouched, signalling the reset to downstream consumers.
+#[derive(Debug, Clone)]
+pub struct MarketEventSequencer<InstrumentKey> {
+    sequences: FnvHashMap<(ExchangeId, InstrumentKey, &'static str), u64>,
+}
+
+impl<InstrumentKey> Default for MarketEventSequencer<InstrumentKey> {
+    fn default() -> Self {
+        Self {
+            sequences: FnvHashMap::default(),
+        }
+    }
+}
+
+impl<InstrumentKey> MarketEventSequencer<InstrumentKey>
+where
+    InstrumentKey: Clone + Eq + Hash,
+{
+    /// Returns the next sequence for the [`MarketEvent`] `(exchange, instrument, kind)`.
+    pub fn next_sequence<Kind>(&mut self, event: &MarketEvent<InstrumentKey, Kind>) -> u64
+    where
+        Kind: SequenceKind,
+    {
+        let next = self
+            .sequences
+            .entry((
+                event.exchange,
+                event.instrument.clone(),
+                event.kind.sequence_kind(),
+            ))
+            .or_default();
+
+        let sequence = *next;
+        *next += 1;
+        sequence
+    }
+
+    /// Reset the sequences of every instrument & kind of the provided exchange.
+    pub fn reset(&mut self, exchange: ExchangeId) {
+        self.sequences
+            .retain(|(sequenced_exchange, _, _), _| *sequenced_exchange != exchange);
+    }
+
+    /// Apply sequencing to a [`MarketStreamEvent`].
+    ///
+    /// A [`Reconnecting`](reconnect::Event::Reconnecting) event resets the sequences of the
+    /// reconnecting exchange, and is passed through untouched.
+    pub fn apply<Kind>(
+        &mut self,
+        event: MarketStreamEvent<InstrumentKey, Kind>,
+    ) -> MarketStreamEvent<InstrumentKey, Kind>
+    where
+        Kind: SequenceKind,
+    {
+        match event {
+            reconnect::Event::Item(event) => {
+                let sequence = self.next_sequence(&event);
+                reconnect::Event::Item(event.with_sequence(sequence))
+            }
+            reconnect::Event::Reconnecting(exchange) => {
+                self.reset(exchange);
+                reconnect::Event::Reconnecting(exchange)
+            }
+        }
+    }
+}
+
+/// Stamp a per-`(exchange, instrument, kind)` sequence on every [`MarketEvent`] of a
+/// [`MarketStreamEvent`] `Stream` using a [`MarketEventSequencer`].
+///
+/// See [`MarketEventSequencer`] for more information.
+pub fn sequence_events<St, InstrumentKey, Kind>(
+    stream: St,
+) -> impl Stream<Item = MarketStreamEvent<InstrumentKey, Kind>>
+where
+    St: Stream<Item = MarketStreamEvent<InstrumentKey, Kind>>,
+    InstrumentKey: Clone + Eq + Hash,
+    Kind: SequenceKind,
+{
+    stream.scan(MarketEventSequencer::default(), |sequencer, event| {
+        std::future::ready(Some(sequencer.apply(event)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::Side;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn event(
+        instrument: &'static str,
+        kind: DataKind,
+    ) -> MarketStreamEvent<&'static str, DataKind> {
+        reconnect::Event::Item(MarketEvent {
+            time_exchange: DateTime::<Utc>::MIN_UTC,
+            time_received: DateTime::<Utc>::MIN_UTC,
+            time_processed: None,
+            sequence: None,
+            exchange: ExchangeId::BinanceSpot,
+            instrument,
+            kind,
+        })
+    }
+
+    fn trade(instrument: &'static str) -> MarketStreamEvent<&'static str, DataKind> {
+        event(
+            instrument,
+            DataKind::Trade(PublicTrade {
+                id: "1".to_string(),
+                price: dec!(100),
+                amount: dec!(1),
+                side: Side::Buy,
+            }),
+        )
+    }
+
+    fn l1(instrument: &'static str) -> MarketStreamEvent<&'static str, DataKind> {
+        event(instrument, DataKind::OrderBookL1(OrderBookL1::default()))
+    }
+
+    #[tokio::test]
+    async fn test_sequence_events_increase_per_instrument_and_reset_on_reconnecting() {
+        let input = vec![
+            trade("btc_usdt"),
+            trade("eth_usdt"),
+            trade("btc_usdt"),
+            l1("btc_usdt"),
+            trade("btc_usdt"),
+            reconnect::Event::Reconnecting(ExchangeId::BinanceSpot),
+            trade("eth_usdt"),
+            trade("btc_usdt"),
+        ];
+
+        let output = sequence_events(futures::stream::iter(input))
+            .map(|event| match event {
+                reconnect::Event::Item(event) => Some((
+                    event.instrument,
+                    event.kind.kind_name(),
+                    event.sequence.unwrap(),
+                )),
+                reconnect::Event::Reconnecting(_) => None,
+            })
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            output,
+            vec![
+                Some(("btc_usdt", "public_trade", 0)),
+                Some(("eth_usdt", "public_trade", 0)),
+                Some(("btc_usdt", "public_trade", 1)),
+                Some(("btc_usdt", "l1", 0)),
+                Some(("btc_usdt", "public_trade", 2)),
+                // Reconnecting boundary is passed through, signalling the reset
+                None,
+                Some(("eth_usdt", "public_trade", 0)),
+                Some(("btc_usdt", "public_trade", 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_market_event_sequencer_reset_only_affects_reconnecting_exchange() {
+        let mut sequencer = MarketEventSequencer::default();
+
+        let reconnect::Event::Item(binance) = trade("btc_usdt") else {
+            unreachable!()
+        };
+        let okx = MarketEvent {
+            exchange: ExchangeId::Okx,
+            ..binance.clone()
+        };
+
+        assert_eq!(sequencer.next_sequence(&binance), 0);
+        assert_eq!(sequencer.next_sequence(&okx), 0);
+        assert_eq!(sequencer.next_sequence(&okx), 1);
+
+        sequencer.reset(ExchangeId::BinanceSpot);
+
+        assert_eq!(sequencer.next_sequence(&binance), 0);
+        assert_eq!(sequencer.next_sequence(&okx), 2);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{error::DataError, event::MarketEvent, streams::consumer::MarketStreamResult};
+use chrono::TimeDelta;
+use futures::Stream;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// Default maximum deviation of a [`MarketEvent`] `time_exchange` from its `time_received`
+/// tolerated by an [`EventTimeGuard`].
+pub const DEFAULT_EVENT_TIME_TOLERANCE: TimeDelta = TimeDelta::minutes(5);
+
+/// How an [`EventTimeGuard`] handles a [`MarketEvent`] whose `time_exchange` is outside the
+/// tolerance.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum EventTimePolicy {
+    /// Replace the [`MarketEvent`] with a [`DataError::EventTimeOutOfTolerance`].
+    ///
+    /// This error is not terminal, so the `MarketStream` consumer logs it and drops the event.
+    #[default]
+    Reject,
+
+    /// Clamp the `time_exchange` to the nearest bound of the tolerance window, and pass the
+    /// [`MarketEvent`] through.
+    Clamp,
+}
+
+/// Guards against exchange [`MarketEvent`]s with implausibly old or future `time_exchange`
+/// timestamps (eg/ epoch 0), which would otherwise poison event-driven clocks and latency
+/// statistics.
+///
+/// A [`MarketEvent`] is out of tolerance if its `time_exchange` is more than `max_past` before,
+/// or more than `max_future` after, its `time_received`. Out of tolerance events are handled
+/// according to the configured [`EventTimePolicy`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct EventTimeGuard {
+    pub max_past: TimeDelta,
+    pub max_future: TimeDelta,
+    pub policy: EventTimePolicy,
+}
+
+impl Default for EventTimeGuard {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_TIME_TOLERANCE, EventTimePolicy::default())
+    }
+}
+
+impl EventTimeGuard {
+    /// Construct a new [`Self`] that tolerates a `time_exchange` deviation of up to `tolerance`
+    /// in either direction.
+    pub fn new(tolerance: TimeDelta, policy: EventTimePolicy) -> Self {
+        let tolerance = tolerance.abs();
+        Self {
+            max_past: tolerance,
+            max_future: tolerance,
+            policy,
+        }
+    }
+
+    /// Check the `time_exchange` of a [`MarketEvent`], applying the [`EventTimePolicy`] if it is
+    /// out of tolerance.
+    ///
+    /// If the tolerance window overflows the representable time range, the event is treated as
+    /// out of tolerance and rejected, since there is no bound to clamp to.
+    pub fn check<InstrumentKey, Kind>(
+        &self,
+        mut event: MarketEvent<InstrumentKey, Kind>,
+    ) -> Result<MarketEvent<InstrumentKey, Kind>, DataError> {
+        let window = event
+            .time_received
+            .checked_sub_signed(self.max_past)
+            .zip(event.time_received.checked_add_signed(self.max_future));
+
+        if let Some((earliest, latest)) = window
+            && (earliest..=latest).contains(&event.time_exchange)
+        {
+            return Ok(event);
+        }
+
+        match (self.policy, window) {
+            (EventTimePolicy::Reject, _) | (EventTimePolicy::Clamp, None) => {
+                Err(DataError::EventTimeOutOfTolerance {
+                    exchange: event.exchange,
+                    time_exchange: event.time_exchange,
+                    time_received: event.time_received,
+                })
+            }
+            (EventTimePolicy::Clamp, Some((earliest, latest))) => {
+                let clamped = event.time_exchange.clamp(earliest, latest);
+                warn!(
+                    exchange = %event.exchange,
+                    time_exchange = %event.time_exchange,
+                    time_received = %event.time_received,
+                    %clamped,
+                    "MarketEvent time_exchange outside tolerance, clamping"
+                );
+                event.time_exchange = clamped;
+                Ok(event)
+            }
+        }
+    }
+
+    /// Apply the [`EventTimeGuard`] to a [`MarketStreamResult`].
+    ///
+    /// Reconnection events & errors are passed through untouched.
+    pub fn apply<InstrumentKey, Kind>(
+        &self,
+        event: MarketStreamResult<InstrumentKey, Kind>,
+    ) -> MarketStreamResult<InstrumentKey, Kind> {
+        event.map(|result| result.and_then(|event| self.check(event)))
+    }
+}
+
+/// Guard the `time_exchange` of every [`MarketEvent`] in a [`MarketStreamResult`] `Stream` using
+/// the provided [`EventTimeGuard`].
+///
+/// See [`EventTimeGuard`] for more information.
+pub fn guard_event_times<St, InstrumentKey, Kind>(
+    stream: St,
+    guard: EventTimeGuard,
+) -> impl Stream<Item = MarketStreamResult<InstrumentKey, Kind>>
+where
+    St: Stream<Item = MarketStreamResult<InstrumentKey, Kind>>,
+{
+    stream.map(move |event| guard.apply(event))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{streams::reconnect, subscription::trade::PublicTrade};
+    use barter_instrument::{Side, exchange::ExchangeId};
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn event(
+        time_exchange: DateTime<Utc>,
+        time_received: DateTime<Utc>,
+    ) -> MarketEvent<&'static str, PublicTrade> {
+        MarketEvent {
+            time_exchange,
+            time_received,
+            time_processed: None,
+            sequence: None,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: "btc_usdt",
+            kind: PublicTrade {
+                id: "1".to_string(),
+                price: dec!(100),
+                amount: dec!(1),
+                side: Side::Buy,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_guard_event_times_rejects_out_of_tolerance_events() {
+        let received = DateTime::<Utc>::from_timestamp(1_750_000_000, 0).unwrap();
+        let epoch = DateTime::<Utc>::UNIX_EPOCH;
+        let future = received + TimeDelta::days(365);
+        let valid = received - TimeDelta::seconds(1);
+
+        let input = vec![
+            reconnect::Event::Item(Ok(event(valid, received))),
+            reconnect::Event::Item(Ok(event(epoch, received))),
+            reconnect::Event::Reconnecting(ExchangeId::BinanceSpot),
+            reconnect::Event::Item(Ok(event(future, received))),
+        ];
+
+        let guard = EventTimeGuard::new(TimeDelta::seconds(5), EventTimePolicy::Reject);
+        let output = guard_event_times(futures::stream::iter(input), guard)
+            .collect::<Vec<_>>()
+            .await;
+
+        assert_eq!(
+            output,
+            vec![
+                reconnect::Event::Item(Ok(event(valid, received))),
+                reconnect::Event::Item(Err(DataError::EventTimeOutOfTolerance {
+                    exchange: ExchangeId::BinanceSpot,
+                    time_exchange: epoch,
+                    time_received: received,
+                })),
+                reconnect::Event::Reconnecting(ExchangeId::BinanceSpot),
+                reconnect::Event::Item(Err(DataError::EventTimeOutOfTolerance {
+                    exchange: ExchangeId::BinanceSpot,
+                    time_exchange: future,
+                    time_received: received,
+                })),
+            ]
+        );
+
+        // Rejected events are dropped by the consumer rather than terminating the stream
+        let reconnect::Event::Item(Err(error)) = &output[1] else {
+            panic!("expected DataError")
+        };
+        assert!(!error.is_terminal());
+    }
+
+    #[test]
+    fn test_event_time_guard_clamps_out_of_tolerance_events() {
+        let received = DateTime::<Utc>::from_timestamp(1_750_000_000, 0).unwrap();
+        let guard = EventTimeGuard::new(TimeDelta::seconds(5), EventTimePolicy::Clamp);
+
+        // Epoch 0 is clamped to the earliest tolerated time
+        assert_eq!(
+            guard
+                .check(event(DateTime::<Utc>::UNIX_EPOCH, received))
+                .unwrap()
+                .time_exchange,
+            received - TimeDelta::seconds(5)
+        );
+
+        // Far future is clamped to the latest tolerated time
+        assert_eq!(
+            guard
+                .check(event(received + TimeDelta::days(365), received))
+                .unwrap()
+                .time_exchange,
+            received + TimeDelta::seconds(5)
+        );
+
+        // Within tolerance is untouched
+        assert_eq!(
+            guard
+                .check(event(received + TimeDelta::seconds(5), received))
+                .unwrap()
+                .time_exchange,
+            received + TimeDelta::seconds(5)
+        );
+    }
+
+    #[test]
+    fn test_event_time_guard_rejects_overflowing_tolerance_window() {
+        let received = DateTime::<Utc>::MAX_UTC;
+
+        for policy in [EventTimePolicy::Reject, EventTimePolicy::Clamp] {
+            let guard = EventTimeGuard::new(TimeDelta::seconds(5), policy);
+
+            assert_eq!(
+                guard.check(event(received, received)),
+                Err(DataError::EventTimeOutOfTolerance {
+                    exchange: ExchangeId::BinanceSpot,
+                    time_exchange: received,
+                    time_received: received,
+                }),
+                "{policy:?} failed"
+            );
+        }
+    }
+}
@@ -4,7 +4,7 @@ use crate::{
     instrument::InstrumentData,
     subscription::{Map, Subscription, SubscriptionKind, SubscriptionMeta},
 };
-use barter_integration::subscription::SubscriptionId;
+use barter_integration::{protocol::websocket::WsMessage, subscription::SubscriptionId};
 use fnv::FnvHashMap;
 use serde::{Deserialize, Serialize};
 
@@ -24,6 +24,9 @@ pub trait SubscriptionMapper {
 
 /// Standard [`SubscriptionMapper`] for
 /// [`WebSocket`](barter_integration::protocol::websocket::WebSocket)s suitable for most exchanges.
+///
+/// [`Subscription`]s are chunked into batches of at most [`Connector::SUBSCRIPTION_BATCH_LIMIT`],
+/// with the subscription payloads of every batch sent over the same socket.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub struct WebSocketSubMapper;
 
@@ -45,27 +48,33 @@ impl SubscriptionMapper for WebSocketSubMapper {
             Default::default(),
         ));
 
-        // Map Barter Subscriptions to exchange specific subscriptions
-        let exchange_subs = subscriptions
-            .iter()
-            .map(|subscription| {
-                // Translate Barter Subscription to exchange specific subscription
-                let exchange_sub = ExchangeSub::new(subscription);
+        // Chunk Barter Subscriptions into batches the exchange server accepts in one message
+        let ws_subscriptions = subscriptions
+            .chunks(Exchange::SUBSCRIPTION_BATCH_LIMIT.max(1))
+            .flat_map(|batch| {
+                // Map Barter Subscriptions to exchange specific subscriptions
+                let exchange_subs = batch
+                    .iter()
+                    .map(|subscription| {
+                        // Translate Barter Subscription to exchange specific subscription
+                        let exchange_sub = ExchangeSub::new(subscription);
 
-                // Determine the SubscriptionId associated with this exchange specific subscription
-                let subscription_id = exchange_sub.id();
+                        // Determine the SubscriptionId associated with this exchange specific subscription
+                        let subscription_id = exchange_sub.id();
 
-                // Use ExchangeSub SubscriptionId as the manager to this Barter Subscription
-                instrument_map
-                    .0
-                    .insert(subscription_id, subscription.instrument.key().clone());
+                        // Use ExchangeSub SubscriptionId as the manager to this Barter Subscription
+                        instrument_map
+                            .0
+                            .insert(subscription_id, subscription.instrument.key().clone());
 
-                exchange_sub
-            })
-            .collect::<Vec<ExchangeSub<Exchange::Channel, Exchange::Market>>>();
+                        exchange_sub
+                    })
+                    .collect::<Vec<ExchangeSub<Exchange::Channel, Exchange::Market>>>();
 
-        // Construct WebSocket message subscriptions requests
-        let ws_subscriptions = Exchange::requests(exchange_subs);
+                // Construct WebSocket message subscriptions requests for this batch
+                Exchange::requests(exchange_subs)
+            })
+            .collect::<Vec<WsMessage>>();
 
         SubscriptionMeta {
             instrument_map,
@@ -73,3 +82,62 @@ impl SubscriptionMapper for WebSocketSubMapper {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::bybit::{SUBSCRIPTION_BATCH_LIMIT_BYBIT, spot::BybitSpot},
+        subscription::trade::PublicTrades,
+    };
+    use barter_instrument::instrument::market_data::{
+        MarketDataInstrument, kind::MarketDataInstrumentKind,
+    };
+
+    #[test]
+    fn test_websocket_sub_mapper_chunks_subscriptions_into_batches() {
+        let subscriptions = (0..25)
+            .map(|index| {
+                Subscription::<BybitSpot, MarketDataInstrument, PublicTrades>::new(
+                    BybitSpot::default(),
+                    MarketDataInstrument::from((
+                        format!("base{index}").as_str(),
+                        "usdt",
+                        MarketDataInstrumentKind::Spot,
+                    )),
+                    PublicTrades,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let SubscriptionMeta {
+            instrument_map,
+            ws_subscriptions,
+        } = WebSocketSubMapper::map(&subscriptions);
+
+        // 25 Subscriptions with a batch limit of 10 are split into 3 subscribe frames
+        assert_eq!(SUBSCRIPTION_BATCH_LIMIT_BYBIT, 10);
+        assert_eq!(ws_subscriptions.len(), 3);
+        assert_eq!(BybitSpot::expected_responses(&instrument_map), 3);
+
+        // Every Subscription is mapped, regardless of which batch it was sent in
+        assert_eq!(instrument_map.0.len(), subscriptions.len());
+        assert!(subscriptions.iter().all(|subscription| {
+            instrument_map
+                .0
+                .values()
+                .any(|instrument| instrument == &subscription.instrument)
+        }));
+
+        // Each frame contains at most SUBSCRIPTION_BATCH_LIMIT_BYBIT args
+        let batch_sizes = ws_subscriptions
+            .iter()
+            .map(|message| {
+                let payload =
+                    serde_json::from_str::<serde_json::Value>(message.to_text().unwrap()).unwrap();
+                payload["args"].as_array().unwrap().len()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(batch_sizes, vec![10, 10, 5]);
+    }
+}
@@ -1,5 +1,8 @@
 use super::SubscriptionKind;
-use crate::books::{Level, OrderBook, mid_price, volume_weighted_mid_price};
+use crate::{
+    books::{Level, OrderBook, mid_price, volume_weighted_mid_price},
+    event::MarketIter,
+};
 use barter_macro::{DeSubKind, SerSubKind};
 use chrono::{DateTime, Utc};
 use derive_more::Constructor;
@@ -62,6 +65,94 @@ impl OrderBookL1 {
     }
 }
 
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields lightweight
+/// [`Bbo`] (best-bid-offer) market events.
+///
+/// Subscribes to the lightest available BBO channel per exchange (eg/ Binance `bookTicker`).
+/// Exchanges without a dedicated BBO channel derive each [`Bbo`] from their L1 channel, skipping
+/// updates where either side of the book is empty.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, DeSubKind, SerSubKind,
+)]
+pub struct BestBidOffer;
+
+impl SubscriptionKind for BestBidOffer {
+    type Event = Bbo;
+    fn as_str(&self) -> &'static str {
+        "bbo"
+    }
+}
+
+impl std::fmt::Display for BestBidOffer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Normalised Barter minimal best-bid-offer containing only the best bid and ask price & size.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Constructor,
+)]
+pub struct Bbo {
+    pub time: DateTime<Utc>,
+    pub bid: Decimal,
+    pub bid_qty: Decimal,
+    pub ask: Decimal,
+    pub ask_qty: Decimal,
+}
+
+impl Bbo {
+    /// Derive a [`Bbo`] from an [`OrderBookL1`], returning `None` if either side is empty.
+    pub fn from_l1(l1: &OrderBookL1) -> Option<Self> {
+        let (best_bid, best_ask) = l1.best_bid.zip(l1.best_ask)?;
+        Some(Self {
+            time: l1.last_update_time,
+            bid: best_bid.price,
+            bid_qty: best_bid.amount,
+            ask: best_ask.price,
+            ask_qty: best_ask.amount,
+        })
+    }
+
+    /// Calculate the mid-price by taking the average of the best bid and ask prices.
+    pub fn mid_price(&self) -> Decimal {
+        mid_price(self.bid, self.ask)
+    }
+
+    /// Calculate the spread between the best ask and bid prices.
+    pub fn spread(&self) -> Decimal {
+        self.ask - self.bid
+    }
+}
+
+impl<InstrumentKey> From<MarketIter<InstrumentKey, OrderBookL1>>
+    for MarketIter<InstrumentKey, Bbo>
+{
+    fn from(MarketIter(events): MarketIter<InstrumentKey, OrderBookL1>) -> Self {
+        events
+            .into_iter()
+            .filter_map(|result| match result {
+                Ok(event) => {
+                    let bbo = Bbo::from_l1(&event.kind)?;
+                    Some(Ok(event.map_kind(|_| bbo)))
+                }
+                Err(error) => Some(Err(error)),
+            })
+            .collect()
+    }
+}
+
 /// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields L2
 /// [`OrderBookEvent`] market events
 ///
@@ -33,4 +33,12 @@ pub struct Candle {
     pub close: f64,
     pub volume: f64,
     pub trade_count: u64,
+    /// `true` if the [`Candle`] interval has closed and this is the final update, `false` if
+    /// this is an update of an in-progress [`Candle`].
+    #[serde(default = "default_is_closed")]
+    pub is_closed: bool,
+}
+
+fn default_is_closed() -> bool {
+    true
 }
@@ -0,0 +1,76 @@
+use super::{SubscriptionKind, candle::Candle};
+use serde::{Deserialize, Serialize};
+
+/// Barter [`Subscription`](super::Subscription) [`SubscriptionKind`] that yields exchange native
+/// [`Candle`] [`MarketEvent<T>`](crate::event::MarketEvent) events for the configured
+/// [`KlineInterval`].
+///
+/// Both in-progress and closed [`Candle`] updates are yielded, distinguished via
+/// [`Candle::is_closed`].
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub struct Klines {
+    pub interval: KlineInterval,
+}
+
+impl Klines {
+    /// Construct a new [`Klines`] [`SubscriptionKind`] for the provided [`KlineInterval`].
+    pub fn new(interval: KlineInterval) -> Self {
+        Self { interval }
+    }
+}
+
+impl SubscriptionKind for Klines {
+    type Event = Candle;
+
+    fn as_str(&self) -> &'static str {
+        "klines"
+    }
+}
+
+impl std::fmt::Display for Klines {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", self.as_str(), self.interval.as_str())
+    }
+}
+
+/// [`Klines`] candle interval.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum KlineInterval {
+    #[default]
+    #[serde(rename = "1m")]
+    Minute1,
+    #[serde(rename = "5m")]
+    Minute5,
+    #[serde(rename = "15m")]
+    Minute15,
+    #[serde(rename = "1h")]
+    Hour1,
+    #[serde(rename = "4h")]
+    Hour4,
+    #[serde(rename = "1d")]
+    Day1,
+}
+
+impl KlineInterval {
+    /// Return the &str representation of this [`KlineInterval`] (eg/ "1m").
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KlineInterval::Minute1 => "1m",
+            KlineInterval::Minute5 => "5m",
+            KlineInterval::Minute15 => "15m",
+            KlineInterval::Hour1 => "1h",
+            KlineInterval::Hour4 => "4h",
+            KlineInterval::Day1 => "1d",
+        }
+    }
+}
+
+impl std::fmt::Display for KlineInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -20,6 +20,9 @@ pub mod book;
 /// Candle [`SubscriptionKind`] and the associated Barter output data model.
 pub mod candle;
 
+/// Exchange native kline [`SubscriptionKind`] that yields interval [`Candle`](candle::Candle)s.
+pub mod kline;
+
 /// Liquidation [`SubscriptionKind`] and the associated Barter output data model.
 pub mod liquidation;
 
@@ -335,6 +338,27 @@ mod tests {
 
     mod subscription {
         use super::*;
+
+        #[test]
+        fn test_subscription_json_msgpack_round_trip() {
+            use barter_integration::msgpack::{from_msgpack, to_msgpack};
+
+            let subscription = Subscription::<ExchangeId, MarketDataInstrument, SubKind>::new(
+                ExchangeId::BinanceSpot,
+                MarketDataInstrument::from(("btc", "usdt", MarketDataInstrumentKind::Spot)),
+                SubKind::PublicTrades,
+            );
+
+            let bytes = to_msgpack(&subscription).unwrap();
+            let from_msgpack = from_msgpack::<Subscription>(&bytes).unwrap();
+            assert_eq!(from_msgpack, subscription);
+
+            let json = serde_json::to_string(&subscription).unwrap();
+            assert_eq!(
+                serde_json::from_str::<Subscription>(&json).unwrap(),
+                from_msgpack
+            );
+        }
         use crate::{
             exchange::{coinbase::Coinbase, okx::Okx},
             subscription::trade::PublicTrades,
@@ -0,0 +1,506 @@
+use super::ExchangeTransformer;
+use crate::{
+    Identifier,
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::Connector,
+    subscription::{
+        Map,
+        book::OrderBookL1,
+        trade::{PublicTrade, PublicTrades},
+    },
+};
+use async_trait::async_trait;
+use barter_instrument::{Side, exchange::ExchangeId};
+use barter_integration::{
+    Transformer, protocol::websocket::WsMessage, subscription::SubscriptionId,
+};
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{hash::Hash, marker::PhantomData};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// Normalised public trade for venues that may omit the aggressor [`Side`].
+///
+/// Converted into a [`PublicTrade`] by the [`AggressorTransformer`], which infers the [`Side`]
+/// if it is not provided.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize, Serialize)]
+pub struct UnsidedPublicTrade {
+    pub id: String,
+    pub price: Decimal,
+    pub amount: Decimal,
+    pub side: Option<Side>,
+}
+
+/// Input of an [`AggressorTransformer`], received over the same connection.
+///
+/// Exchange specific `Book` top of book updates (ie/ the exchange L1 stream) are consumed to
+/// maintain the best bid & ask used by the quote rule, and are not output.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum AggressorInput<Trades, Book> {
+    Trades(Trades),
+    Book(Book),
+}
+
+impl<Trades, Book> Identifier<Option<SubscriptionId>> for AggressorInput<Trades, Book>
+where
+    Trades: Identifier<Option<SubscriptionId>>,
+    Book: Identifier<Option<SubscriptionId>>,
+{
+    fn id(&self) -> Option<SubscriptionId> {
+        match self {
+            Self::Trades(trades) => trades.id(),
+            Self::Book(book) => book.id(),
+        }
+    }
+}
+
+/// Per-instrument aggressor [`Side`] inference for trades without an explicit side.
+///
+/// Sides are inferred using the following rules, in order:
+/// 1. **Provided**: the exchange provided [`Side`] is passed through.
+/// 2. **Quote rule**: if a mid price is known (see [`Self::update_bbo`]), trades above the mid
+///    are buys and trades below the mid are sells.
+/// 3. **Tick rule**: trades at a higher price than the previous trade are buys, and trades at a
+///    lower price are sells. Trades at the same price inherit the previous trade [`Side`].
+///
+/// If no rule applies (eg/ the first trade of an instrument with no known mid), the [`Side`] is
+/// unknown.
+#[derive(Debug, Clone)]
+pub struct AggressorSideInference<InstrumentKey> {
+    states: FnvHashMap<InstrumentKey, AggressorState>,
+}
+
+impl<InstrumentKey> Default for AggressorSideInference<InstrumentKey> {
+    fn default() -> Self {
+        Self {
+            states: FnvHashMap::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct AggressorState {
+    mid: Option<Decimal>,
+    last_price: Option<Decimal>,
+    last_side: Option<Side>,
+}
+
+impl<InstrumentKey> AggressorSideInference<InstrumentKey>
+where
+    InstrumentKey: Eq + Hash,
+{
+    /// Update the last known best bid & ask of an instrument, used by the quote rule.
+    pub fn update_bbo(&mut self, instrument: InstrumentKey, best_bid: Decimal, best_ask: Decimal) {
+        self.states.entry(instrument).or_default().mid = best_bid
+            .checked_add(best_ask)
+            .and_then(|sum| sum.checked_div(Decimal::TWO))
+            .filter(|mid| mid.is_sign_positive() && !mid.is_zero());
+    }
+
+    /// Update the last known best bid & ask of an instrument from the next [`OrderBookL1`].
+    ///
+    /// If either side of the book is empty, the quote rule is disabled until both are known.
+    pub fn update_from_l1(&mut self, instrument: InstrumentKey, book: &OrderBookL1) {
+        match (book.best_bid, book.best_ask) {
+            (Some(best_bid), Some(best_ask)) => {
+                self.update_bbo(instrument, best_bid.price, best_ask.price)
+            }
+            _ => self.states.entry(instrument).or_default().mid = None,
+        }
+    }
+
+    /// Determine the aggressor [`Side`] of the next trade of an instrument, passing through the
+    /// `provided` [`Side`] if present.
+    ///
+    /// Returns `None` if the [`Side`] is not provided and cannot be inferred.
+    pub fn side(
+        &mut self,
+        instrument: InstrumentKey,
+        price: Decimal,
+        provided: Option<Side>,
+    ) -> Option<Side> {
+        let state = self.states.entry(instrument).or_default();
+
+        let side = provided
+            .or_else(|| {
+                state
+                    .mid
+                    .and_then(|mid| Self::side_from_price_change(mid, price))
+            })
+            .or_else(|| {
+                state
+                    .last_price
+                    .and_then(|last_price| Self::side_from_price_change(last_price, price))
+            })
+            .or(state.last_side);
+
+        state.last_price = Some(price);
+        state.last_side = side;
+
+        side
+    }
+
+    fn side_from_price_change(reference: Decimal, price: Decimal) -> Option<Side> {
+        match price.cmp(&reference) {
+            std::cmp::Ordering::Greater => Some(Side::Buy),
+            std::cmp::Ordering::Less => Some(Side::Sell),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
+
+/// [`ExchangeTransformer`] for [`PublicTrades`] streams of venues that may omit the trade
+/// aggressor [`Side`].
+///
+/// Exchange specific `Trades` are first translated into [`UnsidedPublicTrade`]s, and any missing
+/// [`Side`] is inferred by the [`AggressorSideInference`] maintained per [`SubscriptionId`]. Exchange
+/// specific `Book` updates received over the same connection feed the best bid & ask used by the
+/// quote rule.
+///
+/// Trades with a [`Side`] that is not provided and cannot be inferred are dropped.
+#[derive(Debug)]
+pub struct AggressorTransformer<Exchange, InstrumentKey, Trades, Book> {
+    instrument_map: Map<InstrumentKey>,
+    inference: AggressorSideInference<SubscriptionId>,
+    phantom: PhantomData<(Exchange, Trades, Book)>,
+}
+
+impl<Exchange, InstrumentKey, Trades, Book>
+    AggressorTransformer<Exchange, InstrumentKey, Trades, Book>
+{
+    /// Construct a new [`Self`] using the provided `instrument_map`.
+    pub fn new(instrument_map: Map<InstrumentKey>) -> Self {
+        Self {
+            instrument_map,
+            inference: AggressorSideInference::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<Exchange, InstrumentKey, Trades, Book>
+    ExchangeTransformer<Exchange, InstrumentKey, PublicTrades>
+    for AggressorTransformer<Exchange, InstrumentKey, Trades, Book>
+where
+    Exchange: Connector + Send,
+    InstrumentKey: Clone + Send,
+    Trades: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de> + Send,
+    Book: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de> + Send,
+    MarketIter<InstrumentKey, UnsidedPublicTrade>: From<(ExchangeId, InstrumentKey, Trades)>,
+    MarketIter<InstrumentKey, OrderBookL1>: From<(ExchangeId, InstrumentKey, Book)>,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        _: &[MarketEvent<InstrumentKey, PublicTrade>],
+        _: mpsc::UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        Ok(Self::new(instrument_map))
+    }
+}
+
+impl<Exchange, InstrumentKey, Trades, Book> Transformer
+    for AggressorTransformer<Exchange, InstrumentKey, Trades, Book>
+where
+    Exchange: Connector,
+    InstrumentKey: Clone,
+    Trades: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    Book: Identifier<Option<SubscriptionId>> + for<'de> Deserialize<'de>,
+    MarketIter<InstrumentKey, UnsidedPublicTrade>: From<(ExchangeId, InstrumentKey, Trades)>,
+    MarketIter<InstrumentKey, OrderBookL1>: From<(ExchangeId, InstrumentKey, Book)>,
+{
+    type Error = DataError;
+    type Input = AggressorInput<Trades, Book>;
+    type Output = MarketEvent<InstrumentKey, PublicTrade>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        // Determine if the message has an identifiable SubscriptionId
+        let subscription_id = match input.id() {
+            Some(subscription_id) => subscription_id,
+            None => return vec![],
+        };
+
+        // Find Instrument associated with Input and transform
+        let instrument = match self.instrument_map.find(&subscription_id) {
+            Ok(instrument) => instrument.clone(),
+            Err(unidentifiable) => return vec![Err(DataError::from(unidentifiable))],
+        };
+
+        let trades = match input {
+            AggressorInput::Trades(trades) => trades,
+            AggressorInput::Book(book) => {
+                return MarketIter::<InstrumentKey, OrderBookL1>::from((
+                    Exchange::ID,
+                    instrument,
+                    book,
+                ))
+                .0
+                .into_iter()
+                .filter_map(|result| match result {
+                    Ok(event) => {
+                        self.inference
+                            .update_from_l1(subscription_id.clone(), &event.kind);
+                        None
+                    }
+                    Err(error) => Some(Err(error)),
+                })
+                .collect();
+            }
+        };
+
+        MarketIter::<InstrumentKey, UnsidedPublicTrade>::from((Exchange::ID, instrument, trades))
+            .0
+            .into_iter()
+            .filter_map(|result| {
+                let event = match result {
+                    Ok(event) => event,
+                    Err(error) => return Some(Err(error)),
+                };
+
+                let Some(side) =
+                    self.inference
+                        .side(subscription_id.clone(), event.kind.price, event.kind.side)
+                else {
+                    debug!(
+                        exchange = %event.exchange,
+                        trade_id = %event.kind.id,
+                        "AggressorTransformer dropping trade with unknown aggressor side"
+                    );
+                    return None;
+                };
+
+                Some(Ok(MarketEvent {
+                    time_exchange: event.time_exchange,
+                    time_received: event.time_received,
+                    time_processed: event.time_processed,
+                    sequence: event.sequence,
+                    exchange: event.exchange,
+                    instrument: event.instrument,
+                    kind: PublicTrade {
+                        id: event.kind.id,
+                        price: event.kind.price,
+                        amount: event.kind.amount,
+                        side,
+                    },
+                }))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{books::Level, exchange::coinbase::Coinbase};
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    #[derive(Debug, Deserialize)]
+    struct TestTrade {
+        price: Decimal,
+        side: Option<Side>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct TestBook {
+        best_bid: Decimal,
+        best_ask: Decimal,
+    }
+
+    impl Identifier<Option<SubscriptionId>> for TestTrade {
+        fn id(&self) -> Option<SubscriptionId> {
+            Some(SubscriptionId::from("trades|BTC-USD"))
+        }
+    }
+
+    impl Identifier<Option<SubscriptionId>> for TestBook {
+        fn id(&self) -> Option<SubscriptionId> {
+            Some(SubscriptionId::from("trades|BTC-USD"))
+        }
+    }
+
+    impl From<(ExchangeId, &'static str, TestTrade)> for MarketIter<&'static str, UnsidedPublicTrade> {
+        fn from((exchange, instrument, trade): (ExchangeId, &'static str, TestTrade)) -> Self {
+            Self(vec![Ok(MarketEvent {
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                time_received: DateTime::<Utc>::MIN_UTC,
+                time_processed: None,
+                sequence: None,
+                exchange,
+                instrument,
+                kind: UnsidedPublicTrade {
+                    id: trade.price.to_string(),
+                    price: trade.price,
+                    amount: dec!(1),
+                    side: trade.side,
+                },
+            })])
+        }
+    }
+
+    impl From<(ExchangeId, &'static str, TestBook)> for MarketIter<&'static str, OrderBookL1> {
+        fn from((exchange, instrument, book): (ExchangeId, &'static str, TestBook)) -> Self {
+            Self(vec![Ok(MarketEvent {
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                time_received: DateTime::<Utc>::MIN_UTC,
+                time_processed: None,
+                sequence: None,
+                exchange,
+                instrument,
+                kind: OrderBookL1 {
+                    last_update_time: DateTime::<Utc>::MIN_UTC,
+                    best_bid: Some(Level::new(book.best_bid, dec!(1))),
+                    best_ask: Some(Level::new(book.best_ask, dec!(1))),
+                },
+            })])
+        }
+    }
+
+    type TestTransformer = AggressorTransformer<Coinbase, &'static str, TestTrade, TestBook>;
+
+    fn transformer() -> TestTransformer {
+        AggressorTransformer::new(Map::from_iter([(
+            SubscriptionId::from("trades|BTC-USD"),
+            "btc_usd",
+        )]))
+    }
+
+    fn sides(
+        transformer: &mut TestTransformer,
+        trades: impl IntoIterator<Item = (Decimal, Option<Side>)>,
+    ) -> Vec<Side> {
+        trades
+            .into_iter()
+            .flat_map(|(price, side)| {
+                transformer.transform(AggressorInput::Trades(TestTrade { price, side }))
+            })
+            .map(|result| result.unwrap().kind.side)
+            .collect()
+    }
+
+    #[test]
+    fn test_aggressor_transformer_passes_through_provided_side() {
+        let mut transformer = transformer();
+
+        // Provided sides are used even if they contradict the tick rule
+        assert_eq!(
+            sides(
+                &mut transformer,
+                [
+                    (dec!(100), Some(Side::Sell)),
+                    (dec!(101), Some(Side::Sell)),
+                    (dec!(99), Some(Side::Buy)),
+                ]
+            ),
+            vec![Side::Sell, Side::Sell, Side::Buy]
+        );
+    }
+
+    #[test]
+    fn test_aggressor_transformer_infers_side_with_tick_rule() {
+        let mut transformer = transformer();
+
+        assert_eq!(
+            sides(
+                &mut transformer,
+                [
+                    // No reference price, so side is unknown & trade is dropped
+                    (dec!(100), None),
+                    // Down-tick
+                    (dec!(99.5), None),
+                    // Zero-tick inherits previous side
+                    (dec!(99.5), None),
+                    // Up-tick
+                    (dec!(100.5), None),
+                    // Zero-tick inherits previous side
+                    (dec!(100.5), None),
+                    // Provided side updates the reference for subsequent zero-ticks
+                    (dec!(100.5), Some(Side::Sell)),
+                    (dec!(100.5), None),
+                ]
+            ),
+            vec![
+                Side::Sell,
+                Side::Sell,
+                Side::Buy,
+                Side::Buy,
+                Side::Sell,
+                Side::Sell,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aggressor_transformer_infers_side_with_quote_rule_from_book() {
+        let mut transformer = transformer();
+
+        // Book updates are consumed, not output
+        assert!(
+            transformer
+                .transform(AggressorInput::Book(TestBook {
+                    best_bid: dec!(99),
+                    best_ask: dec!(101),
+                }))
+                .is_empty()
+        );
+
+        assert_eq!(
+            sides(
+                &mut transformer,
+                [
+                    // Above mid, despite being the first trade
+                    (dec!(100.5), None),
+                    // Below mid, even though the tick rule would infer the same
+                    (dec!(99.5), None),
+                    // Below mid, even though the tick rule would infer Buy
+                    (dec!(99.8), None),
+                    // At mid, fall back to the tick rule
+                    (dec!(100), None),
+                ]
+            ),
+            vec![Side::Buy, Side::Sell, Side::Sell, Side::Buy]
+        );
+    }
+
+    #[test]
+    fn test_aggressor_side_inference_quote_rule() {
+        let mut inference = AggressorSideInference::default();
+        inference.update_bbo("btc_usd", dec!(99), dec!(101));
+
+        // Above & below mid, even if tick rule would disagree
+        assert_eq!(
+            inference.side("btc_usd", dec!(100.5), None),
+            Some(Side::Buy)
+        );
+        assert_eq!(
+            inference.side("btc_usd", dec!(99.5), None),
+            Some(Side::Sell)
+        );
+        assert_eq!(
+            inference.side("btc_usd", dec!(99.8), None),
+            Some(Side::Sell)
+        );
+
+        // At mid, fall back to the tick rule
+        assert_eq!(inference.side("btc_usd", dec!(100), None), Some(Side::Buy));
+
+        // One sided book disables the quote rule
+        inference.update_from_l1(
+            "btc_usd",
+            &OrderBookL1 {
+                last_update_time: DateTime::<Utc>::MIN_UTC,
+                best_bid: Some(Level::new(dec!(99), dec!(1))),
+                best_ask: None,
+            },
+        );
+        assert_eq!(
+            inference.side("btc_usd", dec!(99.9), None),
+            Some(Side::Sell)
+        );
+    }
+}
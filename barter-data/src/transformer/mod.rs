@@ -11,6 +11,10 @@ use tokio::sync::mpsc;
 /// [`PublicTrades`](crate::subscription::trade::PublicTrades) streams.
 pub mod stateless;
 
+/// [`ExchangeTransformer`] for [`PublicTrades`](crate::subscription::trade::PublicTrades) streams
+/// that infers the trade aggressor side when the exchange omits it.
+pub mod aggressor;
+
 /// Defines how to construct a [`Transformer`] used by [`MarketStream`](super::MarketStream)s to
 /// translate exchange specific types to normalised Barter types.
 #[async_trait]
@@ -28,4 +32,10 @@ where
         initial_snapshots: &[MarketEvent<InstrumentKey, Kind::Event>],
         ws_sink_tx: mpsc::UnboundedSender<WsMessage>,
     ) -> Result<Self, DataError>;
+
+    /// Take any [`MarketEvent`]s generated by [`Self::init`] (eg/ a snapshot fetched by the
+    /// transformer itself), which are emitted before any other event of the market stream.
+    fn take_init_events(&mut self) -> Vec<MarketEvent<InstrumentKey, Kind::Event>> {
+        Vec::new()
+    }
 }
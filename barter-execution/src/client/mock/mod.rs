@@ -3,10 +3,15 @@ use crate::{
     balance::AssetBalance,
     client::ExecutionClient,
     error::{ConnectivityError, UnindexedClientError, UnindexedOrderError},
-    exchange::mock::request::MockExchangeRequest,
+    exchange::mock::{
+        fee::{FeeModel, FeeSchedule},
+        request::MockExchangeRequest,
+    },
     order::{
         Order, OrderEvent, OrderKey,
-        request::{OrderRequestCancel, OrderRequestOpen, UnindexedOrderResponseCancel},
+        request::{
+            OrderRequestBracket, OrderRequestCancel, OrderRequestOpen, UnindexedOrderResponseCancel,
+        },
         state::Open,
     },
     trade::Trade,
@@ -21,6 +26,7 @@ use derive_more::Constructor;
 use futures::stream::BoxStream;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use tokio::sync::{broadcast, mpsc, oneshot};
 use tokio_stream::{StreamExt, wrappers::BroadcastStream};
 use tracing::error;
@@ -32,7 +38,87 @@ pub struct MockExecutionConfig {
     pub mocked_exchange: ExchangeId,
     pub initial_state: UnindexedAccountSnapshot,
     pub latency_ms: u64,
-    pub fees_percent: Decimal,
+    /// Optional fees percent charged on fills. Defaults to the taker fees of the
+    /// `mocked_exchange` in the default [`FeeSchedule`] if not provided.
+    #[serde(default)]
+    pub fees_percent: Option<Decimal>,
+    /// Optional fees percent charged on post-only (maker) fills, which may be negative to
+    /// model maker rebates. Defaults to `fees_percent` if provided, otherwise the maker fees of
+    /// the `mocked_exchange` in the default [`FeeSchedule`].
+    #[serde(default)]
+    pub fees_maker_percent: Option<Decimal>,
+    /// Optional VIP tier used to look up the default [`FeeSchedule`] fees.
+    #[serde(default)]
+    pub fee_tier: Option<u8>,
+    /// Price at which the [`MockExchange`](crate::exchange::mock::MockExchange) fills orders.
+    #[serde(default)]
+    pub fill_price: MockFillPrice,
+    /// Position mode of the [`MockExchange`](crate::exchange::mock::MockExchange), used to
+    /// clamp reduce-only orders. Should match the position mode the Engine tracks positions in.
+    #[serde(default)]
+    pub position_mode: MockPositionMode,
+}
+
+impl MockExecutionConfig {
+    /// Returns the [`FeeModel`] used by the [`MockExchange`](crate::exchange::mock::MockExchange).
+    ///
+    /// Configured fees take precedence over the default [`FeeSchedule`] rates of the
+    /// `mocked_exchange`. If neither are available, no fees are charged.
+    pub fn fee_model(&self) -> FeeModel {
+        match self.fees_percent {
+            Some(fees_percent) => FeeModel::new(
+                self.fees_maker_percent.unwrap_or(fees_percent),
+                fees_percent,
+            ),
+            None => {
+                let default = FeeSchedule::default()
+                    .fee_model(self.mocked_exchange, self.fee_tier)
+                    .unwrap_or_default();
+
+                FeeModel::new(
+                    self.fees_maker_percent.unwrap_or(default.maker_percent),
+                    default.taker_percent,
+                )
+            }
+        }
+    }
+}
+
+/// Price at which the [`MockExchange`](crate::exchange::mock::MockExchange) fills orders.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum MockFillPrice {
+    /// Fill orders at their request price.
+    ///
+    /// Used when backtesting, where orders are priced from the same market data the
+    /// `MockExchange` would otherwise use.
+    #[default]
+    Request,
+    /// Fill orders at the latest market price of the instrument, provided via
+    /// [`MockExchangeRequest::market_price`]. Orders are rejected until a market price is
+    /// available.
+    ///
+    /// Used to "paper trade" against a live market data stream, simulating fills without
+    /// sending real orders.
+    Market,
+}
+
+/// Position mode of the [`MockExchange`](crate::exchange::mock::MockExchange), determining the
+/// position a reduce-only order reduces.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum MockPositionMode {
+    /// One net position per instrument. Reduce-only orders are clamped to the net position.
+    #[default]
+    Netting,
+    /// Independent long and short positions per instrument (eg/ Binance hedge mode).
+    ///
+    /// Trades that are not reduce-only increase the position of their side, and reduce-only
+    /// trades reduce the position of the opposite side. Reduce-only orders are therefore clamped
+    /// to the opposite side's position, regardless of the net position.
+    Hedging,
 }
 
 #[derive(Debug, Constructor)]
@@ -214,6 +300,7 @@ where
                 quantity: request.state.quantity,
                 kind: request.state.kind,
                 time_in_force: request.state.time_in_force,
+                reduce_only: request.state.reduce_only,
                 state: Err(UnindexedOrderError::Connectivity(
                     ConnectivityError::ExchangeOffline(self.mocked_exchange),
                 )),
@@ -229,6 +316,7 @@ where
                 quantity: request.state.quantity,
                 kind: request.state.kind,
                 time_in_force: request.state.time_in_force,
+                reduce_only: request.state.reduce_only,
                 state: Err(UnindexedOrderError::Connectivity(
                     ConnectivityError::ExchangeOffline(self.mocked_exchange),
                 )),
@@ -236,6 +324,56 @@ where
         })
     }
 
+    async fn open_bracket_order(
+        &self,
+        request: OrderRequestBracket<ExchangeId, &InstrumentNameExchange>,
+    ) -> Option<Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>> {
+        let (response_tx, response_rx) = oneshot::channel();
+
+        let request = into_owned_request(request);
+        let entry = request.entry();
+
+        let offline = || Order {
+            key: entry.key.clone(),
+            side: entry.state.side,
+            price: entry.state.price,
+            quantity: entry.state.quantity,
+            kind: entry.state.kind,
+            time_in_force: entry.state.time_in_force,
+            reduce_only: entry.state.reduce_only,
+            state: Err(UnindexedOrderError::Connectivity(
+                ConnectivityError::ExchangeOffline(self.mocked_exchange),
+            )),
+        };
+
+        if self
+            .request_tx
+            .send(MockExchangeRequest::open_bracket_order(
+                self.time_request(),
+                response_tx,
+                request,
+            ))
+            .is_err()
+        {
+            return Some(offline());
+        }
+
+        Some(response_rx.await.unwrap_or_else(|_| offline()))
+    }
+
+    async fn cancel_all_after(&self, timeout: Duration) -> Result<(), UnindexedClientError> {
+        self.request_tx
+            .send(MockExchangeRequest::cancel_all_after(
+                self.time_request(),
+                timeout,
+            ))
+            .map_err(|_| {
+                UnindexedClientError::Connectivity(ConnectivityError::ExchangeOffline(
+                    self.mocked_exchange,
+                ))
+            })
+    }
+
     async fn fetch_balances(
         &self,
         assets: &[AssetNameExchange],
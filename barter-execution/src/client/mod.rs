@@ -1,10 +1,12 @@
 use crate::{
     UnindexedAccountEvent, UnindexedAccountSnapshot,
     balance::AssetBalance,
-    error::{UnindexedClientError, UnindexedOrderError},
+    error::{ApiError, UnindexedClientError, UnindexedOrderError},
     order::{
-        Order,
-        request::{OrderRequestCancel, OrderRequestOpen, UnindexedOrderResponseCancel},
+        Order, OrderKey,
+        request::{
+            OrderRequestBracket, OrderRequestCancel, OrderRequestOpen, UnindexedOrderResponseCancel,
+        },
         state::Open,
     },
     trade::Trade,
@@ -16,7 +18,7 @@ use barter_instrument::{
 };
 use chrono::{DateTime, Utc};
 use futures::Stream;
-use std::future::Future;
+use std::{future::Future, time::Duration};
 
 mod binance;
 pub mod mock;
@@ -80,6 +82,55 @@ where
         )
     }
 
+    /// Open an [`OrderRequestBracket`] entry order with attached take-profit & stop-loss
+    /// contingent orders, returning the entry order response.
+    ///
+    /// Contingent orders are reported via the account stream once the entry fills.
+    ///
+    /// Defaults to rejecting the entry order for exchanges without bracket order support.
+    fn open_bracket_order(
+        &self,
+        request: OrderRequestBracket<ExchangeId, &InstrumentNameExchange>,
+    ) -> impl Future<
+        Output = Option<
+            Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
+        >,
+    > + Send {
+        let entry = request.state.entry;
+        std::future::ready(Some(Order {
+            key: OrderKey {
+                exchange: request.key.exchange,
+                instrument: request.key.instrument.clone(),
+                strategy: request.key.strategy,
+                cid: request.key.cid,
+            },
+            side: entry.side,
+            price: entry.price,
+            quantity: entry.quantity,
+            kind: entry.kind,
+            time_in_force: entry.time_in_force,
+            reduce_only: entry.reduce_only,
+            state: Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(
+                format!("{} does not support bracket orders", Self::EXCHANGE),
+            ))),
+        }))
+    }
+
+    /// Send the exchange dead-man's switch heartbeat, requesting all open orders be cancelled if
+    /// no further heartbeat is received within the provided `timeout`.
+    ///
+    /// Defaults to [`ClientError::Unsupported`](crate::error::ClientError::Unsupported) for
+    /// exchanges without a cancel-all-after API.
+    fn cancel_all_after(
+        &self,
+        timeout: Duration,
+    ) -> impl Future<Output = Result<(), UnindexedClientError>> + Send {
+        std::future::ready(Err(UnindexedClientError::Unsupported(format!(
+            "{} does not support cancel all orders after {timeout:?}",
+            Self::EXCHANGE
+        ))))
+    }
+
     fn fetch_balances(
         &self,
         assets: &[AssetNameExchange],
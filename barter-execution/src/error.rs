@@ -1,3 +1,4 @@
+use crate::order::{TimeInForce, id::ClientOrderId};
 use barter_instrument::{
     asset::{AssetIndex, name::AssetNameExchange},
     exchange::ExchangeId,
@@ -41,6 +42,12 @@ pub enum ClientError<AssetKey = AssetIndex, InstrumentKey = InstrumentIndex> {
     /// Failed to initialise an AccountStream.
     #[error("failed to init AccountStream: {0}")]
     AccountStream(String),
+
+    /// Request is not supported by the exchange or client.
+    ///
+    /// eg/ Dead-man's switch heartbeat on an exchange without a cancel-all-after API.
+    #[error("unsupported: {0}")]
+    Unsupported(String),
 }
 
 /// Represents all connectivity-centric errors.
@@ -93,10 +100,26 @@ pub enum ApiError<AssetKey = AssetIndex, InstrumentKey = InstrumentIndex> {
     BalanceInsufficient(AssetKey, String),
     #[error("order rejected: {0}")]
     OrderRejected(String),
+    #[error("order not found")]
+    OrderNotFound,
     #[error("order already cancelled")]
     OrderAlreadyCancelled,
     #[error("order already fully filled")]
     OrderAlreadyFullyFilled,
+    /// An open request with the same [`ClientOrderId`] was recently sent, so this request was
+    /// rejected to prevent the same order being placed twice.
+    #[error("duplicate ClientOrderId: {0}")]
+    DuplicateClientOrderId(ClientOrderId),
+    #[error("{0}")]
+    TimeInForceUnsupported(#[from] TimeInForceUnsupported),
+}
+
+/// [`TimeInForce`] is not supported by the exchange, so the order was rejected before being sent.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+#[error("exchange {exchange} does not support time in force: {time_in_force}")]
+pub struct TimeInForceUnsupported {
+    pub exchange: ExchangeId,
+    pub time_in_force: TimeInForce,
 }
 
 /// Represents all errors that can be generated when cancelling or opening orders.
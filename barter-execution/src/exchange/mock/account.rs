@@ -9,6 +9,7 @@ use crate::{
     trade::Trade,
 };
 use barter_instrument::{
+    Side,
     asset::{QuoteAsset, name::AssetNameExchange},
     exchange::ExchangeId,
     instrument::name::InstrumentNameExchange,
@@ -16,6 +17,7 @@ use barter_instrument::{
 use chrono::{DateTime, Utc};
 use derive_more::Constructor;
 use fnv::FnvHashMap;
+use rust_decimal::Decimal;
 
 #[derive(Debug, Constructor)]
 pub struct AccountState {
@@ -53,6 +55,24 @@ impl AccountState {
         self.orders_cancelled.values()
     }
 
+    pub fn insert_order_open(&mut self, order: Order<ExchangeId, InstrumentNameExchange, Open>) {
+        self.orders_open.insert(order.key.cid.clone(), order);
+    }
+
+    pub fn remove_order_open(
+        &mut self,
+        cid: &ClientOrderId,
+    ) -> Option<Order<ExchangeId, InstrumentNameExchange, Open>> {
+        self.orders_open.remove(cid)
+    }
+
+    pub fn insert_order_cancelled(
+        &mut self,
+        order: Order<ExchangeId, InstrumentNameExchange, Cancelled>,
+    ) {
+        self.orders_cancelled.insert(order.key.cid.clone(), order);
+    }
+
     pub fn trades(
         &self,
         time_since: DateTime<Utc>,
@@ -62,6 +82,44 @@ impl AccountState {
             .filter(move |trade| trade.time_exchange >= time_since)
     }
 
+    /// Net position quantity of the provided instrument, derived from all acknowledged trades.
+    ///
+    /// Positive for a net long position, negative for a net short position.
+    pub fn position(&self, instrument: &InstrumentNameExchange) -> Decimal {
+        self.trades
+            .iter()
+            .filter(|trade| &trade.instrument == instrument)
+            .map(|trade| trade.side.sign() * trade.quantity.abs())
+            .sum()
+    }
+
+    /// Hedged position quantity of the provided instrument's `position_side` (`Side::Buy` for the
+    /// long position, `Side::Sell` for the short position), derived from all acknowledged trades.
+    ///
+    /// Trades that are not reduce-only increase the position of their side, and reduce-only
+    /// trades reduce the position of the opposite side.
+    pub fn position_hedged(
+        &self,
+        instrument: &InstrumentNameExchange,
+        position_side: Side,
+    ) -> Decimal {
+        self.trades
+            .iter()
+            .filter(|trade| &trade.instrument == instrument)
+            .filter_map(|trade| {
+                if trade.reduce_only {
+                    (trade.side.opposite() == position_side).then(|| -trade.quantity.abs())
+                } else {
+                    (trade.side == position_side).then(|| trade.quantity.abs())
+                }
+            })
+            .sum()
+    }
+
+    pub fn balance(&self, asset: &AssetNameExchange) -> Option<&AssetBalance<AssetNameExchange>> {
+        self.balances.get(asset)
+    }
+
     pub fn balance_mut(
         &mut self,
         asset: &AssetNameExchange,
@@ -102,6 +160,7 @@ impl From<UnindexedAccountSnapshot> for AccountState {
                                     quantity: order.quantity,
                                     kind: order.kind,
                                     time_in_force: order.time_in_force,
+                                    reduce_only: order.reduce_only,
                                     state: open,
                                 },
                             );
@@ -116,6 +175,7 @@ impl From<UnindexedAccountSnapshot> for AccountState {
                                     quantity: order.quantity,
                                     kind: order.kind,
                                     time_in_force: order.time_in_force,
+                                    reduce_only: order.reduce_only,
                                     state: cancelled,
                                 },
                             );
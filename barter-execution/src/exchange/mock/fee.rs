@@ -0,0 +1,230 @@
+use crate::order::TimeInForce;
+use barter_instrument::exchange::ExchangeId;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Default `(exchange, VIP tier, maker fees, taker fees)` rates used by
+/// [`FeeSchedule::default`], with fees expressed as a fraction of the order notional value.
+///
+/// A `None` tier is the default (non-VIP) tier of the exchange. Rates are the published base
+/// rates without any discounts (eg/ paying fees with an exchange token), so update this table
+/// as venues change their fee schedules.
+pub const DEFAULT_FEE_SCHEDULE: &[(ExchangeId, Option<u8>, &str, &str)] = &[
+    (ExchangeId::BinanceSpot, None, "0.001", "0.001"),
+    (ExchangeId::BinanceSpot, Some(1), "0.0009", "0.001"),
+    (ExchangeId::BinanceSpot, Some(2), "0.0008", "0.001"),
+    (ExchangeId::BinanceFuturesUsd, None, "0.0002", "0.0005"),
+    (ExchangeId::BinanceFuturesUsd, Some(1), "0.00016", "0.0004"),
+    (ExchangeId::BinanceFuturesUsd, Some(2), "0.00014", "0.00035"),
+    (ExchangeId::BinanceFuturesCoin, None, "0.0002", "0.0005"),
+    (ExchangeId::Bitfinex, None, "0.001", "0.002"),
+    (ExchangeId::Bitmex, None, "0.0002", "0.00075"),
+    (ExchangeId::BybitSpot, None, "0.001", "0.001"),
+    (ExchangeId::BybitPerpetualsUsd, None, "0.0002", "0.00055"),
+    (ExchangeId::Coinbase, None, "0.004", "0.006"),
+    (ExchangeId::GateioSpot, None, "0.002", "0.002"),
+    (ExchangeId::GateioPerpetualsUsd, None, "0.0002", "0.0005"),
+    (ExchangeId::Kraken, None, "0.0025", "0.004"),
+    (ExchangeId::Okx, None, "0.0008", "0.001"),
+];
+
+/// [`MockExchange`](super::MockExchange) fee model, with fees expressed as a fraction of the
+/// order notional value.
+///
+/// Negative fees are rebates paid by the exchange (eg/ maker rebates), which reduce the
+/// balance required to fill an order and increase the realised PnL of the resulting trade.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct FeeModel {
+    /// Fees charged on fills that provide liquidity.
+    pub maker_percent: Decimal,
+    /// Fees charged on fills that take liquidity.
+    pub taker_percent: Decimal,
+}
+
+impl FeeModel {
+    /// Construct a new [`Self`] with the provided maker & taker fees.
+    pub fn new(maker_percent: Decimal, taker_percent: Decimal) -> Self {
+        Self {
+            maker_percent,
+            taker_percent,
+        }
+    }
+
+    /// Construct a new [`Self`] charging the same fees for maker & taker fills.
+    pub fn flat(fees_percent: Decimal) -> Self {
+        Self::new(fees_percent, fees_percent)
+    }
+
+    /// Returns `true` if a fill of an order with the provided [`TimeInForce`] is modelled as
+    /// providing liquidity.
+    ///
+    /// Only post-only orders are guaranteed to never take liquidity, so all other orders are
+    /// modelled as taker fills.
+    pub fn is_maker(time_in_force: &TimeInForce) -> bool {
+        matches!(
+            time_in_force,
+            TimeInForce::GoodUntilCancelled { post_only: true }
+        )
+    }
+
+    /// Returns the fees percent charged on a fill of an order with the provided [`TimeInForce`].
+    pub fn fees_percent(&self, time_in_force: &TimeInForce) -> Decimal {
+        if Self::is_maker(time_in_force) {
+            self.maker_percent
+        } else {
+            self.taker_percent
+        }
+    }
+}
+
+/// [`FeeSchedule`] entry defining the [`FeeModel`] of an exchange VIP tier.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct FeeScheduleEntry {
+    pub exchange: ExchangeId,
+    /// VIP tier, where `None` is the default (non-VIP) tier of the exchange.
+    #[serde(default)]
+    pub tier: Option<u8>,
+    pub maker_percent: Decimal,
+    pub taker_percent: Decimal,
+}
+
+/// Registry of [`FeeModel`]s keyed by [`ExchangeId`] and optional VIP tier.
+///
+/// Used as the source of default fees for the [`MockExchange`](super::MockExchange) if no fees
+/// are configured. [`FeeSchedule::default`] contains the rates of the [`DEFAULT_FEE_SCHEDULE`],
+/// and custom schedules can be loaded from a collection of [`FeeScheduleEntry`]s.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FeeSchedule {
+    fees: FnvHashMap<(ExchangeId, Option<u8>), FeeModel>,
+}
+
+impl Default for FeeSchedule {
+    fn default() -> Self {
+        DEFAULT_FEE_SCHEDULE
+            .iter()
+            .map(|(exchange, tier, maker, taker)| FeeScheduleEntry {
+                exchange: *exchange,
+                tier: *tier,
+                maker_percent: Decimal::from_str_exact(maker)
+                    .expect("DEFAULT_FEE_SCHEDULE maker fees must be a valid Decimal"),
+                taker_percent: Decimal::from_str_exact(taker)
+                    .expect("DEFAULT_FEE_SCHEDULE taker fees must be a valid Decimal"),
+            })
+            .collect()
+    }
+}
+
+impl FromIterator<FeeScheduleEntry> for FeeSchedule {
+    fn from_iter<Iter: IntoIterator<Item = FeeScheduleEntry>>(iter: Iter) -> Self {
+        iter.into_iter()
+            .fold(Self::empty(), |schedule, entry| schedule.with_entry(entry))
+    }
+}
+
+impl FeeSchedule {
+    /// Construct a new [`Self`] containing no fee rates.
+    pub fn empty() -> Self {
+        Self {
+            fees: FnvHashMap::default(),
+        }
+    }
+
+    /// Return [`Self`] with the provided [`FeeScheduleEntry`], overriding any existing rates for
+    /// the same exchange and tier.
+    pub fn with_entry(mut self, entry: FeeScheduleEntry) -> Self {
+        self.fees.insert(
+            (entry.exchange, entry.tier),
+            FeeModel::new(entry.maker_percent, entry.taker_percent),
+        );
+        self
+    }
+
+    /// Returns the [`FeeModel`] of the provided exchange VIP tier.
+    ///
+    /// Falls back to the default tier of the exchange if the VIP tier is not present, and
+    /// returns `None` if the exchange is not present.
+    pub fn fee_model(&self, exchange: ExchangeId, tier: Option<u8>) -> Option<FeeModel> {
+        self.fees
+            .get(&(exchange, tier))
+            .or_else(|| self.fees.get(&(exchange, None)))
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_model_fees_percent() {
+        let model = FeeModel::new(Decimal::new(-1, 4), Decimal::new(5, 4));
+
+        assert_eq!(
+            model.fees_percent(&TimeInForce::GoodUntilCancelled { post_only: true }),
+            Decimal::new(-1, 4)
+        );
+        assert_eq!(
+            model.fees_percent(&TimeInForce::GoodUntilCancelled { post_only: false }),
+            Decimal::new(5, 4)
+        );
+        assert_eq!(
+            model.fees_percent(&TimeInForce::ImmediateOrCancel),
+            Decimal::new(5, 4)
+        );
+    }
+
+    #[test]
+    fn test_fee_schedule_default_rates() {
+        let schedule = FeeSchedule::default();
+
+        assert_eq!(
+            schedule.fee_model(ExchangeId::BinanceSpot, None),
+            Some(FeeModel::new(Decimal::new(1, 3), Decimal::new(1, 3)))
+        );
+        assert_eq!(
+            schedule.fee_model(ExchangeId::BinanceFuturesUsd, Some(1)),
+            Some(FeeModel::new(Decimal::new(16, 5), Decimal::new(4, 4)))
+        );
+        assert_eq!(
+            schedule.fee_model(ExchangeId::Kraken, None),
+            Some(FeeModel::new(Decimal::new(25, 4), Decimal::new(4, 3)))
+        );
+
+        // Unknown VIP tier falls back to the default tier
+        assert_eq!(
+            schedule.fee_model(ExchangeId::Okx, Some(9)),
+            schedule.fee_model(ExchangeId::Okx, None)
+        );
+
+        // Unknown exchange has no rates
+        assert_eq!(schedule.fee_model(ExchangeId::Mock, None), None);
+    }
+
+    #[test]
+    fn test_fee_schedule_override_takes_precedence() {
+        let schedule = FeeSchedule::default().with_entry(FeeScheduleEntry {
+            exchange: ExchangeId::BinanceSpot,
+            tier: None,
+            maker_percent: Decimal::new(-1, 4),
+            taker_percent: Decimal::new(5, 4),
+        });
+
+        assert_eq!(
+            schedule.fee_model(ExchangeId::BinanceSpot, None),
+            Some(FeeModel::new(Decimal::new(-1, 4), Decimal::new(5, 4)))
+        );
+
+        // Other tiers & exchanges are unchanged
+        assert_eq!(
+            schedule.fee_model(ExchangeId::BinanceSpot, Some(1)),
+            Some(FeeModel::new(Decimal::new(9, 4), Decimal::new(1, 3)))
+        );
+        assert_eq!(
+            schedule.fee_model(ExchangeId::Coinbase, None),
+            Some(FeeModel::new(Decimal::new(4, 3), Decimal::new(6, 3)))
+        );
+    }
+}
@@ -1,16 +1,21 @@
 use crate::{
     AccountEventKind, InstrumentAccountSnapshot, UnindexedAccountEvent, UnindexedAccountSnapshot,
     balance::AssetBalance,
-    client::mock::MockExecutionConfig,
+    client::mock::{MockExecutionConfig, MockFillPrice, MockPositionMode},
     error::{ApiError, UnindexedApiError, UnindexedOrderError},
     exchange::mock::{
         account::AccountState,
+        fee::FeeModel,
         request::{MockExchangeRequest, MockExchangeRequestKind},
     },
     order::{
-        Order, OrderKind, UnindexedOrder,
-        id::OrderId,
-        request::{OrderRequestCancel, OrderRequestOpen},
+        Order, OrderKind, PegReference, TimeInForce, UnindexedOrder,
+        format::FormattedRequestOpen,
+        id::{ClientOrderId, OrderId},
+        request::{
+            OrderRequestBracket, OrderRequestCancel, OrderRequestOpen, RequestOpen,
+            UnindexedOrderResponseCancel,
+        },
         state::{Cancelled, Open},
     },
     trade::{AssetFees, Trade, TradeId},
@@ -24,29 +29,47 @@ use barter_instrument::{
 use barter_integration::snapshot::Snapshot;
 use chrono::{DateTime, TimeDelta, Utc};
 use fnv::FnvHashMap;
-use futures::stream::BoxStream;
+use futures::{future::Either, stream::BoxStream};
 use itertools::Itertools;
 use rust_decimal::Decimal;
 use smol_str::ToSmolStr;
 use std::fmt::Debug;
-use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::{
+    sync::{broadcast, mpsc, oneshot},
+    time::Instant,
+};
 use tokio_stream::{StreamExt, wrappers::BroadcastStream};
-use tracing::{error, info};
+use tracing::{debug, error, info};
 
 pub mod account;
+pub mod fee;
 pub mod request;
 
 #[derive(Debug)]
 pub struct MockExchange {
     pub exchange: ExchangeId,
     pub latency_ms: u64,
-    pub fees_percent: Decimal,
+    pub fee_model: FeeModel,
     pub request_rx: mpsc::UnboundedReceiver<MockExchangeRequest>,
     pub event_tx: broadcast::Sender<UnindexedAccountEvent>,
     pub instruments: FnvHashMap<InstrumentNameExchange, Instrument<ExchangeId, AssetNameExchange>>,
     pub account: AccountState,
     pub order_sequence: u64,
     pub time_exchange_latest: DateTime<Utc>,
+    /// Open bracket contingent orders, mapped to their (optional) sibling contingent order.
+    pub contingent_orders: FnvHashMap<ClientOrderId, Option<ClientOrderId>>,
+    /// Latest best bid and offer of each instrument, used to price [`OrderKind::Peg`] orders.
+    pub bbos: FnvHashMap<InstrumentNameExchange, (Decimal, Decimal)>,
+    /// Price at which orders are filled.
+    pub fill_price: MockFillPrice,
+    /// Position mode used to clamp reduce-only orders.
+    pub position_mode: MockPositionMode,
+    /// Deadline after which all open orders are cancelled, armed by the latest dead-man's
+    /// switch heartbeat (see [`Self::cancel_all_after`]).
+    pub cancel_all_deadline: Option<Instant>,
+    /// Latest market price of each instrument, used to fill orders if the `fill_price` is
+    /// [`MockFillPrice::Market`].
+    pub market_prices: FnvHashMap<InstrumentNameExchange, Decimal>,
 }
 
 impl MockExchange {
@@ -59,82 +82,187 @@ impl MockExchange {
         Self {
             exchange: config.mocked_exchange,
             latency_ms: config.latency_ms,
-            fees_percent: config.fees_percent,
+            fee_model: config.fee_model(),
             request_rx,
             event_tx,
             instruments,
             account: AccountState::from(config.initial_state),
             order_sequence: 0,
             time_exchange_latest: Default::default(),
+            contingent_orders: FnvHashMap::default(),
+            bbos: FnvHashMap::default(),
+            fill_price: config.fill_price,
+            position_mode: config.position_mode,
+            market_prices: FnvHashMap::default(),
+            cancel_all_deadline: None,
         }
     }
 
     pub async fn run(mut self) {
-        while let Some(request) = self.request_rx.recv().await {
-            self.update_time_exchange(request.time_request);
+        loop {
+            let cancel_all = match self.cancel_all_deadline {
+                Some(deadline) => Either::Left(tokio::time::sleep_until(deadline)),
+                None => Either::Right(std::future::pending()),
+            };
 
-            match request.kind {
-                MockExchangeRequestKind::FetchAccountSnapshot { response_tx } => {
-                    let snapshot = self.account_snapshot();
-                    self.respond_with_latency(response_tx, snapshot);
-                }
-                MockExchangeRequestKind::FetchBalances {
-                    response_tx,
-                    assets,
-                } => {
-                    let balances = self
-                        .account
-                        .balances()
-                        .filter(|balance| assets.contains(&balance.asset))
-                        .cloned()
-                        .collect();
-                    self.respond_with_latency(response_tx, balances);
+            tokio::select! {
+                request = self.request_rx.recv() => match request {
+                    Some(request) => self.process_request(request),
+                    None => break,
+                },
+                () = cancel_all => {
+                    info!(
+                        exchange = %self.exchange,
+                        "MockExchange dead-man's switch expired - cancelling all open orders"
+                    );
+                    self.cancel_all_deadline = None;
+                    let cancelled = self.cancel_all_orders();
+                    let balances = cancelled
+                        .iter()
+                        .map(|order| &order.key.instrument)
+                        .unique()
+                        .filter_map(|instrument| self.build_balance_snapshot_event(instrument))
+                        .collect::<Vec<_>>();
+                    self.send_events_with_latency(
+                        cancelled
+                            .into_iter()
+                            .map(|order| {
+                                self.build_account_event(Snapshot(UnindexedOrder::from(order)))
+                            })
+                            .chain(balances)
+                            .collect(),
+                    );
                 }
-                MockExchangeRequestKind::FetchOrdersOpen {
-                    response_tx,
-                    instruments,
-                } => {
-                    let orders_open = self
-                        .account
-                        .orders_open()
-                        .filter(|order| instruments.contains(&order.key.instrument))
-                        .cloned()
-                        .collect();
-                    self.respond_with_latency(response_tx, orders_open);
+            }
+        }
+
+        info!(exchange = %self.exchange, "MockExchange shutting down");
+    }
+
+    fn process_request(&mut self, request: MockExchangeRequest) {
+        self.update_time_exchange(request.time_request);
+
+        match request.kind {
+            MockExchangeRequestKind::FetchAccountSnapshot { response_tx } => {
+                let snapshot = self.account_snapshot();
+                self.respond_with_latency(response_tx, snapshot);
+            }
+            MockExchangeRequestKind::FetchBalances {
+                response_tx,
+                assets,
+            } => {
+                let balances = self
+                    .account
+                    .balances()
+                    .filter(|balance| assets.contains(&balance.asset))
+                    .cloned()
+                    .collect();
+                self.respond_with_latency(response_tx, balances);
+            }
+            MockExchangeRequestKind::FetchOrdersOpen {
+                response_tx,
+                instruments,
+            } => {
+                let orders_open = self
+                    .account
+                    .orders_open()
+                    .filter(|order| instruments.contains(&order.key.instrument))
+                    .cloned()
+                    .collect();
+                self.respond_with_latency(response_tx, orders_open);
+            }
+            MockExchangeRequestKind::FetchTrades {
+                response_tx,
+                time_since,
+            } => {
+                let trades = self.account.trades(time_since).cloned().collect();
+                self.respond_with_latency(response_tx, trades);
+            }
+            MockExchangeRequestKind::CancelOrder {
+                response_tx,
+                request,
+            } => {
+                let response = self.cancel_order(request);
+                self.respond_with_latency(response_tx, response.clone());
+
+                if response.state.is_ok() {
+                    // Cancelled resting orders release their reserved balance
+                    let balance = self.build_balance_snapshot_event(&response.key.instrument);
+                    self.send_events_with_latency(
+                        std::iter::once(
+                            self.build_account_event(AccountEventKind::OrderCancelled(response)),
+                        )
+                        .chain(balance)
+                        .collect(),
+                    );
                 }
-                MockExchangeRequestKind::FetchTrades {
-                    response_tx,
-                    time_since,
-                } => {
-                    let trades = self.account.trades(time_since).cloned().collect();
-                    self.respond_with_latency(response_tx, trades);
+            }
+            MockExchangeRequestKind::CancelAllAfter { timeout } => {
+                debug!(
+                    exchange = %self.exchange,
+                    ?timeout,
+                    "MockExchange received dead-man's switch heartbeat"
+                );
+                self.cancel_all_after(timeout);
+            }
+            MockExchangeRequestKind::OpenOrder {
+                response_tx,
+                request,
+            } => {
+                let (response, notifications) = self.open_order(request);
+                let instrument = response.key.instrument.clone();
+                let resting = notifications.is_none() && response.state.is_ok();
+                self.respond_with_latency(response_tx, response);
+
+                if let Some(notifications) = notifications {
+                    self.account.ack_trade(notifications.trade.clone());
+                    self.send_notifications_with_latency(notifications);
                 }
-                MockExchangeRequestKind::CancelOrder {
-                    response_tx: _,
-                    request,
-                } => {
-                    error!(
-                        exchange = %self.exchange,
-                        ?request,
-                        "MockExchange received cancel request but only Market orders are supported"
+
+                // Resting orders reserve balance
+                if resting {
+                    self.send_events_with_latency(
+                        self.build_balance_snapshot_event(&instrument)
+                            .into_iter()
+                            .collect(),
                     );
                 }
-                MockExchangeRequestKind::OpenOrder {
-                    response_tx,
-                    request,
-                } => {
-                    let (response, notifications) = self.open_order(request);
-                    self.respond_with_latency(response_tx, response);
-
-                    if let Some(notifications) = notifications {
-                        self.account.ack_trade(notifications.trade.clone());
-                        self.send_notifications_with_latency(notifications);
-                    }
+            }
+            MockExchangeRequestKind::OpenBracketOrder {
+                response_tx,
+                request,
+            } => {
+                let (response, notifications, contingent) = self.open_bracket_order(request);
+                self.respond_with_latency(response_tx, response);
+
+                if let Some(notifications) = notifications {
+                    self.account.ack_trade(notifications.trade.clone());
+                    self.send_notifications_with_latency(notifications);
+                }
+
+                self.send_events_with_latency(
+                    contingent
+                        .into_iter()
+                        .map(|order| {
+                            self.build_account_event(Snapshot(UnindexedOrder::from(order)))
+                        })
+                        .collect(),
+                );
+            }
+            MockExchangeRequestKind::MarketBbo {
+                instrument,
+                best_bid,
+                best_ask,
+            } => {
+                let notifications = self.update_market_bbo(&instrument, best_bid, best_ask);
+                self.send_peg_notifications_with_latency(notifications);
+            }
+            MockExchangeRequestKind::MarketPrice { instrument, price } => {
+                for notifications in self.update_market_price(&instrument, price) {
+                    self.send_contingent_notifications_with_latency(notifications);
                 }
             }
         }
-
-        info!(exchange = %self.exchange, "MockExchange shutting down");
     }
 
     fn update_time_exchange(&mut self, time_request: DateTime<Utc>) {
@@ -244,6 +372,106 @@ impl MockExchange {
         });
     }
 
+    /// Sends the provided [`ContingentOrderNotifications`] via the `MockExchanges`
+    /// `broadcast::Sender<UnindexedAccountEvent>` after waiting for the latency [`Duration`],
+    /// acknowledging the contingent order trade.
+    ///
+    /// Used to simulate network latency between the exchange and client.
+    fn send_contingent_notifications_with_latency(
+        &mut self,
+        notifications: ContingentOrderNotifications,
+    ) {
+        let ContingentOrderNotifications { filled, cancelled } = notifications;
+
+        let mut events = Vec::with_capacity(cancelled.len() + 1);
+        if let Some((order, fill)) = filled {
+            self.account.ack_trade(fill.trade.clone());
+            self.send_notifications_with_latency(fill);
+            events.push(self.build_account_event(Snapshot(UnindexedOrder::from(order))));
+        }
+        events.extend(
+            cancelled
+                .into_iter()
+                .map(|order| self.build_account_event(Snapshot(UnindexedOrder::from(order)))),
+        );
+
+        self.send_events_with_latency(events);
+    }
+
+    /// Sends the provided [`PegOrderNotifications`] via the `MockExchanges`
+    /// `broadcast::Sender<UnindexedAccountEvent>` after waiting for the latency [`Duration`],
+    /// acknowledging the peg order trades.
+    ///
+    /// Used to simulate network latency between the exchange and client.
+    fn send_peg_notifications_with_latency(&mut self, notifications: PegOrderNotifications) {
+        let PegOrderNotifications {
+            repriced,
+            filled,
+            cancelled,
+        } = notifications;
+
+        let repriced_instruments = repriced
+            .iter()
+            .map(|order| order.key.instrument.clone())
+            .collect::<Vec<_>>();
+
+        let mut events = repriced
+            .into_iter()
+            .map(|order| self.build_account_event(Snapshot(UnindexedOrder::from(order))))
+            .collect::<Vec<_>>();
+
+        for (order, fill) in filled {
+            self.account.ack_trade(fill.trade.clone());
+            self.send_notifications_with_latency(fill);
+            events.push(self.build_account_event(Snapshot(UnindexedOrder::from(order))));
+        }
+
+        // Re-priced & cancelled peg orders change their reserved balance
+        let balances = repriced_instruments
+            .iter()
+            .chain(cancelled.iter().map(|order| &order.key.instrument))
+            .unique()
+            .filter_map(|instrument| self.build_balance_snapshot_event(instrument))
+            .collect::<Vec<_>>();
+
+        events.extend(
+            cancelled
+                .into_iter()
+                .map(|order| self.build_account_event(Snapshot(UnindexedOrder::from(order)))),
+        );
+        events.extend(balances);
+
+        self.send_events_with_latency(events);
+    }
+
+    /// Sends the provided `UnindexedAccountEvents` via the `MockExchanges`
+    /// `broadcast::Sender<UnindexedAccountEvent>` after waiting for the latency
+    /// [`Duration`].
+    ///
+    /// Used to simulate network latency between the exchange and client.
+    fn send_events_with_latency(&self, events: Vec<UnindexedAccountEvent>) {
+        if events.is_empty() {
+            return;
+        }
+
+        let exchange = self.exchange;
+        let latency = std::time::Duration::from_millis(self.latency_ms);
+        let tx = self.event_tx.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(latency).await;
+
+            for event in events {
+                if tx.send(event).is_err() {
+                    error!(
+                        %exchange,
+                        kind = "Snapshot<Order<ExchangeId, InstrumentNameExchange, OrderState>>",
+                        "MockExchange failed to send AccountEvent notification to client"
+                    );
+                }
+            }
+        });
+    }
+
     pub fn account_stream(&self) -> BoxStream<'static, UnindexedAccountEvent> {
         futures::StreamExt::boxed(BroadcastStream::new(self.event_tx.subscribe()).map_while(
             |result| match result {
@@ -259,16 +487,83 @@ impl MockExchange {
         ))
     }
 
+    /// Arm the dead-man's switch, cancelling all open orders if no further heartbeat is
+    /// received within the provided `timeout`.
+    ///
+    /// Each heartbeat replaces the previous deadline, and a zero `timeout` disarms the switch.
+    pub fn cancel_all_after(&mut self, timeout: std::time::Duration) {
+        self.cancel_all_deadline = (!timeout.is_zero()).then(|| Instant::now() + timeout);
+    }
+
+    /// Cancel all resting orders (eg/ [`OrderKind::Peg`] and bracket contingent orders).
+    pub fn cancel_all_orders(
+        &mut self,
+    ) -> Vec<Order<ExchangeId, InstrumentNameExchange, Cancelled>> {
+        let cids = self
+            .account
+            .orders_open()
+            .map(|order| order.key.cid.clone())
+            .collect::<Vec<_>>();
+
+        self.contingent_orders.clear();
+
+        cids.into_iter()
+            .filter_map(|cid| {
+                let order = self.account.remove_order_open(&cid)?;
+                self.release_resting_order(&order);
+                Some(self.cancel_resting_order(order))
+            })
+            .collect()
+    }
+
+    /// Cancel a resting order (eg/ [`OrderKind::Peg`] or bracket contingent order).
+    ///
+    /// Rejects the request if no open order matches the [`ClientOrderId`] (and [`OrderId`], if
+    /// provided).
     pub fn cancel_order(
         &mut self,
-        _: OrderRequestCancel<ExchangeId, InstrumentNameExchange>,
-    ) -> Order<ExchangeId, InstrumentNameExchange, Result<Cancelled, UnindexedOrderError>> {
-        unimplemented!()
+        request: OrderRequestCancel<ExchangeId, InstrumentNameExchange>,
+    ) -> UnindexedOrderResponseCancel {
+        let matches_request = |order: &Order<ExchangeId, InstrumentNameExchange, Open>| {
+            order.key.instrument == request.key.instrument
+                && request
+                    .state
+                    .id
+                    .as_ref()
+                    .is_none_or(|id| id == &order.state.id)
+        };
+
+        let state = match self.account.remove_order_open(&request.key.cid) {
+            Some(order) if matches_request(&order) => {
+                self.contingent_orders.remove(&order.key.cid);
+                self.release_resting_order(&order);
+                Ok(self.cancel_resting_order(order).state)
+            }
+            Some(order) => {
+                self.account.insert_order_open(order);
+                Err(UnindexedOrderError::Rejected(ApiError::OrderNotFound))
+            }
+            None if self
+                .account
+                .orders_cancelled()
+                .any(|order| order.key.cid == request.key.cid) =>
+            {
+                Err(UnindexedOrderError::Rejected(
+                    ApiError::OrderAlreadyCancelled,
+                ))
+            }
+            None => Err(UnindexedOrderError::Rejected(ApiError::OrderNotFound)),
+        };
+
+        UnindexedOrderResponseCancel {
+            key: request.key,
+            state,
+        }
     }
 
     pub fn open_order(
         &mut self,
-        request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
+        mut request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
     ) -> (
         Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
         Option<OpenOrderNotifications>,
@@ -277,12 +572,61 @@ impl MockExchange {
             return (build_open_order_err_response(request, error), None);
         }
 
-        let underlying = match self.find_instrument_data(&request.key.instrument) {
-            Ok(instrument) => instrument.underlying.clone(),
+        if let OrderKind::Peg { reference, offset } = request.state.kind {
+            return self.open_peg_order(request, reference, offset);
+        }
+
+        let (underlying, formatted) = match self.find_instrument_data(&request.key.instrument) {
+            Ok(instrument) => (
+                instrument.underlying.clone(),
+                FormattedRequestOpen::new(self.exchange, &request.state, instrument.spec.as_ref()),
+            ),
             Err(error) => return (build_open_order_err_response(request, error), None),
         };
 
+        // Fill at the price & quantity as formatted for submission to the mocked exchange
+        match parse_formatted_request(&formatted) {
+            Ok((price, quantity)) => {
+                request.state.price = price;
+                request.state.quantity = quantity;
+            }
+            Err(error) => return (build_open_order_err_response(request, error), None),
+        }
+
+        if self.fill_price == MockFillPrice::Market {
+            let Some(price) = self.market_prices.get(&request.key.instrument) else {
+                let error = ApiError::OrderRejected(format!(
+                    "MockExchange has no market price to fill {} order at",
+                    request.key.instrument
+                ));
+                return (build_open_order_err_response(request, error), None);
+            };
+            request.state.price = *price;
+        }
+
+        let quantity = if request.state.reduce_only {
+            let position = match self.position_mode {
+                MockPositionMode::Netting => self.account.position(&request.key.instrument),
+                MockPositionMode::Hedging => {
+                    // Reduce-only orders reduce the opposite side's position, signed as a net
+                    // position so it is clamped the same way
+                    let position_side = request.state.side.opposite();
+                    position_side.sign()
+                        * self
+                            .account
+                            .position_hedged(&request.key.instrument, position_side)
+                }
+            };
+            match reduce_only_quantity(position, request.state.side, request.state.quantity) {
+                Ok(quantity) => quantity,
+                Err(error) => return (build_open_order_err_response(request, error), None),
+            }
+        } else {
+            request.state.quantity
+        };
+
         let time_exchange = self.time_exchange();
+        let fees_percent = self.fee_model.fees_percent(&request.state.time_in_force);
 
         let balance_change_result = match request.state.side {
             Side::Buy => {
@@ -292,18 +636,15 @@ impl MockExchange {
                     .balance_mut(&underlying.quote)
                     .expect("MockExchange has Balance for all configured Instrument assets");
 
-                // Currently we only supported MarketKind orders, so they should be identical
-                assert_eq!(current.balance.total, current.balance.free);
-
-                let order_value_quote = request.state.price * request.state.quantity.abs();
-                let order_fees_quote = order_value_quote * self.fees_percent;
+                let order_value_quote = request.state.price * quantity.abs();
+                let order_fees_quote = order_value_quote * fees_percent;
                 let quote_required = order_value_quote + order_fees_quote;
 
                 let maybe_new_balance = current.balance.free - quote_required;
 
                 if maybe_new_balance >= Decimal::ZERO {
                     current.balance.free = maybe_new_balance;
-                    current.balance.total = maybe_new_balance;
+                    current.balance.total -= quote_required;
                     current.time_exchange = time_exchange;
 
                     Ok((current.clone(), AssetFees::quote_fees(order_fees_quote)))
@@ -324,18 +665,15 @@ impl MockExchange {
                     .balance_mut(&underlying.quote)
                     .expect("MockExchange has Balance for all configured Instrument assets");
 
-                // Currently we only supported MarketKind orders, so they should be identical
-                assert_eq!(current.balance.total, current.balance.free);
-
-                let order_value_base = request.state.quantity.abs();
-                let order_fees_base = order_value_base * self.fees_percent;
+                let order_value_base = quantity.abs();
+                let order_fees_base = order_value_base * fees_percent;
                 let base_required = order_value_base + order_fees_base;
 
                 let maybe_new_balance = current.balance.free - base_required;
 
                 if maybe_new_balance >= Decimal::ZERO {
                     current.balance.free = maybe_new_balance;
-                    current.balance.total = maybe_new_balance;
+                    current.balance.total -= base_required;
                     current.time_exchange = time_exchange;
 
                     let fees_quote = order_fees_base * request.state.price;
@@ -365,13 +703,14 @@ impl MockExchange {
             key: request.key.clone(),
             side: request.state.side,
             price: request.state.price,
-            quantity: request.state.quantity,
+            quantity,
             kind: request.state.kind,
             time_in_force: request.state.time_in_force,
+            reduce_only: request.state.reduce_only,
             state: Ok(Open {
                 id: order_id.clone(),
                 time_exchange: self.time_exchange(),
-                filled_quantity: request.state.quantity,
+                filled_quantity: quantity,
             }),
         };
 
@@ -385,19 +724,481 @@ impl MockExchange {
                 time_exchange: self.time_exchange(),
                 side: request.state.side,
                 price: request.state.price,
-                quantity: request.state.quantity,
+                quantity,
                 fees,
+                reduce_only: request.state.reduce_only,
             },
         };
 
         (order_response, Some(notifications))
     }
 
+    /// Open an [`OrderKind::Peg`] order, priced using the latest best bid and offer of the
+    /// instrument.
+    ///
+    /// A marketable peg order fills immediately at its peg price, otherwise it rests on the
+    /// `MockExchange` and is re-priced by [`Self::update_market_bbo`]. Resting peg orders reserve
+    /// the free balance required to fill them at their peg price until they fill or are
+    /// cancelled (see [`Self::resting_order_reserved`]).
+    fn open_peg_order(
+        &mut self,
+        request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
+        reference: PegReference,
+        offset: Decimal,
+    ) -> (
+        Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
+        Option<OpenOrderNotifications>,
+    ) {
+        let Some(&(best_bid, best_ask)) = self.bbos.get(&request.key.instrument) else {
+            let error = ApiError::OrderRejected(format!(
+                "MockExchange has no best bid and offer to peg {} order to",
+                request.key.instrument
+            ));
+            return (build_open_order_err_response(request, error), None);
+        };
+
+        let price = reference.price(request.state.side, offset, best_bid, best_ask);
+
+        if peg_order_marketable(request.state.side, price, best_bid, best_ask) {
+            let kind = request.state.kind;
+            let (response, notifications) = self.open_order(OrderRequestOpen {
+                key: request.key,
+                state: RequestOpen {
+                    price,
+                    kind: OrderKind::Market,
+                    ..request.state
+                },
+            });
+            return (Order { kind, ..response }, notifications);
+        }
+
+        let reserved = self.resting_order_reserved(
+            request.state.side,
+            price,
+            request.state.quantity,
+            &request.state.time_in_force,
+        );
+        if let Err(error) = self.reserve_balance(&request.key.instrument, reserved) {
+            return (build_open_order_err_response(request, error), None);
+        }
+
+        let order = Order {
+            key: request.key,
+            side: request.state.side,
+            price,
+            quantity: request.state.quantity,
+            kind: request.state.kind,
+            time_in_force: request.state.time_in_force,
+            reduce_only: request.state.reduce_only,
+            state: Open {
+                id: self.order_id_sequence_fetch_add(),
+                time_exchange: self.time_exchange(),
+                filled_quantity: Decimal::ZERO,
+            },
+        };
+        self.account.insert_order_open(order.clone());
+
+        (
+            Order {
+                key: order.key,
+                side: order.side,
+                price: order.price,
+                quantity: order.quantity,
+                kind: order.kind,
+                time_in_force: order.time_in_force,
+                reduce_only: order.reduce_only,
+                state: Ok(order.state),
+            },
+            None,
+        )
+    }
+
+    /// Update the `MockExchange` with the latest best bid and offer of an instrument.
+    ///
+    /// Resting [`OrderKind::Peg`] orders the market has reached (eg/ a Buy with the best ask at
+    /// or below its price) fill at their resting price, and all others are re-priced to track
+    /// the new best bid and offer. Re-priced orders that cross the new best bid and offer fill
+    /// immediately at their new peg price.
+    pub fn update_market_bbo(
+        &mut self,
+        instrument: &InstrumentNameExchange,
+        best_bid: Decimal,
+        best_ask: Decimal,
+    ) -> PegOrderNotifications {
+        self.bbos.insert(instrument.clone(), (best_bid, best_ask));
+
+        let pegged = self
+            .account
+            .orders_open()
+            .filter(|order| {
+                &order.key.instrument == instrument && matches!(order.kind, OrderKind::Peg { .. })
+            })
+            .map(|order| order.key.cid.clone())
+            .collect::<Vec<_>>();
+
+        let mut notifications = PegOrderNotifications::default();
+
+        for cid in pegged {
+            let Some(mut order) = self.account.remove_order_open(&cid) else {
+                continue;
+            };
+            let OrderKind::Peg { reference, offset } = order.kind else {
+                continue;
+            };
+
+            // Release the balance reserved at the previous peg price, which is reserved again
+            // at the new peg price if the order keeps resting, or debited by the fill
+            self.release_resting_order(&order);
+
+            // Re-price orders the market has not reached, but fill immediately if the new peg
+            // price crosses the best bid and offer, so an order never rests crossed
+            if !peg_order_marketable(order.side, order.price, best_bid, best_ask) {
+                order.price = reference.price(order.side, offset, best_bid, best_ask);
+
+                if !peg_order_marketable(order.side, order.price, best_bid, best_ask) {
+                    let reserved = self.resting_order_reserved(
+                        order.side,
+                        order.price,
+                        order.quantity,
+                        &order.time_in_force,
+                    );
+
+                    match self.reserve_balance(&order.key.instrument, reserved) {
+                        Ok(()) => {
+                            self.account.insert_order_open(order.clone());
+                            notifications.repriced.push(order);
+                        }
+                        Err(error) => {
+                            error!(
+                                exchange = %self.exchange,
+                                ?cid,
+                                %error,
+                                "MockExchange failed to reserve balance for re-priced peg order - cancelling"
+                            );
+                            notifications
+                                .cancelled
+                                .push(self.cancel_resting_order(order));
+                        }
+                    }
+                    continue;
+                }
+            }
+
+            let (response, fill) = self.open_order(OrderRequestOpen {
+                key: order.key.clone(),
+                state: RequestOpen {
+                    side: order.side,
+                    price: order.price,
+                    quantity: order.quantity,
+                    kind: OrderKind::Market,
+                    time_in_force: order.time_in_force,
+                    reduce_only: order.reduce_only,
+                },
+            });
+
+            match (response.state, fill) {
+                (Ok(open), Some(fill)) => notifications.filled.push((
+                    Order {
+                        state: Open {
+                            id: order.state.id.clone(),
+                            filled_quantity: open.filled_quantity,
+                            time_exchange: open.time_exchange,
+                        },
+                        ..order
+                    },
+                    fill,
+                )),
+                (state, _) => {
+                    error!(
+                        exchange = %self.exchange,
+                        ?cid,
+                        ?state,
+                        "MockExchange failed to fill peg order - cancelling"
+                    );
+                    notifications
+                        .cancelled
+                        .push(self.cancel_resting_order(order));
+                }
+            }
+        }
+
+        notifications
+    }
+
+    /// Open the entry order of an [`OrderRequestBracket`], and if it fills, open the attached
+    /// take-profit & stop-loss as contingent orders.
+    ///
+    /// Contingent orders rest on the `MockExchange` until triggered by
+    /// [`Self::update_market_price`], at which point the sibling contingent order is cancelled.
+    pub fn open_bracket_order(
+        &mut self,
+        request: OrderRequestBracket<ExchangeId, InstrumentNameExchange>,
+    ) -> (
+        Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
+        Option<OpenOrderNotifications>,
+        Vec<Order<ExchangeId, InstrumentNameExchange, Open>>,
+    ) {
+        if let Err(error) = request.state.validate() {
+            return (
+                build_open_order_err_response(
+                    request.entry(),
+                    UnindexedOrderError::Rejected(ApiError::OrderRejected(error.to_string())),
+                ),
+                None,
+                vec![],
+            );
+        }
+
+        let (response, notifications) = self.open_order(request.entry());
+        let Some(notifications) = notifications else {
+            return (response, None, vec![]);
+        };
+
+        let take_profit = request.take_profit();
+        let stop_loss = request.stop_loss();
+        let take_profit_cid = take_profit.as_ref().map(|request| request.key.cid.clone());
+        let stop_loss_cid = stop_loss.as_ref().map(|request| request.key.cid.clone());
+
+        let contingent = [
+            take_profit.map(|request| (request, stop_loss_cid)),
+            stop_loss.map(|request| (request, take_profit_cid)),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|(request, sibling)| {
+            let order = Order {
+                key: request.key,
+                side: request.state.side,
+                price: request.state.price,
+                quantity: notifications.trade.quantity,
+                kind: request.state.kind,
+                time_in_force: request.state.time_in_force,
+                reduce_only: request.state.reduce_only,
+                state: Open {
+                    id: self.order_id_sequence_fetch_add(),
+                    time_exchange: self.time_exchange(),
+                    filled_quantity: Decimal::ZERO,
+                },
+            };
+
+            self.contingent_orders
+                .insert(order.key.cid.clone(), sibling);
+            self.account.insert_order_open(order.clone());
+            order
+        })
+        .collect();
+
+        (response, Some(notifications), contingent)
+    }
+
+    /// Update the `MockExchange` with the latest market price of an instrument, filling any
+    /// triggered bracket contingent orders & cancelling their sibling.
+    ///
+    /// If the `fill_price` is [`MockFillPrice::Market`], subsequent orders fill at this price.
+    ///
+    /// - Take-profit ([`OrderKind::Limit`]): Sell triggers at or above the limit price, Buy
+    ///   triggers at or below the limit price. Fills at the limit price.
+    /// - Stop-loss ([`OrderKind::Stop`]): Sell triggers at or below the trigger price, Buy
+    ///   triggers at or above the trigger price. Fills at the prevailing market price, so a
+    ///   market gapping through the trigger fills at the gapped price.
+    pub fn update_market_price(
+        &mut self,
+        instrument: &InstrumentNameExchange,
+        price: Decimal,
+    ) -> Vec<ContingentOrderNotifications> {
+        self.market_prices.insert(instrument.clone(), price);
+
+        let triggered = self
+            .account
+            .orders_open()
+            .filter(|order| {
+                &order.key.instrument == instrument
+                    && self.contingent_orders.contains_key(&order.key.cid)
+                    && contingent_order_triggered(order, price)
+            })
+            .map(|order| order.key.cid.clone())
+            .collect::<Vec<_>>();
+
+        triggered
+            .into_iter()
+            .filter_map(|cid| self.fill_contingent_order(&cid, price))
+            .collect()
+    }
+
+    fn fill_contingent_order(
+        &mut self,
+        cid: &ClientOrderId,
+        market_price: Decimal,
+    ) -> Option<ContingentOrderNotifications> {
+        // Sibling may have already been cancelled by a contingent order triggered at this price
+        let sibling = self.contingent_orders.remove(cid)?;
+        let order = self.account.remove_order_open(cid)?;
+
+        let price = match order.kind {
+            OrderKind::Stop { .. } => market_price,
+            _ => order.price,
+        };
+
+        let (response, fill) = self.open_order(OrderRequestOpen {
+            key: order.key.clone(),
+            state: RequestOpen {
+                side: order.side,
+                price,
+                quantity: order.quantity,
+                kind: OrderKind::Market,
+                time_in_force: order.time_in_force,
+                reduce_only: order.reduce_only,
+            },
+        });
+
+        let mut cancelled = Vec::with_capacity(2);
+        let filled = match (response.state, fill) {
+            (Ok(open), Some(fill)) => Some((
+                Order {
+                    state: Open {
+                        id: order.state.id.clone(),
+                        filled_quantity: open.filled_quantity,
+                        time_exchange: open.time_exchange,
+                    },
+                    ..order
+                },
+                fill,
+            )),
+            (state, _) => {
+                error!(
+                    exchange = %self.exchange,
+                    ?cid,
+                    ?state,
+                    "MockExchange failed to fill triggered contingent order - cancelling"
+                );
+                cancelled.push(self.cancel_resting_order(order));
+                None
+            }
+        };
+
+        if let Some(sibling) = sibling {
+            self.contingent_orders.remove(&sibling);
+            if let Some(sibling) = self.account.remove_order_open(&sibling) {
+                cancelled.push(self.cancel_resting_order(sibling));
+            }
+        }
+
+        Some(ContingentOrderNotifications { filled, cancelled })
+    }
+
+    fn cancel_resting_order(
+        &mut self,
+        order: Order<ExchangeId, InstrumentNameExchange, Open>,
+    ) -> Order<ExchangeId, InstrumentNameExchange, Cancelled> {
+        let cancelled = Order {
+            key: order.key,
+            side: order.side,
+            price: order.price,
+            quantity: order.quantity,
+            kind: order.kind,
+            time_in_force: order.time_in_force,
+            reduce_only: order.reduce_only,
+            state: Cancelled {
+                id: order.state.id,
+                time_exchange: self.time_exchange(),
+            },
+        };
+        self.account.insert_order_cancelled(cancelled.clone());
+        cancelled
+    }
+
+    /// Balance reserved by a resting order until it fills or is cancelled, ie/ the balance
+    /// [`Self::open_order`] debits to fill it at its resting price (including fees).
+    fn resting_order_reserved(
+        &self,
+        side: Side,
+        price: Decimal,
+        quantity: Decimal,
+        time_in_force: &TimeInForce,
+    ) -> Decimal {
+        let order_value = match side {
+            Side::Buy => price * quantity.abs(),
+            Side::Sell => quantity.abs(),
+        };
+
+        order_value + order_value * self.fee_model.fees_percent(time_in_force)
+    }
+
+    /// Reserve `amount` of the free balance debited by fills of the instrument, or release it
+    /// if `amount` is negative.
+    ///
+    /// Rejects the reservation if the free balance is insufficient.
+    fn reserve_balance(
+        &mut self,
+        instrument: &InstrumentNameExchange,
+        amount: Decimal,
+    ) -> Result<(), UnindexedApiError> {
+        let asset = self
+            .find_instrument_data(instrument)?
+            .underlying
+            .quote
+            .clone();
+        let time_exchange = self.time_exchange();
+
+        let current = self
+            .account
+            .balance_mut(&asset)
+            .expect("MockExchange has Balance for all configured Instrument assets");
+
+        if amount > current.balance.free {
+            return Err(ApiError::BalanceInsufficient(
+                asset,
+                format!(
+                    "Available Balance: {}, Required Balance inc. fees: {}",
+                    current.balance.free, amount
+                ),
+            ));
+        }
+
+        current.balance.free -= amount;
+        current.time_exchange = time_exchange;
+        Ok(())
+    }
+
+    /// Release the balance reserved by a resting [`OrderKind::Peg`] order.
+    fn release_resting_order(&mut self, order: &Order<ExchangeId, InstrumentNameExchange, Open>) {
+        if !matches!(order.kind, OrderKind::Peg { .. }) {
+            return;
+        }
+
+        let reserved = self.resting_order_reserved(
+            order.side,
+            order.price,
+            order.quantity,
+            &order.time_in_force,
+        );
+
+        if let Err(error) = self.reserve_balance(&order.key.instrument, -reserved) {
+            error!(
+                exchange = %self.exchange,
+                cid = ?order.key.cid,
+                %error,
+                "MockExchange failed to release balance reserved by resting order"
+            );
+        }
+    }
+
+    /// Build a [`AccountEventKind::BalanceSnapshot`] of the balance debited by fills of the
+    /// instrument, used to notify the client of balance reservations.
+    fn build_balance_snapshot_event(
+        &self,
+        instrument: &InstrumentNameExchange,
+    ) -> Option<UnindexedAccountEvent> {
+        let asset = &self.find_instrument_data(instrument).ok()?.underlying.quote;
+        let balance = self.account.balance(asset)?.clone();
+        Some(self.build_account_event(Snapshot(balance)))
+    }
+
     pub fn validate_order_kind_supported(
         &self,
         order_kind: OrderKind,
     ) -> Result<(), UnindexedOrderError> {
-        if order_kind == OrderKind::Market {
+        if matches!(order_kind, OrderKind::Market | OrderKind::Peg { .. }) {
             Ok(())
         } else {
             Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(
@@ -435,6 +1236,73 @@ impl MockExchange {
     }
 }
 
+/// Clamp the quantity of a reduce-only order to the size of the position it reduces.
+///
+/// Rejects the order if there is no opposing position to reduce, so a reduce-only order can
+/// never open a new position or flip an existing one.
+fn reduce_only_quantity(
+    position: Decimal,
+    side: Side,
+    quantity: Decimal,
+) -> Result<Decimal, UnindexedOrderError> {
+    let reducible = match side {
+        Side::Buy if position < Decimal::ZERO => position.abs(),
+        Side::Sell if position > Decimal::ZERO => position,
+        _ => Decimal::ZERO,
+    };
+
+    if reducible.is_zero() {
+        Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(
+            format!(
+                "MockExchange rejected reduce-only {side} order with no position to reduce: {position}"
+            ),
+        )))
+    } else {
+        Ok(quantity.abs().min(reducible))
+    }
+}
+
+/// Determine if a bracket contingent order is triggered by the provided market price.
+fn contingent_order_triggered(
+    order: &Order<ExchangeId, InstrumentNameExchange, Open>,
+    price: Decimal,
+) -> bool {
+    match (order.kind, order.side) {
+        (OrderKind::Limit, Side::Sell) => price >= order.price,
+        (OrderKind::Limit, Side::Buy) => price <= order.price,
+        (OrderKind::Stop { trigger }, Side::Sell) => price <= trigger,
+        (OrderKind::Stop { trigger }, Side::Buy) => price >= trigger,
+        (OrderKind::Market | OrderKind::Peg { .. }, _) => false,
+    }
+}
+
+/// Determine if an [`OrderKind::Peg`] order at the provided price crosses the best bid and offer.
+fn peg_order_marketable(side: Side, price: Decimal, best_bid: Decimal, best_ask: Decimal) -> bool {
+    match side {
+        Side::Buy => price >= best_ask,
+        Side::Sell => price <= best_bid,
+    }
+}
+
+/// Parse the [`FormattedRequestOpen`] price & quantity received by the exchange, rejecting the
+/// order if either is malformed.
+fn parse_formatted_request(
+    formatted: &FormattedRequestOpen,
+) -> Result<(Decimal, Decimal), UnindexedOrderError> {
+    match (
+        Decimal::from_str_exact(&formatted.price),
+        Decimal::from_str_exact(&formatted.quantity),
+    ) {
+        (Ok(price), Ok(quantity)) => Ok((price, quantity)),
+        _ => Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(
+            format!(
+                "MockExchange received malformed order price: {}, quantity: {}",
+                formatted.price, formatted.quantity
+            ),
+        ))),
+    }
+}
+
 fn build_open_order_err_response<E>(
     request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
     error: E,
@@ -449,6 +1317,7 @@ where
         quantity: request.state.quantity,
         kind: request.state.kind,
         time_in_force: request.state.time_in_force,
+        reduce_only: request.state.reduce_only,
         state: Err(error.into()),
     }
 }
@@ -458,3 +1327,698 @@ pub struct OpenOrderNotifications {
     pub balance: Snapshot<AssetBalance<AssetNameExchange>>,
     pub trade: Trade<QuoteAsset, InstrumentNameExchange>,
 }
+
+/// Notifications generated when the best bid and offer of an instrument updates.
+#[derive(Debug, Default)]
+pub struct PegOrderNotifications {
+    /// Resting peg orders re-priced to track the new best bid and offer.
+    pub repriced: Vec<Order<ExchangeId, InstrumentNameExchange, Open>>,
+    /// Peg orders reached by the market & their fills.
+    pub filled: Vec<(
+        Order<ExchangeId, InstrumentNameExchange, Open>,
+        OpenOrderNotifications,
+    )>,
+    /// Peg orders cancelled because their fill failed.
+    pub cancelled: Vec<Order<ExchangeId, InstrumentNameExchange, Cancelled>>,
+}
+
+/// Notifications generated when a bracket contingent order is triggered.
+#[derive(Debug)]
+pub struct ContingentOrderNotifications {
+    /// Triggered contingent order & its fill, if the fill succeeded.
+    pub filled: Option<(
+        Order<ExchangeId, InstrumentNameExchange, Open>,
+        OpenOrderNotifications,
+    )>,
+    /// Cancelled sibling contingent order, plus the triggered order if its fill failed.
+    pub cancelled: Vec<Order<ExchangeId, InstrumentNameExchange, Cancelled>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        balance::Balance,
+        order::{
+            OrderKey, TimeInForce,
+            id::StrategyId,
+            request::{OrderRequestBracket, RequestBracket, RequestBracketError, RequestCancel},
+            state::OrderState,
+        },
+    };
+    use barter_instrument::{
+        Underlying,
+        instrument::{kind::InstrumentKind, quote::InstrumentQuoteAsset},
+    };
+
+    fn mock_exchange() -> MockExchange {
+        let instrument = Instrument::new(
+            ExchangeId::Mock,
+            "mock-btc_usdt",
+            "btc_usdt",
+            Underlying::new(
+                AssetNameExchange::new("btc"),
+                AssetNameExchange::new("usdt"),
+            ),
+            InstrumentQuoteAsset::UnderlyingQuote,
+            InstrumentKind::Spot,
+            None,
+        );
+
+        let initial_state = UnindexedAccountSnapshot {
+            exchange: ExchangeId::Mock,
+            balances: ["btc", "usdt"]
+                .into_iter()
+                .map(|asset| AssetBalance {
+                    asset: AssetNameExchange::new(asset),
+                    balance: Balance::new(Decimal::from(10_000), Decimal::from(10_000)),
+                    time_exchange: DateTime::<Utc>::MIN_UTC,
+                })
+                .collect(),
+            instruments: vec![],
+        };
+
+        MockExchange::new(
+            MockExecutionConfig {
+                mocked_exchange: ExchangeId::Mock,
+                initial_state,
+                latency_ms: 0,
+                fees_percent: Some(Decimal::ZERO),
+                fees_maker_percent: None,
+                fee_tier: None,
+                fill_price: MockFillPrice::Request,
+                position_mode: Default::default(),
+            },
+            mpsc::unbounded_channel().1,
+            broadcast::channel(1).0,
+            FnvHashMap::from_iter([(instrument.name_exchange.clone(), instrument)]),
+        )
+    }
+
+    fn open(
+        cid: &str,
+        side: Side,
+        quantity: u64,
+        reduce_only: bool,
+    ) -> OrderRequestOpen<ExchangeId, InstrumentNameExchange> {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeId::Mock,
+                instrument: InstrumentNameExchange::new("btc_usdt"),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side,
+                price: Decimal::ONE_HUNDRED,
+                quantity: Decimal::from(quantity),
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only,
+            },
+        }
+    }
+
+    /// Open an order & acknowledge the resulting trade, as `MockExchange::run` does.
+    fn open_and_ack(
+        exchange: &mut MockExchange,
+        request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
+    ) -> (
+        Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
+        Option<Trade<QuoteAsset, InstrumentNameExchange>>,
+    ) {
+        let (order, notifications) = exchange.open_order(request);
+        let trade = notifications.map(|notifications| {
+            exchange.account.ack_trade(notifications.trade.clone());
+            notifications.trade
+        });
+        (order, trade)
+    }
+
+    #[test]
+    fn test_open_order_reduce_only_never_opens_or_flips_position() {
+        let mut exchange = mock_exchange();
+        let instrument = InstrumentNameExchange::new("btc_usdt");
+
+        // Reduce-only order with no position to reduce is rejected
+        let (order, trade) = open_and_ack(&mut exchange, open("ro-1", Side::Sell, 1, true));
+        assert!(matches!(
+            order.state,
+            Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(_)))
+        ));
+        assert!(trade.is_none());
+        assert_eq!(exchange.account.position(&instrument), Decimal::ZERO);
+
+        // Open LONG 2
+        let (order, trade) = open_and_ack(&mut exchange, open("buy", Side::Buy, 2, false));
+        assert!(order.state.is_ok());
+        assert!(!trade.unwrap().reduce_only);
+        assert_eq!(exchange.account.position(&instrument), Decimal::TWO);
+
+        // Reduce-only buy would increase the LONG, so is rejected
+        let (order, trade) = open_and_ack(&mut exchange, open("ro-2", Side::Buy, 1, true));
+        assert!(order.state.is_err());
+        assert!(trade.is_none());
+
+        // Reduce-only sell larger than the LONG only closes it
+        let (order, trade) = open_and_ack(&mut exchange, open("ro-3", Side::Sell, 5, true));
+        assert!(order.reduce_only);
+        assert_eq!(order.quantity, Decimal::TWO);
+        assert_eq!(order.state.unwrap().filled_quantity, Decimal::TWO);
+
+        let trade = trade.unwrap();
+        assert!(trade.reduce_only);
+        assert_eq!(trade.side, Side::Sell);
+        assert_eq!(trade.quantity, Decimal::TWO);
+        assert_eq!(exchange.account.position(&instrument), Decimal::ZERO);
+
+        // Position is flat, so further reduce-only orders never open a SHORT
+        let (order, trade) = open_and_ack(&mut exchange, open("ro-4", Side::Sell, 1, true));
+        assert!(order.state.is_err());
+        assert!(trade.is_none());
+        assert_eq!(exchange.account.position(&instrument), Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_open_order_reduce_only_hedging_closes_both_legs() {
+        let mut exchange = mock_exchange();
+        exchange.position_mode = MockPositionMode::Hedging;
+        let instrument = InstrumentNameExchange::new("btc_usdt");
+
+        // Open hedged LONG 2 & SHORT 1, so the net position is LONG 1
+        open_and_ack(&mut exchange, open("long", Side::Buy, 2, false));
+        open_and_ack(&mut exchange, open("short", Side::Sell, 1, false));
+        assert_eq!(exchange.account.position(&instrument), Decimal::ONE);
+        assert_eq!(
+            exchange.account.position_hedged(&instrument, Side::Buy),
+            Decimal::TWO
+        );
+        assert_eq!(
+            exchange.account.position_hedged(&instrument, Side::Sell),
+            Decimal::ONE
+        );
+
+        // Close the LONG leg in full, rather than clamping to the net position
+        let (order, trade) = open_and_ack(&mut exchange, open("close-long", Side::Sell, 2, true));
+        assert_eq!(order.state.unwrap().filled_quantity, Decimal::TWO);
+        assert_eq!(trade.unwrap().quantity, Decimal::TWO);
+
+        // Close the SHORT leg, which is still open despite the net position being flat
+        let (order, trade) = open_and_ack(&mut exchange, open("close-short", Side::Buy, 1, true));
+        assert_eq!(order.state.unwrap().filled_quantity, Decimal::ONE);
+        assert_eq!(trade.unwrap().quantity, Decimal::ONE);
+
+        // Both legs are closed, so further reduce-only orders are rejected
+        for side in [Side::Buy, Side::Sell] {
+            assert_eq!(
+                exchange.account.position_hedged(&instrument, side),
+                Decimal::ZERO
+            );
+            let (order, trade) = open_and_ack(&mut exchange, open("ro", side, 1, true));
+            assert!(order.state.is_err());
+            assert!(trade.is_none());
+        }
+    }
+
+    #[test]
+    fn test_open_order_post_only_fill_receives_maker_rebate() {
+        let mut exchange = mock_exchange();
+        exchange.fee_model = FeeModel::new(Decimal::new(-1, 4), Decimal::new(5, 4));
+        let usdt = AssetNameExchange::new("usdt");
+
+        // Post-only Buy 2 @ 100 is a maker fill, so receives a 0.02 rebate
+        let request = OrderRequestOpen {
+            state: RequestOpen {
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+                ..open("maker", Side::Buy, 2, false).state
+            },
+            ..open("maker", Side::Buy, 2, false)
+        };
+        let (order, trade) = open_and_ack(&mut exchange, request);
+        assert!(order.state.is_ok());
+        assert_eq!(trade.unwrap().fees.fees, Decimal::new(-2, 2));
+        assert_eq!(
+            exchange.account.balance_mut(&usdt).unwrap().balance.total,
+            Decimal::new(980002, 2)
+        );
+
+        // Immediate-or-cancel Buy 2 @ 100 is a taker fill, so pays 0.1 fees
+        let (_, trade) = open_and_ack(&mut exchange, open("taker", Side::Buy, 2, false));
+        assert_eq!(trade.unwrap().fees.fees, Decimal::new(1, 1));
+        assert_eq!(
+            exchange.account.balance_mut(&usdt).unwrap().balance.total,
+            Decimal::new(959992, 2)
+        );
+    }
+
+    #[test]
+    fn test_bracket_order_take_profit_fill_cancels_stop_loss() {
+        let mut exchange = mock_exchange();
+        let instrument = InstrumentNameExchange::new("btc_usdt");
+        let request = OrderRequestBracket {
+            key: open("bracket", Side::Buy, 2, false).key,
+            state: RequestBracket::new(
+                open("bracket", Side::Buy, 2, false).state,
+                Some(Decimal::from(110)),
+                Some(Decimal::from(95)),
+            ),
+        };
+
+        // Entry fills, and the take-profit & stop-loss are opened as contingent orders
+        let (order, notifications, contingent) = exchange.open_bracket_order(request);
+        assert!(order.state.is_ok());
+        exchange.account.ack_trade(notifications.unwrap().trade);
+        assert_eq!(exchange.account.position(&instrument), Decimal::TWO);
+
+        let cids = contingent
+            .iter()
+            .map(|order| {
+                assert_eq!(order.side, Side::Sell);
+                assert_eq!(order.quantity, Decimal::TWO);
+                assert!(order.reduce_only);
+                (order.key.cid.0.as_str(), order.kind, order.price)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            cids,
+            vec![
+                ("bracket-tp", OrderKind::Limit, Decimal::from(110)),
+                (
+                    "bracket-sl",
+                    OrderKind::Stop {
+                        trigger: Decimal::from(95)
+                    },
+                    Decimal::from(95)
+                ),
+            ]
+        );
+        assert_eq!(exchange.account.orders_open().count(), 2);
+
+        // Price between the stop-loss & take-profit triggers nothing
+        assert!(
+            exchange
+                .update_market_price(&instrument, Decimal::from(105))
+                .is_empty()
+        );
+        assert_eq!(exchange.account.orders_open().count(), 2);
+
+        // Price through the take-profit fills it at the take-profit price, cancelling the stop-loss
+        let mut notifications = exchange.update_market_price(&instrument, Decimal::from(111));
+        assert_eq!(notifications.len(), 1);
+        let ContingentOrderNotifications { filled, cancelled } = notifications.remove(0);
+
+        let (filled, fill) = filled.unwrap();
+        assert_eq!(filled.key.cid, ClientOrderId::new("bracket-tp"));
+        assert_eq!(filled.state.filled_quantity, Decimal::TWO);
+        assert_eq!(fill.trade.side, Side::Sell);
+        assert_eq!(fill.trade.price, Decimal::from(110));
+        assert!(fill.trade.reduce_only);
+        exchange.account.ack_trade(fill.trade);
+        assert_eq!(exchange.account.position(&instrument), Decimal::ZERO);
+
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(cancelled[0].key.cid, ClientOrderId::new("bracket-sl"));
+        assert_eq!(exchange.account.orders_open().count(), 0);
+        assert!(exchange.contingent_orders.is_empty());
+
+        let snapshot = exchange.account_snapshot();
+        assert!(matches!(
+            snapshot.instruments[0].orders[0].state,
+            OrderState::Inactive(_)
+        ));
+
+        // Stop-loss price no longer triggers anything
+        assert!(
+            exchange
+                .update_market_price(&instrument, Decimal::from(90))
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_bracket_order_stop_loss_fills_at_market_price_and_cancels_take_profit() {
+        let mut exchange = mock_exchange();
+        let instrument = InstrumentNameExchange::new("btc_usdt");
+        let request = OrderRequestBracket {
+            key: open("bracket", Side::Buy, 2, false).key,
+            state: RequestBracket::new(
+                open("bracket", Side::Buy, 2, false).state,
+                Some(Decimal::from(110)),
+                Some(Decimal::from(95)),
+            ),
+        };
+
+        let (_, notifications, _) = exchange.open_bracket_order(request);
+        exchange.account.ack_trade(notifications.unwrap().trade);
+
+        // Market gaps through the stop-loss trigger, so it fills at the gapped market price
+        let mut notifications = exchange.update_market_price(&instrument, Decimal::from(90));
+        assert_eq!(notifications.len(), 1);
+        let ContingentOrderNotifications { filled, cancelled } = notifications.remove(0);
+
+        let (filled, fill) = filled.unwrap();
+        assert_eq!(filled.key.cid, ClientOrderId::new("bracket-sl"));
+        assert_eq!(fill.trade.side, Side::Sell);
+        assert_eq!(fill.trade.price, Decimal::from(90));
+        exchange.account.ack_trade(fill.trade);
+        assert_eq!(exchange.account.position(&instrument), Decimal::ZERO);
+
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(cancelled[0].key.cid, ClientOrderId::new("bracket-tp"));
+        assert!(exchange.contingent_orders.is_empty());
+    }
+
+    #[test]
+    fn test_bracket_order_rejects_take_profit_on_wrong_side_of_entry() {
+        let mut exchange = mock_exchange();
+        let request = OrderRequestBracket {
+            key: open("bracket", Side::Buy, 1, false).key,
+            state: RequestBracket::new(
+                open("bracket", Side::Buy, 1, false).state,
+                Some(Decimal::from(90)),
+                None,
+            ),
+        };
+
+        assert_eq!(
+            request.state.validate(),
+            Err(RequestBracketError::TakeProfit {
+                side: Side::Buy,
+                price: Decimal::from(90),
+                entry: Decimal::ONE_HUNDRED,
+            })
+        );
+
+        let (order, notifications, contingent) = exchange.open_bracket_order(request);
+        assert!(matches!(
+            order.state,
+            Err(UnindexedOrderError::Rejected(ApiError::OrderRejected(_)))
+        ));
+        assert!(notifications.is_none());
+        assert!(contingent.is_empty());
+    }
+
+    #[test]
+    fn test_peg_order_follows_best_bid_and_fills_when_crossed() {
+        let mut exchange = mock_exchange();
+        let instrument = InstrumentNameExchange::new("btc_usdt");
+        let request = OrderRequestOpen {
+            state: RequestOpen {
+                kind: OrderKind::Peg {
+                    reference: PegReference::BestBid,
+                    offset: Decimal::ZERO,
+                },
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                ..open("peg", Side::Buy, 1, false).state
+            },
+            ..open("peg", Side::Buy, 1, false)
+        };
+
+        // Peg order without a best bid and offer to peg to is rejected
+        let (order, trade) = open_and_ack(&mut exchange, request.clone());
+        assert!(order.state.is_err());
+        assert!(trade.is_none());
+
+        // Peg order rests at the best bid
+        exchange.update_market_bbo(&instrument, Decimal::from(99), Decimal::from(101));
+        let (order, trade) = open_and_ack(&mut exchange, request);
+        assert_eq!(order.price, Decimal::from(99));
+        assert_eq!(order.state.unwrap().filled_quantity, Decimal::ZERO);
+        assert!(trade.is_none());
+
+        // Peg order follows the best bid up
+        for best_bid in [100, 101] {
+            let notifications = exchange.update_market_bbo(
+                &instrument,
+                Decimal::from(best_bid),
+                Decimal::from(best_bid + 2),
+            );
+            assert_eq!(notifications.repriced.len(), 1);
+            assert_eq!(notifications.repriced[0].price, Decimal::from(best_bid));
+            assert!(notifications.filled.is_empty());
+        }
+
+        // Best ask crosses the resting peg order, so it fills at its resting price
+        let notifications =
+            exchange.update_market_bbo(&instrument, Decimal::from(100), Decimal::from(101));
+        assert!(notifications.repriced.is_empty());
+        assert!(notifications.cancelled.is_empty());
+
+        let (order, fill) = notifications.filled.into_iter().next().unwrap();
+        assert_eq!(order.key.cid, ClientOrderId::new("peg"));
+        assert_eq!(order.state.filled_quantity, Decimal::ONE);
+        assert_eq!(fill.trade.side, Side::Buy);
+        assert_eq!(fill.trade.price, Decimal::from(101));
+        exchange.account.ack_trade(fill.trade);
+
+        assert_eq!(exchange.account.position(&instrument), Decimal::ONE);
+        assert_eq!(exchange.account.orders_open().count(), 0);
+    }
+
+    #[test]
+    fn test_peg_order_reserves_balance_until_filled_or_cancelled() {
+        let mut exchange = mock_exchange();
+        let instrument = InstrumentNameExchange::new("btc_usdt");
+        let usdt = AssetNameExchange::new("usdt");
+        let peg = |cid: &str, quantity: u64| OrderRequestOpen {
+            state: RequestOpen {
+                kind: OrderKind::Peg {
+                    reference: PegReference::BestBid,
+                    offset: Decimal::ZERO,
+                },
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                ..open(cid, Side::Buy, quantity, false).state
+            },
+            ..open(cid, Side::Buy, quantity, false)
+        };
+        let balance = |exchange: &MockExchange| exchange.account.balance(&usdt).unwrap().balance;
+
+        // Resting peg order reserves the free balance required to fill it
+        exchange.update_market_bbo(&instrument, Decimal::from(99), Decimal::from(101));
+        let (order, _) = open_and_ack(&mut exchange, peg("peg", 1));
+        assert!(order.state.is_ok());
+        assert_eq!(
+            balance(&exchange),
+            Balance::new(Decimal::from(10_000), Decimal::from(9_901))
+        );
+
+        // Peg order exceeding the free balance is rejected
+        let (order, _) = open_and_ack(&mut exchange, peg("large", 200));
+        assert!(matches!(
+            order.state,
+            Err(UnindexedOrderError::Rejected(
+                ApiError::BalanceInsufficient(_, _)
+            ))
+        ));
+        assert_eq!(exchange.account.orders_open().count(), 1);
+
+        // Re-priced peg order reserves the balance required at its new price
+        exchange.update_market_bbo(&instrument, Decimal::from(101), Decimal::from(103));
+        assert_eq!(
+            balance(&exchange),
+            Balance::new(Decimal::from(10_000), Decimal::from(9_899))
+        );
+
+        // Filled peg order debits the reserved balance
+        let notifications =
+            exchange.update_market_bbo(&instrument, Decimal::from(100), Decimal::from(101));
+        let (_, fill) = notifications.filled.into_iter().next().unwrap();
+        assert_eq!(
+            fill.balance.0.balance,
+            Balance::new(Decimal::from(9_899), Decimal::from(9_899))
+        );
+
+        // Cancelled peg order releases the reserved balance
+        let (order, _) = open_and_ack(&mut exchange, peg("cancelled", 1));
+        assert_eq!(
+            balance(&exchange),
+            Balance::new(Decimal::from(9_899), Decimal::from(9_799))
+        );
+        let response = exchange.cancel_order(OrderRequestCancel {
+            key: order.key,
+            state: RequestCancel { id: None },
+        });
+        assert!(response.state.is_ok());
+        assert_eq!(
+            balance(&exchange),
+            Balance::new(Decimal::from(9_899), Decimal::from(9_899))
+        );
+    }
+
+    #[test]
+    fn test_peg_order_repriced_across_best_bid_and_offer_fills_immediately() {
+        let mut exchange = mock_exchange();
+        let instrument = InstrumentNameExchange::new("btc_usdt");
+        let request = OrderRequestOpen {
+            state: RequestOpen {
+                kind: OrderKind::Peg {
+                    reference: PegReference::BestBid,
+                    offset: Decimal::TWO,
+                },
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                ..open("peg", Side::Buy, 1, false).state
+            },
+            ..open("peg", Side::Buy, 1, false)
+        };
+
+        // Peg order rests at the best bid + 2, below the best ask
+        exchange.update_market_bbo(&instrument, Decimal::from(95), Decimal::from(100));
+        let (order, trade) = open_and_ack(&mut exchange, request);
+        assert_eq!(order.price, Decimal::from(97));
+        assert!(trade.is_none());
+
+        // Spread narrows, so the re-priced peg order (100 + 2) crosses the best ask & fills
+        let notifications =
+            exchange.update_market_bbo(&instrument, Decimal::from(100), Decimal::from(101));
+        assert!(notifications.repriced.is_empty());
+        assert!(notifications.cancelled.is_empty());
+
+        let (order, fill) = notifications.filled.into_iter().next().unwrap();
+        assert_eq!(order.price, Decimal::from(102));
+        assert_eq!(fill.trade.price, Decimal::from(102));
+        assert_eq!(exchange.account.orders_open().count(), 0);
+    }
+
+    #[test]
+    fn test_cancel_order_cancels_resting_order() {
+        let mut exchange = mock_exchange();
+        let instrument = InstrumentNameExchange::new("btc_usdt");
+        let request = OrderRequestOpen {
+            state: RequestOpen {
+                kind: OrderKind::Peg {
+                    reference: PegReference::BestBid,
+                    offset: Decimal::ZERO,
+                },
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                ..open("peg", Side::Buy, 1, false).state
+            },
+            ..open("peg", Side::Buy, 1, false)
+        };
+
+        exchange.update_market_bbo(&instrument, Decimal::from(99), Decimal::from(101));
+        let (order, _) = open_and_ack(&mut exchange, request);
+        let id = order.state.unwrap().id;
+
+        let cancel = |cid: &str, id: Option<OrderId>| OrderRequestCancel {
+            key: open(cid, Side::Buy, 1, false).key,
+            state: RequestCancel { id },
+        };
+
+        // Unknown order is not found
+        let response = exchange.cancel_order(cancel("unknown", None));
+        assert!(matches!(
+            response.state,
+            Err(UnindexedOrderError::Rejected(ApiError::OrderNotFound))
+        ));
+
+        // Mismatched OrderId is not found, and the order keeps resting
+        let response = exchange.cancel_order(cancel("peg", Some(OrderId::new("other"))));
+        assert!(response.state.is_err());
+        assert_eq!(exchange.account.orders_open().count(), 1);
+
+        // Resting order is cancelled
+        let response = exchange.cancel_order(cancel("peg", Some(id.clone())));
+        assert_eq!(response.key.cid, ClientOrderId::new("peg"));
+        assert_eq!(response.state.unwrap().id, id);
+        assert_eq!(exchange.account.orders_open().count(), 0);
+        assert_eq!(exchange.account.orders_cancelled().count(), 1);
+
+        // Cancelled order can not be cancelled again
+        let response = exchange.cancel_order(cancel("peg", None));
+        assert!(matches!(
+            response.state,
+            Err(UnindexedOrderError::Rejected(
+                ApiError::OrderAlreadyCancelled
+            ))
+        ));
+
+        // Cancelled peg order is no longer re-priced
+        let notifications =
+            exchange.update_market_bbo(&instrument, Decimal::from(100), Decimal::from(102));
+        assert!(notifications.repriced.is_empty());
+        assert!(notifications.filled.is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_cancel_all_after_cancels_resting_and_contingent_orders_when_expired() {
+        let (request_tx, request_rx) = mpsc::unbounded_channel();
+        let (event_tx, event_rx) = broadcast::channel(16);
+        let mut exchange = MockExchange {
+            request_rx,
+            event_tx,
+            ..mock_exchange()
+        };
+        let instrument = InstrumentNameExchange::new("btc_usdt");
+
+        // Resting peg order
+        exchange.update_market_bbo(&instrument, Decimal::from(99), Decimal::from(101));
+        let (order, _) = open_and_ack(
+            &mut exchange,
+            OrderRequestOpen {
+                state: RequestOpen {
+                    kind: OrderKind::Peg {
+                        reference: PegReference::BestBid,
+                        offset: Decimal::ZERO,
+                    },
+                    time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                    ..open("peg", Side::Buy, 1, false).state
+                },
+                ..open("peg", Side::Buy, 1, false)
+            },
+        );
+        assert!(order.state.is_ok());
+
+        // Resting bracket contingent orders
+        let (_, notifications, contingent) = exchange.open_bracket_order(OrderRequestBracket {
+            key: open("bracket", Side::Buy, 1, false).key,
+            state: RequestBracket::new(
+                open("bracket", Side::Buy, 1, false).state,
+                Some(Decimal::from(110)),
+                Some(Decimal::from(90)),
+            ),
+        });
+        exchange.account.ack_trade(notifications.unwrap().trade);
+        assert_eq!(contingent.len(), 2);
+        assert_eq!(exchange.account.orders_open().count(), 3);
+
+        let exchange = tokio::spawn(exchange.run());
+        let mut events = BroadcastStream::new(event_rx);
+
+        // Heartbeats re-arm the switch, so no orders are cancelled while they keep arriving
+        for _ in 0..3 {
+            request_tx
+                .send(MockExchangeRequest::cancel_all_after(
+                    DateTime::<Utc>::MIN_UTC,
+                    std::time::Duration::from_secs(30),
+                ))
+                .unwrap();
+            tokio::time::sleep(std::time::Duration::from_secs(20)).await;
+        }
+        assert!(
+            tokio::time::timeout(std::time::Duration::ZERO, events.next())
+                .await
+                .is_err()
+        );
+
+        // Heartbeats stop, so all resting & contingent orders are cancelled after the timeout
+        tokio::time::sleep(std::time::Duration::from_secs(10)).await;
+        let mut cancelled = Vec::new();
+        for _ in 0..3 {
+            let event = events.next().await.unwrap().unwrap();
+            let AccountEventKind::OrderSnapshot(Snapshot(order)) = event.kind else {
+                panic!("expected cancelled OrderSnapshot, got: {event:?}");
+            };
+            assert!(matches!(order.state, OrderState::Inactive(_)));
+            cancelled.push(order.key.cid);
+        }
+        cancelled.sort();
+
+        let mut expected = vec![ClientOrderId::new("peg")];
+        expected.extend(contingent.into_iter().map(|order| order.key.cid));
+        expected.sort();
+        assert_eq!(cancelled, expected);
+
+        drop(request_tx);
+        exchange.await.unwrap();
+    }
+}
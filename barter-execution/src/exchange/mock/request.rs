@@ -4,7 +4,9 @@ use crate::{
     error::UnindexedOrderError,
     order::{
         Order,
-        request::{OrderRequestCancel, OrderRequestOpen, UnindexedOrderResponseCancel},
+        request::{
+            OrderRequestBracket, OrderRequestCancel, OrderRequestOpen, UnindexedOrderResponseCancel,
+        },
         state::Open,
     },
     trade::Trade,
@@ -15,6 +17,8 @@ use barter_instrument::{
     instrument::name::InstrumentNameExchange,
 };
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use std::time::Duration;
 use tokio::sync::oneshot;
 
 #[derive(Debug)]
@@ -94,6 +98,13 @@ impl MockExchangeRequest {
         )
     }
 
+    pub fn cancel_all_after(time_request: DateTime<Utc>, timeout: Duration) -> Self {
+        Self::new(
+            time_request,
+            MockExchangeRequestKind::CancelAllAfter { timeout },
+        )
+    }
+
     pub fn open_order(
         time_request: DateTime<Utc>,
         response_tx: oneshot::Sender<
@@ -109,6 +120,49 @@ impl MockExchangeRequest {
             },
         )
     }
+
+    pub fn open_bracket_order(
+        time_request: DateTime<Utc>,
+        response_tx: oneshot::Sender<
+            Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
+        >,
+        request: OrderRequestBracket<ExchangeId, InstrumentNameExchange>,
+    ) -> Self {
+        Self::new(
+            time_request,
+            MockExchangeRequestKind::OpenBracketOrder {
+                response_tx,
+                request,
+            },
+        )
+    }
+
+    pub fn market_bbo(
+        time_request: DateTime<Utc>,
+        instrument: InstrumentNameExchange,
+        best_bid: Decimal,
+        best_ask: Decimal,
+    ) -> Self {
+        Self::new(
+            time_request,
+            MockExchangeRequestKind::MarketBbo {
+                instrument,
+                best_bid,
+                best_ask,
+            },
+        )
+    }
+
+    pub fn market_price(
+        time_request: DateTime<Utc>,
+        instrument: InstrumentNameExchange,
+        price: Decimal,
+    ) -> Self {
+        Self::new(
+            time_request,
+            MockExchangeRequestKind::MarketPrice { instrument, price },
+        )
+    }
 }
 
 #[derive(Debug)]
@@ -132,10 +186,31 @@ pub enum MockExchangeRequestKind {
         response_tx: oneshot::Sender<UnindexedOrderResponseCancel>,
         request: OrderRequestCancel<ExchangeId, InstrumentNameExchange>,
     },
+    CancelAllAfter {
+        timeout: Duration,
+    },
     OpenOrder {
         response_tx: oneshot::Sender<
             Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
         >,
         request: OrderRequestOpen<ExchangeId, InstrumentNameExchange>,
     },
+    OpenBracketOrder {
+        response_tx: oneshot::Sender<
+            Order<ExchangeId, InstrumentNameExchange, Result<Open, UnindexedOrderError>>,
+        >,
+        request: OrderRequestBracket<ExchangeId, InstrumentNameExchange>,
+    },
+    /// Latest market price of an instrument, used to trigger bracket contingent orders.
+    MarketPrice {
+        instrument: InstrumentNameExchange,
+        price: Decimal,
+    },
+    /// Latest best bid and offer of an instrument, used to re-price & fill
+    /// [`OrderKind::Peg`](crate::order::OrderKind::Peg) orders.
+    MarketBbo {
+        instrument: InstrumentNameExchange,
+        best_bid: Decimal,
+        best_ask: Decimal,
+    },
 }
@@ -137,6 +137,7 @@ impl AccountEventIndexer {
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state,
         } = order;
 
@@ -166,6 +167,7 @@ impl AccountEventIndexer {
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state,
         })
     }
@@ -214,8 +216,13 @@ impl AccountEventIndexer {
                 ApiError::BalanceInsufficient(self.map.find_asset_index(&asset)?, value)
             }
             UnindexedApiError::OrderRejected(reason) => ApiError::OrderRejected(reason),
+            UnindexedApiError::OrderNotFound => ApiError::OrderNotFound,
             UnindexedApiError::OrderAlreadyCancelled => ApiError::OrderAlreadyCancelled,
             UnindexedApiError::OrderAlreadyFullyFilled => ApiError::OrderAlreadyFullyFilled,
+            UnindexedApiError::DuplicateClientOrderId(cid) => ApiError::DuplicateClientOrderId(cid),
+            UnindexedApiError::TimeInForceUnsupported(error) => {
+                ApiError::TimeInForceUnsupported(error)
+            }
         })
     }
 
@@ -264,6 +271,7 @@ impl AccountEventIndexer {
             UnindexedClientError::Api(error) => ClientError::Api(self.api_error(error)?),
             UnindexedClientError::AccountSnapshot(value) => ClientError::AccountSnapshot(value),
             UnindexedClientError::AccountStream(value) => ClientError::AccountStream(value),
+            UnindexedClientError::Unsupported(value) => ClientError::Unsupported(value),
         })
     }
 
@@ -281,6 +289,7 @@ impl AccountEventIndexer {
             price,
             quantity,
             fees,
+            reduce_only,
         } = trade;
 
         let instrument_index = self.map.find_instrument_index(&instrument)?;
@@ -295,6 +304,7 @@ impl AccountEventIndexer {
             price,
             quantity,
             fees,
+            reduce_only,
         })
     }
 }
@@ -46,6 +46,7 @@ pub mod exchange;
 pub mod indexer;
 pub mod map;
 pub mod order;
+pub mod snapshot;
 pub mod trade;
 
 /// 使用 [`ExchangeId`]、[`AssetNameExchange`] 和 [`InstrumentNameExchange`]
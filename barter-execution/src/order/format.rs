@@ -0,0 +1,179 @@
+use crate::order::request::RequestOpen;
+use barter_instrument::{exchange::ExchangeId, instrument::spec::InstrumentSpec};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [`Decimal`] string format expected by an exchange for order prices & quantities.
+///
+/// Formatted values never use scientific notation.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum DecimalFormat {
+    /// Shortest representation without trailing zeros.
+    ///
+    /// eg/ `1.50` is formatted as "1.5".
+    Normalized,
+    /// Fixed number of decimal places matching the instrument precision (eg/ price tick size or
+    /// quantity increment), rounding any excess precision.
+    ///
+    /// eg/ `1.5` with a tick size of `0.001` is formatted as "1.500".
+    ///
+    /// Falls back to [`DecimalFormat::Normalized`] if the instrument precision is unknown.
+    FixedPrecision,
+}
+
+impl DecimalFormat {
+    /// Format convention of the provided [`ExchangeId`].
+    ///
+    /// Exchanges without a known convention (including [`ExchangeId::Mock`] and
+    /// [`ExchangeId::Simulated`]) use [`DecimalFormat::Normalized`].
+    pub fn for_exchange(exchange: ExchangeId) -> Self {
+        match exchange {
+            ExchangeId::BinanceFuturesCoin
+            | ExchangeId::BinanceFuturesUsd
+            | ExchangeId::BinanceOptions
+            | ExchangeId::BinancePortfolioMargin
+            | ExchangeId::BinanceSpot
+            | ExchangeId::BinanceUs
+            | ExchangeId::Kraken => Self::FixedPrecision,
+            _ => Self::Normalized,
+        }
+    }
+
+    /// Format the provided `value`, using the `increment` (eg/ price tick size) as the
+    /// instrument precision.
+    pub fn format(&self, value: Decimal, increment: Option<Decimal>) -> String {
+        match (self, increment) {
+            (Self::FixedPrecision, Some(increment)) => {
+                let decimal_places = increment.normalize().scale();
+                format!(
+                    "{:.*}",
+                    decimal_places as usize,
+                    value.round_dp(decimal_places)
+                )
+            }
+            _ => value.normalize().to_string(),
+        }
+    }
+}
+
+/// [`RequestOpen`] price & quantity formatted for submission to an exchange.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct FormattedRequestOpen {
+    pub price: String,
+    pub quantity: String,
+}
+
+impl FormattedRequestOpen {
+    /// Format the [`RequestOpen`] price & quantity using the [`DecimalFormat`] of the provided
+    /// [`ExchangeId`], and the precision of the optional [`InstrumentSpec`].
+    pub fn new<AssetKey>(
+        exchange: ExchangeId,
+        request: &RequestOpen,
+        spec: Option<&InstrumentSpec<AssetKey>>,
+    ) -> Self {
+        let format = DecimalFormat::for_exchange(exchange);
+
+        Self {
+            price: format.format(request.price, spec.map(|spec| spec.price.tick_size)),
+            quantity: format.format(request.quantity, spec.map(|spec| spec.quantity.increment)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::order::{OrderKind, TimeInForce};
+    use barter_instrument::{
+        Side,
+        asset::name::AssetNameExchange,
+        instrument::spec::{
+            InstrumentSpecNotional, InstrumentSpecPrice, InstrumentSpecQuantity, OrderQuantityUnits,
+        },
+    };
+
+    #[test]
+    fn test_formatted_request_open() {
+        struct TestCase {
+            exchange: ExchangeId,
+            price: Decimal,
+            quantity: Decimal,
+            expected: FormattedRequestOpen,
+        }
+
+        let spec = InstrumentSpec::new(
+            InstrumentSpecPrice::new(Decimal::new(1, 2), Decimal::new(1, 2)),
+            InstrumentSpecQuantity::new(
+                OrderQuantityUnits::<AssetNameExchange>::Quote,
+                Decimal::new(1, 3),
+                Decimal::new(1, 3),
+            ),
+            InstrumentSpecNotional::new(Decimal::from(5)),
+        );
+
+        let tests = vec![
+            TestCase {
+                // TC0: fixed precision venue pads to the instrument precision
+                exchange: ExchangeId::BinanceSpot,
+                price: Decimal::new(2500050, 2),
+                quantity: Decimal::new(1, 1),
+                expected: FormattedRequestOpen {
+                    price: "25000.50".to_string(),
+                    quantity: "0.100".to_string(),
+                },
+            },
+            TestCase {
+                // TC1: normalized venue strips trailing zeros from the same values
+                exchange: ExchangeId::Okx,
+                price: Decimal::new(2500050, 2),
+                quantity: Decimal::new(1, 1),
+                expected: FormattedRequestOpen {
+                    price: "25000.5".to_string(),
+                    quantity: "0.1".to_string(),
+                },
+            },
+            TestCase {
+                // TC2: fixed precision venue rounds excess precision
+                exchange: ExchangeId::Kraken,
+                price: Decimal::new(25000505, 3),
+                quantity: Decimal::new(12345, 5),
+                expected: FormattedRequestOpen {
+                    price: "25000.50".to_string(),
+                    quantity: "0.123".to_string(),
+                },
+            },
+            TestCase {
+                // TC3: tiny values are never formatted using scientific notation
+                exchange: ExchangeId::Okx,
+                price: Decimal::new(1, 12),
+                quantity: Decimal::new(100000, 3),
+                expected: FormattedRequestOpen {
+                    price: "0.000000000001".to_string(),
+                    quantity: "100".to_string(),
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let request = RequestOpen {
+                side: Side::Buy,
+                price: test.price,
+                quantity: test.quantity,
+                kind: OrderKind::Limit,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: false,
+            };
+
+            let actual = FormattedRequestOpen::new(test.exchange, &request, Some(&spec));
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_decimal_format_fixed_precision_without_spec_is_normalized() {
+        assert_eq!(
+            DecimalFormat::FixedPrecision.format(Decimal::new(1500, 3), None),
+            "1.5"
+        );
+    }
+}
@@ -76,3 +76,67 @@ impl StrategyId {
         Self::new("unknown")
     }
 }
+
+/// Generates [`ClientOrderId`]s namespaced by [`StrategyId`], in the form
+/// `{strategy}-{sequence}`.
+///
+/// Since the sequence suffix is strictly numeric, generators of distinct strategies can never
+/// produce the same [`ClientOrderId`], and a single generator never repeats one. Use
+/// [`Self::with_sequence`] to resume a sequence (eg/ after a restart) so ids of a previous
+/// session are not reused.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct ClientOrderIdGenerator {
+    pub strategy: StrategyId,
+    pub sequence: u64,
+}
+
+impl ClientOrderIdGenerator {
+    /// Construct a new [`Self`] for the provided [`StrategyId`], starting at sequence zero.
+    pub fn new(strategy: StrategyId) -> Self {
+        Self::with_sequence(strategy, 0)
+    }
+
+    /// Construct a new [`Self`] for the provided [`StrategyId`], starting at `sequence`.
+    pub fn with_sequence(strategy: StrategyId, sequence: u64) -> Self {
+        Self { strategy, sequence }
+    }
+}
+
+impl Iterator for ClientOrderIdGenerator {
+    type Item = ClientOrderId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cid = ClientOrderId::new(format!("{}-{}", self.strategy, self.sequence));
+        self.sequence = self.sequence.checked_add(1)?;
+        Some(cid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_client_order_id_generator_distinct_across_strategies() {
+        let mut generator_a = ClientOrderIdGenerator::new(StrategyId::new("a"));
+        let mut generator_a_1 = ClientOrderIdGenerator::new(StrategyId::new("a-1"));
+
+        assert_eq!(generator_a.next(), Some(ClientOrderId::new("a-0")));
+        assert_eq!(generator_a_1.next(), Some(ClientOrderId::new("a-1-0")));
+
+        let cids = generator_a
+            .take(100)
+            .chain(generator_a_1.take(100))
+            .collect::<Vec<_>>();
+
+        assert_eq!(cids.iter().collect::<HashSet<_>>().len(), cids.len());
+    }
+
+    #[test]
+    fn test_client_order_id_generator_resumes_sequence() {
+        let mut generator = ClientOrderIdGenerator::with_sequence(StrategyId::new("strategy"), 41);
+        assert_eq!(generator.next(), Some(ClientOrderId::new("strategy-41")));
+        assert_eq!(generator.sequence, 42);
+    }
+}
@@ -1,7 +1,10 @@
-use crate::order::{
-    id::StrategyId,
-    request::{OrderRequestCancel, OrderRequestOpen, RequestCancel, RequestOpen},
-    state::UnindexedOrderState,
+use crate::{
+    error::TimeInForceUnsupported,
+    order::{
+        id::StrategyId,
+        request::{OrderRequestCancel, OrderRequestOpen, RequestCancel, RequestOpen},
+        state::UnindexedOrderState,
+    },
 };
 use barter_instrument::{
     Side,
@@ -28,6 +31,12 @@ pub mod state;
 /// ie/ `OrderRequestOpen` & `OrderRequestCancel`.
 pub mod request;
 
+/// Per-exchange [`TimeInForce`] capabilities & validation.
+pub mod time_in_force;
+
+/// Per-exchange price & quantity formatting for order submission.
+pub mod format;
+
 /// Convenient type alias for an [`Order`] keyed with [`ExchangeId`] and [`InstrumentNameExchange`].
 pub type UnindexedOrder = Order<ExchangeId, InstrumentNameExchange, UnindexedOrderState>;
 
@@ -79,9 +88,23 @@ pub struct Order<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex, S
     pub quantity: Decimal,
     pub kind: OrderKind,
     pub time_in_force: TimeInForce,
+    #[serde(default)]
+    pub reduce_only: bool,
     pub state: State,
 }
 
+impl<ExchangeKey, InstrumentKey, State> Order<ExchangeKey, InstrumentKey, State> {
+    /// Validate the [`Order`] [`TimeInForce`] is supported by the provided [`ExchangeId`].
+    ///
+    /// See [`time_in_force::validate_time_in_force`].
+    pub fn validate_time_in_force(
+        &self,
+        exchange: ExchangeId,
+    ) -> Result<TimeInForce, TimeInForceUnsupported> {
+        time_in_force::validate_time_in_force(exchange, self.time_in_force)
+    }
+}
+
 impl<ExchangeKey, AssetKey, InstrumentKey>
     Order<ExchangeKey, InstrumentKey, OrderState<AssetKey, InstrumentKey>>
 {
@@ -101,6 +124,7 @@ impl<ExchangeKey, AssetKey, InstrumentKey>
             quantity: self.quantity,
             kind: self.kind,
             time_in_force: self.time_in_force,
+            reduce_only: self.reduce_only,
             state: state.clone(),
         })
     }
@@ -124,6 +148,7 @@ impl<ExchangeKey, AssetKey, InstrumentKey>
             quantity: self.quantity,
             kind: self.kind,
             time_in_force: self.time_in_force,
+            reduce_only: self.reduce_only,
             state: state.clone(),
         })
     }
@@ -158,6 +183,58 @@ where
 pub enum OrderKind {
     Market,
     Limit,
+    /// Order that re-prices to track the best bid and offer, priced at the `reference` price
+    /// plus an `offset` towards the opposite side of the book.
+    ///
+    /// A positive `offset` prices the order more aggressively (eg/ a Buy pegged to the
+    /// [`PegReference::BestBid`] with an offset of `1` is priced at best bid + 1).
+    #[display("Peg({reference}, {offset})")]
+    Peg {
+        reference: PegReference,
+        offset: Decimal,
+    },
+    /// Market order that rests until the market trades through the `trigger` price, at which
+    /// point it executes at the prevailing market price.
+    ///
+    /// A Sell stop triggers when the market trades at or below the `trigger`, and a Buy stop
+    /// triggers when the market trades at or above the `trigger`.
+    #[display("Stop({trigger})")]
+    Stop {
+        trigger: Decimal,
+    },
+}
+
+/// Reference price of an [`OrderKind::Peg`] order.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Display,
+)]
+pub enum PegReference {
+    BestBid,
+    BestAsk,
+    Mid,
+}
+
+impl PegReference {
+    /// Price of an [`OrderKind::Peg`] order on the provided `side`, given the current best bid
+    /// and best ask.
+    pub fn price(
+        &self,
+        side: Side,
+        offset: Decimal,
+        best_bid: Decimal,
+        best_ask: Decimal,
+    ) -> Decimal {
+        let reference = match self {
+            Self::BestBid => best_bid,
+            Self::BestAsk => best_ask,
+            Self::Mid => (best_bid + best_ask) / Decimal::TWO,
+        };
+
+        match side {
+            Side::Buy => reference + offset,
+            Side::Sell => reference - offset,
+        }
+    }
 }
 
 #[derive(
@@ -186,6 +263,7 @@ where
                     quantity,
                     kind,
                     time_in_force,
+                    reduce_only,
                 },
         } = value;
 
@@ -196,6 +274,7 @@ where
             quantity: *quantity,
             kind: *kind,
             time_in_force: *time_in_force,
+            reduce_only: *reduce_only,
             state: ActiveOrderState::OpenInFlight(OpenInFlight),
         }
     }
@@ -212,6 +291,7 @@ impl<ExchangeKey, InstrumentKey> From<Order<ExchangeKey, InstrumentKey, Open>>
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state,
         } = value;
 
@@ -222,6 +302,7 @@ impl<ExchangeKey, InstrumentKey> From<Order<ExchangeKey, InstrumentKey, Open>>
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state: ActiveOrderState::Open(state),
         }
     }
@@ -238,6 +319,7 @@ impl<ExchangeKey, AssetKey, InstrumentKey> From<Order<ExchangeKey, InstrumentKey
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state,
         } = value;
 
@@ -248,6 +330,7 @@ impl<ExchangeKey, AssetKey, InstrumentKey> From<Order<ExchangeKey, InstrumentKey
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state: OrderState::Active(ActiveOrderState::Open(state)),
         }
     }
@@ -264,6 +347,7 @@ impl<ExchangeKey, AssetKey, InstrumentKey> From<Order<ExchangeKey, InstrumentKey
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state,
         } = value;
 
@@ -274,6 +358,7 @@ impl<ExchangeKey, AssetKey, InstrumentKey> From<Order<ExchangeKey, InstrumentKey
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state: OrderState::Inactive(InactiveOrderState::Cancelled(state)),
         }
     }
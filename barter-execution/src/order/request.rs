@@ -1,6 +1,10 @@
 use crate::{
     error::OrderError,
-    order::{OrderEvent, OrderKind, TimeInForce, id::OrderId, state::Cancelled},
+    order::{
+        OrderEvent, OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, OrderId},
+        state::Cancelled,
+    },
 };
 use barter_instrument::{
     Side,
@@ -11,6 +15,7 @@ use barter_instrument::{
 use derive_more::Constructor;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 pub type OrderRequestOpen<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> =
     OrderEvent<RequestOpen, ExchangeKey, InstrumentKey>;
@@ -18,6 +23,9 @@ pub type OrderRequestOpen<ExchangeKey = ExchangeIndex, InstrumentKey = Instrumen
 pub type OrderRequestCancel<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> =
     OrderEvent<RequestCancel, ExchangeKey, InstrumentKey>;
 
+pub type OrderRequestBracket<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> =
+    OrderEvent<RequestBracket, ExchangeKey, InstrumentKey>;
+
 pub type OrderResponseCancel<
     ExchangeKey = ExchangeIndex,
     AssetKey = AssetIndex,
@@ -36,6 +44,8 @@ pub struct RequestOpen {
     pub quantity: Decimal,
     pub kind: OrderKind,
     pub time_in_force: TimeInForce,
+    #[serde(default)]
+    pub reduce_only: bool,
 }
 
 #[derive(
@@ -44,3 +54,135 @@ pub struct RequestOpen {
 pub struct RequestCancel {
     pub id: Option<OrderId>,
 }
+
+/// Entry [`RequestOpen`] with optional attached take-profit & stop-loss contingent orders.
+///
+/// Once the entry fills, the take-profit & stop-loss are opened as reduce-only orders on the
+/// opposite side for the entry quantity. When one of them fills, the other is cancelled.
+///
+/// - Take-profit: [`OrderKind::Limit`] order at the `take_profit` price.
+/// - Stop-loss: [`OrderKind::Stop`] order triggered when the market trades through the
+///   `stop_loss` price, executing at the prevailing market price.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct RequestBracket {
+    pub entry: RequestOpen,
+    pub take_profit: Option<Decimal>,
+    pub stop_loss: Option<Decimal>,
+}
+
+impl RequestBracket {
+    /// Validate the take-profit & stop-loss prices are on the profitable & losing sides of the
+    /// entry price respectively.
+    pub fn validate(&self) -> Result<(), RequestBracketError> {
+        let side = self.entry.side;
+        let entry = self.entry.price;
+
+        let (take_profit_invalid, stop_loss_invalid) = match side {
+            Side::Buy => (
+                self.take_profit.filter(|price| *price <= entry),
+                self.stop_loss.filter(|price| *price >= entry),
+            ),
+            Side::Sell => (
+                self.take_profit.filter(|price| *price >= entry),
+                self.stop_loss.filter(|price| *price <= entry),
+            ),
+        };
+
+        if let Some(price) = take_profit_invalid {
+            Err(RequestBracketError::TakeProfit { side, price, entry })
+        } else if let Some(price) = stop_loss_invalid {
+            Err(RequestBracketError::StopLoss { side, price, entry })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Reason a [`RequestBracket`] is invalid.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum RequestBracketError {
+    /// Take-profit price is not on the profitable side of the entry price.
+    #[error("{side} bracket take-profit {price} is not beyond entry price {entry}")]
+    TakeProfit {
+        side: Side,
+        price: Decimal,
+        entry: Decimal,
+    },
+
+    /// Stop-loss price is not on the losing side of the entry price.
+    #[error("{side} bracket stop-loss {price} is not beyond entry price {entry}")]
+    StopLoss {
+        side: Side,
+        price: Decimal,
+        entry: Decimal,
+    },
+}
+
+impl<ExchangeKey, InstrumentKey> OrderRequestBracket<ExchangeKey, InstrumentKey>
+where
+    ExchangeKey: Clone,
+    InstrumentKey: Clone,
+{
+    /// Entry [`OrderRequestOpen`] of the bracket.
+    pub fn entry(&self) -> OrderRequestOpen<ExchangeKey, InstrumentKey> {
+        OrderRequestOpen {
+            key: self.key.clone(),
+            state: self.state.entry.clone(),
+        }
+    }
+
+    /// Take-profit contingent [`OrderRequestOpen`], if configured.
+    ///
+    /// The [`ClientOrderId`] is derived from the entry [`ClientOrderId`] with a `-tp` suffix.
+    pub fn take_profit(&self) -> Option<OrderRequestOpen<ExchangeKey, InstrumentKey>> {
+        self.state.take_profit.map(|price| {
+            self.contingent(
+                "tp",
+                price,
+                OrderKind::Limit,
+                TimeInForce::GoodUntilCancelled { post_only: false },
+            )
+        })
+    }
+
+    /// Stop-loss contingent [`OrderRequestOpen`], if configured.
+    ///
+    /// The [`ClientOrderId`] is derived from the entry [`ClientOrderId`] with a `-sl` suffix.
+    pub fn stop_loss(&self) -> Option<OrderRequestOpen<ExchangeKey, InstrumentKey>> {
+        self.state.stop_loss.map(|price| {
+            self.contingent(
+                "sl",
+                price,
+                OrderKind::Stop { trigger: price },
+                TimeInForce::GoodUntilCancelled { post_only: false },
+            )
+        })
+    }
+
+    fn contingent(
+        &self,
+        suffix: &str,
+        price: Decimal,
+        kind: OrderKind,
+        time_in_force: TimeInForce,
+    ) -> OrderRequestOpen<ExchangeKey, InstrumentKey> {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: self.key.exchange.clone(),
+                instrument: self.key.instrument.clone(),
+                strategy: self.key.strategy.clone(),
+                cid: ClientOrderId::new(format!("{}-{suffix}", self.key.cid)),
+            },
+            state: RequestOpen {
+                side: self.state.entry.side.opposite(),
+                price,
+                quantity: self.state.entry.quantity,
+                kind,
+                time_in_force,
+                reduce_only: true,
+            },
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{error::TimeInForceUnsupported, order::TimeInForce};
+use barter_instrument::exchange::ExchangeId;
+use serde::{Deserialize, Serialize};
+
+/// [`TimeInForce`] variants supported by an exchange.
+///
+/// Used to reject unsupported [`TimeInForce`]s before an order is sent, avoiding a round-trip
+/// to the exchange that would be rejected anyway.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct TimeInForceCapabilities {
+    pub good_until_cancelled: bool,
+    pub post_only: bool,
+    pub good_until_end_of_day: bool,
+    pub fill_or_kill: bool,
+    pub immediate_or_cancel: bool,
+}
+
+impl TimeInForceCapabilities {
+    /// Supports every [`TimeInForce`].
+    pub const ALL: Self = Self {
+        good_until_cancelled: true,
+        post_only: true,
+        good_until_end_of_day: true,
+        fill_or_kill: true,
+        immediate_or_cancel: true,
+    };
+
+    /// Supports GTC, GTC post-only, FOK & IOC, but not good until end of day.
+    pub const STANDARD: Self = Self {
+        good_until_end_of_day: false,
+        ..Self::ALL
+    };
+
+    /// Supports GTC, GTC post-only & IOC.
+    pub const WITHOUT_FILL_OR_KILL: Self = Self {
+        fill_or_kill: false,
+        ..Self::STANDARD
+    };
+
+    /// Capability table for the provided [`ExchangeId`].
+    ///
+    /// Exchanges without a known capability set (including [`ExchangeId::Mock`] and
+    /// [`ExchangeId::Simulated`]) support every [`TimeInForce`], leaving validation to the
+    /// exchange itself.
+    pub fn for_exchange(exchange: ExchangeId) -> Self {
+        match exchange {
+            ExchangeId::BinanceFuturesCoin
+            | ExchangeId::BinanceFuturesUsd
+            | ExchangeId::BinanceOptions
+            | ExchangeId::BinancePortfolioMargin
+            | ExchangeId::BinanceSpot
+            | ExchangeId::BinanceUs
+            | ExchangeId::Bitfinex
+            | ExchangeId::BybitPerpetualsUsd
+            | ExchangeId::BybitSpot
+            | ExchangeId::Coinbase
+            | ExchangeId::GateioFuturesBtc
+            | ExchangeId::GateioFuturesUsd
+            | ExchangeId::GateioOptions
+            | ExchangeId::GateioPerpetualsBtc
+            | ExchangeId::GateioPerpetualsUsd
+            | ExchangeId::GateioSpot
+            | ExchangeId::Okx => Self::STANDARD,
+            ExchangeId::Kraken => Self::WITHOUT_FILL_OR_KILL,
+            _ => Self::ALL,
+        }
+    }
+
+    /// Returns `true` if the provided [`TimeInForce`] is supported.
+    pub fn supports(&self, time_in_force: TimeInForce) -> bool {
+        match time_in_force {
+            TimeInForce::GoodUntilCancelled { post_only: false } => self.good_until_cancelled,
+            TimeInForce::GoodUntilCancelled { post_only: true } => self.post_only,
+            TimeInForce::GoodUntilEndOfDay => self.good_until_end_of_day,
+            TimeInForce::FillOrKill => self.fill_or_kill,
+            TimeInForce::ImmediateOrCancel => self.immediate_or_cancel,
+        }
+    }
+}
+
+/// Validate the provided [`TimeInForce`] against the [`TimeInForceCapabilities`] of the
+/// [`ExchangeId`], returning the [`TimeInForce`] to send to the exchange.
+///
+/// Unsupported [`TimeInForce`]s are rejected rather than translated, since substituting a
+/// different variant (eg/ IOC for FOK) would change the execution semantics of the order.
+pub fn validate_time_in_force(
+    exchange: ExchangeId,
+    time_in_force: TimeInForce,
+) -> Result<TimeInForce, TimeInForceUnsupported> {
+    if TimeInForceCapabilities::for_exchange(exchange).supports(time_in_force) {
+        Ok(time_in_force)
+    } else {
+        Err(TimeInForceUnsupported {
+            exchange,
+            time_in_force,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_time_in_force() {
+        struct TestCase {
+            exchange: ExchangeId,
+            time_in_force: TimeInForce,
+            expected: Result<TimeInForce, TimeInForceUnsupported>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: venue lacking FOK rejects it
+                exchange: ExchangeId::Kraken,
+                time_in_force: TimeInForce::FillOrKill,
+                expected: Err(TimeInForceUnsupported {
+                    exchange: ExchangeId::Kraken,
+                    time_in_force: TimeInForce::FillOrKill,
+                }),
+            },
+            TestCase {
+                // TC1: venue supporting post-only accepts it
+                exchange: ExchangeId::BinanceFuturesUsd,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+                expected: Ok(TimeInForce::GoodUntilCancelled { post_only: true }),
+            },
+            TestCase {
+                // TC2: venue supporting FOK accepts it
+                exchange: ExchangeId::Okx,
+                time_in_force: TimeInForce::FillOrKill,
+                expected: Ok(TimeInForce::FillOrKill),
+            },
+            TestCase {
+                // TC3: venue lacking good until end of day rejects it
+                exchange: ExchangeId::BinanceSpot,
+                time_in_force: TimeInForce::GoodUntilEndOfDay,
+                expected: Err(TimeInForceUnsupported {
+                    exchange: ExchangeId::BinanceSpot,
+                    time_in_force: TimeInForce::GoodUntilEndOfDay,
+                }),
+            },
+            TestCase {
+                // TC4: Mock exchange supports every TimeInForce
+                exchange: ExchangeId::Mock,
+                time_in_force: TimeInForce::GoodUntilEndOfDay,
+                expected: Ok(TimeInForce::GoodUntilEndOfDay),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = validate_time_in_force(test.exchange, test.time_in_force);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{AccountSnapshot, InstrumentAccountSnapshot, balance::AssetBalance};
+use barter_instrument::{asset::AssetIndex, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use tracing::warn;
+
+/// Partial [`AccountSnapshot`] component.
+///
+/// Some exchanges expose account balances and open orders via separate endpoints or stream
+/// messages, so a full [`AccountSnapshot`] can only be assembled once both parts are received.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum PartialAccountSnapshot<
+    ExchangeKey = ExchangeIndex,
+    AssetKey = AssetIndex,
+    InstrumentKey = InstrumentIndex,
+> {
+    Balances(Vec<AssetBalance<AssetKey>>),
+    Instruments(Vec<InstrumentAccountSnapshot<ExchangeKey, AssetKey, InstrumentKey>>),
+}
+
+/// Buffering combiner that assembles [`PartialAccountSnapshot`]s into a coherent
+/// [`AccountSnapshot`].
+///
+/// Each partial is tagged with a `Generation` key (eg/ the time the snapshot was requested).
+/// Partials are only ever merged with partials of the same generation:
+/// - A partial of a newer generation discards any incomplete buffered generation.
+/// - A partial of an older generation than the one buffered is stale, and is ignored.
+/// - A repeated partial of the buffered generation replaces the previously buffered part.
+///
+/// Once both balances and instruments are buffered for a generation, the merged
+/// [`AccountSnapshot`] is emitted and the buffer is cleared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountSnapshotCombiner<
+    ExchangeKey = ExchangeIndex,
+    AssetKey = AssetIndex,
+    InstrumentKey = InstrumentIndex,
+    Generation = DateTime<Utc>,
+> {
+    exchange: ExchangeKey,
+    pending: Option<PendingAccountSnapshot<ExchangeKey, AssetKey, InstrumentKey, Generation>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PendingAccountSnapshot<ExchangeKey, AssetKey, InstrumentKey, Generation> {
+    generation: Generation,
+    balances: Option<Vec<AssetBalance<AssetKey>>>,
+    instruments: Option<Vec<InstrumentAccountSnapshot<ExchangeKey, AssetKey, InstrumentKey>>>,
+}
+
+impl<ExchangeKey, AssetKey, InstrumentKey, Generation>
+    PendingAccountSnapshot<ExchangeKey, AssetKey, InstrumentKey, Generation>
+{
+    fn new(generation: Generation) -> Self {
+        Self {
+            generation,
+            balances: None,
+            instruments: None,
+        }
+    }
+}
+
+impl<ExchangeKey, AssetKey, InstrumentKey, Generation>
+    AccountSnapshotCombiner<ExchangeKey, AssetKey, InstrumentKey, Generation>
+where
+    ExchangeKey: Clone,
+    Generation: Ord + Debug,
+{
+    /// Construct a new [`AccountSnapshotCombiner`] for the provided exchange.
+    pub fn new(exchange: ExchangeKey) -> Self {
+        Self {
+            exchange,
+            pending: None,
+        }
+    }
+
+    /// Returns the generation currently being buffered, if any.
+    pub fn pending_generation(&self) -> Option<&Generation> {
+        self.pending.as_ref().map(|pending| &pending.generation)
+    }
+
+    /// Discard any buffered partials.
+    pub fn clear(&mut self) {
+        self.pending = None;
+    }
+
+    /// Buffer a [`PartialAccountSnapshot`] of the provided generation.
+    ///
+    /// Returns the merged [`AccountSnapshot`] if this partial completes its generation.
+    pub fn push(
+        &mut self,
+        generation: Generation,
+        partial: PartialAccountSnapshot<ExchangeKey, AssetKey, InstrumentKey>,
+    ) -> Option<AccountSnapshot<ExchangeKey, AssetKey, InstrumentKey>> {
+        let mut pending = match self.pending.take() {
+            Some(pending) if pending.generation == generation => pending,
+            Some(pending) if pending.generation > generation => {
+                warn!(
+                    buffered = ?pending.generation,
+                    received = ?generation,
+                    "AccountSnapshotCombiner ignoring stale PartialAccountSnapshot"
+                );
+                self.pending = Some(pending);
+                return None;
+            }
+            Some(pending) => {
+                warn!(
+                    discarded = ?pending.generation,
+                    received = ?generation,
+                    "AccountSnapshotCombiner discarding incomplete PartialAccountSnapshot generation"
+                );
+                PendingAccountSnapshot::new(generation)
+            }
+            None => PendingAccountSnapshot::new(generation),
+        };
+
+        match partial {
+            PartialAccountSnapshot::Balances(balances) => pending.balances = Some(balances),
+            PartialAccountSnapshot::Instruments(instruments) => {
+                pending.instruments = Some(instruments)
+            }
+        }
+
+        match pending {
+            PendingAccountSnapshot {
+                balances: Some(balances),
+                instruments: Some(instruments),
+                ..
+            } => Some(AccountSnapshot::new(
+                self.exchange.clone(),
+                balances,
+                instruments,
+            )),
+            pending => {
+                self.pending = Some(pending);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::balance::Balance;
+    use rust_decimal::Decimal;
+
+    type Combiner = AccountSnapshotCombiner<ExchangeIndex, AssetIndex, InstrumentIndex, u64>;
+
+    fn balances(total: Decimal) -> Vec<AssetBalance<AssetIndex>> {
+        vec![AssetBalance::new(
+            AssetIndex(0),
+            Balance::new(total, total),
+            DateTime::<Utc>::MIN_UTC,
+        )]
+    }
+
+    fn instruments(instrument: usize) -> Vec<InstrumentAccountSnapshot> {
+        vec![InstrumentAccountSnapshot::new(
+            InstrumentIndex(instrument),
+            vec![],
+        )]
+    }
+
+    #[test]
+    fn test_account_snapshot_combiner_merges_partials() {
+        let mut combiner = Combiner::new(ExchangeIndex(0));
+
+        assert_eq!(
+            combiner.push(
+                1,
+                PartialAccountSnapshot::Balances(balances(Decimal::from(1)))
+            ),
+            None
+        );
+        assert_eq!(combiner.pending_generation(), Some(&1));
+
+        let snapshot = combiner
+            .push(1, PartialAccountSnapshot::Instruments(instruments(0)))
+            .unwrap();
+
+        assert_eq!(
+            snapshot,
+            AccountSnapshot::new(ExchangeIndex(0), balances(Decimal::from(1)), instruments(0))
+        );
+        assert_eq!(combiner.pending_generation(), None);
+    }
+
+    #[test]
+    fn test_account_snapshot_combiner_does_not_mix_generations() {
+        let mut combiner = Combiner::new(ExchangeIndex(0));
+
+        // Incomplete generation 1 is discarded by newer generation 2
+        assert!(
+            combiner
+                .push(1, PartialAccountSnapshot::Instruments(instruments(1)))
+                .is_none()
+        );
+        assert!(
+            combiner
+                .push(
+                    2,
+                    PartialAccountSnapshot::Balances(balances(Decimal::from(2)))
+                )
+                .is_none()
+        );
+
+        // Stale generation 1 partial is ignored
+        assert!(
+            combiner
+                .push(1, PartialAccountSnapshot::Instruments(instruments(1)))
+                .is_none()
+        );
+        assert_eq!(combiner.pending_generation(), Some(&2));
+
+        // Repeated partial of the same generation replaces the buffered part
+        assert!(
+            combiner
+                .push(
+                    2,
+                    PartialAccountSnapshot::Balances(balances(Decimal::from(3)))
+                )
+                .is_none()
+        );
+
+        let snapshot = combiner
+            .push(2, PartialAccountSnapshot::Instruments(instruments(2)))
+            .unwrap();
+
+        assert_eq!(
+            snapshot,
+            AccountSnapshot::new(ExchangeIndex(0), balances(Decimal::from(3)), instruments(2))
+        );
+        assert_eq!(combiner.pending_generation(), None);
+    }
+}
@@ -29,6 +29,8 @@ pub struct Trade<AssetKey, InstrumentKey> {
     pub price: Decimal,
     pub quantity: Decimal,
     pub fees: AssetFees<AssetKey>,
+    #[serde(default)]
+    pub reduce_only: bool,
 }
 
 impl<AssetKey, InstrumentKey> Trade<AssetKey, InstrumentKey> {
@@ -5,7 +5,11 @@ use crate::{
     index::{
         IndexedInstruments, find_asset_by_exchange_and_name_internal, find_exchange_by_exchange_id,
     },
-    instrument::{Instrument, InstrumentIndex, spec::OrderQuantityUnits},
+    instrument::{
+        Instrument, InstrumentIndex,
+        normalise::{SymbolError, SymbolNormaliser},
+        spec::OrderQuantityUnits,
+    },
 };
 
 #[derive(Debug, Default)]
@@ -59,6 +63,19 @@ impl IndexedInstrumentsBuilder {
         self
     }
 
+    /// Add an [`Instrument`] from an exchange-native symbol (eg/ "btcusdt"), using the provided
+    /// [`SymbolNormaliser`] to derive its canonical `Underlying` and kind.
+    pub fn add_symbol(
+        self,
+        normaliser: &SymbolNormaliser,
+        exchange: ExchangeId,
+        symbol: &str,
+    ) -> Result<Self, SymbolError> {
+        normaliser
+            .instrument(exchange, symbol)
+            .map(|instrument| self.add_instrument(instrument))
+    }
+
     pub fn build(mut self) -> IndexedInstruments {
         // Sort & dedup
         self.exchanges.sort();
@@ -110,11 +127,7 @@ impl IndexedInstrumentsBuilder {
             })
             .collect();
 
-        IndexedInstruments {
-            exchanges,
-            instruments,
-            assets,
-        }
+        IndexedInstruments::from_parts(exchanges, assets, instruments)
     }
 }
 
@@ -245,6 +258,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_builder_add_symbol_normalises_underlying() {
+        let normaliser = SymbolNormaliser::default();
+
+        let indexed = [
+            (ExchangeId::Coinbase, "BTC-USDT"),
+            (ExchangeId::BinanceSpot, "btcusdt"),
+            (ExchangeId::GateioSpot, "BTC_USDT"),
+            (ExchangeId::Kraken, "BTC/USDT"),
+        ]
+        .into_iter()
+        .try_fold(
+            IndexedInstrumentsBuilder::default(),
+            |builder, (exchange, symbol)| builder.add_symbol(&normaliser, exchange, symbol),
+        )
+        .unwrap()
+        .build();
+
+        assert_eq!(indexed.instruments().len(), 4);
+        for instrument in indexed.instruments() {
+            let base = indexed
+                .find_asset(instrument.value.underlying.base)
+                .unwrap();
+            let quote = indexed
+                .find_asset(instrument.value.underlying.quote)
+                .unwrap();
+            assert_eq!(base.asset.name_internal.name(), "btc");
+            assert_eq!(quote.asset.name_internal.name(), "usdt");
+        }
+    }
+
     #[test]
     fn test_builder_ordering() {
         // Add instruments in any order
@@ -2,16 +2,26 @@ use crate::{
     Keyed,
     asset::{Asset, AssetIndex, ExchangeAsset, name::AssetNameInternal},
     exchange::{ExchangeId, ExchangeIndex},
-    index::{builder::IndexedInstrumentsBuilder, error::IndexError},
-    instrument::{Instrument, InstrumentIndex, name::InstrumentNameInternal},
+    index::{
+        builder::IndexedInstrumentsBuilder,
+        error::IndexError,
+        search::{InstrumentMatch, SearchFields, query_terms},
+    },
+    instrument::{
+        Instrument, InstrumentIndex, kind::InstrumentKindTag, name::InstrumentNameInternal,
+    },
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 pub mod builder;
 
 /// Contains error variants that can occur when working with an [`IndexedInstruments`] collection.
 pub mod error;
 
+/// Exchange-agnostic instrument search utilities used by [`IndexedInstruments::search`].
+pub mod search;
+
 /// Indexed collection of exchanges, assets, and instruments.
 ///
 /// Initialise incrementally via the [`IndexedInstrumentsBuilder`], or all at once via the
@@ -27,11 +37,58 @@ pub mod error;
 /// - `InstrumentIndex`: Unique identifier for each [`Instrument`] added during initialisation.
 /// - `AssetIndex`: Unique identifier for each [`ExchangeAsset`] added during initialisation.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+#[serde(from = "IndexedInstrumentsParts")]
 pub struct IndexedInstruments {
     exchanges: Vec<Keyed<ExchangeIndex, ExchangeId>>,
     assets: Vec<Keyed<AssetIndex, ExchangeAsset<Asset>>>,
     instruments:
         Vec<Keyed<InstrumentIndex, Instrument<Keyed<ExchangeIndex, ExchangeId>, AssetIndex>>>,
+
+    /// Instrument lookup tables derived from the indexed `instruments`, built once at
+    /// construction.
+    #[serde(skip)]
+    lookup: InstrumentLookup,
+}
+
+/// Serialised form of an [`IndexedInstruments`], used to rebuild the [`InstrumentLookup`]
+/// tables during deserialisation.
+#[derive(Deserialize)]
+struct IndexedInstrumentsParts {
+    exchanges: Vec<Keyed<ExchangeIndex, ExchangeId>>,
+    assets: Vec<Keyed<AssetIndex, ExchangeAsset<Asset>>>,
+    instruments:
+        Vec<Keyed<InstrumentIndex, Instrument<Keyed<ExchangeIndex, ExchangeId>, AssetIndex>>>,
+}
+
+impl From<IndexedInstrumentsParts> for IndexedInstruments {
+    fn from(value: IndexedInstrumentsParts) -> Self {
+        let IndexedInstrumentsParts {
+            exchanges,
+            assets,
+            instruments,
+        } = value;
+
+        Self::from_parts(exchanges, assets, instruments)
+    }
+}
+
+/// [`InstrumentLookup`] key of an instrument, identified by its [`ExchangeId`], base & quote
+/// [`AssetNameInternal`]s, and [`InstrumentKindTag`].
+type UnderlyingKey = (
+    ExchangeId,
+    AssetNameInternal,
+    AssetNameInternal,
+    InstrumentKindTag,
+);
+
+/// Instrument lookup tables of an [`IndexedInstruments`] collection.
+#[derive(Debug, Clone, Default, PartialEq, PartialOrd)]
+struct InstrumentLookup {
+    /// [`InstrumentIndex`] of each [`InstrumentNameInternal`].
+    by_name: BTreeMap<InstrumentNameInternal, InstrumentIndex>,
+
+    /// First indexed [`InstrumentIndex`] of each [`UnderlyingKey`].
+    by_underlying: BTreeMap<UnderlyingKey, InstrumentIndex>,
 }
 
 impl IndexedInstruments {
@@ -57,6 +114,54 @@ impl IndexedInstruments {
             .build()
     }
 
+    /// Construct an `IndexedInstruments` from already indexed exchanges, assets, and
+    /// instruments, building the instrument lookup tables.
+    fn from_parts(
+        exchanges: Vec<Keyed<ExchangeIndex, ExchangeId>>,
+        assets: Vec<Keyed<AssetIndex, ExchangeAsset<Asset>>>,
+        instruments: Vec<
+            Keyed<InstrumentIndex, Instrument<Keyed<ExchangeIndex, ExchangeId>, AssetIndex>>,
+        >,
+    ) -> Self {
+        let mut lookup = InstrumentLookup::default();
+
+        for indexed in &instruments {
+            lookup
+                .by_name
+                .insert(indexed.value.name_internal.clone(), indexed.key);
+
+            let asset_name = |index: AssetIndex| {
+                assets
+                    .get(index.index())
+                    .map(|asset| asset.value.asset.name_internal.clone())
+            };
+
+            let (Some(base), Some(quote)) = (
+                asset_name(indexed.value.underlying.base),
+                asset_name(indexed.value.underlying.quote),
+            ) else {
+                continue;
+            };
+
+            lookup
+                .by_underlying
+                .entry((
+                    indexed.value.exchange.value,
+                    base,
+                    quote,
+                    indexed.value.kind.tag(),
+                ))
+                .or_insert(indexed.key);
+        }
+
+        Self {
+            exchanges,
+            assets,
+            instruments,
+            lookup,
+        }
+    }
+
     /// Returns a new [`IndexedInstrumentsBuilder`] useful for incremental initialisation of
     /// `IndexedInstruments`.
     pub fn builder() -> IndexedInstrumentsBuilder {
@@ -156,6 +261,152 @@ impl IndexedInstruments {
             )))
     }
 
+    /// Finds the [`InstrumentIndex`] of the instrument with the provided base & quote
+    /// [`AssetNameInternal`]s, [`InstrumentKindTag`], and [`ExchangeId`].
+    ///
+    /// If multiple instruments match (eg/ futures with different expiries), the first indexed
+    /// instrument is returned.
+    ///
+    /// # Arguments
+    /// * `base` - The `AssetNameInternal` of the underlying base asset (eg/ "btc").
+    /// * `quote` - The `AssetNameInternal` of the underlying quote asset (eg/ "usdt").
+    /// * `kind` - The `InstrumentKindTag` of the instrument (eg/ `Perpetual`).
+    /// * `exchange` - The `ExchangeId` associated with the instrument.
+    pub fn find(
+        &self,
+        base: &AssetNameInternal,
+        quote: &AssetNameInternal,
+        kind: InstrumentKindTag,
+        exchange: ExchangeId,
+    ) -> Option<InstrumentIndex> {
+        self.lookup
+            .by_underlying
+            .get(&(exchange, base.clone(), quote.clone(), kind))
+            .copied()
+    }
+
+    /// Searches the indexed instruments using a free-text query of partial symbols, returning
+    /// [`InstrumentMatch`] candidates ranked by match quality.
+    ///
+    /// Each query term (eg/ "btc", "perp", "binance") is matched against the instrument base
+    /// asset, quote asset, [`ExchangeId`], [`InstrumentKindTag`], and internal name. Instruments
+    /// that match more terms rank first, with ties broken by the total match score and then by
+    /// [`InstrumentIndex`]. Instruments matching no terms are excluded.
+    ///
+    /// # Arguments
+    /// * `query` - Free-text query (eg/ "btc perp binance").
+    pub fn search(&self, query: &str) -> Vec<InstrumentMatch> {
+        let terms = query_terms(query);
+
+        let asset_name = |index: AssetIndex| {
+            self.assets
+                .get(index.index())
+                .map(|asset| asset.value.asset.name_internal.name().as_str())
+                .unwrap_or_default()
+        };
+
+        let mut matches = self
+            .instruments
+            .iter()
+            .filter_map(|indexed| {
+                let fields = SearchFields {
+                    exchange: indexed.value.exchange.value,
+                    base: asset_name(indexed.value.underlying.base),
+                    quote: asset_name(indexed.value.underlying.quote),
+                    kind: indexed.value.kind.tag(),
+                    name_internal: indexed.value.name_internal.name().as_str(),
+                };
+
+                let (matched_terms, score) = terms
+                    .iter()
+                    .map(|term| fields.score(term))
+                    .filter(|score| *score > 0)
+                    .fold((0, 0), |(matched, total), score| {
+                        (matched + 1, total + score)
+                    });
+
+                (matched_terms > 0).then_some(InstrumentMatch {
+                    instrument: indexed.key,
+                    matched_terms,
+                    score,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        matches.sort_by(|a, b| {
+            b.matched_terms
+                .cmp(&a.matched_terms)
+                .then(b.score.cmp(&a.score))
+                .then(a.instrument.cmp(&b.instrument))
+        });
+
+        matches
+    }
+
+    /// Returns the [`InstrumentIndex`] associated with the provided [`InstrumentNameInternal`],
+    /// or `None` if it is not indexed.
+    ///
+    /// Note that an `InstrumentNameInternal` is unique across exchanges.
+    ///
+    /// # Arguments
+    /// * `name` - The `InstrumentNameInternal` of the instrument (eg/ binance_spot-btc_usdt).
+    pub fn index_of(&self, name: &InstrumentNameInternal) -> Option<InstrumentIndex> {
+        self.lookup.by_name.get(name).copied()
+    }
+
+    /// Returns the [`InstrumentNameInternal`] associated with the provided [`InstrumentIndex`],
+    /// or `None` if it is not indexed.
+    ///
+    /// # Arguments
+    /// * `index` - The `InstrumentIndex` of the instrument.
+    pub fn name_of(&self, index: InstrumentIndex) -> Option<&InstrumentNameInternal> {
+        self.instruments
+            .get(index.index())
+            .map(|indexed| &indexed.value.name_internal)
+    }
+
+    /// Remaps a batch of [`InstrumentNameInternal`] keyed values to [`InstrumentIndex`] keyed
+    /// values, preserving order.
+    ///
+    /// Useful at integration boundaries where external data is keyed by instrument name.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Keyed<InstrumentIndex, Value>>)` - every instrument name found.
+    /// * `Err(IndexError)` - one or more instrument names not found, all of which are described.
+    pub fn remap_to_index<Iter, Value>(
+        &self,
+        keyed: Iter,
+    ) -> Result<Vec<Keyed<InstrumentIndex, Value>>, IndexError>
+    where
+        Iter: IntoIterator<Item = Keyed<InstrumentNameInternal, Value>>,
+    {
+        remap_keys(keyed, |name| self.index_of(name)).map_err(|unknown| {
+            IndexError::InstrumentIndex(format!(
+                "Instruments: {unknown:?} are not present in indexed instruments"
+            ))
+        })
+    }
+
+    /// Remaps a batch of [`InstrumentIndex`] keyed values to [`InstrumentNameInternal`] keyed
+    /// values, preserving order.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Keyed<InstrumentNameInternal, Value>>)` - every instrument index found.
+    /// * `Err(IndexError)` - one or more instrument indexes not found, all of which are described.
+    pub fn remap_to_name<Iter, Value>(
+        &self,
+        keyed: Iter,
+    ) -> Result<Vec<Keyed<InstrumentNameInternal, Value>>, IndexError>
+    where
+        Iter: IntoIterator<Item = Keyed<InstrumentIndex, Value>>,
+    {
+        remap_keys(keyed, |index| self.name_of(*index).cloned()).map_err(|unknown| {
+            IndexError::InstrumentIndex(format!(
+                "InstrumentIndexes: {unknown:?} are not present in indexed instruments"
+            ))
+        })
+    }
+
     pub fn find_instrument(
         &self,
         index: InstrumentIndex,
@@ -182,6 +433,36 @@ where
     }
 }
 
+/// Remaps the key of every [`Keyed`] value using the provided `lookup`, preserving order.
+///
+/// Returns every key the `lookup` could not resolve if any are unknown.
+fn remap_keys<Iter, Key, NewKey, Value>(
+    keyed: Iter,
+    lookup: impl Fn(&Key) -> Option<NewKey>,
+) -> Result<Vec<Keyed<NewKey, Value>>, Vec<Key>>
+where
+    Iter: IntoIterator<Item = Keyed<Key, Value>>,
+{
+    let mut unknown = Vec::new();
+
+    let remapped = keyed
+        .into_iter()
+        .filter_map(|Keyed { key, value }| match lookup(&key) {
+            Some(new_key) => Some(Keyed::new(new_key, value)),
+            None => {
+                unknown.push(key);
+                None
+            }
+        })
+        .collect();
+
+    if unknown.is_empty() {
+        Ok(remapped)
+    } else {
+        Err(unknown)
+    }
+}
+
 fn find_exchange_by_exchange_id(
     haystack: &[Keyed<ExchangeIndex, ExchangeId>],
     needle: &ExchangeId,
@@ -220,9 +501,11 @@ mod tests {
         asset::Asset,
         exchange::ExchangeId,
         instrument::{
-            kind::InstrumentKind, name::InstrumentNameExchange, quote::InstrumentQuoteAsset,
+            kind::{InstrumentKind, perpetual::PerpetualContract},
+            name::InstrumentNameExchange,
+            quote::InstrumentQuoteAsset,
         },
-        test_utils::{exchange_asset, instrument},
+        test_utils::{asset, exchange_asset, instrument},
     };
 
     #[test]
@@ -382,6 +665,77 @@ mod tests {
         assert!(matches!(err, IndexError::AssetIndex(_)));
     }
 
+    #[test]
+    fn test_index_of_and_name_of() {
+        let indexed = IndexedInstruments::new(vec![
+            instrument(ExchangeId::BinanceSpot, "btc", "usdt"),
+            instrument(ExchangeId::Coinbase, "eth", "usd"),
+        ]);
+        let eth_usd = InstrumentNameInternal::from("coinbase-eth_usd");
+
+        // Hit
+        assert_eq!(indexed.index_of(&eth_usd), Some(InstrumentIndex(1)));
+        assert_eq!(indexed.name_of(InstrumentIndex(1)), Some(&eth_usd));
+
+        // Miss
+        assert_eq!(
+            indexed.index_of(&InstrumentNameInternal::from("kraken-eth_usd")),
+            None
+        );
+        assert_eq!(indexed.name_of(InstrumentIndex(2)), None);
+    }
+
+    #[test]
+    fn test_remap_to_index_and_name() {
+        let indexed = IndexedInstruments::new(vec![
+            instrument(ExchangeId::BinanceSpot, "btc", "usdt"),
+            instrument(ExchangeId::Coinbase, "eth", "usd"),
+        ]);
+        let btc_usdt = InstrumentNameInternal::from("binance_spot-btc_usdt");
+        let eth_usd = InstrumentNameInternal::from("coinbase-eth_usd");
+
+        // All names known, so every value is remapped in order
+        let remapped = indexed
+            .remap_to_index(vec![
+                Keyed::new(eth_usd.clone(), "eth"),
+                Keyed::new(btc_usdt.clone(), "btc"),
+            ])
+            .unwrap();
+        assert_eq!(
+            remapped,
+            vec![
+                Keyed::new(InstrumentIndex(1), "eth"),
+                Keyed::new(InstrumentIndex(0), "btc"),
+            ]
+        );
+
+        // Remapping back to names round-trips
+        assert_eq!(
+            indexed.remap_to_name(remapped).unwrap(),
+            vec![
+                Keyed::new(eth_usd.clone(), "eth"),
+                Keyed::new(btc_usdt, "btc")
+            ]
+        );
+
+        // One unknown name fails the batch with an error describing it
+        let err = indexed
+            .remap_to_index(vec![
+                Keyed::new(eth_usd, "eth"),
+                Keyed::new(InstrumentNameInternal::from("kraken-sol_usd"), "sol"),
+            ])
+            .unwrap_err();
+        assert!(
+            matches!(&err, IndexError::InstrumentIndex(description) if description.contains("kraken-sol_usd"))
+        );
+
+        // One unknown index fails the batch
+        let err = indexed
+            .remap_to_name(vec![Keyed::new(InstrumentIndex(5), "unknown")])
+            .unwrap_err();
+        assert!(matches!(err, IndexError::InstrumentIndex(_)));
+    }
+
     #[test]
     fn test_private_find_exchange_by_exchange_id() {
         let exchanges = vec![
@@ -476,4 +830,125 @@ mod tests {
         assert_eq!(indexed.assets().len(), 4); // BTC and USDT on both exchanges
         assert_eq!(indexed.instruments().len(), 2);
     }
+
+    fn perpetual(exchange: ExchangeId, base: &str, quote: &str) -> Instrument<ExchangeId, Asset> {
+        Instrument {
+            kind: InstrumentKind::Perpetual(PerpetualContract {
+                contract_size: rust_decimal::Decimal::ONE,
+                settlement_asset: asset(quote),
+            }),
+            ..instrument(exchange, base, quote)
+        }
+    }
+
+    fn search_universe() -> IndexedInstruments {
+        IndexedInstruments::new([
+            instrument(ExchangeId::BinanceSpot, "btc", "usdt"),
+            perpetual(ExchangeId::BinanceFuturesUsd, "btc", "usdt"),
+            instrument(ExchangeId::BinanceSpot, "eth", "usdt"),
+            perpetual(ExchangeId::Okx, "btc", "usdt"),
+        ])
+    }
+
+    fn index_of(indexed: &IndexedInstruments, exchange: ExchangeId, name: &str) -> InstrumentIndex {
+        indexed
+            .find_instrument_index(
+                exchange,
+                &InstrumentNameInternal::new_from_exchange(exchange, name),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_find_exact_match() {
+        let indexed = search_universe();
+
+        assert_eq!(
+            indexed.find(
+                &AssetNameInternal::from("btc"),
+                &AssetNameInternal::from("usdt"),
+                InstrumentKindTag::Perpetual,
+                ExchangeId::BinanceFuturesUsd,
+            ),
+            Some(index_of(
+                &indexed,
+                ExchangeId::BinanceFuturesUsd,
+                "btc_usdt"
+            ))
+        );
+        assert_eq!(
+            indexed.find(
+                &AssetNameInternal::from("btc"),
+                &AssetNameInternal::from("usdt"),
+                InstrumentKindTag::Spot,
+                ExchangeId::BinanceSpot,
+            ),
+            Some(index_of(&indexed, ExchangeId::BinanceSpot, "btc_usdt"))
+        );
+
+        // Kind mismatch
+        assert_eq!(
+            indexed.find(
+                &AssetNameInternal::from("eth"),
+                &AssetNameInternal::from("usdt"),
+                InstrumentKindTag::Perpetual,
+                ExchangeId::BinanceSpot,
+            ),
+            None
+        );
+
+        // Asset not present on exchange
+        assert_eq!(
+            indexed.find(
+                &AssetNameInternal::from("eth"),
+                &AssetNameInternal::from("usdt"),
+                InstrumentKindTag::Perpetual,
+                ExchangeId::Okx,
+            ),
+            None
+        );
+
+        // Lookup tables are rebuilt when deserialised
+        let deserialised: IndexedInstruments =
+            serde_json::from_str(&serde_json::to_string(&indexed).unwrap()).unwrap();
+        assert_eq!(deserialised, indexed);
+    }
+
+    #[test]
+    fn test_search_partial_query_ranked() {
+        let indexed = search_universe();
+
+        let ranked = indexed
+            .search("BTC perpetual bin")
+            .into_iter()
+            .map(|candidate| (candidate.instrument, candidate.matched_terms))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            ranked,
+            vec![
+                // Matches base, kind & exchange prefix
+                (
+                    index_of(&indexed, ExchangeId::BinanceFuturesUsd, "btc_usdt"),
+                    3
+                ),
+                // Matches base & kind
+                (index_of(&indexed, ExchangeId::Okx, "btc_usdt"), 2),
+                // Matches base & exchange prefix, which scores lower than an exact kind match
+                (index_of(&indexed, ExchangeId::BinanceSpot, "btc_usdt"), 2),
+                // Matches exchange prefix only
+                (index_of(&indexed, ExchangeId::BinanceSpot, "eth_usdt"), 1),
+            ]
+        );
+
+        // Only instruments matching at least one term are returned
+        let ranked = indexed.search("eth");
+        assert_eq!(ranked.len(), 1);
+        assert_eq!(
+            ranked[0].instrument,
+            index_of(&indexed, ExchangeId::BinanceSpot, "eth_usdt")
+        );
+
+        assert!(indexed.search("sol").is_empty());
+    }
 }
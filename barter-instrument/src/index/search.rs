@@ -0,0 +1,76 @@
+use crate::{
+    exchange::ExchangeId, instrument::InstrumentIndex, instrument::kind::InstrumentKindTag,
+};
+use serde::{Deserialize, Serialize};
+
+/// Candidate [`InstrumentIndex`] returned by
+/// [`IndexedInstruments::search`](super::IndexedInstruments::search).
+///
+/// Candidates are ranked by the number of query terms matched, then by the total match `score`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct InstrumentMatch {
+    pub instrument: InstrumentIndex,
+    pub matched_terms: usize,
+    pub score: u32,
+}
+
+/// Searchable fields of an indexed [`Instrument`](crate::instrument::Instrument).
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct SearchFields<'a> {
+    pub exchange: ExchangeId,
+    pub base: &'a str,
+    pub quote: &'a str,
+    pub kind: InstrumentKindTag,
+    pub name_internal: &'a str,
+}
+
+impl SearchFields<'_> {
+    /// Score how well a lowercase query `term` matches these fields, returning `0` if it does
+    /// not match any field.
+    ///
+    /// Exact matches score higher than prefix matches, and base asset matches score higher than
+    /// quote asset, exchange, or kind matches. Any other substring of the internal instrument
+    /// name is a weak match.
+    pub(crate) fn score(&self, term: &str) -> u32 {
+        let kind = match self.kind {
+            InstrumentKindTag::Spot => "spot",
+            InstrumentKindTag::Perpetual => "perpetual",
+            InstrumentKindTag::Future => "future",
+            InstrumentKindTag::Option => "option",
+        };
+
+        [
+            (self.base, 100, 50),
+            (self.quote, 80, 40),
+            (self.exchange.as_str(), 60, 30),
+            (kind, 60, 30),
+        ]
+        .into_iter()
+        .map(|(field, exact, prefix)| {
+            if field == term {
+                exact
+            } else if field.starts_with(term) {
+                prefix
+            } else {
+                0
+            }
+        })
+        .chain(std::iter::once(if self.name_internal.contains(term) {
+            10
+        } else {
+            0
+        }))
+        .max()
+        .unwrap_or(0)
+    }
+}
+
+/// Split a free-text instrument query (eg/ "BTC perp on binance", "btc/usdt") into lowercase
+/// terms.
+pub(crate) fn query_terms(query: &str) -> Vec<String> {
+    query
+        .split(|c: char| c.is_whitespace() || matches!(c, '/' | '-' | '_' | ','))
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
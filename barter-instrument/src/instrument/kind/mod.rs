@@ -4,6 +4,7 @@ use crate::instrument::{
         MarketDataFutureContract, MarketDataInstrumentKind, MarketDataOptionContract,
     },
 };
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -26,7 +27,31 @@ pub enum InstrumentKind<AssetKey> {
     Option(OptionContract<AssetKey>),
 }
 
+/// Contract-agnostic discriminant of an [`InstrumentKind`], one of `Spot`, `Perpetual`,
+/// `Future` and `Option`.
+///
+/// Useful for selecting instruments by kind (eg/ "all perpetuals") regardless of their contract
+/// details.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstrumentKindTag {
+    Spot,
+    Perpetual,
+    Future,
+    Option,
+}
+
 impl<AssetKey> InstrumentKind<AssetKey> {
+    /// Returns the contract-agnostic [`InstrumentKindTag`] of the `InstrumentKind`.
+    pub fn tag(&self) -> InstrumentKindTag {
+        match self {
+            InstrumentKind::Spot => InstrumentKindTag::Spot,
+            InstrumentKind::Perpetual(_) => InstrumentKindTag::Perpetual,
+            InstrumentKind::Future(_) => InstrumentKindTag::Future,
+            InstrumentKind::Option(_) => InstrumentKindTag::Option,
+        }
+    }
+
     /// Returns the `contract_size` value for the `InstrumentKind`.
     ///
     /// Note that `Spot` is always `Decimal::ONE`.
@@ -50,6 +75,16 @@ impl<AssetKey> InstrumentKind<AssetKey> {
         }
     }
 
+    /// For `Future` & `Option` variants of [`Self`], returns the contract expiry, and `None` for
+    /// `Spot` & `Perpetual`.
+    pub fn expiry(&self) -> Option<DateTime<Utc>> {
+        match self {
+            InstrumentKind::Spot | InstrumentKind::Perpetual(_) => None,
+            InstrumentKind::Future(kind) => Some(kind.expiry),
+            InstrumentKind::Option(kind) => Some(kind.expiry),
+        }
+    }
+
     /// Determines if the provided [`MarketDataInstrumentKind`] is equivalent to [`Self`] (ignores
     /// settlement asset).
     pub fn eq_market_data_instrument_kind(&self, other: &MarketDataInstrumentKind) -> bool {
@@ -13,6 +13,7 @@ use crate::{
     },
 };
 use derive_more::{Constructor, Display};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt::Formatter;
 
@@ -36,6 +37,10 @@ pub mod market_data;
 /// Defines the [`InstrumentQuoteAsset`] (underlying base or quote) for an [`Instrument`].
 pub mod quote;
 
+/// Defines the [`SymbolNormaliser`](normalise::SymbolNormaliser), which maps exchange-native
+/// instrument symbols (eg/ "BTC-USD", "btcusdt") to a canonical `(base, quote, kind)` and back.
+pub mod normalise;
+
 /// Unique identifier for an `Instrument` traded on an execution.
 ///
 /// Used to key data events in a memory efficient way.
@@ -130,6 +135,13 @@ impl<ExchangeKey, AssetKey> Instrument<ExchangeKey, AssetKey> {
         }
     }
 
+    /// Returns the contract multiplier of this `Instrument`, where `Spot` is always `1`.
+    ///
+    /// See [`InstrumentKind::contract_size`].
+    pub fn contract_size(&self) -> Decimal {
+        self.kind.contract_size()
+    }
+
     /// Map this Instruments `ExchangeKey` to a new key.
     pub fn map_exchange_key<NewExchangeKey>(
         self,
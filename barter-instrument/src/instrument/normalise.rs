@@ -0,0 +1,538 @@
+use crate::{
+    Underlying,
+    asset::{Asset, name::AssetNameInternal},
+    exchange::ExchangeId,
+    instrument::{
+        Instrument,
+        kind::{InstrumentKind, future::FutureContract, perpetual::PerpetualContract},
+        market_data::kind::MarketDataInstrumentKind,
+        name::{InstrumentNameExchange, InstrumentNameInternal},
+        quote::InstrumentQuoteAsset,
+    },
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use smol_str::{SmolStr, StrExt, format_smolstr};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Separators recognised when splitting an exchange-native symbol into base and quote.
+const SEPARATORS: [char; 4] = ['-', '_', '/', ':'];
+
+/// Perpetual suffixes recognised (case-insensitively) on any exchange.
+const PERPETUAL_SUFFIXES: [&str; 5] = ["-perpetual", "_perpetual", "-swap", "-perp", "_perp"];
+
+/// Quote assets used to split unseparated symbols (eg/ "btcusdt"), longest match first.
+const DEFAULT_QUOTES: [&str; 14] = [
+    "fdusd", "usdt", "usdc", "busd", "tusd", "dai", "usd", "eur", "gbp", "jpy", "try", "btc",
+    "eth", "bnb",
+];
+
+/// Canonical `(base, quote, kind)` representation of an exchange-native instrument symbol.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct NormalisedSymbol {
+    pub underlying: Underlying<AssetNameInternal>,
+    pub kind: MarketDataInstrumentKind,
+}
+
+impl NormalisedSymbol {
+    /// Construct a new [`Self`] from the provided base, quote and kind.
+    pub fn new<A>(base: A, quote: A, kind: MarketDataInstrumentKind) -> Self
+    where
+        A: Into<AssetNameInternal>,
+    {
+        Self {
+            underlying: Underlying::new(base, quote),
+            kind,
+        }
+    }
+}
+
+/// Casing used by an exchange for its native instrument symbols.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum SymbolCase {
+    Upper,
+    Lower,
+}
+
+/// Describes how an exchange formats its native instrument symbols.
+///
+/// Used to generate an [`InstrumentNameExchange`] from a [`NormalisedSymbol`].
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct SymbolFormat {
+    pub separator: Option<char>,
+    pub case: SymbolCase,
+    pub perpetual_suffix: Option<SmolStr>,
+}
+
+impl SymbolFormat {
+    /// Construct a new [`Self`] with no perpetual suffix.
+    pub fn new(separator: Option<char>, case: SymbolCase) -> Self {
+        Self {
+            separator,
+            case,
+            perpetual_suffix: None,
+        }
+    }
+
+    /// Set the suffix appended to perpetual symbols (eg/ "-SWAP").
+    pub fn with_perpetual_suffix<S>(self, suffix: S) -> Self
+    where
+        S: Into<SmolStr>,
+    {
+        Self {
+            perpetual_suffix: Some(suffix.into()),
+            ..self
+        }
+    }
+
+    /// Default [`SymbolFormat`] for the provided [`ExchangeId`].
+    ///
+    /// Exchanges without a known format fall back to lowercase "base_quote".
+    pub fn default_for(exchange: ExchangeId) -> Self {
+        use ExchangeId::*;
+        match exchange {
+            Coinbase | CoinbaseInternational | Kucoin => Self::new(Some('-'), SymbolCase::Upper),
+            Okx => Self::new(Some('-'), SymbolCase::Upper).with_perpetual_suffix("-SWAP"),
+            GateioSpot | GateioFuturesUsd | GateioFuturesBtc | GateioPerpetualsUsd
+            | GateioPerpetualsBtc | GateioOptions | Poloniex => {
+                Self::new(Some('_'), SymbolCase::Upper)
+            }
+            Kraken => Self::new(Some('/'), SymbolCase::Upper),
+            BinanceSpot
+            | BinanceUs
+            | BinanceFuturesUsd
+            | BinanceFuturesCoin
+            | BinancePortfolioMargin
+            | BybitSpot
+            | BybitPerpetualsUsd
+            | Bitmart
+            | BitmartFuturesUsd
+            | Mexc => Self::new(None, SymbolCase::Upper),
+            Bitstamp => Self::new(None, SymbolCase::Lower),
+            _ => Self::new(Some('_'), SymbolCase::Lower),
+        }
+    }
+}
+
+/// All errors generated when normalising or formatting exchange-native instrument symbols.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
+pub enum SymbolError {
+    #[error("{exchange} symbol {symbol} could not be split into a base and quote asset")]
+    Unrecognised {
+        exchange: ExchangeId,
+        symbol: SmolStr,
+    },
+
+    #[error("{exchange} symbol cannot be generated for {kind} without an explicit override")]
+    UnsupportedKind {
+        exchange: ExchangeId,
+        kind: MarketDataInstrumentKind,
+    },
+}
+
+/// Configurable mapping between exchange-native instrument symbols (eg/ "BTC-USD", "btcusdt",
+/// "BTC_USDT") and a canonical [`NormalisedSymbol`].
+///
+/// Common separators and casing are handled automatically. Exchange specific behaviour can be
+/// configured via [`SymbolFormat`]s, asset aliases (eg/ Kraken "XBT" -> "btc") and full
+/// symbol overrides, which always take precedence.
+#[derive(Debug, Clone)]
+pub struct SymbolNormaliser {
+    quotes: Vec<SmolStr>,
+    formats: HashMap<ExchangeId, SymbolFormat>,
+    aliases: HashMap<(ExchangeId, SmolStr), AssetNameInternal>,
+    overrides: HashMap<(ExchangeId, SmolStr), NormalisedSymbol>,
+}
+
+impl Default for SymbolNormaliser {
+    fn default() -> Self {
+        Self {
+            quotes: DEFAULT_QUOTES
+                .into_iter()
+                .map(SmolStr::new_static)
+                .collect(),
+            formats: HashMap::new(),
+            aliases: HashMap::new(),
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+impl SymbolNormaliser {
+    /// Add a quote asset used to split unseparated symbols (eg/ "btcusdt").
+    pub fn with_quote<S>(mut self, quote: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        let quote = quote.as_ref().to_lowercase_smolstr();
+        if !self.quotes.contains(&quote) {
+            self.quotes.push(quote);
+            self.quotes
+                .sort_by_key(|quote| std::cmp::Reverse(quote.len()));
+        }
+        self
+    }
+
+    /// Override the default [`SymbolFormat`] of an exchange.
+    pub fn with_format(mut self, exchange: ExchangeId, format: SymbolFormat) -> Self {
+        self.formats.insert(exchange, format);
+        self
+    }
+
+    /// Map an exchange-native asset name to a canonical [`AssetNameInternal`].
+    pub fn with_asset_alias<S, A>(
+        mut self,
+        exchange: ExchangeId,
+        name_exchange: S,
+        asset: A,
+    ) -> Self
+    where
+        S: AsRef<str>,
+        A: Into<AssetNameInternal>,
+    {
+        self.aliases.insert(
+            (exchange, name_exchange.as_ref().to_lowercase_smolstr()),
+            asset.into(),
+        );
+        self
+    }
+
+    /// Map an exact exchange-native symbol to a [`NormalisedSymbol`], bypassing all automatic
+    /// handling in both directions.
+    pub fn with_override<S>(
+        mut self,
+        exchange: ExchangeId,
+        symbol: S,
+        normalised: NormalisedSymbol,
+    ) -> Self
+    where
+        S: Into<SmolStr>,
+    {
+        self.overrides.insert((exchange, symbol.into()), normalised);
+        self
+    }
+
+    /// [`SymbolFormat`] used for the provided [`ExchangeId`].
+    pub fn format(&self, exchange: ExchangeId) -> SymbolFormat {
+        self.formats
+            .get(&exchange)
+            .cloned()
+            .unwrap_or_else(|| SymbolFormat::default_for(exchange))
+    }
+
+    /// Normalise an exchange-native symbol into a canonical [`NormalisedSymbol`].
+    pub fn normalise(
+        &self,
+        exchange: ExchangeId,
+        symbol: &str,
+    ) -> Result<NormalisedSymbol, SymbolError> {
+        if let Some(normalised) = self.overrides.get(&(exchange, SmolStr::new(symbol))) {
+            return Ok(normalised.clone());
+        }
+
+        let unrecognised = || SymbolError::Unrecognised {
+            exchange,
+            symbol: SmolStr::new(symbol),
+        };
+
+        let lower = symbol.to_lowercase_smolstr();
+        let format = self.format(exchange);
+
+        let (pair, perpetual) = match format
+            .perpetual_suffix
+            .as_ref()
+            .map(|suffix| suffix.to_lowercase_smolstr())
+            .into_iter()
+            .chain(PERPETUAL_SUFFIXES.into_iter().map(SmolStr::new_static))
+            .find_map(|suffix| lower.strip_suffix(suffix.as_str()))
+        {
+            Some(pair) => (pair, true),
+            None => (lower.as_str(), is_perpetual_exchange(exchange)),
+        };
+
+        let (base, quote) = match pair.split_once(SEPARATORS) {
+            Some((base, quote)) if !quote.contains(SEPARATORS) => (base, quote),
+            Some(_) => return Err(unrecognised()),
+            None => self
+                .quotes
+                .iter()
+                .find_map(|quote| {
+                    pair.strip_suffix(quote.as_str())
+                        .map(|base| (base, quote.as_str()))
+                })
+                .ok_or_else(unrecognised)?,
+        };
+
+        if base.is_empty() || quote.is_empty() {
+            return Err(unrecognised());
+        }
+
+        Ok(NormalisedSymbol::new(
+            self.resolve_alias(exchange, base),
+            self.resolve_alias(exchange, quote),
+            if perpetual {
+                MarketDataInstrumentKind::Perpetual
+            } else {
+                MarketDataInstrumentKind::Spot
+            },
+        ))
+    }
+
+    /// Generate the exchange-native [`InstrumentNameExchange`] for a [`NormalisedSymbol`].
+    pub fn denormalise(
+        &self,
+        exchange: ExchangeId,
+        normalised: &NormalisedSymbol,
+    ) -> Result<InstrumentNameExchange, SymbolError> {
+        if let Some(((_, symbol), _)) = self
+            .overrides
+            .iter()
+            .find(|((key, _), value)| *key == exchange && *value == normalised)
+        {
+            return Ok(InstrumentNameExchange::new(symbol.clone()));
+        }
+
+        let format = self.format(exchange);
+
+        let suffix = match &normalised.kind {
+            MarketDataInstrumentKind::Spot => None,
+            MarketDataInstrumentKind::Perpetual => format.perpetual_suffix.as_ref(),
+            kind => {
+                return Err(SymbolError::UnsupportedKind {
+                    exchange,
+                    kind: kind.clone(),
+                });
+            }
+        };
+
+        let base = self.asset_name_exchange(exchange, &normalised.underlying.base, format.case);
+        let quote = self.asset_name_exchange(exchange, &normalised.underlying.quote, format.case);
+
+        let symbol = match format.separator {
+            Some(separator) => format_smolstr!("{base}{separator}{quote}"),
+            None => format_smolstr!("{base}{quote}"),
+        };
+
+        Ok(InstrumentNameExchange::new(match suffix {
+            Some(suffix) => format_smolstr!("{symbol}{suffix}"),
+            None => symbol,
+        }))
+    }
+
+    /// Construct an un-indexed [`Instrument`] from an exchange-native symbol.
+    ///
+    /// Derivative contracts are assumed to have a `contract_size` of one and settle in the
+    /// quote asset.
+    pub fn instrument(
+        &self,
+        exchange: ExchangeId,
+        symbol: &str,
+    ) -> Result<Instrument<ExchangeId, Asset>, SymbolError> {
+        let normalised = self.normalise(exchange, symbol)?;
+        let format = self.format(exchange);
+
+        let base = Asset::new(
+            normalised.underlying.base.clone(),
+            self.asset_name_exchange(exchange, &normalised.underlying.base, format.case),
+        );
+        let quote = Asset::new(
+            normalised.underlying.quote.clone(),
+            self.asset_name_exchange(exchange, &normalised.underlying.quote, format.case),
+        );
+
+        let kind = match normalised.kind {
+            MarketDataInstrumentKind::Spot => InstrumentKind::Spot,
+            MarketDataInstrumentKind::Perpetual => InstrumentKind::Perpetual(PerpetualContract {
+                contract_size: Decimal::ONE,
+                settlement_asset: quote.clone(),
+            }),
+            MarketDataInstrumentKind::Future(contract) => InstrumentKind::Future(FutureContract {
+                contract_size: Decimal::ONE,
+                settlement_asset: quote.clone(),
+                expiry: contract.expiry,
+            }),
+            kind @ MarketDataInstrumentKind::Option(_) => {
+                return Err(SymbolError::UnsupportedKind { exchange, kind });
+            }
+        };
+
+        let name_exchange = InstrumentNameExchange::new(symbol);
+
+        Ok(Instrument::new(
+            exchange,
+            InstrumentNameInternal::new_from_exchange(exchange, name_exchange.clone()),
+            name_exchange,
+            Underlying::new(base, quote),
+            InstrumentQuoteAsset::UnderlyingQuote,
+            kind,
+            None,
+        ))
+    }
+
+    fn resolve_alias(&self, exchange: ExchangeId, name: &str) -> AssetNameInternal {
+        self.aliases
+            .get(&(exchange, SmolStr::new(name)))
+            .cloned()
+            .unwrap_or_else(|| AssetNameInternal::new(name))
+    }
+
+    fn asset_name_exchange(
+        &self,
+        exchange: ExchangeId,
+        asset: &AssetNameInternal,
+        case: SymbolCase,
+    ) -> SmolStr {
+        let name = self
+            .aliases
+            .iter()
+            .find(|((key, _), value)| *key == exchange && *value == asset)
+            .map(|((_, name_exchange), _)| name_exchange.as_str())
+            .unwrap_or(asset.name().as_str());
+
+        match case {
+            SymbolCase::Upper => name.to_uppercase_smolstr(),
+            SymbolCase::Lower => name.to_lowercase_smolstr(),
+        }
+    }
+}
+
+fn is_perpetual_exchange(exchange: ExchangeId) -> bool {
+    matches!(
+        exchange,
+        ExchangeId::BinanceFuturesUsd
+            | ExchangeId::BinanceFuturesCoin
+            | ExchangeId::BybitPerpetualsUsd
+            | ExchangeId::BitmartFuturesUsd
+            | ExchangeId::GateioPerpetualsUsd
+            | ExchangeId::GateioPerpetualsBtc
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalise_symbols_from_multiple_exchanges() {
+        struct TestCase {
+            exchange: ExchangeId,
+            symbol: &'static str,
+            expected: Result<NormalisedSymbol, SymbolError>,
+        }
+
+        let normaliser =
+            SymbolNormaliser::default().with_asset_alias(ExchangeId::Kraken, "XBT", "btc");
+
+        let btc_usdt_spot = || NormalisedSymbol::new("btc", "usdt", MarketDataInstrumentKind::Spot);
+
+        let tests = vec![
+            TestCase {
+                exchange: ExchangeId::Coinbase,
+                symbol: "BTC-USDT",
+                expected: Ok(btc_usdt_spot()),
+            },
+            TestCase {
+                exchange: ExchangeId::BinanceSpot,
+                symbol: "btcusdt",
+                expected: Ok(btc_usdt_spot()),
+            },
+            TestCase {
+                exchange: ExchangeId::GateioSpot,
+                symbol: "BTC_USDT",
+                expected: Ok(btc_usdt_spot()),
+            },
+            TestCase {
+                exchange: ExchangeId::Kraken,
+                symbol: "XBT/USDT",
+                expected: Ok(btc_usdt_spot()),
+            },
+            TestCase {
+                exchange: ExchangeId::Okx,
+                symbol: "BTC-USDT-SWAP",
+                expected: Ok(NormalisedSymbol::new(
+                    "btc",
+                    "usdt",
+                    MarketDataInstrumentKind::Perpetual,
+                )),
+            },
+            TestCase {
+                exchange: ExchangeId::BinanceFuturesUsd,
+                symbol: "BTCUSDT",
+                expected: Ok(NormalisedSymbol::new(
+                    "btc",
+                    "usdt",
+                    MarketDataInstrumentKind::Perpetual,
+                )),
+            },
+            TestCase {
+                exchange: ExchangeId::BinanceSpot,
+                symbol: "btcxyz",
+                expected: Err(SymbolError::Unrecognised {
+                    exchange: ExchangeId::BinanceSpot,
+                    symbol: SmolStr::new("btcxyz"),
+                }),
+            },
+            TestCase {
+                exchange: ExchangeId::Coinbase,
+                symbol: "BTC-USD-USDT",
+                expected: Err(SymbolError::Unrecognised {
+                    exchange: ExchangeId::Coinbase,
+                    symbol: SmolStr::new("BTC-USD-USDT"),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = normaliser.normalise(test.exchange, test.symbol);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_denormalise_round_trips_to_exchange_format() {
+        let normaliser = SymbolNormaliser::default()
+            .with_asset_alias(ExchangeId::Kraken, "XBT", "btc")
+            .with_override(
+                ExchangeId::BinanceSpot,
+                "1000SATSUSDT",
+                NormalisedSymbol::new("sats", "usdt", MarketDataInstrumentKind::Spot),
+            );
+
+        for (exchange, symbol) in [
+            (ExchangeId::Coinbase, "BTC-USDT"),
+            (ExchangeId::BinanceSpot, "BTCUSDT"),
+            (ExchangeId::GateioSpot, "BTC_USDT"),
+            (ExchangeId::Kraken, "XBT/USDT"),
+            (ExchangeId::Okx, "BTC-USDT-SWAP"),
+            (ExchangeId::BinanceSpot, "1000SATSUSDT"),
+        ] {
+            let normalised = normaliser.normalise(exchange, symbol).unwrap();
+            assert_eq!(
+                normaliser.denormalise(exchange, &normalised).unwrap(),
+                InstrumentNameExchange::new(symbol),
+                "{exchange} {symbol} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_instrument_from_symbol() {
+        let normaliser =
+            SymbolNormaliser::default().with_asset_alias(ExchangeId::Kraken, "XBT", "btc");
+
+        let instrument = normaliser
+            .instrument(ExchangeId::Kraken, "XBT/USD")
+            .unwrap();
+
+        assert_eq!(
+            instrument.name_exchange,
+            InstrumentNameExchange::new("XBT/USD")
+        );
+        assert_eq!(
+            instrument.underlying,
+            Underlying::new(Asset::new("btc", "XBT"), Asset::new("usd", "USD"))
+        );
+        assert_eq!(instrument.kind, InstrumentKind::Spot);
+    }
+}
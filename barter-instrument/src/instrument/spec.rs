@@ -1,5 +1,5 @@
 use derive_more::Constructor;
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
 
 #[derive(
@@ -19,6 +19,13 @@ pub struct InstrumentSpecPrice {
     pub tick_size: Decimal,
 }
 
+impl InstrumentSpecPrice {
+    /// Conform the provided price to a multiple of the `tick_size` using the [`RoundingMode`].
+    pub fn round(&self, price: Decimal, mode: RoundingMode) -> Decimal {
+        mode.round_to_increment(price, self.tick_size)
+    }
+}
+
 #[derive(
     Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
 )]
@@ -28,6 +35,13 @@ pub struct InstrumentSpecQuantity<AssetKey> {
     pub increment: Decimal,
 }
 
+impl<AssetKey> InstrumentSpecQuantity<AssetKey> {
+    /// Conform the provided quantity to a multiple of the `increment` using the [`RoundingMode`].
+    pub fn round(&self, quantity: Decimal, mode: RoundingMode) -> Decimal {
+        mode.round_to_increment(quantity, self.increment)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 pub enum OrderQuantityUnits<AssetKey> {
     Asset(AssetKey),
@@ -41,3 +55,117 @@ pub enum OrderQuantityUnits<AssetKey> {
 pub struct InstrumentSpecNotional {
     pub min: Decimal,
 }
+
+/// Rounding mode used to conform [`Decimal`] values to a venue precision.
+///
+/// Venues differ in how they round PnL, fees and order values, so using the venue's mode avoids
+/// tiny reconciliation breaks against exchange statements.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// Round to nearest, with midpoints rounded away from zero (eg/ 2.5 -> 3, -2.5 -> -3).
+    HalfUp,
+    /// Round to nearest, with midpoints rounded to the nearest even number (eg/ 2.5 -> 2).
+    HalfEven,
+    /// Truncate towards zero (eg/ 2.9 -> 2, -2.9 -> -2).
+    Truncate,
+}
+
+impl RoundingMode {
+    /// Round the provided value to `dp` decimal places.
+    pub fn round_dp(&self, value: Decimal, dp: u32) -> Decimal {
+        value.round_dp_with_strategy(dp, RoundingStrategy::from(*self))
+    }
+
+    /// Round the provided value to a multiple of `increment` (eg/ a tick size).
+    ///
+    /// A non-positive `increment` leaves the value unchanged.
+    pub fn round_to_increment(&self, value: Decimal, increment: Decimal) -> Decimal {
+        if increment <= Decimal::ZERO {
+            return value;
+        }
+
+        (value / increment).round_dp_with_strategy(0, RoundingStrategy::from(*self)) * increment
+    }
+}
+
+impl From<RoundingMode> for RoundingStrategy {
+    fn from(value: RoundingMode) -> Self {
+        match value {
+            RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+            RoundingMode::Truncate => RoundingStrategy::ToZero,
+        }
+    }
+}
+
+/// [`RoundingMode`] applied at a fixed number of decimal places, eg/ rounding PnL to the cent.
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct DecimalRounding {
+    pub mode: RoundingMode,
+    pub dp: u32,
+}
+
+impl DecimalRounding {
+    /// Round the provided value to `dp` decimal places using the [`RoundingMode`].
+    pub fn round(&self, value: Decimal) -> Decimal {
+        self.mode.round_dp(value, self.dp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_rounding_mode() {
+        struct TestCase {
+            mode: RoundingMode,
+            value: Decimal,
+            expected_dp: Decimal,
+            expected_tick: Decimal,
+        }
+
+        let tests = vec![
+            // TC0: HalfUp rounds midpoint away from zero
+            TestCase {
+                mode: RoundingMode::HalfUp,
+                value: dec!(1.125),
+                expected_dp: dec!(1.13),
+                expected_tick: dec!(1.15),
+            },
+            // TC1: HalfEven rounds midpoint to even
+            TestCase {
+                mode: RoundingMode::HalfEven,
+                value: dec!(1.125),
+                expected_dp: dec!(1.12),
+                expected_tick: dec!(1.10),
+            },
+            // TC2: Truncate rounds towards zero
+            TestCase {
+                mode: RoundingMode::Truncate,
+                value: dec!(-1.129),
+                expected_dp: dec!(-1.12),
+                expected_tick: dec!(-1.10),
+            },
+        ];
+
+        let price = InstrumentSpecPrice::new(dec!(0.05), dec!(0.05));
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                test.mode.round_dp(test.value, 2),
+                test.expected_dp,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                price.round(test.value, test.mode),
+                test.expected_tick,
+                "TC{index} failed"
+            );
+        }
+    }
+}
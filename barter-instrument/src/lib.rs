@@ -19,6 +19,7 @@
 //! 有关全面的示例集合，请参见 Barter 核心 Engine 的 /examples 目录。
 
 use derive_more::Constructor;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
@@ -130,6 +131,37 @@ pub enum Side {
     Sell,
 }
 
+impl Side {
+    /// 返回相反的 [`Side`]（`Buy` => `Sell`，`Sell` => `Buy`）。
+    pub fn opposite(self) -> Self {
+        match self {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        }
+    }
+
+    /// 返回 [`Side`] 的符号乘数：`Buy` 为 `+1`，`Sell` 为 `-1`。
+    ///
+    /// 用于将绝对数量转换为带符号数量（例如 `side.sign() * quantity_abs`）。
+    pub fn sign(self) -> Decimal {
+        match self {
+            Side::Buy => Decimal::ONE,
+            Side::Sell => Decimal::NEGATIVE_ONE,
+        }
+    }
+
+    /// 根据带符号数值返回对应的 [`Side`]：正数为 `Buy`，负数为 `Sell`，零返回 `None`。
+    pub fn from_sign(value: Decimal) -> Option<Self> {
+        if value.is_zero() {
+            None
+        } else if value.is_sign_positive() {
+            Some(Side::Buy)
+        } else {
+            Some(Side::Sell)
+        }
+    }
+}
+
 impl Display for Side {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -195,3 +227,31 @@ pub mod test_utils {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_side_opposite() {
+        assert_eq!(Side::Buy.opposite(), Side::Sell);
+        assert_eq!(Side::Sell.opposite(), Side::Buy);
+        assert_eq!(Side::Buy.opposite().opposite(), Side::Buy);
+    }
+
+    #[test]
+    fn test_side_sign_round_trip() {
+        assert_eq!(Side::Buy.sign(), dec!(1));
+        assert_eq!(Side::Sell.sign(), dec!(-1));
+
+        for side in [Side::Buy, Side::Sell] {
+            assert_eq!(Side::from_sign(side.sign()), Some(side));
+        }
+
+        assert_eq!(Side::from_sign(dec!(0.5)), Some(Side::Buy));
+        assert_eq!(Side::from_sign(dec!(-10)), Some(Side::Sell));
+        assert_eq!(Side::from_sign(dec!(0)), None);
+        assert_eq!(Side::from_sign(-Decimal::ZERO), None);
+    }
+}
@@ -1,12 +1,16 @@
 use crate::Unrecoverable;
 use derive_more::{Constructor, Display};
-use futures::{Sink, Stream};
+use futures::{Sink, Stream, task::AtomicWaker};
 use serde::{Deserialize, Serialize};
 use std::{
+    collections::VecDeque,
     fmt::Debug,
+    num::NonZeroUsize,
     pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
     task::{Context, Poll},
 };
+use tokio::sync::{Notify, mpsc::error::SendError};
 use tracing::warn;
 
 pub trait Tx
@@ -176,3 +180,355 @@ pub fn mpsc_unbounded<T>() -> (UnboundedTx<T>, UnboundedRx<T>) {
     let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
     (UnboundedTx::new(tx), UnboundedRx::new(rx))
 }
+
+/// Action taken when a [`Backpressure::Bounded`] queue is full.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum OverflowPolicy {
+    /// Never drop items - senders wait until the receiver has made space, propagating
+    /// backpressure upstream.
+    Block,
+
+    /// Drop the oldest queued item to make room for the new item.
+    DropOldest,
+
+    /// Drop the new item, leaving the sender to surface the overflow downstream.
+    Error,
+}
+
+impl OverflowPolicy {
+    /// Push an item onto a queue holding at most `capacity` items, applying this
+    /// [`OverflowPolicy`] if the queue is full.
+    ///
+    /// Returns `true` if an item was dropped. [`OverflowPolicy::Block`] never drops items, so
+    /// callers must wait for space themselves before the queue is full.
+    pub fn push<T>(self, queue: &mut VecDeque<T>, capacity: NonZeroUsize, item: T) -> bool {
+        if queue.len() < capacity.get() || self == OverflowPolicy::Block {
+            queue.push_back(item);
+            return false;
+        }
+
+        if self == OverflowPolicy::DropOldest {
+            queue.pop_front();
+            queue.push_back(item);
+        }
+
+        true
+    }
+}
+
+/// Capacity configuration of a [`mpsc_backpressure`] channel.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum Backpressure {
+    /// Queue grows without bound (default).
+    #[default]
+    Unbounded,
+
+    /// Queue holds at most `capacity` items, applying the [`OverflowPolicy`] when full.
+    Bounded {
+        capacity: NonZeroUsize,
+        overflow: OverflowPolicy,
+    },
+}
+
+/// Construct a multi-producer, single-consumer channel whose queue is sized by the provided
+/// [`Backpressure`].
+///
+/// [`Backpressure::Unbounded`] channels are backed by a tokio unbounded channel.
+pub fn mpsc_backpressure<T>(backpressure: Backpressure) -> (BackpressureTx<T>, BackpressureRx<T>) {
+    match backpressure {
+        Backpressure::Unbounded => {
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            (
+                BackpressureTx(BackpressureTxKind::Unbounded(tx)),
+                BackpressureRx(BackpressureRxKind::Unbounded(rx)),
+            )
+        }
+        Backpressure::Bounded { capacity, overflow } => {
+            let shared = Arc::new(BoundedShared {
+                capacity,
+                overflow,
+                state: Mutex::new(BoundedState {
+                    queue: VecDeque::with_capacity(capacity.get()),
+                    senders: 1,
+                    rx_closed: false,
+                }),
+                rx_waker: AtomicWaker::new(),
+                space: Notify::new(),
+            });
+            (
+                BackpressureTx(BackpressureTxKind::Bounded(Arc::clone(&shared))),
+                BackpressureRx(BackpressureRxKind::Bounded(shared)),
+            )
+        }
+    }
+}
+
+/// Sender half of a [`mpsc_backpressure`] channel.
+#[derive(Debug)]
+pub struct BackpressureTx<T>(BackpressureTxKind<T>);
+
+#[derive(Debug)]
+enum BackpressureTxKind<T> {
+    Unbounded(tokio::sync::mpsc::UnboundedSender<T>),
+    Bounded(Arc<BoundedShared<T>>),
+}
+
+/// Receiver half of a [`mpsc_backpressure`] channel.
+///
+/// Yields items until every [`BackpressureTx`] has been dropped and the queue is empty.
+#[derive(Debug)]
+pub struct BackpressureRx<T>(BackpressureRxKind<T>);
+
+#[derive(Debug)]
+enum BackpressureRxKind<T> {
+    Unbounded(tokio::sync::mpsc::UnboundedReceiver<T>),
+    Bounded(Arc<BoundedShared<T>>),
+}
+
+#[derive(Debug)]
+struct BoundedShared<T> {
+    capacity: NonZeroUsize,
+    overflow: OverflowPolicy,
+    state: Mutex<BoundedState<T>>,
+    rx_waker: AtomicWaker,
+    space: Notify,
+}
+
+#[derive(Debug)]
+struct BoundedState<T> {
+    queue: VecDeque<T>,
+    senders: usize,
+    rx_closed: bool,
+}
+
+impl<T> BoundedShared<T> {
+    fn state(&self) -> MutexGuard<'_, BoundedState<T>> {
+        // State is always left consistent, so a panic whilst holding the lock is harmless
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> BackpressureTx<T> {
+    /// Send an item, applying the channel [`OverflowPolicy`] if the queue is full.
+    ///
+    /// Returns `true` if an item was dropped due to overflow. [`OverflowPolicy::Block`] waits for
+    /// the receiver to make space rather than dropping items.
+    pub async fn send(&self, item: T) -> Result<bool, SendError<T>> {
+        let shared = match &self.0 {
+            BackpressureTxKind::Unbounded(tx) => return tx.send(item).map(|_| false),
+            BackpressureTxKind::Bounded(shared) => shared,
+        };
+
+        loop {
+            // Register interest in space before checking, so a concurrent receive is not missed
+            let space = shared.space.notified();
+            let mut space = std::pin::pin!(space);
+            space.as_mut().enable();
+
+            {
+                let mut state = shared.state();
+                if state.rx_closed {
+                    return Err(SendError(item));
+                }
+
+                let is_full = state.queue.len() >= shared.capacity.get();
+                if !is_full || shared.overflow != OverflowPolicy::Block {
+                    let dropped = shared
+                        .overflow
+                        .push(&mut state.queue, shared.capacity, item);
+                    drop(state);
+                    shared.rx_waker.wake();
+                    return Ok(dropped);
+                }
+            }
+
+            space.await;
+        }
+    }
+
+    /// Returns the [`Backpressure`] capacity configuration of the channel.
+    pub fn backpressure(&self) -> Backpressure {
+        match &self.0 {
+            BackpressureTxKind::Unbounded(_) => Backpressure::Unbounded,
+            BackpressureTxKind::Bounded(shared) => Backpressure::Bounded {
+                capacity: shared.capacity,
+                overflow: shared.overflow,
+            },
+        }
+    }
+}
+
+impl<T> Clone for BackpressureTx<T> {
+    fn clone(&self) -> Self {
+        match &self.0 {
+            BackpressureTxKind::Unbounded(tx) => Self(BackpressureTxKind::Unbounded(tx.clone())),
+            BackpressureTxKind::Bounded(shared) => {
+                shared.state().senders += 1;
+                Self(BackpressureTxKind::Bounded(Arc::clone(shared)))
+            }
+        }
+    }
+}
+
+impl<T> Drop for BackpressureTx<T> {
+    fn drop(&mut self) {
+        let BackpressureTxKind::Bounded(shared) = &self.0 else {
+            return;
+        };
+
+        let mut state = shared.state();
+        state.senders -= 1;
+        if state.senders == 0 {
+            drop(state);
+            shared.rx_waker.wake();
+        }
+    }
+}
+
+impl<T> Stream for BackpressureRx<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let shared = match &mut self.0 {
+            BackpressureRxKind::Unbounded(rx) => return rx.poll_recv(cx),
+            BackpressureRxKind::Bounded(shared) => shared,
+        };
+
+        let mut state = shared.state();
+        if let Some(item) = state.queue.pop_front() {
+            drop(state);
+            shared.space.notify_waiters();
+            return Poll::Ready(Some(item));
+        }
+
+        if state.senders == 0 {
+            return Poll::Ready(None);
+        }
+
+        // Registered whilst holding the lock, so a sender pushing after this point wakes us
+        shared.rx_waker.register(cx.waker());
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for BackpressureRx<T> {
+    fn drop(&mut self) {
+        let BackpressureRxKind::Bounded(shared) = &self.0 else {
+            return;
+        };
+
+        let mut state = shared.state();
+        state.rx_closed = true;
+        state.queue.clear();
+        drop(state);
+        shared.space.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_mpsc_backpressure_overflow_policy() {
+        struct TestCase {
+            backpressure: Backpressure,
+            expected_dropped: Vec<bool>,
+            expected: Vec<usize>,
+        }
+
+        let bounded = |overflow| Backpressure::Bounded {
+            capacity: NonZeroUsize::new(3).unwrap(),
+            overflow,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: Unbounded retains every item
+                backpressure: Backpressure::Unbounded,
+                expected_dropped: vec![false; 5],
+                expected: vec![0, 1, 2, 3, 4],
+            },
+            TestCase {
+                // TC1: DropOldest retains the newest items
+                backpressure: bounded(OverflowPolicy::DropOldest),
+                expected_dropped: vec![false, false, false, true, true],
+                expected: vec![2, 3, 4],
+            },
+            TestCase {
+                // TC2: Error retains the oldest items & reports the dropped new items
+                backpressure: bounded(OverflowPolicy::Error),
+                expected_dropped: vec![false, false, false, true, true],
+                expected: vec![0, 1, 2],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (tx, rx) = mpsc_backpressure(test.backpressure);
+
+            // Fill the queue without receiving
+            let mut dropped = Vec::new();
+            for item in 0..5 {
+                dropped.push(tx.send(item).await.unwrap());
+            }
+            drop(tx);
+
+            assert_eq!(dropped, test.expected_dropped, "TC{index} failed");
+            assert_eq!(
+                rx.collect::<Vec<_>>().await,
+                test.expected,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mpsc_backpressure_block_waits_for_space() {
+        let (tx, mut rx) = mpsc_backpressure(Backpressure::Bounded {
+            capacity: NonZeroUsize::new(2).unwrap(),
+            overflow: OverflowPolicy::Block,
+        });
+
+        let sender = tokio::spawn(async move {
+            for item in 0..5 {
+                assert!(!tx.send(item).await.unwrap());
+            }
+        });
+
+        // Sender blocks once the queue is full
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!sender.is_finished());
+        let BackpressureRxKind::Bounded(shared) = &rx.0 else {
+            panic!("expected bounded channel");
+        };
+        assert_eq!(shared.state().queue.len(), 2);
+
+        // Every item is delivered in order once the receiver drains the queue
+        let mut received = Vec::new();
+        while let Some(item) = rx.next().await {
+            received.push(item);
+        }
+        assert_eq!(received, vec![0, 1, 2, 3, 4]);
+        sender.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_mpsc_backpressure_send_fails_once_receiver_dropped() {
+        let (tx, rx) = mpsc_backpressure(Backpressure::Bounded {
+            capacity: NonZeroUsize::new(1).unwrap(),
+            overflow: OverflowPolicy::Block,
+        });
+
+        assert!(!tx.send(0).await.unwrap());
+
+        // Blocked sender is released with an error when the receiver is dropped
+        let blocked = tokio::spawn(async move { tx.send(1).await });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        drop(rx);
+        assert_eq!(blocked.await.unwrap(), Err(SendError(1)));
+    }
+}
@@ -64,6 +64,62 @@ where
     })
 }
 
+/// Deserialize a price-like value as a `Decimal`, accepting either a JSON string
+/// (eg/ `"123.45"`, `"1.2345e2"`) or a JSON number (eg/ `123.45`).
+///
+/// Strings are parsed exactly, falling back to scientific notation. Floating point numbers are
+/// parsed from their shortest round-trip string representation, so `123.45` and `"123.45"`
+/// deserialize into the same `Decimal` without picking up binary floating point noise.
+pub fn de_decimal_flexible<'de, D>(deserializer: D) -> Result<rust_decimal::Decimal, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    deserializer.deserialize_any(DecimalFlexibleVisitor)
+}
+
+/// [`serde::de::Visitor`] used by [`de_decimal_flexible`].
+struct DecimalFlexibleVisitor;
+
+impl serde::de::Visitor<'_> for DecimalFlexibleVisitor {
+    type Value = rust_decimal::Decimal;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a decimal number or a string containing a decimal number")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let value = value.trim();
+        value
+            .parse::<rust_decimal::Decimal>()
+            .or_else(|_| rust_decimal::Decimal::from_scientific(value))
+            .map_err(|error| E::custom(format!("invalid decimal {value:?}: {error}")))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(rust_decimal::Decimal::from(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(rust_decimal::Decimal::from(value))
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        self.visit_str(&value.to_string())
+    }
+}
+
 /// Assists deserialisation of sequences by attempting to extract & parse the next element in the
 /// provided sequence.
 ///
@@ -96,3 +152,70 @@ where
     sequence.serialize_element(&element)?;
     sequence.end()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Price {
+        #[serde(deserialize_with = "de_decimal_flexible")]
+        price: Decimal,
+    }
+
+    #[test]
+    fn test_de_decimal_flexible() {
+        struct TestCase {
+            input: &'static str,
+            expected: Option<Decimal>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: string
+                input: r#"{"price": "123.45"}"#,
+                expected: Some(dec!(123.45)),
+            },
+            TestCase {
+                // TC1: float number
+                input: r#"{"price": 123.45}"#,
+                expected: Some(dec!(123.45)),
+            },
+            TestCase {
+                // TC2: scientific notation string
+                input: r#"{"price": "1.2345e2"}"#,
+                expected: Some(dec!(123.45)),
+            },
+            TestCase {
+                // TC3: integer number
+                input: r#"{"price": 20180}"#,
+                expected: Some(Decimal::from(20180)),
+            },
+            TestCase {
+                // TC4: float number not exactly representable as an f64
+                input: r#"{"price": 0.00010001}"#,
+                expected: Some(dec!(0.00010001)),
+            },
+            TestCase {
+                // TC5: invalid string
+                input: r#"{"price": "not a price"}"#,
+                expected: None,
+            },
+            TestCase {
+                // TC6: float number too large for a Decimal
+                input: r#"{"price": 1e300}"#,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<Price>(test.input)
+                .ok()
+                .map(|price| price.price);
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
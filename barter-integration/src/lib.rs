@@ -61,6 +61,10 @@ pub mod stream;
 /// 快照工具。
 pub mod snapshot;
 
+/// MessagePack 序列化工具，用于以紧凑的二进制格式序列化订阅和配置等类型。
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+
 /// [`Validator`] 能够确定其内部状态是否足以满足实现者定义的某些用例。
 ///
 /// Validator Trait 用于验证对象的状态是否满足特定要求。
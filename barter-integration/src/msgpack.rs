@@ -0,0 +1,82 @@
+use serde::{Serialize, de::DeserializeOwned};
+use thiserror::Error;
+
+/// All MessagePack serialisation related errors generated in `barter-integration`.
+#[derive(Debug, Error)]
+pub enum MsgPackError {
+    #[error("Serialising MessagePack error: {0}")]
+    Serialise(#[from] rmp_serde::encode::Error),
+
+    #[error("Deserialising MessagePack error: {0}")]
+    Deserialise(#[from] rmp_serde::decode::Error),
+}
+
+/// Serialise the provided value as MessagePack bytes.
+///
+/// Structs are encoded as maps keyed by field name (rather than positional arrays), so the
+/// output is self-describing and compatible with `#[serde(default)]`, `#[serde(flatten)]`,
+/// `#[serde(untagged)]` and `skip_serializing_if` attributes in the same way as JSON.
+pub fn to_msgpack<T>(value: &T) -> Result<Vec<u8>, MsgPackError>
+where
+    T: Serialize + ?Sized,
+{
+    rmp_serde::to_vec_named(value).map_err(MsgPackError::from)
+}
+
+/// Deserialise MessagePack bytes (eg/ generated by [`to_msgpack`]) as the desired type.
+pub fn from_msgpack<T>(bytes: &[u8]) -> Result<T, MsgPackError>
+where
+    T: DeserializeOwned,
+{
+    rmp_serde::from_slice(bytes).map_err(MsgPackError::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Inner {
+        name: String,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    #[serde(untagged)]
+    enum Untagged {
+        Inner(Inner),
+    }
+
+    #[derive(Debug, PartialEq, Deserialize, Serialize)]
+    struct Outer {
+        #[serde(flatten)]
+        inner: Inner,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        optional: Option<u64>,
+        untagged: Untagged,
+    }
+
+    #[test]
+    fn test_msgpack_round_trip() {
+        let input = Outer {
+            inner: Inner {
+                name: "flattened".to_string(),
+            },
+            optional: None,
+            untagged: Untagged::Inner(Inner {
+                name: "untagged".to_string(),
+            }),
+        };
+
+        let bytes = to_msgpack(&input).unwrap();
+        assert_eq!(from_msgpack::<Outer>(&bytes).unwrap(), input);
+    }
+
+    #[test]
+    fn test_from_msgpack_invalid_bytes() {
+        assert!(matches!(
+            from_msgpack::<Outer>(&[0xc1]),
+            Err(MsgPackError::Deserialise(_))
+        ));
+    }
+}
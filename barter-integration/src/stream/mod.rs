@@ -1,4 +1,5 @@
-use crate::{Transformer, error::SocketError, protocol::StreamParser};
+use crate::{Transformer, error::SocketError, protocol::StreamParser, stream::stats::StreamStats};
+use chrono::Utc;
 use futures::Stream;
 use pin_project::pin_project;
 use std::{
@@ -10,6 +11,7 @@ use std::{
 
 pub mod indexed;
 pub mod merge;
+pub mod stats;
 
 /// An [`ExchangeStream`] is a communication protocol agnostic [`Stream`]. It polls protocol
 /// messages from the inner [`Stream`], and transforms them into the desired output data structure.
@@ -25,6 +27,7 @@ where
     pub stream: InnerStream,
     pub transformer: StreamTransformer,
     pub buffer: VecDeque<Result<StreamTransformer::Output, StreamTransformer::Error>>,
+    pub stats: StreamStats,
     pub protocol_marker: PhantomData<Protocol>,
 }
 
@@ -47,7 +50,10 @@ where
 
             // Poll inner `Stream` for next the next input protocol message
             let input = match self.as_mut().project().stream.poll_next(cx) {
-                Poll::Ready(Some(input)) => input,
+                Poll::Ready(Some(input)) => {
+                    self.stats.record_message(Utc::now());
+                    input
+                }
                 Poll::Ready(None) => return Poll::Ready(None),
                 Poll::Pending => return Poll::Pending,
             };
@@ -58,7 +64,10 @@ where
                 Some(Ok(exchange_message)) => exchange_message,
 
                 // If `StreamParser` returns an Err pass it downstream
-                Some(Err(err)) => return Poll::Ready(Some(Err(err.into()))),
+                Some(Err(err)) => {
+                    self.stats.record_parse_error();
+                    return Poll::Ready(Some(Err(err.into())));
+                }
 
                 // If `StreamParser` returns None it's a safe-to-skip message
                 None => continue,
@@ -94,7 +103,76 @@ where
             stream,
             transformer,
             buffer,
+            stats: StreamStats::default(),
             protocol_marker: PhantomData,
         }
     }
+
+    /// Configure the [`StreamStats`] handle updated as messages are received.
+    ///
+    /// Useful for sharing one handle between successive reconnections of a logical stream.
+    pub fn with_stats(self, stats: StreamStats) -> Self {
+        Self { stats, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+
+    #[derive(Debug)]
+    struct BurstParser;
+
+    impl StreamParser<usize> for BurstParser {
+        type Stream = futures::stream::Iter<std::vec::IntoIter<Result<usize, SocketError>>>;
+        type Message = usize;
+        type Error = SocketError;
+
+        fn parse(input: Result<Self::Message, Self::Error>) -> Option<Result<usize, SocketError>> {
+            Some(input)
+        }
+    }
+
+    /// Transforms a burst size `n` into outputs `0..n`.
+    #[derive(Debug)]
+    struct BurstTransformer;
+
+    impl Transformer for BurstTransformer {
+        type Error = SocketError;
+        type Input = usize;
+        type Output = usize;
+        type OutputIter = Vec<Result<usize, SocketError>>;
+
+        fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+            (0..input).map(Ok).collect()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_exchange_stream_stats() {
+        let stats = StreamStats::default();
+
+        let stream = ExchangeStream::<BurstParser, _, _>::new(
+            futures::stream::iter(vec![
+                Ok(1),
+                Err(SocketError::Sink),
+                Ok(2),
+                Err(SocketError::Sink),
+            ]),
+            BurstTransformer,
+            VecDeque::new(),
+        )
+        .with_stats(stats.clone());
+
+        assert_eq!(stats.time_last_message(), None);
+
+        let outputs = stream.collect::<Vec<_>>().await;
+        assert_eq!(outputs.len(), 5);
+
+        assert_eq!(stats.messages(), 4);
+        assert_eq!(stats.parse_errors(), 2);
+        assert_eq!(stats.reconnects(), 0);
+        assert!(stats.time_last_message().is_some());
+    }
 }
@@ -0,0 +1,130 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+
+/// Cheaply cloneable handle to the runtime statistics of a data-plane [`Stream`](futures::Stream)
+/// (eg/ an [`ExchangeStream`](super::ExchangeStream)).
+///
+/// Counters are backed by atomics, so the handle can be shared between the stream (which
+/// updates them) and any number of monitoring tasks (which query them) without locking.
+///
+/// Sharing one handle between successive streams (eg/ each reconnection of a market stream)
+/// accumulates statistics over the lifetime of the logical stream.
+#[derive(Debug, Clone, Default)]
+pub struct StreamStats(Arc<StreamStatsInner>);
+
+#[derive(Debug, Default)]
+struct StreamStatsInner {
+    messages: AtomicU64,
+    parse_errors: AtomicU64,
+    reconnects: AtomicU64,
+    /// Nanoseconds since the epoch of the last received message, `0` if none received.
+    time_last_message_ns: AtomicU64,
+}
+
+impl StreamStats {
+    /// Record a protocol message received from the inner stream at the provided time.
+    pub fn record_message(&self, time: DateTime<Utc>) {
+        self.0.messages.fetch_add(1, Ordering::Relaxed);
+        self.0.time_last_message_ns.store(
+            time.timestamp_nanos_opt()
+                .and_then(|nanos| u64::try_from(nanos).ok())
+                .unwrap_or_default(),
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Record a protocol message that failed to parse.
+    pub fn record_parse_error(&self) {
+        self.0.parse_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a successful stream reconnection.
+    pub fn record_reconnect(&self) {
+        self.0.reconnects.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Total number of protocol messages received.
+    pub fn messages(&self) -> u64 {
+        self.0.messages.load(Ordering::Relaxed)
+    }
+
+    /// Total number of protocol messages that failed to parse.
+    pub fn parse_errors(&self) -> u64 {
+        self.0.parse_errors.load(Ordering::Relaxed)
+    }
+
+    /// Total number of successful stream reconnections.
+    pub fn reconnects(&self) -> u64 {
+        self.0.reconnects.load(Ordering::Relaxed)
+    }
+
+    /// Time the last protocol message was received, if any.
+    pub fn time_last_message(&self) -> Option<DateTime<Utc>> {
+        match self.0.time_last_message_ns.load(Ordering::Relaxed) {
+            0 => None,
+            nanos => Some(DateTime::from_timestamp_nanos(nanos as i64)),
+        }
+    }
+
+    /// Take a point-in-time [`StreamStatsSnapshot`] of the statistics.
+    pub fn snapshot(&self, time: DateTime<Utc>) -> StreamStatsSnapshot {
+        StreamStatsSnapshot {
+            time,
+            messages: self.messages(),
+            parse_errors: self.parse_errors(),
+            reconnects: self.reconnects(),
+            time_last_message: self.time_last_message(),
+        }
+    }
+}
+
+/// Point-in-time snapshot of [`StreamStats`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct StreamStatsSnapshot {
+    pub time: DateTime<Utc>,
+    pub messages: u64,
+    pub parse_errors: u64,
+    pub reconnects: u64,
+    pub time_last_message: Option<DateTime<Utc>>,
+}
+
+impl StreamStatsSnapshot {
+    /// Average messages per second received between the `previous` snapshot and this one.
+    ///
+    /// Returns `0.0` if no time has elapsed between the snapshots.
+    pub fn messages_per_sec(&self, previous: &Self) -> f64 {
+        let elapsed = (self.time - previous.time).as_seconds_f64();
+        if elapsed <= 0.0 {
+            return 0.0;
+        }
+
+        self.messages.saturating_sub(previous.messages) as f64 / elapsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+
+    #[test]
+    fn test_stream_stats_snapshot_messages_per_sec() {
+        let stats = StreamStats::default();
+        let time_start = DateTime::<Utc>::from_timestamp_secs(1).unwrap();
+        let previous = stats.snapshot(time_start);
+        assert_eq!(previous.time_last_message, None);
+
+        let time_message = time_start + TimeDelta::milliseconds(500);
+        (0..10).for_each(|_| stats.record_message(time_message));
+
+        let current = stats.snapshot(time_start + TimeDelta::seconds(2));
+        assert_eq!(current.messages, 10);
+        assert_eq!(current.time_last_message, Some(time_message));
+        assert_eq!(current.messages_per_sec(&previous), 5.0);
+        assert_eq!(previous.messages_per_sec(&previous), 0.0);
+    }
+}
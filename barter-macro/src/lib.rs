@@ -8,26 +8,38 @@
 //! - **SerExchange**: 为 Exchange 类型生成序列化实现
 //! - **DeSubKind**: 为 SubscriptionKind 类型生成反序列化实现
 //! - **SerSubKind**: 为 SubscriptionKind 类型生成序列化实现
+//! - **Connector**: 为 Exchange 类型生成 `Connector` 实现中的样板代码
 
 extern crate proc_macro;
 
 use convert_case::{Boundary, Case, Casing};
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::DeriveInput;
+use syn::{
+    DeriveInput, Expr, Ident, Path, Token, Type,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
 
 /// 为 Exchange 类型生成反序列化实现。
 ///
 /// 此宏为 Exchange 类型生成 `serde::Deserialize` 实现。
-/// 它期望输入字符串与 Exchange 的 ID 匹配。
+/// 它期望输入字符串与 Exchange 的 ID 或任何声明的别名匹配（不区分大小写）。
+///
+/// # 属性参数
+///
+/// - `alias`: 可选，可重复。反序列化时额外接受的字符串（例如第三方数据集中的拼写）。
+///   序列化仍然使用规范的 Exchange ID。
 ///
 /// # 使用示例
 ///
 /// ```rust,ignore
 /// #[derive(DeExchange)]
+/// #[exchange(alias = "binance", alias = "binance-spot")]
 /// pub struct BinanceSpot;
 /// ```
-#[proc_macro_derive(DeExchange)]
+#[proc_macro_derive(DeExchange, attributes(exchange))]
 pub fn de_exchange_derive(input: TokenStream) -> TokenStream {
     // 使用 Syn 从 TokenStream 解析 Rust 代码抽象语法树 -> DeriveInput
     let ast: DeriveInput =
@@ -36,23 +48,40 @@ pub fn de_exchange_derive(input: TokenStream) -> TokenStream {
     // 确定 Exchange 名称
     let exchange = &ast.ident;
 
+    // 解析 #[exchange(alias = "...")] 属性中声明的别名
+    let aliases = match exchange_aliases(&ast) {
+        Ok(aliases) => aliases,
+        Err(error) => return TokenStream::from(error.to_compile_error()),
+    };
+
     let generated = quote! {
         impl<'de> serde::Deserialize<'de> for #exchange {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: serde::de::Deserializer<'de>
             {
+                const ALIASES: &[&str] = &[#(#aliases),*];
+
                 let input = <String as serde::Deserialize>::deserialize(deserializer)?;
                 let exchange = #exchange::ID;
                 let expected = exchange.as_str();
 
-                if input.as_str() == expected {
+                if std::iter::once(expected)
+                    .chain(ALIASES.iter().copied())
+                    .any(|accepted| input.eq_ignore_ascii_case(accepted))
+                {
                     Ok(Self::default())
-                } else {
+                } else if ALIASES.is_empty() {
                     Err(serde::de::Error::invalid_value(
                         serde::de::Unexpected::Str(input.as_str()),
                         &expected
                     ))
+                } else {
+                    let expected = format!("one of: {expected}, {}", ALIASES.join(", "));
+                    Err(serde::de::Error::invalid_value(
+                        serde::de::Unexpected::Str(input.as_str()),
+                        &expected.as_str()
+                    ))
                 }
             }
         }
@@ -61,6 +90,42 @@ pub fn de_exchange_derive(input: TokenStream) -> TokenStream {
     TokenStream::from(generated)
 }
 
+/// 从 `#[exchange(alias = "...", ...)]` 属性中收集所有别名。
+fn exchange_aliases(ast: &DeriveInput) -> syn::Result<Vec<syn::LitStr>> {
+    let mut aliases = Vec::new();
+
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("exchange"))
+    {
+        let syn::Meta::List(list) = attr.parse_meta()? else {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "expected #[exchange(alias = \"...\")] attribute",
+            ));
+        };
+
+        for nested in list.nested {
+            match nested {
+                syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue {
+                    path,
+                    lit: syn::Lit::Str(alias),
+                    ..
+                })) if path.is_ident("alias") => aliases.push(alias),
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "unknown #[exchange] argument, expected `alias = \"...\"`",
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(aliases)
+}
+
 /// 为 Exchange 类型生成序列化实现。
 ///
 /// 此宏为 Exchange 类型生成 `serde::Serialize` 实现。
@@ -100,11 +165,20 @@ pub fn ser_exchange_derive(input: TokenStream) -> TokenStream {
 /// 此宏为 SubscriptionKind 类型生成 `serde::Deserialize` 实现。
 /// 它将类型名称从 PascalCase 转换为 snake_case 进行匹配。
 ///
+/// - 单元结构体从名称字符串反序列化，例如 `"public_trades"`。
+/// - 具名字段结构体（携带配置参数的 SubscriptionKind）从包含 `kind` 字段及所有结构体字段的
+///   映射反序列化，例如 `{"kind":"order_books_l2","depth":20}`。
+///
 /// # 使用示例
 ///
 /// ```rust,ignore
 /// #[derive(DeSubKind)]
 /// pub struct PublicTrades;
+///
+/// #[derive(DeSubKind)]
+/// pub struct OrderBooksL2 {
+///     pub depth: u16,
+/// }
 /// ```
 #[proc_macro_derive(DeSubKind)]
 pub fn de_sub_kind_derive(input: TokenStream) -> TokenStream {
@@ -116,27 +190,61 @@ pub fn de_sub_kind_derive(input: TokenStream) -> TokenStream {
     let sub_kind = &ast.ident;
 
     // 将 PascalCase 转换为 snake_case
-    let expected_sub_kind = sub_kind
-        .to_string()
-        .from_case(Case::Pascal)
-        .without_boundaries(&Boundary::letter_digit())
-        .to_case(Case::Snake);
+    let expected_sub_kind = sub_kind_name(sub_kind);
 
-    let generated = quote! {
-        impl<'de> serde::Deserialize<'de> for #sub_kind {
-            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-            where
-                D: serde::de::Deserializer<'de>
-            {
-                let input = <String as serde::Deserialize>::deserialize(deserializer)?;
+    let fields = match sub_kind_fields(&ast) {
+        Ok(fields) => fields,
+        Err(error) => return TokenStream::from(error.to_compile_error()),
+    };
 
-                if input == #expected_sub_kind {
-                    Ok(Self)
-                } else {
-                    Err(serde::de::Error::invalid_value(
-                        serde::de::Unexpected::Str(input.as_str()),
-                        &#expected_sub_kind
-                    ))
+    let generated = match fields {
+        None => quote! {
+            impl<'de> serde::Deserialize<'de> for #sub_kind {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::de::Deserializer<'de>
+                {
+                    let input = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+                    if input == #expected_sub_kind {
+                        Ok(Self)
+                    } else {
+                        Err(serde::de::Error::invalid_value(
+                            serde::de::Unexpected::Str(input.as_str()),
+                            &#expected_sub_kind
+                        ))
+                    }
+                }
+            }
+        },
+        Some(fields) => {
+            let names = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+            let types = fields.iter().map(|field| &field.ty);
+
+            quote! {
+                impl<'de> serde::Deserialize<'de> for #sub_kind {
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: serde::de::Deserializer<'de>
+                    {
+                        // 中间表示：`kind` 名称字段 + SubKind 的所有参数字段
+                        #[derive(serde::Deserialize)]
+                        struct Repr {
+                            kind: String,
+                            #(#names: #types,)*
+                        }
+
+                        let Repr { kind, #(#names,)* } = Repr::deserialize(deserializer)?;
+
+                        if kind == #expected_sub_kind {
+                            Ok(Self { #(#names,)* })
+                        } else {
+                            Err(serde::de::Error::invalid_value(
+                                serde::de::Unexpected::Str(kind.as_str()),
+                                &#expected_sub_kind
+                            ))
+                        }
+                    }
                 }
             }
         }
@@ -150,6 +258,10 @@ pub fn de_sub_kind_derive(input: TokenStream) -> TokenStream {
 /// 此宏为 SubscriptionKind 类型生成 `serde::Serialize` 实现。
 /// 它将类型名称转换为 snake_case 字符串。
 ///
+/// - 单元结构体序列化为名称字符串，例如 `"public_trades"`。
+/// - 具名字段结构体序列化为包含 `kind` 字段及所有结构体字段的映射，
+///   例如 `{"kind":"order_books_l2","depth":20}`。
+///
 /// # 使用示例
 ///
 /// ```rust,ignore
@@ -164,20 +276,373 @@ pub fn ser_sub_kind_derive(input: TokenStream) -> TokenStream {
 
     // 确定 SubKind 名称
     let sub_kind = &ast.ident;
-    // 将类型名称转换为 snake_case
-    let sub_kind_string = sub_kind.to_string().to_case(Case::Snake);
+    // 将类型名称转换为 snake_case（与 DeSubKind 保持一致，确保往返一致）
+    let sub_kind_string = sub_kind_name(sub_kind);
     let sub_kind_str = sub_kind_string.as_str();
 
-    let generated = quote! {
-        impl serde::Serialize for #sub_kind {
-            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-            where
-                S: serde::ser::Serializer,
-            {
-                serializer.serialize_str(#sub_kind_str)
+    let fields = match sub_kind_fields(&ast) {
+        Ok(fields) => fields,
+        Err(error) => return TokenStream::from(error.to_compile_error()),
+    };
+
+    let generated = match fields {
+        None => quote! {
+            impl serde::Serialize for #sub_kind {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::ser::Serializer,
+                {
+                    serializer.serialize_str(#sub_kind_str)
+                }
+            }
+        },
+        Some(fields) => {
+            let names = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+            let name_strs = names
+                .iter()
+                .map(|name| name.as_ref().map(ToString::to_string))
+                .collect::<Vec<_>>();
+            let len = fields.len() + 1;
+
+            quote! {
+                impl serde::Serialize for #sub_kind {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: serde::ser::Serializer,
+                    {
+                        use serde::ser::SerializeStruct;
+
+                        let mut state = serializer.serialize_struct(stringify!(#sub_kind), #len)?;
+                        state.serialize_field("kind", #sub_kind_str)?;
+                        #(state.serialize_field(#name_strs, &self.#names)?;)*
+                        state.end()
+                    }
+                }
             }
         }
     };
 
     TokenStream::from(generated)
 }
+
+/// 将 SubKind 类型名称从 PascalCase 转换为 snake_case，不在字母与数字之间插入分隔符。
+///
+/// 例如：`PublicTrades` -> `public_trades`，`OrderBooksL2` -> `order_books_l2`。
+fn sub_kind_name(sub_kind: &Ident) -> String {
+    sub_kind
+        .to_string()
+        .from_case(Case::Pascal)
+        .without_boundaries(&Boundary::letter_digit())
+        .to_case(Case::Snake)
+}
+
+/// 返回 SubKind 结构体的具名字段。
+///
+/// 单元结构体返回 `None`；元组结构体、枚举、联合体以及带泛型的参数化 SubKind 不受支持，返回错误。
+fn sub_kind_fields(ast: &DeriveInput) -> syn::Result<Option<Vec<syn::Field>>> {
+    let syn::Data::Struct(data) = &ast.data else {
+        return Err(syn::Error::new(
+            ast.ident.span(),
+            "SubKind derives only support structs",
+        ));
+    };
+
+    match &data.fields {
+        syn::Fields::Unit => Ok(None),
+        syn::Fields::Named(_) if !ast.generics.params.is_empty() => Err(syn::Error::new(
+            ast.generics.span(),
+            "SubKind derives do not support generic parameterised kinds",
+        )),
+        syn::Fields::Named(fields) => {
+            if let Some(field) = fields
+                .named
+                .iter()
+                .find(|field| field.ident.as_ref().is_some_and(|ident| ident == "kind"))
+            {
+                return Err(syn::Error::new(
+                    field.span(),
+                    "SubKind field `kind` is reserved for the SubscriptionKind name",
+                ));
+            }
+
+            Ok(Some(fields.named.iter().cloned().collect()))
+        }
+        syn::Fields::Unnamed(fields) => Err(syn::Error::new(
+            fields.span(),
+            "SubKind derives only support unit structs or structs with named fields",
+        )),
+    }
+}
+
+/// 为 Exchange 类型生成 `barter_data::exchange::Connector` 实现。
+///
+/// 此宏生成 `Connector` 实现中的样板部分（ID、关联类型、基础 URL），
+/// 仅将非平凡的订阅请求构造和自定义 ping 逻辑留给手写函数。
+///
+/// # 属性参数
+///
+/// 必需参数：
+/// - `exchange`: 交易所的 `ExchangeId`（例如 `ExchangeId::Okx`）
+/// - `url`: 交易所 WebSocket 基础 URL（字符串字面量或 `&str` 常量）
+/// - `channel`: `Connector::Channel` 类型
+/// - `market`: `Connector::Market` 类型
+/// - `sub_response`: `Connector::SubResponse` 类型
+/// - `requests`: 构造订阅负载的函数路径，签名为
+///   `fn(Vec<ExchangeSub<Channel, Market>>) -> Vec<WsMessage>`
+///
+/// 可选参数：
+/// - `subscriber`: `Connector::Subscriber` 类型（默认为 `WebSocketSubscriber`）
+/// - `sub_validator`: `Connector::SubValidator` 类型（默认为 `WebSocketSubValidator`）
+/// - `ping_interval`: 构造自定义应用级 ping 的函数路径，签名为 `fn() -> Option<PingInterval>`
+/// - `subscription_batch_limit`: 单个订阅批次的最大订阅数量（`usize` 表达式，默认为 `usize::MAX`）
+///
+/// # 错误处理
+///
+/// 如果缺少 `#[connector(...)]` 属性或任何必需参数，将产生带有提示信息的编译错误。
+///
+/// # 注意事项
+///
+/// 生成的代码引用 `barter_data`、`barter_instrument`（`ExchangeId`）、`barter_integration` 和 `url`
+/// crate，调用方必须直接依赖它们。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// #[derive(Clone, Default, Debug, DeExchange, SerExchange, Connector)]
+/// #[connector(
+///     exchange = ExchangeId::Okx,
+///     url = BASE_URL_OKX,
+///     channel = OkxChannel,
+///     market = OkxMarket,
+///     sub_response = OkxSubResponse,
+///     requests = okx_requests,
+///     ping_interval = okx_ping_interval,
+/// )]
+/// pub struct Okx;
+/// ```
+#[proc_macro_derive(Connector, attributes(connector))]
+pub fn connector_derive(input: TokenStream) -> TokenStream {
+    // 使用 Syn 从 TokenStream 解析 Rust 代码抽象语法树 -> DeriveInput
+    let ast: DeriveInput =
+        syn::parse(input).expect("connector_derive() failed to parse input TokenStream");
+
+    match ConnectorArgs::from_derive_input(&ast) {
+        Ok(args) => TokenStream::from(args.generate(&ast)),
+        Err(error) => TokenStream::from(error.to_compile_error()),
+    }
+}
+
+/// `#[connector(...)]` 属性中的单个 `key = value` 参数。
+struct ConnectorArg {
+    key: Ident,
+    value: ConnectorArgValue,
+}
+
+/// `#[connector(...)]` 参数值，根据键解析为对应的语法类型。
+enum ConnectorArgValue {
+    Expr(Expr),
+    Type(Type),
+    Path(Path),
+}
+
+impl Parse for ConnectorArg {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        input.parse::<Token![=]>()?;
+
+        let value = match key.to_string().as_str() {
+            "exchange" | "url" | "subscription_batch_limit" => {
+                ConnectorArgValue::Expr(input.parse()?)
+            }
+            "channel" | "market" | "sub_response" | "subscriber" | "sub_validator" => {
+                ConnectorArgValue::Type(input.parse()?)
+            }
+            "requests" | "ping_interval" => ConnectorArgValue::Path(input.parse()?),
+            unknown => {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!(
+                        "unknown #[connector] argument `{unknown}`, expected one of: exchange, \
+                         url, channel, market, sub_response, requests, subscriber, \
+                         sub_validator, ping_interval, subscription_batch_limit"
+                    ),
+                ));
+            }
+        };
+
+        Ok(Self { key, value })
+    }
+}
+
+/// 从 `#[connector(...)]` 属性中收集的所有参数。
+#[derive(Default)]
+struct ConnectorArgs {
+    exchange: Option<Expr>,
+    url: Option<Expr>,
+    channel: Option<Type>,
+    market: Option<Type>,
+    sub_response: Option<Type>,
+    requests: Option<Path>,
+    subscriber: Option<Type>,
+    sub_validator: Option<Type>,
+    ping_interval: Option<Path>,
+    subscription_batch_limit: Option<Expr>,
+}
+
+impl ConnectorArgs {
+    /// 从 `DeriveInput` 的 `#[connector(...)]` 属性中解析参数，并验证所有必需参数均已提供。
+    fn from_derive_input(ast: &DeriveInput) -> syn::Result<Self> {
+        let mut args = Self::default();
+        let mut found = false;
+
+        for attr in ast
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("connector"))
+        {
+            found = true;
+
+            let parsed =
+                attr.parse_args_with(Punctuated::<ConnectorArg, Token![,]>::parse_terminated)?;
+
+            for ConnectorArg { key, value } in parsed {
+                let duplicate = match (key.to_string().as_str(), value) {
+                    ("exchange", ConnectorArgValue::Expr(value)) => {
+                        args.exchange.replace(value).is_some()
+                    }
+                    ("url", ConnectorArgValue::Expr(value)) => args.url.replace(value).is_some(),
+                    ("channel", ConnectorArgValue::Type(value)) => {
+                        args.channel.replace(value).is_some()
+                    }
+                    ("market", ConnectorArgValue::Type(value)) => {
+                        args.market.replace(value).is_some()
+                    }
+                    ("sub_response", ConnectorArgValue::Type(value)) => {
+                        args.sub_response.replace(value).is_some()
+                    }
+                    ("subscriber", ConnectorArgValue::Type(value)) => {
+                        args.subscriber.replace(value).is_some()
+                    }
+                    ("sub_validator", ConnectorArgValue::Type(value)) => {
+                        args.sub_validator.replace(value).is_some()
+                    }
+                    ("requests", ConnectorArgValue::Path(value)) => {
+                        args.requests.replace(value).is_some()
+                    }
+                    ("ping_interval", ConnectorArgValue::Path(value)) => {
+                        args.ping_interval.replace(value).is_some()
+                    }
+                    ("subscription_batch_limit", ConnectorArgValue::Expr(value)) => {
+                        args.subscription_batch_limit.replace(value).is_some()
+                    }
+                    _ => unreachable!("ConnectorArg::parse only yields known key-value pairs"),
+                };
+
+                if duplicate {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("duplicate #[connector] argument `{key}`"),
+                    ));
+                }
+            }
+        }
+
+        if !found {
+            return Err(syn::Error::new(
+                ast.ident.span(),
+                "#[derive(Connector)] requires a #[connector(exchange = .., url = .., \
+                 channel = .., market = .., sub_response = .., requests = ..)] attribute",
+            ));
+        }
+
+        let missing = [
+            ("exchange", args.exchange.is_none()),
+            ("url", args.url.is_none()),
+            ("channel", args.channel.is_none()),
+            ("market", args.market.is_none()),
+            ("sub_response", args.sub_response.is_none()),
+            ("requests", args.requests.is_none()),
+        ]
+        .into_iter()
+        .filter_map(|(name, is_missing)| is_missing.then_some(name))
+        .collect::<Vec<_>>();
+
+        if missing.is_empty() {
+            Ok(args)
+        } else {
+            Err(syn::Error::new(
+                ast.ident.span(),
+                format!(
+                    "#[derive(Connector)] is missing required #[connector] argument(s): {}",
+                    missing.join(", ")
+                ),
+            ))
+        }
+    }
+
+    /// 生成 `Connector` 实现。
+    fn generate(self, ast: &DeriveInput) -> proc_macro2::TokenStream {
+        let exchange = &ast.ident;
+        let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+        let Self {
+            exchange: exchange_id,
+            url,
+            channel,
+            market,
+            sub_response,
+            requests,
+            subscriber,
+            sub_validator,
+            ping_interval,
+            subscription_batch_limit,
+        } = self;
+
+        let subscriber = subscriber
+            .map(|subscriber| quote!(#subscriber))
+            .unwrap_or_else(|| quote!(barter_data::subscriber::WebSocketSubscriber));
+
+        let sub_validator = sub_validator
+            .map(|sub_validator| quote!(#sub_validator))
+            .unwrap_or_else(|| quote!(barter_data::subscriber::validator::WebSocketSubValidator));
+
+        let ping_interval = ping_interval.map(|ping_interval| {
+            quote! {
+                fn ping_interval() -> Option<barter_data::exchange::PingInterval> {
+                    #ping_interval()
+                }
+            }
+        });
+
+        let subscription_batch_limit = subscription_batch_limit.map(|subscription_batch_limit| {
+            quote! {
+                const SUBSCRIPTION_BATCH_LIMIT: usize = #subscription_batch_limit;
+            }
+        });
+
+        quote! {
+            impl #impl_generics barter_data::exchange::Connector for #exchange #ty_generics #where_clause {
+                const ID: barter_instrument::exchange::ExchangeId = #exchange_id;
+                type Channel = #channel;
+                type Market = #market;
+                type Subscriber = #subscriber;
+                type SubValidator = #sub_validator;
+                type SubResponse = #sub_response;
+
+                #subscription_batch_limit
+
+                fn url() -> Result<url::Url, barter_integration::error::SocketError> {
+                    url::Url::parse(#url).map_err(barter_integration::error::SocketError::UrlParse)
+                }
+
+                #ping_interval
+
+                fn requests(
+                    exchange_subs: Vec<barter_data::exchange::subscription::ExchangeSub<Self::Channel, Self::Market>>,
+                ) -> Vec<barter_integration::protocol::websocket::WsMessage> {
+                    #requests(exchange_subs)
+                }
+            }
+        }
+    }
+}
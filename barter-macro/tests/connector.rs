@@ -0,0 +1,7 @@
+#[test]
+fn test_connector_derive() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/connector_pass.rs");
+    cases.compile_fail("tests/ui/connector_missing_attribute.rs");
+    cases.compile_fail("tests/ui/connector_missing_argument.rs");
+}
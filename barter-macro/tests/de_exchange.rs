@@ -0,0 +1,89 @@
+use barter_data::exchange::{coinbase::Coinbase, kraken::Kraken, okx::Okx};
+
+#[test]
+fn test_de_exchange_aliases() {
+    struct TestCase<T> {
+        input: &'static str,
+        expected: Result<T, ()>,
+    }
+
+    let okx_cases = vec![
+        // TC0: canonical id
+        TestCase {
+            input: r#""okx""#,
+            expected: Ok(Okx),
+        },
+        // TC1: canonical id is matched case-insensitively
+        TestCase {
+            input: r#""OKX""#,
+            expected: Ok(Okx),
+        },
+        // TC2: alias
+        TestCase {
+            input: r#""okex""#,
+            expected: Ok(Okx),
+        },
+        // TC3: alias is matched case-insensitively
+        TestCase {
+            input: r#""OKEX""#,
+            expected: Ok(Okx),
+        },
+        // TC4: unknown input is rejected
+        TestCase {
+            input: r#""okx_spot""#,
+            expected: Err(()),
+        },
+    ];
+
+    for (index, test) in okx_cases.into_iter().enumerate() {
+        let actual = serde_json::from_str::<Okx>(test.input).map_err(|_| ());
+        assert_eq!(actual, test.expected, "Okx TC{index} failed");
+    }
+
+    let coinbase_cases = vec![
+        // TC0: canonical id
+        TestCase {
+            input: r#""coinbase""#,
+            expected: Ok(Coinbase),
+        },
+        // TC1: first alias
+        TestCase {
+            input: r#""coinbase_pro""#,
+            expected: Ok(Coinbase),
+        },
+        // TC2: second alias, matched case-insensitively
+        TestCase {
+            input: r#""GDAX""#,
+            expected: Ok(Coinbase),
+        },
+        // TC3: unknown input is rejected
+        TestCase {
+            input: r#""binance_spot""#,
+            expected: Err(()),
+        },
+    ];
+
+    for (index, test) in coinbase_cases.into_iter().enumerate() {
+        let actual = serde_json::from_str::<Coinbase>(test.input).map_err(|_| ());
+        assert_eq!(actual, test.expected, "Coinbase TC{index} failed");
+    }
+}
+
+#[test]
+fn test_de_exchange_without_aliases() {
+    assert_eq!(
+        serde_json::from_str::<Kraken>(r#""kraken""#).unwrap(),
+        Kraken
+    );
+    assert_eq!(
+        serde_json::from_str::<Kraken>(r#""KRAKEN""#).unwrap(),
+        Kraken
+    );
+    assert!(serde_json::from_str::<Kraken>(r#""okex""#).is_err());
+}
+
+#[test]
+fn test_ser_exchange_uses_canonical_id() {
+    let okx = serde_json::from_str::<Okx>(r#""okex""#).unwrap();
+    assert_eq!(serde_json::to_string(&okx).unwrap(), r#""okx""#);
+}
@@ -0,0 +1,73 @@
+use barter_data::subscription::{book::OrderBooksL2 as UnitOrderBooksL2, trade::PublicTrades};
+use barter_macro::{DeSubKind, SerSubKind};
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, DeSubKind, SerSubKind)]
+struct OrderBooksL2 {
+    depth: u16,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, DeSubKind, SerSubKind)]
+struct Candles {
+    interval: String,
+    limit: Option<u32>,
+}
+
+#[test]
+fn test_sub_kind_unit_round_trip() {
+    assert_eq!(
+        serde_json::to_string(&PublicTrades).unwrap(),
+        r#""public_trades""#
+    );
+    assert_eq!(
+        serde_json::from_str::<PublicTrades>(r#""public_trades""#).unwrap(),
+        PublicTrades
+    );
+
+    let serialised = serde_json::to_string(&UnitOrderBooksL2).unwrap();
+    assert_eq!(serialised, r#""order_books_l2""#);
+    assert_eq!(
+        serde_json::from_str::<UnitOrderBooksL2>(&serialised).unwrap(),
+        UnitOrderBooksL2
+    );
+}
+
+#[test]
+fn test_sub_kind_parameterised_round_trip() {
+    let kind = OrderBooksL2 { depth: 20 };
+
+    let serialised = serde_json::to_string(&kind).unwrap();
+    assert_eq!(serialised, r#"{"kind":"order_books_l2","depth":20}"#);
+    assert_eq!(
+        serde_json::from_str::<OrderBooksL2>(&serialised).unwrap(),
+        kind
+    );
+
+    let kind = Candles {
+        interval: "1m".to_string(),
+        limit: None,
+    };
+
+    let serialised = serde_json::to_string(&kind).unwrap();
+    assert_eq!(
+        serialised,
+        r#"{"kind":"candles","interval":"1m","limit":null}"#
+    );
+    assert_eq!(serde_json::from_str::<Candles>(&serialised).unwrap(), kind);
+}
+
+#[test]
+fn test_sub_kind_parameterised_de_errors() {
+    // Wrong kind name
+    assert!(
+        serde_json::from_str::<OrderBooksL2>(r#"{"kind":"order_books_l1","depth":20}"#).is_err()
+    );
+
+    // Missing parameter field
+    assert!(serde_json::from_str::<OrderBooksL2>(r#"{"kind":"order_books_l2"}"#).is_err());
+
+    // Missing kind field
+    assert!(serde_json::from_str::<OrderBooksL2>(r#"{"depth":20}"#).is_err());
+
+    // Bare name is not accepted for a parameterised kind
+    assert!(serde_json::from_str::<OrderBooksL2>(r#""order_books_l2""#).is_err());
+}
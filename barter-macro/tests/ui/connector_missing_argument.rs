@@ -0,0 +1,7 @@
+use barter_macro::Connector;
+
+#[derive(Connector)]
+#[connector(exchange = ExchangeId::Other, url = "wss://test.exchange.com/ws", channel = String)]
+struct TestExchange;
+
+fn main() {}
@@ -0,0 +1,6 @@
+use barter_macro::Connector;
+
+#[derive(Connector)]
+struct TestExchange;
+
+fn main() {}
@@ -0,0 +1,82 @@
+use barter_data::exchange::{Connector, PingInterval, subscription::ExchangeSub};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{Validator, error::SocketError, protocol::websocket::WsMessage};
+use barter_macro::{Connector, DeExchange, SerExchange};
+use serde::Deserialize;
+use std::time::Duration;
+
+const BASE_URL_TEST: &str = "wss://test.exchange.com/ws";
+
+#[derive(Debug)]
+struct TestChannel(&'static str);
+
+impl AsRef<str> for TestChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
+
+#[derive(Debug)]
+struct TestMarket(String);
+
+impl AsRef<str> for TestMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TestSubResponse {
+    success: bool,
+}
+
+impl Validator for TestSubResponse {
+    fn validate(self) -> Result<Self, SocketError> {
+        if self.success {
+            Ok(self)
+        } else {
+            Err(SocketError::Subscribe("subscription failed".to_string()))
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, DeExchange, SerExchange, Connector)]
+#[connector(
+    exchange = ExchangeId::Other,
+    url = BASE_URL_TEST,
+    channel = TestChannel,
+    market = TestMarket,
+    sub_response = TestSubResponse,
+    requests = requests,
+    ping_interval = ping_interval,
+    subscription_batch_limit = 2,
+)]
+struct TestExchange;
+
+fn requests(exchange_subs: Vec<ExchangeSub<TestChannel, TestMarket>>) -> Vec<WsMessage> {
+    exchange_subs
+        .into_iter()
+        .map(|ExchangeSub { channel, market }| {
+            WsMessage::text(format!("{}:{}", channel.as_ref(), market.as_ref()))
+        })
+        .collect()
+}
+
+fn ping_interval() -> Option<PingInterval> {
+    Some(PingInterval {
+        interval: tokio::time::interval(Duration::from_secs(10)),
+        ping: || WsMessage::text("ping"),
+    })
+}
+
+fn main() {
+    assert_eq!(TestExchange::ID, ExchangeId::Other);
+    assert_eq!(TestExchange::url().unwrap().as_str(), BASE_URL_TEST);
+    assert_eq!(TestExchange::SUBSCRIPTION_BATCH_LIMIT, 2);
+
+    let requests = TestExchange::requests(vec![ExchangeSub {
+        channel: TestChannel("trades"),
+        market: TestMarket("btc_usdt".to_string()),
+    }]);
+    assert_eq!(requests, vec![WsMessage::text("trades:btc_usdt")]);
+}
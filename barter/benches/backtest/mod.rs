@@ -1,5 +1,5 @@
 use barter::{
-    backtest,
+    EngineEvent, backtest,
     backtest::{BacktestArgsConstant, BacktestArgsDynamic, market_data::MarketDataInMemory},
     engine::{
         Engine, Processor,
@@ -47,9 +47,10 @@ use barter_instrument::{
     index::IndexedInstruments,
     instrument::InstrumentIndex,
 };
+use barter_integration::channel::mpsc_unbounded;
 use chrono::{DateTime, Utc};
 use criterion::{Criterion, Throughput};
-use rust_decimal::{Decimal, prelude::FromPrimitive};
+use rust_decimal::Decimal;
 use serde::Deserialize;
 use smol_str::SmolStr;
 use std::{
@@ -179,10 +180,12 @@ pub struct Config {
 fn benchmark_backtest() {
     let Config {
         risk_free_return,
-        system: SystemConfig {
-            instruments,
-            executions,
-        },
+        system:
+            SystemConfig {
+                instruments,
+                executions,
+                ..
+            },
     } = serde_json::from_str(CONFIG).unwrap();
 
     let args_constant = args_constant(instruments, executions);
@@ -191,6 +194,7 @@ fn benchmark_backtest() {
     let mut c = Criterion::default().without_plots();
 
     bench_backtest(&mut c, Arc::clone(&args_constant), &args_dynamic);
+    bench_engine_process_batch(&mut c, &args_constant);
     bench_backtests_concurrent(&mut c, args_constant, args_dynamic);
 }
 
@@ -241,6 +245,83 @@ fn bench_backtest(
     group.finish();
 }
 
+/// Engine 逐个事件处理与批处理的基准测试。
+///
+/// 此函数对比 `Engine::process` 逐个处理市场事件与 `Engine::process_batch` 批量处理
+/// （仅在批次结束时生成一次算法订单）的吞吐量。
+///
+/// # 参数
+///
+/// - `c`: Criterion 基准测试器
+/// - `args_constant`: 回测常量参数
+fn bench_engine_process_batch(
+    c: &mut Criterion,
+    args_constant: &BacktestArgsConstant<
+        MarketDataInMemory<DataKind>,
+        Daily,
+        EngineState<DefaultGlobalData, LoseMoneyInstrumentData>,
+    >,
+) {
+    let market_events =
+        market_data_from_file::<InstrumentIndex, DataKind>(FILE_PATH_MARKET_DATA_INDEXED);
+    let time_first_event = market_events
+        .iter()
+        .find_map(|event| match event {
+            MarketStreamEvent::Item(event) => Some(event.time_exchange),
+            _ => None,
+        })
+        .unwrap();
+    let events = market_events
+        .into_iter()
+        .map(EngineEvent::Market)
+        .collect::<Vec<_>>();
+
+    // 为每次迭代构建新的 Engine，保留 execution_rx 以确保 ExecutionRequest 可以成功发送
+    let engine = || {
+        let (execution_tx, execution_rx) = mpsc_unbounded();
+        let engine = Engine::new(
+            HistoricalClock::new(time_first_event),
+            args_constant.engine_state.clone(),
+            MultiExchangeTxMap::from_iter([(ExchangeId::BinanceSpot, Some(execution_tx))]),
+            LoseMoneyStrategy::default(),
+            DefaultRiskManager::default(),
+        );
+        (engine, execution_rx, events.clone())
+    };
+
+    let mut group = c.benchmark_group("Engine Process");
+    group.warm_up_time(std::time::Duration::from_secs(1));
+    group.measurement_time(std::time::Duration::from_secs(10));
+    group.sample_size(50);
+    group.throughput(Throughput::Elements(events.len() as u64));
+
+    group.bench_function("PerEvent", |b| {
+        b.iter_batched(
+            engine,
+            |(mut engine, execution_rx, events)| {
+                for event in events {
+                    engine.process(event);
+                }
+                (engine, execution_rx)
+            },
+            criterion::BatchSize::LargeInput,
+        );
+    });
+
+    group.bench_function("Batch", |b| {
+        b.iter_batched(
+            engine,
+            |(mut engine, execution_rx, events)| {
+                let audits = engine.process_batch(events);
+                (engine, execution_rx, audits)
+            },
+            criterion::BatchSize::LargeInput,
+        );
+    });
+
+    group.finish();
+}
+
 /// 并发回测的基准测试。
 ///
 /// 此函数对并发执行多个回测的性能进行基准测试。
@@ -350,10 +431,11 @@ impl AlgoStrategy for LoseMoneyStrategy {
                     },
                     state: RequestOpen {
                         side: Side::Buy,
-                        price: Decimal::from_f64(trade_not_sent_as_order_open.price).unwrap(),
-                        quantity: Decimal::from_f64(trade_not_sent_as_order_open.amount).unwrap(),
+                        price: trade_not_sent_as_order_open.price,
+                        quantity: trade_not_sent_as_order_open.amount,
                         kind: OrderKind::Market,
                         time_in_force: TimeInForce::ImmediateOrCancel,
+                        reduce_only: false,
                     },
                 })
             });
@@ -523,6 +605,7 @@ fn args_constant(
         market_data,
         summary_interval: Daily,
         engine_state,
+        warmup: None,
     })
 }
 
@@ -0,0 +1,88 @@
+use barter::engine::state::{
+    EngineState,
+    global::DefaultGlobalData,
+    instrument::{data::DefaultInstrumentMarketData, lookup::InstrumentSymbolIndex},
+};
+use barter_instrument::{
+    exchange::ExchangeId, index::IndexedInstruments, instrument::name::InstrumentNameInternal,
+    test_utils::instrument,
+};
+use chrono::{DateTime, Utc};
+use criterion::{Criterion, Throughput};
+use std::hint::black_box;
+
+criterion::criterion_main!(benchmark_instrument_lookup);
+
+/// 每个交易所生成的交易对数量。
+const INSTRUMENTS_PER_EXCHANGE: usize = 250;
+
+/// 基准测试使用的交易所。
+const EXCHANGES: [ExchangeId; 4] = [
+    ExchangeId::BinanceSpot,
+    ExchangeId::BybitSpot,
+    ExchangeId::Kraken,
+    ExchangeId::Okx,
+];
+
+/// 交易对查找基准测试主函数。
+///
+/// 此函数比较按交易所原生交易对名称查找 `InstrumentState` 的两种方式：
+/// - **InternalName**: 每次查找都构造 `InstrumentNameInternal` 并查找 `InstrumentStates`
+/// - **SymbolIndex**: 使用缓存的 [`InstrumentSymbolIndex`] 直接解析到 `InstrumentStates` 中的位置
+fn benchmark_instrument_lookup() {
+    let instruments = IndexedInstruments::new(EXCHANGES.into_iter().flat_map(|exchange| {
+        (0..INSTRUMENTS_PER_EXCHANGE)
+            .map(move |base| instrument(exchange, &format!("base{base}"), "usdt"))
+    }));
+
+    let state = EngineState::builder(&instruments, DefaultGlobalData, |_| {
+        DefaultInstrumentMarketData::default()
+    })
+    .time_engine_start(DateTime::<Utc>::MIN_UTC)
+    .build();
+
+    let symbols = InstrumentSymbolIndex::new(&instruments);
+
+    let lookups = instruments
+        .instruments()
+        .iter()
+        .map(|instrument| {
+            (
+                instrument.value.exchange.value,
+                instrument.value.name_exchange.name().to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let mut c = Criterion::default().without_plots();
+
+    let mut group = c.benchmark_group("Instrument Lookup");
+    group.warm_up_time(std::time::Duration::from_secs(1));
+    group.measurement_time(std::time::Duration::from_secs(5));
+    group.sample_size(50);
+    group.throughput(Throughput::Elements(lookups.len() as u64));
+
+    group.bench_function("InternalName", |b| {
+        b.iter(|| {
+            for (exchange, name_exchange) in &lookups {
+                let name =
+                    InstrumentNameInternal::new_from_exchange(*exchange, name_exchange.as_str());
+                black_box(state.instruments.instrument(&name));
+            }
+        })
+    });
+
+    group.bench_function("SymbolIndex", |b| {
+        b.iter(|| {
+            for (exchange, name_exchange) in &lookups {
+                black_box(state.instruments.instrument_by_exchange_symbol(
+                    &symbols,
+                    *exchange,
+                    name_exchange,
+                ));
+            }
+        })
+    });
+
+    group.finish();
+}
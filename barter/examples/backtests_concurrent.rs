@@ -42,10 +42,12 @@ async fn main() {
 
     let Config {
         risk_free_return,
-        system: SystemConfig {
-            instruments,
-            executions,
-        },
+        system:
+            SystemConfig {
+                instruments,
+                mut executions,
+                initial_balances,
+            },
     } = load_config();
 
     // Construct IndexedInstruments
@@ -56,12 +58,16 @@ async fn main() {
     let market_data = MarketDataInMemory::new(Arc::new(market_events));
     let time_engine_start = market_data.time_first_event().await.unwrap();
 
+    // Seed MockExchange balances with the configured InitialBalances
+    initial_balances.seed_executions(&mut executions, time_engine_start);
+
     // Construct EngineState
     let engine_state = EngineStateBuilder::new(&instruments, DefaultGlobalData::default(), |_| {
         DefaultInstrumentMarketData::default()
     })
     .time_engine_start(time_engine_start)
     .trading_state(TradingState::Enabled)
+    .balances(initial_balances.engine_balances())
     .build();
 
     // Construct constant backtest arguments
@@ -71,6 +77,7 @@ async fn main() {
         market_data,
         summary_interval: Daily,
         engine_state,
+        warmup: None,
     });
 
     // Define dummy dynamic backtest arguments
@@ -45,6 +45,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let SystemConfig {
         instruments,
         executions,
+        initial_balances,
     } = load_config()?;
 
     // Construct IndexedInstruments
@@ -75,6 +76,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .audit_mode(AuditMode::Disabled)
         // Engine starts with TradingState::Enabled
         .trading_state(TradingState::Enabled)
+        // Seed Engine & MockExchange balances with any configured initial "paper" balances
+        .initial_balances(initial_balances)
         // Build System, but don't start spawning tasks yet
         .build::<EngineEvent, _>()?
         // Init System, spawning component tasks on the current runtime
@@ -47,6 +47,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let SystemConfig {
         instruments,
         executions,
+        initial_balances,
     } = load_config()?;
 
     // 构建索引交易对集合
@@ -80,6 +81,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .audit_mode(AuditMode::Enabled)
         // Engine 以 TradingState::Disabled 状态启动
         .trading_state(TradingState::Disabled)
+        // Seed Engine & MockExchange balances with any configured initial "paper" balances
+        .initial_balances(initial_balances)
         // 构建 System，但尚未启动任务
         .build::<EngineEvent, _>()?
         // 初始化 System，在当前运行时上生成组件任务
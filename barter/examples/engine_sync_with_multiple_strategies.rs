@@ -323,6 +323,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let SystemConfig {
         instruments,
         executions,
+        initial_balances,
     } = load_config()?;
 
     // Construct IndexedInstruments
@@ -356,6 +357,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .audit_mode(AuditMode::Enabled)
         // Engine starts with TradingState::Disabled
         .trading_state(TradingState::Disabled)
+        // Seed Engine & MockExchange balances with any configured initial "paper" balances
+        .initial_balances(initial_balances)
         // Build System, but don't start spawning tasks yet
         .build::<EngineEvent, _>()?
         // Init System, spawning component tasks on the current runtime
@@ -2,13 +2,27 @@
 //!
 //! 本模块提供了回测市场数据的接口和实现。
 //! 定义了如何为回测提供市场数据流和历史时钟。
+//!
+//! 同时提供了 [`candles_from_trades`]，用于将记录的公开成交离线聚合为 OHLCV [`Candle`] 序列，
+//! 以便进行基于 K 线的回测。
 
-use crate::error::BarterError;
-use barter_data::streams::consumer::MarketStreamEvent;
-use barter_instrument::instrument::InstrumentIndex;
-use chrono::{DateTime, Utc};
-use futures::Stream;
-use std::sync::Arc;
+use crate::{
+    engine::state::instrument::data::{CandleData, OhlcvCandle},
+    error::BarterError,
+};
+use barter_data::{
+    event::MarketEvent,
+    streams::consumer::MarketStreamEvent,
+    subscription::{candle::Candle, trade::PublicTrade},
+};
+use barter_instrument::{exchange::ExchangeId, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
+use futures::{Stream, StreamExt};
+use indexmap::IndexMap;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use serde::{Deserialize, Serialize};
+use std::{hash::Hash, sync::Arc};
+use tracing::warn;
 
 /// 提供回测 MarketStream 和相关 [`HistoricalClock`](crate::engine::clock::HistoricalClock) 的接口。
 ///
@@ -132,3 +146,400 @@ impl<Kind> MarketDataInMemory<Kind> {
         }
     }
 }
+
+/// [`candles_from_trades`] 对没有任何成交的时间区间的处理方式。
+///
+/// ## 模式说明
+///
+/// - **Skip**: 空时间区间不生成 K 线（默认）
+/// - **Flat**: 空时间区间生成一根平 K 线，开高低收均为上一根 K 线的收盘价，成交量为零
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum CandleGaps {
+    /// 空时间区间不生成 K 线。
+    #[default]
+    Skip,
+
+    /// 空时间区间生成一根平 K 线。
+    Flat,
+}
+
+/// 将公开成交 [`MarketEvent`] 的 `Stream` 聚合为按 `interval` 划分的 OHLCV [`Candle`] `Stream`。
+///
+/// 时间区间按 UNIX 纪元对齐（例如 1 分钟区间从每分钟的第 0 秒开始），成交按 `time_exchange`
+/// 归入对应区间，每个交易对独立聚合。当任意成交进入新的区间时，输出所有交易对在之前区间的 K 线；
+/// 输入 `Stream` 结束时，输出所有未完成的 K 线。输出的 K 线均为已收盘（`is_closed = true`），
+/// `close_time` 和 `time_exchange` 为区间的结束时间，`time_received` 为该 K 线最后一笔成交的
+/// 接收时间（平 K 线为揭示该空区间的成交的接收时间），并按 `close_time` 递增输出（同一区间内
+/// 按交易对首次出现的顺序）。
+///
+/// 每个交易对的 OHLCV 聚合由 [`CandleData`] 完成，与 Engine 实时聚合 K 线的结果一致。
+///
+/// 输入成交应按时间排序。早于当前区间的乱序成交所属的 K 线已经输出，因此会被丢弃。
+///
+/// ## 参数
+///
+/// - `trades`: 公开成交 [`MarketEvent`] 的 `Stream`
+/// - `interval`: K 线时间区间
+/// - `gaps`: 空时间区间的处理方式
+///
+/// ## Panics
+///
+/// 如果 `interval` 不是正的毫秒数，此函数会 panic。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let candles = candles_from_trades(trades, TimeDelta::minutes(1), CandleGaps::Skip);
+/// ```
+pub fn candles_from_trades<InstrumentKey, St>(
+    trades: St,
+    interval: TimeDelta,
+    gaps: CandleGaps,
+) -> impl Stream<Item = MarketEvent<InstrumentKey, Candle>>
+where
+    InstrumentKey: Clone + Eq + Hash,
+    St: Stream<Item = MarketEvent<InstrumentKey, PublicTrade>>,
+{
+    let aggregator = CandleAggregator::new(interval, gaps);
+
+    futures::stream::unfold(Some((Box::pin(trades), aggregator)), |state| async move {
+        let (mut trades, mut aggregator) = state?;
+
+        while let Some(trade) = trades.next().await {
+            let candles = aggregator.update(trade);
+            if !candles.is_empty() {
+                return Some((candles, Some((trades, aggregator))));
+            }
+        }
+
+        Some((aggregator.finish(), None))
+    })
+    .flat_map(futures::stream::iter)
+}
+
+/// 按交易对聚合公开成交为 OHLCV [`Candle`]，由 [`candles_from_trades`] 使用。
+#[derive(Debug, Clone)]
+struct CandleAggregator<InstrumentKey> {
+    interval: TimeDelta,
+    gaps: CandleGaps,
+    /// 当前时间区间的开始时间，即所有已处理成交中最新的区间。
+    time_open: Option<DateTime<Utc>>,
+    candles: IndexMap<InstrumentKey, InstrumentCandles>,
+}
+
+/// 单个交易对的 K 线聚合状态。
+#[derive(Debug, Clone)]
+struct InstrumentCandles {
+    exchange: ExchangeId,
+    data: CandleData,
+    /// 当前区间最后一笔成交的接收时间。
+    time_received: DateTime<Utc>,
+}
+
+impl<InstrumentKey> CandleAggregator<InstrumentKey>
+where
+    InstrumentKey: Clone + Eq + Hash,
+{
+    fn new(interval: TimeDelta, gaps: CandleGaps) -> Self {
+        assert!(
+            interval.num_milliseconds() > 0,
+            "candle interval must be a positive number of milliseconds"
+        );
+
+        Self {
+            interval,
+            gaps,
+            time_open: None,
+            candles: IndexMap::new(),
+        }
+    }
+
+    /// 使用下一个成交更新聚合状态，返回因进入新区间而完成的 K 线。
+    ///
+    /// 早于当前区间的成交会被丢弃。
+    fn update(
+        &mut self,
+        trade: MarketEvent<InstrumentKey, PublicTrade>,
+    ) -> Vec<MarketEvent<InstrumentKey, Candle>> {
+        let time_open = CandleData::interval_open(self.interval, trade.time_exchange);
+
+        let candles = match self.time_open {
+            Some(current) if time_open < current => {
+                warn!(
+                    exchange = %trade.exchange,
+                    time_exchange = %trade.time_exchange,
+                    "candles_from_trades dropping out of order trade from a closed interval"
+                );
+                return Vec::new();
+            }
+            Some(current) if time_open > current => {
+                self.advance(current, time_open, trade.time_received)
+            }
+            _ => Vec::new(),
+        };
+        self.time_open = Some(time_open);
+
+        let interval = self.interval;
+        let state = self
+            .candles
+            .entry(trade.instrument)
+            .or_insert_with(|| InstrumentCandles {
+                exchange: trade.exchange,
+                data: CandleData::new(interval),
+                time_received: trade.time_received,
+            });
+
+        // 之前区间的 K 线已由 advance 完成，因此此成交不会完成任何 K 线
+        let _ =
+            state
+                .data
+                .update_from_trade(trade.time_exchange, trade.kind.price, trade.kind.amount);
+        state.time_received = trade.time_received;
+
+        candles
+    }
+
+    /// 完成所有交易对在 `previous` 区间的 K 线，并推进到从 `time_open` 开始的新区间，返回按
+    /// `close_time` 排序的 K 线。
+    ///
+    /// `time_received` 为进入新区间的成交的接收时间，用作平 K 线的接收时间。
+    fn advance(
+        &mut self,
+        previous: DateTime<Utc>,
+        time_open: DateTime<Utc>,
+        time_received: DateTime<Utc>,
+    ) -> Vec<MarketEvent<InstrumentKey, Candle>> {
+        let mut candles = Vec::new();
+
+        for (instrument, state) in self.candles.iter_mut() {
+            let mut time_gap = previous;
+
+            if let Some(closed) = state.data.current.take() {
+                candles.push(state.candle(instrument, &closed, state.time_received));
+                time_gap = closed.time_close;
+                state.data.last_closed = Some(closed);
+            }
+
+            if self.gaps == CandleGaps::Flat
+                && let Some(close) = state.data.last_closed.as_ref().map(|candle| candle.close)
+            {
+                while time_gap < time_open {
+                    let flat = OhlcvCandle::new(
+                        time_gap,
+                        time_gap + self.interval,
+                        close,
+                        close,
+                        close,
+                        close,
+                        Decimal::ZERO,
+                        0,
+                    );
+                    candles.push(state.candle(instrument, &flat, time_received));
+                    time_gap += self.interval;
+                }
+            }
+        }
+
+        // 稳定排序，同一区间内保持交易对首次出现的顺序
+        candles.sort_by_key(|candle| candle.kind.close_time);
+        candles
+    }
+
+    /// 结束聚合，返回所有未完成的 K 线。
+    fn finish(self) -> Vec<MarketEvent<InstrumentKey, Candle>> {
+        self.candles
+            .iter()
+            .filter_map(|(instrument, state)| {
+                let current = state.data.current_candle()?;
+                Some(state.candle(instrument, current, state.time_received))
+            })
+            .collect()
+    }
+}
+
+impl InstrumentCandles {
+    fn candle<InstrumentKey>(
+        &self,
+        instrument: &InstrumentKey,
+        candle: &OhlcvCandle,
+        time_received: DateTime<Utc>,
+    ) -> MarketEvent<InstrumentKey, Candle>
+    where
+        InstrumentKey: Clone,
+    {
+        MarketEvent {
+            time_exchange: candle.time_close,
+            time_received,
+            time_processed: None,
+            sequence: None,
+            exchange: self.exchange,
+            instrument: instrument.clone(),
+            kind: Candle {
+                close_time: candle.time_close,
+                open: candle.open.to_f64().unwrap_or_default(),
+                high: candle.high.to_f64().unwrap_or_default(),
+                low: candle.low.to_f64().unwrap_or_default(),
+                close: candle.close.to_f64().unwrap_or_default(),
+                volume: candle.volume.to_f64().unwrap_or_default(),
+                trade_count: candle.trade_count,
+                is_closed: true,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::Side;
+    use rust_decimal_macros::dec;
+
+    fn trade(
+        instrument: usize,
+        seconds: i64,
+        price: Decimal,
+        amount: Decimal,
+    ) -> MarketEvent<InstrumentIndex, PublicTrade> {
+        let time = DateTime::<Utc>::from_timestamp(seconds, 0).unwrap();
+        MarketEvent {
+            time_exchange: time,
+            time_received: time + TimeDelta::seconds(1),
+            time_processed: None,
+            sequence: None,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: InstrumentIndex(instrument),
+            kind: PublicTrade {
+                id: seconds.to_string(),
+                price,
+                amount,
+                side: Side::Buy,
+            },
+        }
+    }
+
+    fn candle(
+        instrument: usize,
+        close_seconds: i64,
+        received_seconds: i64,
+        ohlcv: [f64; 5],
+        trade_count: u64,
+    ) -> MarketEvent<InstrumentIndex, Candle> {
+        let close_time = DateTime::<Utc>::from_timestamp(close_seconds, 0).unwrap();
+        let [open, high, low, close, volume] = ohlcv;
+        MarketEvent {
+            time_exchange: close_time,
+            time_received: DateTime::<Utc>::from_timestamp(received_seconds, 0).unwrap(),
+            time_processed: None,
+            sequence: None,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: InstrumentIndex(instrument),
+            kind: Candle {
+                close_time,
+                open,
+                high,
+                low,
+                close,
+                volume,
+                trade_count,
+                is_closed: true,
+            },
+        }
+    }
+
+    fn trades() -> Vec<MarketEvent<InstrumentIndex, PublicTrade>> {
+        vec![
+            // Interval [0, 60)
+            trade(0, 0, dec!(100), dec!(1)),
+            trade(1, 10, dec!(10), dec!(5)),
+            trade(0, 20, dec!(105), dec!(0.5)),
+            trade(0, 30, dec!(95), dec!(2)),
+            trade(0, 59, dec!(102), dec!(1.5)),
+            // Interval [60, 120)
+            trade(0, 60, dec!(103), dec!(1)),
+            // Interval [120, 180) is empty
+            // Interval [180, 240)
+            trade(0, 200, dec!(98), dec!(3)),
+            trade(1, 210, dec!(11), dec!(1)),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_candles_from_trades_skip_gaps() {
+        let actual = candles_from_trades(
+            futures::stream::iter(trades()),
+            TimeDelta::minutes(1),
+            CandleGaps::Skip,
+        )
+        .collect::<Vec<_>>()
+        .await;
+
+        let expected = vec![
+            candle(0, 60, 60, [100.0, 105.0, 95.0, 102.0, 5.0], 4),
+            candle(1, 60, 11, [10.0, 10.0, 10.0, 10.0, 5.0], 1),
+            candle(0, 120, 61, [103.0, 103.0, 103.0, 103.0, 1.0], 1),
+            candle(0, 240, 201, [98.0, 98.0, 98.0, 98.0, 3.0], 1),
+            candle(1, 240, 211, [11.0, 11.0, 11.0, 11.0, 1.0], 1),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_candles_from_trades_flat_gaps() {
+        let actual = candles_from_trades(
+            futures::stream::iter(trades()),
+            TimeDelta::minutes(1),
+            CandleGaps::Flat,
+        )
+        .collect::<Vec<_>>()
+        .await;
+
+        let expected = vec![
+            candle(0, 60, 60, [100.0, 105.0, 95.0, 102.0, 5.0], 4),
+            candle(1, 60, 11, [10.0, 10.0, 10.0, 10.0, 5.0], 1),
+            candle(0, 120, 61, [103.0, 103.0, 103.0, 103.0, 1.0], 1),
+            candle(1, 120, 201, [10.0, 10.0, 10.0, 10.0, 0.0], 0),
+            candle(0, 180, 201, [103.0, 103.0, 103.0, 103.0, 0.0], 0),
+            candle(1, 180, 201, [10.0, 10.0, 10.0, 10.0, 0.0], 0),
+            candle(0, 240, 201, [98.0, 98.0, 98.0, 98.0, 3.0], 1),
+            candle(1, 240, 211, [11.0, 11.0, 11.0, 11.0, 1.0], 1),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[tokio::test]
+    async fn test_candles_from_trades_drops_out_of_order_trades() {
+        let trades = [
+            trade(0, 0, dec!(100), dec!(1)),
+            trade(0, 70, dec!(101), dec!(1)),
+            // Interval [0, 60) already closed
+            trade(0, 30, dec!(50), dec!(10)),
+            trade(1, 59, dec!(10), dec!(1)),
+            trade(0, 80, dec!(102), dec!(2)),
+        ];
+
+        let actual = candles_from_trades(
+            futures::stream::iter(trades),
+            TimeDelta::minutes(1),
+            CandleGaps::Skip,
+        )
+        .collect::<Vec<_>>()
+        .await;
+
+        let expected = vec![
+            candle(0, 60, 1, [100.0, 100.0, 100.0, 100.0, 1.0], 1),
+            candle(0, 120, 81, [101.0, 102.0, 101.0, 102.0, 3.0], 2),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let close_times = actual
+            .iter()
+            .map(|candle| candle.kind.close_time)
+            .collect::<Vec<_>>();
+        assert!(close_times.is_sorted());
+    }
+}
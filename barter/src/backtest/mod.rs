@@ -24,7 +24,7 @@ use crate::{
     },
     engine::{
         Processor,
-        clock::HistoricalClock,
+        clock::{HistoricalClock, HistoricalClockMode},
         execution_tx::MultiExchangeTxMap,
         state::{EngineState, instrument::data::InstrumentDataState},
     },
@@ -39,12 +39,16 @@ use crate::{
 };
 use crate::{
     engine::Engine,
-    execution::builder::{ExecutionBuild, ExecutionBuilder},
+    execution::{
+        builder::{ExecutionBuild, ExecutionBuilder},
+        paper::{PaperMarketFeed, PaperMarketKind},
+    },
     system::builder::{AuditMode, SystemBuild},
 };
 use barter_data::event::MarketEvent;
 use barter_execution::AccountEvent;
 use barter_instrument::{index::IndexedInstruments, instrument::InstrumentIndex};
+use chrono::TimeDelta;
 use futures::future::try_join_all;
 use rust_decimal::Decimal;
 use smol_str::SmolStr;
@@ -77,6 +81,11 @@ pub struct BacktestArgsConstant<MarketData, SummaryInterval, State> {
     pub summary_interval: SummaryInterval,
     /// EngineState。
     pub engine_state: State,
+    /// 可选的预热期，从第一个市场事件开始计算。
+    ///
+    /// Engine 仍会处理预热期间的事件以构建状态，但预热期间进入的仓位不计入
+    /// `TradingSummary`，且摘要的 `time_engine_start` 为预热结束时间。
+    pub warmup: Option<TimeDelta>,
 }
 
 /// 可在各个回测之间变化的变量配置。
@@ -177,6 +186,7 @@ where
         + Send
         + 'static,
     InstrumentData: InstrumentDataState + Default + Send + 'static,
+    InstrumentData::MarketEventKind: PaperMarketKind,
 {
     let time_start = std::time::Instant::now();
 
@@ -201,9 +211,9 @@ where
 /// ## 工作流程
 ///
 /// 1. 从市场数据创建历史时钟
-/// 2. 创建市场数据流
+/// 2. 创建市场数据流，通过 [`PaperMarketFeed`] 将市场价格和最优买卖价转发到模拟交易所
 /// 3. 构建执行基础设施
-/// 4. 创建 Engine 和 System
+/// 4. 创建 Engine 和 System（如配置了预热期，则设置统计预热截止时间）
 /// 5. 运行回测直到结束
 /// 6. 生成交易摘要
 ///
@@ -268,15 +278,17 @@ where
         + Send
         + 'static,
     InstrumentData: InstrumentDataState + Send + 'static,
+    InstrumentData::MarketEventKind: PaperMarketKind,
 {
-    // 从市场数据获取第一个事件时间并创建历史时钟
-    let clock = args_constant
-        .market_data
-        .time_first_event()
-        .await
-        .map(HistoricalClock::new)?;
-    // 创建市场数据流
-    let market_stream = args_constant.market_data.stream().await?;
+    // 从市场数据获取第一个事件时间并创建历史时钟（仅由事件时间戳推进）
+    let time_first_event = args_constant.market_data.time_first_event().await?;
+    let clock = HistoricalClock::with_mode(time_first_event, HistoricalClockMode::EventTime);
+
+    // 计算可选的预热截止时间
+    let time_warmup_end = args_constant.warmup.map(|warmup| time_first_event + warmup);
+    // 创建市场数据流，并将其市场价格和最优买卖价转发到模拟交易所
+    let paper_market_feed = PaperMarketFeed::default();
+    let market_stream = paper_market_feed.forward(args_constant.market_data.stream().await?);
 
     // 构建执行基础设施
     let ExecutionBuild {
@@ -288,17 +300,27 @@ where
         .clone()
         .into_iter()
         .try_fold(
-            ExecutionBuilder::new(&args_constant.instruments),
+            ExecutionBuilder::new(&args_constant.instruments)
+                .with_paper_market_feed(paper_market_feed),
             |builder, config| match config {
                 ExecutionConfig::Mock(mock_config) => builder.add_mock(mock_config, clock.clone()),
             },
         )?
         .build();
 
+    // 设置 EngineState 的统计预热截止时间
+    let engine_state = match time_warmup_end {
+        Some(time_warmup_end) => args_constant
+            .engine_state
+            .clone()
+            .with_warmup(time_warmup_end),
+        None => args_constant.engine_state.clone(),
+    };
+
     // 创建 Engine
     let engine = Engine::new(
         clock,
-        args_constant.engine_state.clone(),
+        engine_state,
         execution_tx_map,
         args_dynamic.strategy,
         args_dynamic.risk,
@@ -319,10 +341,13 @@ where
     // 运行回测直到结束
     let (engine, _shutdown_audit) = system.shutdown_after_backtest().await?;
 
-    // 生成交易摘要
-    let trading_summary = engine
-        .trading_summary_generator(args_dynamic.risk_free_return)
-        .generate(args_constant.summary_interval);
+    // 生成交易摘要（如有预热期，则从预热结束时间开始）
+    let mut trading_summary_generator =
+        engine.trading_summary_generator(args_dynamic.risk_free_return);
+    if let Some(time_warmup_end) = time_warmup_end {
+        trading_summary_generator = trading_summary_generator.with_warmup(time_warmup_end);
+    }
+    let trading_summary = trading_summary_generator.generate(args_constant.summary_interval);
 
     Ok(BacktestSummary {
         id: args_dynamic.id,
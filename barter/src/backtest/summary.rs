@@ -1,12 +1,15 @@
 //! Summary 回测摘要模块
 //!
 //! 本模块提供了回测结果和指标的数据结构。
-//! 包括单个回测摘要和多个回测的汇总结果。
+//! 包括单个回测摘要和多个回测的汇总结果，以及用于 A/B 策略评估的
+//! 关键指标比较（[`BacktestSummary::compare`]）和排序（[`MultiBacktestSummary::rank_by`]）。
 
 use crate::statistic::summary::TradingSummary;
+use derive_more::Display;
 use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
 use smol_str::SmolStr;
-use std::time::Duration;
+use std::{cmp::Ordering, time::Duration};
 
 /// 包含多个 [`BacktestSummary`] 和相关多回测元数据的容器。
 ///
@@ -59,6 +62,42 @@ impl<Interval> MultiBacktestSummary<Interval> {
             summaries,
         }
     }
+
+    /// 按提供的 [`SummaryMetric`] 对所有 [`BacktestSummary`] 进行排序，最优的排在最前面。
+    ///
+    /// 排序方向由 [`SummaryMetric::is_higher_better`] 决定。缺少该指标值的回测排在最后，
+    /// 指标值相同的回测保持原有顺序。
+    ///
+    /// # 参数
+    ///
+    /// - `metric`: 用于排序的指标
+    ///
+    /// # 返回值
+    ///
+    /// 返回按指标从优到劣排序的 `BacktestSummary` 引用。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let best = multi_summary.rank_by(SummaryMetric::SharpeRatio)[0];
+    /// ```
+    pub fn rank_by(&self, metric: SummaryMetric) -> Vec<&BacktestSummary<Interval>> {
+        let mut ranked = self
+            .summaries
+            .iter()
+            .map(|summary| (summary.metric(metric), summary))
+            .collect::<Vec<_>>();
+
+        ranked.sort_by(|(a, _), (b, _)| match (a, b) {
+            (Some(a), Some(b)) if metric.is_higher_better() => b.cmp(a),
+            (Some(a), Some(b)) => a.cmp(b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+
+        ranked.into_iter().map(|(_, summary)| summary).collect()
+    }
 }
 
 /// 单个回测的 `TradingSummary` 和相关元数据。
@@ -84,3 +123,331 @@ pub struct BacktestSummary<Interval> {
     /// 回测模拟交易的绩效指标和统计信息。
     pub trading_summary: TradingSummary<Interval>,
 }
+
+impl<Interval> BacktestSummary<Interval> {
+    /// 计算 [`BacktestSummary`] 所有交易对的聚合 [`SummaryMetric`] 值。
+    ///
+    /// 聚合方式：
+    /// - **Pnl**: 所有交易对 PnL 之和
+    /// - **SharpeRatio / CalmarRatio / WinRate**: 存在该指标的交易对的平均值
+    /// - **MaxDrawdown**: 所有交易对中最大的最大回撤
+    ///
+    /// # 返回值
+    ///
+    /// 如果没有任何交易对具有该指标，返回 `None`。
+    pub fn metric(&self, metric: SummaryMetric) -> Option<Decimal> {
+        let tear_sheets = self.trading_summary.instruments.values();
+
+        match metric {
+            SummaryMetric::Pnl => tear_sheets
+                .map(|tear_sheet| tear_sheet.pnl)
+                .reduce(|total, pnl| total + pnl),
+            SummaryMetric::SharpeRatio => {
+                mean(tear_sheets.map(|tear_sheet| tear_sheet.sharpe_ratio.value))
+            }
+            SummaryMetric::CalmarRatio => {
+                mean(tear_sheets.map(|tear_sheet| tear_sheet.calmar_ratio.value))
+            }
+            SummaryMetric::WinRate => mean(tear_sheets.filter_map(|tear_sheet| {
+                tear_sheet.win_rate.as_ref().map(|win_rate| win_rate.value)
+            })),
+            SummaryMetric::MaxDrawdown => tear_sheets
+                .filter_map(|tear_sheet| {
+                    tear_sheet
+                        .pnl_drawdown_max
+                        .as_ref()
+                        .map(|drawdown| drawdown.0.value)
+                })
+                .max(),
+        }
+    }
+
+    /// 将此 [`BacktestSummary`] 与另一个进行比较，生成关键指标的差异。
+    ///
+    /// 每个 [`MetricDiff::delta`] 计算为 `self - other`。
+    ///
+    /// # 参数
+    ///
+    /// - `other`: 用于比较的另一个回测摘要
+    ///
+    /// # 返回值
+    ///
+    /// 返回包含所有 [`SummaryMetric::ALL`] 指标差异的 [`BacktestSummaryDiff`]。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let diff = variant_a.compare(&variant_b);
+    /// if diff.is_better(SummaryMetric::SharpeRatio) == Some(true) {
+    ///     // variant_a 的 Sharpe 比率更高
+    /// }
+    /// ```
+    pub fn compare(&self, other: &Self) -> BacktestSummaryDiff {
+        BacktestSummaryDiff {
+            id: self.id.clone(),
+            other_id: other.id.clone(),
+            metrics: SummaryMetric::ALL
+                .into_iter()
+                .map(|metric| MetricDiff::new(metric, self.metric(metric), other.metric(metric)))
+                .collect(),
+        }
+    }
+}
+
+/// 用于比较和排序 [`BacktestSummary`] 的关键指标。
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Display,
+)]
+pub enum SummaryMetric {
+    /// 夏普比率（越高越好）。
+    #[display("sharpe_ratio")]
+    SharpeRatio,
+    /// 卡尔马比率（越高越好）。
+    #[display("calmar_ratio")]
+    CalmarRatio,
+    /// 最大回撤（越低越好）。
+    #[display("max_drawdown")]
+    MaxDrawdown,
+    /// 总 PnL（越高越好）。
+    #[display("pnl")]
+    Pnl,
+    /// 胜率（越高越好）。
+    #[display("win_rate")]
+    WinRate,
+}
+
+impl SummaryMetric {
+    /// 所有 [`SummaryMetric`] 变体。
+    pub const ALL: [Self; 5] = [
+        Self::SharpeRatio,
+        Self::CalmarRatio,
+        Self::MaxDrawdown,
+        Self::Pnl,
+        Self::WinRate,
+    ];
+
+    /// 如果该指标的值越高越好，返回 `true`。
+    pub fn is_higher_better(&self) -> bool {
+        !matches!(self, Self::MaxDrawdown)
+    }
+}
+
+/// 两个 [`BacktestSummary`] 之间单个 [`SummaryMetric`] 的差异。
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MetricDiff {
+    /// 比较的指标。
+    pub metric: SummaryMetric,
+    /// 此回测的指标值。
+    pub value: Option<Decimal>,
+    /// 另一个回测的指标值。
+    pub other: Option<Decimal>,
+    /// `value - other`，如果任一值缺失则为 `None`。
+    pub delta: Option<Decimal>,
+}
+
+impl MetricDiff {
+    /// 根据两个指标值构造新的 [`MetricDiff`]，计算 `delta`。
+    pub fn new(metric: SummaryMetric, value: Option<Decimal>, other: Option<Decimal>) -> Self {
+        Self {
+            metric,
+            value,
+            other,
+            delta: value.zip(other).map(|(value, other)| value - other),
+        }
+    }
+
+    /// 如果此回测在该指标上优于另一个回测，返回 `Some(true)`。
+    ///
+    /// 如果任一值缺失，返回 `None`。
+    pub fn is_better(&self) -> Option<bool> {
+        self.delta
+            .map(|delta| match self.metric.is_higher_better() {
+                true => delta > Decimal::ZERO,
+                false => delta < Decimal::ZERO,
+            })
+    }
+}
+
+/// [`BacktestSummary::compare`] 生成的两个回测关键指标差异。
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct BacktestSummaryDiff {
+    /// 此回测的唯一标识符。
+    pub id: SmolStr,
+    /// 另一个回测的唯一标识符。
+    pub other_id: SmolStr,
+    /// 每个 [`SummaryMetric`] 的差异。
+    pub metrics: Vec<MetricDiff>,
+}
+
+impl BacktestSummaryDiff {
+    /// 查找提供的 [`SummaryMetric`] 的 [`MetricDiff`]。
+    pub fn metric(&self, metric: SummaryMetric) -> Option<&MetricDiff> {
+        self.metrics.iter().find(|diff| diff.metric == metric)
+    }
+
+    /// 如果此回测在提供的 [`SummaryMetric`] 上优于另一个回测，返回 `Some(true)`。
+    pub fn is_better(&self, metric: SummaryMetric) -> Option<bool> {
+        self.metric(metric).and_then(MetricDiff::is_better)
+    }
+}
+
+fn mean(values: impl Iterator<Item = Decimal>) -> Option<Decimal> {
+    let (sum, count) = values.fold((Decimal::ZERO, 0u32), |(sum, count), value| {
+        (sum + value, count + 1)
+    });
+
+    (count > 0).then(|| sum / Decimal::from(count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statistic::{
+        metric::{
+            calmar::CalmarRatio,
+            drawdown::{Drawdown, max::MaxDrawdown},
+            rate_of_return::RateOfReturn,
+            sharpe::SharpeRatio,
+            sortino::SortinoRatio,
+            win_rate::WinRate,
+        },
+        summary::instrument::TearSheet,
+        time::Daily,
+    };
+    use barter_instrument::instrument::name::InstrumentNameInternal;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn tear_sheet(pnl: Decimal, sharpe: Decimal, drawdown_max: Decimal) -> TearSheet<Daily> {
+        TearSheet {
+            pnl,
+            pnl_return: RateOfReturn {
+                value: Decimal::ZERO,
+                interval: Daily,
+            },
+            sharpe_ratio: SharpeRatio {
+                value: sharpe,
+                interval: Daily,
+            },
+            sortino_ratio: SortinoRatio {
+                value: Decimal::ZERO,
+                interval: Daily,
+            },
+            calmar_ratio: CalmarRatio {
+                value: Decimal::ZERO,
+                interval: Daily,
+            },
+            pnl_drawdown: None,
+            pnl_drawdown_mean: None,
+            pnl_drawdown_max: Some(MaxDrawdown(Drawdown::new(
+                drawdown_max,
+                DateTime::<Utc>::MIN_UTC,
+                DateTime::<Utc>::MIN_UTC,
+            ))),
+            win_rate: Some(WinRate { value: dec!(0.5) }),
+            profit_factor: None,
+            pnl_downside_deviation: Decimal::ZERO,
+            pnl_value_at_risk: None,
+            pnl_conditional_value_at_risk: None,
+            pnl_return_geometric_mean: None,
+            pnl_compound_growth_rate: None,
+            snapshots: Vec::new(),
+        }
+    }
+
+    fn summary(id: &str, tear_sheets: Vec<TearSheet<Daily>>) -> BacktestSummary<Daily> {
+        BacktestSummary {
+            id: SmolStr::new(id),
+            risk_free_return: Decimal::ZERO,
+            trading_summary: TradingSummary {
+                time_engine_start: DateTime::<Utc>::MIN_UTC,
+                time_engine_end: DateTime::<Utc>::MIN_UTC,
+                instruments: tear_sheets
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, tear_sheet)| {
+                        (InstrumentNameInternal::new(index.to_string()), tear_sheet)
+                    })
+                    .collect(),
+                assets: Default::default(),
+                exchanges: Default::default(),
+                benchmark: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_multi_backtest_summary_rank_by() {
+        let multi = MultiBacktestSummary::new(
+            Duration::ZERO,
+            [
+                summary(
+                    "low_sharpe",
+                    vec![tear_sheet(dec!(300), dec!(0.5), dec!(0.1))],
+                ),
+                summary(
+                    "high_sharpe",
+                    vec![
+                        tear_sheet(dec!(100), dec!(2.0), dec!(0.3)),
+                        tear_sheet(dec!(100), dec!(1.0), dec!(0.2)),
+                    ],
+                ),
+                summary("no_instruments", vec![]),
+                summary(
+                    "mid_sharpe",
+                    vec![tear_sheet(dec!(50), dec!(1.0), dec!(0.05))],
+                ),
+            ],
+        );
+
+        let ids = |metric| {
+            multi
+                .rank_by(metric)
+                .into_iter()
+                .map(|summary| summary.id.as_str())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ids(SummaryMetric::SharpeRatio),
+            vec!["high_sharpe", "mid_sharpe", "low_sharpe", "no_instruments"]
+        );
+        assert_eq!(
+            ids(SummaryMetric::Pnl),
+            vec!["low_sharpe", "high_sharpe", "mid_sharpe", "no_instruments"]
+        );
+        assert_eq!(
+            ids(SummaryMetric::MaxDrawdown),
+            vec!["mid_sharpe", "low_sharpe", "high_sharpe", "no_instruments"]
+        );
+    }
+
+    #[test]
+    fn test_backtest_summary_compare() {
+        let a = summary("a", vec![tear_sheet(dec!(100), dec!(1.5), dec!(0.2))]);
+        let b = summary("b", vec![tear_sheet(dec!(150), dec!(1.0), dec!(0.1))]);
+
+        let diff = a.compare(&b);
+
+        assert_eq!(diff.id, "a");
+        assert_eq!(diff.other_id, "b");
+        assert_eq!(diff.metrics.len(), SummaryMetric::ALL.len());
+        assert_eq!(
+            diff.metric(SummaryMetric::SharpeRatio),
+            Some(&MetricDiff {
+                metric: SummaryMetric::SharpeRatio,
+                value: Some(dec!(1.5)),
+                other: Some(dec!(1.0)),
+                delta: Some(dec!(0.5)),
+            })
+        );
+        assert_eq!(
+            diff.metric(SummaryMetric::Pnl).unwrap().delta,
+            Some(dec!(-50))
+        );
+        assert_eq!(diff.is_better(SummaryMetric::SharpeRatio), Some(true));
+        assert_eq!(diff.is_better(SummaryMetric::Pnl), Some(false));
+        assert_eq!(diff.is_better(SummaryMetric::MaxDrawdown), Some(false));
+        assert_eq!(diff.is_better(SummaryMetric::WinRate), Some(false));
+    }
+}
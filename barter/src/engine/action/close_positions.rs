@@ -121,7 +121,7 @@ where
 
         // 步骤2：发送订单请求（绕过风险检查）
         let cancels = self.send_requests(cancels);
-        let opens = self.send_requests(opens);
+        let opens = self.send_open_requests(opens);
 
         // 步骤3：记录在途订单请求
         self.state.record_in_flight_cancels(&cancels.sent);
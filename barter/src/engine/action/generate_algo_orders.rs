@@ -12,10 +12,12 @@
 //! # 工作流程
 //!
 //! 1. 策略生成订单请求（取消和开仓）
-//! 2. 风险管理检查订单请求
-//! 3. 发送通过风险检查的订单请求
-//! 4. 记录在途订单请求
-//! 5. 返回操作结果（包括被拒绝的订单）
+//! 2. 根据 [`TradingState`] 过滤开仓请求（`PostOnly` 状态下拒绝可能穿越订单簿的开仓请求），
+//!    并拒绝已过期交易对的开仓请求
+//! 3. 风险管理检查订单请求
+//! 4. 发送通过风险检查的订单请求
+//! 5. 记录在途订单请求
+//! 6. 返回操作结果（包括被拒绝的订单）
 
 use crate::{
     engine::{
@@ -23,7 +25,10 @@ use crate::{
         action::send_requests::{SendCancelsAndOpensOutput, SendRequests, SendRequestsOutput},
         error::UnrecoverableEngineError,
         execution_tx::ExecutionTxMap,
-        state::order::in_flight_recorder::InFlightRequestRecorder,
+        state::{
+            instrument::expiry::InstrumentExpiryState,
+            order::in_flight_recorder::InFlightRequestRecorder, trading::TradingState,
+        },
     },
     risk::{RiskApproved, RiskManager, RiskRefused},
     strategy::algo::AlgoStrategy,
@@ -93,7 +98,9 @@ impl<Clock, State, ExecutionTxs, Strategy, Risk, ExchangeKey, InstrumentKey>
     GenerateAlgoOrders<ExchangeKey, InstrumentKey>
     for Engine<Clock, State, ExecutionTxs, Strategy, Risk>
 where
-    State: InFlightRequestRecorder<ExchangeKey, InstrumentKey>,
+    State: InFlightRequestRecorder<ExchangeKey, InstrumentKey>
+        + AsRef<TradingState>
+        + InstrumentExpiryState<InstrumentKey>,
     ExecutionTxs: ExecutionTxMap<ExchangeKey, InstrumentKey>,
     Strategy: AlgoStrategy<ExchangeKey, InstrumentKey, State = State>,
     Risk: RiskManager<ExchangeKey, InstrumentKey, State = State>,
@@ -105,10 +112,12 @@ where
     /// 此实现执行以下步骤：
     ///
     /// 1. **策略生成**: 调用策略生成订单请求（取消和开仓）
-    /// 2. **风险管理**: 使用风险管理器检查订单请求，分为批准和拒绝两类
-    /// 3. **发送请求**: 发送通过风险检查的订单请求到执行管理器
-    /// 4. **记录在途**: 记录已发送的订单请求，用于跟踪订单状态
-    /// 5. **返回结果**: 返回包含所有结果的输出
+    /// 2. **交易状态过滤**: 在 `TradingState::PostOnly` 下拒绝不是限价 post-only 的开仓请求，
+    ///    并拒绝已过期交易对的开仓请求
+    /// 3. **风险管理**: 使用风险管理器检查订单请求，分为批准和拒绝两类
+    /// 4. **发送请求**: 发送通过风险检查的订单请求到执行管理器
+    /// 5. **记录在途**: 记录已发送的订单请求，用于跟踪订单状态
+    /// 6. **返回结果**: 返回包含所有结果的输出
     ///
     /// ## 错误处理
     ///
@@ -118,27 +127,75 @@ where
         // 步骤1：策略生成订单请求（取消和开仓）
         let (cancels, opens) = self.strategy.generate_algo_orders(&self.state);
 
-        // 步骤2：风险管理检查订单请求（批准和拒绝）
+        // 步骤2：根据 TradingState 过滤开仓请求（PostOnly 状态下仅允许被动订单）
+        let (opens, refused_opens_trading_state) =
+            filter_opens_by_trading_state(*self.state.as_ref(), opens);
+        let (opens, refused_opens_expired): (Vec<_>, Vec<_>) = opens
+            .into_iter()
+            .partition(|open| !self.state.is_expired(&open.key.instrument));
+
+        // 步骤3：风险管理检查订单请求（批准和拒绝）
         let (cancels, opens, refused_cancels, refused_opens) =
             self.risk.check(&self.state, cancels, opens);
 
-        // 步骤3：发送通过风险检查的订单请求
+        // 步骤4：发送通过风险检查的订单请求
         let cancels = self.send_requests(cancels.into_iter().map(|RiskApproved(cancel)| cancel));
-        let opens = self.send_requests(opens.into_iter().map(|RiskApproved(open)| open));
+        let opens = self.send_open_requests(opens.into_iter().map(|RiskApproved(open)| open));
 
-        // 步骤4：收集剩余的迭代器（以便可以访问 &mut self）
+        // 步骤5：收集剩余的迭代器（以便可以访问 &mut self）
         let cancels_refused = refused_cancels.into_iter().collect();
-        let opens_refused = refused_opens.into_iter().collect();
+        let opens_refused = refused_opens_trading_state
+            .into_iter()
+            .chain(
+                refused_opens_expired
+                    .into_iter()
+                    .map(|open| RiskRefused::new(open, "instrument expired")),
+            )
+            .chain(refused_opens)
+            .collect();
 
-        // 步骤5：记录在途订单请求（用于跟踪订单状态）
+        // 步骤6：记录在途订单请求（用于跟踪订单状态）
         self.state.record_in_flight_cancels(cancels.sent.iter());
         self.state.record_in_flight_opens(opens.sent.iter());
 
-        // 步骤6：返回包含所有结果的输出
+        // 步骤7：返回包含所有结果的输出
         GenerateAlgoOrdersOutput::new(cancels, opens, cancels_refused, opens_refused)
     }
 }
 
+/// 根据 [`TradingState`] 将算法开仓请求分为允许和拒绝两类。
+///
+/// 在 `TradingState::PostOnly` 下，只有 [`TradingState::permits_open`] 允许的被动开仓请求
+/// 会被保留，其余开仓请求会被包装为 [`RiskRefused`] 返回，以便在审计中可见。
+/// 其他状态下所有开仓请求都会被保留（`Disabled` 状态下 Engine 不会生成算法订单）。
+fn filter_opens_by_trading_state<ExchangeKey, InstrumentKey>(
+    trading_state: TradingState,
+    opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeKey, InstrumentKey>>,
+) -> (
+    Vec<OrderRequestOpen<ExchangeKey, InstrumentKey>>,
+    Vec<RiskRefused<OrderRequestOpen<ExchangeKey, InstrumentKey>>>,
+) {
+    if trading_state != TradingState::PostOnly {
+        return (opens.into_iter().collect(), Vec::new());
+    }
+
+    let (permitted, refused): (Vec<_>, Vec<_>) = opens
+        .into_iter()
+        .partition(|open| trading_state.permits_open(&open.state));
+
+    let refused = refused
+        .into_iter()
+        .map(|open| {
+            RiskRefused::new(
+                open,
+                "TradingState::PostOnly only permits Limit GoodUntilCancelled post_only opens",
+            )
+        })
+        .collect();
+
+    (permitted, refused)
+}
+
 /// [`Engine`] 操作 [`GenerateAlgoOrders::generate_algo_orders`] 完成的工作摘要。
 ///
 /// GenerateAlgoOrdersOutput 包含算法订单生成操作的完整结果，包括成功的订单、
@@ -273,3 +330,70 @@ impl<ExchangeKey, InstrumentKey> Default for GenerateAlgoOrdersOutput<ExchangeKe
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, StrategyId},
+    };
+    use barter_instrument::Side;
+    use rust_decimal_macros::dec;
+
+    fn open_request(
+        cid: &'static str,
+        kind: OrderKind,
+        time_in_force: TimeInForce,
+    ) -> OrderRequestOpen<ExchangeIndex, InstrumentIndex> {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                price: dec!(100),
+                quantity: dec!(1),
+                kind,
+                time_in_force,
+                reduce_only: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_filter_opens_by_trading_state() {
+        let market = open_request("market", OrderKind::Market, TimeInForce::ImmediateOrCancel);
+        let limit_gtc = open_request(
+            "limit_gtc",
+            OrderKind::Limit,
+            TimeInForce::GoodUntilCancelled { post_only: false },
+        );
+        let limit_post_only = open_request(
+            "limit_post_only",
+            OrderKind::Limit,
+            TimeInForce::GoodUntilCancelled { post_only: true },
+        );
+        let opens = vec![market.clone(), limit_gtc.clone(), limit_post_only.clone()];
+
+        // TradingState::Enabled permits all opens
+        let (permitted, refused) =
+            filter_opens_by_trading_state(TradingState::Enabled, opens.clone());
+        assert_eq!(permitted, opens);
+        assert!(refused.is_empty());
+
+        // TradingState::PostOnly suppresses aggressive opens
+        let (permitted, refused) = filter_opens_by_trading_state(TradingState::PostOnly, opens);
+        assert_eq!(permitted, vec![limit_post_only]);
+        assert_eq!(
+            refused
+                .into_iter()
+                .map(|refused| refused.item)
+                .collect::<Vec<_>>(),
+            vec![market, limit_gtc]
+        );
+    }
+}
@@ -23,10 +23,12 @@ use crate::engine::{
         send_requests::{SendCancelsAndOpensOutput, SendRequestsOutput},
     },
     error::UnrecoverableEngineError,
+    query::QueryStateOutput,
+    swap::SwapStrategyOutput,
 };
 use barter_execution::order::request::{RequestCancel, RequestOpen};
 use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
-use barter_integration::collection::one_or_many::OneOrMany;
+use barter_integration::collection::{none_one_or_many::NoneOneOrMany, one_or_many::OneOrMany};
 use derive_more::From;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -97,6 +99,10 @@ pub enum ActionOutput<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentInd
     OpenOrders(SendRequestsOutput<RequestOpen, ExchangeKey, InstrumentKey>),
     /// 平仓操作的输出。
     ClosePositions(SendCancelsAndOpensOutput<ExchangeKey, InstrumentKey>),
+    /// 状态查询操作的输出。
+    QueryState(QueryStateOutput),
+    /// Strategy 热替换操作的输出。
+    SwapStrategy(SwapStrategyOutput),
 }
 
 impl<ExchangeKey, InstrumentKey> ActionOutput<ExchangeKey, InstrumentKey> {
@@ -142,6 +148,7 @@ impl<ExchangeKey, InstrumentKey> ActionOutput<ExchangeKey, InstrumentKey> {
             ActionOutput::CancelOrders(cancels) => cancels.unrecoverable_errors(),
             ActionOutput::OpenOrders(opens) => opens.unrecoverable_errors(),
             ActionOutput::ClosePositions(requests) => requests.unrecoverable_errors(),
+            ActionOutput::QueryState(_) | ActionOutput::SwapStrategy(_) => NoneOneOrMany::None,
         }
         .into_option()
     }
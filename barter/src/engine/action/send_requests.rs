@@ -22,20 +22,22 @@ use crate::{
         Engine,
         error::{EngineError, RecoverableEngineError, UnrecoverableEngineError},
         execution_tx::ExecutionTxMap,
+        state::order::in_flight_recorder::InFlightRequestRecorder,
     },
     execution::request::ExecutionRequest,
 };
 use barter_execution::order::{
     OrderEvent,
-    request::{RequestCancel, RequestOpen},
+    request::{OrderRequestOpen, RequestCancel, RequestOpen},
 };
 use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
 use barter_integration::{Unrecoverable, channel::Tx, collection::none_one_or_many::NoneOneOrMany};
 use derive_more::Constructor;
-use itertools::Itertools;
+use fnv::FnvHashSet;
+use itertools::{Either, Itertools};
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
-use tracing::error;
+use tracing::{error, warn};
 
 /// 定义 [`Engine`] 如何发送订单请求的 Trait。
 ///
@@ -256,6 +258,64 @@ where
     }
 }
 
+impl<Clock, State, ExecutionTxs, Strategy, Risk>
+    Engine<Clock, State, ExecutionTxs, Strategy, Risk>
+{
+    /// 发送开仓订单请求，在发送前拒绝 [`ClientOrderId`](barter_execution::order::id::ClientOrderId)
+    /// 冲突的请求。
+    ///
+    /// 如果开仓请求复用了仍处于活跃状态的订单的 `ClientOrderId`（参见
+    /// [`InFlightRequestRecorder::is_active_cid`]），或与同一批次中之前的请求重复，
+    /// 该请求不会被发送，而是以 [`RecoverableEngineError::ClientOrderIdCollision`] 错误
+    /// 记录在输出的 `errors` 字段中，避免交易所拒绝或混淆订单。
+    ///
+    /// # 参数
+    ///
+    /// - `requests`: 要发送的开仓订单请求
+    ///
+    /// # 返回值
+    ///
+    /// 返回 [`SendRequestsOutput`]，包含已发送的请求以及发送失败和被拒绝的请求。
+    pub fn send_open_requests<ExchangeKey, InstrumentKey>(
+        &self,
+        requests: impl IntoIterator<Item = OrderRequestOpen<ExchangeKey, InstrumentKey>>,
+    ) -> SendRequestsOutput<RequestOpen, ExchangeKey, InstrumentKey>
+    where
+        State: InFlightRequestRecorder<ExchangeKey, InstrumentKey>,
+        ExecutionTxs: ExecutionTxMap<ExchangeKey, InstrumentKey>,
+        ExchangeKey: Debug + Clone,
+        InstrumentKey: Debug + Clone,
+        ExecutionRequest<ExchangeKey, InstrumentKey>:
+            From<OrderRequestOpen<ExchangeKey, InstrumentKey>>,
+    {
+        let mut batch_cids = FnvHashSet::default();
+
+        // 拒绝与活跃订单或同批次请求 ClientOrderId 冲突的请求
+        let (requests, collisions): (Vec<_>, Vec<_>) =
+            requests.into_iter().partition_map(|request| {
+                if self.state.is_active_cid(&request.key)
+                    || !batch_cids.insert(request.key.cid.clone())
+                {
+                    warn!(
+                        cid = %request.key.cid,
+                        ?request,
+                        "Engine rejected OrderRequestOpen with colliding ClientOrderId"
+                    );
+                    let error = EngineError::Recoverable(
+                        RecoverableEngineError::ClientOrderIdCollision(request.key.cid.clone()),
+                    );
+                    Either::Right((request, error))
+                } else {
+                    Either::Left(request)
+                }
+            });
+
+        let SendRequestsOutput { sent, errors } = self.send_requests(requests);
+
+        SendRequestsOutput::new(sent, errors.extend(NoneOneOrMany::from(collisions)))
+    }
+}
+
 /// [`Engine`] 发送给 `ExecutionManager` 的取消和开仓订单请求摘要。
 ///
 /// SendCancelsAndOpensOutput 合并了取消请求和开仓请求的发送结果，提供统一的接口
@@ -0,0 +1,291 @@
+//! 审计流二进制编解码模块
+//!
+//! 本模块为通过网络发送到远程 [`StateReplicaManager`](super::state_replica::StateReplicaManager)
+//! 的审计流提供紧凑的二进制线格式。完整的 `EngineState` 快照以 JSON 序列化时体积很大，
+//! 因此审计以 MessagePack 编码，并可选地使用 zstd 压缩。
+//!
+//! 本模块需要启用 `audit-codec` feature。
+//!
+//! # 线格式
+//!
+//! 每个帧由两字节头部和负载组成：
+//!
+//! ```text
+//! | version: u8 | compression: u8 | payload: [u8] |
+//! ```
+//!
+//! - **version**: 线格式版本号，当前为 [`AUDIT_WIRE_VERSION`]
+//! - **compression**: 负载压缩方式，`0` 表示无压缩，`1` 表示 zstd
+//! - **payload**: MessagePack 编码的审计（以字段名编码结构体，与 serde 属性兼容）
+//!
+//! # 使用示例
+//!
+//! ```rust,ignore
+//! // 发送端
+//! let encoder = AuditEncoder::new(AuditCompression::Zstd { level: 3 });
+//! let frame = encoder.encode(&audit_tick)?;
+//!
+//! // 副本端
+//! let audit_tick: AuditTick<_> = decode_audit(&frame)?;
+//! ```
+
+use crate::error::BarterError;
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tracing::error;
+
+/// 当前审计线格式的版本号。
+///
+/// 每当帧头部或负载编码发生不兼容的变化时，应递增此版本号。
+pub const AUDIT_WIRE_VERSION: u8 = 1;
+
+/// 审计帧头部的长度（字节）。
+const AUDIT_FRAME_HEADER_LEN: usize = 2;
+
+/// 审计帧负载的压缩方式。
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum AuditCompression {
+    /// 不压缩负载。
+    #[default]
+    None,
+    /// 使用提供的压缩级别进行 zstd 压缩（`0` 表示 zstd 默认级别）。
+    Zstd { level: i32 },
+}
+
+impl AuditCompression {
+    /// 返回帧头部中表示此压缩方式的标识字节。
+    pub fn flag(&self) -> u8 {
+        match self {
+            AuditCompression::None => 0,
+            AuditCompression::Zstd { .. } => 1,
+        }
+    }
+}
+
+/// 将审计编码为带版本号的二进制帧。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let encoder = AuditEncoder::new(AuditCompression::Zstd { level: 3 });
+/// let frame = encoder.encode(&audit_tick)?;
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct AuditEncoder {
+    /// 负载压缩方式。
+    pub compression: AuditCompression,
+}
+
+impl AuditEncoder {
+    /// 使用提供的 [`AuditCompression`] 构造新的 `AuditEncoder`。
+    pub fn new(compression: AuditCompression) -> Self {
+        Self { compression }
+    }
+
+    /// 将审计编码为带版本号的二进制帧。
+    ///
+    /// # 返回值
+    ///
+    /// - `Ok(Vec<u8>)`: 编码后的帧
+    /// - `Err(BarterError::AuditCodec)`: 序列化或压缩失败
+    pub fn encode<T>(&self, audit: &T) -> Result<Vec<u8>, BarterError>
+    where
+        T: Serialize,
+    {
+        let payload = rmp_serde::to_vec_named(audit)
+            .map_err(|error| BarterError::AuditCodec(error.to_string()))?;
+
+        let payload = match self.compression {
+            AuditCompression::None => payload,
+            AuditCompression::Zstd { level } => zstd::encode_all(payload.as_slice(), level)
+                .map_err(|error| BarterError::AuditCodec(error.to_string()))?,
+        };
+
+        let mut frame = Vec::with_capacity(AUDIT_FRAME_HEADER_LEN + payload.len());
+        frame.push(AUDIT_WIRE_VERSION);
+        frame.push(self.compression.flag());
+        frame.extend_from_slice(&payload);
+
+        Ok(frame)
+    }
+}
+
+/// 解码由 [`AuditEncoder::encode`] 生成的二进制帧。
+///
+/// 压缩方式从帧头部读取，因此副本端无需知道发送端的 [`AuditCompression`] 配置。
+///
+/// # 返回值
+///
+/// - `Ok(T)`: 解码后的审计
+/// - `Err(BarterError::AuditWireVersion)`: 帧的线格式版本号与 [`AUDIT_WIRE_VERSION`] 不匹配
+/// - `Err(BarterError::AuditCodec)`: 帧格式无效、解压缩或反序列化失败
+pub fn decode_audit<T>(frame: &[u8]) -> Result<T, BarterError>
+where
+    T: DeserializeOwned,
+{
+    let [version, compression, payload @ ..] = frame else {
+        return Err(BarterError::AuditCodec(format!(
+            "audit frame shorter than {AUDIT_FRAME_HEADER_LEN} byte header"
+        )));
+    };
+
+    if *version != AUDIT_WIRE_VERSION {
+        return Err(BarterError::AuditWireVersion {
+            expected: AUDIT_WIRE_VERSION,
+            found: *version,
+        });
+    }
+
+    let decompressed;
+    let payload = match *compression {
+        0 => payload,
+        1 => {
+            decompressed = zstd::decode_all(payload)
+                .map_err(|error| BarterError::AuditCodec(error.to_string()))?;
+            decompressed.as_slice()
+        }
+        unknown => {
+            return Err(BarterError::AuditCodec(format!(
+                "unknown audit frame compression: {unknown}"
+            )));
+        }
+    };
+
+    rmp_serde::from_slice(payload).map_err(|error| BarterError::AuditCodec(error.to_string()))
+}
+
+/// 将二进制帧迭代器解码为审计迭代器，可直接作为 `StateReplicaManager` 的更新流。
+///
+/// 遇到第一个无法解码的帧时，记录错误并结束迭代，因为跳过帧会破坏审计序列的连续性。
+pub fn decode_audit_frames<T, Frames>(frames: Frames) -> impl Iterator<Item = T>
+where
+    T: DeserializeOwned,
+    Frames: IntoIterator,
+    Frames::Item: AsRef<[u8]>,
+{
+    frames
+        .into_iter()
+        .map_while(|frame| match decode_audit(frame.as_ref()) {
+            Ok(audit) => Some(audit),
+            Err(error) => {
+                error!(%error, "failed to decode audit frame, ending audit stream");
+                None
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        EngineEvent, Sequence,
+        engine::{
+            EngineOutput,
+            audit::{AuditTick, EngineAudit, context::EngineContext},
+            state::{
+                EngineState, global::DefaultGlobalData,
+                instrument::data::DefaultInstrumentMarketData, trading::TradingState,
+            },
+        },
+        test_utils::spot_engine_state,
+    };
+    use barter_data::{
+        event::{DataKind, MarketEvent},
+        streams::consumer::MarketStreamEvent,
+        subscription::trade::PublicTrade,
+    };
+    use barter_instrument::{Side, exchange::ExchangeId, instrument::InstrumentIndex};
+    use chrono::{DateTime, Utc};
+    use rust_decimal::Decimal;
+
+    type State = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+    type Audit = AuditTick<EngineAudit<EngineEvent, EngineOutput<(), ()>>>;
+
+    fn context(sequence: u64) -> EngineContext {
+        EngineContext::new(Sequence(sequence), DateTime::<Utc>::MIN_UTC)
+    }
+
+    fn snapshot() -> AuditTick<State> {
+        let state = spot_engine_state(&["btc"]);
+
+        AuditTick::new(state, context(0))
+    }
+
+    fn updates() -> Vec<Audit> {
+        vec![
+            AuditTick::new(
+                EngineAudit::process(EngineEvent::TradingStateUpdate(TradingState::Enabled)),
+                context(1),
+            ),
+            AuditTick::new(
+                EngineAudit::process(EngineEvent::Market(MarketStreamEvent::Item(MarketEvent {
+                    time_exchange: DateTime::<Utc>::MIN_UTC,
+                    time_received: DateTime::<Utc>::MIN_UTC,
+                    time_processed: None,
+                    sequence: None,
+                    exchange: ExchangeId::BinanceSpot,
+                    instrument: InstrumentIndex(0),
+                    kind: DataKind::Trade(PublicTrade {
+                        id: "trade".to_string(),
+                        price: Decimal::ONE_HUNDRED,
+                        amount: Decimal::ONE,
+                        side: Side::Buy,
+                    }),
+                }))),
+                context(2),
+            ),
+            AuditTick::new(EngineAudit::FeedEnded, context(3)),
+        ]
+    }
+
+    #[test]
+    fn test_audit_codec_round_trip() {
+        for compression in [AuditCompression::None, AuditCompression::Zstd { level: 0 }] {
+            let encoder = AuditEncoder::new(compression);
+
+            let snapshot = snapshot();
+            let frame = encoder.encode(&snapshot).unwrap();
+            assert_eq!(frame[0], AUDIT_WIRE_VERSION);
+            assert_eq!(frame[1], compression.flag());
+            assert_eq!(decode_audit::<AuditTick<State>>(&frame).unwrap(), snapshot);
+
+            let updates = updates();
+            let frames = updates
+                .iter()
+                .map(|audit| encoder.encode(audit).unwrap())
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                decode_audit_frames::<Audit, _>(&frames).collect::<Vec<_>>(),
+                updates
+            );
+        }
+    }
+
+    #[test]
+    fn test_audit_codec_rejects_invalid_frames() {
+        let mut frame = AuditEncoder::default().encode(&snapshot()).unwrap();
+
+        frame[0] = AUDIT_WIRE_VERSION + 1;
+        assert_eq!(
+            decode_audit::<AuditTick<State>>(&frame).unwrap_err(),
+            BarterError::AuditWireVersion {
+                expected: AUDIT_WIRE_VERSION,
+                found: AUDIT_WIRE_VERSION + 1,
+            }
+        );
+
+        frame[0] = AUDIT_WIRE_VERSION;
+        frame[1] = u8::MAX;
+        assert!(matches!(
+            decode_audit::<AuditTick<State>>(&frame),
+            Err(BarterError::AuditCodec(_))
+        ));
+
+        assert!(matches!(
+            decode_audit::<AuditTick<State>>(&[AUDIT_WIRE_VERSION]),
+            Err(BarterError::AuditCodec(_))
+        ));
+    }
+}
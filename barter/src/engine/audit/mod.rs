@@ -29,6 +29,10 @@ use barter_integration::{FeedEnded, Terminal, collection::none_one_or_many::None
 use derive_more::Constructor;
 use serde::{Deserialize, Serialize};
 
+/// 定义用于将 [`AuditTick`] 发送到远程状态副本的紧凑二进制线格式（需要 `audit-codec` feature）。
+#[cfg(feature = "audit-codec")]
+pub mod codec;
+
 /// 定义表示 `Engine` [`AuditTick`] 生成上下文的数据结构。
 pub mod context;
 
@@ -231,12 +231,35 @@ pub struct HistoricalClock {
 ///
 /// - `time_exchange_last`: 最后一个事件的交易所时间戳
 /// - `time_live_last_event`: 处理最后一个事件时的系统时间（用于计算时间差）
+/// - `mode`: 时间计算模式
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 struct HistoricalClockInner {
     /// 最后一个事件的交易所时间戳
     time_exchange_last: DateTime<Utc>,
     /// 处理最后一个事件时的系统时间
     time_live_last_event: DateTime<Utc>,
+    /// 时间计算模式
+    mode: HistoricalClockMode,
+}
+
+/// [`HistoricalClock`] 计算当前时间的模式。
+///
+/// 两种模式都从市场事件（`time_exchange`）和账户事件（成交、订单、余额等时间戳）中
+/// 单调推进时间，乱序事件永远不会使时钟倒退。
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum HistoricalClockMode {
+    /// 当前时间 = 最新事件时间 + 自处理该事件以来经过的系统时间（默认）。
+    ///
+    /// 适用于按实时速度回放历史数据的场景，事件之间时间平滑推进。
+    #[default]
+    Interpolated,
+
+    /// 当前时间 = 已处理事件中的最大时间戳。
+    ///
+    /// 适用于回测，确保 TearSheet、手续费等均以真实事件时间记录，且结果可复现。
+    EventTime,
 }
 
 impl HistoricalClock {
@@ -260,10 +283,24 @@ impl HistoricalClock {
     /// let clock = HistoricalClock::new(first_event_time);
     /// ```
     pub fn new(last_exchange_time: DateTime<Utc>) -> Self {
+        Self::with_mode(last_exchange_time, HistoricalClockMode::default())
+    }
+
+    /// 使用提供的 `last_exchange_time` 作为种子和指定的 [`HistoricalClockMode`]
+    /// 构造一个新的 `HistoricalClock`。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// // 回测：时钟仅由市场和账户事件的时间戳推进
+    /// let clock = HistoricalClock::with_mode(first_event_time, HistoricalClockMode::EventTime);
+    /// ```
+    pub fn with_mode(last_exchange_time: DateTime<Utc>, mode: HistoricalClockMode) -> Self {
         Self {
             inner: Arc::new(parking_lot::RwLock::new(HistoricalClockInner {
                 time_exchange_last: last_exchange_time,
                 time_live_last_event: Utc::now(),
+                mode,
             })),
         }
     }
@@ -296,13 +333,21 @@ impl EngineClock for HistoricalClock {
     ///
     /// 如果系统时间倒退（不应该发生，但为了健壮性），只返回最后一个事件的交易所时间戳，
     /// 不添加负的时间差。这确保了时间的单调性。
+    ///
+    /// 在 [`HistoricalClockMode::EventTime`] 模式下，直接返回最后一个事件的交易所时间戳。
     fn time(&self) -> DateTime<Utc> {
         // 读取内部状态（使用读锁，允许多个并发读取）
         let lock = self.inner.read();
         let time_live_last_event = lock.time_live_last_event;
         let time_exchange_last = lock.time_exchange_last;
+        let mode = lock.mode;
         drop(lock);
 
+        // EventTime 模式：仅返回已处理事件中的最大时间戳
+        if mode == HistoricalClockMode::EventTime {
+            return time_exchange_last;
+        }
+
         // 计算自处理最后一个事件以来经过的系统时间
         let delta_since_last_event_live_time =
             Utc::now().signed_duration_since(time_live_last_event);
@@ -455,6 +500,8 @@ mod tests {
         EngineEvent::Market(MarketStreamEvent::Item(MarketEvent {
             time_exchange,
             time_received: Default::default(),
+            time_processed: None,
+            sequence: None,
             exchange: ExchangeId::BinanceSpot,
             instrument: InstrumentIndex::new(0),
             kind: (),
@@ -607,4 +654,61 @@ mod tests {
             "Historical clock time delta outside expected range"
         );
     }
+
+    #[test]
+    fn test_historical_clock_event_time_tracks_max_of_market_and_account_events() {
+        use barter_execution::{
+            AccountEvent,
+            order::id::{OrderId, StrategyId},
+            trade::{AssetFees, Trade, TradeId},
+        };
+        use barter_instrument::{Side, exchange::ExchangeIndex};
+        use rust_decimal::Decimal;
+
+        let time_base = DateTime::<Utc>::MIN_UTC;
+        let plus_ms = |ms: i64| time_base + TimeDelta::milliseconds(ms);
+
+        let account_trade = |time_exchange| -> EngineEvent<()> {
+            EngineEvent::Account(AccountStreamEvent::Item(AccountEvent {
+                exchange: ExchangeIndex(0),
+                kind: AccountEventKind::Trade(Trade {
+                    id: TradeId::new("trade"),
+                    order_id: OrderId::new("order"),
+                    instrument: InstrumentIndex(0),
+                    strategy: StrategyId::new("strategy"),
+                    time_exchange,
+                    side: Side::Buy,
+                    price: Decimal::ONE,
+                    quantity: Decimal::ONE,
+                    fees: AssetFees::default(),
+                    reduce_only: false,
+                }),
+            }))
+        };
+
+        // (input event, expected clock time after processing)
+        let cases = vec![
+            (market_event(plus_ms(1000)), plus_ms(1000)),
+            (account_trade(plus_ms(1500)), plus_ms(1500)),
+            // Out-of-order market event does not rewind the clock
+            (market_event(plus_ms(1200)), plus_ms(1500)),
+            (market_event(plus_ms(2000)), plus_ms(2000)),
+            // Out-of-order synthetic fill does not rewind the clock
+            (account_trade(plus_ms(1800)), plus_ms(2000)),
+            (account_trade(plus_ms(2500)), plus_ms(2500)),
+            (market_event(plus_ms(2500)), plus_ms(2500)),
+        ];
+
+        let mut clock = HistoricalClock::with_mode(time_base, HistoricalClockMode::EventTime);
+        assert_eq!(clock.time(), time_base);
+
+        for (index, (event, expected)) in cases.into_iter().enumerate() {
+            clock.process(&event);
+
+            // Wall clock time passing does not affect EventTime mode
+            spin_sleep::sleep(std::time::Duration::from_millis(1));
+
+            assert_eq!(clock.time(), expected, "TC{index} failed");
+        }
+    }
 }
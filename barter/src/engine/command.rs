@@ -23,7 +23,9 @@
 //! 3. Engine 根据命令类型执行相应的操作
 //! 4. 操作结果通过 EngineEvent 返回
 
-use crate::engine::state::instrument::filter::InstrumentFilter;
+use crate::engine::{
+    query::QueryState, state::instrument::filter::InstrumentFilter, swap::SwapStrategy,
+};
 use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
 use barter_instrument::{asset::AssetIndex, exchange::ExchangeIndex, instrument::InstrumentIndex};
 use barter_integration::collection::one_or_many::OneOrMany;
@@ -53,6 +55,8 @@ use serde::{Deserialize, Serialize};
 /// 2. **SendOpenRequests**: 发送开仓订单请求
 /// 3. **ClosePositions**: 平仓（根据过滤器筛选）
 /// 4. **CancelOrders**: 取消订单（根据过滤器筛选）
+/// 5. **QueryState**: 查询当前状态（仓位、活跃订单或余额），通过一次性回复通道回复
+/// 6. **SwapStrategy**: 在运行时替换 Strategy 实例，保留 `EngineState`
 ///
 /// ## 使用场景
 ///
@@ -243,4 +247,30 @@ pub enum Command<
     /// );
     /// ```
     CancelOrders(InstrumentFilter<ExchangeKey, AssetKey, InstrumentKey>),
+
+    /// 查询当前 `EngineState` 的子集（仓位、活跃订单或余额）。
+    ///
+    /// Engine 通过 [`QueryState`] 中的一次性回复通道发送
+    /// [`QueryResponse`](crate::engine::query::QueryResponse)，回复路径与 Engine 事件流和
+    /// 审计流相互独立。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let (query, reply_rx) = QueryState::new(QueryKind::Positions(InstrumentFilter::None));
+    /// let command = Command::QueryState(query);
+    /// ```
+    QueryState(QueryState<ExchangeKey, AssetKey, InstrumentKey>),
+
+    /// 在运行时替换 Engine 的 Strategy 实例（及其参数），保留 `EngineState`。
+    ///
+    /// 替换在命令处理过程中完成，之后的算法订单由新的 Strategy 生成。详见
+    /// [`swap`](crate::engine::swap) 模块文档。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let command = Command::SwapStrategy(SwapStrategy::new(MyStrategy::new(new_parameters)));
+    /// ```
+    SwapStrategy(SwapStrategy),
 }
@@ -18,6 +18,7 @@
 //! - 网络连接问题
 //! - 执行通道暂时不可用
 //! - 临时性的资源问题
+//! - ClientOrderId 与活跃订单冲突（请求在发送前被本地拒绝）
 //!
 //! ## 不可恢复错误
 //!
@@ -32,6 +33,7 @@
 //! - **可恢复错误**: Engine 记录错误并继续运行
 //! - **不可恢复错误**: Engine 触发优雅关闭流程
 
+use barter_execution::order::id::ClientOrderId;
 use barter_instrument::index::error::IndexError;
 use barter_integration::Unrecoverable;
 use serde::{Deserialize, Serialize};
@@ -169,6 +171,9 @@ pub enum RecoverableEngineError {
     /// Engine 会记录错误并等待一段时间后重试。如果持续失败，可能会升级为不可恢复错误。
     #[error("ExecutionRequest channel unhealthy: {0}")]
     ExecutionChannelUnhealthy(String),
+
+    #[error("ClientOrderId collides with an active order: {0}")]
+    ClientOrderIdCollision(ClientOrderId),
 }
 
 /// 表示 [`Engine`](super::Engine) 无法从中恢复的致命错误条件。
@@ -0,0 +1,358 @@
+//! Engine 事件源模块
+//!
+//! 本模块定义了 Engine 事件源的可选优先级队列，用于在行情剧烈波动期间，让控制事件
+//! （例如 `Command`、`Shutdown`、`TradingStateUpdate`）优先于大量的市场事件被处理。
+//!
+//! # 核心概念
+//!
+//! - **FeedPriority**: 定义事件是否为应优先处理的控制事件
+//! - **EngineFeedPriority**: 配置 Engine 事件源是先进先出（默认）还是优先处理控制事件
+//! - **EngineFeed**: 包装 Engine 事件通道的接收器，按照配置的 [`EngineFeedPriority`] 输出事件
+//!
+//! # 工作原理
+//!
+//! 在 `EngineFeedPriority::Control` 模式下，每次循环迭代 [`EngineFeed`] 都会将通道中所有
+//! 可用的事件取出，分别放入控制事件队列和普通事件队列，然后优先输出控制事件。
+//!
+//! 为避免市场事件处理被完全饿死，连续输出 `max_consecutive` 个控制事件后，如果存在
+//! 等待中的普通事件，则会先输出一个普通事件。普通事件之间（例如账户事件和市场事件）
+//! 的相对顺序保持不变。
+
+use crate::EngineEvent;
+use barter_integration::channel::UnboundedRx;
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::VecDeque,
+    num::NonZeroUsize,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::sync::mpsc::error::TryRecvError;
+
+/// 定义事件是否为应优先于普通事件（例如市场事件）处理的控制事件。
+pub trait FeedPriority {
+    /// 如果事件应被优先处理，返回 `true`。
+    fn is_priority(&self) -> bool;
+}
+
+impl<MarketKind, ExchangeKey, AssetKey, InstrumentKey> FeedPriority
+    for EngineEvent<MarketKind, ExchangeKey, AssetKey, InstrumentKey>
+{
+    fn is_priority(&self) -> bool {
+        matches!(
+            self,
+            Self::Shutdown(_) | Self::Command(_) | Self::TradingStateUpdate(_)
+        )
+    }
+}
+
+/// 定义 [`EngineFeed`] 输出事件的顺序。
+///
+/// ## 模式说明
+///
+/// - **Fifo**: 按照事件到达的顺序输出（默认）
+/// - **Control**: 优先输出控制事件（参见 [`FeedPriority`]），连续输出 `max_consecutive`
+///   个控制事件后，如果存在等待中的普通事件，则先输出一个普通事件以避免饿死市场事件处理
+///
+/// 注意：`Control` 模式下 `Shutdown` 也会被优先处理，因此队列中尚未处理的市场事件
+/// 可能会被跳过。回测应使用默认的 `Fifo` 模式。
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum EngineFeedPriority {
+    /// 按照事件到达的顺序输出（默认）。
+    #[default]
+    Fifo,
+
+    /// 优先输出控制事件，最多连续输出 `max_consecutive` 个控制事件。
+    ///
+    /// `max_consecutive` 至少为 1，以保证控制事件始终能被优先输出。
+    Control { max_consecutive: NonZeroUsize },
+}
+
+/// Engine 事件源，包装 Engine 事件通道的 [`UnboundedRx`]，并按照配置的
+/// [`EngineFeedPriority`] 输出事件。
+///
+/// 同时实现了 `Iterator`（用于 `sync_run`）和 `Stream`（用于 `async_run`）。
+///
+/// 只有 [`EngineFeedPriority::Control`] 模式需要区分控制事件，因此 `Event` 仅在通过
+/// [`EngineFeed::new`] 选择事件处理顺序时才需要实现 [`FeedPriority`]。
+#[derive(Debug)]
+pub struct EngineFeed<Event> {
+    rx: UnboundedRx<Event>,
+    priority: EngineFeedPriority,
+    is_priority: fn(&Event) -> bool,
+    queue_priority: VecDeque<Event>,
+    queue_standard: VecDeque<Event>,
+    consecutive_priority: usize,
+    disconnected: bool,
+}
+
+impl<Event> EngineFeed<Event> {
+    /// 使用提供的 [`UnboundedRx`] 和 [`EngineFeedPriority`] 构造新的 `EngineFeed`。
+    pub fn new(rx: UnboundedRx<Event>, priority: EngineFeedPriority) -> Self
+    where
+        Event: FeedPriority,
+    {
+        Self::with_classifier(rx, priority, Event::is_priority)
+    }
+
+    /// 使用提供的 [`UnboundedRx`] 构造按照事件到达顺序输出事件的 `EngineFeed`。
+    ///
+    /// 不要求 `Event` 实现 [`FeedPriority`]。
+    pub fn fifo(rx: UnboundedRx<Event>) -> Self {
+        Self::with_classifier(rx, EngineFeedPriority::Fifo, |_| false)
+    }
+
+    /// 使用提供的控制事件分类函数构造新的 `EngineFeed`。
+    pub(crate) fn with_classifier(
+        rx: UnboundedRx<Event>,
+        priority: EngineFeedPriority,
+        is_priority: fn(&Event) -> bool,
+    ) -> Self {
+        Self {
+            rx,
+            priority,
+            is_priority,
+            queue_priority: VecDeque::new(),
+            queue_standard: VecDeque::new(),
+            consecutive_priority: 0,
+            disconnected: false,
+        }
+    }
+
+    /// 将事件放入对应的控制事件队列或普通事件队列。
+    fn enqueue(&mut self, event: Event) {
+        if (self.is_priority)(&event) {
+            self.queue_priority.push_back(event);
+        } else {
+            self.queue_standard.push_back(event);
+        }
+    }
+
+    /// 以非阻塞方式取出通道中所有可用的事件，并放入对应的队列。
+    ///
+    /// 注意：使用 `try_recv` 而非 `poll_recv`，以避免 tokio 协作式调度预算中断取出过程。
+    fn drain(&mut self) {
+        while !self.disconnected {
+            match self.rx.rx.try_recv() {
+                Ok(event) => self.enqueue(event),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => self.disconnected = true,
+            }
+        }
+    }
+
+    /// 取出下一个事件，优先取出控制事件，直到达到 `max_consecutive` 限制。
+    fn dequeue(&mut self, max_consecutive: NonZeroUsize) -> Option<Event> {
+        let take_priority = !self.queue_priority.is_empty()
+            && (self.consecutive_priority < max_consecutive.get()
+                || self.queue_standard.is_empty());
+
+        if take_priority {
+            self.consecutive_priority += 1;
+            self.queue_priority.pop_front()
+        } else {
+            self.consecutive_priority = 0;
+            self.queue_standard.pop_front()
+        }
+    }
+}
+
+impl<Event> Iterator for EngineFeed<Event> {
+    type Item = Event;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let EngineFeedPriority::Control { max_consecutive } = self.priority else {
+            return self.rx.next();
+        };
+
+        self.drain();
+
+        if self.queue_priority.is_empty() && self.queue_standard.is_empty() {
+            if self.disconnected {
+                return None;
+            }
+
+            // 队列为空，像 Fifo 模式一样等待下一个事件，然后取出其后所有可用的事件
+            match self.rx.next() {
+                Some(event) => self.enqueue(event),
+                None => self.disconnected = true,
+            }
+            self.drain();
+        }
+
+        self.dequeue(max_consecutive)
+    }
+}
+
+// EngineFeed 从不固定（pin）其缓冲的事件，因此无论 Event 是否实现 Unpin，它都可以安全地实现 Unpin
+impl<Event> Unpin for EngineFeed<Event> {}
+
+impl<Event> Stream for EngineFeed<Event> {
+    type Item = Event;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        let EngineFeedPriority::Control { max_consecutive } = this.priority else {
+            return Pin::new(&mut this.rx).poll_next(cx);
+        };
+
+        loop {
+            this.drain();
+
+            if let Some(event) = this.dequeue(max_consecutive) {
+                break Poll::Ready(Some(event));
+            }
+
+            if this.disconnected {
+                break Poll::Ready(None);
+            }
+
+            // 通道为空，注册 Waker 并等待下一个事件
+            match this.rx.rx.poll_recv(cx) {
+                Poll::Ready(Some(event)) => this.enqueue(event),
+                Poll::Ready(None) => this.disconnected = true,
+                Poll::Pending => break Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::command::Command;
+    use barter_data::{event::DataKind, streams::consumer::MarketStreamEvent};
+    use barter_instrument::exchange::ExchangeId;
+    use barter_integration::{
+        channel::{Tx, UnboundedTx, mpsc_unbounded},
+        collection::one_or_many::OneOrMany,
+    };
+
+    type Event = EngineEvent<DataKind>;
+
+    fn market() -> Event {
+        EngineEvent::Market(MarketStreamEvent::Reconnecting(ExchangeId::BinanceSpot))
+    }
+
+    fn command() -> Event {
+        EngineEvent::Command(Command::SendCancelRequests(OneOrMany::Many(vec![])))
+    }
+
+    fn command_behind_market_events(num_market: usize) -> (UnboundedTx<Event>, UnboundedRx<Event>) {
+        let (tx, rx) = mpsc_unbounded();
+        for _ in 0..num_market {
+            tx.send(market()).unwrap();
+        }
+        tx.send(command()).unwrap();
+        (tx, rx)
+    }
+
+    #[test]
+    fn test_engine_feed_iterator_prioritises_command_behind_market_events() {
+        // Fifo: Command is processed behind all queued market events
+        let (tx, rx) = command_behind_market_events(10_000);
+        drop(tx);
+        let mut feed = EngineFeed::new(rx, EngineFeedPriority::Fifo);
+        assert_eq!(feed.position(|event| event.is_priority()), Some(10_000));
+
+        // Control: Command is processed promptly, ahead of queued market events
+        let (tx, rx) = command_behind_market_events(10_000);
+        drop(tx);
+        let mut feed = EngineFeed::new(
+            rx,
+            EngineFeedPriority::Control {
+                max_consecutive: NonZeroUsize::new(8).unwrap(),
+            },
+        );
+        assert_eq!(feed.next(), Some(command()));
+        assert_eq!(feed.count(), 10_000);
+    }
+
+    #[tokio::test]
+    async fn test_engine_feed_stream_prioritises_command_behind_market_events() {
+        use futures::StreamExt;
+
+        let (tx, rx) = command_behind_market_events(10_000);
+        let mut feed = EngineFeed::new(
+            rx,
+            EngineFeedPriority::Control {
+                max_consecutive: NonZeroUsize::new(8).unwrap(),
+            },
+        );
+        assert_eq!(StreamExt::next(&mut feed).await, Some(command()));
+
+        drop(tx);
+        assert_eq!(StreamExt::count(feed).await, 10_000);
+    }
+
+    #[test]
+    fn test_engine_feed_fifo_does_not_require_feed_priority() {
+        // Custom Engine event types that do not implement FeedPriority can still use Fifo
+        #[derive(Debug, Clone, PartialEq)]
+        struct CustomEvent(u64);
+
+        let (tx, rx) = mpsc_unbounded::<CustomEvent>();
+        tx.send(CustomEvent(1)).unwrap();
+        tx.send(CustomEvent(2)).unwrap();
+        drop(tx);
+
+        let feed = EngineFeed::fifo(rx);
+        assert_eq!(
+            feed.collect::<Vec<_>>(),
+            vec![CustomEvent(1), CustomEvent(2)]
+        );
+    }
+
+    #[test]
+    fn test_engine_feed_control_does_not_starve_standard_events() {
+        let (tx, rx) = mpsc_unbounded::<Event>();
+        tx.send(market()).unwrap();
+        tx.send(market()).unwrap();
+        for _ in 0..5 {
+            tx.send(command()).unwrap();
+        }
+        drop(tx);
+
+        let feed = EngineFeed::new(
+            rx,
+            EngineFeedPriority::Control {
+                max_consecutive: NonZeroUsize::new(2).unwrap(),
+            },
+        );
+        let actual = feed.map(|event| event.is_priority()).collect::<Vec<_>>();
+
+        assert_eq!(actual, vec![true, true, false, true, true, false, true]);
+    }
+
+    #[test]
+    fn test_engine_feed_iterator_control_waits_for_next_event() {
+        let (tx, rx) = mpsc_unbounded::<Event>();
+        let mut feed = EngineFeed::new(
+            rx,
+            EngineFeedPriority::Control {
+                max_consecutive: NonZeroUsize::new(2).unwrap(),
+            },
+        );
+
+        let sender = std::thread::spawn({
+            let tx = tx.clone();
+            move || {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+                tx.send(command()).unwrap();
+            }
+        });
+
+        // Feed waits on the empty channel until the Command arrives
+        assert_eq!(feed.next(), Some(command()));
+        sender.join().unwrap();
+
+        tx.send(market()).unwrap();
+        drop(tx);
+        assert_eq!(feed.next(), Some(market()));
+        assert_eq!(feed.next(), None);
+    }
+}
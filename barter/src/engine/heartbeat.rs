@@ -0,0 +1,101 @@
+//! Engine 执行心跳模块
+//!
+//! 本模块定义了 [`ExecutionHeartbeat`]，用于在 Engine 运行期间定期向所有 ExecutionManager 发送
+//! [`ExecutionRequest::Heartbeat`]，证明 Engine 仍在运行。
+//!
+//! # 工作原理
+//!
+//! 通过 `SystemBuilder::execution_heartbeat` 启用后，`System` 在 Engine 旁边启动一个使用
+//! `tokio::time::interval` 的墙钟定时任务，每隔 `interval` 发送一次心跳。心跳与 Engine 是否在
+//! 处理事件无关，因此行情清淡或 `Control` 事件源空闲时，健康的 Engine 也会持续发送心跳。
+//!
+//! Engine 任务结束（包括 panic）后心跳随之停止。启用死人开关的 ExecutionManager 只在 `timeout`
+//! 内收到过 Engine 请求（包括心跳）时才向交易所发送 cancel-all-after 心跳（参见
+//! [`DeadMansSwitchConfig`](crate::execution::manager::DeadMansSwitchConfig)），因此 Engine 停止
+//! 运行后挂单会被交易所自动取消。`interval` 应小于死人开关的 `timeout`。
+
+use crate::execution::request::ExecutionRequest;
+use barter_integration::channel::{Tx, UnboundedTx};
+use derive_more::Constructor;
+use std::time::Duration;
+use tokio::task::AbortHandle;
+use tracing::warn;
+
+/// 按墙钟定期向所有 ExecutionManager 发送 [`ExecutionRequest::Heartbeat`] 的定时任务。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// // 每 10 秒向所有 ExecutionManager 发送一次心跳，直到 Engine 任务结束
+/// let heartbeat = ExecutionHeartbeat::new(Duration::from_secs(10), execution_txs);
+/// tokio::spawn(heartbeat.run(engine_handle.abort_handle()));
+/// ```
+#[derive(Debug, Clone, Constructor)]
+pub struct ExecutionHeartbeat {
+    /// 发送心跳的间隔
+    pub interval: Duration,
+    /// 发送心跳的 ExecutionManager 请求通道
+    pub execution_txs: Vec<UnboundedTx<ExecutionRequest>>,
+}
+
+impl ExecutionHeartbeat {
+    /// 每隔 `interval` 向所有 ExecutionManager 发送心跳（第一次心跳立即发送），直到 `engine`
+    /// 对应的 Engine 任务结束（包括 panic 或被中止）。
+    pub async fn run(self, engine: AbortHandle) {
+        let mut interval = tokio::time::interval(self.interval);
+
+        loop {
+            interval.tick().await;
+
+            if engine.is_finished() {
+                break;
+            }
+
+            self.execution_txs.iter().for_each(|execution_tx| {
+                if execution_tx.send(ExecutionRequest::Heartbeat).is_err() {
+                    warn!("failed to send ExecutionRequest::Heartbeat - receiver dropped");
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::channel::mpsc_unbounded;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_execution_heartbeat_sent_without_engine_events() {
+        let (execution_tx, mut execution_rx) = mpsc_unbounded();
+        let engine = tokio::spawn(std::future::pending::<()>());
+
+        // Engine processes no events, yet heartbeats are sent on the wall-clock interval
+        let heartbeat = tokio::spawn(
+            ExecutionHeartbeat::new(Duration::from_secs(10), vec![execution_tx])
+                .run(engine.abort_handle()),
+        );
+
+        // Sample between ticks at 5s, 15s, 25s & 35s
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        let mut heartbeats = Vec::new();
+        for _ in 0..4 {
+            heartbeats
+                .push(std::iter::from_fn(|| execution_rx.rx.try_recv().ok()).collect::<Vec<_>>());
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        }
+
+        // One heartbeat at each of 0s, 10s, 20s & 30s
+        assert_eq!(heartbeats, vec![vec![ExecutionRequest::Heartbeat]; 4]);
+
+        // Heartbeats stop once the Engine task ends, dropping the ExecutionRequest channel
+        engine.abort();
+        heartbeat.await.unwrap();
+        let remaining = std::iter::from_fn(|| execution_rx.rx.try_recv().ok()).count();
+        assert_eq!(remaining, 1);
+        assert_eq!(
+            execution_rx.rx.try_recv(),
+            Err(tokio::sync::mpsc::error::TryRecvError::Disconnected)
+        );
+    }
+}
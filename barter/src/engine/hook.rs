@@ -0,0 +1,181 @@
+//! Engine 处理钩子模块
+//!
+//! 本模块定义了 [`EngineHook`]，用于在每次 Engine 处理事件前后调用自定义回调，以便添加
+//! tracing span、外部指标或调试等自定义埋点，而无需手动包装 `Processor`。
+//!
+//! # 工作原理
+//!
+//! 1. **pre_process**: 通过 `Engine::with_pre_process_hook` 设置，在 Engine 处理每个事件之前
+//!    以该事件的引用调用
+//! 2. **post_process**: 通过 `Engine::with_post_process_hook` 设置，在 Engine 处理每个事件之后
+//!    以生成的审计（包含被处理的事件）的引用调用
+//!
+//! 钩子只接收不可变引用，因此不会改变事件处理语义。`Processor::process` 和批处理
+//! （`Engine::process_batch_with_cadence`）都会为每个事件调用钩子。
+//!
+//! # 钩子类型
+//!
+//! 钩子的参数类型由 [`ProcessHookInput`] 在编译期确定：
+//! - pre_process 钩子的参数类型由 `EngineState` 决定，因此替换 Strategy 后保持不变
+//! - post_process 钩子的参数类型（审计）由 Engine 的 Strategy 输出类型决定，因此
+//!   `Engine::map_strategy` 会移除 post_process 钩子，需要时为新的 Engine 重新设置
+//!
+//! # 使用示例
+//!
+//! ```rust,ignore
+//! let engine = Engine::new(clock, state, execution_txs, strategy, risk)
+//!     .with_pre_process_hook(|event| debug!(?event, "Engine processing event"))
+//!     .with_post_process_hook(|audit| debug!(?audit, "Engine processed event"));
+//! ```
+
+use crate::{
+    EngineEvent,
+    engine::{
+        Engine, EngineOutput,
+        audit::EngineAudit,
+        state::{EngineState, instrument::data::InstrumentDataState},
+    },
+    strategy::{on_disconnect::OnDisconnectStrategy, on_trading_disabled::OnTradingDisabled},
+};
+use std::{
+    cmp::Ordering,
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+/// 定义以 `Self` 为来源的 [`EngineHook`] 接收的参数类型。
+///
+/// - `EngineState` 的参数类型为 Engine 处理的 [`EngineEvent`]（pre_process 钩子）
+/// - `Engine` 的参数类型为 Engine 生成的 [`EngineAudit`]（post_process 钩子）
+pub trait ProcessHookInput {
+    type Input;
+}
+
+impl<GlobalData, InstrumentData> ProcessHookInput for EngineState<GlobalData, InstrumentData>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type Input = EngineEvent<InstrumentData::MarketEventKind>;
+}
+
+impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk> ProcessHookInput
+    for Engine<Clock, EngineState<GlobalData, InstrumentData>, ExecutionTxs, Strategy, Risk>
+where
+    InstrumentData: InstrumentDataState,
+    Strategy: OnTradingDisabled<Clock, EngineState<GlobalData, InstrumentData>, ExecutionTxs, Risk>
+        + OnDisconnectStrategy<Clock, EngineState<GlobalData, InstrumentData>, ExecutionTxs, Risk>,
+{
+    type Input = EngineAudit<
+        EngineEvent<InstrumentData::MarketEventKind>,
+        EngineOutput<Strategy::OnTradingDisabled, Strategy::OnDisconnect>,
+    >;
+}
+
+/// 以 `Source` 的 [`ProcessHookInput`] 为参数的钩子回调。
+///
+/// 所有 `Fn(&Source::Input)` 闭包都实现了此 trait。
+pub trait ProcessHook<Source>: Send + Sync {
+    /// 以提供的 `input` 调用钩子。
+    fn call(&self, input: &<Source as ProcessHookInput>::Input)
+    where
+        Source: ProcessHookInput;
+}
+
+impl<Source, F> ProcessHook<Source> for F
+where
+    Source: ProcessHookInput,
+    F: Fn(&Source::Input) + Send + Sync,
+{
+    fn call(&self, input: &<Source as ProcessHookInput>::Input) {
+        self(input)
+    }
+}
+
+/// 以 `Source` 的 [`ProcessHookInput`] 为参数的 Engine 处理钩子。
+///
+/// 钩子可以被克隆（例如克隆 Engine 时），所有克隆共享同一个回调。
+///
+/// 钩子不参与 Engine 的比较和哈希：所有 `EngineHook` 彼此相等。
+pub struct EngineHook<Source>(Arc<dyn ProcessHook<Source>>);
+
+impl<Source> EngineHook<Source> {
+    /// 使用提供的 [`ProcessHook`] 构造 `EngineHook`。
+    pub fn new<Hook>(hook: Hook) -> Self
+    where
+        Hook: ProcessHook<Source> + 'static,
+    {
+        Self(Arc::new(hook))
+    }
+
+    /// 以提供的 `input` 调用钩子。
+    pub fn call(&self, input: &Source::Input)
+    where
+        Source: ProcessHookInput,
+    {
+        self.0.call(input)
+    }
+}
+
+impl<Source> Clone for EngineHook<Source> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<Source> PartialEq for EngineHook<Source> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<Source> Eq for EngineHook<Source> {}
+
+impl<Source> PartialOrd for EngineHook<Source> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Source> Ord for EngineHook<Source> {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<Source> Hash for EngineHook<Source> {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl<Source> Debug for EngineHook<Source> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EngineHook").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    struct Source;
+
+    impl ProcessHookInput for Source {
+        type Input = u64;
+    }
+
+    #[test]
+    fn test_engine_hook_clones_share_callback() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let hook = EngineHook::<Source>::new({
+            let calls = Arc::clone(&calls);
+            move |value: &u64| calls.lock().unwrap().push(*value)
+        });
+
+        hook.call(&1);
+        hook.clone().call(&2);
+
+        assert_eq!(*calls.lock().unwrap(), vec![1, 2]);
+    }
+}
@@ -42,20 +42,33 @@ use crate::{
             cancel_orders::CancelOrders,
             close_positions::ClosePositions,
             generate_algo_orders::{GenerateAlgoOrders, GenerateAlgoOrdersOutput},
-            send_requests::SendRequests,
+            send_requests::{SendCancelsAndOpensOutput, SendRequests},
         },
         audit::{AuditTick, Auditor, EngineAudit, ProcessAudit, context::EngineContext},
         clock::EngineClock,
         command::Command,
         execution_tx::ExecutionTxMap,
+        hook::EngineHook,
+        query::QueryStateOutput,
+        rejection::{OrderRejection, OrderRejectionTx},
         state::{
-            EngineState, instrument::data::InstrumentDataState,
-            order::in_flight_recorder::InFlightRequestRecorder, position::PositionExited,
+            EngineState,
+            instrument::{
+                data::InstrumentDataState, expiry::InstrumentExpired, filter::InstrumentFilter,
+            },
+            order::{
+                in_flight_recorder::InFlightRequestRecorder, in_flight_timeout::InFlightTimeout,
+            },
+            position::PositionExited,
             trading::TradingState,
         },
+        swap::{StrategySwap, SwapStrategyOutput},
     },
     execution::{AccountStreamEvent, request::ExecutionRequest},
-    risk::RiskManager,
+    risk::{
+        RiskManager,
+        circuit_breaker::{DrawdownBreached, DrawdownCircuitBreaker, session_pnl},
+    },
     shutdown::SyncShutdown,
     statistic::summary::TradingSummaryGenerator,
     strategy::{
@@ -66,12 +79,20 @@ use crate::{
 use barter_data::{event::MarketEvent, streams::consumer::MarketStreamEvent};
 use barter_execution::AccountEvent;
 use barter_instrument::{asset::QuoteAsset, exchange::ExchangeIndex, instrument::InstrumentIndex};
-use barter_integration::channel::Tx;
-use chrono::{DateTime, Utc};
+use barter_integration::{
+    Terminal,
+    channel::{Tx, UnboundedTx},
+};
+use chrono::{DateTime, TimeDelta, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
-use tracing::info;
+use std::{
+    fmt::Debug,
+    num::NonZeroUsize,
+    ops::ControlFlow,
+    panic::{self, AssertUnwindSafe},
+};
+use tracing::{info, warn};
 
 /// 定义 Engine 如何处理 Command（命令）以及相关的输出。
 pub mod action;
@@ -92,6 +113,26 @@ pub mod command;
 /// 定义 Engine 中可能发生的所有错误。
 pub mod error;
 
+/// 定义 [`Command::QueryState`] 使用的状态查询请求和回复，外部进程可以通过一次性回复通道
+/// 同步读取当前仓位、活跃订单或余额。
+pub mod query;
+
+/// 定义 [`Command::SwapStrategy`] 使用的 Strategy 热替换请求，外部进程可以在运行时替换
+/// Strategy 实例而不丢失 `EngineState`。
+pub mod swap;
+
+/// 定义订单拒绝通道使用的 [`OrderRejection`](rejection::OrderRejection)，汇总风险拒绝和交易所
+/// 拒绝，以便外部进程独立于审计流订阅。
+pub mod rejection;
+
+/// 定义 [`EngineHook`](hook::EngineHook)，在每次 `Engine::process` 前后调用自定义回调，用于
+/// tracing、外部指标或调试等自定义埋点。
+pub mod hook;
+
+/// 定义 [`ExecutionHeartbeat`](heartbeat::ExecutionHeartbeat)，System 在 Engine 运行期间按墙钟
+/// 定期向 ExecutionManager 发送心跳，以便死人开关在 Engine 停止运行时停止向交易所发送心跳。
+pub mod heartbeat;
+
 /// 定义 [`ExecutionTxMap`] 接口，该接口建模用于将 ExecutionRequest 路由到相应 ExecutionManager 的发送器集合。
 pub mod execution_tx;
 
@@ -100,6 +141,10 @@ pub mod execution_tx;
 /// 例如：`ConnectivityStates`、`AssetStates`、`InstrumentStates`、`Position` 等。
 pub mod state;
 
+/// 定义 Engine 事件源 [`EngineFeed`](feed::EngineFeed)，可配置为优先处理控制事件
+/// （例如 `Command`、`Shutdown`）而非市场事件。
+pub mod feed;
+
 /// Engine 运行器，用于处理输入事件。
 ///
 /// 例如：`fn sync_run`、`fn sync_run_with_audit`、`fn async_run`、`fn async_run_with_audit`
@@ -192,7 +237,7 @@ where
 ///
 /// - **事件处理**: 处理输入的 [`EngineEvent`]（或自定义事件，如果已实现）
 /// - **状态管理**: 维护内部的 [`EngineState`]（工具数据状态、未完成订单、持仓等）
-/// - **订单生成**: 如果 `TradingState::Enabled`，根据 Strategy 生成算法订单
+/// - **订单生成**: 如果 `TradingState::Enabled`（或 `TradingState::PostOnly`，仅被动订单），根据 Strategy 生成算法订单
 /// - **风险检查**: 通过 RiskManager 检查生成的订单
 /// - **订单执行**: 将订单发送到 ExecutionManager 执行
 ///
@@ -239,7 +284,7 @@ where
 /// // 处理事件
 /// let audit = engine.process(market_event);
 /// ```
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct Engine<Clock, State, ExecutionTxs, Strategy, Risk> {
     /// 时间接口，用于获取当前时间
     pub clock: Clock,
@@ -253,6 +298,24 @@ pub struct Engine<Clock, State, ExecutionTxs, Strategy, Risk> {
     pub strategy: Strategy,
     /// 风险管理器
     pub risk: Risk,
+    /// 是否为处理的 [`MarketEvent`] 标记 `time_processed`，用于延迟分析（默认：`false`）
+    pub stamp_time_processed: bool,
+    /// 在途请求超时时间，超时未确认的在途请求会生成 [`EngineOutput::InFlightTimeout`]（默认：禁用）
+    pub in_flight_timeout: Option<TimeDelta>,
+    /// 是否检测交易对过期，过期的交易对会生成 [`EngineOutput::InstrumentExpired`] 并停止算法开仓（默认：`false`）
+    pub expiry_handling: bool,
+    /// 仓位最大持有时间，超时的仓位会被自动平仓并生成 [`EngineOutput::PositionAgeExceeded`]（默认：禁用）
+    pub max_position_age: Option<TimeDelta>,
+    /// 回撤熔断器，会话权益回撤超过上限时禁用交易并生成 [`EngineOutput::DrawdownBreached`]（默认：禁用）
+    pub drawdown_circuit_breaker: Option<DrawdownCircuitBreaker>,
+    /// 订单拒绝通道，每个风险拒绝和交易所拒绝都会作为 [`OrderRejection`] 发送到该通道（默认：禁用）
+    pub order_rejection_tx: Option<OrderRejectionTx>,
+    /// Strategy 热替换，从 [`Command::SwapStrategy`] 中取出新的 Strategy 实例（默认：禁用）
+    pub strategy_swap: Option<StrategySwap<Strategy>>,
+    /// 每次处理事件之前以事件引用调用的钩子（默认：禁用）
+    pub pre_process_hook: Option<EngineHook<State>>,
+    /// 每次处理事件之后以审计引用调用的钩子（默认：禁用）
+    pub post_process_hook: Option<EngineHook<Self>>,
 }
 
 /// 运行中的 [`Engine`] 元数据。
@@ -300,20 +363,230 @@ where
     >;
 
     fn process(&mut self, event: EngineEvent<InstrumentData::MarketEventKind>) -> Self::Audit {
+        self.call_pre_process_hook(&event);
+
+        let audit = match self.update_from_event(event) {
+            ControlFlow::Continue(process_audit) => {
+                // 如果交易状态为 Enabled 或 PostOnly，生成算法订单（PostOnly 下仅发送被动开仓请求）
+                self.add_algo_orders_to_audit(process_audit)
+            }
+            ControlFlow::Break(audit) => audit,
+        };
+
+        self.call_post_process_hook(&audit);
+
+        audit
+    }
+}
+
+impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
+    Engine<Clock, EngineState<GlobalData, InstrumentData>, ExecutionTxs, Strategy, Risk>
+where
+    Clock: EngineClock + for<'a> Processor<&'a EngineEvent<InstrumentData::MarketEventKind>>,
+    InstrumentData: InstrumentDataState,
+    GlobalData: for<'a> Processor<&'a AccountEvent>
+        + for<'a> Processor<&'a MarketEvent<InstrumentIndex, InstrumentData::MarketEventKind>>,
+    ExecutionTxs: ExecutionTxMap<ExchangeIndex, InstrumentIndex>,
+    Strategy: OnTradingDisabled<Clock, EngineState<GlobalData, InstrumentData>, ExecutionTxs, Risk>
+        + OnDisconnectStrategy<Clock, EngineState<GlobalData, InstrumentData>, ExecutionTxs, Risk>
+        + AlgoStrategy<State = EngineState<GlobalData, InstrumentData>>
+        + ClosePositionsStrategy<State = EngineState<GlobalData, InstrumentData>>,
+    Risk: RiskManager<State = EngineState<GlobalData, InstrumentData>>,
+{
+    /// 设置每次处理事件之前调用的钩子，以即将处理的事件的引用调用。
+    ///
+    /// 钩子不会改变事件处理语义，详见 [`hook`] 模块。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let engine = Engine::new(clock, state, execution_txs, strategy, risk)
+    ///     .with_pre_process_hook(|event| debug!(?event, "Engine processing event"));
+    /// ```
+    pub fn with_pre_process_hook<F>(self, hook: F) -> Self
+    where
+        F: Fn(&EngineEvent<InstrumentData::MarketEventKind>) + Send + Sync + 'static,
+    {
+        Self {
+            pre_process_hook: Some(EngineHook::new(hook)),
+            ..self
+        }
+    }
+
+    /// 设置每次处理事件之后调用的钩子，以生成的 [`EngineAudit`]（包含被处理的事件）的引用调用。
+    ///
+    /// 钩子不会改变事件处理语义，详见 [`hook`] 模块。由于审计类型依赖 Strategy，
+    /// [`Engine::map_strategy`] 会移除此钩子。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let engine = Engine::new(clock, state, execution_txs, strategy, risk)
+    ///     .with_post_process_hook(|audit| debug!(?audit, "Engine processed event"));
+    /// ```
+    pub fn with_post_process_hook<F>(self, hook: F) -> Self
+    where
+        F: Fn(
+                &EngineAudit<
+                    EngineEvent<InstrumentData::MarketEventKind>,
+                    EngineOutput<Strategy::OnTradingDisabled, Strategy::OnDisconnect>,
+                >,
+            ) + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            post_process_hook: Some(EngineHook::new(hook)),
+            ..self
+        }
+    }
+
+    /// 以批处理语义处理一批事件：先应用所有状态更新，最后仅生成一次算法订单。
+    ///
+    /// 等价于 `process_batch_with_cadence(events, usize::MAX)`，详见
+    /// [`Self::process_batch_with_cadence`]。
+    ///
+    /// # 参数
+    ///
+    /// - `events`: 要处理的事件迭代器
+    ///
+    /// # 返回值
+    ///
+    /// 返回每个已处理事件对应的审计信息，算法订单输出附加在批次最后一个事件的审计上。
+    pub fn process_batch<Events>(
+        &mut self,
+        events: Events,
+    ) -> Vec<<Self as Processor<EngineEvent<InstrumentData::MarketEventKind>>>::Audit>
+    where
+        Events: IntoIterator<Item = EngineEvent<InstrumentData::MarketEventKind>>,
+    {
+        self.process_batch_with_cadence(events, NonZeroUsize::MAX)
+    }
+
+    /// 以批处理语义处理一批事件，每处理 `cadence` 个事件（以及批次结束时）生成一次算法订单。
+    ///
+    /// 与逐个事件调用 [`Processor::process`] 相比，此方法跳过了中间事件的算法订单生成，
+    /// 以牺牲逐 tick 的响应性换取吞吐量（例如：回测中处理数百万条事件）。
+    ///
+    /// # 批处理语义
+    ///
+    /// - 每个事件仍会立即更新 Clock 和 [`EngineState`]，命令和交易状态更新也会立即执行
+    /// - Strategy 仅在每 `cadence` 个事件后以及批次结束时看到 [`EngineState`]，
+    ///   因此中间状态不会触发算法订单
+    /// - 如果产生终止审计（例如 `Shutdown` 或不可恢复错误），处理立即停止，剩余事件不会被消费
+    ///
+    /// 对于只依赖最终状态的 Strategy（例如：已有持仓或在途订单时不再开仓），
+    /// 批处理与逐个事件处理收敛到相同的最终 [`EngineState`]。
+    ///
+    /// # 参数
+    ///
+    /// - `events`: 要处理的事件迭代器
+    /// - `cadence`: 两次算法订单生成之间处理的事件数量
+    ///
+    /// # 返回值
+    ///
+    /// 返回每个已处理事件对应的审计信息，算法订单输出附加在触发生成的事件的审计上。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let audits = engine.process_batch_with_cadence(events, NonZeroUsize::new(1_000).unwrap());
+    /// ```
+    pub fn process_batch_with_cadence<Events>(
+        &mut self,
+        events: Events,
+        cadence: NonZeroUsize,
+    ) -> Vec<<Self as Processor<EngineEvent<InstrumentData::MarketEventKind>>>::Audit>
+    where
+        Events: IntoIterator<Item = EngineEvent<InstrumentData::MarketEventKind>>,
+    {
+        let mut events = events.into_iter().enumerate().peekable();
+        let mut audits = Vec::with_capacity(events.size_hint().0);
+
+        while let Some((index, event)) = events.next() {
+            self.call_pre_process_hook(&event);
+
+            let audit = match self.update_from_event(event) {
+                ControlFlow::Continue(process_audit) => {
+                    let generate = (index + 1) % cadence.get() == 0 || events.peek().is_none();
+
+                    if generate {
+                        self.add_algo_orders_to_audit(process_audit)
+                    } else {
+                        EngineAudit::from(process_audit)
+                    }
+                }
+                ControlFlow::Break(audit) => audit,
+            };
+
+            self.call_post_process_hook(&audit);
+
+            let terminal = audit.is_terminal();
+            audits.push(audit);
+            if terminal {
+                break;
+            }
+        }
+
+        audits
+    }
+
+    /// 如果设置了 pre_process 钩子，以即将处理的事件的引用调用。
+    fn call_pre_process_hook(&self, event: &EngineEvent<InstrumentData::MarketEventKind>) {
+        if let Some(hook) = &self.pre_process_hook {
+            hook.call(event);
+        }
+    }
+
+    /// 如果设置了 post_process 钩子，以生成的审计的引用调用。
+    fn call_post_process_hook(
+        &self,
+        audit: &<Self as Processor<EngineEvent<InstrumentData::MarketEventKind>>>::Audit,
+    ) {
+        if let Some(hook) = &self.post_process_hook {
+            hook.call(audit);
+        }
+    }
+
+    /// 根据事件更新 Clock 和 [`EngineState`]，不生成算法订单。
+    ///
+    /// 如果事件处理需要立即返回（例如 `Shutdown` 或命令产生不可恢复错误），返回
+    /// `ControlFlow::Break` 包含最终审计。
+    fn update_from_event(
+        &mut self,
+        mut event: EngineEvent<InstrumentData::MarketEventKind>,
+    ) -> ControlFlow<
+        <Self as Processor<EngineEvent<InstrumentData::MarketEventKind>>>::Audit,
+        ProcessAudit<
+            EngineEvent<InstrumentData::MarketEventKind>,
+            EngineOutput<Strategy::OnTradingDisabled, Strategy::OnDisconnect>,
+        >,
+    > {
         // 更新时钟时间（某些事件可能影响时间，如回测中的历史事件）
         self.clock.process(&event);
 
+        // 如果启用，为市场事件标记处理时间，用于延迟分析
+        if self.stamp_time_processed
+            && let EngineEvent::Market(MarketStreamEvent::Item(market)) = &mut event
+        {
+            market.time_processed = Some(self.clock.time());
+        }
+
         // 根据事件类型处理事件并生成审计信息
         let process_audit = match &event {
             // 关闭事件：直接返回，不进行后续处理
-            EngineEvent::Shutdown(_) => return EngineAudit::process(event),
+            EngineEvent::Shutdown(_) => return ControlFlow::Break(EngineAudit::process(event)),
             // 命令事件：执行命令（平仓、取消订单等）
             EngineEvent::Command(command) => {
                 let output = self.action(command);
 
                 // 如果命令执行产生不可恢复的错误，立即返回错误审计
                 if let Some(unrecoverable) = output.unrecoverable_errors() {
-                    return EngineAudit::process_with_output_and_errs(event, unrecoverable, output);
+                    return ControlFlow::Break(EngineAudit::process_with_output_and_errs(
+                        event,
+                        unrecoverable,
+                        output,
+                    ));
                 } else {
                     ProcessAudit::with_output(event, output)
                 }
@@ -325,6 +598,11 @@ where
             }
             // 账户事件：更新账户状态（余额、订单、持仓等）
             EngineEvent::Account(account) => {
+                // 如果启用，发送被交易所拒绝的订单
+                if let AccountStreamEvent::Item(item) = account {
+                    self.send_order_rejections(OrderRejection::exchange(item, self.clock.time()));
+                }
+
                 let output = self.update_from_account_stream(account);
                 ProcessAudit::with_account_update(event, output)
             }
@@ -335,24 +613,201 @@ where
             }
         };
 
-        // 如果交易状态为 Enabled，生成算法订单
-        if let TradingState::Enabled = self.state.trading {
-            let output = self.generate_algo_orders();
-
-            // 根据订单生成结果构造最终审计
-            if output.is_empty() {
-                // 没有生成订单，直接返回处理审计
-                EngineAudit::from(process_audit)
-            } else if let Some(unrecoverable) = output.unrecoverable_errors() {
-                // 有不可恢复的错误，添加错误到审计
-                EngineAudit::Process(process_audit.add_errors(unrecoverable))
-            } else {
-                // 正常生成订单，添加输出到审计
-                EngineAudit::from(process_audit.add_output(output))
+        // 如果启用，检测超时未确认的在途请求
+        let process_audit =
+            self.in_flight_timeouts()
+                .into_iter()
+                .fold(process_audit, |process_audit, timeout| {
+                    process_audit.add_output(EngineOutput::InFlightTimeout(timeout))
+                });
+
+        // 如果启用，标记已到期的交易对
+        let process_audit =
+            self.instruments_expired()
+                .into_iter()
+                .fold(process_audit, |process_audit, expired| {
+                    process_audit.add_output(EngineOutput::InstrumentExpired(expired))
+                });
+
+        // 如果启用，平仓持有时间超过上限的仓位
+        let process_audit = match self.close_aged_positions() {
+            Some(output) => process_audit
+                .add_errors(output.unrecoverable_errors())
+                .add_output(EngineOutput::PositionAgeExceeded(output)),
+            None => process_audit,
+        };
+
+        // 如果启用，回撤超过上限时禁用交易
+        let process_audit = match self.drawdown_breached() {
+            Some(breached) => {
+                let process_audit =
+                    process_audit.add_output(EngineOutput::DrawdownBreached(breached));
+                match self.update_from_trading_state_update(TradingState::Disabled) {
+                    Some(disabled) => {
+                        process_audit.add_output(EngineOutput::OnTradingDisabled(disabled))
+                    }
+                    None => process_audit,
+                }
             }
-        } else {
+            None => process_audit,
+        };
+
+        ControlFlow::Continue(process_audit)
+    }
+
+    /// 如果启用了订单拒绝通道，发送提供的 [`OrderRejection`]。
+    fn send_order_rejections(&self, rejections: impl IntoIterator<Item = OrderRejection>) {
+        let Some(OrderRejectionTx(tx)) = &self.order_rejection_tx else {
+            return;
+        };
+
+        for rejection in rejections {
+            if tx.send(rejection).is_err() {
+                warn!("Engine failed to send OrderRejection - receiver dropped");
+            }
+        }
+    }
+
+    /// 如果启用了回撤熔断，使用当前会话盈亏更新熔断器，并在新触发熔断时返回 [`DrawdownBreached`]。
+    fn drawdown_breached(&mut self) -> Option<DrawdownBreached> {
+        let breaker = self.drawdown_circuit_breaker.as_mut()?;
+
+        breaker.update(
+            session_pnl(&self.state.instruments),
+            self.state.trading,
+            self.clock.time(),
+        )
+    }
+
+    /// 如果启用了仓位最大持有时间，平仓持有时间超过上限的仓位并返回平仓输出。
+    fn close_aged_positions(&mut self) -> Option<SendCancelsAndOpensOutput> {
+        let max_age = self.max_position_age?;
+
+        let time = self.clock.time();
+        let aged = self
+            .state
+            .instruments
+            .instruments(&InstrumentFilter::None)
+            .filter(|state| state.position_age_exceeded(time, max_age))
+            .map(|state| state.key)
+            .collect::<Vec<_>>();
+
+        if aged.is_empty() {
+            return None;
+        }
+
+        let output = self.close_positions(&InstrumentFilter::instruments(aged));
+
+        // 记录新发送的在途请求的发送时间
+        self.sync_in_flight_timeouts();
+
+        Some(output)
+    }
+
+    /// 如果启用了交易对过期检测，使用当前 [`EngineClock`] 时间标记并返回新过期的交易对。
+    fn instruments_expired(&mut self) -> Vec<InstrumentExpired> {
+        if !self.expiry_handling {
+            return vec![];
+        }
+
+        let time = self.clock.time();
+        self.state
+            .instruments
+            .0
+            .values_mut()
+            .filter_map(|state| state.update_expiry(time))
+            .collect()
+    }
+
+    /// 如果启用了在途请求超时检测，同步在途请求发送时间并返回新超时的在途请求。
+    fn in_flight_timeouts(&mut self) -> Vec<InFlightTimeout> {
+        let Some(timeout) = self.in_flight_timeout else {
+            return vec![];
+        };
+
+        self.sync_in_flight_timeouts();
+
+        let time = self.clock.time();
+        let EngineState {
+            instruments,
+            in_flight_timeouts,
+            ..
+        } = &mut self.state;
+
+        in_flight_timeouts.timed_out(
+            instruments
+                .instruments(&InstrumentFilter::None)
+                .flat_map(|state| state.orders.active.values()),
+            time,
+            timeout,
+        )
+    }
+
+    /// 如果启用了在途请求超时检测，使用当前 [`EngineClock`] 时间记录新发送的在途请求。
+    fn sync_in_flight_timeouts(&mut self) {
+        if self.in_flight_timeout.is_none() {
+            return;
+        }
+
+        let time = self.clock.time();
+        let EngineState {
+            instruments,
+            in_flight_timeouts,
+            ..
+        } = &mut self.state;
+
+        in_flight_timeouts.sync(
+            instruments
+                .instruments(&InstrumentFilter::None)
+                .flat_map(|state| state.orders.active.values()),
+            time,
+        );
+    }
+
+    /// 如果交易状态为 Enabled 或 PostOnly，生成算法订单并将输出添加到审计中。
+    fn add_algo_orders_to_audit(
+        &mut self,
+        process_audit: ProcessAudit<
+            EngineEvent<InstrumentData::MarketEventKind>,
+            EngineOutput<Strategy::OnTradingDisabled, Strategy::OnDisconnect>,
+        >,
+    ) -> <Self as Processor<EngineEvent<InstrumentData::MarketEventKind>>>::Audit {
+        if !self.state.trading.generates_algo_orders() {
             // 交易状态为 Disabled，不生成订单，直接返回处理审计
+            return EngineAudit::from(process_audit);
+        }
+
+        let output = self.generate_algo_orders();
+
+        // 记录新发送的在途请求的发送时间
+        self.sync_in_flight_timeouts();
+
+        // 如果启用，发送被拒绝的算法订单
+        if self.order_rejection_tx.is_some() {
+            let time = self.clock.time();
+            let cancels = output
+                .cancels_refused
+                .iter()
+                .cloned()
+                .map(|refused| OrderRejection::risk(refused, time));
+            let opens = output
+                .opens_refused
+                .iter()
+                .cloned()
+                .map(|refused| OrderRejection::risk(refused, time));
+            self.send_order_rejections(cancels.chain(opens));
+        }
+
+        // 根据订单生成结果构造最终审计
+        if output.is_empty() {
+            // 没有生成订单，直接返回处理审计
             EngineAudit::from(process_audit)
+        } else if let Some(unrecoverable) = output.unrecoverable_errors() {
+            // 有不可恢复的错误，添加错误到审计
+            EngineAudit::Process(process_audit.add_errors(unrecoverable))
+        } else {
+            // 正常生成订单，添加输出到审计
+            EngineAudit::from(process_audit.add_output(output))
         }
     }
 }
@@ -408,6 +863,8 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
     /// - `SendOpenRequests`: 发送开仓订单请求
     /// - `ClosePositions`: 平仓命令
     /// - `CancelOrders`: 取消订单命令
+    /// - `QueryState`: 状态查询命令，通过一次性回复通道回复查询结果
+    /// - `SwapStrategy`: Strategy 热替换命令，替换 Engine 的 Strategy 实例并保留 `EngineState`
     ///
     /// # 使用示例
     ///
@@ -434,7 +891,7 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
             }
             Command::SendOpenRequests(requests) => {
                 info!(?requests, "Engine actioning user Command::SendOpenRequests");
-                let output = self.send_requests(requests.clone());
+                let output = self.send_open_requests(requests.clone());
                 self.state.record_in_flight_opens(&output.sent);
                 ActionOutput::OpenOrders(output)
             }
@@ -446,6 +903,34 @@ impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Strategy, Risk>
                 info!(?filter, "Engine actioning user Command::CancelOrders");
                 ActionOutput::CancelOrders(self.cancel_orders(filter))
             }
+            Command::QueryState(query) => {
+                info!(kind = ?query.kind, "Engine actioning user Command::QueryState");
+                let response = query::query_state(&self.state, &query.kind);
+                ActionOutput::QueryState(QueryStateOutput {
+                    replied: query.reply.send(response),
+                })
+            }
+            Command::SwapStrategy(swap) => {
+                let swapped = self
+                    .strategy_swap
+                    .and_then(|strategy_swap| strategy_swap.take(swap))
+                    .map(|strategy| self.strategy = strategy)
+                    .is_some();
+
+                if swapped {
+                    info!("Engine actioning user Command::SwapStrategy");
+                } else if self.strategy_swap.is_none() {
+                    warn!(
+                        "Engine ignoring user Command::SwapStrategy with Strategy swapping disabled"
+                    );
+                } else {
+                    warn!(
+                        "Engine ignoring user Command::SwapStrategy without a Strategy of the Engine Strategy type"
+                    );
+                }
+
+                ActionOutput::SwapStrategy(SwapStrategyOutput { swapped })
+            }
         }
     }
 
@@ -696,6 +1181,158 @@ where
             execution_txs,
             strategy,
             risk,
+            stamp_time_processed: false,
+            in_flight_timeout: None,
+            expiry_handling: false,
+            max_position_age: None,
+            drawdown_circuit_breaker: None,
+            order_rejection_tx: None,
+            strategy_swap: None,
+            pre_process_hook: None,
+            post_process_hook: None,
+        }
+    }
+
+    /// 启用或禁用为处理的 [`MarketEvent`] 标记 `time_processed`。
+    ///
+    /// 启用后，Engine 在处理每个 [`MarketEvent`] 时使用 [`EngineClock`] 时间设置其
+    /// `time_processed`，标记后的事件会出现在 [`EngineAudit`] 中，可通过
+    /// [`MarketEvent::latency`] 计算传输延迟和处理延迟。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let engine = Engine::new(LiveClock, state, execution_txs, strategy, risk)
+    ///     .with_time_processed_stamping(true);
+    /// ```
+    pub fn with_time_processed_stamping(self, enabled: bool) -> Self {
+        Self {
+            stamp_time_processed: enabled,
+            ..self
+        }
+    }
+
+    /// 启用在途请求超时检测。
+    ///
+    /// 启用后，Engine 使用 [`EngineClock`] 时间记录每个在途开仓和在途取消请求的发送时间。
+    /// 如果请求在 `timeout` 内未收到交易所确认，Engine 会为其生成一次
+    /// [`EngineOutput::InFlightTimeout`]，Strategy 可据此重试请求或发出告警，防止订单静默丢失。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let engine = Engine::new(LiveClock, state, execution_txs, strategy, risk)
+    ///     .with_in_flight_timeout(TimeDelta::seconds(30));
+    /// ```
+    pub fn with_in_flight_timeout(self, timeout: TimeDelta) -> Self {
+        Self {
+            in_flight_timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// 启用或禁用交易对过期检测。
+    ///
+    /// 启用后，Engine 每处理一个事件都会使用 [`EngineClock`] 时间检查交易对（例如交割合约）
+    /// 是否已到期。到期的交易对会被标记为过期，并生成一次 [`EngineOutput::InstrumentExpired`]
+    /// （包含仍未平仓的仓位），之后该交易对的算法开仓请求会被拒绝。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let engine = Engine::new(clock, state, execution_txs, strategy, risk)
+    ///     .with_expiry_handling(true);
+    /// ```
+    pub fn with_expiry_handling(self, enabled: bool) -> Self {
+        Self {
+            expiry_handling: enabled,
+            ..self
+        }
+    }
+
+    /// 启用仓位最大持有时间自动平仓。
+    ///
+    /// 启用后，Engine 每处理一个事件都会使用 [`EngineClock`] 时间检查仓位的 `time_enter`。
+    /// 持有时间达到 `max_age` 的仓位会使用 Strategy 的 `ClosePositionsStrategy` 平仓（与
+    /// `Command::ClosePositions` 相同，绕过风险检查），并生成 [`EngineOutput::PositionAgeExceeded`]。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let engine = Engine::new(clock, state, execution_txs, strategy, risk)
+    ///     .with_max_position_age(TimeDelta::hours(4));
+    /// ```
+    pub fn with_max_position_age(self, max_age: TimeDelta) -> Self {
+        Self {
+            max_position_age: Some(max_age),
+            ..self
+        }
+    }
+
+    /// 启用回撤熔断。
+    ///
+    /// 启用后，Engine 每处理一个事件都会使用 [`DrawdownCircuitBreaker`] 跟踪会话权益（已实现和
+    /// 未实现盈亏）。权益相对峰值的回撤超过上限时，Engine 生成一次
+    /// [`EngineOutput::DrawdownBreached`]，并将 [`TradingState`] 转换为 `Disabled`，拒绝所有新的
+    /// 算法开仓。熔断后必须显式发送 [`TradingState`] 更新重新启用交易。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let engine = Engine::new(clock, state, execution_txs, strategy, risk)
+    ///     .with_drawdown_circuit_breaker(DrawdownCircuitBreaker::new(dec!(100_000), dec!(0.05)));
+    /// ```
+    pub fn with_drawdown_circuit_breaker(self, breaker: DrawdownCircuitBreaker) -> Self {
+        Self {
+            drawdown_circuit_breaker: Some(breaker),
+            ..self
+        }
+    }
+
+    /// 启用订单拒绝通道。
+    ///
+    /// 启用后，Engine 会将观察到的每个订单拒绝作为 [`OrderRejection`] 发送到 `tx`，包括被拒绝的
+    /// 算法订单（[`OrderRejectionStage::Risk`]）和被交易所拒绝的开仓和取消请求
+    /// （[`OrderRejectionStage::Exchange`]），合规工具等外部进程可以独立于审计流消费。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let (rejection_tx, rejection_rx) = mpsc_unbounded();
+    /// let engine = Engine::new(clock, state, execution_txs, strategy, risk)
+    ///     .with_order_rejection_tx(rejection_tx);
+    /// ```
+    ///
+    /// [`OrderRejectionStage::Risk`]: rejection::OrderRejectionStage::Risk
+    /// [`OrderRejectionStage::Exchange`]: rejection::OrderRejectionStage::Exchange
+    pub fn with_order_rejection_tx(self, tx: UnboundedTx<OrderRejection>) -> Self {
+        Self {
+            order_rejection_tx: Some(OrderRejectionTx(tx)),
+            ..self
+        }
+    }
+
+    /// 启用 Strategy 热替换。
+    ///
+    /// 启用后，Engine 处理 [`Command::SwapStrategy`] 时会取出其中与 Engine Strategy 类型相同的
+    /// 新 Strategy 实例并替换当前 Strategy，`EngineState` 保持不变（参见 [`swap`] 模块）。
+    /// 未启用时 `Command::SwapStrategy` 会被忽略。
+    ///
+    /// 替换需要在运行时检查新 Strategy 的类型，因此要求 Strategy 为 `'static`。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let engine = Engine::new(clock, state, execution_txs, strategy, risk)
+    ///     .with_strategy_swap();
+    /// ```
+    pub fn with_strategy_swap(self) -> Self
+    where
+        Strategy: 'static,
+    {
+        Self {
+            strategy_swap: Some(StrategySwap::new()),
+            ..self
         }
     }
 
@@ -735,6 +1372,89 @@ where
     }
 }
 
+impl<Clock, State, ExecutionTxs, Strategy, Risk>
+    Engine<Clock, State, ExecutionTxs, Strategy, Risk>
+{
+    /// 使用 `f` 将 Engine 的策略映射为新的策略，其余组件保持不变。
+    ///
+    /// post_process 钩子的审计类型依赖 Strategy，因此会被移除，需要时使用
+    /// `with_post_process_hook` 为新的 Engine 重新设置。pre_process 钩子保持不变。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let engine = engine.map_strategy(CancelOrdersOnDisconnect::new);
+    /// ```
+    pub fn map_strategy<NextStrategy>(
+        self,
+        f: impl FnOnce(Strategy) -> NextStrategy,
+    ) -> Engine<Clock, State, ExecutionTxs, NextStrategy, Risk> {
+        self.split_strategy(|strategy| (f(strategy), ())).0
+    }
+
+    /// 使用 `split` 将 Engine 的策略拆分为新的策略和其余部分，其余组件保持不变，
+    /// post_process 钩子被移除。
+    fn split_strategy<NextStrategy, Rest>(
+        self,
+        split: impl FnOnce(Strategy) -> (NextStrategy, Rest),
+    ) -> (Engine<Clock, State, ExecutionTxs, NextStrategy, Risk>, Rest) {
+        let (strategy, rest) = split(self.strategy);
+        let engine = Engine {
+            clock: self.clock,
+            meta: self.meta,
+            state: self.state,
+            execution_txs: self.execution_txs,
+            strategy,
+            risk: self.risk,
+            stamp_time_processed: self.stamp_time_processed,
+            in_flight_timeout: self.in_flight_timeout,
+            expiry_handling: self.expiry_handling,
+            max_position_age: self.max_position_age,
+            drawdown_circuit_breaker: self.drawdown_circuit_breaker,
+            order_rejection_tx: self.order_rejection_tx,
+            strategy_swap: None,
+            pre_process_hook: self.pre_process_hook,
+            post_process_hook: None,
+        };
+        (engine, rest)
+    }
+
+    /// 将 Engine 临时切换为从当前策略拆分出的内部策略，并以此调用 `f`。
+    ///
+    /// `split` 将当前策略拆分为内部策略和其余部分，`f` 返回后使用 `join` 重新组合。
+    /// 用于包装策略（例如 `CancelOrdersOnDisconnect` 或 `MultiStrategy`）将 `OnTradingDisabled`
+    /// 和 `OnDisconnectStrategy` 委托给被包装的策略，这些 trait 要求 Engine 使用被包装策略的类型。
+    ///
+    /// 如果 `f` panic，Engine 会先恢复为使用 `join` 重新组合的策略，然后继续展开 panic。
+    /// `split` 和 `join` 应该只移动策略（例如取出和放回字段），如果它们 panic，Engine 无法
+    /// 恢复到有效状态，进程会中止。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let output = engine.with_inner_strategy(
+    ///     |wrapper| (wrapper.strategy, ()),
+    ///     |engine| Inner::on_trading_disabled(engine),
+    ///     |strategy, ()| CancelOrdersOnDisconnect::new(strategy),
+    /// );
+    /// ```
+    pub fn with_inner_strategy<Inner, Rest, Output>(
+        &mut self,
+        split: impl FnOnce(Strategy) -> (Inner, Rest),
+        f: impl FnOnce(&mut Engine<Clock, State, ExecutionTxs, Inner, Risk>) -> Output,
+        join: impl FnOnce(Inner, Rest) -> Strategy,
+    ) -> Output {
+        let post_process_hook = self.post_process_hook.take();
+        let output = replace_with::replace_with_or_abort_and_return(self, |engine| {
+            let (mut inner, rest) = engine.split_strategy(split);
+            let output = panic::catch_unwind(AssertUnwindSafe(|| f(&mut inner)));
+            (output, inner.map_strategy(|inner| join(inner, rest)))
+        });
+        self.post_process_hook = post_process_hook;
+        output.unwrap_or_else(|panic| panic::resume_unwind(panic))
+    }
+}
+
 /// Engine 操作产生的输出，用于构造 Engine 的 [`EngineAudit`]。
 ///
 /// EngineOutput 枚举了 Engine 可能产生的所有输出类型，这些输出会被转换为 EngineAudit
@@ -755,6 +1475,10 @@ where
 /// - `PositionExit`: 持仓平仓输出
 /// - `MarketDisconnect`: 市场数据连接断开时的策略输出
 /// - `AlgoOrders`: 算法订单生成输出
+/// - `InFlightTimeout`: 在途请求超时未收到交易所确认
+/// - `InstrumentExpired`: 交易对到达到期时间
+/// - `PositionAgeExceeded`: 仓位持有时间超过上限后的自动平仓输出
+/// - `DrawdownBreached`: 会话权益回撤超过上限，交易已被禁用
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
 pub enum EngineOutput<
     OnTradingDisabled,
@@ -774,6 +1498,14 @@ pub enum EngineOutput<
     MarketDisconnect(OnDisconnect),
     /// 算法订单生成输出
     AlgoOrders(GenerateAlgoOrdersOutput<ExchangeKey, InstrumentKey>),
+    /// 在途请求超时未收到交易所确认（参见 [`Engine::with_in_flight_timeout`]）
+    InFlightTimeout(InFlightTimeout<ExchangeKey, InstrumentKey>),
+    /// 交易对到达到期时间（参见 [`Engine::with_expiry_handling`]）
+    InstrumentExpired(InstrumentExpired<InstrumentKey>),
+    /// 仓位持有时间超过上限后的自动平仓输出（参见 [`Engine::with_max_position_age`]）
+    PositionAgeExceeded(SendCancelsAndOpensOutput<ExchangeKey, InstrumentKey>),
+    /// 会话权益回撤超过上限，交易已被禁用（参见 [`Engine::with_drawdown_circuit_breaker`]）
+    DrawdownBreached(DrawdownBreached),
 }
 
 /// Engine 从 [`TradingState`] 更新时产生的输出，用于构造 Engine 的 [`EngineAudit`]。
@@ -860,3 +1592,29 @@ impl<OnTradingDisabled, OnDisconnect, ExchangeKey, InstrumentKey>
         Self::AlgoOrders(value)
     }
 }
+
+impl<OnTradingDisabled, OnDisconnect, ExchangeKey, InstrumentKey>
+    From<InFlightTimeout<ExchangeKey, InstrumentKey>>
+    for EngineOutput<OnTradingDisabled, OnDisconnect, ExchangeKey, InstrumentKey>
+{
+    fn from(value: InFlightTimeout<ExchangeKey, InstrumentKey>) -> Self {
+        Self::InFlightTimeout(value)
+    }
+}
+
+impl<OnTradingDisabled, OnDisconnect, ExchangeKey, InstrumentKey>
+    From<InstrumentExpired<InstrumentKey>>
+    for EngineOutput<OnTradingDisabled, OnDisconnect, ExchangeKey, InstrumentKey>
+{
+    fn from(value: InstrumentExpired<InstrumentKey>) -> Self {
+        Self::InstrumentExpired(value)
+    }
+}
+
+impl<OnTradingDisabled, OnDisconnect, ExchangeKey, InstrumentKey> From<DrawdownBreached>
+    for EngineOutput<OnTradingDisabled, OnDisconnect, ExchangeKey, InstrumentKey>
+{
+    fn from(value: DrawdownBreached) -> Self {
+        Self::DrawdownBreached(value)
+    }
+}
@@ -0,0 +1,191 @@
+//! Engine 状态查询模块
+//!
+//! 本模块定义了 [`QueryState`] 请求，外部进程可以通过 [`Command::QueryState`] 发送给 Engine，
+//! 以同步方式读取当前的仓位、活跃订单或余额，而无需订阅审计流。
+//!
+//! # 工作原理
+//!
+//! 1. 外部进程使用 [`QueryState::new`] 构造查询请求，获得一次性（oneshot）回复接收器
+//! 2. 查询请求作为 [`Command::QueryState`] 发送给 Engine
+//! 3. Engine 在处理命令时根据 [`QueryKind`] 读取 `EngineState`，并通过回复通道发送
+//!    [`QueryResponse`]，回复路径与 Engine 事件流和审计流相互独立
+//!
+//! # 使用示例
+//!
+//! ```rust,ignore
+//! let (query, reply_rx) = QueryState::new(QueryKind::Positions(InstrumentFilter::None));
+//! system.send(Command::QueryState(query));
+//!
+//! let QueryResponse::Positions(positions) = reply_rx.await? else { unreachable!() };
+//! ```
+//!
+//! [`Command::QueryState`]: super::command::Command::QueryState
+
+use crate::engine::state::{
+    EngineState,
+    asset::{AssetState, filter::AssetFilter},
+    instrument::filter::InstrumentFilter,
+    position::Position,
+};
+use barter_execution::order::{Order, state::ActiveOrderState};
+use barter_instrument::{
+    asset::{AssetIndex, QuoteAsset},
+    exchange::ExchangeIndex,
+    instrument::InstrumentIndex,
+};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    cmp::Ordering,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::oneshot;
+
+/// 要查询的 `EngineState` 子集。
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub enum QueryKind<
+    ExchangeKey = ExchangeIndex,
+    AssetKey = AssetIndex,
+    InstrumentKey = InstrumentIndex,
+> {
+    /// 查询通过 [`InstrumentFilter`] 筛选的交易对的当前仓位。
+    Positions(InstrumentFilter<ExchangeKey, AssetKey, InstrumentKey>),
+    /// 查询通过 [`InstrumentFilter`] 筛选的交易对的活跃订单。
+    Orders(InstrumentFilter<ExchangeKey, AssetKey, InstrumentKey>),
+    /// 查询通过 [`AssetFilter`] 筛选的资产余额。
+    Balances(AssetFilter),
+}
+
+/// Engine 对 [`QueryKind`] 的回复。
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub enum QueryResponse {
+    /// 筛选的交易对的所有当前仓位。
+    Positions(Vec<Position<QuoteAsset, InstrumentIndex>>),
+    /// 筛选的交易对的所有活跃订单（按 `ClientOrderId` 排序）。
+    Orders(Vec<Order<ExchangeIndex, InstrumentIndex, ActiveOrderState>>),
+    /// 筛选的资产状态。
+    Balances(Vec<AssetState>),
+}
+
+/// 查询请求，包含 [`QueryKind`] 和一次性回复通道。
+///
+/// 使用 [`Self::new`] 构造，详见模块文档。
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct QueryState<
+    ExchangeKey = ExchangeIndex,
+    AssetKey = AssetIndex,
+    InstrumentKey = InstrumentIndex,
+> {
+    /// 要查询的 `EngineState` 子集
+    pub kind: QueryKind<ExchangeKey, AssetKey, InstrumentKey>,
+    /// 回复通道
+    pub reply: QueryReplyTx,
+}
+
+impl<ExchangeKey, AssetKey, InstrumentKey> QueryState<ExchangeKey, AssetKey, InstrumentKey> {
+    /// 构造新的 `QueryState` 请求，返回请求和用于接收 [`QueryResponse`] 的接收器。
+    pub fn new(
+        kind: QueryKind<ExchangeKey, AssetKey, InstrumentKey>,
+    ) -> (Self, oneshot::Receiver<QueryResponse>) {
+        let (reply, reply_rx) = QueryReplyTx::new();
+        (Self { kind, reply }, reply_rx)
+    }
+}
+
+/// [`QueryResponse`] 的一次性回复发送器。
+///
+/// 回复发送器可以被克隆（例如用于审计），但所有克隆共享同一个通道，因此最多只会发送一次回复。
+///
+/// 回复发送器不会被序列化：反序列化的 `QueryReplyTx` 没有关联的接收器，向其发送的回复会被丢弃。
+#[derive(Debug, Clone, Default)]
+pub struct QueryReplyTx(Arc<Mutex<Option<oneshot::Sender<QueryResponse>>>>);
+
+impl QueryReplyTx {
+    /// 构造新的 `QueryReplyTx`，返回发送器和关联的接收器。
+    pub fn new() -> (Self, oneshot::Receiver<QueryResponse>) {
+        let (tx, rx) = oneshot::channel();
+        (Self(Arc::new(Mutex::new(Some(tx)))), rx)
+    }
+
+    /// 发送 [`QueryResponse`]，如果回复被接收器接收则返回 `true`。
+    ///
+    /// 如果回复已经发送过、接收器已被丢弃，或发送器没有关联的接收器，则返回 `false`。
+    pub fn send(&self, response: QueryResponse) -> bool {
+        self.0
+            .lock()
+            .ok()
+            .and_then(|mut tx| tx.take())
+            .is_some_and(|tx| tx.send(response).is_ok())
+    }
+}
+
+impl PartialEq for QueryReplyTx {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl PartialOrd for QueryReplyTx {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self == other).then_some(Ordering::Equal)
+    }
+}
+
+impl Serialize for QueryReplyTx {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_unit()
+    }
+}
+
+impl<'de> Deserialize<'de> for QueryReplyTx {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <()>::deserialize(deserializer).map(|_| Self::default())
+    }
+}
+
+/// [`QueryState`] 命令的执行输出。
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct QueryStateOutput {
+    /// 回复是否被接收器接收
+    pub replied: bool,
+}
+
+/// 根据 [`QueryKind`] 读取 `EngineState`，生成 [`QueryResponse`]。
+///
+/// # 参数
+///
+/// - `state`: Engine 状态
+/// - `kind`: 要查询的 `EngineState` 子集
+pub fn query_state<GlobalData, InstrumentData>(
+    state: &EngineState<GlobalData, InstrumentData>,
+    kind: &QueryKind,
+) -> QueryResponse {
+    match kind {
+        QueryKind::Positions(filter) => QueryResponse::Positions(
+            state
+                .instruments
+                .instruments(filter)
+                .flat_map(|instrument| instrument.position.positions().cloned())
+                .collect(),
+        ),
+        QueryKind::Orders(filter) => {
+            let mut orders = state
+                .instruments
+                .instruments(filter)
+                .flat_map(|instrument| instrument.orders.active.values().cloned())
+                .collect::<Vec<_>>();
+            orders.sort_by(|a, b| a.key.cid.cmp(&b.key.cid));
+            QueryResponse::Orders(orders)
+        }
+        QueryKind::Balances(filter) => {
+            QueryResponse::Balances(state.assets.filtered(filter).cloned().collect())
+        }
+    }
+}
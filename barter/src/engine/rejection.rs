@@ -0,0 +1,175 @@
+//! 订单拒绝通道模块
+//!
+//! 本模块定义了 [`OrderRejection`]，汇总 Engine 观察到的所有订单拒绝（风险拒绝和交易所拒绝），
+//! 以便合规工具等外部进程独立于主审计流订阅。
+//!
+//! # 工作原理
+//!
+//! 启用 `Engine::with_order_rejection_tx` 后，`Engine` 会将每个订单拒绝发送到提供的通道：
+//!
+//! 1. **风险拒绝**: 算法订单中被 `RiskManager`（或 Engine 的交易状态、过期检测）拒绝的
+//!    [`RiskRefused`] 请求，阶段为 [`OrderRejectionStage::Risk`]
+//! 2. **交易所拒绝**: 账户事件中被交易所拒绝（[`OrderError::Rejected`]）的开仓和取消请求，
+//!    阶段为 [`OrderRejectionStage::Exchange`]
+//!
+//! 连接错误（例如超时）导致的失败不是拒绝，不会发送到通道。
+
+use crate::risk::RiskRefused;
+use barter_execution::{
+    AccountEvent, AccountEventKind,
+    error::OrderError,
+    order::{
+        Order,
+        request::{OrderRequestCancel, OrderRequestOpen, RequestCancel, RequestOpen},
+        state::{InactiveOrderState, OrderState},
+    },
+};
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use barter_integration::channel::UnboundedTx;
+use chrono::{DateTime, Utc};
+use derive_more::From;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+/// 被拒绝的订单，包含拒绝阶段和原因等完整上下文。
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct OrderRejection<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    /// 被拒绝的订单请求
+    pub order: RejectedOrder<ExchangeKey, InstrumentKey>,
+    /// 拒绝订单的阶段
+    pub stage: OrderRejectionStage,
+    /// 拒绝原因
+    pub reason: String,
+    /// 观察到拒绝的 `EngineClock` 时间
+    pub time: DateTime<Utc>,
+}
+
+/// 被拒绝的订单请求。
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, From)]
+pub enum RejectedOrder<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    /// 被拒绝的开仓请求
+    Open(OrderRequestOpen<ExchangeKey, InstrumentKey>),
+    /// 被拒绝的取消请求
+    Cancel(OrderRequestCancel<ExchangeKey, InstrumentKey>),
+}
+
+/// Engine 发送 [`OrderRejection`] 的通道。
+///
+/// 通道不参与 Engine 的比较和哈希：所有 `OrderRejectionTx` 彼此相等，因此 Engine 的
+/// `PartialEq`、`Ord` 和 `Hash` 只比较其状态和配置。
+#[derive(Debug, Clone, From)]
+pub struct OrderRejectionTx(pub UnboundedTx<OrderRejection>);
+
+impl PartialEq for OrderRejectionTx {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for OrderRejectionTx {}
+
+impl PartialOrd for OrderRejectionTx {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderRejectionTx {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Hash for OrderRejectionTx {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+/// 订单被拒绝的阶段。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum OrderRejectionStage {
+    /// 发送前被风险检查拒绝
+    Risk,
+    /// 发送后被交易所拒绝
+    Exchange,
+}
+
+impl<ExchangeKey, InstrumentKey> OrderRejection<ExchangeKey, InstrumentKey> {
+    /// 使用 [`RiskRefused`] 订单请求构造 [`OrderRejectionStage::Risk`] 阶段的 `OrderRejection`。
+    pub fn risk<Request>(refused: RiskRefused<Request>, time: DateTime<Utc>) -> Self
+    where
+        Request: Into<RejectedOrder<ExchangeKey, InstrumentKey>>,
+    {
+        Self {
+            order: refused.item.into(),
+            stage: OrderRejectionStage::Risk,
+            reason: refused.reason,
+            time,
+        }
+    }
+}
+
+impl OrderRejection {
+    /// 如果 [`AccountEvent`] 是交易所拒绝的开仓或取消响应，构造
+    /// [`OrderRejectionStage::Exchange`] 阶段的 `OrderRejection`。
+    pub fn exchange(event: &AccountEvent, time: DateTime<Utc>) -> Option<Self> {
+        let (order, error) = match &event.kind {
+            AccountEventKind::OrderSnapshot(snapshot) => {
+                let Order {
+                    key,
+                    side,
+                    price,
+                    quantity,
+                    kind,
+                    time_in_force,
+                    reduce_only,
+                    state,
+                } = &snapshot.0;
+
+                let OrderState::Inactive(InactiveOrderState::OpenFailed(error)) = state else {
+                    return None;
+                };
+
+                let order = RejectedOrder::Open(OrderRequestOpen {
+                    key: key.clone(),
+                    state: RequestOpen {
+                        side: *side,
+                        price: *price,
+                        quantity: *quantity,
+                        kind: *kind,
+                        time_in_force: *time_in_force,
+                        reduce_only: *reduce_only,
+                    },
+                });
+
+                (order, error)
+            }
+            AccountEventKind::OrderCancelled(response) => {
+                let Err(error) = &response.state else {
+                    return None;
+                };
+
+                let order = RejectedOrder::Cancel(OrderRequestCancel {
+                    key: response.key.clone(),
+                    state: RequestCancel { id: None },
+                });
+
+                (order, error)
+            }
+            _ => return None,
+        };
+
+        let OrderError::Rejected(error) = error else {
+            return None;
+        };
+
+        Some(Self {
+            order,
+            stage: OrderRejectionStage::Exchange,
+            reason: error.to_string(),
+            time,
+        })
+    }
+}
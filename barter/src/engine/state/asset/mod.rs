@@ -28,14 +28,17 @@ use barter_instrument::{
 };
 use barter_integration::{collection::FnvIndexMap, snapshot::Snapshot};
 use chrono::Utc;
-use derive_more::Constructor;
 use itertools::Either;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
 /// 定义 `AssetFilter`，用于过滤以资产为中心的数据结构。
 pub mod filter;
 
+/// 为在途开仓请求计算所需的预留余额。
+pub mod reservation;
+
 /// 按 [`AssetIndex`] 索引的交易所 [`AssetState`] 集合。
 ///
 /// AssetStates 维护所有交易所资产的状态映射。注意，不同交易所上的同名资产会有
@@ -70,6 +73,9 @@ pub mod filter;
 #[derive(Debug, Clone, PartialEq, Default, Deserialize, Serialize)]
 pub struct AssetStates(
     /// 以 ExchangeAsset 为键的资产状态映射
+    ///
+    /// 序列化为 `(key, value)` 条目序列，因为 `ExchangeAsset` 键无法作为 JSON 对象键。
+    #[serde(with = "indexmap::map::serde_seq")]
     pub FnvIndexMap<ExchangeAsset<AssetNameInternal>, AssetState>,
 );
 
@@ -186,6 +192,7 @@ impl AssetStates {
 /// - **asset**: 资产名称数据（内部名称和交易所名称）
 /// - **statistics**: 交易会话变更摘要生成器
 /// - **balance**: 当前余额和关联的交易所时间戳（可选）
+/// - **reserved**: 在途开仓请求预留的余额
 ///
 /// ## 使用场景
 ///
@@ -210,7 +217,7 @@ impl AssetStates {
 ///     println!("Balance: {:?}", balance.value);
 /// }
 /// ```
-#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize, Constructor)]
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub struct AssetState {
     /// 资产名称数据，包含内部名称和交易所名称。
     pub asset: Asset,
@@ -220,9 +227,47 @@ pub struct AssetState {
 
     /// 资产的当前余额和关联的交易所时间戳（如果存在）。
     pub balance: Option<Timed<Balance>>,
+
+    /// 在途开仓请求预留的余额。
+    ///
+    /// 交易所只有在确认开仓请求后才会从 `free` 余额中扣除挂单所需资金，因此在此之前
+    /// 发送的多个开仓请求可能超额使用同一余额。记录在途开仓请求时增加预留，
+    /// 订单被确认、成交、取消或拒绝（即不再处于在途状态）时释放预留。
+    ///
+    /// 参见 [`reservation`]。
+    #[serde(default)]
+    pub reserved: Decimal,
 }
 
 impl AssetState {
+    /// 使用提供的资产、统计摘要生成器和余额构造没有任何预留余额的 `AssetState`。
+    pub fn new(
+        asset: Asset,
+        statistics: TearSheetAssetGenerator,
+        balance: Option<Timed<Balance>>,
+    ) -> Self {
+        Self {
+            asset,
+            statistics,
+            balance,
+            reserved: Decimal::ZERO,
+        }
+    }
+
+    /// 返回真正可用的余额，即 `free` 余额减去在途开仓请求预留的余额。
+    ///
+    /// 如果资产还没有余额，返回 `None`。
+    pub fn balance_available(&self) -> Option<Decimal> {
+        self.balance
+            .as_ref()
+            .map(|balance| balance.value.free - self.reserved)
+    }
+
+    /// 按提供的变化量调整预留余额（正数为预留，负数为释放），预留余额不会低于零。
+    pub fn update_reserved(&mut self, delta: Decimal) {
+        self.reserved = (self.reserved + delta).max(Decimal::ZERO);
+    }
+
     /// 从 [`AssetBalance`] 快照更新 `AssetState`，如果快照更新则应用更新。
     ///
     /// 此方法通过仅应用至少与当前状态一样新的快照更新来确保时间一致性。
@@ -274,6 +319,7 @@ impl From<&AssetState> for AssetBalance<AssetNameExchange> {
             asset,
             statistics: _,
             balance,
+            reserved: _,
         } = value;
 
         let (balance, time_exchange) = match balance {
@@ -355,6 +401,7 @@ mod tests {
             },
             statistics: Default::default(),
             balance: None,
+            reserved: Decimal::ZERO,
         };
 
         let snapshot = Snapshot(AssetBalance {
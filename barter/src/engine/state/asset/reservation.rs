@@ -0,0 +1,82 @@
+use crate::engine::state::order::Orders;
+use barter_execution::order::state::ActiveOrderState;
+use barter_instrument::{
+    Side,
+    instrument::{Instrument, kind::InstrumentKind},
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// 为交易对的在途开仓请求预留的基础资产和计价资产余额。
+///
+/// 仅模拟现货订单的资金需求：
+/// - 买入预留计价资产：`price * quantity`
+/// - 卖出预留基础资产：`quantity`
+///
+/// 衍生品（保证金）订单和 reduce-only 订单不预留余额。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Default, Deserialize, Serialize)]
+pub struct InstrumentReserved {
+    /// 预留的基础资产余额。
+    pub base: Decimal,
+    /// 预留的计价资产余额。
+    pub quote: Decimal,
+}
+
+impl InstrumentReserved {
+    /// 计算为提供的开仓订单参数预留的余额。
+    ///
+    /// # 参数
+    ///
+    /// - `instrument`: 订单的交易对
+    /// - `side`: 订单方向
+    /// - `price`: 订单价格
+    /// - `quantity`: 订单数量
+    /// - `reduce_only`: 订单是否为 reduce-only
+    pub fn required<ExchangeKey, AssetKey>(
+        instrument: &Instrument<ExchangeKey, AssetKey>,
+        side: Side,
+        price: Decimal,
+        quantity: Decimal,
+        reduce_only: bool,
+    ) -> Self {
+        if reduce_only || !matches!(instrument.kind, InstrumentKind::Spot) {
+            return Self::default();
+        }
+
+        match side {
+            Side::Buy => Self {
+                base: Decimal::ZERO,
+                quote: (price * quantity).abs(),
+            },
+            Side::Sell => Self {
+                base: quantity.abs(),
+                quote: Decimal::ZERO,
+            },
+        }
+    }
+
+    /// 计算交易对所有在途开仓请求（`ActiveOrderState::OpenInFlight`）预留的余额。
+    pub fn in_flight<ExchangeKey, AssetKey, InstrumentKey>(
+        instrument: &Instrument<ExchangeKey, AssetKey>,
+        orders: &Orders<ExchangeKey, InstrumentKey>,
+    ) -> Self {
+        orders
+            .active
+            .values()
+            .filter(|order| matches!(order.state, ActiveOrderState::OpenInFlight(_)))
+            .fold(Self::default(), |reserved, order| {
+                let required = Self::required(
+                    instrument,
+                    order.side,
+                    order.price,
+                    order.quantity,
+                    order.reduce_only,
+                );
+
+                Self {
+                    base: reserved.base + required.base,
+                    quote: reserved.quote + required.quote,
+                }
+            })
+    }
+}
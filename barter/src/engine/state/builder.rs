@@ -16,9 +16,12 @@
 //! - 自定义 EngineState 配置
 
 use crate::engine::state::{
-    EngineState, asset::generate_empty_indexed_asset_states,
+    EngineState,
+    asset::generate_empty_indexed_asset_states,
     connectivity::generate_empty_indexed_connectivity_states,
-    instrument::generate_indexed_instrument_states, order::Orders, position::PositionManager,
+    instrument::generate_indexed_instrument_states,
+    order::{Orders, in_flight_timeout::InFlightTimeouts},
+    position::{PositionManager, PositionMode},
     trading::TradingState,
 };
 use barter_execution::balance::{AssetBalance, Balance};
@@ -27,7 +30,7 @@ use barter_instrument::{
     asset::{AssetIndex, ExchangeAsset, name::AssetNameInternal},
     exchange::{ExchangeId, ExchangeIndex},
     index::IndexedInstruments,
-    instrument::{Instrument, InstrumentIndex},
+    instrument::{Instrument, InstrumentIndex, spec::DecimalRounding},
 };
 use barter_integration::snapshot::Snapshot;
 use chrono::{DateTime, Utc};
@@ -97,6 +100,10 @@ pub struct EngineStateBuilder<'a, GlobalData, FnInstrumentData> {
     global: GlobalData,
     /// 初始资产余额映射（交易所资产 -> 余额）
     balances: FnvHashMap<ExchangeAsset<AssetNameInternal>, Balance>,
+    /// 所有交易对 `PositionManager` 使用的仓位模式（默认：`PositionMode::Netting`）
+    position_mode: PositionMode,
+    /// 所有交易对 `PositionManager` 使用的可选盈亏舍入方式（默认：不舍入）
+    position_rounding: Option<DecimalRounding>,
     /// 交易对数据初始化函数
     instrument_data_init: FnInstrumentData,
 }
@@ -148,6 +155,8 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
             trading_state: None,
             global,
             balances: FnvHashMap::default(),
+            position_mode: PositionMode::default(),
+            position_rounding: None,
             instrument_data_init,
         }
     }
@@ -212,6 +221,46 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
         }
     }
 
+    /// 设置所有交易对 `PositionManager` 使用的 [`PositionMode`]。
+    ///
+    /// 默认使用 `PositionMode::Netting`（每个交易对一个净仓位）。如果交易所使用对冲模式，
+    /// 可以设置为 `PositionMode::Hedging` 以独立跟踪多头和空头仓位。使用 `MockExchange` 时，
+    /// 应同时将 `MockExecutionConfig::position_mode` 设置为 `MockPositionMode::Hedging`，以便
+    /// reduce-only 平仓请求按各自的仓位而非净仓位截断。
+    ///
+    /// # 参数
+    ///
+    /// - `value`: 仓位模式
+    ///
+    /// # 返回值
+    ///
+    /// 返回更新后的构建器，支持方法链式调用。
+    pub fn position_mode(self, value: PositionMode) -> Self {
+        Self {
+            position_mode: value,
+            ..self
+        }
+    }
+
+    /// 设置所有交易对 `PositionManager` 计算盈亏和分摊手续费时使用的 [`DecimalRounding`]。
+    ///
+    /// 默认不舍入。如果需要按交易所结算单精确对账（例如按分舍入），可以设置与交易所一致的
+    /// [`RoundingMode`](barter_instrument::instrument::spec::RoundingMode) 和精度。
+    ///
+    /// # 参数
+    ///
+    /// - `value`: 舍入方式和小数位数
+    ///
+    /// # 返回值
+    ///
+    /// 返回更新后的构建器，支持方法链式调用。
+    pub fn position_rounding(self, value: DecimalRounding) -> Self {
+        Self {
+            position_rounding: Some(value),
+            ..self
+        }
+    }
+
     /// 可选地提供初始交易所资产 `Balance`（余额）。
     ///
     /// 此方法用于设置 EngineState 的初始资产余额。这在回测场景中特别有用，因为需要
@@ -315,6 +364,8 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
             trading_state,
             global,
             balances,
+            position_mode,
+            position_rounding,
             instrument_data_init,
         } = self;
 
@@ -344,7 +395,10 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
         let instruments = generate_indexed_instrument_states(
             instruments,
             time_engine_start,
-            PositionManager::default,
+            || PositionManager {
+                rounding: position_rounding,
+                ..PositionManager::with_mode(position_mode)
+            },
             Orders::default,
             instrument_data_init,
         );
@@ -355,6 +409,7 @@ impl<'a, GlobalData, FnInstrumentData> EngineStateBuilder<'a, GlobalData, FnInst
             connectivity,
             assets,
             instruments,
+            in_flight_timeouts: InFlightTimeouts::default(),
         }
     }
 }
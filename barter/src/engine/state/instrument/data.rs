@@ -4,17 +4,19 @@ use crate::{
 };
 use barter_data::{
     event::{DataKind, MarketEvent},
-    subscription::book::OrderBookL1,
+    subscription::book::{Bbo, OrderBookL1},
 };
 use barter_execution::{
     AccountEvent,
     order::request::{OrderRequestCancel, OrderRequestOpen},
 };
 use barter_instrument::{asset::AssetIndex, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
 use derive_more::Constructor;
-use rust_decimal::{Decimal, prelude::FromPrimitive};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
+use tracing::debug;
 
 /// Defines a state object for tracking and managing custom instrument level data.
 ///
@@ -51,6 +53,13 @@ pub trait InstrumentDataState<
     fn price(&self) -> Option<Decimal>;
 }
 
+/// Defines an instrument data state that maintains the instrument's best bid & ask, used by eg/
+/// [`CheckPriceSanity`](crate::risk::check::CheckPriceSanity) as a reference price.
+pub trait InstrumentBboState {
+    /// Latest best bid & ask for an instrument, if both sides are available.
+    fn bbo(&self) -> Option<Bbo>;
+}
+
 /// Basic [`InstrumentDataState`] implementation that tracks the [`OrderBookL1`] and last traded
 /// price for an instrument.
 ///
@@ -75,6 +84,12 @@ impl InstrumentDataState for DefaultInstrumentMarketData {
     }
 }
 
+impl InstrumentBboState for DefaultInstrumentMarketData {
+    fn bbo(&self) -> Option<Bbo> {
+        Bbo::from_l1(&self.l1)
+    }
+}
+
 impl<InstrumentKey> Processor<&MarketEvent<InstrumentKey, DataKind>>
     for DefaultInstrumentMarketData
 {
@@ -82,16 +97,14 @@ impl<InstrumentKey> Processor<&MarketEvent<InstrumentKey, DataKind>>
 
     fn process(&mut self, event: &MarketEvent<InstrumentKey, DataKind>) -> Self::Audit {
         match &event.kind {
-            DataKind::Trade(trade) => {
+            DataKind::Trade(trade)
                 if self
                     .last_traded_price
                     .as_ref()
-                    .is_none_or(|price| price.time < event.time_exchange)
-                    && let Some(price) = Decimal::from_f64(trade.price)
-                {
-                    self.last_traded_price
-                        .replace(Timed::new(price, event.time_exchange));
-                }
+                    .is_none_or(|price| price.time < event.time_exchange) =>
+            {
+                self.last_traded_price
+                    .replace(Timed::new(trade.price, event.time_exchange));
             }
             DataKind::OrderBookL1(l1) => {
                 if self.l1.last_update_time < event.time_exchange {
@@ -118,3 +131,546 @@ impl<ExchangeKey, InstrumentKey> InFlightRequestRecorder<ExchangeKey, Instrument
 
     fn record_in_flight_open(&mut self, _: &OrderRequestOpen<ExchangeKey, InstrumentKey>) {}
 }
+
+/// OHLCV candle aggregated from `PublicTrade`s over the interval `[time_open, time_close)`.
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct OhlcvCandle {
+    pub time_open: DateTime<Utc>,
+    pub time_close: DateTime<Utc>,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
+    pub trade_count: u64,
+}
+
+impl OhlcvCandle {
+    /// Construct a new [`OhlcvCandle`] for the interval starting at `time_open` from its first
+    /// trade.
+    fn from_first_trade(
+        time_open: DateTime<Utc>,
+        interval: TimeDelta,
+        price: Decimal,
+        amount: Decimal,
+    ) -> Self {
+        Self {
+            time_open,
+            time_close: time_open + interval,
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: amount,
+            trade_count: 1,
+        }
+    }
+
+    /// Update the [`OhlcvCandle`] with a trade that occurred within its interval.
+    fn update(&mut self, price: Decimal, amount: Decimal) {
+        self.high = self.high.max(price);
+        self.low = self.low.min(price);
+        self.close = price;
+        self.volume += amount;
+        self.trade_count += 1;
+    }
+}
+
+/// [`InstrumentDataState`] implementation that aggregates `PublicTrade`s into fixed-interval
+/// [`OhlcvCandle`]s using the [`MarketEvent`] `time_exchange`.
+///
+/// Candle intervals are aligned to the Unix epoch (eg/ a 1 minute interval opens on each whole
+/// minute). When a trade crosses an interval boundary, the in-progress candle is closed and is
+/// retrievable via [`CandleData::last_closed_candle`]. Intervals without any trades produce no
+/// candle.
+///
+/// Trades that occur before the in-progress candle interval (ie/ out-of-order) are ignored.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CandleData {
+    pub interval: TimeDelta,
+    pub current: Option<OhlcvCandle>,
+    pub last_closed: Option<OhlcvCandle>,
+    pub last_traded_price: Option<Timed<Decimal>>,
+}
+
+impl CandleData {
+    /// Construct a new [`CandleData`] that aggregates trades into candles of the provided
+    /// `interval`.
+    ///
+    /// Panics if the `interval` is not at least one millisecond.
+    pub fn new(interval: TimeDelta) -> Self {
+        assert!(
+            interval.num_milliseconds() > 0,
+            "CandleData interval must be at least one millisecond"
+        );
+
+        Self {
+            interval,
+            current: None,
+            last_closed: None,
+            last_traded_price: None,
+        }
+    }
+
+    /// In-progress [`OhlcvCandle`], if any trades have occurred in the current interval.
+    pub fn current_candle(&self) -> Option<&OhlcvCandle> {
+        self.current.as_ref()
+    }
+
+    /// Most recently closed [`OhlcvCandle`].
+    pub fn last_closed_candle(&self) -> Option<&OhlcvCandle> {
+        self.last_closed.as_ref()
+    }
+
+    /// Open time of the epoch aligned `interval` containing the provided time.
+    pub(crate) fn interval_open(interval: TimeDelta, time: DateTime<Utc>) -> DateTime<Utc> {
+        let interval_ms = interval.num_milliseconds();
+        let open_ms = time.timestamp_millis().div_euclid(interval_ms) * interval_ms;
+        DateTime::from_timestamp_millis(open_ms).unwrap_or(time)
+    }
+
+    /// Aggregate a trade into the in-progress candle, returning the closed candle if the trade
+    /// crossed an interval boundary.
+    pub fn update_from_trade(
+        &mut self,
+        time: DateTime<Utc>,
+        price: Decimal,
+        amount: Decimal,
+    ) -> Option<OhlcvCandle> {
+        let time_open = Self::interval_open(self.interval, time);
+
+        let closed = match &mut self.current {
+            Some(current) if current.time_open == time_open => {
+                current.update(price, amount);
+                None
+            }
+            Some(current) if current.time_open > time_open => {
+                debug!(
+                    %time,
+                    current_time_open = %current.time_open,
+                    "CandleData ignoring out-of-order trade"
+                );
+                return None;
+            }
+            _ => self.current.replace(OhlcvCandle::from_first_trade(
+                time_open,
+                self.interval,
+                price,
+                amount,
+            )),
+        };
+
+        if self
+            .last_traded_price
+            .as_ref()
+            .is_none_or(|last| last.time <= time)
+        {
+            self.last_traded_price = Some(Timed::new(price, time));
+        }
+
+        if let Some(closed) = &closed {
+            self.last_closed = Some(closed.clone());
+        }
+
+        closed
+    }
+}
+
+impl InstrumentDataState for CandleData {
+    type MarketEventKind = DataKind;
+
+    fn price(&self) -> Option<Decimal> {
+        self.last_traded_price.as_ref().map(|timed| timed.value)
+    }
+}
+
+impl<InstrumentKey> Processor<&MarketEvent<InstrumentKey, DataKind>> for CandleData {
+    type Audit = Option<OhlcvCandle>;
+
+    fn process(&mut self, event: &MarketEvent<InstrumentKey, DataKind>) -> Self::Audit {
+        let DataKind::Trade(trade) = &event.kind else {
+            return None;
+        };
+
+        self.update_from_trade(event.time_exchange, trade.price, trade.amount)
+    }
+}
+
+impl<ExchangeKey, AssetKey, InstrumentKey>
+    Processor<&AccountEvent<ExchangeKey, AssetKey, InstrumentKey>> for CandleData
+{
+    type Audit = ();
+
+    fn process(&mut self, _: &AccountEvent<ExchangeKey, AssetKey, InstrumentKey>) -> Self::Audit {}
+}
+
+impl<ExchangeKey, InstrumentKey> InFlightRequestRecorder<ExchangeKey, InstrumentKey>
+    for CandleData
+{
+    fn record_in_flight_cancel(&mut self, _: &OrderRequestCancel<ExchangeKey, InstrumentKey>) {}
+
+    fn record_in_flight_open(&mut self, _: &OrderRequestOpen<ExchangeKey, InstrumentKey>) {}
+}
+
+/// [`InstrumentDataState`] implementation that maintains several [`CandleData`] timeframes
+/// (eg/ 1m, 5m & 1h) simultaneously from the same `PublicTrade` stream.
+///
+/// Every trade updates all timeframes, and each timeframe closes its candle independently at its
+/// own epoch aligned boundary. The latest closed candle of each timeframe is retrievable via
+/// [`MultiCandleData::candle`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MultiCandleData {
+    pub timeframes: Vec<CandleData>,
+}
+
+impl MultiCandleData {
+    /// Construct a new [`MultiCandleData`] that aggregates trades into candles of each of the
+    /// provided `intervals`.
+    ///
+    /// Timeframes are ordered from the shortest to the longest interval, and duplicate intervals
+    /// are ignored.
+    ///
+    /// Panics if any `interval` is not at least one millisecond.
+    pub fn new<Intervals>(intervals: Intervals) -> Self
+    where
+        Intervals: IntoIterator<Item = TimeDelta>,
+    {
+        let mut intervals = intervals.into_iter().collect::<Vec<_>>();
+        intervals.sort();
+        intervals.dedup();
+
+        Self {
+            timeframes: intervals.into_iter().map(CandleData::new).collect(),
+        }
+    }
+
+    /// [`CandleData`] for the provided `interval`, if it is a configured timeframe.
+    pub fn timeframe(&self, interval: TimeDelta) -> Option<&CandleData> {
+        self.timeframes
+            .iter()
+            .find(|timeframe| timeframe.interval == interval)
+    }
+
+    /// Most recently closed [`OhlcvCandle`] for the provided `interval`.
+    ///
+    /// Returns `None` if the `interval` is not a configured timeframe, or no candle of that
+    /// interval has closed yet.
+    pub fn candle(&self, interval: TimeDelta) -> Option<&OhlcvCandle> {
+        self.timeframe(interval)
+            .and_then(CandleData::last_closed_candle)
+    }
+
+    /// Aggregate a trade into every timeframe, returning the candles closed by the trade ordered
+    /// from the shortest to the longest interval.
+    pub fn update_from_trade(
+        &mut self,
+        time: DateTime<Utc>,
+        price: Decimal,
+        amount: Decimal,
+    ) -> Vec<OhlcvCandle> {
+        self.timeframes
+            .iter_mut()
+            .filter_map(|timeframe| timeframe.update_from_trade(time, price, amount))
+            .collect()
+    }
+}
+
+impl InstrumentDataState for MultiCandleData {
+    type MarketEventKind = DataKind;
+
+    fn price(&self) -> Option<Decimal> {
+        self.timeframes
+            .iter()
+            .filter_map(|timeframe| timeframe.last_traded_price.as_ref())
+            .max_by_key(|timed| timed.time)
+            .map(|timed| timed.value)
+    }
+}
+
+impl<InstrumentKey> Processor<&MarketEvent<InstrumentKey, DataKind>> for MultiCandleData {
+    type Audit = Vec<OhlcvCandle>;
+
+    fn process(&mut self, event: &MarketEvent<InstrumentKey, DataKind>) -> Self::Audit {
+        let DataKind::Trade(trade) = &event.kind else {
+            return vec![];
+        };
+
+        self.update_from_trade(event.time_exchange, trade.price, trade.amount)
+    }
+}
+
+impl<ExchangeKey, AssetKey, InstrumentKey>
+    Processor<&AccountEvent<ExchangeKey, AssetKey, InstrumentKey>> for MultiCandleData
+{
+    type Audit = ();
+
+    fn process(&mut self, _: &AccountEvent<ExchangeKey, AssetKey, InstrumentKey>) -> Self::Audit {}
+}
+
+impl<ExchangeKey, InstrumentKey> InFlightRequestRecorder<ExchangeKey, InstrumentKey>
+    for MultiCandleData
+{
+    fn record_in_flight_cancel(&mut self, _: &OrderRequestCancel<ExchangeKey, InstrumentKey>) {}
+
+    fn record_in_flight_open(&mut self, _: &OrderRequestOpen<ExchangeKey, InstrumentKey>) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::position::Position,
+        test_utils::{time_plus_days, trade},
+    };
+    use barter_data::{
+        event::MarketIter, exchange::binance::trade::BinanceTrade, subscription::trade::PublicTrade,
+    };
+    use barter_instrument::{Side, exchange::ExchangeId};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_high_precision_trade_price_updates_pnl_unrealised_without_drift() {
+        // Price with more significant digits than an f64 can represent exactly
+        let input = r#"{
+            "e":"trade","E":1649324825173,"s":"BTCUSDT","t":1,
+            "p":"30000.123456789012345","q":"0.000000000123456789",
+            "T":1649324825173,"m":false,"M":true
+        }"#;
+
+        let trade_exchange = serde_json::from_str::<BinanceTrade>(input).unwrap();
+        let MarketIter(events) = MarketIter::<InstrumentIndex, PublicTrade>::from((
+            ExchangeId::BinanceSpot,
+            InstrumentIndex(0),
+            trade_exchange,
+        ));
+        let event = MarketEvent::<InstrumentIndex, DataKind>::from(events[0].clone().unwrap());
+
+        let DataKind::Trade(public_trade) = &event.kind else {
+            panic!("expected DataKind::Trade")
+        };
+        assert_eq!(public_trade.price, dec!(30000.123456789012345));
+        assert_eq!(public_trade.amount, dec!(0.000000000123456789));
+
+        let mut data = DefaultInstrumentMarketData::default();
+        data.process(&event);
+        let price = data.price().unwrap();
+        assert_eq!(price, dec!(30000.123456789012345));
+
+        let mut position = Position::from(&trade(
+            time_plus_days(DateTime::<Utc>::MIN_UTC, 0),
+            Side::Buy,
+            30_000.0,
+            1.0,
+            0.0,
+        ));
+        position.update_pnl_unrealised(price);
+        assert_eq!(position.pnl_unrealised, dec!(0.123456789012345));
+    }
+
+    fn trade_event(
+        time: DateTime<Utc>,
+        price: Decimal,
+        amount: Decimal,
+    ) -> MarketEvent<InstrumentIndex, DataKind> {
+        MarketEvent {
+            time_exchange: time,
+            time_received: time,
+            time_processed: None,
+            sequence: None,
+            exchange: ExchangeId::BinanceSpot,
+            instrument: InstrumentIndex(0),
+            kind: DataKind::Trade(PublicTrade {
+                id: "id".to_string(),
+                price,
+                amount,
+                side: Side::Buy,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_candle_data_rollover_exactly_on_boundary() {
+        let base = DateTime::<Utc>::from_timestamp(1_700_000_040, 0).unwrap();
+        let mut data = CandleData::new(TimeDelta::minutes(1));
+
+        assert_eq!(data.process(&trade_event(base, dec!(100), dec!(1))), None);
+        assert_eq!(
+            data.process(&trade_event(
+                base + TimeDelta::seconds(10),
+                dec!(105),
+                dec!(2)
+            )),
+            None
+        );
+        assert_eq!(
+            data.process(&trade_event(
+                base + TimeDelta::milliseconds(59_999),
+                dec!(95),
+                dec!(1)
+            )),
+            None
+        );
+        assert!(data.last_closed_candle().is_none());
+
+        // Trade exactly on the interval boundary opens the next candle
+        let boundary = base + TimeDelta::minutes(1);
+        let closed = data.process(&trade_event(boundary, dec!(98), dec!(3)));
+
+        let expected = OhlcvCandle {
+            time_open: base,
+            time_close: boundary,
+            open: dec!(100),
+            high: dec!(105),
+            low: dec!(95),
+            close: dec!(95),
+            volume: dec!(4),
+            trade_count: 3,
+        };
+        assert_eq!(closed, Some(expected.clone()));
+        assert_eq!(data.last_closed_candle(), Some(&expected));
+
+        let current = data.current_candle().unwrap();
+        assert_eq!(current.time_open, boundary);
+        assert_eq!(current.open, dec!(98));
+        assert_eq!(current.trade_count, 1);
+        assert_eq!(data.price(), Some(dec!(98)));
+    }
+
+    #[test]
+    fn test_candle_data_rollover_with_gap() {
+        let base = DateTime::<Utc>::from_timestamp(1_700_000_040, 0).unwrap();
+        let mut data = CandleData::new(TimeDelta::minutes(1));
+
+        data.process(&trade_event(
+            base + TimeDelta::seconds(30),
+            dec!(100),
+            dec!(1),
+        ));
+
+        // No trades during the second interval, next trade occurs in the third interval
+        let closed = data
+            .process(&trade_event(
+                base + TimeDelta::seconds(150),
+                dec!(110),
+                dec!(1),
+            ))
+            .unwrap();
+        assert_eq!(closed.time_open, base);
+        assert_eq!(closed.time_close, base + TimeDelta::minutes(1));
+        assert_eq!(closed.close, dec!(100));
+
+        let current = data.current_candle().unwrap();
+        assert_eq!(current.time_open, base + TimeDelta::minutes(2));
+        assert_eq!(current.time_close, base + TimeDelta::minutes(3));
+
+        // Out-of-order trade from a previous interval is ignored
+        assert_eq!(
+            data.process(&trade_event(
+                base + TimeDelta::seconds(70),
+                dec!(1),
+                dec!(1)
+            )),
+            None
+        );
+        assert_eq!(data.current_candle().unwrap().low, dec!(110));
+        assert_eq!(data.last_closed_candle(), Some(&closed));
+        assert_eq!(data.price(), Some(dec!(110)));
+    }
+
+    #[test]
+    fn test_multi_candle_data_closes_each_timeframe_on_its_boundary() {
+        // Aligned to a whole hour
+        let base = DateTime::<Utc>::from_timestamp(1_699_999_200, 0).unwrap();
+        let mut data = MultiCandleData::new([
+            TimeDelta::hours(1),
+            TimeDelta::minutes(5),
+            TimeDelta::minutes(1),
+        ]);
+        assert_eq!(
+            data.timeframes
+                .iter()
+                .map(|timeframe| timeframe.interval)
+                .collect::<Vec<_>>(),
+            vec![
+                TimeDelta::minutes(1),
+                TimeDelta::minutes(5),
+                TimeDelta::hours(1)
+            ]
+        );
+
+        data.process(&trade_event(base, dec!(100), dec!(1)));
+        data.process(&trade_event(
+            base + TimeDelta::minutes(3),
+            dec!(110),
+            dec!(2),
+        ));
+        data.process(&trade_event(
+            base + TimeDelta::seconds(299),
+            dec!(90),
+            dec!(1),
+        ));
+        assert!(data.candle(TimeDelta::minutes(5)).is_none());
+
+        // 5m boundary closes the 1m & 5m candles, but not the 1h candle
+        let closed = data.process(&trade_event(
+            base + TimeDelta::minutes(5),
+            dec!(95),
+            dec!(3),
+        ));
+        let expected_5m = OhlcvCandle {
+            time_open: base,
+            time_close: base + TimeDelta::minutes(5),
+            open: dec!(100),
+            high: dec!(110),
+            low: dec!(90),
+            close: dec!(90),
+            volume: dec!(4),
+            trade_count: 3,
+        };
+        assert_eq!(closed.len(), 2);
+        assert_eq!(closed[1], expected_5m);
+        assert_eq!(data.candle(TimeDelta::minutes(5)), Some(&expected_5m));
+        assert_eq!(
+            data.candle(TimeDelta::minutes(1)).unwrap().time_open,
+            base + TimeDelta::minutes(4)
+        );
+        assert!(data.candle(TimeDelta::hours(1)).is_none());
+
+        let current_1h = data
+            .timeframe(TimeDelta::hours(1))
+            .unwrap()
+            .current_candle()
+            .unwrap();
+        assert_eq!(current_1h.time_open, base);
+        assert_eq!(current_1h.close, dec!(95));
+        assert_eq!(current_1h.volume, dec!(7));
+        assert_eq!(current_1h.trade_count, 4);
+
+        // 1h boundary closes the 1h candle with all trades aggregated
+        let closed = data.process(&trade_event(base + TimeDelta::hours(1), dec!(120), dec!(1)));
+        assert_eq!(closed.len(), 3);
+        assert_eq!(
+            data.candle(TimeDelta::hours(1)),
+            Some(&OhlcvCandle {
+                time_open: base,
+                time_close: base + TimeDelta::hours(1),
+                open: dec!(100),
+                high: dec!(110),
+                low: dec!(90),
+                close: dec!(95),
+                volume: dec!(7),
+                trade_count: 4,
+            })
+        );
+        assert_eq!(
+            data.candle(TimeDelta::minutes(5)).unwrap().time_open,
+            base + TimeDelta::minutes(5)
+        );
+        assert_eq!(data.price(), Some(dec!(120)));
+
+        // Unconfigured timeframe
+        assert!(data.candle(TimeDelta::minutes(15)).is_none());
+    }
+}
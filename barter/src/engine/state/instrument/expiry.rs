@@ -0,0 +1,154 @@
+//! 交易对过期检测模块
+//!
+//! 本模块定义了交割合约、期权等有到期时间的交易对的过期检测，用于防止 `Engine` 在回测
+//! 跨越合约展期时继续交易已过期的合约。
+//!
+//! # 工作原理
+//!
+//! 启用 `Engine::with_expiry_handling` 后，`Engine` 每处理一个事件都会使用
+//! `EngineClock::time()` 检查交易对的到期时间：
+//!
+//! 1. **标记**: 到期时间不晚于当前时间的交易对被标记为过期（[`InstrumentState::expired`]）
+//! 2. **审计**: 每个交易对过期时生成一次 [`InstrumentExpired`]，并记录仍未平仓的仓位
+//! 3. **禁止交易**: 过期交易对的算法开仓请求会被拒绝，取消请求仍然允许发送
+//!
+//! [`InstrumentState::expired`]: super::InstrumentState::expired
+
+use crate::engine::state::{EngineState, instrument::InstrumentState};
+use barter_instrument::{Side, instrument::InstrumentIndex};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// 交易对到达到期时间的信号。
+///
+/// 每个交易对最多生成一次，通过 `EngineOutput::InstrumentExpired` 出现在 Engine 审计中。
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct InstrumentExpired<InstrumentKey = InstrumentIndex> {
+    /// 过期交易对的标识符
+    pub instrument: InstrumentKey,
+    /// 交易对的到期时间
+    pub expiry: DateTime<Utc>,
+    /// 检测到过期的 `EngineClock` 时间
+    pub time_expired: DateTime<Utc>,
+    /// 过期时仍未平仓的仓位（方向和绝对数量），需要由 Strategy 或交易所结算处理
+    pub positions: Vec<(Side, Decimal)>,
+}
+
+/// 定义如何查询交易对是否已过期，供通用的 Engine 操作（例如生成算法订单）使用。
+pub trait InstrumentExpiryState<InstrumentKey = InstrumentIndex> {
+    /// 如果交易对已被标记为过期，返回 `true`。
+    fn is_expired(&self, instrument: &InstrumentKey) -> bool;
+}
+
+impl<GlobalData, InstrumentData> InstrumentExpiryState for EngineState<GlobalData, InstrumentData> {
+    fn is_expired(&self, instrument: &InstrumentIndex) -> bool {
+        self.instruments.instrument_index(instrument).expired
+    }
+}
+
+impl<InstrumentData> InstrumentState<InstrumentData> {
+    /// 如果交易对在 `time` 时已到期且尚未被标记为过期，将其标记为过期并返回
+    /// [`InstrumentExpired`]。
+    ///
+    /// 没有到期时间的交易对（例如现货和永续合约）永远不会过期。
+    pub fn update_expiry(&mut self, time: DateTime<Utc>) -> Option<InstrumentExpired> {
+        if self.expired {
+            return None;
+        }
+
+        let expiry = self
+            .instrument
+            .kind
+            .expiry()
+            .filter(|expiry| *expiry <= time)?;
+
+        self.expired = true;
+
+        Some(InstrumentExpired {
+            instrument: self.key,
+            expiry,
+            time_expired: time,
+            positions: self
+                .position
+                .positions()
+                .map(|position| (position.side, position.quantity_abs))
+                .collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{order::Orders, position::PositionManager},
+        statistic::summary::instrument::TearSheetGenerator,
+    };
+    use barter_instrument::{
+        Underlying,
+        asset::AssetIndex,
+        exchange::ExchangeIndex,
+        instrument::{
+            Instrument,
+            kind::{InstrumentKind, future::FutureContract},
+            quote::InstrumentQuoteAsset,
+        },
+    };
+    use chrono::TimeDelta;
+
+    fn instrument_state(kind: InstrumentKind<AssetIndex>) -> InstrumentState<()> {
+        InstrumentState::new(
+            InstrumentIndex(0),
+            Instrument::new(
+                ExchangeIndex(0),
+                "instrument",
+                "INSTRUMENT",
+                Underlying::new(AssetIndex(0), AssetIndex(1)),
+                InstrumentQuoteAsset::UnderlyingQuote,
+                kind,
+                None,
+            ),
+            TearSheetGenerator::init(DateTime::<Utc>::MIN_UTC),
+            PositionManager::default(),
+            Orders::default(),
+            (),
+            false,
+        )
+    }
+
+    #[test]
+    fn test_instrument_state_update_expiry() {
+        let expiry = DateTime::<Utc>::MIN_UTC + TimeDelta::days(10);
+
+        // TC0: Spot never expires
+        let mut state = instrument_state(InstrumentKind::Spot);
+        assert_eq!(state.update_expiry(DateTime::<Utc>::MAX_UTC), None);
+        assert!(!state.expired);
+
+        // TC1: Future does not expire before expiry
+        let mut state = instrument_state(InstrumentKind::Future(FutureContract {
+            contract_size: Decimal::ONE,
+            settlement_asset: AssetIndex(1),
+            expiry,
+        }));
+        assert_eq!(state.update_expiry(expiry - TimeDelta::seconds(1)), None);
+        assert!(!state.expired);
+
+        // TC2: Future expires at expiry
+        assert_eq!(
+            state.update_expiry(expiry),
+            Some(InstrumentExpired {
+                instrument: InstrumentIndex(0),
+                expiry,
+                time_expired: expiry,
+                positions: vec![],
+            })
+        );
+        assert!(state.expired);
+
+        // TC3: Expired Future is only flagged once
+        assert_eq!(state.update_expiry(expiry + TimeDelta::days(1)), None);
+        assert!(state.expired);
+    }
+}
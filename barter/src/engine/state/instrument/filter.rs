@@ -1,5 +1,8 @@
 use barter_instrument::{
-    Underlying, asset::AssetIndex, exchange::ExchangeIndex, instrument::InstrumentIndex,
+    Underlying,
+    asset::AssetIndex,
+    exchange::ExchangeIndex,
+    instrument::{InstrumentIndex, kind::InstrumentKindTag},
 };
 use barter_integration::collection::one_or_many::OneOrMany;
 use serde::{Deserialize, Serialize};
@@ -17,6 +20,7 @@ pub enum InstrumentFilter<
     Exchanges(OneOrMany<ExchangeKey>),
     Instruments(OneOrMany<InstrumentKey>),
     Underlyings(OneOrMany<Underlying<AssetKey>>),
+    Kinds(OneOrMany<InstrumentKindTag>),
 }
 
 impl<ExchangeKey, AssetKey, InstrumentKey> InstrumentFilter<ExchangeKey, AssetKey, InstrumentKey> {
@@ -31,4 +35,8 @@ impl<ExchangeKey, AssetKey, InstrumentKey> InstrumentFilter<ExchangeKey, AssetKe
     pub fn underlyings(exchanges: impl IntoIterator<Item = Underlying<AssetKey>>) -> Self {
         Self::Underlyings(OneOrMany::from_iter(exchanges))
     }
+
+    pub fn kinds(kinds: impl IntoIterator<Item = InstrumentKindTag>) -> Self {
+        Self::Kinds(OneOrMany::from_iter(kinds))
+    }
 }
@@ -0,0 +1,152 @@
+use crate::engine::state::instrument::{InstrumentState, InstrumentStates};
+use barter_instrument::{
+    exchange::ExchangeId,
+    index::IndexedInstruments,
+    instrument::{InstrumentIndex, name::InstrumentNameExchange},
+};
+use fnv::FnvHashMap;
+
+/// 从 `(ExchangeId, InstrumentNameExchange)` 到 [`InstrumentIndex`] 的缓存反向索引。
+///
+/// [`InstrumentStates::instrument`] 以 `InstrumentNameInternal` 为键查找，因此接收交易所原生
+/// 交易对名称的热循环需要为每个事件重新构造 `InstrumentNameInternal` 并重新哈希。
+/// `InstrumentSymbolIndex` 只需构建一次，之后通过借用的 `&str` 直接解析到 `InstrumentStates`
+/// 中的位置，无需任何分配。
+///
+/// 索引必须由构建 `InstrumentStates` 时使用的同一 [`IndexedInstruments`] 生成。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let symbols = InstrumentSymbolIndex::new(&indexed_instruments);
+///
+/// let state = state
+///     .instruments
+///     .instrument_by_exchange_symbol(&symbols, ExchangeId::BinanceSpot, "BTCUSDT");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct InstrumentSymbolIndex(
+    FnvHashMap<ExchangeId, FnvHashMap<InstrumentNameExchange, InstrumentIndex>>,
+);
+
+impl InstrumentSymbolIndex {
+    /// 从 [`IndexedInstruments`] 构建 `InstrumentSymbolIndex`。
+    pub fn new(instruments: &IndexedInstruments) -> Self {
+        let mut index =
+            FnvHashMap::<ExchangeId, FnvHashMap<InstrumentNameExchange, InstrumentIndex>>::default(
+            );
+
+        for instrument in instruments.instruments() {
+            index
+                .entry(instrument.value.exchange.value)
+                .or_default()
+                .insert(instrument.value.name_exchange.clone(), instrument.key);
+        }
+
+        Self(index)
+    }
+
+    /// 查找与交易所原生交易对名称关联的 [`InstrumentIndex`]。
+    pub fn find(&self, exchange: ExchangeId, name_exchange: &str) -> Option<InstrumentIndex> {
+        self.0.get(&exchange)?.get(name_exchange).copied()
+    }
+
+    /// 返回被索引的交易对数量。
+    pub fn len(&self) -> usize {
+        self.0.values().map(FnvHashMap::len).sum()
+    }
+
+    /// 如果没有被索引的交易对则返回 `true`。
+    pub fn is_empty(&self) -> bool {
+        self.0.values().all(FnvHashMap::is_empty)
+    }
+}
+
+impl<InstrumentData> InstrumentStates<InstrumentData> {
+    /// 通过缓存的 [`InstrumentSymbolIndex`] 返回与交易所原生交易对名称关联的
+    /// `InstrumentState` 的引用。
+    ///
+    /// 如果交易对未被索引则返回 `None`。
+    pub fn instrument_by_exchange_symbol(
+        &self,
+        symbols: &InstrumentSymbolIndex,
+        exchange: ExchangeId,
+        name_exchange: &str,
+    ) -> Option<&InstrumentState<InstrumentData>> {
+        let index = symbols.find(exchange, name_exchange)?;
+        self.0.get_index(index.index()).map(|(_, state)| state)
+    }
+
+    /// 通过缓存的 [`InstrumentSymbolIndex`] 返回与交易所原生交易对名称关联的
+    /// `InstrumentState` 的可变引用。
+    ///
+    /// 如果交易对未被索引则返回 `None`。
+    pub fn instrument_by_exchange_symbol_mut(
+        &mut self,
+        symbols: &InstrumentSymbolIndex,
+        exchange: ExchangeId,
+        name_exchange: &str,
+    ) -> Option<&mut InstrumentState<InstrumentData>> {
+        let index = symbols.find(exchange, name_exchange)?;
+        self.0.get_index_mut(index.index()).map(|(_, state)| state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::engine_state;
+    use barter_instrument::{instrument::name::InstrumentNameInternal, test_utils::instrument};
+
+    #[test]
+    fn test_instrument_by_exchange_symbol_matches_internal_name_lookup() {
+        let instruments = IndexedInstruments::new([
+            instrument(ExchangeId::BinanceSpot, "btc", "usdt"),
+            instrument(ExchangeId::BinanceSpot, "eth", "usdt"),
+            instrument(ExchangeId::Kraken, "btc", "usdt"),
+        ]);
+
+        let mut state = engine_state(&instruments);
+
+        let symbols = InstrumentSymbolIndex::new(&instruments);
+        assert_eq!(symbols.len(), 3);
+
+        for (exchange, name_exchange) in [
+            (ExchangeId::BinanceSpot, "btc_usdt"),
+            (ExchangeId::BinanceSpot, "eth_usdt"),
+            (ExchangeId::Kraken, "btc_usdt"),
+        ] {
+            let expected =
+                state
+                    .instruments
+                    .instrument(&InstrumentNameInternal::new_from_exchange(
+                        exchange,
+                        name_exchange,
+                    ));
+
+            let actual = state
+                .instruments
+                .instrument_by_exchange_symbol(&symbols, exchange, name_exchange)
+                .unwrap();
+
+            assert_eq!(actual, expected);
+            assert_eq!(actual.instrument.name_exchange.name(), name_exchange);
+        }
+
+        // Same exchange symbol on a different exchange is resolved independently
+        let kraken_btc = state
+            .instruments
+            .instrument_by_exchange_symbol_mut(&symbols, ExchangeId::Kraken, "btc_usdt")
+            .unwrap()
+            .key;
+        assert_eq!(kraken_btc, InstrumentIndex(2));
+
+        // Untracked symbols are not found
+        assert!(
+            state
+                .instruments
+                .instrument_by_exchange_symbol(&symbols, ExchangeId::Kraken, "eth_usdt")
+                .is_none()
+        );
+    }
+}
@@ -57,6 +57,16 @@ pub mod data;
 /// 定义 `InstrumentFilter`，用于过滤以交易对为中心的数据结构。
 pub mod filter;
 
+/// 定义 [`InstrumentExpired`](expiry::InstrumentExpired) 以及交割合约等有到期时间的交易对的
+/// 过期检测。
+pub mod expiry;
+
+/// 定义仓位最大持有时间检测，用于自动平仓持有时间过长的仓位。
+pub mod position_age;
+
+/// 定义 `InstrumentSymbolIndex`，按交易所原生交易对名称快速查找 [`InstrumentState`]。
+pub mod lookup;
+
 /// 按 [`InstrumentIndex`] 索引的 [`InstrumentState`] 集合。
 ///
 /// InstrumentStates 维护所有交易对的状态映射。注意，具有相同 [`InstrumentNameExchange`]
@@ -261,6 +271,39 @@ impl<InstrumentData> InstrumentStates<InstrumentData> {
         self.filtered_mut(filter).map(|state| &mut state.data)
     }
 
+    /// Return an `Iterator` of `InstrumentState`s grouped by exchange.
+    ///
+    /// `InstrumentState`s are stored in [`IndexedInstruments`] order, which is sorted by exchange,
+    /// so each exchange is a contiguous run of `InstrumentState`s. Groups are yielded in
+    /// `ExchangeIndex` order without allocating, and each group yields its `InstrumentState`s in
+    /// insertion order.
+    ///
+    /// See [`EngineState::instruments_by_exchange`](crate::engine::state::EngineState::instruments_by_exchange)
+    /// to group by [`ExchangeId`].
+    pub fn group_by_exchange(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            ExchangeIndex,
+            impl Iterator<Item = &InstrumentState<InstrumentData>>,
+        ),
+    > {
+        let mut states = self.0.as_slice();
+
+        std::iter::from_fn(move || {
+            let exchange = states.first()?.1.instrument.exchange;
+            let len = states
+                .values()
+                .take_while(|state| state.instrument.exchange == exchange)
+                .count();
+
+            let (group, rest) = states.split_at(len);
+            states = rest;
+
+            Some((exchange, group.values()))
+        })
+    }
+
     /// Return a filtered `Iterator` of `InstrumentState`s based on the provided `InstrumentFilter`.
     fn filtered<'a>(
         &'a self,
@@ -277,15 +320,20 @@ impl<InstrumentData> InstrumentStates<InstrumentData> {
                     .values()
                     .filter(|state| exchanges.contains(&state.instrument.exchange)),
             )),
-            Instruments(instruments) => Either::Right(Either::Right(
+            Instruments(instruments) => Either::Right(Either::Left(Either::Left(
                 self.0
                     .values()
                     .filter(|state| instruments.contains(&state.key)),
-            )),
-            Underlyings(underlying) => Either::Right(Either::Left(
+            ))),
+            Underlyings(underlying) => Either::Right(Either::Left(Either::Right(
                 self.0
                     .values()
                     .filter(|state| underlying.contains(&state.instrument.underlying)),
+            ))),
+            Kinds(kinds) => Either::Right(Either::Right(
+                self.0
+                    .values()
+                    .filter(|state| kinds.contains(&state.instrument.kind.tag())),
             )),
         }
     }
@@ -307,15 +355,20 @@ impl<InstrumentData> InstrumentStates<InstrumentData> {
                     .values_mut()
                     .filter(|state| exchanges.contains(&state.instrument.exchange)),
             )),
-            Instruments(instruments) => Either::Right(Either::Right(
+            Instruments(instruments) => Either::Right(Either::Left(Either::Left(
                 self.0
                     .values_mut()
                     .filter(|state| instruments.contains(&state.key)),
-            )),
-            Underlyings(underlying) => Either::Right(Either::Left(
+            ))),
+            Underlyings(underlying) => Either::Right(Either::Left(Either::Right(
                 self.0
                     .values_mut()
                     .filter(|state| underlying.contains(&state.instrument.underlying)),
+            ))),
+            Kinds(kinds) => Either::Right(Either::Right(
+                self.0
+                    .values_mut()
+                    .filter(|state| kinds.contains(&state.instrument.kind.tag())),
             )),
         }
     }
@@ -358,6 +411,7 @@ impl<InstrumentData> InstrumentStates<InstrumentData> {
 ///     PositionManager::default(),
 ///     Orders::default(),
 ///     instrument_data,
+///     false,
 /// );
 ///
 /// // 从交易更新状态
@@ -391,6 +445,11 @@ pub struct InstrumentState<
     /// 用户提供的交易对级别数据状态。可以包括市场数据、策略数据、风险数据、
     /// 期权定价数据或任何其他交易对特定信息。
     pub data: InstrumentData,
+
+    /// 交易对（例如交割合约）是否已过期，过期后 `Engine` 不再发送算法开仓请求
+    /// （参见 `Engine::with_expiry_handling`）。
+    #[serde(default)]
+    pub expired: bool,
 }
 
 impl<InstrumentData, ExchangeKey, AssetKey, InstrumentKey>
@@ -557,18 +616,20 @@ impl<InstrumentData, ExchangeKey, AssetKey, InstrumentKey>
         // 更新交易对数据
         self.data.process(event);
 
-        // 如果存在开放仓位，尝试更新未实现盈亏
-        let Some(position) = &mut self.position.current else {
+        // 如果不存在开放仓位，无需更新未实现盈亏
+        if self.position.positions().next().is_none() {
             return;
-        };
+        }
 
         // 从交易对数据中提取价格
         let Some(price) = self.data.price() else {
             return;
         };
 
-        // 使用价格更新未实现盈亏
-        position.update_pnl_unrealised(price);
+        // 使用价格更新所有开放仓位（对冲模式下包括多头和空头）的未实现盈亏
+        self.position
+            .positions_mut()
+            .for_each(|position| position.update_pnl_unrealised(price));
     }
 }
 
@@ -632,6 +693,7 @@ where
         position: _,
         orders,
         data: _,
+        expired: _,
     } = state;
 
     InstrumentAccountSnapshot {
@@ -648,6 +710,7 @@ where
                     quantity,
                     kind,
                     time_in_force,
+                    reduce_only,
                     state: ActiveOrderState::Open(open),
                 } = order
                 else {
@@ -667,6 +730,7 @@ where
                     quantity: *quantity,
                     kind: *kind,
                     time_in_force: *time_in_force,
+                    reduce_only: *reduce_only,
                     state: OrderState::active(open.clone()),
                 })
             })
@@ -753,15 +817,139 @@ where
                         instrument.value.clone().map_exchange_key(exchange_index),
                         // 使用启动时间初始化 TearSheet
                         TearSheetGenerator::init(time_engine_start),
-                        // 使用初始化函数创建仓位管理器
-                        position_manager_init(),
+                        // 使用初始化函数创建仓位管理器，合约乘数取自交易对定义
+                        position_manager_init()
+                            .with_contract_size(instrument.value.contract_size()),
                         // 使用初始化函数创建订单管理器
                         orders_init(),
                         // 使用初始化函数创建交易对数据
                         instrument_data_init(instrument),
+                        // 交易对初始为未过期
+                        false,
                     ),
                 )
             })
             .collect(),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::state::{
+        EngineState, global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+    };
+    use barter_instrument::{
+        index::IndexedInstruments,
+        instrument::kind::{InstrumentKind, InstrumentKindTag, perpetual::PerpetualContract},
+        test_utils::{asset, instrument},
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn test_instrument_states_group_by_exchange() {
+        let instruments = IndexedInstruments::new([
+            instrument(ExchangeId::BinanceSpot, "btc", "usdt"),
+            instrument(ExchangeId::Okx, "btc", "usdt"),
+            instrument(ExchangeId::BinanceSpot, "eth", "usdt"),
+            instrument(ExchangeId::Okx, "eth", "usdt"),
+            instrument(ExchangeId::BinanceSpot, "sol", "usdt"),
+        ]);
+
+        let state = EngineState::builder(&instruments, DefaultGlobalData, |_| {
+            DefaultInstrumentMarketData::default()
+        })
+        .time_engine_start(DateTime::<Utc>::MIN_UTC)
+        .build();
+
+        let names = |states: &mut dyn Iterator<Item = &InstrumentState<_>>| {
+            states
+                .map(|state| state.instrument.name_internal.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let expected = vec![
+            (
+                ExchangeId::BinanceSpot,
+                vec![
+                    "binance_spot-btc_usdt".to_string(),
+                    "binance_spot-eth_usdt".to_string(),
+                    "binance_spot-sol_usdt".to_string(),
+                ],
+            ),
+            (
+                ExchangeId::Okx,
+                vec!["okx-btc_usdt".to_string(), "okx-eth_usdt".to_string()],
+            ),
+        ];
+
+        let by_index = state
+            .instruments
+            .group_by_exchange()
+            .map(|(exchange, mut states)| (exchange, names(&mut states)))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            by_index,
+            vec![
+                (ExchangeIndex(0), expected[0].1.clone()),
+                (ExchangeIndex(1), expected[1].1.clone()),
+            ]
+        );
+
+        let by_id = state
+            .instruments_by_exchange()
+            .map(|(exchange, mut states)| (exchange, names(&mut states)))
+            .collect::<Vec<_>>();
+        assert_eq!(by_id, expected);
+    }
+
+    #[test]
+    fn test_instrument_states_filtered_by_kinds() {
+        let mut perpetual_eth = instrument(ExchangeId::BinanceSpot, "eth", "usdt");
+        perpetual_eth.kind = InstrumentKind::Perpetual(PerpetualContract {
+            contract_size: Decimal::ONE,
+            settlement_asset: asset("usdt"),
+        });
+        let mut perpetual_btc = instrument(ExchangeId::Okx, "btc", "usdt");
+        perpetual_btc.kind = perpetual_eth.kind.clone();
+
+        let instruments = IndexedInstruments::new([
+            instrument(ExchangeId::BinanceSpot, "btc", "usdt"),
+            perpetual_eth,
+            perpetual_btc,
+        ]);
+
+        let mut state = EngineState::builder(&instruments, DefaultGlobalData, |_| {
+            DefaultInstrumentMarketData::default()
+        })
+        .time_engine_start(DateTime::<Utc>::MIN_UTC)
+        .build();
+
+        let filter = InstrumentFilter::kinds([InstrumentKindTag::Perpetual]);
+
+        let selected = state
+            .instruments
+            .instruments(&filter)
+            .map(|state| state.key)
+            .collect::<Vec<_>>();
+        assert_eq!(selected, vec![InstrumentIndex(1), InstrumentIndex(2)]);
+
+        let selected_mut = state
+            .instruments
+            .instruments_mut(&filter)
+            .map(|state| state.key)
+            .collect::<Vec<_>>();
+        assert_eq!(selected_mut, selected);
+
+        let spot = InstrumentFilter::kinds([InstrumentKindTag::Spot]);
+        assert_eq!(
+            state
+                .instruments
+                .instruments(&spot)
+                .map(|state| state.key)
+                .collect::<Vec<_>>(),
+            vec![InstrumentIndex(0)]
+        );
+    }
+}
@@ -0,0 +1,41 @@
+//! 仓位最大持有时间检测模块
+//!
+//! 本模块定义了仓位持有时间检测，用于在仓位持有时间超过配置的上限时自动平仓（例如防止
+//! 策略缺陷导致仓位被无限期持有）。
+//!
+//! # 工作原理
+//!
+//! 启用 `Engine::with_max_position_age` 后，`Engine` 每处理一个事件都会使用
+//! `EngineClock::time()` 检查每个仓位的 [`Position::time_enter`]：
+//!
+//! 1. **检测**: 持有时间不短于最大持有时间的仓位被视为超时
+//! 2. **平仓**: 使用 Strategy 的 `ClosePositionsStrategy` 为超时仓位所属的交易对生成并发送平仓订单
+//! 3. **审计**: 平仓输出通过 `EngineOutput::PositionAgeExceeded` 出现在 Engine 审计中
+//!
+//! 交易对存在任何活跃订单时（例如已发送的平仓订单尚未确认，或已确认但尚未成交）不会重复
+//! 平仓，直到这些订单成交或被取消。
+//!
+//! [`Position::time_enter`]: crate::engine::state::position::Position::time_enter
+
+use crate::engine::state::instrument::InstrumentState;
+use chrono::{DateTime, TimeDelta, Utc};
+
+impl<InstrumentData> InstrumentState<InstrumentData> {
+    /// 如果交易对有仓位在 `time` 时持有时间已达到 `max_age`，且交易对没有活跃订单，返回 `true`。
+    ///
+    /// 如果 `time_enter + max_age` 溢出（例如 `max_age` 极大），仓位永远不会被视为超时。
+    ///
+    /// # 参数
+    ///
+    /// - `time`: 当前 `EngineClock` 时间
+    /// - `max_age`: 仓位的最大持有时间
+    pub fn position_age_exceeded(&self, time: DateTime<Utc>, max_age: TimeDelta) -> bool {
+        self.orders.active.is_empty()
+            && self.position.positions().any(|position| {
+                position
+                    .time_enter
+                    .checked_add_signed(max_age)
+                    .is_some_and(|deadline| deadline <= time)
+            })
+    }
+}
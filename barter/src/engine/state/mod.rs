@@ -29,13 +29,14 @@
 use crate::engine::{
     Processor,
     state::{
-        asset::{AssetStates, filter::AssetFilter},
+        asset::{AssetStates, filter::AssetFilter, reservation::InstrumentReserved},
         builder::EngineStateBuilder,
         connectivity::ConnectivityStates,
         instrument::{
             InstrumentStates, data::InstrumentDataState, filter::InstrumentFilter,
             generate_unindexed_instrument_account_snapshot,
         },
+        order::in_flight_timeout::InFlightTimeouts,
         position::PositionExited,
         trading::TradingState,
     },
@@ -52,6 +53,7 @@ use barter_instrument::{
     instrument::{Instrument, InstrumentIndex},
 };
 use barter_integration::{collection::one_or_many::OneOrMany, snapshot::Snapshot};
+use chrono::{DateTime, Utc};
 use derive_more::Constructor;
 use fnv::FnvHashMap;
 use serde::{Deserialize, Serialize};
@@ -81,6 +83,9 @@ pub mod builder;
 /// 定义默认的 `GlobalData` 实现，可用于不需要特定全局数据的系统。
 pub mod global;
 
+/// 带模式版本号的 [`EngineState`] 持久化（序列化与加载）工具。
+pub mod persist;
+
 /// 算法交易 `Engine` 的状态。
 ///
 /// EngineState 是 Engine 的核心数据结构，维护了所有交易相关的状态信息。它包含了
@@ -134,7 +139,7 @@ pub mod global;
 /// ```
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Constructor)]
 pub struct EngineState<GlobalData, InstrumentData> {
-    /// 当前 `Engine` 的 `TradingState`（交易启用/禁用）。
+    /// 当前 `Engine` 的 `TradingState`（交易启用/仅被动/禁用）。
     pub trading: TradingState,
 
     /// 可配置的 `GlobalData` 状态（用户自定义的全局数据）。
@@ -148,6 +153,10 @@ pub struct EngineState<GlobalData, InstrumentData> {
 
     /// 被 `Engine` 跟踪的每个交易对的状态（例如 "okx_spot_btc_usdt", "bybit_perpetual_btc_usdt" 等）。
     pub instruments: InstrumentStates<InstrumentData, ExchangeIndex, AssetIndex, InstrumentIndex>,
+
+    /// 在途请求的发送时间，用于检测超时未确认的在途请求（参见 `Engine::with_in_flight_timeout`）。
+    #[serde(default)]
+    pub in_flight_timeouts: InFlightTimeouts,
 }
 
 impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
@@ -193,6 +202,67 @@ impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
         EngineStateBuilder::new(instruments, global, instrument_data_init)
     }
 
+    /// 返回按 [`ExchangeId`] 分组的 `InstrumentState` 迭代器。
+    ///
+    /// 交易所按 `ExchangeIndex` 顺序返回，每组内的 `InstrumentState` 保持插入顺序。
+    /// `ExchangeIndex` 通过 [`ConnectivityStates`]（按 `ExchangeIndex` 顺序存储交易所）映射为
+    /// [`ExchangeId`]。参见 [`InstrumentStates::group_by_exchange`]。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// for (exchange, instruments) in state.instruments_by_exchange() {
+    ///     let open_orders = instruments.map(|state| state.orders.active.len()).sum::<usize>();
+    /// }
+    /// ```
+    pub fn instruments_by_exchange(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            ExchangeId,
+            impl Iterator<Item = &instrument::InstrumentState<InstrumentData>>,
+        ),
+    > {
+        self.instruments
+            .group_by_exchange()
+            .map(|(exchange, instruments)| {
+                let (exchange, _) = self
+                    .connectivity
+                    .exchanges
+                    .get_index(exchange.index())
+                    .unwrap_or_else(|| {
+                        panic!("EngineState does not contain ConnectivityState for: {exchange}")
+                    });
+
+                (*exchange, instruments)
+            })
+    }
+
+    /// 设置统计预热截止时间，在此之前的交易和余额不计入统计。
+    ///
+    /// Engine 仍会处理预热期间的事件以构建状态（例如指标和仓位），但：
+    /// - 在 `time_warmup_end` 之前进入的仓位不计入交易对的 [`TearSheetGenerator`](crate::statistic::summary::instrument::TearSheetGenerator)
+    /// - 在 `time_warmup_end` 之前的余额仅用于重新初始化资产的回撤统计
+    ///
+    /// # 参数
+    ///
+    /// - `time_warmup_end`: 预热截止时间，统计从此时开始
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let state = state.with_warmup(time_first_event + TimeDelta::days(30));
+    /// ```
+    pub fn with_warmup(mut self, time_warmup_end: DateTime<Utc>) -> Self {
+        self.instruments.0.values_mut().for_each(|state| {
+            state.tear_sheet = state.tear_sheet.clone().with_warmup(time_warmup_end);
+        });
+        self.assets.0.values_mut().for_each(|state| {
+            state.statistics = state.statistics.clone().with_warmup(time_warmup_end);
+        });
+        self
+    }
+
     /// 从 `AccountEvent` 更新内部状态。
     ///
     /// 此方法处理账户事件，更新相关的状态（连接状态、资产状态、交易对状态等）。
@@ -246,6 +316,9 @@ impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
         // 如果账户连接之前处于重连状态，将其设置为健康状态
         self.connectivity.update_from_account_event(&event.exchange);
 
+        // 记录可能改变在途开仓请求的交易对当前的预留余额
+        let reserved = self.account_event_reserved(&event.kind);
+
         // 根据事件类型更新相应的状态
         let output = match &event.kind {
             AccountEventKind::Snapshot(snapshot) => {
@@ -302,12 +375,66 @@ impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
             }
         };
 
+        // 释放不再处于在途状态的开仓请求预留的余额
+        for (instrument, before) in reserved {
+            self.update_reserved(&instrument, before);
+        }
+
         // 更新用户自定义的全局数据状态
         self.global.process(event);
 
         output
     }
 
+    /// 返回交易对的在途开仓请求当前预留的余额。
+    fn instrument_reserved(&self, instrument: &InstrumentIndex) -> InstrumentReserved {
+        let state = self.instruments.instrument_index(instrument);
+        InstrumentReserved::in_flight(&state.instrument, &state.orders)
+    }
+
+    /// 返回可能被 [`AccountEventKind`] 改变订单状态的每个交易对当前的预留余额。
+    fn account_event_reserved(
+        &self,
+        kind: &AccountEventKind<ExchangeIndex, AssetIndex, InstrumentIndex>,
+    ) -> Vec<(InstrumentIndex, InstrumentReserved)> {
+        let reserved =
+            |instrument: InstrumentIndex| (instrument, self.instrument_reserved(&instrument));
+
+        match kind {
+            AccountEventKind::Snapshot(snapshot) => snapshot
+                .instruments
+                .iter()
+                .map(|instrument| reserved(instrument.instrument))
+                .collect(),
+            AccountEventKind::OrderSnapshot(order) => vec![reserved(order.value().key.instrument)],
+            AccountEventKind::OrderCancelled(response) => vec![reserved(response.key.instrument)],
+            AccountEventKind::BalanceSnapshot(_) | AccountEventKind::Trade(_) => vec![],
+        }
+    }
+
+    /// 将交易对在途开仓请求预留余额相对于 `before` 的变化应用到其基础资产和计价资产的
+    /// [`AssetState`](asset::AssetState)。
+    fn update_reserved(&mut self, instrument: &InstrumentIndex, before: InstrumentReserved) {
+        let after = self.instrument_reserved(instrument);
+        if after == before {
+            return;
+        }
+
+        let underlying = &self
+            .instruments
+            .instrument_index(instrument)
+            .instrument
+            .underlying;
+        let (base, quote) = (underlying.base, underlying.quote);
+
+        self.assets
+            .asset_index_mut(&base)
+            .update_reserved(after.base - before.base);
+        self.assets
+            .asset_index_mut(&quote)
+            .update_reserved(after.quote - before.quote);
+    }
+
     /// 从 `MarketEvent` 更新内部状态。
     ///
     /// 此方法处理市场事件，更新连接状态、全局数据和交易对数据。
@@ -316,7 +443,7 @@ impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
     ///
     /// 1. **更新连接状态**: 如果市场数据连接之前处于重连状态，将其设置为健康状态
     /// 2. **更新全局数据**: 使用市场事件更新用户自定义的全局数据
-    /// 3. **更新交易对数据**: 使用市场事件更新对应交易对的数据
+    /// 3. **更新交易对数据**: 使用市场事件更新对应交易对的数据，并重新计算开放仓位的未实现盈亏
     ///
     /// ## 与账户事件的区别
     ///
@@ -357,9 +484,16 @@ impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
         // 获取对应的交易对状态
         let instrument_state = self.instruments.instrument_index_mut(&event.instrument);
 
-        // 更新全局数据和交易对数据
+        // 更新全局数据和交易对数据（包括开放仓位的未实现盈亏）
         self.global.process(event);
-        instrument_state.data.process(event);
+        instrument_state.update_from_market(event);
+    }
+}
+
+impl<GlobalData, InstrumentData> AsRef<TradingState> for EngineState<GlobalData, InstrumentData> {
+    /// 返回当前 `Engine` 的 [`TradingState`]，供通用的 Engine 操作（例如生成算法订单）读取。
+    fn as_ref(&self) -> &TradingState {
+        &self.trading
     }
 }
 
@@ -410,6 +544,7 @@ impl<GlobalData, InstrumentData> From<&EngineState<GlobalData, InstrumentData>>
             connectivity,
             assets,
             instruments,
+            in_flight_timeouts: _,
         } = value;
 
         // 根据交易所数量预分配容量
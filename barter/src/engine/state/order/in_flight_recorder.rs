@@ -1,5 +1,8 @@
-use crate::engine::state::EngineState;
-use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use crate::engine::state::{EngineState, instrument::filter::InstrumentFilter};
+use barter_execution::order::{
+    OrderKey,
+    request::{OrderRequestCancel, OrderRequestOpen},
+};
 use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
 
 /// Synchronous in-flight open and in-flight cancel order request tracker.
@@ -33,6 +36,15 @@ pub trait InFlightRequestRecorder<ExchangeKey = ExchangeIndex, InstrumentKey = I
     fn record_in_flight_cancel(&mut self, request: &OrderRequestCancel<ExchangeKey, InstrumentKey>);
 
     fn record_in_flight_open(&mut self, request: &OrderRequestOpen<ExchangeKey, InstrumentKey>);
+
+    /// Returns `true` if the [`ClientOrderId`](barter_execution::order::id::ClientOrderId) of
+    /// the provided [`OrderKey`] is already used by a tracked active order, meaning recording a
+    /// new request with it would collide.
+    ///
+    /// Defaults to `false` for recorders that do not track active orders.
+    fn is_active_cid(&self, _key: &OrderKey<ExchangeKey, InstrumentKey>) -> bool {
+        false
+    }
 }
 
 impl<GlobalData, InstrumentData> InFlightRequestRecorder<ExchangeIndex, InstrumentIndex>
@@ -44,23 +56,39 @@ where
         &mut self,
         request: &OrderRequestCancel<ExchangeIndex, InstrumentIndex>,
     ) {
+        let reserved = self.instrument_reserved(&request.key.instrument);
+
         let instrument_state = self
             .instruments
             .instrument_index_mut(&request.key.instrument);
 
         instrument_state.orders.record_in_flight_cancel(request);
         instrument_state.data.record_in_flight_cancel(request);
+
+        self.update_reserved(&request.key.instrument, reserved);
     }
 
     fn record_in_flight_open(
         &mut self,
         request: &OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
     ) {
+        let reserved = self.instrument_reserved(&request.key.instrument);
+
         let instrument_state = self
             .instruments
             .instrument_index_mut(&request.key.instrument);
 
         instrument_state.orders.record_in_flight_open(request);
         instrument_state.data.record_in_flight_open(request);
+
+        // 为在途开仓请求预留余额，防止后续开仓请求超额使用同一余额
+        self.update_reserved(&request.key.instrument, reserved);
+    }
+
+    fn is_active_cid(&self, key: &OrderKey<ExchangeIndex, InstrumentIndex>) -> bool {
+        // ClientOrderId 在交易所账户内必须唯一，因此检查所有交易对的活跃订单
+        self.instruments
+            .instruments(&InstrumentFilter::None)
+            .any(|state| state.orders.is_active_cid(key))
     }
 }
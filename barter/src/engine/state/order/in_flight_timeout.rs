@@ -0,0 +1,249 @@
+//! 在途请求超时检测模块
+//!
+//! 本模块定义了 [`InFlightTimeouts`]，用于记录在途开仓和在途取消请求的发送时间，并在
+//! 请求超过可配置的超时时间仍未收到交易所确认时生成 [`InFlightTimeout`] 信号。
+//!
+//! # 工作原理
+//!
+//! 1. **同步**: 每次同步时，新出现的在途订单以当前 `EngineClock` 时间记录，已离开在途状态
+//!    （已确认、已取消等）的订单被移除
+//! 2. **检测**: 在途时间达到超时时间的请求生成一次 [`InFlightTimeout`]，之后不会重复生成，
+//!    直到该订单离开在途状态
+//!
+//! # 使用场景
+//!
+//! - 检测交易所从未确认的订单请求，防止订单静默丢失
+//! - 让 Strategy 根据 [`InFlightTimeout`] 审计重试请求或发出告警
+
+use barter_execution::order::{
+    Order, OrderKey,
+    id::ClientOrderId,
+    state::{ActiveOrderState, CancelInFlight, OpenInFlight},
+};
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
+use fnv::FnvHashMap;
+use serde::{Deserialize, Serialize};
+
+/// 在途请求类型。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum InFlightRequestKind {
+    /// 在途开仓请求（[`OpenInFlight`]）
+    Open,
+    /// 在途取消请求（[`CancelInFlight`]）
+    Cancel,
+}
+
+impl InFlightRequestKind {
+    /// 返回 [`ActiveOrderState`] 对应的在途请求类型，如果订单不在途则返回 `None`。
+    pub fn from_state(state: &ActiveOrderState) -> Option<Self> {
+        match state {
+            ActiveOrderState::OpenInFlight(OpenInFlight) => Some(Self::Open),
+            ActiveOrderState::CancelInFlight(CancelInFlight { .. }) => Some(Self::Cancel),
+            ActiveOrderState::Open(_) => None,
+        }
+    }
+}
+
+/// 在途请求超过超时时间仍未收到交易所确认的信号。
+///
+/// 每个在途请求最多生成一次，通过 `EngineOutput::InFlightTimeout` 出现在 Engine 审计中。
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct InFlightTimeout<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    /// 在途订单的 [`OrderKey`]
+    pub key: OrderKey<ExchangeKey, InstrumentKey>,
+    /// 在途请求类型
+    pub kind: InFlightRequestKind,
+    /// 请求发送时间（首次记录为在途的 `EngineClock` 时间）
+    pub time_sent: DateTime<Utc>,
+    /// 检测到超时的 `EngineClock` 时间
+    pub time_timeout: DateTime<Utc>,
+}
+
+/// 在途请求发送时间跟踪器。
+///
+/// 以 [`ClientOrderId`] 为键记录每个在途请求的类型和发送时间，详见模块文档。
+#[derive(Debug, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct InFlightTimeouts(FnvHashMap<ClientOrderId, InFlightRequestTime>);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize)]
+struct InFlightRequestTime {
+    kind: InFlightRequestKind,
+    time_sent: DateTime<Utc>,
+    timed_out: bool,
+}
+
+impl InFlightTimeouts {
+    /// 返回当前跟踪的在途请求数量。
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// 如果当前没有跟踪任何在途请求则返回 `true`。
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// 返回在途请求的发送时间（如果正在跟踪）。
+    pub fn time_sent(&self, cid: &ClientOrderId) -> Option<DateTime<Utc>> {
+        self.0.get(cid).map(|request| request.time_sent)
+    }
+
+    /// 将跟踪器与当前活跃订单同步。
+    ///
+    /// - 新的在途请求（包括从在途开仓转换为在途取消的订单）以 `time` 记录
+    /// - 不再在途的订单被移除
+    ///
+    /// # 参数
+    ///
+    /// - `orders`: 所有活跃订单
+    /// - `time`: 当前 `EngineClock` 时间
+    pub fn sync<'a, ExchangeKey, InstrumentKey>(
+        &mut self,
+        orders: impl IntoIterator<Item = &'a Order<ExchangeKey, InstrumentKey, ActiveOrderState>>,
+        time: DateTime<Utc>,
+    ) where
+        ExchangeKey: 'a,
+        InstrumentKey: 'a,
+    {
+        let mut requests = FnvHashMap::with_capacity_and_hasher(self.0.len(), Default::default());
+
+        for order in orders {
+            let Some(kind) = InFlightRequestKind::from_state(&order.state) else {
+                continue;
+            };
+
+            let request = match self.0.get(&order.key.cid) {
+                Some(request) if request.kind == kind => *request,
+                _ => InFlightRequestTime {
+                    kind,
+                    time_sent: time,
+                    timed_out: false,
+                },
+            };
+
+            requests.insert(order.key.cid.clone(), request);
+        }
+
+        self.0 = requests;
+    }
+
+    /// 返回在 `time` 时在途时间达到 `timeout` 的在途请求，每个请求仅返回一次。
+    ///
+    /// 应在 [`Self::sync`] 之后调用，以确保已确认的请求不会被误报。
+    ///
+    /// # 参数
+    ///
+    /// - `orders`: 所有活跃订单，用于查找超时请求的 [`OrderKey`]
+    /// - `time`: 当前 `EngineClock` 时间
+    /// - `timeout`: 在途请求超时时间
+    pub fn timed_out<'a, ExchangeKey, InstrumentKey>(
+        &mut self,
+        orders: impl IntoIterator<Item = &'a Order<ExchangeKey, InstrumentKey, ActiveOrderState>>,
+        time: DateTime<Utc>,
+        timeout: TimeDelta,
+    ) -> Vec<InFlightTimeout<ExchangeKey, InstrumentKey>>
+    where
+        ExchangeKey: Clone + 'a,
+        InstrumentKey: Clone + 'a,
+    {
+        orders
+            .into_iter()
+            .filter_map(|order| {
+                let request = self.0.get_mut(&order.key.cid)?;
+
+                if request.timed_out || time - request.time_sent < timeout {
+                    return None;
+                }
+
+                request.timed_out = true;
+
+                Some(InFlightTimeout {
+                    key: order.key.clone(),
+                    kind: request.kind,
+                    time_sent: request.time_sent,
+                    time_timeout: time,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::time_plus_secs;
+    use barter_execution::order::{
+        OrderKind, TimeInForce,
+        id::{OrderId, StrategyId},
+        state::Open,
+    };
+    use barter_instrument::Side;
+    use rust_decimal_macros::dec;
+
+    fn order(
+        cid: &str,
+        state: ActiveOrderState,
+    ) -> Order<ExchangeIndex, InstrumentIndex, ActiveOrderState> {
+        Order {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            side: Side::Buy,
+            price: dec!(1),
+            quantity: dec!(1),
+            kind: OrderKind::Limit,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+            reduce_only: false,
+            state,
+        }
+    }
+
+    #[test]
+    fn test_in_flight_timeouts() {
+        let base = DateTime::<Utc>::MIN_UTC;
+        let timeout = TimeDelta::seconds(10);
+        let mut timeouts = InFlightTimeouts::default();
+
+        let open = order("open", ActiveOrderState::OpenInFlight(OpenInFlight));
+        let acked = order("acked", ActiveOrderState::OpenInFlight(OpenInFlight));
+        timeouts.sync([&open, &acked], base);
+        assert_eq!(timeouts.len(), 2);
+
+        // Order "acked" is acknowledged, so is no longer tracked
+        let acked = order(
+            "acked",
+            ActiveOrderState::Open(Open::new(OrderId::new("id"), base, dec!(0))),
+        );
+        timeouts.sync([&open, &acked], time_plus_secs(base, 5));
+        assert_eq!(timeouts.len(), 1);
+        assert_eq!(timeouts.time_sent(&ClientOrderId::new("open")), Some(base));
+
+        // Not yet timed out
+        assert!(
+            timeouts
+                .timed_out([&open, &acked], time_plus_secs(base, 9), timeout)
+                .is_empty()
+        );
+
+        // Timed out exactly once
+        let time = time_plus_secs(base, 10);
+        assert_eq!(
+            timeouts.timed_out([&open, &acked], time, timeout),
+            vec![InFlightTimeout {
+                key: open.key.clone(),
+                kind: InFlightRequestKind::Open,
+                time_sent: base,
+                time_timeout: time,
+            }]
+        );
+        assert!(
+            timeouts
+                .timed_out([&open, &acked], time_plus_secs(base, 20), timeout)
+                .is_empty()
+        );
+    }
+}
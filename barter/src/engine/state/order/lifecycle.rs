@@ -0,0 +1,364 @@
+//! 订单生命周期日志模块
+//!
+//! 本模块定义了 [`OrderLifecycleLog`]，一个可选的、有界的逐订单生命周期历史记录，
+//! 由 [`Orders`](super::Orders) 在处理在途请求、订单快照与取消响应时填充。
+//!
+//! # 核心概念
+//!
+//! - **OrderLifecycleConfig**: 生命周期日志配置（最大订单数、每个订单最大事件数）
+//! - **OrderLifecycleStage**: 订单生命周期阶段（Requested -> Open -> PartiallyFilled -> ...）
+//! - **OrderLifecycleEvent**: 单个生命周期事件，包含阶段、交易所时间戳和已成交数量
+//! - **OrderLifecycle**: 单个订单的有界生命周期事件历史
+//!
+//! # 内存开销
+//!
+//! 生命周期日志默认关闭，仅在通过 [`Orders::with_lifecycle_log`](super::Orders::with_lifecycle_log)
+//! 启用后才会分配内存。超出配置上限时，最早记录的订单（或事件）会被淘汰。
+
+use barter_execution::order::{
+    OrderKey,
+    id::ClientOrderId,
+    state::{ActiveOrderState, InactiveOrderState},
+};
+use barter_integration::collection::FnvIndexMap;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// 订单生命周期日志配置。
+///
+/// ## 字段说明
+///
+/// - **max_orders**: 保留生命周期历史的最大订单数，超出时淘汰最早记录的订单
+/// - **max_events_per_order**: 每个订单保留的最大事件数，超出时淘汰最早的事件
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub struct OrderLifecycleConfig {
+    /// 保留生命周期历史的最大订单数
+    pub max_orders: usize,
+    /// 每个订单保留的最大事件数
+    pub max_events_per_order: usize,
+}
+
+impl Default for OrderLifecycleConfig {
+    fn default() -> Self {
+        Self {
+            max_orders: 1024,
+            max_events_per_order: 32,
+        }
+    }
+}
+
+/// 订单生命周期阶段。
+///
+/// 订单通常按以下阶段转换：
+/// `Requested -> Open -> PartiallyFilled -> FullyFilled`，
+/// 或 `... -> CancelRequested -> Cancelled`。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum OrderLifecycleStage {
+    /// 开仓请求已发送到交易所
+    Requested,
+    /// 订单已在交易所确认开仓，且尚未成交
+    Open,
+    /// 订单已部分成交
+    PartiallyFilled,
+    /// 取消请求已发送到交易所
+    CancelRequested,
+    /// 交易所拒绝了取消请求
+    CancelRejected,
+    /// 订单已完全成交（终止状态）
+    FullyFilled,
+    /// 订单已取消（终止状态）
+    Cancelled,
+    /// 订单已过期（终止状态）
+    Expired,
+    /// 订单开仓失败（终止状态）
+    OpenFailed,
+}
+
+impl OrderLifecycleStage {
+    /// 如果该阶段为终止状态则返回 `true`。
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::FullyFilled | Self::Cancelled | Self::Expired | Self::OpenFailed
+        )
+    }
+}
+
+/// 单个订单生命周期事件。
+///
+/// ## 字段说明
+///
+/// - **stage**: 生命周期阶段
+/// - **time_exchange**: 交易所时间戳（如果该阶段由交易所提供时间，例如 Open 或 Cancelled）
+/// - **filled_quantity**: 该阶段时已知的已成交数量
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct OrderLifecycleEvent {
+    /// 生命周期阶段
+    pub stage: OrderLifecycleStage,
+    /// 交易所时间戳（如果可用）
+    pub time_exchange: Option<DateTime<Utc>>,
+    /// 已成交数量
+    pub filled_quantity: Decimal,
+}
+
+impl OrderLifecycleEvent {
+    /// 构造一个没有交易所时间戳的生命周期事件。
+    pub fn new(stage: OrderLifecycleStage, filled_quantity: Decimal) -> Self {
+        Self {
+            stage,
+            time_exchange: None,
+            filled_quantity,
+        }
+    }
+
+    /// 根据 [`ActiveOrderState`] 与订单总数量构造生命周期事件。
+    ///
+    /// `Open` 状态根据已成交数量映射为 `Open`、`PartiallyFilled` 或 `FullyFilled`。
+    pub fn from_active(state: &ActiveOrderState, quantity: Decimal) -> Self {
+        match state {
+            ActiveOrderState::OpenInFlight(_) => {
+                Self::new(OrderLifecycleStage::Requested, Decimal::ZERO)
+            }
+            ActiveOrderState::Open(open) => {
+                let stage = if open.filled_quantity.is_zero() {
+                    OrderLifecycleStage::Open
+                } else if open.quantity_remaining(quantity).is_zero() {
+                    OrderLifecycleStage::FullyFilled
+                } else {
+                    OrderLifecycleStage::PartiallyFilled
+                };
+
+                Self {
+                    stage,
+                    time_exchange: Some(open.time_exchange),
+                    filled_quantity: open.filled_quantity,
+                }
+            }
+            ActiveOrderState::CancelInFlight(cancel) => Self::new(
+                OrderLifecycleStage::CancelRequested,
+                cancel
+                    .order
+                    .as_ref()
+                    .map_or(Decimal::ZERO, |open| open.filled_quantity),
+            ),
+        }
+    }
+
+    /// 根据 [`InactiveOrderState`] 构造终止生命周期事件。
+    ///
+    /// `filled_quantity` 为该订单此前已知的已成交数量（`FullyFilled` 时为订单总数量）。
+    pub fn from_inactive<AssetKey, InstrumentKey>(
+        state: &InactiveOrderState<AssetKey, InstrumentKey>,
+        filled_quantity: Decimal,
+    ) -> Self {
+        match state {
+            InactiveOrderState::Cancelled(cancelled) => Self {
+                stage: OrderLifecycleStage::Cancelled,
+                time_exchange: Some(cancelled.time_exchange),
+                filled_quantity,
+            },
+            InactiveOrderState::FullyFilled => {
+                Self::new(OrderLifecycleStage::FullyFilled, filled_quantity)
+            }
+            InactiveOrderState::OpenFailed(_) => {
+                Self::new(OrderLifecycleStage::OpenFailed, filled_quantity)
+            }
+            InactiveOrderState::Expired => Self::new(OrderLifecycleStage::Expired, filled_quantity),
+        }
+    }
+}
+
+/// 单个订单的有界生命周期事件历史。
+///
+/// ## 字段说明
+///
+/// - **key**: 订单键
+/// - **events**: 按记录顺序排列的生命周期事件
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct OrderLifecycle<ExchangeKey, InstrumentKey> {
+    /// 订单键
+    pub key: OrderKey<ExchangeKey, InstrumentKey>,
+    /// 按记录顺序排列的生命周期事件
+    pub events: VecDeque<OrderLifecycleEvent>,
+}
+
+impl<ExchangeKey, InstrumentKey> OrderLifecycle<ExchangeKey, InstrumentKey> {
+    /// 返回最近记录的生命周期事件。
+    pub fn latest(&self) -> Option<&OrderLifecycleEvent> {
+        self.events.back()
+    }
+
+    /// 返回按记录顺序排列的生命周期阶段迭代器。
+    pub fn stages(&self) -> impl Iterator<Item = OrderLifecycleStage> + '_ {
+        self.events.iter().map(|event| event.stage)
+    }
+}
+
+/// 有界的逐订单生命周期日志，可通过 [`OrderKey`] 或 [`ClientOrderId`] 查询。
+///
+/// 订单在 [`Orders`](super::Orders) 中到达终止状态并被移除后，其生命周期历史仍会被保留，
+/// 直到被更新的订单淘汰。这便于对账与 UI 展示。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let orders = Orders::default().with_lifecycle_log(OrderLifecycleConfig::default());
+///
+/// // ... 处理在途请求、订单快照与取消响应 ...
+///
+/// if let Some(lifecycle) = orders.lifecycle_log().and_then(|log| log.by_cid(&cid)) {
+///     println!("{:?}", lifecycle.stages().collect::<Vec<_>>());
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct OrderLifecycleLog<ExchangeKey, InstrumentKey> {
+    /// 生命周期日志配置
+    pub config: OrderLifecycleConfig,
+    lifecycles: FnvIndexMap<ClientOrderId, OrderLifecycle<ExchangeKey, InstrumentKey>>,
+}
+
+impl<ExchangeKey, InstrumentKey> OrderLifecycleLog<ExchangeKey, InstrumentKey> {
+    /// 使用提供的配置构造一个空的生命周期日志。
+    pub fn new(config: OrderLifecycleConfig) -> Self {
+        Self {
+            config,
+            lifecycles: FnvIndexMap::default(),
+        }
+    }
+
+    /// 通过 [`ClientOrderId`] 查询订单生命周期。
+    pub fn by_cid(
+        &self,
+        cid: &ClientOrderId,
+    ) -> Option<&OrderLifecycle<ExchangeKey, InstrumentKey>> {
+        self.lifecycles.get(cid)
+    }
+
+    /// 通过 [`OrderKey`] 查询订单生命周期。
+    ///
+    /// 仅当记录的订单键与提供的订单键完全匹配时返回 `Some`。
+    pub fn by_key(
+        &self,
+        key: &OrderKey<ExchangeKey, InstrumentKey>,
+    ) -> Option<&OrderLifecycle<ExchangeKey, InstrumentKey>>
+    where
+        ExchangeKey: PartialEq,
+        InstrumentKey: PartialEq,
+    {
+        self.by_cid(&key.cid)
+            .filter(|lifecycle| lifecycle.key == *key)
+    }
+
+    /// 返回按首次记录顺序排列的所有订单生命周期迭代器。
+    pub fn lifecycles(&self) -> impl Iterator<Item = &OrderLifecycle<ExchangeKey, InstrumentKey>> {
+        self.lifecycles.values()
+    }
+
+    /// 返回保留生命周期历史的订单数。
+    pub fn len(&self) -> usize {
+        self.lifecycles.len()
+    }
+
+    /// 如果没有保留任何订单生命周期则返回 `true`。
+    pub fn is_empty(&self) -> bool {
+        self.lifecycles.is_empty()
+    }
+
+    /// 如果存在该 [`ClientOrderId`] 的生命周期历史则返回 `true`。
+    pub fn contains(&self, cid: &ClientOrderId) -> bool {
+        self.lifecycles.contains_key(cid)
+    }
+
+    /// 为提供的订单记录一个生命周期事件。
+    ///
+    /// 与最近记录事件完全相同的事件会被忽略，以避免重复快照造成噪声。
+    pub fn record(&mut self, key: &OrderKey<ExchangeKey, InstrumentKey>, event: OrderLifecycleEvent)
+    where
+        ExchangeKey: Clone,
+        InstrumentKey: Clone,
+    {
+        if self.config.max_orders == 0 || self.config.max_events_per_order == 0 {
+            return;
+        }
+
+        if !self.lifecycles.contains_key(&key.cid) {
+            while self.lifecycles.len() >= self.config.max_orders {
+                self.lifecycles.shift_remove_index(0);
+            }
+        }
+
+        let lifecycle = self
+            .lifecycles
+            .entry(key.cid.clone())
+            .or_insert_with(|| OrderLifecycle {
+                key: key.clone(),
+                events: VecDeque::new(),
+            });
+
+        if lifecycle.latest() == Some(&event) {
+            return;
+        }
+
+        if lifecycle.events.len() >= self.config.max_events_per_order {
+            lifecycle.events.pop_front();
+        }
+        lifecycle.events.push_back(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::order::id::StrategyId;
+    use barter_instrument::exchange::ExchangeId;
+    use rust_decimal_macros::dec;
+
+    fn key(cid: &str) -> OrderKey<ExchangeId, u64> {
+        OrderKey {
+            exchange: ExchangeId::Simulated,
+            instrument: 1,
+            strategy: StrategyId::unknown(),
+            cid: ClientOrderId::new(cid),
+        }
+    }
+
+    #[test]
+    fn test_order_lifecycle_log_bounds() {
+        let mut log = OrderLifecycleLog::new(OrderLifecycleConfig {
+            max_orders: 2,
+            max_events_per_order: 2,
+        });
+
+        let requested = OrderLifecycleEvent::new(OrderLifecycleStage::Requested, dec!(0));
+        let cancel = OrderLifecycleEvent::new(OrderLifecycleStage::CancelRequested, dec!(0));
+        let expired = OrderLifecycleEvent::new(OrderLifecycleStage::Expired, dec!(0));
+
+        // Duplicate events are ignored, oldest events evicted once full
+        log.record(&key("a"), requested);
+        log.record(&key("a"), requested);
+        log.record(&key("a"), cancel);
+        log.record(&key("a"), expired);
+        assert_eq!(
+            log.by_key(&key("a")).unwrap().stages().collect::<Vec<_>>(),
+            vec![
+                OrderLifecycleStage::CancelRequested,
+                OrderLifecycleStage::Expired
+            ]
+        );
+
+        // Oldest order evicted once full
+        log.record(&key("b"), requested);
+        log.record(&key("c"), requested);
+        assert_eq!(log.len(), 2);
+        assert!(!log.contains(&ClientOrderId::new("a")));
+        assert!(log.contains(&ClientOrderId::new("b")));
+        assert!(log.contains(&ClientOrderId::new("c")));
+
+        // OrderKey lookup requires the full key to match
+        let mut other_instrument = key("b");
+        other_instrument.instrument = 2;
+        assert!(log.by_key(&other_instrument).is_none());
+    }
+}
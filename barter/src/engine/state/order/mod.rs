@@ -7,6 +7,8 @@
 //! - **Orders**: 订单管理器，维护活跃订单的映射
 //! - **OrderManager**: 订单管理 Trait，定义订单管理接口
 //! - **InFlightRequestRecorder**: 在途请求记录器 Trait，记录在途订单请求
+//! - **InFlightTimeouts**: 在途请求超时检测器，标记长时间未收到确认的在途请求
+//! - **OrderLifecycleLog**: 可选的有界逐订单生命周期日志
 //!
 //! # 订单状态转换
 //!
@@ -23,23 +25,30 @@
 //! - 处理订单快照和取消响应
 
 use crate::engine::state::order::{
-    in_flight_recorder::InFlightRequestRecorder, manager::OrderManager,
+    in_flight_recorder::InFlightRequestRecorder,
+    lifecycle::{
+        OrderLifecycleConfig, OrderLifecycleEvent, OrderLifecycleLog, OrderLifecycleStage,
+    },
+    manager::OrderManager,
 };
 use barter_execution::order::{
-    Order,
+    Order, OrderKey,
     id::ClientOrderId,
     request::{OrderRequestCancel, OrderRequestOpen, OrderResponseCancel},
-    state::{ActiveOrderState, CancelInFlight, OrderState},
+    state::{ActiveOrderState, CancelInFlight, InactiveOrderState, OrderState},
 };
 use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
 use barter_integration::snapshot::Snapshot;
 use derive_more::Constructor;
 use fnv::FnvHashMap;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::{collections::hash_map::Entry, fmt::Debug};
 use tracing::{debug, error, warn};
 
 pub mod in_flight_recorder;
+pub mod in_flight_timeout;
+pub mod lifecycle;
 pub mod manager;
 
 /// 同步订单管理器，跟踪活跃交易所订单的生命周期。
@@ -83,43 +92,71 @@ pub mod manager;
 /// orders.update_from_cancel_response(&cancel_response);
 /// ```
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Constructor)]
-pub struct Orders<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex>(
-    /// 以 ClientOrderId 为键的订单映射
-    pub FnvHashMap<ClientOrderId, Order<ExchangeKey, InstrumentKey, ActiveOrderState>>,
-);
+#[serde(default)]
+pub struct Orders<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    /// 以 ClientOrderId 为键的活跃订单映射
+    pub active: FnvHashMap<ClientOrderId, Order<ExchangeKey, InstrumentKey, ActiveOrderState>>,
+    /// 可选的订单生命周期日志，默认关闭以避免内存开销
+    pub lifecycle: Option<OrderLifecycleLog<ExchangeKey, InstrumentKey>>,
+}
 
 impl<ExchangeKey, InstrumentKey> Default for Orders<ExchangeKey, InstrumentKey> {
     fn default() -> Self {
-        Self(FnvHashMap::default())
+        Self {
+            active: FnvHashMap::default(),
+            lifecycle: None,
+        }
     }
 }
 
-impl<ExchangeKey, InstrumentKey> OrderManager<ExchangeKey, InstrumentKey>
-    for Orders<ExchangeKey, InstrumentKey>
+impl<ExchangeKey, InstrumentKey> Orders<ExchangeKey, InstrumentKey> {
+    /// 启用有界的逐订单生命周期日志。
+    ///
+    /// 启用后，每个订单的生命周期（Requested -> Open -> PartiallyFilled ->
+    /// FullyFilled/Cancelled 等）都会被记录，并可通过 [`Self::lifecycle_log`] 查询。
+    ///
+    /// # 参数
+    ///
+    /// - `config`: 生命周期日志配置
+    ///
+    /// # 返回值
+    ///
+    /// 返回启用了生命周期日志的 `Orders`。
+    pub fn with_lifecycle_log(self, config: OrderLifecycleConfig) -> Self {
+        Self {
+            lifecycle: Some(OrderLifecycleLog::new(config)),
+            ..self
+        }
+    }
+
+    /// 返回订单生命周期日志（如果已启用）。
+    pub fn lifecycle_log(&self) -> Option<&OrderLifecycleLog<ExchangeKey, InstrumentKey>> {
+        self.lifecycle.as_ref()
+    }
+}
+
+impl<ExchangeKey, InstrumentKey> Orders<ExchangeKey, InstrumentKey>
 where
     ExchangeKey: Debug + Clone,
     InstrumentKey: Debug + Clone,
 {
-    fn orders<'a>(
-        &'a self,
-    ) -> impl Iterator<Item = &'a Order<ExchangeKey, InstrumentKey, ActiveOrderState>>
-    where
-        ExchangeKey: 'a,
-        InstrumentKey: 'a,
-    {
-        self.0.values()
+    fn filled_quantity(&self, cid: &ClientOrderId) -> Option<Decimal> {
+        self.active.get(cid).map(|order| {
+            order
+                .state
+                .open_meta()
+                .map_or(Decimal::ZERO, |open| open.filled_quantity)
+        })
     }
 
-    fn update_from_order_snapshot<AssetKey>(
+    fn apply_order_snapshot<AssetKey>(
         &mut self,
-        snapshot: Snapshot<&Order<ExchangeKey, InstrumentKey, OrderState<AssetKey, InstrumentKey>>>,
+        snapshot: &Order<ExchangeKey, InstrumentKey, OrderState<AssetKey, InstrumentKey>>,
     ) where
         AssetKey: Debug + Clone,
     {
-        let Snapshot(snapshot) = snapshot;
-
         let (mut current_entry, update) = match (
-            self.0.entry(snapshot.key.cid.clone()),
+            self.active.entry(snapshot.key.cid.clone()),
             snapshot.to_active(),
         ) {
             // Order untracked, input Snapshot is InactiveOrderState (ie/ finished), so ignore
@@ -165,6 +202,27 @@ where
                 return;
             }
 
+            // Order tracked, input Snapshot is InactiveOrderState but older than the tracked
+            // state (eg/ out of sequence on reconnect), so ignore
+            (Entry::Occupied(entry), None)
+                if entry
+                    .get()
+                    .state
+                    .open_meta()
+                    .zip(snapshot.state.time_exchange())
+                    .is_some_and(|(current, update)| current.time_exchange > update) =>
+            {
+                debug!(
+                    exchange = ?snapshot.key.exchange,
+                    instrument = ?snapshot.key.instrument,
+                    strategy = %snapshot.key.strategy,
+                    cid = %snapshot.key.cid,
+                    update = ?snapshot,
+                    "OrderManager received an out of sequence inactive order snapshot - ignoring"
+                );
+                return;
+            }
+
             // Order tracked, input Snapshot is InactiveOrderState (ie/ finished), so remove
             (Entry::Occupied(entry), None) => {
                 debug!(
@@ -318,6 +376,74 @@ where
             }
         }
     }
+}
+
+impl<ExchangeKey, InstrumentKey> OrderManager<ExchangeKey, InstrumentKey>
+    for Orders<ExchangeKey, InstrumentKey>
+where
+    ExchangeKey: Debug + Clone,
+    InstrumentKey: Debug + Clone,
+{
+    fn orders<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = &'a Order<ExchangeKey, InstrumentKey, ActiveOrderState>>
+    where
+        ExchangeKey: 'a,
+        InstrumentKey: 'a,
+    {
+        self.active.values()
+    }
+
+    fn update_from_order_snapshot<AssetKey>(
+        &mut self,
+        snapshot: Snapshot<&Order<ExchangeKey, InstrumentKey, OrderState<AssetKey, InstrumentKey>>>,
+    ) where
+        AssetKey: Debug + Clone,
+    {
+        let Snapshot(snapshot) = snapshot;
+        let filled_quantity_before = self.filled_quantity(&snapshot.key.cid);
+
+        self.apply_order_snapshot(snapshot);
+
+        if let Some(log) = &mut self.lifecycle {
+            let event = match (self.active.get(&snapshot.key.cid), &snapshot.state) {
+                (Some(order), _) => Some(OrderLifecycleEvent::from_active(
+                    &order.state,
+                    order.quantity,
+                )),
+                (None, OrderState::Inactive(inactive)) => {
+                    let filled_quantity = match inactive {
+                        InactiveOrderState::FullyFilled => snapshot.quantity,
+                        _ => filled_quantity_before.unwrap_or_default(),
+                    };
+                    Some(OrderLifecycleEvent::from_inactive(
+                        inactive,
+                        filled_quantity,
+                    ))
+                }
+                // Open snapshot that was actually FullyFilled, so the order is not tracked
+                (None, OrderState::Active(ActiveOrderState::Open(open))) => {
+                    Some(OrderLifecycleEvent {
+                        stage: OrderLifecycleStage::FullyFilled,
+                        time_exchange: Some(open.time_exchange),
+                        filled_quantity: open.filled_quantity,
+                    })
+                }
+                (None, OrderState::Active(_)) => None,
+            };
+
+            // Only record snapshots for orders that are (or were) tracked
+            let is_known = filled_quantity_before.is_some()
+                || self.active.contains_key(&snapshot.key.cid)
+                || log.contains(&snapshot.key.cid);
+
+            if let Some(event) = event
+                && is_known
+            {
+                log.record(&snapshot.key, event);
+            }
+        }
+    }
 
     fn update_from_cancel_response<AssetKey>(
         &mut self,
@@ -325,7 +451,24 @@ where
     ) where
         AssetKey: Debug + Clone,
     {
-        let Entry::Occupied(mut order) = self.0.entry(response.key.cid.clone()) else {
+        let filled_quantity = self.filled_quantity(&response.key.cid);
+        if let Some(log) = &mut self.lifecycle
+            && let Some(filled_quantity) = filled_quantity
+        {
+            let event = match &response.state {
+                Ok(cancelled) => OrderLifecycleEvent {
+                    stage: OrderLifecycleStage::Cancelled,
+                    time_exchange: Some(cancelled.time_exchange),
+                    filled_quantity,
+                },
+                Err(_) => {
+                    OrderLifecycleEvent::new(OrderLifecycleStage::CancelRejected, filled_quantity)
+                }
+            };
+            log.record(&response.key, event);
+        }
+
+        let Entry::Occupied(mut order) = self.active.entry(response.key.cid.clone()) else {
             warn!(
                 exchange = ?response.key.exchange,
                 instrument = ?response.key.instrument,
@@ -413,7 +556,7 @@ where
         &mut self,
         request: &OrderRequestCancel<ExchangeKey, InstrumentKey>,
     ) {
-        let Some(order) = self.0.get_mut(&request.key.cid) else {
+        let Some(order) = self.active.get_mut(&request.key.cid) else {
             error!(
                 cid = %request.key.cid,
                 event = ?request,
@@ -425,11 +568,19 @@ where
         order.state = ActiveOrderState::CancelInFlight(CancelInFlight {
             order: order.state.open_meta().cloned(),
         });
+
+        if let Some(log) = &mut self.lifecycle {
+            log.record(
+                &order.key,
+                OrderLifecycleEvent::from_active(&order.state, order.quantity),
+            );
+        }
     }
 
     fn record_in_flight_open(&mut self, request: &OrderRequestOpen<ExchangeKey, InstrumentKey>) {
-        if let Some(duplicate_cid_order) =
-            self.0.insert(request.key.cid.clone(), Order::from(request))
+        if let Some(duplicate_cid_order) = self
+            .active
+            .insert(request.key.cid.clone(), Order::from(request))
         {
             error!(
                 cid = %duplicate_cid_order.key.cid,
@@ -437,6 +588,17 @@ where
                 "OrderManager upserted Order OpenInFlight with duplicate ClientOrderId"
             );
         }
+
+        if let Some(log) = &mut self.lifecycle {
+            log.record(
+                &request.key,
+                OrderLifecycleEvent::new(OrderLifecycleStage::Requested, Decimal::ZERO),
+            );
+        }
+    }
+
+    fn is_active_cid(&self, key: &OrderKey<ExchangeKey, InstrumentKey>) -> bool {
+        self.active.contains_key(&key.cid)
     }
 }
 
@@ -461,11 +623,12 @@ mod tests {
     fn orders(
         orders: impl IntoIterator<Item = Order<ExchangeId, u64, ActiveOrderState>>,
     ) -> Orders<ExchangeId, u64> {
-        Orders(
+        Orders::new(
             orders
                 .into_iter()
                 .map(|order| (order.key.cid.clone(), order))
                 .collect(),
+            None,
         )
     }
 
@@ -482,6 +645,7 @@ mod tests {
             quantity: dec!(1),
             kind: OrderKind::Limit,
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+            reduce_only: false,
             state,
         }
     }
@@ -508,6 +672,7 @@ mod tests {
             quantity: Default::default(),
             kind: OrderKind::Market,
             time_in_force: TimeInForce::GoodUntilEndOfDay,
+            reduce_only: false,
             state: OrderState::inactive(Cancelled {
                 id: OrderId(SmolStr::default()),
                 time_exchange: Default::default(),
@@ -530,6 +695,7 @@ mod tests {
             quantity: Default::default(),
             kind: OrderKind::Market,
             time_in_force: TimeInForce::GoodUntilEndOfDay,
+            reduce_only: false,
             state: OrderState::fully_filled(),
         })
     }
@@ -549,6 +715,7 @@ mod tests {
             quantity: Default::default(),
             kind: OrderKind::Market,
             time_in_force: TimeInForce::GoodUntilEndOfDay,
+            reduce_only: false,
             state: OrderState::inactive(OrderError::Connectivity(ConnectivityError::Timeout)),
         })
     }
@@ -568,6 +735,7 @@ mod tests {
             quantity: Default::default(),
             kind: OrderKind::Market,
             time_in_force: TimeInForce::GoodUntilEndOfDay,
+            reduce_only: false,
             state: OrderState::expired(),
         })
     }
@@ -588,6 +756,7 @@ mod tests {
             quantity: dec!(1),
             kind: OrderKind::Limit,
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+            reduce_only: false,
             state: OrderState::active(open(time_exchange)),
         })
     }
@@ -635,6 +804,7 @@ mod tests {
                 quantity: dec!(1),
                 kind: OrderKind::Limit,
                 time_in_force: TimeInForce::GoodUntilEndOfDay,
+                reduce_only: false,
             },
         }
     }
@@ -1021,6 +1191,52 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_update_from_order_snapshot_ignores_out_of_sequence_snapshots() {
+        let time_base = DateTime::<Utc>::MIN_UTC;
+        let cid = ClientOrderId::default();
+
+        let order_snapshot_cancelled_at = |time_exchange: DateTime<Utc>| {
+            let Snapshot(mut snapshot) = order_snapshot_cancelled(cid.clone());
+            snapshot.state = OrderState::inactive(Cancelled {
+                id: OrderId(SmolStr::default()),
+                time_exchange,
+            });
+            Snapshot(snapshot)
+        };
+
+        let expected_newer = orders([order(
+            cid.clone(),
+            ActiveOrderState::from(open(time_plus_secs(time_base, 2))),
+        )]);
+
+        let mut state = Orders::default();
+
+        // TC0: newer Open snapshot is applied
+        state.update_from_order_snapshot(
+            order_snapshot_open(cid.clone(), time_plus_secs(time_base, 2)).as_ref(),
+        );
+        assert_eq!(state, expected_newer);
+
+        // TC1: older Open snapshot is ignored
+        state.update_from_order_snapshot(
+            order_snapshot_open(cid.clone(), time_plus_secs(time_base, 1)).as_ref(),
+        );
+        assert_eq!(state, expected_newer);
+
+        // TC2: older Cancelled snapshot is ignored
+        state.update_from_order_snapshot(
+            order_snapshot_cancelled_at(time_plus_secs(time_base, 1)).as_ref(),
+        );
+        assert_eq!(state, expected_newer);
+
+        // TC3: newer Cancelled snapshot removes the order
+        state.update_from_order_snapshot(
+            order_snapshot_cancelled_at(time_plus_secs(time_base, 3)).as_ref(),
+        );
+        assert_eq!(state, Orders::default());
+    }
+
     #[test]
     fn test_update_from_cancel_response() {
         struct TestCase {
@@ -1157,19 +1373,22 @@ mod tests {
                 // TC0: Insert unseen InFlight
                 state: Orders::default(),
                 input: vec![request_open(cid_1.clone())],
-                expected: Orders(request_opens([request_open(cid_1.clone())])),
+                expected: Orders::new(request_opens([request_open(cid_1.clone())]), None),
             },
             TestCase {
                 // TC1: Insert InFlight that is already tracked
-                state: Orders(request_opens([request_open(cid_1.clone())])),
+                state: Orders::new(request_opens([request_open(cid_1.clone())]), None),
                 input: vec![request_open(cid_1.clone())],
-                expected: Orders(request_opens([request_open(cid_1.clone())])),
+                expected: Orders::new(request_opens([request_open(cid_1.clone())]), None),
             },
             TestCase {
                 // TC2: Insert one untracked InFlight, and one already tracked
-                state: Orders(request_opens([request_open(cid_1.clone())])),
+                state: Orders::new(request_opens([request_open(cid_1.clone())]), None),
                 input: vec![request_open(cid_1.clone()), request_open(cid_2.clone())],
-                expected: Orders(request_opens([request_open(cid_1), request_open(cid_2)])),
+                expected: Orders::new(
+                    request_opens([request_open(cid_1), request_open(cid_2)]),
+                    None,
+                ),
             },
         ];
 
@@ -1180,4 +1399,86 @@ mod tests {
             assert_eq!(test.state, test.expected, "TC{index} failed")
         }
     }
+
+    #[test]
+    fn test_order_lifecycle_log_open_partial_fill_cancel() {
+        let cid = ClientOrderId::new("lifecycle");
+        let time_open = DateTime::<Utc>::MIN_UTC;
+        let time_partial = time_plus_secs(time_open, 1);
+        let time_cancelled = time_plus_secs(time_open, 2);
+
+        // Lifecycle log is disabled by default
+        assert!(
+            Orders::<ExchangeId, u64>::default()
+                .lifecycle_log()
+                .is_none()
+        );
+
+        let mut orders = Orders::<ExchangeId, u64>::default()
+            .with_lifecycle_log(OrderLifecycleConfig::default());
+
+        // Requested
+        orders.record_in_flight_open(&request_open(cid.clone()));
+
+        // Open
+        orders.update_from_order_snapshot(order_snapshot_open(cid.clone(), time_open).as_ref());
+
+        // Partially filled, then a duplicate snapshot which should not be recorded twice
+        let partial_fill = Snapshot(order(
+            cid.clone(),
+            OrderState::<u64, u64>::active(Open {
+                id: OrderId(SmolStr::default()),
+                time_exchange: time_partial,
+                filled_quantity: dec!(0.4),
+            }),
+        ));
+        orders.update_from_order_snapshot(partial_fill.as_ref());
+        orders.update_from_order_snapshot(partial_fill.as_ref());
+
+        // Cancel requested, then cancelled
+        orders.record_in_flight_cancel(&request_cancel(cid.clone()));
+        let mut cancelled = response_cancel_ok(cid.clone());
+        cancelled.state = Ok(Cancelled {
+            id: OrderId(SmolStr::default()),
+            time_exchange: time_cancelled,
+        });
+        orders.update_from_cancel_response(&cancelled);
+
+        // Order is no longer tracked, but its lifecycle is retained
+        assert!(orders.active.is_empty());
+
+        let log = orders.lifecycle_log().unwrap();
+        let lifecycle = log.by_cid(&cid).unwrap();
+        assert_eq!(log.by_key(&cancelled.key), Some(lifecycle));
+        assert_eq!(
+            lifecycle.events.iter().copied().collect::<Vec<_>>(),
+            vec![
+                OrderLifecycleEvent::new(OrderLifecycleStage::Requested, dec!(0)),
+                OrderLifecycleEvent {
+                    stage: OrderLifecycleStage::Open,
+                    time_exchange: Some(time_open),
+                    filled_quantity: dec!(0),
+                },
+                OrderLifecycleEvent {
+                    stage: OrderLifecycleStage::PartiallyFilled,
+                    time_exchange: Some(time_partial),
+                    filled_quantity: dec!(0.4),
+                },
+                OrderLifecycleEvent::new(OrderLifecycleStage::CancelRequested, dec!(0.4)),
+                OrderLifecycleEvent {
+                    stage: OrderLifecycleStage::Cancelled,
+                    time_exchange: Some(time_cancelled),
+                    filled_quantity: dec!(0.4),
+                },
+            ]
+        );
+        assert!(lifecycle.latest().unwrap().stage.is_terminal());
+    }
+
+    #[test]
+    fn test_orders_deserialise_without_lifecycle() {
+        // Orders serialised before the lifecycle log existed deserialise with it disabled
+        let actual = serde_json::from_str::<Orders<ExchangeId, u64>>(r#"{"active":{}}"#).unwrap();
+        assert_eq!(actual, Orders::default());
+    }
 }
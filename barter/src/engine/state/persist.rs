@@ -0,0 +1,293 @@
+//! EngineState 持久化模块
+//!
+//! 本模块为 [`EngineState`] 提供带模式版本号的序列化与加载工具，使持久化的状态在
+//! `EngineState` 结构演进时能够被迁移，或以明确的 [`BarterError`] 失败。
+//!
+//! # 核心概念
+//!
+//! - **STATE_SCHEMA_VERSION**: 当前 `EngineState` 序列化模式的版本号
+//! - **VersionedEngineState**: 嵌入版本号的序列化包装结构
+//! - **迁移钩子**: 加载旧版本快照时，将其原始 JSON 迁移到当前模式
+//!
+//! # 序列化格式
+//!
+//! ```json
+//! { "version": 1, "state": { "trading": ..., "global": ..., ... } }
+//! ```
+//!
+//! 引入版本号之前持久化的状态（即没有 `version` 字段的原始 `EngineState`）被视为版本 `0`。
+
+use crate::{engine::state::EngineState, error::BarterError};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+
+/// 当前 [`EngineState`] 序列化模式的版本号。
+///
+/// 每当 `EngineState`（或其子状态）的序列化形式发生不兼容的变化时，应递增此版本号，
+/// 并为旧版本提供迁移钩子。
+pub const STATE_SCHEMA_VERSION: u32 = 1;
+
+/// 嵌入模式版本号的 [`EngineState`] 序列化包装结构。
+///
+/// ## 字段说明
+///
+/// - **version**: 序列化时的模式版本号
+/// - **state**: 被序列化的状态
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct VersionedEngineState<State> {
+    /// 序列化时的模式版本号
+    pub version: u32,
+    /// 被序列化的状态
+    pub state: State,
+}
+
+impl<GlobalData, InstrumentData> EngineState<GlobalData, InstrumentData> {
+    /// 将 `EngineState` 序列化为嵌入 [`STATE_SCHEMA_VERSION`] 的 JSON 字符串。
+    ///
+    /// # 返回值
+    ///
+    /// - `Ok(String)`: 带版本号的 JSON 字符串
+    /// - `Err(BarterError::StateSerde)`: 序列化失败
+    pub fn to_json_versioned(&self) -> Result<String, BarterError>
+    where
+        GlobalData: Serialize,
+        InstrumentData: Serialize,
+    {
+        serde_json::to_string(&VersionedEngineState {
+            version: STATE_SCHEMA_VERSION,
+            state: self,
+        })
+        .map_err(|error| BarterError::StateSerde(error.to_string()))
+    }
+
+    /// 从带版本号的 JSON 字符串加载 `EngineState`。
+    ///
+    /// 如果持久化的版本号与 [`STATE_SCHEMA_VERSION`] 不匹配，返回
+    /// [`BarterError::StateSchemaVersion`]。如需迁移旧版本，请使用
+    /// [`Self::from_json_versioned_with_migration`]。
+    ///
+    /// # 参数
+    ///
+    /// - `json`: 由 [`Self::to_json_versioned`] 生成的 JSON 字符串
+    pub fn from_json_versioned(json: &str) -> Result<Self, BarterError>
+    where
+        GlobalData: DeserializeOwned,
+        InstrumentData: DeserializeOwned,
+    {
+        Self::from_json_versioned_with_migration(json, |found, _| {
+            Err(BarterError::StateSchemaVersion {
+                expected: STATE_SCHEMA_VERSION,
+                found,
+            })
+        })
+    }
+
+    /// 从带版本号的 JSON 字符串加载 `EngineState`，并使用提供的迁移钩子迁移旧版本。
+    ///
+    /// 仅当持久化的版本号与 [`STATE_SCHEMA_VERSION`] 不匹配时才会调用迁移钩子。钩子接收
+    /// 持久化的版本号和原始状态 JSON，并返回符合当前模式的状态 JSON，或返回错误
+    /// （例如 [`BarterError::StateSchemaVersion`]）表示无法迁移。
+    ///
+    /// # 参数
+    ///
+    /// - `json`: 带版本号的 JSON 字符串（或版本 `0` 的原始 `EngineState` JSON）
+    /// - `migrate`: 迁移钩子 `(found_version, state) -> migrated_state`
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let state = EngineState::<DefaultGlobalData, DefaultInstrumentMarketData>::
+    ///     from_json_versioned_with_migration(&json, |found, mut state| match found {
+    ///         0 => {
+    ///             // 迁移版本 0 的状态...
+    ///             Ok(state)
+    ///         }
+    ///         found => Err(BarterError::StateSchemaVersion {
+    ///             expected: STATE_SCHEMA_VERSION,
+    ///             found,
+    ///         }),
+    ///     })?;
+    /// ```
+    pub fn from_json_versioned_with_migration<FnMigrate>(
+        json: &str,
+        mut migrate: FnMigrate,
+    ) -> Result<Self, BarterError>
+    where
+        GlobalData: DeserializeOwned,
+        InstrumentData: DeserializeOwned,
+        FnMigrate: FnMut(u32, Value) -> Result<Value, BarterError>,
+    {
+        let value = serde_json::from_str::<Value>(json)
+            .map_err(|error| BarterError::StateSerde(error.to_string()))?;
+
+        let (version, state) = match value {
+            Value::Object(mut object) if object.contains_key("version") => {
+                let version = object
+                    .remove("version")
+                    .and_then(|version| version.as_u64())
+                    .and_then(|version| u32::try_from(version).ok())
+                    .ok_or_else(|| {
+                        BarterError::StateSerde("invalid EngineState schema version".to_string())
+                    })?;
+                let state = object.remove("state").ok_or_else(|| {
+                    BarterError::StateSerde("missing versioned EngineState".to_string())
+                })?;
+                (version, state)
+            }
+            // EngineState persisted before schema versioning was introduced
+            unversioned => (0, unversioned),
+        };
+
+        let state = if version == STATE_SCHEMA_VERSION {
+            state
+        } else {
+            migrate(version, state)?
+        };
+
+        serde_json::from_value(state).map_err(|error| BarterError::StateSerde(error.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{
+            global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+            trading::TradingState,
+        },
+        test_utils::{spot_engine_state, time_plus_days},
+    };
+    use barter_execution::{
+        AccountEvent, AccountEventKind,
+        order::id::{OrderId, StrategyId},
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+    use chrono::{DateTime, Utc};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    type State = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn state() -> State {
+        let mut state = spot_engine_state(&["btc", "eth"]);
+        state.trading = TradingState::Enabled;
+        state
+    }
+
+    fn trade(day: u64, instrument: usize, side: Side, price: Decimal) -> AccountEvent {
+        AccountEvent {
+            exchange: ExchangeIndex(0),
+            kind: AccountEventKind::Trade(Trade {
+                id: TradeId::new(format!("{instrument}-{day}")),
+                order_id: OrderId::new(format!("{instrument}-{day}")),
+                instrument: InstrumentIndex(instrument),
+                strategy: StrategyId::new("strategy"),
+                time_exchange: time_plus_days(DateTime::<Utc>::MIN_UTC, day),
+                side,
+                price,
+                quantity: dec!(1),
+                fees: AssetFees::quote_fees(dec!(0.1)),
+                reduce_only: false,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_engine_state_versioned_round_trip() {
+        let state = state();
+        let json = state.to_json_versioned().unwrap();
+
+        let value = serde_json::from_str::<Value>(&json).unwrap();
+        assert_eq!(value["version"], Value::from(STATE_SCHEMA_VERSION));
+
+        assert_eq!(State::from_json_versioned(&json).unwrap(), state);
+    }
+
+    #[test]
+    fn test_engine_state_versioned_round_trip_with_positions() {
+        let mut state = state();
+
+        // btc_usdt position is opened & closed, eth_usdt position remains open
+        assert!(
+            state
+                .update_from_account(&trade(1, 0, Side::Buy, dec!(100)))
+                .is_none()
+        );
+        assert!(
+            state
+                .update_from_account(&trade(2, 0, Side::Sell, dec!(110)))
+                .is_some()
+        );
+        assert!(
+            state
+                .update_from_account(&trade(3, 1, Side::Buy, dec!(10)))
+                .is_none()
+        );
+
+        let btc = state.instruments.instrument_index(&InstrumentIndex(0));
+        assert!(btc.position.current.is_none());
+        assert_eq!(btc.tear_sheet.pnl_returns.returns.len(), 1);
+        assert!(
+            state
+                .instruments
+                .instrument_index(&InstrumentIndex(1))
+                .position
+                .current
+                .is_some()
+        );
+
+        let json = state.to_json_versioned().unwrap();
+        assert_eq!(State::from_json_versioned(&json).unwrap(), state);
+    }
+
+    #[test]
+    fn test_engine_state_versioned_wrong_version() {
+        let state = state();
+        let mut value = serde_json::from_str::<Value>(&state.to_json_versioned().unwrap()).unwrap();
+        value["version"] = Value::from(STATE_SCHEMA_VERSION + 1);
+        let json = value.to_string();
+
+        let error = State::from_json_versioned(&json).unwrap_err();
+        assert_eq!(
+            error,
+            BarterError::StateSchemaVersion {
+                expected: STATE_SCHEMA_VERSION,
+                found: STATE_SCHEMA_VERSION + 1,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "EngineState schema version mismatch: expected {STATE_SCHEMA_VERSION}, found {} with no migration",
+                STATE_SCHEMA_VERSION + 1
+            )
+        );
+    }
+
+    #[test]
+    fn test_engine_state_versioned_migration() {
+        let state = state();
+
+        // Unversioned EngineState is treated as version 0 & passed to the migration hook
+        let unversioned = serde_json::to_string(&state).unwrap();
+        assert_eq!(
+            State::from_json_versioned(&unversioned).unwrap_err(),
+            BarterError::StateSchemaVersion {
+                expected: STATE_SCHEMA_VERSION,
+                found: 0,
+            }
+        );
+
+        let mut migrated_from = None;
+        let migrated = State::from_json_versioned_with_migration(&unversioned, |found, state| {
+            migrated_from = Some(found);
+            Ok(state)
+        })
+        .unwrap();
+
+        assert_eq!(migrated_from, Some(0));
+        assert_eq!(migrated, state);
+    }
+}
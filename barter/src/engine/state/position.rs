@@ -7,6 +7,8 @@
 //!
 //! - **Position**: 当前持仓，表示在特定交易对上的开仓状态
 //! - **PositionManager**: 仓位管理器，管理当前仓位
+//! - **PositionMode**: 仓位模式，净仓（Netting）或对冲（Hedging，多空仓位独立存在）
+//! - **PositionSettlement**: 仓位结算方式，线性（Linear）或反向合约（Inverse，以基础资产结算）
 //! - **PositionExited**: 已平仓的仓位，包含完整的交易历史
 //! - **PnL**: 盈亏计算（已实现盈亏和未实现盈亏）
 //!
@@ -23,12 +25,14 @@
 //! - **已实现盈亏（PnL Realised）**: 已平仓部分的盈亏
 //! - **未实现盈亏（PnL Unrealised）**: 当前持仓的估算盈亏
 //! - **手续费**: 入场和出场手续费分别计算
+//! - **舍入**: 可选的 [`DecimalRounding`]，按交易所的舍入方式（四舍五入、银行家舍入或截断）
+//!   舍入盈亏和按比例分摊的手续费（默认：不舍入）
 
 use barter_execution::trade::{AssetFees, Trade, TradeId};
 use barter_instrument::{
     Side,
     asset::{AssetIndex, QuoteAsset},
-    instrument::InstrumentIndex,
+    instrument::{InstrumentIndex, spec::DecimalRounding},
 };
 use chrono::{DateTime, Utc};
 use derive_more::Constructor;
@@ -37,10 +41,53 @@ use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use tracing::error;
 
+/// 仓位模式，决定 [`PositionManager`] 如何处理同一交易对上的相反方向交易。
+///
+/// - `Netting`（默认）：每个交易对只维护一个净仓位，相反方向的交易会减仓、平仓或翻仓
+/// - `Hedging`：多头和空头仓位可以同时存在，按交易方向（或提供的持仓方向）独立更新
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum PositionMode {
+    /// 净仓模式：每个交易对只有一个净仓位。
+    #[default]
+    Netting,
+    /// 对冲模式：多头和空头仓位独立存在。
+    Hedging,
+}
+
+/// 仓位结算方式，决定 [`Position`] 的数量单位以及盈亏和手续费的计价资产。
+///
+/// - `Linear`（默认）：现货和线性合约（例如 USDT 本位永续），数量以基础资产计价，
+///   盈亏以报价资产结算
+/// - `Inverse`：反向合约（例如 BTC 本位永续 `XBTUSD`），数量为以报价资产计价的合约面值
+///   （例如 USD），盈亏以基础资产结算
+///
+/// ## 反向合约盈亏计算
+///
+/// - **做多（LONG）**: `数量 / 平均入场价格 - 数量 / 当前价格`
+/// - **做空（SHORT）**: `数量 / 当前价格 - 数量 / 平均入场价格`
+///
+/// 例如：以 8,000 做多 10,000 USD 面值的 `XBTUSD`，以 10,000 平仓，
+/// 盈亏为 `10,000 / 8,000 - 10,000 / 10,000 = 0.25 BTC`。
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum PositionSettlement {
+    /// 线性结算：盈亏以报价资产计价。
+    #[default]
+    Linear,
+    /// 反向结算：盈亏以基础资产计价。
+    Inverse,
+}
+
 /// 仓位管理器，管理当前仓位状态。
 ///
-/// PositionManager 负责跟踪和管理当前仓位。它维护一个可选的当前仓位，
-/// 当仓位被完全平仓时，返回 `PositionExited`。
+/// PositionManager 负责跟踪和管理当前仓位。当仓位被完全平仓时，返回 `PositionExited`。
+///
+/// 根据 [`PositionMode`]：
+/// - `Netting`: 使用 `current` 维护单一净仓位
+/// - `Hedging`: 使用 `long` 和 `short` 分别维护多头和空头仓位（`current` 始终为 `None`）
 ///
 /// ## 类型参数
 ///
@@ -61,23 +108,94 @@ use tracing::error;
 /// if let Some(position_exited) = position_manager.update_from_trade(&trade) {
 ///     // 处理已平仓的仓位
 /// }
+///
+/// // 对冲模式
+/// let mut hedged = PositionManager::with_mode(PositionMode::Hedging);
 /// ```
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize, Constructor)]
 pub struct PositionManager<InstrumentKey = InstrumentIndex> {
-    /// 当前仓位（如果存在）
+    /// 仓位模式（默认：`PositionMode::Netting`）
+    #[serde(default)]
+    pub mode: PositionMode,
+    /// 当前净仓位（如果存在，仅用于 `PositionMode::Netting`）
     pub current: Option<Position<QuoteAsset, InstrumentKey>>,
+    /// 多头仓位（如果存在，仅用于 `PositionMode::Hedging`）
+    pub long: Option<Position<QuoteAsset, InstrumentKey>>,
+    /// 空头仓位（如果存在，仅用于 `PositionMode::Hedging`）
+    pub short: Option<Position<QuoteAsset, InstrumentKey>>,
+    /// 新开仓位的结算方式（默认：`PositionSettlement::Linear`）
+    #[serde(default)]
+    pub settlement: PositionSettlement,
+    /// 新开仓位的合约乘数，即每单位数量代表的标的数量（默认：`1`，例如现货）
+    #[serde(default = "default_contract_size")]
+    pub contract_size: Decimal,
+    /// 新开仓位盈亏和手续费使用的舍入方式（默认：`None`，不舍入）
+    #[serde(default)]
+    pub rounding: Option<DecimalRounding>,
 }
 
 impl<InstrumentKey> Default for PositionManager<InstrumentKey> {
     fn default() -> Self {
-        Self { current: None }
+        Self::with_mode(PositionMode::default())
     }
 }
 
 impl<InstrumentKey> PositionManager<InstrumentKey> {
-    /// 基于新交易更新当前仓位状态。
+    /// 使用提供的 [`PositionMode`] 构造没有任何仓位的 `PositionManager`。
+    pub fn with_mode(mode: PositionMode) -> Self {
+        Self {
+            mode,
+            current: None,
+            long: None,
+            short: None,
+            settlement: PositionSettlement::default(),
+            contract_size: default_contract_size(),
+            rounding: None,
+        }
+    }
+
+    /// 设置新开仓位的 [`PositionSettlement`]，例如反向合约使用 `PositionSettlement::Inverse`。
+    pub fn with_settlement(self, settlement: PositionSettlement) -> Self {
+        Self { settlement, ..self }
+    }
+
+    /// 设置新开仓位的合约乘数，例如 1 张合约代表 100 单位标的时使用 `dec!(100)`。
     ///
-    /// 此方法处理各种仓位操作场景：
+    /// 通常取自 [`Instrument::contract_size`](barter_instrument::instrument::Instrument::contract_size)。
+    pub fn with_contract_size(self, contract_size: Decimal) -> Self {
+        Self {
+            contract_size,
+            ..self
+        }
+    }
+
+    /// 设置新开仓位盈亏和手续费使用的 [`DecimalRounding`]，例如按交易所规则舍入到分。
+    pub fn with_rounding(self, rounding: DecimalRounding) -> Self {
+        Self {
+            rounding: Some(rounding),
+            ..self
+        }
+    }
+
+    /// 返回所有开放仓位的迭代器（`Netting` 模式下最多一个，`Hedging` 模式下最多两个）。
+    pub fn positions(&self) -> impl Iterator<Item = &Position<QuoteAsset, InstrumentKey>> {
+        [&self.current, &self.long, &self.short]
+            .into_iter()
+            .filter_map(Option::as_ref)
+    }
+
+    /// 返回所有开放仓位的可变引用迭代器。
+    pub fn positions_mut(
+        &mut self,
+    ) -> impl Iterator<Item = &mut Position<QuoteAsset, InstrumentKey>> {
+        [&mut self.current, &mut self.long, &mut self.short]
+            .into_iter()
+            .filter_map(Option::as_mut)
+    }
+
+    /// 基于新交易更新仓位状态，按照 [`PositionMode`] 分发。
+    ///
+    /// `PositionMode::Netting` 下更新当前净仓位，处理各种仓位操作场景：
     ///
     /// - **开仓**: 如果当前没有仓位，从交易创建新仓位
     /// - **加仓**: 如果交易方向与当前仓位相同，增加仓位数量
@@ -85,13 +203,16 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
     /// - **平仓**: 如果交易完全抵消当前仓位，关闭仓位
     /// - **翻仓**: 如果交易数量超过当前仓位，关闭当前仓位并开立反向仓位
     ///
+    /// `PositionMode::Hedging` 下委托给 [`Self::update_from_hedged_trade`]：交易方向决定更新
+    /// 多头还是空头仓位，reduce-only 交易则减少反方向的仓位，对冲仓位永不翻仓。
+    ///
     /// # 参数
     ///
     /// - `trade`: 新交易
     ///
     /// # 返回值
     ///
-    /// - `Some(PositionExited)`: 如果仓位被完全平仓
+    /// - `Some(PositionExited)`: 如果仓位被完全平仓（包括翻仓时被关闭的原仓位）
     /// - `None`: 如果仓位仍然存在或新开仓
     ///
     /// # 类型约束
@@ -114,6 +235,16 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
     where
         InstrumentKey: Debug + Clone + PartialEq,
     {
+        if self.mode == PositionMode::Hedging {
+            // 对冲模式：按交易方向更新对应的多头或空头仓位，reduce-only 交易减少反方向的仓位
+            let position_side = if trade.reduce_only {
+                trade.side.opposite()
+            } else {
+                trade.side
+            };
+            return self.update_from_hedged_trade(trade, position_side);
+        }
+
         let (current, closed) = match self.current.take() {
             Some(position) => {
                 // 更新当前仓位，可能会关闭它，并可能用剩余的交易数量开立新仓位
@@ -121,7 +252,14 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
             }
             None => {
                 // 当前没有仓位，所以从交易创建新仓位
-                (Some(Position::from(trade)), None)
+                (
+                    Some(
+                        Position::from_trade_with_settlement(trade, self.settlement)
+                            .with_contract_size(self.contract_size)
+                            .with_rounding(self.rounding),
+                    ),
+                    None,
+                )
             }
         };
 
@@ -129,6 +267,76 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
 
         closed
     }
+
+    /// 在 `PositionMode::Hedging` 下，基于新交易更新 `position_side` 对应的仓位。
+    ///
+    /// 与交易所对冲模式一致，`position_side` 标识交易所属的仓位（`Side::Buy` 为多头，
+    /// `Side::Sell` 为空头）：
+    ///
+    /// - 交易方向与 `position_side` 相同：开仓或加仓
+    /// - 交易方向与 `position_side` 相反：减仓或平仓，对冲仓位永不翻仓，
+    ///   超出仓位数量的部分会被记录错误并忽略
+    ///
+    /// [`Self::update_from_trade`] 在对冲模式下使用交易方向作为 `position_side`（reduce-only
+    /// 交易除外，其使用反方向），因此非 reduce-only 交易只会开仓或加仓。如果交易所报告了交易的
+    /// 持仓方向，应使用此方法减少对冲仓位。
+    ///
+    /// # 参数
+    ///
+    /// - `trade`: 新交易
+    /// - `position_side`: 交易所属的仓位方向
+    ///
+    /// # 返回值
+    ///
+    /// - `Some(PositionExited)`: 如果对应仓位被完全平仓
+    /// - `None`: 如果仓位仍然存在或新开仓
+    pub fn update_from_hedged_trade(
+        &mut self,
+        trade: &Trade<QuoteAsset, InstrumentKey>,
+        position_side: Side,
+    ) -> Option<PositionExited<QuoteAsset, InstrumentKey>>
+    where
+        InstrumentKey: Debug + Clone + PartialEq,
+    {
+        let leg = match position_side {
+            Side::Buy => &mut self.long,
+            Side::Sell => &mut self.short,
+        };
+
+        let (next, closed) = match leg.take() {
+            Some(position) => position.update_from_trade(trade),
+            None if trade.side == position_side => (
+                Some(
+                    Position::from_trade_with_settlement(trade, self.settlement)
+                        .with_contract_size(self.contract_size)
+                        .with_rounding(self.rounding),
+                ),
+                None,
+            ),
+            None => {
+                error!(
+                    ?position_side,
+                    trade = ?trade,
+                    "PositionManager received a reducing Trade for a hedged Position that does not exist - ignoring"
+                );
+                return None;
+            }
+        };
+
+        *leg = next.filter(|position| {
+            let same_side = position.side == position_side;
+            if !same_side {
+                error!(
+                    ?position_side,
+                    trade = ?trade,
+                    "PositionManager received a Trade that would flip a hedged Position - ignoring excess quantity"
+                );
+            }
+            same_side
+        });
+
+        closed
+    }
 }
 
 /// 表示特定交易对的开放交易仓位。
@@ -181,7 +389,8 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
 ///     side: Side::Buy,
 ///     price: dec!(50_000.0),
 ///     quantity: dec!(0.1),
-///     fees: AssetFees::quote_fees(dec!(5.0))
+///     fees: AssetFees::quote_fees(dec!(5.0)),
+///     reduce_only: false,
 /// });
 /// assert_eq!(position.side, Side::Buy);
 /// assert_eq!(position.quantity_abs, dec!(0.1));
@@ -196,7 +405,8 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
 ///     side: Side::Sell,
 ///     price: dec!(60_000.0),
 ///     quantity: dec!(0.05),
-///     fees: AssetFees::quote_fees(dec!(2.5))
+///     fees: AssetFees::quote_fees(dec!(2.5)),
+///     reduce_only: false,
 /// });
 ///
 /// // LONG Position is still open, but with reduced size
@@ -229,7 +439,8 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
 ///     side: Side::Sell,
 ///     price: dec!(50_000.0),
 ///     quantity: dec!(0.1),
-///     fees: AssetFees::quote_fees(dec!(5.0))
+///     fees: AssetFees::quote_fees(dec!(5.0)),
+///     reduce_only: false,
 /// });
 /// assert_eq!(position.side, Side::Sell);
 /// assert_eq!(position.quantity_abs, dec!(0.1));
@@ -244,7 +455,8 @@ impl<InstrumentKey> PositionManager<InstrumentKey> {
 ///     side: Side::Buy,
 ///     price: dec!(40_000.0),
 ///     quantity: dec!(0.2),
-///     fees: AssetFees::quote_fees(dec!(10.0))
+///     fees: AssetFees::quote_fees(dec!(10.0)),
+///     reduce_only: false,
 /// });
 ///
 /// // Original SHORT Position closed with profit
@@ -318,9 +530,64 @@ pub struct Position<AssetKey = AssetIndex, InstrumentKey = InstrumentIndex> {
     ///
     /// 包括开仓、加仓、减仓、平仓等所有相关交易。
     pub trades: Vec<TradeId>,
+
+    /// 仓位结算方式，决定盈亏以报价资产（线性）还是基础资产（反向合约）计价。
+    #[serde(default)]
+    pub settlement: PositionSettlement,
+
+    /// 合约乘数，即每单位 `quantity_abs` 代表的标的数量（现货为 `1`）。
+    ///
+    /// 盈亏计算时数量会乘以此值，例如 1 张合约代表 100 单位标的时为 `100`。
+    #[serde(default = "default_contract_size")]
+    pub contract_size: Decimal,
+
+    /// 盈亏和按比例分摊的手续费使用的舍入方式（`None` 表示不舍入）。
+    #[serde(default)]
+    pub rounding: Option<DecimalRounding>,
+}
+
+/// [`Position`] 和 [`PositionManager`] 的默认合约乘数（`1`）。
+fn default_contract_size() -> Decimal {
+    Decimal::ONE
 }
 
 impl<InstrumentKey> Position<QuoteAsset, InstrumentKey> {
+    /// 从初始交易以提供的 [`PositionSettlement`] 创建新仓位。
+    ///
+    /// 对于 `PositionSettlement::Inverse`，交易数量为合约面值（例如 USD），
+    /// 交易手续费应以基础资产计价。
+    pub fn from_trade_with_settlement(
+        trade: &Trade<QuoteAsset, InstrumentKey>,
+        settlement: PositionSettlement,
+    ) -> Self
+    where
+        InstrumentKey: Clone,
+    {
+        Self {
+            settlement,
+            ..Self::from(trade)
+        }
+    }
+
+    /// 设置仓位的合约乘数，用于盈亏计算。
+    pub fn with_contract_size(self, contract_size: Decimal) -> Self {
+        Self {
+            contract_size,
+            ..self
+        }
+    }
+
+    /// 设置仓位盈亏和按比例分摊的手续费使用的 [`DecimalRounding`]（`None` 表示不舍入）。
+    pub fn with_rounding(self, rounding: Option<DecimalRounding>) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// 如果配置了 [`DecimalRounding`]，舍入提供的值，否则原样返回。
+    fn round(&self, value: Decimal) -> Decimal {
+        self.rounding
+            .map_or(value, |rounding| rounding.round(value))
+    }
+
     /// 基于新交易更新仓位状态。
     ///
     /// 此方法处理各种仓位操作场景：
@@ -432,7 +699,7 @@ impl<InstrumentKey> Position<QuoteAsset, InstrumentKey> {
                 // 交易翻仓，所以为下一个仓位生成理论上的初始交易
                 let next_position_quantity = trade.quantity.abs() - self.quantity_abs;
                 let next_position_fee_enter =
-                    trade.fees.fees * (next_position_quantity / trade.quantity.abs());
+                    self.round(trade.fees.fees * (next_position_quantity / trade.quantity.abs()));
                 let next_position_trade = Trade {
                     id: trade.id.clone(),
                     order_id: trade.order_id.clone(),
@@ -446,10 +713,12 @@ impl<InstrumentKey> Position<QuoteAsset, InstrumentKey> {
                         asset: trade.fees.asset.clone(),
                         fees: next_position_fee_enter,
                     },
+                    reduce_only: trade.reduce_only,
                 };
 
                 // Update closing Position with appropriate ratio of fees for theoretical quantity
-                let fee_exit = trade.fees.fees * (self.quantity_abs / trade.quantity.abs());
+                let fee_exit =
+                    self.round(trade.fees.fees * (self.quantity_abs / trade.quantity.abs()));
                 self.fees_exit.fees += fee_exit;
                 self.time_exchange_update = trade.time_exchange;
                 self.update_pnl_realised(self.quantity_abs, trade.price, fee_exit);
@@ -457,7 +726,11 @@ impl<InstrumentKey> Position<QuoteAsset, InstrumentKey> {
                 self.update_pnl_unrealised(trade.price);
 
                 (
-                    Some(Self::from(&next_position_trade)),
+                    Some(
+                        Self::from_trade_with_settlement(&next_position_trade, self.settlement)
+                            .with_contract_size(self.contract_size)
+                            .with_rounding(self.rounding),
+                    ),
                     Some(PositionExited::from(self)),
                 )
             }
@@ -476,8 +749,16 @@ impl<InstrumentKey> Position<QuoteAsset, InstrumentKey> {
     /// # 工作原理
     ///
     /// 使用公式：`(当前平均价格 * 当前数量 + 新交易价格 * 新交易数量) / (当前数量 + 新交易数量)`
+    ///
+    /// 对于 `PositionSettlement::Inverse`，使用数量加权调和平均价格，参见
+    /// [`calculate_price_entry_average_inverse`]。
     fn update_price_entry_average(&mut self, trade: &Trade<QuoteAsset, InstrumentKey>) {
-        self.price_entry_average = calculate_price_entry_average(
+        let calculate = match self.settlement {
+            PositionSettlement::Linear => calculate_price_entry_average,
+            PositionSettlement::Inverse => calculate_price_entry_average_inverse,
+        };
+
+        self.price_entry_average = calculate(
             self.price_entry_average,
             self.quantity_abs,
             trade.price,
@@ -511,14 +792,20 @@ impl<InstrumentKey> Position<QuoteAsset, InstrumentKey> {
     /// position.update_pnl_unrealised(current_market_price);
     /// ```
     pub fn update_pnl_unrealised(&mut self, price: Decimal) {
-        self.pnl_unrealised = calculate_pnl_unrealised(
+        let calculate = match self.settlement {
+            PositionSettlement::Linear => calculate_pnl_unrealised,
+            PositionSettlement::Inverse => calculate_pnl_unrealised_inverse,
+        };
+
+        self.pnl_unrealised = self.round(calculate(
             self.side,
             self.price_entry_average,
             self.quantity_abs,
             self.quantity_abs_max,
             self.fees_enter.fees,
             price,
-        );
+            self.contract_size,
+        ));
     }
 
     /// 从已平仓的仓位数量更新 [`Position`] 的 `pnl_realised`（已实现盈亏）。
@@ -547,14 +834,20 @@ impl<InstrumentKey> Position<QuoteAsset, InstrumentKey> {
         closed_price: Decimal,
         closed_fee: Decimal,
     ) {
+        let calculate = match self.settlement {
+            PositionSettlement::Linear => calculate_pnl_realised,
+            PositionSettlement::Inverse => calculate_pnl_realised_inverse,
+        };
+
         // 使用已平仓数量的盈亏更新总仓位的已实现盈亏
-        self.pnl_realised += calculate_pnl_realised(
+        self.pnl_realised += self.round(calculate(
             self.side,
             self.price_entry_average,
             closed_quantity,
             closed_price,
             closed_fee,
-        );
+            self.contract_size,
+        ));
     }
 }
 
@@ -578,6 +871,9 @@ where
             time_enter: trade.time_exchange,
             time_exchange_update: trade.time_exchange,
             trades,
+            settlement: PositionSettlement::default(),
+            contract_size: default_contract_size(),
+            rounding: None,
         }
     }
 }
@@ -696,14 +992,49 @@ fn calculate_price_entry_average(
     (current_value + trade_value) / (current_quantity_abs + trade_quantity_abs)
 }
 
+/// 计算反向合约仓位的新平均入场价格（数量加权调和平均）。
+///
+/// 反向合约的数量为以报价资产计价的合约面值，因此入场价格需保持
+/// `数量 / 平均入场价格` 等于各笔交易的基础资产价值之和：
+/// `(当前数量 + 新交易数量) / (当前数量 / 当前平均价格 + 新交易数量 / 新交易价格)`
+///
+/// # 边界情况
+///
+/// - 如果当前数量和新交易数量都为 0，返回 0
+/// - 如果基础资产价值之和为 0（例如所有价格都为 0），返回 0
+fn calculate_price_entry_average_inverse(
+    current_price_entry_average: Decimal,
+    current_quantity_abs: Decimal,
+    trade_price: Decimal,
+    trade_quantity_abs: Decimal,
+) -> Decimal {
+    if current_quantity_abs.is_zero() && trade_quantity_abs.is_zero() {
+        return Decimal::ZERO;
+    }
+
+    let current_value_base = inverse_value_base(current_quantity_abs, current_price_entry_average);
+    let trade_value_base = inverse_value_base(trade_quantity_abs, trade_price);
+
+    (current_quantity_abs + trade_quantity_abs)
+        .checked_div(current_value_base + trade_value_base)
+        .unwrap_or(Decimal::ZERO)
+}
+
+/// 反向合约面值 `quantity_abs` 在 `price` 下的基础资产价值。
+///
+/// 数量为 0 时返回 0。价格为 0 时基础资产价值无定义，同样返回 0 而不是 panic。
+fn inverse_value_base(quantity_abs: Decimal, price: Decimal) -> Decimal {
+    quantity_abs.checked_div(price).unwrap_or(Decimal::ZERO)
+}
+
 /// 计算以提供的价格平仓仓位 `quantity_abs` 时的估算未实现盈亏。
 ///
 /// 此函数计算如果以指定价格平仓当前持仓数量时的估算盈亏，包括估算的出场手续费。
 ///
 /// ## 计算公式
 ///
-/// - **做多（LONG）**: `(当前价格 - 平均入场价格) * 数量 - 估算出场手续费`
-/// - **做空（SHORT）**: `(平均入场价格 - 当前价格) * 数量 - 估算出场手续费`
+/// - **做多（LONG）**: `(当前价格 - 平均入场价格) * 数量 * 合约乘数 - 估算出场手续费`
+/// - **做空（SHORT）**: `(平均入场价格 - 当前价格) * 数量 * 合约乘数 - 估算出场手续费`
 ///
 /// ## 手续费估算
 ///
@@ -717,6 +1048,7 @@ fn calculate_price_entry_average(
 /// - `quantity_abs_max`: 历史最大持仓数量（用于计算手续费比例）
 /// - `fees_enter`: 入场手续费
 /// - `price`: 用于计算盈亏的价格（通常是当前市场价格）
+/// - `contract_size`: 合约乘数，即每单位数量代表的标的数量（现货为 `1`）
 ///
 /// # 返回值
 ///
@@ -728,12 +1060,14 @@ pub fn calculate_pnl_unrealised(
     quantity_abs_max: Decimal,
     fees_enter: Decimal,
     price: Decimal,
+    contract_size: Decimal,
 ) -> Decimal {
     let approx_exit_fees =
         approximate_remaining_exit_fees(quantity_abs, quantity_abs_max, fees_enter);
 
-    let value_quote_current = quantity_abs * price;
-    let value_quote_entry = quantity_abs * price_entry_average;
+    let quantity_underlying = quantity_abs * contract_size;
+    let value_quote_current = quantity_underlying * price;
+    let value_quote_entry = quantity_underlying * price_entry_average;
 
     match position_side {
         Side::Buy => value_quote_current - value_quote_entry - approx_exit_fees,
@@ -741,6 +1075,37 @@ pub fn calculate_pnl_unrealised(
     }
 }
 
+/// 计算以提供的价格平仓反向合约仓位 `quantity_abs` 时的估算未实现盈亏（以基础资产计价）。
+///
+/// ## 计算公式
+///
+/// - **做多（LONG）**: `数量 / 平均入场价格 - 数量 / 当前价格 - 估算出场手续费`
+/// - **做空（SHORT）**: `数量 / 当前价格 - 数量 / 平均入场价格 - 估算出场手续费`
+///
+/// 参数与 [`calculate_pnl_unrealised`] 相同，其中 `quantity_abs * contract_size` 为合约面值，
+/// `fees_enter` 以基础资产计价。
+pub fn calculate_pnl_unrealised_inverse(
+    position_side: Side,
+    price_entry_average: Decimal,
+    quantity_abs: Decimal,
+    quantity_abs_max: Decimal,
+    fees_enter: Decimal,
+    price: Decimal,
+    contract_size: Decimal,
+) -> Decimal {
+    let approx_exit_fees =
+        approximate_remaining_exit_fees(quantity_abs, quantity_abs_max, fees_enter);
+
+    let quantity_face = quantity_abs * contract_size;
+    let value_base_current = inverse_value_base(quantity_face, price);
+    let value_base_entry = inverse_value_base(quantity_face, price_entry_average);
+
+    match position_side {
+        Side::Buy => value_base_entry - value_base_current - approx_exit_fees,
+        Side::Sell => value_base_current - value_base_entry - approx_exit_fees,
+    }
+}
+
 /// Approximate the exit fees from closing a [`Position`] with `quantity_abs`.
 ///
 /// The `fees_enter` value was the fee cost to enter a [`Position`] of `quantity_abs_max`,
@@ -760,8 +1125,8 @@ fn approximate_remaining_exit_fees(
 ///
 /// ## 计算公式
 ///
-/// - **做多（LONG）**: `(平仓价格 - 平均入场价格) * 平仓数量 - 平仓手续费`
-/// - **做空（SHORT）**: `(平均入场价格 - 平仓价格) * 平仓数量 - 平仓手续费`
+/// - **做多（LONG）**: `(平仓价格 - 平均入场价格) * 平仓数量 * 合约乘数 - 平仓手续费`
+/// - **做空（SHORT）**: `(平均入场价格 - 平仓价格) * 平仓数量 * 合约乘数 - 平仓手续费`
 ///
 /// # 参数
 ///
@@ -770,6 +1135,7 @@ fn approximate_remaining_exit_fees(
 /// - `closed_quantity`: 已平仓的数量
 /// - `closed_price`: 平仓价格
 /// - `closed_fee`: 平仓手续费
+/// - `contract_size`: 合约乘数，即每单位数量代表的标的数量（现货为 `1`）
 ///
 /// # 返回值
 ///
@@ -780,8 +1146,9 @@ pub fn calculate_pnl_realised(
     closed_quantity: Decimal,
     closed_price: Decimal,
     closed_fee: Decimal,
+    contract_size: Decimal,
 ) -> Decimal {
-    let close_quantity = closed_quantity.abs();
+    let close_quantity = closed_quantity.abs() * contract_size;
     let value_quote_closed = close_quantity * closed_price;
     let value_quote_entry = close_quantity * price_entry_average;
 
@@ -791,6 +1158,33 @@ pub fn calculate_pnl_realised(
     }
 }
 
+/// 计算以指定价格和手续费平仓反向合约仓位数量时产生的已实现盈亏（以基础资产计价）。
+///
+/// ## 计算公式
+///
+/// - **做多（LONG）**: `平仓数量 / 平均入场价格 - 平仓数量 / 平仓价格 - 平仓手续费`
+/// - **做空（SHORT）**: `平仓数量 / 平仓价格 - 平仓数量 / 平均入场价格 - 平仓手续费`
+///
+/// 参数与 [`calculate_pnl_realised`] 相同，其中 `closed_quantity * contract_size` 为合约面值，
+/// `closed_fee` 以基础资产计价。
+pub fn calculate_pnl_realised_inverse(
+    position_side: Side,
+    price_entry_average: Decimal,
+    closed_quantity: Decimal,
+    closed_price: Decimal,
+    closed_fee: Decimal,
+    contract_size: Decimal,
+) -> Decimal {
+    let close_quantity = closed_quantity.abs() * contract_size;
+    let value_base_closed = inverse_value_base(close_quantity, closed_price);
+    let value_base_entry = inverse_value_base(close_quantity, price_entry_average);
+
+    match position_side {
+        Side::Buy => value_base_entry - value_base_closed - closed_fee,
+        Side::Sell => value_base_closed - value_base_entry - closed_fee,
+    }
+}
+
 /// 计算盈亏回报率。
 ///
 /// 此函数计算投资的回报率（ROI），公式为：`已实现盈亏 / 投资成本`
@@ -833,9 +1227,313 @@ pub fn calculate_pnl_return(
 mod tests {
     use super::*;
     use crate::test_utils::{time_plus_days, trade};
-    use barter_instrument::instrument::name::InstrumentNameInternal;
+    use barter_instrument::instrument::{name::InstrumentNameInternal, spec::RoundingMode};
     use rust_decimal_macros::dec;
 
+    #[test]
+    fn test_position_manager_hedging_mode() {
+        let base_time = DateTime::<Utc>::MIN_UTC;
+
+        // Netting: opposite side trades net into a single Position
+        let mut netting = PositionManager::default();
+        assert!(
+            netting
+                .update_from_trade(&trade(base_time, Side::Buy, 100.0, 1.0, 0.0))
+                .is_none()
+        );
+        let exited = netting.update_from_trade(&trade(base_time, Side::Sell, 110.0, 1.0, 0.0));
+        assert_eq!(exited.unwrap().pnl_realised, dec!(10.0));
+        assert_eq!(netting.positions().count(), 0);
+
+        // Hedging: opposite side trades open two independent Positions
+        let mut hedging = PositionManager::with_mode(PositionMode::Hedging);
+        assert!(
+            hedging
+                .update_from_trade(&trade(base_time, Side::Buy, 100.0, 1.0, 0.0))
+                .is_none()
+        );
+        assert!(
+            hedging
+                .update_from_trade(&trade(base_time, Side::Sell, 110.0, 2.0, 0.0))
+                .is_none()
+        );
+        assert!(hedging.current.is_none());
+        assert_eq!(hedging.positions().count(), 2);
+
+        let long = hedging.long.as_ref().unwrap();
+        assert_eq!(long.side, Side::Buy);
+        assert_eq!(long.quantity_abs, dec!(1.0));
+        assert_eq!(long.price_entry_average, dec!(100.0));
+
+        let short = hedging.short.as_ref().unwrap();
+        assert_eq!(short.side, Side::Sell);
+        assert_eq!(short.quantity_abs, dec!(2.0));
+        assert_eq!(short.price_entry_average, dec!(110.0));
+
+        // Hedging: same side trades increase the matching Position
+        hedging.update_from_trade(&trade(base_time, Side::Buy, 120.0, 1.0, 0.0));
+        assert_eq!(hedging.long.as_ref().unwrap().quantity_abs, dec!(2.0));
+        assert_eq!(hedging.short.as_ref().unwrap().quantity_abs, dec!(2.0));
+
+        // Hedging: reducing the short Position leaves the long Position untouched
+        let exited = hedging
+            .update_from_hedged_trade(&trade(base_time, Side::Buy, 100.0, 1.0, 0.0), Side::Sell);
+        assert!(exited.is_none());
+        assert_eq!(hedging.short.as_ref().unwrap().quantity_abs, dec!(1.0));
+        assert_eq!(hedging.long.as_ref().unwrap().quantity_abs, dec!(2.0));
+
+        // Hedging: closing the long Position emits PositionExited, excess is not flipped
+        let exited = hedging
+            .update_from_hedged_trade(&trade(base_time, Side::Sell, 120.0, 3.0, 0.0), Side::Buy)
+            .unwrap();
+        assert_eq!(exited.side, Side::Buy);
+        assert_eq!(exited.pnl_realised, dec!(20.0));
+        assert!(hedging.long.is_none());
+        assert_eq!(hedging.short.as_ref().unwrap().quantity_abs, dec!(1.0));
+
+        // Hedging: reducing a non-existent Position is ignored
+        assert!(
+            hedging
+                .update_from_hedged_trade(&trade(base_time, Side::Sell, 120.0, 1.0, 0.0), Side::Buy)
+                .is_none()
+        );
+        assert_eq!(hedging.positions().count(), 1);
+    }
+
+    #[test]
+    fn test_position_maker_rebate_increases_pnl_realised() {
+        let base_time = DateTime::<Utc>::MIN_UTC;
+        let mut manager = PositionManager::default();
+
+        // Open LONG 1 @ 100 with a maker rebate (negative fee) of 0.1
+        manager.update_from_trade(&trade(base_time, Side::Buy, 100.0, 1.0, -0.1));
+        let position = manager.current.as_mut().unwrap();
+        assert_eq!(position.pnl_realised, dec!(0.1));
+        assert_eq!(position.fees_enter.fees, dec!(-0.1));
+
+        // Approximate exit rebate is included in unrealised PnL
+        position.update_pnl_unrealised(dec!(100));
+        assert_eq!(position.pnl_unrealised, dec!(0.1));
+
+        // Close LONG 1 @ 100 with another maker rebate of 0.1
+        let exited = manager
+            .update_from_trade(&trade(base_time, Side::Sell, 100.0, 1.0, -0.1))
+            .unwrap();
+        assert_eq!(exited.pnl_realised, dec!(0.2));
+        assert_eq!(exited.fees_exit.fees, dec!(-0.1));
+    }
+
+    #[test]
+    fn test_position_manager_hedging_mode_reduce_only_trade() {
+        let base_time = DateTime::<Utc>::MIN_UTC;
+        let mut hedging = PositionManager::with_mode(PositionMode::Hedging);
+
+        // Open SHORT 1
+        hedging.update_from_trade(&trade(base_time, Side::Sell, 100.0, 1.0, 0.0));
+
+        // Reduce-only Buy reduces the SHORT rather than opening a LONG, excess is not flipped
+        let exited = hedging
+            .update_from_trade(&Trade {
+                reduce_only: true,
+                ..trade(base_time, Side::Buy, 90.0, 5.0, 0.0)
+            })
+            .unwrap();
+        assert_eq!(exited.side, Side::Sell);
+        assert_eq!(exited.pnl_realised, dec!(10.0));
+        assert!(hedging.long.is_none());
+        assert!(hedging.short.is_none());
+    }
+
+    #[test]
+    fn test_position_manager_inverse_settlement() {
+        // Known BTC-margined inverse example (eg/ BitMEX XBTUSD): 10,000 USD contract value
+        let base_time = DateTime::<Utc>::MIN_UTC;
+
+        // LONG 10,000 @ 8,000 -> close @ 10,000 = 10,000/8,000 - 10,000/10,000 = 0.25 BTC
+        let mut long = PositionManager::default().with_settlement(PositionSettlement::Inverse);
+        long.update_from_trade(&trade(base_time, Side::Buy, 8_000.0, 10_000.0, 0.0001));
+        let position = long.current.as_mut().unwrap();
+        assert_eq!(position.settlement, PositionSettlement::Inverse);
+        position.update_pnl_unrealised(dec!(10_000));
+        assert_eq!(position.pnl_unrealised, dec!(0.2499));
+
+        let exited = long
+            .update_from_trade(&trade(base_time, Side::Sell, 10_000.0, 10_000.0, 0.0001))
+            .unwrap();
+        assert_eq!(exited.pnl_realised, dec!(0.2498));
+
+        // SHORT 10,000 @ 10,000 -> close @ 8,000 = 10,000/8,000 - 10,000/10,000 = 0.25 BTC
+        let mut short = PositionManager::default().with_settlement(PositionSettlement::Inverse);
+        short.update_from_trade(&trade(base_time, Side::Sell, 10_000.0, 10_000.0, 0.0));
+        let exited = short
+            .update_from_trade(&trade(base_time, Side::Buy, 8_000.0, 10_000.0, 0.0))
+            .unwrap();
+        assert_eq!(exited.pnl_realised, dec!(0.25));
+
+        // SHORT 10,000 @ 8,000 -> flip @ 10,000 with 20,000: loss of 0.25 BTC & new inverse LONG
+        let mut flip = PositionManager::default().with_settlement(PositionSettlement::Inverse);
+        flip.update_from_trade(&trade(base_time, Side::Sell, 8_000.0, 10_000.0, 0.0));
+        let exited = flip
+            .update_from_trade(&trade(base_time, Side::Buy, 10_000.0, 20_000.0, 0.0))
+            .unwrap();
+        assert_eq!(exited.pnl_realised, dec!(-0.25));
+        let next = flip.current.as_ref().unwrap();
+        assert_eq!(next.side, Side::Buy);
+        assert_eq!(next.settlement, PositionSettlement::Inverse);
+
+        // Entry average is the quantity-weighted harmonic mean, so PnL remains exact in BTC:
+        // LONG 10,000 @ 8,000 + 10,000 @ 10,000 -> close 20,000 @ 10,000 = 1.25 + 1 - 2 = 0.25
+        let mut increase = PositionManager::default().with_settlement(PositionSettlement::Inverse);
+        increase.update_from_trade(&trade(base_time, Side::Buy, 8_000.0, 10_000.0, 0.0));
+        increase.update_from_trade(&trade(base_time, Side::Buy, 10_000.0, 10_000.0, 0.0));
+        let exited = increase
+            .update_from_trade(&trade(base_time, Side::Sell, 10_000.0, 20_000.0, 0.0))
+            .unwrap();
+        assert!((exited.pnl_realised - dec!(0.25)).abs() < dec!(0.0000000001));
+
+        // Linear settlement of the same LONG trades is denominated in the quote asset
+        let mut linear = PositionManager::default();
+        linear.update_from_trade(&trade(base_time, Side::Buy, 8_000.0, 1.0, 0.0));
+        let exited = linear
+            .update_from_trade(&trade(base_time, Side::Sell, 10_000.0, 1.0, 0.0))
+            .unwrap();
+        assert_eq!(exited.pnl_realised, dec!(2_000));
+    }
+
+    #[test]
+    fn test_position_manager_inverse_settlement_zero_price_does_not_panic() {
+        let base_time = DateTime::<Utc>::MIN_UTC;
+
+        // Zero-priced trades and marks have no defined base asset value, so they contribute zero
+        let mut manager = PositionManager::default().with_settlement(PositionSettlement::Inverse);
+        manager.update_from_trade(&trade(base_time, Side::Buy, 0.0, 10_000.0, 0.0));
+        let position = manager.current.as_mut().unwrap();
+        assert_eq!(position.price_entry_average, Decimal::ZERO);
+        position.update_pnl_unrealised(Decimal::ZERO);
+        assert_eq!(position.pnl_unrealised, Decimal::ZERO);
+
+        let mut manager = PositionManager::default().with_settlement(PositionSettlement::Inverse);
+        manager.update_from_trade(&trade(base_time, Side::Buy, 8_000.0, 10_000.0, 0.0));
+        manager
+            .current
+            .as_mut()
+            .unwrap()
+            .update_pnl_unrealised(Decimal::ZERO);
+        let exited = manager
+            .update_from_trade(&trade(base_time, Side::Sell, 0.0, 10_000.0, 0.0))
+            .unwrap();
+        assert_eq!(exited.pnl_realised, dec!(1.25));
+    }
+
+    #[test]
+    fn test_position_manager_contract_size() {
+        // Futures contract with a multiplier of 100 (eg/ 1 contract = 100 units) versus spot
+        let base_time = DateTime::<Utc>::MIN_UTC;
+
+        let mut spot = PositionManager::default();
+        let mut future = PositionManager::default().with_contract_size(dec!(100));
+
+        // LONG 2 @ 100
+        spot.update_from_trade(&trade(base_time, Side::Buy, 100.0, 2.0, 1.0));
+        future.update_from_trade(&trade(base_time, Side::Buy, 100.0, 2.0, 1.0));
+
+        // Mark @ 110: spot = (110-100)*2 - 1 = 19, future = (110-100)*2*100 - 1 = 1999
+        let spot_position = spot.current.as_mut().unwrap();
+        spot_position.update_pnl_unrealised(dec!(110));
+        assert_eq!(spot_position.contract_size, Decimal::ONE);
+        assert_eq!(spot_position.pnl_unrealised, dec!(19));
+
+        let future_position = future.current.as_mut().unwrap();
+        future_position.update_pnl_unrealised(dec!(110));
+        assert_eq!(future_position.contract_size, dec!(100));
+        assert_eq!(future_position.pnl_unrealised, dec!(1999));
+
+        // Reduce 1 @ 120: spot = -1 + (120-100)*1 - 1 = 18, future = -1 + (120-100)*1*100 - 1 = 1998
+        spot.update_from_trade(&trade(base_time, Side::Sell, 120.0, 1.0, 1.0));
+        future.update_from_trade(&trade(base_time, Side::Sell, 120.0, 1.0, 1.0));
+        assert_eq!(spot.current.as_ref().unwrap().pnl_realised, dec!(18));
+        assert_eq!(future.current.as_ref().unwrap().pnl_realised, dec!(1998));
+
+        // Flip with SELL 3 @ 90: closes 1 @ 90 & opens SHORT 2 with the same contract size
+        let spot_exited = spot
+            .update_from_trade(&trade(base_time, Side::Sell, 90.0, 3.0, 0.0))
+            .unwrap();
+        let future_exited = future
+            .update_from_trade(&trade(base_time, Side::Sell, 90.0, 3.0, 0.0))
+            .unwrap();
+        assert_eq!(spot_exited.pnl_realised, dec!(8)); // 18 + (90-100)*1
+        assert_eq!(future_exited.pnl_realised, dec!(998)); // 1998 + (90-100)*1*100
+
+        let future_next = future.current.as_ref().unwrap();
+        assert_eq!(future_next.side, Side::Sell);
+        assert_eq!(future_next.contract_size, dec!(100));
+
+        // Linear PnL scales exactly by the contract size (ignoring fees)
+        assert_eq!(
+            calculate_pnl_realised(Side::Buy, dec!(100), dec!(2), dec!(125), dec!(0), dec!(100)),
+            dec!(100)
+                * calculate_pnl_realised(
+                    Side::Buy,
+                    dec!(100),
+                    dec!(2),
+                    dec!(125),
+                    dec!(0),
+                    Decimal::ONE
+                ),
+        );
+    }
+
+    #[test]
+    fn test_position_manager_rounding() {
+        let base_time = DateTime::<Utc>::MIN_UTC;
+
+        let exit = |rounding: Option<DecimalRounding>| {
+            let mut manager = PositionManager::default();
+            if let Some(rounding) = rounding {
+                manager = manager.with_rounding(rounding);
+            }
+
+            // LONG 1 @ 100, then mark & close @ 100.025 with a PnL of 0.025
+            manager.update_from_trade(&trade(base_time, Side::Buy, 100.0, 1.0, 0.0));
+            let position = manager.current.as_mut().unwrap();
+            position.update_pnl_unrealised(dec!(100.025));
+            let pnl_unrealised = position.pnl_unrealised;
+
+            let exited = manager
+                .update_from_trade(&trade(base_time, Side::Sell, 100.025, 1.0, 0.0))
+                .unwrap();
+
+            (pnl_unrealised, exited.pnl_realised)
+        };
+
+        // Default behaviour does not round
+        assert_eq!(exit(None), (dec!(0.025), dec!(0.025)));
+
+        // HalfUp rounds the midpoint away from zero
+        assert_eq!(
+            exit(Some(DecimalRounding::new(RoundingMode::HalfUp, 2))),
+            (dec!(0.03), dec!(0.03))
+        );
+
+        // HalfEven rounds the midpoint to the nearest even cent
+        assert_eq!(
+            exit(Some(DecimalRounding::new(RoundingMode::HalfEven, 2))),
+            (dec!(0.02), dec!(0.02))
+        );
+
+        // Prorated flip fees are rounded, and rounding carries over to the next Position
+        let mut manager = PositionManager::default()
+            .with_rounding(DecimalRounding::new(RoundingMode::HalfEven, 2));
+        manager.update_from_trade(&trade(base_time, Side::Buy, 100.0, 1.0, 0.0));
+        let exited = manager
+            .update_from_trade(&trade(base_time, Side::Sell, 100.0, 8.0, 0.2))
+            .unwrap();
+        assert_eq!(exited.fees_exit.fees, dec!(0.02)); // 0.2 * 1/8 = 0.025
+        let next = manager.current.as_ref().unwrap();
+        assert_eq!(next.fees_enter.fees, dec!(0.18)); // 0.2 * 7/8 = 0.175
+        assert_eq!(next.rounding, manager.rounding);
+    }
+
     #[test]
     fn test_position_update_from_trade() {
         struct TestCase {
@@ -871,6 +1569,9 @@ mod tests {
                     time_enter: base_time,
                     time_exchange_update: time_plus_days(base_time, 1),
                     trades: vec![TradeId::new("trade_id"), TradeId::new("trade_id")],
+                    settlement: PositionSettlement::Linear,
+                    contract_size: Decimal::ONE,
+                    rounding: None,
                 }),
                 expected_position_exited: None,
             },
@@ -897,6 +1598,9 @@ mod tests {
                     time_enter: base_time,
                     time_exchange_update: time_plus_days(base_time, 1),
                     trades: vec![TradeId::new("trade_id"), TradeId::new("trade_id")],
+                    settlement: PositionSettlement::Linear,
+                    contract_size: Decimal::ONE,
+                    rounding: None,
                 }),
                 expected_position_exited: None,
             },
@@ -947,6 +1651,9 @@ mod tests {
                     time_enter: time_plus_days(base_time, 1),
                     time_exchange_update: time_plus_days(base_time, 1),
                     trades: vec![TradeId::new("trade_id")],
+                    settlement: PositionSettlement::Linear,
+                    contract_size: Decimal::ONE,
+                    rounding: None,
                 }),
                 expected_position_exited: Some(PositionExited {
                     instrument: InstrumentNameInternal::new("instrument"),
@@ -990,6 +1697,9 @@ mod tests {
                     time_enter: base_time,
                     time_exchange_update: base_time,
                     trades: vec![TradeId::new("trade_id"), TradeId::new("trade_id")],
+                    settlement: PositionSettlement::Linear,
+                    contract_size: Decimal::ONE,
+                    rounding: None,
                 }),
                 expected_position_exited: None,
             },
@@ -1016,6 +1726,9 @@ mod tests {
                     time_enter: base_time,
                     time_exchange_update: base_time,
                     trades: vec![TradeId::new("trade_id"), TradeId::new("trade_id")],
+                    settlement: PositionSettlement::Linear,
+                    contract_size: Decimal::ONE,
+                    rounding: None,
                 }),
                 expected_position_exited: None,
             },
@@ -1066,6 +1779,9 @@ mod tests {
                     time_enter: base_time,
                     time_exchange_update: base_time,
                     trades: vec![TradeId::new("trade_id")],
+                    settlement: PositionSettlement::Linear,
+                    contract_size: Decimal::ONE,
+                    rounding: None,
                 }),
                 expected_position_exited: Some(PositionExited {
                     instrument: InstrumentNameInternal::new("instrument"),
@@ -1257,6 +1973,7 @@ mod tests {
                 test.quantity_abs_max,
                 test.fees_enter,
                 test.price,
+                Decimal::ONE,
             );
 
             assert_eq!(actual, test.expected, "TC{} failed", index);
@@ -1443,6 +2160,7 @@ mod tests {
                 test.closed_quantity.into(),
                 test.closed_price.into(),
                 test.closed_fee.into(),
+                Decimal::ONE,
             );
 
             assert_eq!(actual, test.expected, "TC{} failed", index);
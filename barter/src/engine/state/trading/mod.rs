@@ -6,6 +6,7 @@
 //!
 //! - **TradingState**: 交易状态枚举，表示 Engine 是否启用交易
 //! - **Enabled**: 交易启用，Engine 会生成算法订单
+//! - **PostOnly**: 仅被动交易，Engine 只会发送不会穿越订单簿的挂单（Maker）开仓请求和取消请求
 //! - **Disabled**: 交易禁用，Engine 不会生成算法订单，但仍会处理命令和更新状态
 //!
 //! # 使用场景
@@ -13,15 +14,19 @@
 //! - 控制 Engine 的交易行为
 //! - 暂停/恢复算法交易
 //! - 系统维护时禁用交易
+//! - 市场剧烈波动时仅允许被动挂单，避免吃单成本
 
+use barter_execution::order::{OrderKind, TimeInForce, request::RequestOpen};
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
 /// 表示 `Engine` 的当前 `TradingState`（交易状态）。
 ///
-/// TradingState 控制 Engine 是否生成算法订单。它有两个状态：
+/// TradingState 控制 Engine 是否生成算法订单。它有三个状态：
 ///
 /// - **Enabled**: Engine 会使用 `AlgoStrategy` 实现生成算法订单
+/// - **PostOnly**: Engine 会生成算法订单，但只发送取消请求以及被动的限价 post-only
+///   开仓请求；任何可能穿越订单簿的开仓请求（例如市价单）都会被拒绝
 /// - **Disabled**: Engine 会继续基于输入事件更新状态，但不会生成算法订单。
 ///   在此状态下，`Commands` 仍然会被执行（例如"开仓"、"取消订单"、"平仓"等）
 ///
@@ -50,6 +55,8 @@ use tracing::info;
 pub enum TradingState {
     /// 交易启用：Engine 会生成算法订单。
     Enabled,
+    /// 仅被动交易：Engine 会生成算法订单，但只允许取消请求和限价 post-only 开仓请求。
+    PostOnly,
     /// 交易禁用：Engine 不会生成算法订单，但仍会处理命令和更新状态（默认状态）。
     #[default]
     Disabled,
@@ -83,21 +90,29 @@ impl TradingState {
     pub fn update(&mut self, update: TradingState) -> TradingStateUpdateAudit {
         let prev = *self;
         let next = match (*self, update) {
-            (TradingState::Enabled, TradingState::Disabled) => {
-                info!("EngineState setting TradingState::Disabled");
+            (TradingState::Disabled, TradingState::Disabled) => {
+                info!("EngineState set TradingState::Disabled, although it was already disabled");
                 TradingState::Disabled
             }
-            (TradingState::Disabled, TradingState::Enabled) => {
-                info!("EngineState setting TradingState::Enabled");
-                TradingState::Enabled
+            (_, TradingState::Disabled) => {
+                info!("EngineState setting TradingState::Disabled");
+                TradingState::Disabled
             }
             (TradingState::Enabled, TradingState::Enabled) => {
                 info!("EngineState set TradingState::Enabled, although it was already enabled");
                 TradingState::Enabled
             }
-            (TradingState::Disabled, TradingState::Disabled) => {
-                info!("EngineState set TradingState::Disabled, although it was already disabled");
-                TradingState::Disabled
+            (_, TradingState::Enabled) => {
+                info!("EngineState setting TradingState::Enabled");
+                TradingState::Enabled
+            }
+            (TradingState::PostOnly, TradingState::PostOnly) => {
+                info!("EngineState set TradingState::PostOnly, although it was already post-only");
+                TradingState::PostOnly
+            }
+            (_, TradingState::PostOnly) => {
+                info!("EngineState setting TradingState::PostOnly");
+                TradingState::PostOnly
             }
         };
 
@@ -108,6 +123,46 @@ impl TradingState {
             current: next,
         }
     }
+
+    /// 如果 Engine 在此 `TradingState` 下应该生成算法订单，返回 `true`。
+    ///
+    /// `Enabled` 和 `PostOnly` 状态都会生成算法订单（`PostOnly` 会额外过滤开仓请求），
+    /// 只有 `Disabled` 状态不会生成算法订单。
+    pub fn generates_algo_orders(&self) -> bool {
+        matches!(self, TradingState::Enabled | TradingState::PostOnly)
+    }
+
+    /// 如果此 `TradingState` 允许发送给定的算法开仓请求，返回 `true`。
+    ///
+    /// - `Enabled`: 允许所有开仓请求
+    /// - `PostOnly`: 仅允许 [`OrderKind::Limit`] 且 [`TimeInForce::GoodUntilCancelled`]
+    ///   `post_only: true` 的开仓请求，这类订单保证不会穿越订单簿成为 Taker
+    /// - `Disabled`: 不允许任何算法开仓请求
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let request = RequestOpen {
+    ///     side: Side::Buy,
+    ///     price: dec!(100),
+    ///     quantity: dec!(1),
+    ///     kind: OrderKind::Market,
+    ///     time_in_force: TimeInForce::ImmediateOrCancel,
+    ///     reduce_only: false,
+    /// };
+    ///
+    /// assert!(!TradingState::PostOnly.permits_open(&request));
+    /// ```
+    pub fn permits_open(&self, request: &RequestOpen) -> bool {
+        match self {
+            TradingState::Enabled => true,
+            TradingState::PostOnly => {
+                request.kind == OrderKind::Limit
+                    && request.time_in_force == TimeInForce::GoodUntilCancelled { post_only: true }
+            }
+            TradingState::Disabled => false,
+        }
+    }
 }
 
 /// [`TradingState`] 更新的审计记录，包含之前和当前的状态。
@@ -131,6 +186,9 @@ pub struct TradingStateUpdateAudit {
 impl TradingStateUpdateAudit {
     /// 仅当之前的状态不是 `Disabled`，且新状态是 `Disabled` 时返回 `true`。
     ///
+    /// 注意：从 `PostOnly` 转换到 `Disabled` 同样视为转换到禁用状态，而在 `Enabled`
+    /// 和 `PostOnly` 之间的转换不会触发。
+    ///
     /// 此方法用于检测是否转换到禁用状态，这对于需要响应交易禁用的组件非常有用。
     ///
     /// # 返回值
@@ -155,6 +213,8 @@ impl TradingStateUpdateAudit {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use barter_instrument::Side;
+    use rust_decimal_macros::dec;
 
     struct TestCase {
         name: &'static str,
@@ -207,6 +267,56 @@ mod tests {
                     current: TradingState::Disabled,
                 },
             },
+            TestCase {
+                name: "PostOnly when enabled",
+                initial: TradingState::Enabled,
+                update: TradingState::PostOnly,
+                expected_state: TradingState::PostOnly,
+                expected_audit: TradingStateUpdateAudit {
+                    prev: TradingState::Enabled,
+                    current: TradingState::PostOnly,
+                },
+            },
+            TestCase {
+                name: "PostOnly when disabled",
+                initial: TradingState::Disabled,
+                update: TradingState::PostOnly,
+                expected_state: TradingState::PostOnly,
+                expected_audit: TradingStateUpdateAudit {
+                    prev: TradingState::Disabled,
+                    current: TradingState::PostOnly,
+                },
+            },
+            TestCase {
+                name: "PostOnly when already post-only",
+                initial: TradingState::PostOnly,
+                update: TradingState::PostOnly,
+                expected_state: TradingState::PostOnly,
+                expected_audit: TradingStateUpdateAudit {
+                    prev: TradingState::PostOnly,
+                    current: TradingState::PostOnly,
+                },
+            },
+            TestCase {
+                name: "Enable when post-only",
+                initial: TradingState::PostOnly,
+                update: TradingState::Enabled,
+                expected_state: TradingState::Enabled,
+                expected_audit: TradingStateUpdateAudit {
+                    prev: TradingState::PostOnly,
+                    current: TradingState::Enabled,
+                },
+            },
+            TestCase {
+                name: "Disable when post-only",
+                initial: TradingState::PostOnly,
+                update: TradingState::Disabled,
+                expected_state: TradingState::Disabled,
+                expected_audit: TradingStateUpdateAudit {
+                    prev: TradingState::PostOnly,
+                    current: TradingState::Disabled,
+                },
+            },
         ];
 
         for test in test_cases {
@@ -266,6 +376,26 @@ mod tests {
                     current: TradingState::Enabled,
                 },
             },
+            TestCase {
+                name: "Detect transition to disabled from post-only",
+                initial: TradingState::PostOnly,
+                update: TradingState::Disabled,
+                expected_state: TradingState::Disabled,
+                expected_audit: TradingStateUpdateAudit {
+                    prev: TradingState::PostOnly,
+                    current: TradingState::Disabled,
+                },
+            },
+            TestCase {
+                name: "No transition detected when switching to post-only",
+                initial: TradingState::Enabled,
+                update: TradingState::PostOnly,
+                expected_state: TradingState::PostOnly,
+                expected_audit: TradingStateUpdateAudit {
+                    prev: TradingState::Enabled,
+                    current: TradingState::PostOnly,
+                },
+            },
         ];
 
         for test in test_cases {
@@ -283,4 +413,78 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_trading_state_permits_open() {
+        struct PermitTestCase {
+            name: &'static str,
+            state: TradingState,
+            kind: OrderKind,
+            time_in_force: TimeInForce,
+            expected: bool,
+        }
+
+        let test_cases = vec![
+            PermitTestCase {
+                name: "Enabled permits Market order",
+                state: TradingState::Enabled,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                expected: true,
+            },
+            PermitTestCase {
+                name: "PostOnly refuses Market order",
+                state: TradingState::PostOnly,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                expected: false,
+            },
+            PermitTestCase {
+                name: "PostOnly refuses Limit order without post_only",
+                state: TradingState::PostOnly,
+                kind: OrderKind::Limit,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                expected: false,
+            },
+            PermitTestCase {
+                name: "PostOnly refuses Limit FillOrKill order",
+                state: TradingState::PostOnly,
+                kind: OrderKind::Limit,
+                time_in_force: TimeInForce::FillOrKill,
+                expected: false,
+            },
+            PermitTestCase {
+                name: "PostOnly permits Limit post_only order",
+                state: TradingState::PostOnly,
+                kind: OrderKind::Limit,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+                expected: true,
+            },
+            PermitTestCase {
+                name: "Disabled refuses Limit post_only order",
+                state: TradingState::Disabled,
+                kind: OrderKind::Limit,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+                expected: false,
+            },
+        ];
+
+        for test in test_cases {
+            let request = RequestOpen {
+                side: Side::Buy,
+                price: dec!(100),
+                quantity: dec!(1),
+                kind: test.kind,
+                time_in_force: test.time_in_force,
+                reduce_only: false,
+            };
+
+            assert_eq!(
+                test.state.permits_open(&request),
+                test.expected,
+                "Failed test '{}'",
+                test.name
+            );
+        }
+    }
 }
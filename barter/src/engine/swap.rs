@@ -0,0 +1,179 @@
+//! Engine Strategy 热替换模块
+//!
+//! 本模块定义了 [`SwapStrategy`] 请求，外部进程可以通过 [`Command::SwapStrategy`] 在运行时
+//! 替换 Engine 的 Strategy 实例（及其参数），而无需重启系统或丢失 `EngineState`。
+//!
+//! # 工作原理
+//!
+//! 1. 外部进程使用 [`SwapStrategy::new`] 包装新的 Strategy 实例
+//! 2. 请求作为 [`Command::SwapStrategy`] 发送给 Engine
+//! 3. 启用了 `Engine::with_strategy_swap` 的 Engine 在处理命令时取出新的 Strategy 并替换当前
+//!    Strategy，`EngineState` 保持不变（`SystemBuilder` 通过
+//!    `SystemBuilder::with_strategy_swap` 启用）
+//!
+//! Engine 按顺序处理事件，替换发生在两次 `process` 调用之间的命令处理过程中，因此任何事件
+//! 都只会由替换前或替换后的完整 Strategy 处理。替换命令之后的算法订单由新的 Strategy 生成。
+//!
+//! 新的 Strategy 必须与 Engine 的 Strategy 类型相同，并且 Engine 必须启用 Strategy 热替换，
+//! 否则替换会被忽略，并在 [`SwapStrategyOutput`] 中记录 `swapped: false`。
+//!
+//! # 使用示例
+//!
+//! ```rust,ignore
+//! system.swap_strategy(MyStrategy::new(new_parameters));
+//! ```
+//!
+//! [`Command::SwapStrategy`]: super::command::Command::SwapStrategy
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    any::Any,
+    cmp::Ordering,
+    fmt::{Debug, Formatter},
+    hash::{Hash, Hasher},
+    sync::{Arc, Mutex},
+};
+
+/// Strategy 热替换请求，包含类型擦除的新 Strategy 实例。
+///
+/// 请求可以被克隆（例如用于审计），但所有克隆共享同一个 Strategy 实例，因此最多只会被替换一次。
+///
+/// 新的 Strategy 实例不会被序列化：反序列化的 `SwapStrategy` 不包含 Strategy，Engine 会忽略它。
+#[derive(Debug, Clone, Default)]
+pub struct SwapStrategy(Arc<Mutex<Option<Box<dyn Any + Send>>>>);
+
+impl SwapStrategy {
+    /// 使用提供的新 Strategy 实例构造 `SwapStrategy` 请求。
+    pub fn new<Strategy>(strategy: Strategy) -> Self
+    where
+        Strategy: Send + 'static,
+    {
+        Self(Arc::new(Mutex::new(Some(Box::new(strategy)))))
+    }
+
+    /// 如果请求包含类型为 `Strategy` 的新 Strategy 实例，取出并返回它。
+    ///
+    /// 如果 Strategy 已经被取出，或类型不匹配，则返回 `None`。
+    pub fn take<Strategy>(&self) -> Option<Strategy>
+    where
+        Strategy: 'static,
+    {
+        let mut strategy = self.0.lock().ok()?;
+
+        if !strategy.as_ref()?.is::<Strategy>() {
+            return None;
+        }
+
+        strategy
+            .take()
+            .and_then(|strategy| strategy.downcast::<Strategy>().ok())
+            .map(|strategy| *strategy)
+    }
+}
+
+impl PartialEq for SwapStrategy {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl PartialOrd for SwapStrategy {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self == other).then_some(Ordering::Equal)
+    }
+}
+
+impl Serialize for SwapStrategy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_unit()
+    }
+}
+
+impl<'de> Deserialize<'de> for SwapStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <()>::deserialize(deserializer).map(|_| Self::default())
+    }
+}
+
+/// 从 [`SwapStrategy`] 请求中取出 `Strategy` 类型的新 Strategy 实例。
+///
+/// 启用 Strategy 热替换的 Engine 保存 `StrategySwap`，因此只有构造 `StrategySwap` 时要求
+/// Strategy 为 `'static`。所有 `StrategySwap<Strategy>` 行为相同，因此彼此相等。
+pub struct StrategySwap<Strategy>(fn(&SwapStrategy) -> Option<Strategy>);
+
+impl<Strategy> StrategySwap<Strategy> {
+    /// 构造取出 `Strategy` 类型新 Strategy 实例的 `StrategySwap`。
+    pub fn new() -> Self
+    where
+        Strategy: 'static,
+    {
+        Self(SwapStrategy::take::<Strategy>)
+    }
+
+    /// 如果请求包含 `Strategy` 类型的新 Strategy 实例，取出并返回它。
+    pub fn take(&self, swap: &SwapStrategy) -> Option<Strategy> {
+        (self.0)(swap)
+    }
+}
+
+impl<Strategy> Default for StrategySwap<Strategy>
+where
+    Strategy: 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Strategy> Debug for StrategySwap<Strategy> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StrategySwap").finish_non_exhaustive()
+    }
+}
+
+impl<Strategy> Clone for StrategySwap<Strategy> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Strategy> Copy for StrategySwap<Strategy> {}
+
+impl<Strategy> PartialEq for StrategySwap<Strategy> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<Strategy> Eq for StrategySwap<Strategy> {}
+
+impl<Strategy> PartialOrd for StrategySwap<Strategy> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Strategy> Ord for StrategySwap<Strategy> {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl<Strategy> Hash for StrategySwap<Strategy> {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+/// [`SwapStrategy`] 命令的执行输出。
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct SwapStrategyOutput {
+    /// Engine 的 Strategy 是否被替换
+    pub swapped: bool,
+}
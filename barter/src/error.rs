@@ -23,6 +23,20 @@ pub enum BarterError {
 
     #[error("JoinError: {0}")]
     JoinError(String),
+
+    #[error(
+        "EngineState schema version mismatch: expected {expected}, found {found} with no migration"
+    )]
+    StateSchemaVersion { expected: u32, found: u32 },
+
+    #[error("EngineState serialisation: {0}")]
+    StateSerde(String),
+
+    #[error("audit wire format version mismatch: expected {expected}, found {found}")]
+    AuditWireVersion { expected: u8, found: u8 },
+
+    #[error("audit codec: {0}")]
+    AuditCodec(String),
 }
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Error)]
 #[error("RxDropped")]
@@ -13,7 +13,11 @@ use crate::{
     engine::{clock::EngineClock, execution_tx::MultiExchangeTxMap},
     error::BarterError,
     execution::{
-        AccountStreamEvent, Execution, error::ExecutionError, manager::ExecutionManager,
+        AccountStreamEvent, Execution,
+        coalesce::coalesce_balance_snapshots,
+        error::ExecutionError,
+        manager::{DeadMansSwitchConfig, ExecutionManager},
+        paper::PaperMarketFeed,
         request::ExecutionRequest,
     },
     shutdown::AsyncShutdown,
@@ -25,7 +29,7 @@ use barter_execution::{
     UnindexedAccountEvent,
     client::{
         ExecutionClient,
-        mock::{MockExecution, MockExecutionClientConfig, MockExecutionConfig},
+        mock::{MockExecution, MockExecutionClientConfig, MockExecutionConfig, MockFillPrice},
     },
     exchange::mock::{MockExchange, request::MockExchangeRequest},
     indexer::AccountEventIndexer,
@@ -98,6 +102,10 @@ pub struct ExecutionBuilder<'a> {
     mock_exchange_futures: Vec<RunFuture>,
     /// 执行管理器初始化 Future 集合。
     execution_init_futures: Vec<ExecutionInitFuture>,
+    /// 可选的余额快照合并时间窗口。
+    balance_coalescing: Option<Duration>,
+    /// 可选的实盘纸面交易馈送。
+    paper_market_feed: Option<PaperMarketFeed>,
 }
 
 impl<'a> ExecutionBuilder<'a> {
@@ -117,6 +125,36 @@ impl<'a> ExecutionBuilder<'a> {
             merged_channel: Channel::default(),
             mock_exchange_futures: Vec::default(),
             execution_init_futures: Vec::default(),
+            balance_coalescing: None,
+            paper_market_feed: None,
+        }
+    }
+
+    /// 在提供的时间窗口内按资产合并每个交易所账户流的余额快照。
+    ///
+    /// 窗口内同一资产的多个 `BalanceSnapshot` 只会将最新的一个传递给 Engine，从而减少
+    /// 高频余额更新造成的状态和统计更新。完整账户快照、订单和成交事件不会被合并。
+    ///
+    /// 仅应用于此后添加的执行链接，因此应在添加交易所配置之前调用。
+    ///
+    /// 更多信息请参阅 [`BalanceCoalescer`](super::coalesce::BalanceCoalescer)。
+    pub fn with_balance_coalescing(self, window: Duration) -> Self {
+        Self {
+            balance_coalescing: Some(window),
+            ..self
+        }
+    }
+
+    /// 使用提供的 [`PaperMarketFeed`] 为 [`MockExchange`] 提供市场价格和最优买卖价，
+    /// 用于以 [`MockFillPrice::Market`] 成交（实盘纸面交易模式）和重新定价 peg 订单。
+    ///
+    /// 仅应用于此后添加的模拟交易所，因此应在添加交易所配置之前调用。
+    ///
+    /// 更多信息请参阅 [`paper`](super::paper) 模块。
+    pub fn with_paper_market_feed(self, paper_market_feed: PaperMarketFeed) -> Self {
+        Self {
+            paper_market_feed: Some(paper_market_feed),
+            ..self
         }
     }
 
@@ -134,6 +172,9 @@ impl<'a> ExecutionBuilder<'a> {
     /// - `config`: 模拟执行配置
     /// - `clock`: Engine 时钟
     ///
+    /// 如果提供了 [`Self::with_paper_market_feed`]，`MockExchange` 订阅其市场价格和最优买卖价。
+    /// 使用 [`MockFillPrice::Market`] 的配置必须提供。
+    ///
     /// # 返回值
     ///
     /// 返回更新后的 ExecutionBuilder，如果配置无效则返回错误。
@@ -151,6 +192,24 @@ impl<'a> ExecutionBuilder<'a> {
         let (request_tx, request_rx) = mpsc::unbounded_channel();
         let (event_tx, event_rx) = broadcast::channel(ACCOUNT_STREAM_CAPACITY);
 
+        // Register market price & best bid and offer forwarding Future
+        if let Some(paper_market_feed) = &self.paper_market_feed {
+            let clock = clock.clone();
+            let forward_prices_future = paper_market_feed.forward_to_mock_exchange(
+                self.instruments,
+                config.mocked_exchange,
+                request_tx.clone(),
+                move || clock.time(),
+            );
+            self.mock_exchange_futures
+                .push(Box::pin(forward_prices_future));
+        } else if config.fill_price == MockFillPrice::Market {
+            return Err(BarterError::ExecutionBuilder(format!(
+                "MockExchange with market fill price requires a PaperMarketFeed: {}",
+                config.mocked_exchange
+            )));
+        }
+
         let mock_execution_client_config = MockExecutionClientConfig {
             mocked_exchange: config.mocked_exchange,
             clock: move || clock.time(),
@@ -166,6 +225,7 @@ impl<'a> ExecutionBuilder<'a> {
             mock_execution_client_config.mocked_exchange,
             mock_execution_client_config,
             DUMMY_EXECUTION_REQUEST_TIMEOUT,
+            None,
         )
     }
 
@@ -206,7 +266,42 @@ impl<'a> ExecutionBuilder<'a> {
         Client::AccountStream: Send,
         Client::Config: Send,
     {
-        self.add_execution::<Client>(Client::EXCHANGE, config, request_timeout)
+        self.add_execution::<Client>(Client::EXCHANGE, config, request_timeout, None)
+    }
+
+    /// 为真实交易所添加启用死人开关的 [`ExecutionManager`]。
+    ///
+    /// 与 [`Self::add_live`] 相同，但 ExecutionManager 会按照提供的 [`DeadMansSwitchConfig`]
+    /// 定期向交易所发送 cancel-all-after 心跳，因此如果系统停止运行或 Engine 卡住，挂单会被交易所
+    /// 自动取消。应通过 `SystemBuilder::execution_heartbeat` 以小于 `timeout` 的间隔发送 Engine
+    /// 存活心跳，否则 Engine 长时间没有发送请求时挂单也会被取消。
+    ///
+    /// # 参数
+    ///
+    /// - `config`: 客户端配置
+    /// - `request_timeout`: 请求超时时间
+    /// - `dead_mans_switch`: 死人开关配置
+    ///
+    /// # 返回值
+    ///
+    /// 返回更新后的 ExecutionBuilder，如果配置无效则返回错误。
+    pub fn add_live_with_dead_mans_switch<Client>(
+        self,
+        config: Client::Config,
+        request_timeout: Duration,
+        dead_mans_switch: DeadMansSwitchConfig,
+    ) -> Result<Self, BarterError>
+    where
+        Client: ExecutionClient + Send + Sync + 'static,
+        Client::AccountStream: Send,
+        Client::Config: Send,
+    {
+        self.add_execution::<Client>(
+            Client::EXCHANGE,
+            config,
+            request_timeout,
+            Some(dead_mans_switch),
+        )
     }
 
     fn add_execution<Client>(
@@ -214,6 +309,7 @@ impl<'a> ExecutionBuilder<'a> {
         exchange: ExchangeId,
         config: Client::Config,
         request_timeout: Duration,
+        dead_mans_switch: Option<DeadMansSwitchConfig>,
     ) -> Result<Self, BarterError>
     where
         Client: ExecutionClient + Send + Sync + 'static,
@@ -235,6 +331,7 @@ impl<'a> ExecutionBuilder<'a> {
         }
 
         let merged_tx = self.merged_channel.tx.clone();
+        let balance_coalescing = self.balance_coalescing;
 
         // Init ExecutionManager Future
         let future_result = ExecutionManager::init(
@@ -245,10 +342,19 @@ impl<'a> ExecutionBuilder<'a> {
             STREAM_RECONNECTION_POLICY,
         );
 
-        let future_result = future_result.map(|result| {
+        let future_result = future_result.map(move |result| {
             result.map(|(manager, account_stream)| {
+                let manager = match dead_mans_switch {
+                    Some(config) => manager.with_dead_mans_switch(config),
+                    None => manager,
+                };
                 let manager_future: RunFuture = Box::pin(manager.run());
-                let stream_future: RunFuture = Box::pin(account_stream.forward_to(merged_tx));
+                let stream_future: RunFuture = match balance_coalescing {
+                    Some(window) => Box::pin(
+                        coalesce_balance_snapshots(account_stream, window).forward_to(merged_tx),
+                    ),
+                    None => Box::pin(account_stream.forward_to(merged_tx)),
+                };
 
                 (manager_future, stream_future)
             })
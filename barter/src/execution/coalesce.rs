@@ -0,0 +1,290 @@
+//! 余额快照合并模块
+//!
+//! 本模块定义了 [`BalanceCoalescer`]，用于在短时间窗口内按资产合并
+//! `AccountEventKind::BalanceSnapshot` 事件，仅将每个资产的最新余额传递给下游。
+//!
+//! # 使用场景
+//!
+//! 繁忙账户的高频余额更新会导致大量的 `AssetState` 更新和统计计算。合并后，窗口内
+//! 同一资产的多个余额快照只会应用最新的一个（按 `time_exchange`），与
+//! `AssetState::update_from_balance` 的时间戳单调规则保持一致。
+//!
+//! 完整账户快照、订单和成交等其他事件不会被合并，而是立即传递。
+
+use crate::execution::AccountStreamEvent;
+use barter_data::streams::reconnect;
+use barter_execution::{AccountEvent, AccountEventKind};
+use barter_instrument::{asset::AssetIndex, exchange::ExchangeIndex};
+use fnv::FnvHashMap;
+use futures::{Stream, StreamExt, future::Either};
+use std::{collections::VecDeque, time::Duration};
+use tokio::time::Instant;
+
+/// 在时间窗口内按资产合并 `AccountEventKind::BalanceSnapshot` 事件。
+///
+/// 每个 `(ExchangeIndex, AssetIndex)` 的第一个余额快照到达后开始计时，`window` 到期时
+/// 输出窗口内 `time_exchange` 最新的余额快照。
+///
+/// 其他事件的处理方式：
+/// - 完整账户快照和重连事件：先输出所有待处理的余额快照，以保持顺序
+/// - 订单和成交事件：绕过合并，立即输出
+///
+/// 零 `window` 禁用合并。
+#[derive(Debug, Clone)]
+pub struct BalanceCoalescer {
+    window: Duration,
+    pending: FnvHashMap<(ExchangeIndex, AssetIndex), PendingBalance>,
+}
+
+#[derive(Debug, Clone)]
+struct PendingBalance {
+    deadline: Instant,
+    event: AccountEvent,
+}
+
+impl BalanceCoalescer {
+    /// 使用提供的合并时间窗口构造新的 [`BalanceCoalescer`]。
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            pending: FnvHashMap::default(),
+        }
+    }
+
+    /// 返回最早的待处理余额快照的到期时间。
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending.values().map(|pending| pending.deadline).min()
+    }
+
+    /// 处理下一个 [`AccountStreamEvent`]，返回可立即输出的事件。
+    ///
+    /// # 参数
+    ///
+    /// - `event`: 账户流事件
+    /// - `now`: 当前时间
+    pub fn process(&mut self, event: AccountStreamEvent, now: Instant) -> Vec<AccountStreamEvent> {
+        let event = match event {
+            reconnect::Event::Item(event) => event,
+            reconnecting @ reconnect::Event::Reconnecting(_) => {
+                return self.flush_then(reconnecting);
+            }
+        };
+
+        match &event.kind {
+            AccountEventKind::BalanceSnapshot(balance) if !self.window.is_zero() => {
+                let key = (event.exchange, balance.0.asset);
+                let time_exchange = balance.0.time_exchange;
+
+                match self.pending.get_mut(&key) {
+                    Some(pending) => {
+                        if pending_time_exchange(&pending.event) <= Some(time_exchange) {
+                            pending.event = event;
+                        }
+                    }
+                    None => {
+                        self.pending.insert(
+                            key,
+                            PendingBalance {
+                                deadline: now + self.window,
+                                event,
+                            },
+                        );
+                    }
+                }
+                vec![]
+            }
+            AccountEventKind::Snapshot(_) => self.flush_then(reconnect::Event::Item(event)),
+            _ => vec![reconnect::Event::Item(event)],
+        }
+    }
+
+    /// 输出所有在 `now` 之前到期的待处理余额快照。
+    pub fn flush_expired(&mut self, now: Instant) -> Vec<AccountStreamEvent> {
+        self.drain(|pending| pending.deadline <= now)
+    }
+
+    /// 输出所有待处理的余额快照。
+    pub fn flush(&mut self) -> Vec<AccountStreamEvent> {
+        self.drain(|_| true)
+    }
+
+    fn flush_then(&mut self, event: AccountStreamEvent) -> Vec<AccountStreamEvent> {
+        let mut events = self.flush();
+        events.push(event);
+        events
+    }
+
+    fn drain<F>(&mut self, predicate: F) -> Vec<AccountStreamEvent>
+    where
+        F: Fn(&PendingBalance) -> bool,
+    {
+        let mut expired = self
+            .pending
+            .extract_if(|_, pending| predicate(pending))
+            .map(|(_, pending)| pending)
+            .collect::<Vec<_>>();
+
+        expired.sort_by_key(|pending| (pending.deadline, pending_time_exchange(&pending.event)));
+
+        expired
+            .into_iter()
+            .map(|pending| reconnect::Event::Item(pending.event))
+            .collect()
+    }
+}
+
+fn pending_time_exchange(event: &AccountEvent) -> Option<chrono::DateTime<chrono::Utc>> {
+    match &event.kind {
+        AccountEventKind::BalanceSnapshot(balance) => Some(balance.0.time_exchange),
+        _ => None,
+    }
+}
+
+/// 使用 [`BalanceCoalescer`] 合并 [`AccountStreamEvent`] `Stream` 中的余额快照。
+///
+/// 输入 `Stream` 结束时，所有待处理的余额快照会被输出。
+///
+/// 更多信息请参阅 [`BalanceCoalescer`]。
+pub fn coalesce_balance_snapshots<St>(
+    stream: St,
+    window: Duration,
+) -> impl Stream<Item = AccountStreamEvent>
+where
+    St: Stream<Item = AccountStreamEvent>,
+{
+    let state = (
+        Box::pin(stream),
+        BalanceCoalescer::new(window),
+        VecDeque::new(),
+        false,
+    );
+
+    futures::stream::unfold(
+        state,
+        |(mut stream, mut coalescer, mut ready, mut ended)| async move {
+            loop {
+                if let Some(event) = ready.pop_front() {
+                    return Some((event, (stream, coalescer, ready, ended)));
+                }
+
+                if ended {
+                    return None;
+                }
+
+                let next_deadline = match coalescer.next_deadline() {
+                    Some(deadline) => Either::Left(tokio::time::sleep_until(deadline)),
+                    None => Either::Right(std::future::pending()),
+                };
+
+                tokio::select! {
+                    event = stream.next() => match event {
+                        Some(event) => ready.extend(coalescer.process(event, Instant::now())),
+                        None => {
+                            ready.extend(coalescer.flush());
+                            ended = true;
+                        }
+                    },
+                    _ = next_deadline => {
+                        ready.extend(coalescer.flush_expired(Instant::now()));
+                    }
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::time_plus_secs;
+    use barter_execution::{
+        balance::{AssetBalance, Balance},
+        order::id::{OrderId, StrategyId},
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use barter_instrument::{Side, exchange::ExchangeId, instrument::InstrumentIndex};
+    use barter_integration::{
+        channel::{Tx, mpsc_unbounded},
+        snapshot::Snapshot,
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn balance(asset: usize, total: Decimal, secs: i64) -> AccountStreamEvent {
+        reconnect::Event::Item(AccountEvent {
+            exchange: ExchangeIndex(0),
+            kind: AccountEventKind::BalanceSnapshot(Snapshot(AssetBalance {
+                asset: AssetIndex(asset),
+                balance: Balance::new(total, total),
+                time_exchange: time_plus_secs(DateTime::<Utc>::MIN_UTC, secs),
+            })),
+        })
+    }
+
+    fn trade() -> AccountStreamEvent {
+        reconnect::Event::Item(AccountEvent {
+            exchange: ExchangeIndex(0),
+            kind: AccountEventKind::Trade(Trade {
+                id: TradeId::new("trade"),
+                order_id: OrderId::new("order"),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                side: Side::Buy,
+                price: dec!(100),
+                quantity: dec!(1),
+                fees: AssetFees::quote_fees(Decimal::ZERO),
+                reduce_only: false,
+            }),
+        })
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_coalesce_balance_snapshots_applies_latest_per_asset() {
+        let (tx, rx) = mpsc_unbounded();
+        let mut stream = Box::pin(coalesce_balance_snapshots(
+            rx.into_stream(),
+            Duration::from_millis(100),
+        ));
+
+        // Burst of balance updates for two assets, with an out of order stale update
+        tx.send(balance(0, dec!(1), 1)).unwrap();
+        tx.send(balance(1, dec!(10), 1)).unwrap();
+        tx.send(balance(0, dec!(2), 2)).unwrap();
+        tx.send(balance(0, dec!(4), 4)).unwrap();
+        tx.send(balance(0, dec!(3), 3)).unwrap();
+        tx.send(trade()).unwrap();
+        tx.send(balance(1, dec!(20), 2)).unwrap();
+
+        // Trades bypass coalescing
+        assert_eq!(stream.next().await, Some(trade()));
+
+        // Only the latest balance per asset is output once the window elapses
+        let start = Instant::now();
+        let mut coalesced = vec![stream.next().await.unwrap(), stream.next().await.unwrap()];
+        assert_eq!(start.elapsed(), Duration::from_millis(100));
+
+        coalesced.sort_by_key(|event| match event {
+            reconnect::Event::Item(AccountEvent {
+                kind: AccountEventKind::BalanceSnapshot(balance),
+                ..
+            }) => balance.0.asset,
+            _ => panic!("expected BalanceSnapshot, got: {event:?}"),
+        });
+        assert_eq!(
+            coalesced,
+            vec![balance(0, dec!(4), 4), balance(1, dec!(20), 2)]
+        );
+
+        // Reconnections flush pending balances first to preserve ordering
+        tx.send(balance(0, dec!(5), 5)).unwrap();
+        tx.send(reconnect::Event::Reconnecting(ExchangeId::Mock))
+            .unwrap();
+        assert_eq!(stream.next().await, Some(balance(0, dec!(5), 5)));
+        assert_eq!(
+            stream.next().await,
+            Some(reconnect::Event::Reconnecting(ExchangeId::Mock))
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! 开仓请求幂等性保护模块
+//!
+//! 本模块定义了 [`OpenIdempotencyGuard`]，[`ExecutionManager`](super::manager::ExecutionManager)
+//! 使用它跟踪最近发送的开仓请求的 [`ClientOrderId`]，并在时间窗口内拒绝重复的
+//! `ExecutionRequest::Open`。
+//!
+//! # 使用场景
+//!
+//! 重连或重试时可能会重新发送已到达交易所的开仓请求。由于 [`ClientOrderId`] 唯一标识
+//! 一个订单，在窗口内使用相同 [`ClientOrderId`] 的开仓请求被视为重复请求，并以
+//! `ApiError::DuplicateClientOrderId` 拒绝，从而防止重复下单。使用不同 [`ClientOrderId`]
+//! 的订单不受影响。
+//!
+//! 只有实际发送到交易所的开仓请求才会被记录，因此发送前即被拒绝的请求（例如不支持的
+//! `TimeInForce`）可以立即使用相同的 [`ClientOrderId`] 重试。
+
+use barter_execution::order::id::ClientOrderId;
+use fnv::FnvHashMap;
+use std::{collections::VecDeque, time::Duration};
+use tokio::time::Instant;
+
+/// [`OpenIdempotencyGuard`] 的默认去重时间窗口。
+pub const DEFAULT_OPEN_IDEMPOTENCY_WINDOW: Duration = Duration::from_secs(30);
+
+/// 基于 [`ClientOrderId`] 的开仓请求幂等性保护。
+///
+/// 通过 [`Self::record_sent`] 记录每个已发送开仓请求的 [`ClientOrderId`] 和发送时间，`window`
+/// 内再次出现的相同 [`ClientOrderId`] 被 [`Self::is_duplicate`] 视为重复请求。超过 `window` 的
+/// 记录会被淘汰，零 `window` 禁用去重。
+#[derive(Debug, Clone)]
+pub struct OpenIdempotencyGuard {
+    window: Duration,
+    sent: FnvHashMap<ClientOrderId, Instant>,
+    order: VecDeque<(Instant, ClientOrderId)>,
+}
+
+impl Default for OpenIdempotencyGuard {
+    fn default() -> Self {
+        Self::new(DEFAULT_OPEN_IDEMPOTENCY_WINDOW)
+    }
+}
+
+impl OpenIdempotencyGuard {
+    /// 使用提供的去重时间窗口构造新的 [`OpenIdempotencyGuard`]。
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            sent: FnvHashMap::default(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// 返回去重时间窗口。
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// 如果 [`ClientOrderId`] 在 `now` 之前的窗口内已发送过则返回 `true`。
+    ///
+    /// # 参数
+    ///
+    /// - `cid`: 开仓请求的 [`ClientOrderId`]
+    /// - `now`: 当前时间
+    pub fn is_duplicate(&mut self, cid: &ClientOrderId, now: Instant) -> bool {
+        if self.window.is_zero() {
+            return false;
+        }
+
+        self.evict_expired(now);
+        self.sent.contains_key(cid)
+    }
+
+    /// 将 [`ClientOrderId`] 记录为在 `now` 发送到交易所。
+    ///
+    /// # 参数
+    ///
+    /// - `cid`: 已发送开仓请求的 [`ClientOrderId`]
+    /// - `now`: 当前时间
+    pub fn record_sent(&mut self, cid: &ClientOrderId, now: Instant) {
+        if self.window.is_zero() {
+            return;
+        }
+
+        self.evict_expired(now);
+        self.sent.insert(cid.clone(), now);
+        self.order.push_back((now, cid.clone()));
+    }
+
+    fn evict_expired(&mut self, now: Instant) {
+        while let Some((time_sent, _)) = self.order.front()
+            && now.duration_since(*time_sent) >= self.window
+        {
+            if let Some((time_sent, cid)) = self.order.pop_front()
+                && self.sent.get(&cid) == Some(&time_sent)
+            {
+                self.sent.remove(&cid);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_idempotency_guard() {
+        let mut guard = OpenIdempotencyGuard::new(Duration::from_secs(10));
+        let start = Instant::now();
+        let (a, b) = (ClientOrderId::new("a"), ClientOrderId::new("b"));
+
+        // ClientOrderIds are only duplicates once recorded as sent
+        assert!(!guard.is_duplicate(&a, start));
+        guard.record_sent(&a, start);
+        assert!(guard.is_duplicate(&a, start + Duration::from_secs(1)));
+
+        // Distinct ClientOrderIds are allowed
+        assert!(!guard.is_duplicate(&b, start + Duration::from_secs(2)));
+        guard.record_sent(&b, start + Duration::from_secs(2));
+
+        // After the window has elapsed the ClientOrderId is forgotten
+        assert!(!guard.is_duplicate(&a, start + Duration::from_secs(10)));
+        assert!(guard.is_duplicate(&b, start + Duration::from_secs(11)));
+
+        // Zero window disables deduplication
+        let mut disabled = OpenIdempotencyGuard::new(Duration::ZERO);
+        disabled.record_sent(&a, start);
+        assert!(!disabled.is_duplicate(&a, start));
+    }
+}
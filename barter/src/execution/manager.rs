@@ -8,10 +8,14 @@
 //! - **ExecutionManager**: 每个交易所的执行管理器
 //! - **工作流程**: 接收请求 → 转换标识符 → 发送到交易所 → 处理响应 → 转发回 Engine
 //! - **超时处理**: 跟踪请求并在超时时返回错误
+//! - **死人开关**: 在 Engine 仍在发送请求或心跳时定期向交易所发送 cancel-all-after 心跳，Engine
+//!   卡住或 ExecutionManager 停止时订单自动取消
+//! - **幂等性保护**: 在时间窗口内拒绝使用相同 `ClientOrderId` 的重复开仓请求
 
 use crate::execution::{
     AccountStreamEvent,
     error::ExecutionError,
+    idempotency::OpenIdempotencyGuard,
     request::{ExecutionRequest, RequestFuture},
 };
 use barter_data::streams::{
@@ -21,7 +25,7 @@ use barter_data::streams::{
 use barter_execution::{
     AccountEvent, AccountEventKind,
     client::ExecutionClient,
-    error::{ConnectivityError, OrderError, UnindexedOrderError},
+    error::{ApiError, ConnectivityError, OrderError, UnindexedOrderError},
     indexer::{AccountEventIndexer, IndexedAccountStream},
     map::ExecutionInstrumentMap,
     order::{
@@ -30,6 +34,7 @@ use barter_execution::{
             OrderRequestCancel, OrderRequestOpen, OrderResponseCancel, UnindexedOrderResponseCancel,
         },
         state::{Open, OrderState},
+        time_in_force::validate_time_in_force,
     },
 };
 use barter_instrument::{
@@ -45,8 +50,36 @@ use barter_integration::{
 };
 use derive_more::Constructor;
 use futures::{Stream, StreamExt, future::Either, stream::FuturesUnordered};
-use std::sync::Arc;
-use tracing::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::watch;
+use tracing::{debug, error, info, warn};
+
+/// 死人开关（dead-man's switch）配置。
+///
+/// 许多交易所支持 "在 X 秒后取消所有订单" 的 API。启用后，[`ExecutionManager`] 每隔
+/// `interval` 通过 [`ExecutionClient::cancel_all_after`] 发送心跳，请求交易所在 `timeout`
+/// 内未收到下一次心跳时取消所有挂单。
+///
+/// 心跳只在 `timeout` 内收到过 Engine 请求（包括 [`ExecutionRequest::Heartbeat`]）时发送，因此
+/// 如果 Engine 停止运行、Engine 进程崩溃或 ExecutionManager 停止运行，心跳随之停止，挂单会被交易所
+/// 自动取消。`timeout` 应大于 `interval`，以容忍单次心跳的延迟，并应通过
+/// `SystemBuilder::execution_heartbeat` 以小于 `timeout` 的间隔发送 Engine 存活心跳。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let config = DeadMansSwitchConfig::new(Duration::from_secs(15), Duration::from_secs(60));
+/// ```
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct DeadMansSwitchConfig {
+    /// 发送心跳的间隔
+    pub interval: Duration,
+    /// 每次心跳请求交易所在多长时间未收到下一次心跳后取消所有订单
+    pub timeout: Duration,
+}
 
 /// 每个交易所的执行管理器，处理来自 Engine 的订单请求并转发响应。
 ///
@@ -86,6 +119,12 @@ pub struct ExecutionManager<RequestStream, Client> {
     ///
     /// 例如，`InstrumentNameExchange` -> `InstrumentIndex`。
     pub indexer: AccountEventIndexer,
+
+    /// 可选的死人开关配置，启用后在 Engine 仍在发送请求或心跳时定期向交易所发送 cancel-all-after 心跳。
+    pub dead_mans_switch: Option<DeadMansSwitchConfig>,
+
+    /// 开仓请求幂等性保护，在时间窗口内拒绝使用相同 `ClientOrderId` 的重复开仓请求。
+    pub idempotency: OpenIdempotencyGuard,
 }
 
 impl<RequestStream, Client> ExecutionManager<RequestStream, Client>
@@ -187,11 +226,72 @@ where
                 response_tx,
                 client,
                 indexer,
+                None,
+                OpenIdempotencyGuard::default(),
             ),
             merged_account_stream,
         ))
     }
 
+    /// 使用提供的 [`DeadMansSwitchConfig`] 启用死人开关心跳。
+    pub fn with_dead_mans_switch(self, config: DeadMansSwitchConfig) -> Self {
+        Self {
+            dead_mans_switch: Some(config),
+            ..self
+        }
+    }
+
+    /// 使用提供的去重时间窗口替换默认的开仓请求幂等性保护。
+    ///
+    /// 零 `window` 禁用去重。
+    pub fn with_open_idempotency_window(self, window: Duration) -> Self {
+        Self {
+            idempotency: OpenIdempotencyGuard::new(window),
+            ..self
+        }
+    }
+
+    fn reject_unsupported_time_in_force(
+        exchange: ExchangeId,
+        request: &OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) -> Option<AccountStreamEvent> {
+        let error = validate_time_in_force(exchange, request.state.time_in_force).err()?;
+
+        warn!(
+            %exchange,
+            ?request,
+            %error,
+            "ExecutionManager rejecting open request with unsupported TimeInForce"
+        );
+
+        Some(Self::process_open_failed(
+            request.clone(),
+            OrderError::Rejected(error.into()),
+        ))
+    }
+
+    fn reject_duplicate_open(
+        idempotency: &mut OpenIdempotencyGuard,
+        exchange: ExchangeId,
+        request: &OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) -> Option<AccountStreamEvent> {
+        if !idempotency.is_duplicate(&request.key.cid, tokio::time::Instant::now()) {
+            return None;
+        }
+
+        warn!(
+            %exchange,
+            ?request,
+            window = ?idempotency.window(),
+            "ExecutionManager rejecting duplicate open request with recently sent ClientOrderId"
+        );
+
+        Some(Self::process_open_failed(
+            request.clone(),
+            OrderError::Rejected(ApiError::DuplicateClientOrderId(request.key.cid.clone())),
+        ))
+    }
+
     fn determine_account_stream_key(
         instrument_map: &Arc<ExecutionInstrumentMap>,
     ) -> Result<StreamKey, ExecutionError> {
@@ -279,6 +379,19 @@ where
         let mut in_flight_cancels = FuturesUnordered::new();
         let mut in_flight_opens = FuturesUnordered::new();
 
+        // Send dead-man's switch heartbeats for as long as the Engine keeps sending requests
+        let (engine_alive_tx, engine_alive_rx) = watch::channel(tokio::time::Instant::now());
+        let heartbeats = match self.dead_mans_switch {
+            Some(config) => Either::Left(schedule_cancel_all_after_heartbeats(
+                self.indexer.map.exchange.value,
+                Arc::clone(&self.client),
+                config,
+                engine_alive_rx,
+            )),
+            None => Either::Right(std::future::pending()),
+        };
+        tokio::pin!(heartbeats);
+
         loop {
             let next_cancel_response = if in_flight_cancels.is_empty() {
                 Either::Left(std::future::pending())
@@ -293,40 +406,71 @@ where
             };
 
             tokio::select! {
+                // Drive dead-man's switch heartbeats (never completes)
+                _ = &mut heartbeats => {},
+
                 // Process Engine ExecutionRequests
-                request = self.request_stream.next() => match request {
-                    Some(ExecutionRequest::Shutdown) | None => {
-                        break;
-                    }
-                    Some(ExecutionRequest::Cancel(request)) => {
-                        // Panic since the system is set up incorrectly, so it's foolish to continue
-                        let client_request = self
-                            .indexer
-                            .order_request(&request)
-                            .unwrap_or_else(|error| panic!(
-                                "ExecutionManager received cancel request for non-configured key: {error}"
-                            ));
-
-                        in_flight_cancels.push(RequestFuture::new(
-                            self.client.cancel_order(client_request),
-                            self.request_timeout,
-                            request,
-                        ))
-                    },
-                    Some(ExecutionRequest::Open(request)) => {
-                        // Panic since the system is set up incorrectly, so it's foolish to continue
-                        let client_request = self
-                            .indexer
-                            .order_request(&request)
-                            .unwrap_or_else(|error| panic!(
-                                "ExecutionManager received open request for non-configured key: {error}"
-                            ));
-
-                        in_flight_opens.push(RequestFuture::new(
-                            self.client.open_order(client_request),
-                            self.request_timeout,
-                            request,
-                        ))
+                request = self.request_stream.next() => {
+                    // Any Engine request proves the Engine is alive for the dead-man's switch
+                    engine_alive_tx.send_replace(tokio::time::Instant::now());
+
+                    match request {
+                        Some(ExecutionRequest::Shutdown) | None => {
+                            break;
+                        }
+                        Some(ExecutionRequest::Heartbeat) => {}
+                        Some(ExecutionRequest::Cancel(request)) => {
+                            // Panic since the system is set up incorrectly, so it's foolish to continue
+                            let client_request = self
+                                .indexer
+                                .order_request(&request)
+                                .unwrap_or_else(|error| panic!(
+                                    "ExecutionManager received cancel request for non-configured key: {error}"
+                                ));
+
+                            in_flight_cancels.push(RequestFuture::new(
+                                self.client.cancel_order(client_request),
+                                self.request_timeout,
+                                request,
+                            ))
+                        },
+                        Some(ExecutionRequest::Open(request)) => {
+                            // Reject duplicate open requests (eg/ resent after a retry) so the
+                            // same order is never placed twice, and unsupported TimeInForce
+                            // without a round-trip to the exchange
+                            let exchange = self.indexer.map.exchange.value;
+                            let rejected = Self::reject_duplicate_open(
+                                &mut self.idempotency,
+                                exchange,
+                                &request,
+                            )
+                            .or_else(|| Self::reject_unsupported_time_in_force(exchange, &request));
+
+                            if let Some(rejected) = rejected {
+                                if self.response_tx.send(rejected).is_err() {
+                                    break;
+                                }
+                                continue;
+                            }
+
+                            // Panic since the system is set up incorrectly, so it's foolish to continue
+                            let client_request = self
+                                .indexer
+                                .order_request(&request)
+                                .unwrap_or_else(|error| panic!(
+                                    "ExecutionManager received open request for non-configured key: {error}"
+                                ));
+
+                            // Only remember the ClientOrderId once the open request is sent
+                            self.idempotency
+                                .record_sent(&request.key.cid, tokio::time::Instant::now());
+
+                            in_flight_opens.push(RequestFuture::new(
+                                self.client.open_order(client_request),
+                                self.request_timeout,
+                                request,
+                            ))
+                        }
                     }
                 },
 
@@ -441,6 +585,7 @@ where
             quantity,
             kind,
             time_in_force,
+            reduce_only,
             state,
         } = order;
 
@@ -461,6 +606,7 @@ where
                 quantity,
                 kind,
                 time_in_force,
+                reduce_only,
                 state,
             })),
         }))
@@ -468,6 +614,14 @@ where
 
     fn process_open_timeout(
         order: OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+    ) -> AccountStreamEvent {
+        Self::process_open_failed(order, OrderError::Connectivity(ConnectivityError::Timeout))
+    }
+
+    /// 为发送前即失败的开仓请求生成 `OpenFailed` 订单快照事件。
+    fn process_open_failed(
+        order: OrderRequestOpen<ExchangeIndex, InstrumentIndex>,
+        error: OrderError,
     ) -> AccountStreamEvent {
         let OrderRequestOpen { key, state } = order;
 
@@ -480,8 +634,371 @@ where
                 quantity: state.quantity,
                 kind: state.kind,
                 time_in_force: state.time_in_force,
-                state: OrderState::inactive(OrderError::Connectivity(ConnectivityError::Timeout)),
+                reduce_only: state.reduce_only,
+                state: OrderState::inactive(error),
             })),
         })
     }
 }
+
+/// 使用提供的 [`DeadMansSwitchConfig`] 安排向交易所发送死人开关心跳。
+///
+/// 每隔 `config.interval` 调用一次 [`ExecutionClient::cancel_all_after`]（第一次心跳立即发送），
+/// 请求交易所在 `config.timeout` 内未收到下一次心跳时取消所有订单。发送失败的心跳会记录警告，
+/// 但不会停止调度。
+///
+/// 如果 `engine_alive` 记录的最近一次 Engine 请求或心跳早于 `config.timeout`，说明 Engine 已经
+/// 卡住，此时不发送心跳（记录警告），交易所会在 `config.timeout` 后取消所有订单。Engine 恢复
+/// 发送请求后心跳随之恢复。
+///
+/// 此 Future 永不完成，应与 ExecutionManager 的生命周期绑定，以便 ExecutionManager 停止时
+/// 心跳随之停止。
+///
+/// # 参数
+///
+/// - `exchange`: 交易所标识（用于日志记录）
+/// - `client`: 执行客户端
+/// - `config`: 死人开关配置
+/// - `engine_alive`: 最近一次收到 Engine 请求或心跳的时间
+pub async fn schedule_cancel_all_after_heartbeats<Client>(
+    exchange: ExchangeId,
+    client: Arc<Client>,
+    config: DeadMansSwitchConfig,
+    engine_alive: watch::Receiver<tokio::time::Instant>,
+) where
+    Client: ExecutionClient,
+{
+    let mut interval = tokio::time::interval(config.interval);
+
+    loop {
+        // 等待下一次计划的心跳
+        interval.tick().await;
+
+        // Engine 卡住时不发送心跳，以便交易所取消所有订单
+        let engine_silent = engine_alive.borrow().elapsed();
+        if engine_silent > config.timeout {
+            warn!(
+                %exchange,
+                ?engine_silent,
+                "Engine unresponsive - withholding dead-man's switch heartbeat so the exchange cancels all orders"
+            );
+            continue;
+        }
+
+        debug!(%exchange, timeout = ?config.timeout, "sending dead-man's switch heartbeat to exchange");
+
+        if let Err(error) = client.cancel_all_after(config.timeout).await {
+            warn!(%exchange, %error, "failed to send dead-man's switch heartbeat to exchange");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::{
+        client::mock::MockExecution,
+        exchange::mock::request::{MockExchangeRequest, MockExchangeRequestKind},
+        map::generate_execution_instrument_map,
+        order::{
+            OrderKey, OrderKind, TimeInForce,
+            id::{ClientOrderId, StrategyId},
+            request::RequestOpen,
+            state::InactiveOrderState,
+        },
+    };
+    use barter_instrument::{Side, index::IndexedInstruments, test_utils::instrument};
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+    use tokio::sync::{broadcast, mpsc};
+
+    #[tokio::test(start_paused = true)]
+    async fn test_schedule_cancel_all_after_heartbeats_cadence() {
+        let (request_tx, mut request_rx) = mpsc::unbounded_channel::<MockExchangeRequest>();
+        let client = Arc::new(MockExecution::new(
+            ExchangeId::Mock,
+            || DateTime::<Utc>::MIN_UTC,
+            request_tx,
+            broadcast::channel(1).1,
+        ));
+
+        let config = DeadMansSwitchConfig::new(Duration::from_secs(10), Duration::from_secs(30));
+        let start = tokio::time::Instant::now();
+        let (engine_alive_tx, engine_alive_rx) = watch::channel(start);
+
+        let heartbeats = tokio::spawn(schedule_cancel_all_after_heartbeats(
+            ExchangeId::Mock,
+            client,
+            config,
+            engine_alive_rx,
+        ));
+
+        let mut heartbeat_offsets = Vec::new();
+        while heartbeat_offsets.len() < 4 {
+            let request = request_rx.recv().await.unwrap();
+            let MockExchangeRequestKind::CancelAllAfter { timeout } = request.kind else {
+                panic!("expected CancelAllAfter heartbeat, got: {request:?}");
+            };
+            assert_eq!(timeout, config.timeout);
+            heartbeat_offsets.push(start.elapsed());
+
+            // Engine keeps sending requests
+            engine_alive_tx.send_replace(tokio::time::Instant::now());
+        }
+
+        // First heartbeat is sent immediately, then at the configured interval
+        assert_eq!(
+            heartbeat_offsets,
+            vec![
+                Duration::ZERO,
+                Duration::from_secs(10),
+                Duration::from_secs(20),
+                Duration::from_secs(30),
+            ]
+        );
+
+        // No heartbeat is sent before the next interval elapses
+        tokio::time::sleep(Duration::from_secs(9)).await;
+        assert!(request_rx.try_recv().is_err());
+
+        heartbeats.abort();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_schedule_cancel_all_after_heartbeats_withheld_while_engine_unresponsive() {
+        let (request_tx, mut request_rx) = mpsc::unbounded_channel::<MockExchangeRequest>();
+        let client = Arc::new(MockExecution::new(
+            ExchangeId::Mock,
+            || DateTime::<Utc>::MIN_UTC,
+            request_tx,
+            broadcast::channel(1).1,
+        ));
+
+        let config = DeadMansSwitchConfig::new(Duration::from_secs(10), Duration::from_secs(30));
+        let start = tokio::time::Instant::now();
+        let (engine_alive_tx, engine_alive_rx) = watch::channel(start);
+
+        let heartbeats = tokio::spawn(schedule_cancel_all_after_heartbeats(
+            ExchangeId::Mock,
+            client,
+            config,
+            engine_alive_rx,
+        ));
+
+        // Engine goes silent after start, so heartbeats stop once the timeout elapses
+        tokio::time::sleep(Duration::from_secs(75)).await;
+        let mut heartbeats_sent = 0;
+        while request_rx.try_recv().is_ok() {
+            heartbeats_sent += 1;
+        }
+        // Heartbeats at 0s, 10s, 20s & 30s, then withheld at 40s, 50s, 60s & 70s
+        assert_eq!(heartbeats_sent, 4);
+
+        // Engine recovers, so heartbeats resume at the next interval (80s)
+        engine_alive_tx.send_replace(tokio::time::Instant::now());
+        let request = request_rx.recv().await.unwrap();
+        assert!(matches!(
+            request.kind,
+            MockExchangeRequestKind::CancelAllAfter { .. }
+        ));
+        assert_eq!(start.elapsed(), Duration::from_secs(80));
+
+        heartbeats.abort();
+    }
+
+    type TestExecutionManager = ExecutionManager<
+        futures::stream::BoxStream<'static, ExecutionRequest>,
+        MockExecution<fn() -> DateTime<Utc>>,
+    >;
+
+    fn execution_manager(
+        window: Duration,
+    ) -> (
+        TestExecutionManager,
+        UnboundedTx<ExecutionRequest>,
+        mpsc::UnboundedReceiver<MockExchangeRequest>,
+        barter_integration::channel::UnboundedRx<AccountStreamEvent>,
+    ) {
+        let instruments =
+            IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]);
+        let map = generate_execution_instrument_map(&instruments, ExchangeId::BinanceSpot).unwrap();
+
+        let (exchange_tx, exchange_rx) = mpsc::unbounded_channel::<MockExchangeRequest>();
+        let client = Arc::new(MockExecution::new(
+            ExchangeId::Mock,
+            (|| DateTime::<Utc>::MIN_UTC) as fn() -> DateTime<Utc>,
+            exchange_tx,
+            broadcast::channel(1).1,
+        ));
+
+        let (request_tx, request_rx) = mpsc_unbounded();
+        let (response_tx, response_rx) = mpsc_unbounded();
+        let manager = ExecutionManager::new(
+            request_rx.into_stream().boxed(),
+            Duration::from_secs(5),
+            response_tx,
+            client,
+            AccountEventIndexer::new(Arc::new(map)),
+            None,
+            OpenIdempotencyGuard::new(window),
+        );
+
+        (manager, request_tx, exchange_rx, response_rx)
+    }
+
+    fn open(cid: &str, time_in_force: TimeInForce) -> ExecutionRequest {
+        ExecutionRequest::Open(OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                price: dec!(100),
+                quantity: dec!(1),
+                kind: OrderKind::Limit,
+                time_in_force,
+                reduce_only: false,
+            },
+        })
+    }
+
+    fn gtc(cid: &str) -> ExecutionRequest {
+        open(cid, TimeInForce::GoodUntilCancelled { post_only: false })
+    }
+
+    /// Returns the ClientOrderIds of the open requests received by the exchange.
+    fn opened(
+        exchange_rx: &mut mpsc::UnboundedReceiver<MockExchangeRequest>,
+    ) -> Vec<ClientOrderId> {
+        std::iter::from_fn(|| exchange_rx.try_recv().ok())
+            .map(|request| {
+                let MockExchangeRequestKind::OpenOrder { request, .. } = request.kind else {
+                    panic!("expected OpenOrder, got: {request:?}");
+                };
+                request.key.cid
+            })
+            .collect()
+    }
+
+    /// Returns the ClientOrderId & error of the failed open responses sent to the Engine.
+    fn open_failed(
+        response_rx: &mut barter_integration::channel::UnboundedRx<AccountStreamEvent>,
+    ) -> Vec<(ClientOrderId, OrderError)> {
+        std::iter::from_fn(|| response_rx.rx.try_recv().ok())
+            .map(|event| {
+                let AccountStreamEvent::Item(AccountEvent {
+                    kind: AccountEventKind::OrderSnapshot(Snapshot(order)),
+                    ..
+                }) = event
+                else {
+                    panic!("expected OrderSnapshot, got: {event:?}");
+                };
+                let OrderState::Inactive(InactiveOrderState::OpenFailed(error)) = order.state
+                else {
+                    panic!("expected OpenFailed, got: {:?}", order.state);
+                };
+                (order.key.cid, error)
+            })
+            .collect()
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_execution_manager_rejects_duplicate_open_requests() {
+        let (manager, request_tx, mut exchange_rx, mut response_rx) =
+            execution_manager(Duration::from_secs(30));
+        let manager = tokio::spawn(manager.run());
+
+        // Same open request resent quickly, followed by a distinct order
+        request_tx.send(gtc("cid")).unwrap();
+        request_tx.send(gtc("cid")).unwrap();
+        request_tx.send(gtc("other")).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        // Only one of the duplicate open requests reaches the exchange...
+        assert_eq!(
+            opened(&mut exchange_rx),
+            vec![ClientOrderId::new("cid"), ClientOrderId::new("other")]
+        );
+
+        // ...and the duplicate is explicitly rejected
+        assert_eq!(
+            open_failed(&mut response_rx),
+            vec![(
+                ClientOrderId::new("cid"),
+                OrderError::Rejected(ApiError::DuplicateClientOrderId(ClientOrderId::new("cid")))
+            )]
+        );
+
+        manager.abort();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_execution_manager_open_rejected_before_sending_can_be_retried() {
+        let (manager, request_tx, mut exchange_rx, mut response_rx) =
+            execution_manager(Duration::from_secs(30));
+        let manager = tokio::spawn(manager.run());
+
+        // BinanceSpot does not support GoodUntilEndOfDay, so the open is never sent
+        request_tx
+            .send(open("cid", TimeInForce::GoodUntilEndOfDay))
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(opened(&mut exchange_rx).is_empty());
+        assert!(matches!(
+            open_failed(&mut response_rx).as_slice(),
+            [(_, OrderError::Rejected(ApiError::TimeInForceUnsupported(_)))]
+        ));
+
+        // Retry with the same ClientOrderId is not a duplicate
+        request_tx.send(gtc("cid")).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(opened(&mut exchange_rx), vec![ClientOrderId::new("cid")]);
+        assert!(open_failed(&mut response_rx).is_empty());
+
+        manager.abort();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_execution_manager_open_retry_after_timeout() {
+        let (manager, request_tx, mut exchange_rx, mut response_rx) =
+            execution_manager(Duration::from_secs(30));
+        let manager = tokio::spawn(manager.run());
+
+        // Exchange never responds (but holds the request), so the open times out
+        request_tx.send(gtc("cid")).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let unanswered = exchange_rx.try_recv().unwrap();
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        assert_eq!(
+            open_failed(&mut response_rx),
+            vec![(
+                ClientOrderId::new("cid"),
+                OrderError::Connectivity(ConnectivityError::Timeout)
+            )]
+        );
+
+        // Retry within the window may duplicate the timed out order, so it is rejected
+        request_tx.send(gtc("cid")).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(opened(&mut exchange_rx).is_empty());
+        assert_eq!(
+            open_failed(&mut response_rx),
+            vec![(
+                ClientOrderId::new("cid"),
+                OrderError::Rejected(ApiError::DuplicateClientOrderId(ClientOrderId::new("cid")))
+            )]
+        );
+
+        // Retry after the window has elapsed is sent to the exchange
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        request_tx.send(gtc("cid")).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(opened(&mut exchange_rx), vec![ClientOrderId::new("cid")]);
+
+        drop(unanswered);
+        manager.abort();
+    }
+}
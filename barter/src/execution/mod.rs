@@ -30,12 +30,24 @@ use barter_integration::channel::Channel;
 /// 提供执行管理器构建器，用于方便地初始化到模拟和真实交易所的多个执行链接。
 pub mod builder;
 
+/// 在短时间窗口内按资产合并余额快照，减少高频余额更新造成的 Engine 状态更新。
+pub mod coalesce;
+
 /// 提供表示执行链接生成的所有错误的错误类型。
 pub mod error;
 
+/// 基于 `ClientOrderId` 的开仓请求幂等性保护，防止重复下单。
+pub mod idempotency;
+
 /// 每个交易所的执行管理器，处理来自 Engine 的订单请求并转发响应。
 pub mod manager;
 
+/// 将账户成交桥接为合成的公共成交 `MarketEvent`，使自有成交可以并入公共成交流。
+pub mod own_trade;
+
+/// 实盘纸面交易馈送，将实时市场价格转发到以市场价格成交的模拟交易所。
+pub mod paper;
+
 /// 定义 `Engine` 用于与 `ExecutionManager` 通信的 `ExecutionRequest`。
 pub mod request;
 
@@ -0,0 +1,217 @@
+//! 自有成交桥接模块
+//!
+//! 本模块将账户成交（[`AccountEventKind::Trade`]）转换为合成的公共成交
+//! [`MarketEvent<InstrumentKey, PublicTrade>`]，使自有成交可以与公共成交流一起被
+//! 市场数据风格的消费者（例如逐笔成交分析）统一处理。
+//!
+//! # 自有成交标记
+//!
+//! 合成的 [`PublicTrade::id`] 以 [`OWN_TRADE_ID_PREFIX`] 为前缀，因此消费者可以使用
+//! [`is_own_trade`] 将自有成交与交易所公共成交区分开。
+//!
+//! # 键映射
+//!
+//! [`AccountEvent`] 使用 [`ExchangeIndex`] 标识交易所，而 [`MarketEvent`] 使用 [`ExchangeId`]，
+//! 因此 [`OwnTradeBridge`] 使用 [`IndexedInstruments`] 将 [`ExchangeIndex`] 映射为 [`ExchangeId`]。
+//! 交易对键（[`InstrumentIndex`]）在索引化的市场数据流和账户流之间是共享的，因此保持不变。
+//!
+//! # 使用示例
+//!
+//! ```rust,ignore
+//! let bridge = OwnTradeBridge::new(&instruments);
+//!
+//! // 将自有成交合并到公共成交流中
+//! let tape = bridge.merge(public_trades, account_stream);
+//! ```
+
+use crate::execution::AccountStreamEvent;
+use barter_data::{
+    event::MarketEvent, streams::consumer::MarketStreamEvent, subscription::trade::PublicTrade,
+};
+use barter_execution::{AccountEvent, AccountEventKind, trade::Trade};
+use barter_instrument::{
+    exchange::{ExchangeId, ExchangeIndex},
+    index::IndexedInstruments,
+    instrument::InstrumentIndex,
+};
+use barter_integration::stream::merge::merge;
+use fnv::FnvHashMap;
+use futures::{Stream, StreamExt};
+
+/// 合成的自有成交 [`PublicTrade::id`] 的前缀。
+pub const OWN_TRADE_ID_PREFIX: &str = "own:";
+
+/// 判断 [`PublicTrade`] 是否是由账户成交合成的自有成交。
+pub fn is_own_trade(trade: &PublicTrade) -> bool {
+    trade.id.starts_with(OWN_TRADE_ID_PREFIX)
+}
+
+/// 将账户 [`Trade`] 转换为合成的自有成交 [`MarketEvent<InstrumentKey, PublicTrade>`]。
+///
+/// - `time_exchange` 和 `time_received` 均为成交的 `time_exchange`
+/// - `id` 为带 [`OWN_TRADE_ID_PREFIX`] 前缀的成交 ID
+/// - `amount` 为成交数量的绝对值
+///
+/// # 参数
+///
+/// - `exchange`: 成交所在的交易所
+/// - `trade`: 要转换的账户成交
+pub fn own_trade_market_event<AssetKey, InstrumentKey>(
+    exchange: ExchangeId,
+    trade: &Trade<AssetKey, InstrumentKey>,
+) -> MarketEvent<InstrumentKey, PublicTrade>
+where
+    InstrumentKey: Clone,
+{
+    MarketEvent {
+        time_exchange: trade.time_exchange,
+        time_received: trade.time_exchange,
+        time_processed: None,
+        sequence: None,
+        exchange,
+        instrument: trade.instrument.clone(),
+        kind: PublicTrade {
+            id: format!("{OWN_TRADE_ID_PREFIX}{}", trade.id.0),
+            price: trade.price,
+            amount: trade.quantity.abs(),
+            side: trade.side,
+        },
+    }
+}
+
+/// 将索引化的 [`AccountEvent`] 成交桥接为合成的自有成交 [`MarketEvent`]。
+///
+/// 使用 [`IndexedInstruments`] 将账户事件的 [`ExchangeIndex`] 映射为 [`ExchangeId`]。
+#[derive(Debug, Clone, Default)]
+pub struct OwnTradeBridge {
+    exchanges: FnvHashMap<ExchangeIndex, ExchangeId>,
+}
+
+impl OwnTradeBridge {
+    /// 使用提供的 [`IndexedInstruments`] 中的交易所构造新的 [`OwnTradeBridge`]。
+    pub fn new(instruments: &IndexedInstruments) -> Self {
+        Self {
+            exchanges: instruments
+                .exchanges()
+                .iter()
+                .map(|exchange| (exchange.key, exchange.value))
+                .collect(),
+        }
+    }
+
+    /// 如果 [`AccountEvent`] 是成交，则将其转换为合成的自有成交 [`MarketEvent`]。
+    ///
+    /// 对于非成交事件，或交易所不在 [`IndexedInstruments`] 中的成交，返回 `None`。
+    pub fn market_event(
+        &self,
+        event: &AccountEvent,
+    ) -> Option<MarketEvent<InstrumentIndex, PublicTrade>> {
+        let AccountEventKind::Trade(trade) = &event.kind else {
+            return None;
+        };
+
+        self.exchanges
+            .get(&event.exchange)
+            .map(|exchange| own_trade_market_event(*exchange, trade))
+    }
+
+    /// 将 [`AccountStreamEvent`] 流转换为合成的自有成交 [`MarketEvent`] 流。
+    ///
+    /// 非成交账户事件和重连事件会被丢弃。
+    pub fn own_trades<AccountStream>(
+        self,
+        account_stream: AccountStream,
+    ) -> impl Stream<Item = MarketEvent<InstrumentIndex, PublicTrade>>
+    where
+        AccountStream: Stream<Item = AccountStreamEvent>,
+    {
+        account_stream.filter_map(move |event| {
+            std::future::ready(match event {
+                AccountStreamEvent::Item(event) => self.market_event(&event),
+                AccountStreamEvent::Reconnecting(_) => None,
+            })
+        })
+    }
+
+    /// 将合成的自有成交合并到公共成交市场流中。
+    ///
+    /// 公共成交流的重连事件会原样转发。
+    pub fn merge<MarketStream, AccountStream>(
+        self,
+        market_stream: MarketStream,
+        account_stream: AccountStream,
+    ) -> impl Stream<Item = MarketStreamEvent<InstrumentIndex, PublicTrade>>
+    where
+        MarketStream: Stream<Item = MarketStreamEvent<InstrumentIndex, PublicTrade>>,
+        AccountStream: Stream<Item = AccountStreamEvent>,
+    {
+        merge(
+            market_stream,
+            self.own_trades(account_stream).map(MarketStreamEvent::Item),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::{
+        order::id::{OrderId, StrategyId},
+        trade::{AssetFees, TradeId},
+    };
+    use barter_instrument::{Side, test_utils::instrument};
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_own_trade_bridge_market_event() {
+        let instruments =
+            IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]);
+        let bridge = OwnTradeBridge::new(&instruments);
+
+        let time = DateTime::<Utc>::MIN_UTC;
+        let event = AccountEvent {
+            exchange: ExchangeIndex(0),
+            kind: AccountEventKind::Trade(Trade {
+                id: TradeId::new("trade"),
+                order_id: OrderId::new("order"),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                time_exchange: time,
+                side: Side::Sell,
+                price: dec!(100),
+                quantity: dec!(-2),
+                fees: AssetFees::quote_fees(dec!(0.1)),
+                reduce_only: false,
+            }),
+        };
+
+        let market_event = bridge.market_event(&event).unwrap();
+
+        assert_eq!(
+            market_event,
+            MarketEvent {
+                time_exchange: time,
+                time_received: time,
+                time_processed: None,
+                sequence: None,
+                exchange: ExchangeId::BinanceSpot,
+                instrument: InstrumentIndex(0),
+                kind: PublicTrade {
+                    id: "own:trade".to_string(),
+                    price: dec!(100),
+                    amount: dec!(2),
+                    side: Side::Sell,
+                },
+            }
+        );
+        assert!(is_own_trade(&market_event.kind));
+
+        // Unknown exchange is not bridged
+        let unknown = AccountEvent {
+            exchange: ExchangeIndex(1),
+            ..event
+        };
+        assert!(bridge.market_event(&unknown).is_none());
+    }
+}
@@ -0,0 +1,499 @@
+//! 实盘纸面交易（live-paper）模块
+//!
+//! 本模块定义了 [`PaperMarketFeed`]，将实时 `MarketStream` 与 [`MockExchange`] 的成交模拟
+//! 结合起来，在真实市场条件下验证策略，而无需发送真实订单。
+//!
+//! # 工作原理
+//!
+//! 1. [`PaperMarketFeed::forward`] 包装实时 [`MarketStreamEvent`] 流，在每个事件传递给
+//!    Engine 之前提取最新市场价格（成交价或 L1 中间价）以及 L1 最优买卖价
+//! 2. 每个 [`MockExchange`] 订阅 `PaperMarketFeed`，将其交易所的最新市场价格作为
+//!    `MockExchangeRequest::market_price` 请求接收，将最优买卖价作为
+//!    `MockExchangeRequest::market_bbo` 请求接收
+//! 3. `MockExchange` 以最新市场价格成交订单（[`MockFillPrice::Market`]），并随最优买卖价
+//!    变动重新定价挂单的 peg 订单，生成真实的 `AccountEvent`
+//!
+//! 回测同样使用 `PaperMarketFeed` 将历史市场数据转发到模拟交易所。
+//!
+//! # 使用示例
+//!
+//! ```rust,ignore
+//! let paper_market_feed = PaperMarketFeed::default();
+//!
+//! // 将实时市场价格转发到模拟交易所
+//! let market_stream = paper_market_feed.forward(live_market_stream);
+//!
+//! let execution = ExecutionBuilder::new(&instruments)
+//!     .with_paper_market_feed(paper_market_feed)
+//!     .add_mock(mock_config_with_market_fill_price, clock)?
+//!     .build();
+//! ```
+//!
+//! [`MockExchange`]: barter_execution::exchange::mock::MockExchange
+//! [`MockFillPrice::Market`]: barter_execution::client::mock::MockFillPrice::Market
+
+use barter_data::{
+    event::{DataKind, MarketEvent},
+    streams::{consumer::MarketStreamEvent, reconnect},
+};
+use barter_execution::exchange::mock::request::MockExchangeRequest;
+use barter_instrument::{
+    exchange::ExchangeId,
+    index::IndexedInstruments,
+    instrument::{InstrumentIndex, name::InstrumentNameExchange},
+};
+use chrono::{DateTime, Utc};
+use fnv::FnvHashMap;
+use futures::{Stream, StreamExt};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{
+    broadcast::{self, error::RecvError},
+    mpsc,
+};
+use tracing::warn;
+
+/// [`PaperMarketFeed`] 广播通道的容量。
+const PAPER_MARKET_FEED_CAPACITY: usize = 1024;
+
+/// 从实时市场数据流中提取的交易对最新市场价格。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct PaperMarketPrice {
+    /// 交易对索引
+    pub instrument: InstrumentIndex,
+    /// 最新市场价格
+    pub price: Decimal,
+    /// 最新 L1 最优买卖价 `(best_bid, best_ask)`，仅由订单簿事件提供
+    #[serde(default)]
+    pub bbo: Option<(Decimal, Decimal)>,
+}
+
+/// 可从中提取 [`PaperMarketPrice`] 的市场事件类型。
+pub trait PaperMarketKind {
+    /// 最新市场价格：成交价，或 L1 订单簿的中间价。
+    fn paper_market_price(&self) -> Option<Decimal>;
+
+    /// 最新 L1 最优买卖价 `(best_bid, best_ask)`。
+    fn paper_market_bbo(&self) -> Option<(Decimal, Decimal)>;
+}
+
+impl PaperMarketKind for DataKind {
+    fn paper_market_price(&self) -> Option<Decimal> {
+        match self {
+            DataKind::Trade(trade) => Some(trade.price),
+            DataKind::OrderBookL1(l1) => l1.mid_price(),
+            DataKind::Bbo(bbo) => Some(bbo.mid_price()),
+            _ => None,
+        }
+    }
+
+    fn paper_market_bbo(&self) -> Option<(Decimal, Decimal)> {
+        match self {
+            DataKind::OrderBookL1(l1) => Some((l1.best_bid?.price, l1.best_ask?.price)),
+            DataKind::Bbo(bbo) => Some((bbo.bid, bbo.ask)),
+            _ => None,
+        }
+    }
+}
+
+/// 将实时市场价格广播到模拟交易所的实盘纸面交易馈送。
+///
+/// 克隆的 `PaperMarketFeed` 共享同一个广播通道。
+#[derive(Debug, Clone)]
+pub struct PaperMarketFeed {
+    tx: broadcast::Sender<PaperMarketPrice>,
+}
+
+impl Default for PaperMarketFeed {
+    fn default() -> Self {
+        Self {
+            tx: broadcast::channel(PAPER_MARKET_FEED_CAPACITY).0,
+        }
+    }
+}
+
+impl PaperMarketFeed {
+    /// 包装实时 [`MarketStreamEvent`] 流，在每个事件传递给下游之前将其最新市场价格
+    /// 广播到所有订阅的模拟交易所。
+    ///
+    /// 事件本身保持不变。
+    pub fn forward<St, Kind>(
+        &self,
+        market_stream: St,
+    ) -> impl Stream<Item = MarketStreamEvent<InstrumentIndex, Kind>> + use<St, Kind>
+    where
+        St: Stream<Item = MarketStreamEvent<InstrumentIndex, Kind>>,
+        Kind: PaperMarketKind,
+    {
+        let tx = self.tx.clone();
+
+        market_stream.inspect(move |event| {
+            let reconnect::Event::Item(event) = event else {
+                return;
+            };
+
+            if let Some(price) = paper_market_price(event) {
+                // 没有订阅的模拟交易所时发送失败是正常的
+                let _ = tx.send(price);
+            }
+        })
+    }
+
+    /// 订阅 `PaperMarketFeed`，返回将 `exchange` 交易对的最新市场价格作为
+    /// `MockExchangeRequest::market_price` 请求、最优买卖价作为
+    /// `MockExchangeRequest::market_bbo` 请求转发到 `MockExchange` 的 Future。
+    ///
+    /// 订阅在调用时立即生效，因此不会错过调用之后广播的价格。当所有 `PaperMarketFeed`
+    /// 被丢弃或 `MockExchange` 停止运行时，Future 结束。
+    ///
+    /// # 参数
+    ///
+    /// - `instruments`: 索引化交易对集合，用于将 [`InstrumentIndex`] 映射为交易所交易对名称
+    /// - `exchange`: 模拟的交易所
+    /// - `request_tx`: `MockExchange` 请求发送器
+    /// - `clock`: 用于请求时间的时钟
+    pub fn forward_to_mock_exchange<FnTime>(
+        &self,
+        instruments: &IndexedInstruments,
+        exchange: ExchangeId,
+        request_tx: mpsc::UnboundedSender<MockExchangeRequest>,
+        clock: FnTime,
+    ) -> impl Future<Output = ()> + Send + 'static + use<FnTime>
+    where
+        FnTime: Fn() -> DateTime<Utc> + Send + 'static,
+    {
+        let mut prices = self.tx.subscribe();
+
+        let instruments = instruments
+            .instruments()
+            .iter()
+            .filter(|instrument| instrument.value.exchange.value == exchange)
+            .map(|instrument| (instrument.key, instrument.value.name_exchange.clone()))
+            .collect::<FnvHashMap<InstrumentIndex, InstrumentNameExchange>>();
+
+        async move {
+            loop {
+                let price = match prices.recv().await {
+                    Ok(price) => price,
+                    Err(RecvError::Lagged(skipped)) => {
+                        warn!(
+                            %exchange,
+                            skipped,
+                            "PaperMarketFeed lagged - MockExchange skipped market prices"
+                        );
+                        continue;
+                    }
+                    Err(RecvError::Closed) => break,
+                };
+
+                let Some(instrument) = instruments.get(&price.instrument) else {
+                    continue;
+                };
+
+                let request =
+                    MockExchangeRequest::market_price(clock(), instrument.clone(), price.price);
+
+                if request_tx.send(request).is_err() {
+                    break;
+                }
+
+                let Some((best_bid, best_ask)) = price.bbo else {
+                    continue;
+                };
+
+                let request = MockExchangeRequest::market_bbo(
+                    clock(),
+                    instrument.clone(),
+                    best_bid,
+                    best_ask,
+                );
+
+                if request_tx.send(request).is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// 提取 [`MarketEvent`] 的最新市场价格：成交价，或 L1 订单簿的中间价。
+fn paper_market_price<Kind>(event: &MarketEvent<InstrumentIndex, Kind>) -> Option<PaperMarketPrice>
+where
+    Kind: PaperMarketKind,
+{
+    Some(PaperMarketPrice {
+        instrument: event.instrument,
+        price: event.kind.paper_market_price()?,
+        bbo: event.kind.paper_market_bbo(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_data::{
+        books::Level,
+        subscription::{book::OrderBookL1, trade::PublicTrade},
+    };
+    use barter_execution::{
+        AccountEventKind, UnindexedAccountEvent, UnindexedAccountSnapshot,
+        balance::{AssetBalance, Balance},
+        client::mock::{MockExecutionConfig, MockFillPrice},
+        exchange::mock::MockExchange,
+        order::{
+            OrderKey, OrderKind, PegReference, TimeInForce,
+            id::{ClientOrderId, StrategyId},
+            request::{OrderRequestOpen, RequestOpen},
+        },
+    };
+    use barter_instrument::{
+        Side, Underlying,
+        asset::name::AssetNameExchange,
+        instrument::{Instrument, kind::InstrumentKind, quote::InstrumentQuoteAsset},
+        test_utils::instrument,
+    };
+    use rust_decimal_macros::dec;
+    use tokio::sync::oneshot;
+
+    fn trade(instrument: usize, price: Decimal) -> MarketStreamEvent<InstrumentIndex, DataKind> {
+        reconnect::Event::Item(MarketEvent {
+            time_exchange: DateTime::<Utc>::MIN_UTC,
+            time_received: DateTime::<Utc>::MIN_UTC,
+            time_processed: None,
+            sequence: None,
+            exchange: ExchangeId::Mock,
+            instrument: InstrumentIndex(instrument),
+            kind: DataKind::Trade(PublicTrade {
+                id: format!("{price}"),
+                price,
+                amount: dec!(1),
+                side: Side::Sell,
+            }),
+        })
+    }
+
+    fn l1(
+        instrument: usize,
+        best_bid: Decimal,
+        best_ask: Decimal,
+    ) -> MarketStreamEvent<InstrumentIndex, DataKind> {
+        reconnect::Event::Item(MarketEvent {
+            time_exchange: DateTime::<Utc>::MIN_UTC,
+            time_received: DateTime::<Utc>::MIN_UTC,
+            time_processed: None,
+            sequence: None,
+            exchange: ExchangeId::Mock,
+            instrument: InstrumentIndex(instrument),
+            kind: DataKind::OrderBookL1(OrderBookL1 {
+                last_update_time: DateTime::<Utc>::MIN_UTC,
+                best_bid: Some(Level::new(best_bid, dec!(1))),
+                best_ask: Some(Level::new(best_ask, dec!(1))),
+            }),
+        })
+    }
+
+    fn mock_exchange(
+        request_rx: mpsc::UnboundedReceiver<MockExchangeRequest>,
+        event_tx: broadcast::Sender<UnindexedAccountEvent>,
+    ) -> MockExchange {
+        let time = DateTime::<Utc>::MIN_UTC;
+        let instrument = Instrument::new(
+            ExchangeId::Mock,
+            "mock-btc_usdt",
+            "btc_usdt",
+            Underlying::new(
+                AssetNameExchange::from("btc"),
+                AssetNameExchange::from("usdt"),
+            ),
+            InstrumentQuoteAsset::UnderlyingQuote,
+            InstrumentKind::Spot,
+            None,
+        );
+
+        MockExchange::new(
+            MockExecutionConfig {
+                mocked_exchange: ExchangeId::Mock,
+                initial_state: UnindexedAccountSnapshot {
+                    exchange: ExchangeId::Mock,
+                    balances: vec![
+                        AssetBalance::new(
+                            AssetNameExchange::from("btc"),
+                            Balance::new(dec!(0), dec!(0)),
+                            time,
+                        ),
+                        AssetBalance::new(
+                            AssetNameExchange::from("usdt"),
+                            Balance::new(dec!(1000), dec!(1000)),
+                            time,
+                        ),
+                    ],
+                    instruments: vec![],
+                },
+                latency_ms: 0,
+                fees_percent: Some(Decimal::ZERO),
+                fees_maker_percent: None,
+                fee_tier: None,
+                fill_price: MockFillPrice::Market,
+                position_mode: Default::default(),
+            },
+            request_rx,
+            event_tx,
+            FnvHashMap::from_iter([(instrument.name_exchange.clone(), instrument)]),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_paper_market_feed_fills_crossing_order_at_live_price() {
+        let instruments = IndexedInstruments::new([instrument(ExchangeId::Mock, "btc", "usdt")]);
+
+        let (request_tx, request_rx) = mpsc::unbounded_channel();
+        let (event_tx, mut event_rx) = broadcast::channel(16);
+        let mock_exchange = tokio::spawn(mock_exchange(request_rx, event_tx).run());
+
+        let feed = PaperMarketFeed::default();
+        let forward_prices = tokio::spawn(feed.forward_to_mock_exchange(
+            &instruments,
+            ExchangeId::Mock,
+            request_tx.clone(),
+            || DateTime::<Utc>::MIN_UTC,
+        ));
+
+        // Scripted live MarketStream: latest trade price 100
+        let events = vec![
+            trade(0, dec!(101)),
+            reconnect::Event::Reconnecting(ExchangeId::Mock),
+            trade(0, dec!(100)),
+        ];
+        let forwarded = feed
+            .forward(futures::stream::iter(events.clone()))
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(forwarded, events);
+
+        // Dropping the feed ends the forwarder once every price has been sent to the MockExchange
+        drop(feed);
+        forward_prices.await.unwrap();
+
+        // Buy priced at 105 crosses the live price of 100, so fills at the live price
+        let (response_tx, response_rx) = oneshot::channel();
+        request_tx
+            .send(MockExchangeRequest::open_order(
+                DateTime::<Utc>::MIN_UTC,
+                response_tx,
+                OrderRequestOpen {
+                    key: OrderKey {
+                        exchange: ExchangeId::Mock,
+                        instrument: InstrumentNameExchange::new("btc_usdt"),
+                        strategy: StrategyId::new("strategy"),
+                        cid: ClientOrderId::new("cid"),
+                    },
+                    state: RequestOpen {
+                        side: Side::Buy,
+                        price: dec!(105),
+                        quantity: dec!(2),
+                        kind: OrderKind::Market,
+                        time_in_force: TimeInForce::ImmediateOrCancel,
+                        reduce_only: false,
+                    },
+                },
+            ))
+            .unwrap();
+
+        let order = response_rx.await.unwrap();
+        assert!(order.state.is_ok());
+        assert_eq!(order.price, dec!(100));
+
+        let mut trade_price = None;
+        let mut balance = None;
+        while trade_price.is_none() || balance.is_none() {
+            match event_rx.recv().await.unwrap().kind {
+                AccountEventKind::Trade(trade) => trade_price = Some(trade.price),
+                AccountEventKind::BalanceSnapshot(snapshot) => balance = Some(snapshot.0.balance),
+                _ => {}
+            }
+        }
+        assert_eq!(trade_price, Some(dec!(100)));
+        assert_eq!(balance, Some(Balance::new(dec!(800), dec!(800))));
+
+        drop(request_tx);
+        mock_exchange.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_paper_market_feed_reprices_peg_order_from_l1_events() {
+        let instruments = IndexedInstruments::new([instrument(ExchangeId::Mock, "btc", "usdt")]);
+
+        let (request_tx, request_rx) = mpsc::unbounded_channel();
+        let (event_tx, mut event_rx) = broadcast::channel(16);
+        let mock_exchange = tokio::spawn(mock_exchange(request_rx, event_tx).run());
+
+        // Forwards a scripted live MarketStream to the MockExchange, returning once every market
+        // price and best bid and offer has been sent
+        let forward = |events: Vec<MarketStreamEvent<InstrumentIndex, DataKind>>| {
+            let feed = PaperMarketFeed::default();
+            let forwarder = tokio::spawn(feed.forward_to_mock_exchange(
+                &instruments,
+                ExchangeId::Mock,
+                request_tx.clone(),
+                || DateTime::<Utc>::MIN_UTC,
+            ));
+            async move {
+                feed.forward(futures::stream::iter(events))
+                    .collect::<Vec<_>>()
+                    .await;
+                drop(feed);
+                forwarder.await.unwrap();
+            }
+        };
+
+        // Best bid and offer 99/101
+        forward(vec![l1(0, dec!(98), dec!(102)), l1(0, dec!(99), dec!(101))]).await;
+
+        // Buy pegged to the best bid rests at 99
+        let (response_tx, response_rx) = oneshot::channel();
+        request_tx
+            .send(MockExchangeRequest::open_order(
+                DateTime::<Utc>::MIN_UTC,
+                response_tx,
+                OrderRequestOpen {
+                    key: OrderKey {
+                        exchange: ExchangeId::Mock,
+                        instrument: InstrumentNameExchange::new("btc_usdt"),
+                        strategy: StrategyId::new("strategy"),
+                        cid: ClientOrderId::new("peg"),
+                    },
+                    state: RequestOpen {
+                        side: Side::Buy,
+                        price: Decimal::ZERO,
+                        quantity: dec!(1),
+                        kind: OrderKind::Peg {
+                            reference: PegReference::BestBid,
+                            offset: Decimal::ZERO,
+                        },
+                        time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                        reduce_only: false,
+                    },
+                },
+            ))
+            .unwrap();
+
+        let order = response_rx.await.unwrap();
+        assert!(order.state.is_ok());
+        assert_eq!(order.price, dec!(99));
+
+        // Best bid moves up to 100, so the resting peg order is re-priced to follow it
+        forward(vec![l1(0, dec!(100), dec!(102))]).await;
+
+        let repriced = loop {
+            match event_rx.recv().await.unwrap().kind {
+                AccountEventKind::OrderSnapshot(snapshot) => break snapshot.0,
+                _ => continue,
+            }
+        };
+        assert_eq!(repriced.key.cid, ClientOrderId::new("peg"));
+        assert_eq!(repriced.price, dec!(100));
+
+        drop(request_tx);
+        mock_exchange.await.unwrap();
+    }
+}
@@ -31,6 +31,7 @@ use std::{
 /// ## 变体说明
 ///
 /// - **Shutdown**: 请求 ExecutionManager 关闭
+/// - **Heartbeat**: Engine 存活心跳，证明 Engine 仍在运行
 /// - **Cancel**: 请求取消现有订单
 /// - **Open**: 请求开仓新订单
 ///
@@ -51,6 +52,10 @@ pub enum ExecutionRequest<ExchangeKey = ExchangeIndex, InstrumentKey = Instrumen
     /// 请求 `ExecutionManager` 关闭。
     Shutdown,
 
+    /// Engine 存活心跳，启用死人开关的 `ExecutionManager` 只在 Engine 仍在发送请求或心跳时才向
+    /// 交易所发送心跳（参见 `SystemBuilder::execution_heartbeat`）。
+    Heartbeat,
+
     /// 请求取消现有 `Order`。
     Cancel(OrderRequestCancel<ExchangeKey, InstrumentKey>),
 
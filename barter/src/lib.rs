@@ -301,18 +301,67 @@ impl Sequence {
 /// 提供用于测试的辅助函数和工具，包括时间操作、浮点数比较、测试数据生成等。
 pub mod test_utils {
     use crate::{
-        Timed, engine::state::asset::AssetState, statistic::summary::asset::TearSheetAssetGenerator,
+        Timed,
+        engine::{
+            clock::EngineClock,
+            state::{
+                EngineState, asset::AssetState, global::DefaultGlobalData,
+                instrument::data::DefaultInstrumentMarketData,
+            },
+        },
+        statistic::summary::asset::TearSheetAssetGenerator,
     };
     use barter_execution::{
         balance::Balance,
-        order::id::{OrderId, StrategyId},
+        order::{
+            id::{OrderId, StrategyId},
+            request::OrderRequestOpen,
+        },
         trade::{AssetFees, Trade, TradeId},
     };
     use barter_instrument::{
-        Side, asset::QuoteAsset, instrument::name::InstrumentNameInternal, test_utils::asset,
+        Side,
+        asset::QuoteAsset,
+        exchange::ExchangeId,
+        index::IndexedInstruments,
+        instrument::name::InstrumentNameInternal,
+        test_utils::{asset, instrument},
     };
     use chrono::{DateTime, Days, TimeDelta, Utc};
     use rust_decimal::Decimal;
+    use std::{cell::Cell, rc::Rc};
+
+    /// 测试用的可手动推进的 [`EngineClock`]。
+    ///
+    /// 克隆的 TestClock 共享同一时间，因此可以将克隆交给被测对象，同时在测试中推进时间。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let clock = TestClock::new(DateTime::<Utc>::MIN_UTC);
+    /// let strategy = Dca::new(strategy_id, config, clock.clone());
+    /// clock.advance(TimeDelta::days(1));
+    /// ```
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct TestClock(Rc<Cell<DateTime<Utc>>>);
+
+    impl TestClock {
+        /// 构造从 `time` 开始的 TestClock。
+        pub fn new(time: DateTime<Utc>) -> Self {
+            Self(Rc::new(Cell::new(time)))
+        }
+
+        /// 将时间推进 `delta`。
+        pub fn advance(&self, delta: TimeDelta) {
+            self.0.set(self.0.get() + delta)
+        }
+    }
+
+    impl EngineClock for TestClock {
+        fn time(&self) -> DateTime<Utc> {
+            self.0.get()
+        }
+    }
 
     /// 比较两个 f64 浮点数是否相等（考虑 NaN 和无穷大）。
     ///
@@ -431,6 +480,46 @@ pub mod test_utils {
             .unwrap()
     }
 
+    /// 使用提供的交易对创建一个测试用的 [`EngineState`]。
+    ///
+    /// 所有交易对使用 [`DefaultInstrumentMarketData`]，Engine 启动时间为
+    /// `DateTime::<Utc>::MIN_UTC`。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let instruments = IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]);
+    /// let state = engine_state(&instruments);
+    /// ```
+    pub fn engine_state(
+        instruments: &IndexedInstruments,
+    ) -> EngineState<DefaultGlobalData, DefaultInstrumentMarketData> {
+        EngineState::builder(instruments, DefaultGlobalData, |_| {
+            DefaultInstrumentMarketData::default()
+        })
+        .time_engine_start(DateTime::<Utc>::MIN_UTC)
+        .build()
+    }
+
+    /// 创建一个包含 `BinanceSpot` `<base>_usdt` 现货交易对的测试用 [`EngineState`]。
+    ///
+    /// 交易对索引与 `bases` 的顺序一致，例如 `bases[0]` 对应 `InstrumentIndex(0)`。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let state = spot_engine_state(&["btc", "eth"]);
+    /// ```
+    pub fn spot_engine_state(
+        bases: &[&str],
+    ) -> EngineState<DefaultGlobalData, DefaultInstrumentMarketData> {
+        engine_state(&IndexedInstruments::new(
+            bases
+                .iter()
+                .map(|base| instrument(ExchangeId::BinanceSpot, base, "usdt")),
+        ))
+    }
+
     /// 创建一个测试用的 Trade（交易）对象。
     ///
     /// 用于测试场景中快速创建 Trade 实例，所有字段都使用默认或提供的值。
@@ -478,6 +567,45 @@ pub mod test_utils {
                 asset: QuoteAsset,
                 fees: fees.try_into().unwrap(),
             },
+            reduce_only: false,
+        }
+    }
+
+    /// 创建一个成交 `request` 的测试用 Trade（交易）对象。
+    ///
+    /// 基于 [`trade`] 创建，成交价格、方向、交易对和策略 ID 取自 `request`，订单 ID 为
+    /// `request` 的客户端订单 ID，手续费为零。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let fill = trade_from_request(&request, request.state.quantity);
+    /// ```
+    pub fn trade_from_request<ExchangeKey, InstrumentKey>(
+        request: &OrderRequestOpen<ExchangeKey, InstrumentKey>,
+        quantity: Decimal,
+    ) -> Trade<QuoteAsset, InstrumentKey>
+    where
+        InstrumentKey: Clone,
+    {
+        let Trade {
+            id,
+            time_exchange,
+            fees,
+            ..
+        } = trade(DateTime::<Utc>::MIN_UTC, request.state.side, 0.0, 0.0, 0.0);
+
+        Trade {
+            id,
+            order_id: OrderId::new(&request.key.cid.0),
+            instrument: request.key.instrument.clone(),
+            strategy: request.key.strategy.clone(),
+            time_exchange,
+            side: request.state.side,
+            price: request.state.price,
+            quantity,
+            fees,
+            reduce_only: request.state.reduce_only,
         }
     }
 
@@ -524,6 +652,7 @@ pub mod test_utils {
             asset: asset(symbol),
             balance: Some(balance),
             statistics: TearSheetAssetGenerator::init(&balance),
+            reserved: Decimal::ZERO,
         }
     }
 }
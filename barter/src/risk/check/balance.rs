@@ -0,0 +1,344 @@
+use crate::{
+    engine::state::{
+        asset::{AssetStates, reservation::InstrumentReserved},
+        instrument::{InstrumentStates, data::InstrumentDataState},
+    },
+    risk::{RiskApproved, RiskRefused},
+};
+use barter_execution::order::{id::ClientOrderId, request::OrderRequestOpen};
+use barter_instrument::{
+    asset::{AssetIndex, name::AssetNameInternal},
+    exchange::ExchangeIndex,
+    instrument::InstrumentIndex,
+};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// 可用余额风险检查。
+///
+/// 拒绝所需资金超过资产可用余额（`free` 余额减去在途开仓请求预留的余额，参见
+/// [`AssetState::balance_available`](crate::engine::state::asset::AssetState::balance_available)）
+/// 的开仓请求，防止在成交确认之前发送的多个开仓请求超额使用同一余额。
+///
+/// 所需资金由 [`InstrumentReserved::required`] 计算：现货买入需要计价资产，现货卖出需要
+/// 基础资产。不需要预留余额的开仓请求（例如衍生品或 reduce-only 开仓请求）不受影响。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let (approved, refused) =
+///     CheckBalanceAvailable.check_opens(&state.assets, &state.instruments, opens);
+/// ```
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct CheckBalanceAvailable;
+
+impl CheckBalanceAvailable {
+    /// 返回 RiskManager 检查的名称。
+    pub fn name() -> &'static str {
+        "CheckBalanceAvailable"
+    }
+
+    /// 检查所需资金是否不超过可用余额。
+    ///
+    /// # 参数
+    ///
+    /// - `asset`: 所需资金的资产名称
+    /// - `available`: 资产的可用余额
+    /// - `required`: 开仓请求所需的资金
+    /// - `open`: 要检查的开仓请求
+    pub fn check<ExchangeKey, InstrumentKey>(
+        &self,
+        asset: &AssetNameInternal,
+        available: Decimal,
+        required: Decimal,
+        open: &OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    ) -> Result<(), CheckFailBalanceAvailable> {
+        if required <= available {
+            Ok(())
+        } else {
+            Err(CheckFailBalanceAvailable {
+                cid: open.key.cid.clone(),
+                asset: asset.clone(),
+                required,
+                available,
+            })
+        }
+    }
+
+    /// 检查每个开仓请求，返回已批准和被拒绝的开仓请求。
+    ///
+    /// 同一批次中先前已批准的开仓请求会消耗可用余额，因此多个开仓请求合计不会超过
+    /// 可用余额。
+    ///
+    /// # 参数
+    ///
+    /// - `assets`: 资产状态集合
+    /// - `instruments`: 交易对状态集合
+    /// - `opens`: 要检查的开仓请求
+    ///
+    /// # 返回值
+    ///
+    /// 返回 `(已批准的开仓请求, 被拒绝的开仓请求)`。
+    pub fn check_opens<InstrumentData>(
+        &self,
+        assets: &AssetStates,
+        instruments: &InstrumentStates<InstrumentData>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        Vec<RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        Vec<RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    )
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        let mut available = FnvHashMap::<AssetIndex, Decimal>::default();
+
+        opens.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut approved, mut refused), open| {
+                let instrument = &instruments
+                    .instrument_index(&open.key.instrument)
+                    .instrument;
+                let reserved = InstrumentReserved::required(
+                    instrument,
+                    open.state.side,
+                    open.state.price,
+                    open.state.quantity,
+                    open.state.reduce_only,
+                );
+
+                let (asset, required) = if reserved.quote > Decimal::ZERO {
+                    (instrument.underlying.quote, reserved.quote)
+                } else if reserved.base > Decimal::ZERO {
+                    (instrument.underlying.base, reserved.base)
+                } else {
+                    approved.push(RiskApproved::new(open));
+                    return (approved, refused);
+                };
+
+                let asset_state = assets.asset_index(&asset);
+                let remaining = available
+                    .entry(asset)
+                    .or_insert_with(|| asset_state.balance_available().unwrap_or(Decimal::ZERO));
+
+                match self.check(
+                    &asset_state.asset.name_internal,
+                    *remaining,
+                    required,
+                    &open,
+                ) {
+                    Ok(()) => {
+                        *remaining -= required;
+                        approved.push(RiskApproved::new(open));
+                    }
+                    Err(error) => {
+                        refused.push(RiskRefused::new(open, error.to_string()));
+                    }
+                }
+
+                (approved, refused)
+            },
+        )
+    }
+}
+
+/// [`CheckBalanceAvailable`] 失败时返回的错误。
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize, Constructor, Error,
+)]
+#[error(
+    "CheckBalanceAvailable failed: order {cid} requires {required} {asset} but only {available} is available"
+)]
+pub struct CheckFailBalanceAvailable {
+    /// 被检查的开仓请求的 ClientOrderId
+    pub cid: ClientOrderId,
+    /// 所需资金的资产
+    pub asset: AssetNameInternal,
+    /// 开仓请求所需的资金
+    pub required: Decimal,
+    /// 资产的可用余额
+    pub available: Decimal,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{
+            EngineState, global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+            order::in_flight_recorder::InFlightRequestRecorder,
+        },
+        test_utils::spot_engine_state,
+    };
+    use barter_execution::{
+        AccountEvent, AccountEventKind,
+        balance::{AssetBalance, Balance},
+        order::{
+            Order, OrderKey, OrderKind, TimeInForce,
+            id::{OrderId, StrategyId},
+            request::{OrderResponseCancel, RequestOpen},
+            state::{Cancelled, Open, OrderState},
+        },
+    };
+    use barter_instrument::Side;
+    use barter_integration::snapshot::Snapshot;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    type State = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    const USDT: AssetIndex = AssetIndex(1);
+
+    fn state() -> State {
+        let mut state = spot_engine_state(&["btc"]);
+
+        assert_eq!(
+            state
+                .instruments
+                .instrument_index(&InstrumentIndex(0))
+                .instrument
+                .underlying
+                .quote,
+            USDT
+        );
+
+        state
+            .assets
+            .asset_index_mut(&USDT)
+            .update_from_balance(Snapshot(&AssetBalance::new(
+                USDT,
+                Balance::new(dec!(100), dec!(100)),
+                DateTime::<Utc>::MIN_UTC,
+            )));
+
+        state
+    }
+
+    fn open(cid: &str, price: Decimal) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                price,
+                quantity: dec!(1),
+                kind: OrderKind::Limit,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: false,
+            },
+        }
+    }
+
+    fn check(state: &State, opens: Vec<OrderRequestOpen>) -> (Vec<String>, Vec<String>) {
+        let (approved, refused) =
+            CheckBalanceAvailable.check_opens(&state.assets, &state.instruments, opens);
+
+        (
+            approved
+                .into_iter()
+                .map(|approved| approved.into_item().key.cid.to_string())
+                .collect(),
+            refused.into_iter().map(|refused| refused.reason).collect(),
+        )
+    }
+
+    #[test]
+    fn test_check_balance_available_with_in_flight_reservations() {
+        let mut state = state();
+
+        // First open is approved & reserves its notional once recorded in-flight
+        let first = open("first", dec!(60));
+        assert_eq!(
+            check(&state, vec![first.clone()]),
+            (vec!["first".to_string()], vec![])
+        );
+        state.record_in_flight_open(&first);
+        assert_eq!(state.assets.asset_index(&USDT).reserved, dec!(60));
+        assert_eq!(
+            state.assets.asset_index(&USDT).balance_available(),
+            Some(dec!(40))
+        );
+
+        // Concurrent second open exceeds free-minus-reserved balance
+        let second = open("second", dec!(60));
+        assert_eq!(
+            check(&state, vec![second.clone()]),
+            (
+                vec![],
+                vec![
+                    "CheckBalanceAvailable failed: order second requires 60 usdt but only 40 is available"
+                        .to_string()
+                ]
+            )
+        );
+
+        // Cancelling the in-flight open releases its reservation
+        state.update_from_account(&AccountEvent {
+            exchange: ExchangeIndex(0),
+            kind: AccountEventKind::OrderCancelled(OrderResponseCancel {
+                key: first.key.clone(),
+                state: Ok(Cancelled::new(
+                    OrderId::new("first"),
+                    DateTime::<Utc>::MIN_UTC,
+                )),
+            }),
+        });
+        assert_eq!(state.assets.asset_index(&USDT).reserved, Decimal::ZERO);
+        assert_eq!(
+            check(&state, vec![second]),
+            (vec!["second".to_string()], vec![])
+        );
+    }
+
+    #[test]
+    fn test_check_balance_available_within_batch() {
+        let state = state();
+
+        let (approved, refused) = check(
+            &state,
+            vec![open("first", dec!(60)), open("second", dec!(60))],
+        );
+        assert_eq!(approved, vec!["first".to_string()]);
+        assert_eq!(refused.len(), 1);
+    }
+
+    #[test]
+    fn test_in_flight_reservation_released_on_open_acknowledged() {
+        let mut state = state();
+
+        let first = open("first", dec!(60));
+        state.record_in_flight_open(&first);
+        assert_eq!(state.assets.asset_index(&USDT).reserved, dec!(60));
+
+        // Once Open, the exchange free balance accounts for the order
+        let snapshot = Order {
+            key: first.key.clone(),
+            side: first.state.side,
+            price: first.state.price,
+            quantity: first.state.quantity,
+            kind: first.state.kind,
+            time_in_force: first.state.time_in_force,
+            reduce_only: first.state.reduce_only,
+            state: OrderState::active(Open::new(
+                OrderId::new("first"),
+                DateTime::<Utc>::MIN_UTC,
+                Decimal::ZERO,
+            )),
+        };
+
+        state.update_from_account(&AccountEvent {
+            exchange: ExchangeIndex(0),
+            kind: AccountEventKind::OrderSnapshot(Snapshot(snapshot)),
+        });
+        assert_eq!(state.assets.asset_index(&USDT).reserved, Decimal::ZERO);
+    }
+}
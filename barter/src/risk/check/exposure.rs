@@ -0,0 +1,667 @@
+use crate::{
+    engine::state::{
+        instrument::{InstrumentStates, data::InstrumentDataState},
+        position::PositionMode,
+    },
+    risk::{
+        RiskApproved, RiskRefused,
+        check::{RiskCheck, util::calculate_quote_notional},
+    },
+};
+use barter_execution::order::{request::OrderRequestOpen, state::ActiveOrderState};
+use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use derive_more::{Constructor, Display};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// 账户级别的聚合敞口（以报价资产计价的名义价值）。
+///
+/// ## 字段
+///
+/// - `gross`: 总敞口，各交易对多头和空头名义价值之和（对冲模式下两边分别计入）
+/// - `net`: 净敞口，各交易对有符号名义价值之和（多头为正，空头为负）
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Constructor,
+)]
+pub struct AggregateExposure {
+    /// 总敞口（各交易对多头和空头名义价值之和）
+    pub gross: Decimal,
+    /// 净敞口（各交易对有符号名义价值之和）
+    pub net: Decimal,
+}
+
+impl AggregateExposure {
+    /// 将某个交易对的敞口从 `prev` 替换为 `next`，返回新的 `AggregateExposure`。
+    ///
+    /// 如果计算溢出，返回 `None`。
+    pub fn checked_replace(
+        &self,
+        prev: &InstrumentExposure,
+        next: &InstrumentExposure,
+    ) -> Option<Self> {
+        Some(Self {
+            gross: self
+                .gross
+                .checked_sub(prev.gross()?)?
+                .checked_add(next.gross()?)?,
+            net: self
+                .net
+                .checked_sub(prev.net()?)?
+                .checked_add(next.net()?)?,
+        })
+    }
+}
+
+/// [`CheckAggregateExposure`] 的敞口限制类型。
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Display,
+)]
+pub enum ExposureLimit {
+    /// 总敞口限制。
+    #[display("gross")]
+    Gross,
+    /// 净敞口限制（按绝对值比较）。
+    #[display("net")]
+    Net,
+}
+
+/// 限制账户级别总敞口和净敞口的风险检查。
+///
+/// 单个交易对的限制无法约束整个账户的风险。`CheckAggregateExposure` 遍历
+/// [`InstrumentStates`] 中的所有仓位和待成交的开仓订单，使用每个交易对的价格计算总敞口
+/// 和净敞口，并拒绝会突破任一限制的开仓请求。
+///
+/// ## 计算方式
+///
+/// - 每个交易对的 [`InstrumentExposure`] = 仓位 + 待成交开仓订单的多头和空头名义价值
+/// - 名义价值使用 [`InstrumentDataState::price`] 计算，如果不可用则回退到订单价格或仓位
+///   平均入场价格，并乘以合约乘数
+/// - 总敞口 = 各交易对多头和空头名义价值之和（净仓模式下多空相互抵消，对冲模式下两边
+///   分别计入）
+/// - 净敞口 = 各交易对多头减空头名义价值之和
+///
+/// 减少敞口的开仓请求（例如平仓订单）即使在限制已被突破时也会被批准。如果名义价值计算
+/// 溢出，开仓请求会被拒绝。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let check = CheckAggregateExposure::new(dec!(100_000), dec!(50_000));
+/// let (approved, refused) = check.check_opens(&state.instruments, opens);
+/// ```
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct CheckAggregateExposure {
+    /// 总敞口上限
+    pub limit_gross: Decimal,
+    /// 净敞口绝对值上限
+    pub limit_net: Decimal,
+}
+
+impl RiskCheck for CheckAggregateExposure {
+    type Input = AggregateExposure;
+    type Error = CheckFailAggregateExposure;
+
+    fn name() -> &'static str {
+        "CheckAggregateExposure"
+    }
+
+    fn check(&self, input: &Self::Input) -> Result<(), Self::Error> {
+        self.check_limit(ExposureLimit::Gross, input)?;
+        self.check_limit(ExposureLimit::Net, input)
+    }
+}
+
+impl CheckAggregateExposure {
+    /// 检查 [`AggregateExposure`] 是否突破指定的 [`ExposureLimit`]。
+    pub fn check_limit(
+        &self,
+        limit: ExposureLimit,
+        input: &AggregateExposure,
+    ) -> Result<(), CheckFailAggregateExposure> {
+        let (limit_value, exposure, exposure_abs) = match limit {
+            ExposureLimit::Gross => (self.limit_gross, input.gross, input.gross),
+            ExposureLimit::Net => (self.limit_net, input.net, input.net.abs()),
+        };
+
+        if exposure_abs > limit_value {
+            Err(CheckFailAggregateExposure {
+                limit,
+                limit_value,
+                exposure,
+                excess: exposure_abs - limit_value,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 计算 [`InstrumentStates`] 中每个交易对的 [`InstrumentExposure`]（按 [`InstrumentIndex`]
+    /// 排列）。
+    ///
+    /// 包括所有开放仓位（对冲模式下包括多头和空头）以及所有待成交的开仓订单。
+    ///
+    /// 如果名义价值计算溢出，返回 `None`。
+    pub fn instrument_exposures<InstrumentData>(
+        instruments: &InstrumentStates<InstrumentData>,
+    ) -> Option<Vec<InstrumentExposure>>
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        instruments
+            .0
+            .values()
+            .map(|state| {
+                let price = state.data.price();
+                let contract_size = state.instrument.kind.contract_size();
+                let exposure = InstrumentExposure::new(state.position.mode);
+
+                let exposure =
+                    state
+                        .position
+                        .positions()
+                        .try_fold(exposure, |exposure, position| {
+                            exposure.checked_add(
+                                position.side,
+                                calculate_quote_notional(
+                                    position.quantity_abs,
+                                    price.unwrap_or(position.price_entry_average),
+                                    contract_size,
+                                )?,
+                                false,
+                            )
+                        })?;
+
+                state
+                    .orders
+                    .active
+                    .values()
+                    .try_fold(exposure, |exposure, order| {
+                        let quantity_remaining = match &order.state {
+                            ActiveOrderState::OpenInFlight(_) => order.quantity,
+                            state => state
+                                .open_meta()
+                                .map(|open| open.quantity_remaining(order.quantity))
+                                .unwrap_or(order.quantity),
+                        };
+
+                        exposure.checked_add(
+                            order.side,
+                            calculate_quote_notional(
+                                quantity_remaining.abs(),
+                                price.unwrap_or(order.price),
+                                contract_size,
+                            )?,
+                            order.reduce_only,
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// 计算 [`InstrumentStates`] 的当前 [`AggregateExposure`]。
+    ///
+    /// 如果名义价值计算溢出，返回 `None`。
+    pub fn exposure<InstrumentData>(
+        instruments: &InstrumentStates<InstrumentData>,
+    ) -> Option<AggregateExposure>
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        aggregate(&Self::instrument_exposures(instruments)?)
+    }
+
+    /// 按顺序检查开仓请求，拒绝会突破总敞口或净敞口限制的请求。
+    ///
+    /// 已批准的开仓请求会计入后续请求的敞口计算。拒绝原因包含被突破的限制类型以及
+    /// 超出的数量。如果敞口的名义价值计算溢出，则拒绝开仓请求（fail closed）。
+    ///
+    /// # 参数
+    ///
+    /// - `instruments`: 交易对状态集合
+    /// - `opens`: 要检查的开仓请求
+    ///
+    /// # 返回值
+    ///
+    /// 返回 `(已批准的开仓请求, 被拒绝的开仓请求)`。
+    pub fn check_opens<InstrumentData>(
+        &self,
+        instruments: &InstrumentStates<InstrumentData>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        Vec<RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        Vec<RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    )
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        let mut state_exposures = Self::instrument_exposures(instruments)
+            .and_then(|exposures| Some((aggregate(&exposures)?, exposures)));
+
+        opens.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut approved, mut refused), open| {
+                let Some((current, exposures)) = state_exposures.as_mut() else {
+                    refused.push(RiskRefused::new(open, ERROR_OVERFLOW));
+                    return (approved, refused);
+                };
+
+                let state = instruments.instrument_index(&open.key.instrument);
+                let index = open.key.instrument.index();
+                let instrument_prev = exposures[index];
+
+                let Some((instrument_next, next)) = calculate_quote_notional(
+                    open.state.quantity.abs(),
+                    state.data.price().unwrap_or(open.state.price),
+                    state.instrument.kind.contract_size(),
+                )
+                .and_then(|notional| {
+                    instrument_prev.checked_add(open.state.side, notional, open.state.reduce_only)
+                })
+                .and_then(|instrument_next| {
+                    Some((
+                        instrument_next,
+                        current.checked_replace(&instrument_prev, &instrument_next)?,
+                    ))
+                }) else {
+                    refused.push(RiskRefused::new(open, ERROR_OVERFLOW));
+                    return (approved, refused);
+                };
+
+                // 仅当超限的敞口被进一步恶化时才拒绝，以便减少敞口的开仓请求可以通过
+                let breach = [ExposureLimit::Gross, ExposureLimit::Net]
+                    .into_iter()
+                    .filter_map(|limit| self.check_limit(limit, &next).err())
+                    .find(|error| error.is_increased_by(current, &next));
+
+                match breach {
+                    Some(error) => {
+                        refused.push(RiskRefused::new(open, error.to_string()));
+                    }
+                    None => {
+                        exposures[index] = instrument_next;
+                        *current = next;
+                        approved.push(RiskApproved::new(open));
+                    }
+                }
+
+                (approved, refused)
+            },
+        )
+    }
+}
+
+/// 名义价值计算溢出时的拒绝原因。
+const ERROR_OVERFLOW: &str =
+    "CheckAggregateExposure failed: exposure notional calculation overflowed";
+
+/// 单个交易对的多头和空头名义价值（均为非负数）。
+///
+/// - `PositionMode::Netting`: 多头和空头相互抵消，因此最多只有一边非零
+/// - `PositionMode::Hedging`: 多头和空头独立存在，总敞口为两边之和
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct InstrumentExposure {
+    /// 仓位模式
+    pub mode: PositionMode,
+    /// 多头名义价值
+    pub long: Decimal,
+    /// 空头名义价值
+    pub short: Decimal,
+}
+
+impl InstrumentExposure {
+    /// 构造一个指定 [`PositionMode`] 的空 `InstrumentExposure`。
+    pub fn new(mode: PositionMode) -> Self {
+        Self {
+            mode,
+            long: Decimal::ZERO,
+            short: Decimal::ZERO,
+        }
+    }
+
+    /// 总敞口（多头和空头名义价值之和）。
+    pub fn gross(&self) -> Option<Decimal> {
+        self.long.checked_add(self.short)
+    }
+
+    /// 净敞口（多头为正，空头为负）。
+    pub fn net(&self) -> Option<Decimal> {
+        self.long.checked_sub(self.short)
+    }
+
+    /// 加上一笔 `side` 方向的名义价值。
+    ///
+    /// 对冲模式下，reduce-only 名义价值减少反方向的一边（最多减少到零），否则增加 `side`
+    /// 方向的一边。净仓模式下，多头和空头相互抵消。
+    ///
+    /// 如果计算溢出，返回 `None`。
+    pub fn checked_add(self, side: Side, notional: Decimal, reduce_only: bool) -> Option<Self> {
+        let (mut long, mut short) = (self.long, self.short);
+
+        match (self.mode, side, reduce_only) {
+            (PositionMode::Hedging, Side::Buy, true) => short -= notional.min(short),
+            (PositionMode::Hedging, Side::Sell, true) => long -= notional.min(long),
+            (_, Side::Buy, _) => long = long.checked_add(notional)?,
+            (_, Side::Sell, _) => short = short.checked_add(notional)?,
+        }
+
+        if self.mode == PositionMode::Netting {
+            let offset = long.min(short);
+            long -= offset;
+            short -= offset;
+        }
+
+        Some(Self {
+            long,
+            short,
+            ..self
+        })
+    }
+}
+
+/// [`CheckAggregateExposure`] 失败时返回的错误，包含被突破的限制以及超出的数量。
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize, Constructor, Error,
+)]
+#[error(
+    "CheckAggregateExposure failed: {limit} exposure {exposure} breaches limit {limit_value} by {excess}"
+)]
+pub struct CheckFailAggregateExposure {
+    /// 被突破的限制类型
+    pub limit: ExposureLimit,
+    /// 限制值
+    pub limit_value: Decimal,
+    /// 突破限制的敞口
+    pub exposure: Decimal,
+    /// 超出限制的数量
+    pub excess: Decimal,
+}
+
+impl CheckFailAggregateExposure {
+    /// 如果从 `prev` 到 `next` 的变化增加了被突破限制对应的敞口，返回 `true`。
+    fn is_increased_by(&self, prev: &AggregateExposure, next: &AggregateExposure) -> bool {
+        match self.limit {
+            ExposureLimit::Gross => next.gross > prev.gross,
+            ExposureLimit::Net => next.net.abs() > prev.net.abs(),
+        }
+    }
+}
+
+fn aggregate(exposures: &[InstrumentExposure]) -> Option<AggregateExposure> {
+    exposures
+        .iter()
+        .try_fold(AggregateExposure::default(), |acc, exposure| {
+            Some(AggregateExposure {
+                gross: acc.gross.checked_add(exposure.gross()?)?,
+                net: acc.net.checked_add(exposure.net()?)?,
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Timed,
+        engine::state::{
+            EngineState,
+            global::DefaultGlobalData,
+            instrument::data::DefaultInstrumentMarketData,
+            position::{Position, PositionSettlement},
+        },
+        test_utils::spot_engine_state,
+    };
+    use barter_execution::{
+        order::{
+            OrderKey, OrderKind, TimeInForce,
+            id::{ClientOrderId, StrategyId},
+            request::RequestOpen,
+        },
+        trade::AssetFees,
+    };
+    use barter_instrument::asset::QuoteAsset;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn state() -> EngineState<DefaultGlobalData, DefaultInstrumentMarketData> {
+        let mut state = spot_engine_state(&["btc", "eth", "sol"]);
+
+        // Prices: btc=100, eth=10, sol=1
+        for (index, price) in [(0, dec!(100)), (1, dec!(10)), (2, dec!(1))] {
+            state
+                .instruments
+                .instrument_index_mut(&InstrumentIndex(index))
+                .data
+                .last_traded_price = Some(Timed::new(price, DateTime::<Utc>::MIN_UTC));
+        }
+
+        // Positions: long 10 btc (1000), short 50 eth (-500)
+        for (index, side, quantity) in [(0, Side::Buy, dec!(10)), (1, Side::Sell, dec!(50))] {
+            state
+                .instruments
+                .instrument_index_mut(&InstrumentIndex(index))
+                .position
+                .current = Some(position(index, side, quantity));
+        }
+
+        state
+    }
+
+    fn position(instrument: usize, side: Side, quantity: Decimal) -> Position<QuoteAsset> {
+        Position {
+            instrument: InstrumentIndex(instrument),
+            side,
+            price_entry_average: Decimal::ONE,
+            quantity_abs: quantity,
+            quantity_abs_max: quantity,
+            pnl_unrealised: Decimal::ZERO,
+            pnl_realised: Decimal::ZERO,
+            fees_enter: AssetFees::default(),
+            fees_exit: AssetFees::default(),
+            time_enter: DateTime::<Utc>::MIN_UTC,
+            time_exchange_update: DateTime::<Utc>::MIN_UTC,
+            trades: vec![],
+            settlement: PositionSettlement::default(),
+            contract_size: Decimal::ONE,
+            rounding: None,
+        }
+    }
+
+    fn open(instrument: usize, side: Side, quantity: Decimal) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(instrument),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(format!("{instrument}-{side}-{quantity}")),
+            },
+            state: RequestOpen {
+                side,
+                price: Decimal::ONE,
+                quantity,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_aggregate_exposure_from_instrument_states() {
+        let state = state();
+        assert_eq!(
+            CheckAggregateExposure::exposure(&state.instruments),
+            Some(AggregateExposure::new(dec!(1500), dec!(500)))
+        );
+    }
+
+    #[test]
+    fn test_aggregate_exposure_sums_hedged_legs() {
+        let mut state = state();
+
+        // Hedged btc: long 10 (1000) & short 4 (400) legs, plus short 50 eth (-500)
+        let btc = &mut state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .position;
+        btc.mode = PositionMode::Hedging;
+        btc.current = None;
+        btc.long = Some(position(0, Side::Buy, dec!(10)));
+        btc.short = Some(position(0, Side::Sell, dec!(4)));
+
+        assert_eq!(
+            CheckAggregateExposure::exposure(&state.instruments),
+            Some(AggregateExposure::new(dec!(1900), dec!(100)))
+        );
+
+        let check = CheckAggregateExposure::new(dec!(1900), dec!(1000));
+        let reduce_short = OrderRequestOpen {
+            state: RequestOpen {
+                reduce_only: true,
+                ..open(0, Side::Buy, dec!(2)).state
+            },
+            ..open(0, Side::Buy, dec!(2))
+        };
+
+        // Reduce-only buy reduces the short leg (gross 1700), so the hedged short increase that
+        // follows fits within the gross limit (gross 1800), but a further short increase does not
+        let (approved, refused) = check.check_opens(
+            &state.instruments,
+            [
+                reduce_short.clone(),
+                open(0, Side::Sell, dec!(1)),
+                open(0, Side::Sell, dec!(2)),
+            ],
+        );
+        assert_eq!(
+            approved
+                .into_iter()
+                .map(RiskApproved::into_item)
+                .collect::<Vec<_>>(),
+            vec![reduce_short, open(0, Side::Sell, dec!(1))]
+        );
+        assert_eq!(
+            refused
+                .into_iter()
+                .map(|refused| refused.reason)
+                .collect::<Vec<_>>(),
+            vec![
+                "CheckAggregateExposure failed: gross exposure 2000 breaches limit 1900 by 100"
+                    .to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_check_aggregate_exposure_refuses_on_notional_overflow() {
+        let state = state();
+        let check = CheckAggregateExposure::new(Decimal::MAX, Decimal::MAX);
+
+        let (approved, refused) =
+            check.check_opens(&state.instruments, [open(0, Side::Buy, Decimal::MAX)]);
+        assert!(approved.is_empty());
+        assert_eq!(refused[0].reason, ERROR_OVERFLOW);
+    }
+
+    #[test]
+    fn test_check_aggregate_exposure_check_opens() {
+        struct TestCase {
+            name: &'static str,
+            check: CheckAggregateExposure,
+            opens: Vec<OrderRequestOpen>,
+            expected_approved: Vec<OrderRequestOpen>,
+            expected_refused: Vec<(OrderRequestOpen, &'static str)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                name: "opens within both limits are approved",
+                check: CheckAggregateExposure::new(dec!(2000), dec!(1000)),
+                opens: vec![open(2, Side::Buy, dec!(100))],
+                expected_approved: vec![open(2, Side::Buy, dec!(100))],
+                expected_refused: vec![],
+            },
+            TestCase {
+                name: "gross breach is refused with excess",
+                check: CheckAggregateExposure::new(dec!(1600), dec!(1000)),
+                opens: vec![open(2, Side::Sell, dec!(200))],
+                expected_approved: vec![],
+                expected_refused: vec![(
+                    open(2, Side::Sell, dec!(200)),
+                    "CheckAggregateExposure failed: gross exposure 1700 breaches limit 1600 by 100",
+                )],
+            },
+            TestCase {
+                name: "net breach is refused with excess",
+                check: CheckAggregateExposure::new(dec!(10_000), dec!(600)),
+                opens: vec![open(0, Side::Buy, dec!(2))],
+                expected_approved: vec![],
+                expected_refused: vec![(
+                    open(0, Side::Buy, dec!(2)),
+                    "CheckAggregateExposure failed: net exposure 700 breaches limit 600 by 100",
+                )],
+            },
+            TestCase {
+                name: "approved opens count towards subsequent opens",
+                check: CheckAggregateExposure::new(dec!(1650), dec!(1000)),
+                opens: vec![open(2, Side::Buy, dec!(100)), open(2, Side::Buy, dec!(100))],
+                expected_approved: vec![open(2, Side::Buy, dec!(100))],
+                expected_refused: vec![(
+                    open(2, Side::Buy, dec!(100)),
+                    "CheckAggregateExposure failed: gross exposure 1700 breaches limit 1650 by 50",
+                )],
+            },
+            TestCase {
+                name: "exposure reducing opens are approved even if limits already breached",
+                check: CheckAggregateExposure::new(dec!(1000), dec!(100)),
+                opens: vec![open(0, Side::Sell, dec!(2)), open(1, Side::Sell, dec!(1))],
+                expected_approved: vec![open(0, Side::Sell, dec!(2))],
+                expected_refused: vec![(
+                    open(1, Side::Sell, dec!(1)),
+                    "CheckAggregateExposure failed: gross exposure 1310 breaches limit 1000 by 310",
+                )],
+            },
+        ];
+
+        let state = state();
+
+        for test in tests {
+            let (approved, refused) = test.check.check_opens(&state.instruments, test.opens);
+
+            assert_eq!(
+                approved
+                    .into_iter()
+                    .map(RiskApproved::into_item)
+                    .collect::<Vec<_>>(),
+                test.expected_approved,
+                "TC '{}' failed",
+                test.name
+            );
+            assert_eq!(
+                refused
+                    .into_iter()
+                    .map(|refused| (refused.item, refused.reason))
+                    .collect::<Vec<_>>(),
+                test.expected_refused
+                    .into_iter()
+                    .map(|(open, reason)| (open, reason.to_string()))
+                    .collect::<Vec<_>>(),
+                "TC '{}' failed",
+                test.name
+            );
+        }
+    }
+}
@@ -0,0 +1,469 @@
+use crate::{
+    engine::state::{
+        asset::AssetStates,
+        instrument::{InstrumentStates, data::InstrumentDataState},
+    },
+    risk::{RiskApproved, RiskRefused, check::util::calculate_quote_notional},
+};
+use barter_execution::order::{id::ClientOrderId, request::OrderRequestOpen};
+use barter_instrument::{
+    asset::{AssetIndex, name::AssetNameInternal},
+    exchange::ExchangeIndex,
+    instrument::{Instrument, InstrumentIndex, kind::InstrumentKind},
+};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// 杠杆交易对（永续合约和期货）的开仓初始保证金检查。
+///
+/// 使用交易对的合约乘数和杠杆计算开仓请求所需的初始保证金，并拒绝所需保证金超过结算资产
+/// 可用余额（`free` 余额减去在途开仓请求预留的余额）的开仓请求。
+///
+/// ## 计算方式
+///
+/// - **线性合约**（结算资产为计价资产，例如 USDT 本位）：
+///   `初始保证金 = |数量| * 价格 * 合约乘数 / 杠杆`
+/// - **反向合约**（结算资产为基础资产，例如币本位）：
+///   `初始保证金 = |数量| * 合约乘数 / 价格 / 杠杆`
+///
+/// 现货、期权和 reduce-only 开仓请求不受影响。交易对的杠杆默认为 `leverage`，可以通过
+/// [`Self::with_instrument_leverage`] 为单个交易对覆盖。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let check = CheckInitialMargin::new(dec!(10)).with_instrument_leverage(btc_perp, dec!(20));
+/// let (approved, refused) = check.check_opens(&state.assets, &state.instruments, opens);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct CheckInitialMargin {
+    /// 默认杠杆
+    pub leverage: Decimal,
+    /// 单个交易对的杠杆覆盖
+    pub instrument_leverage: FnvHashMap<InstrumentIndex, Decimal>,
+}
+
+impl CheckInitialMargin {
+    /// 使用提供的默认杠杆构造新的 `CheckInitialMargin`。
+    pub fn new(leverage: Decimal) -> Self {
+        Self {
+            leverage,
+            instrument_leverage: FnvHashMap::default(),
+        }
+    }
+
+    /// 为单个交易对覆盖默认杠杆。
+    pub fn with_instrument_leverage(
+        mut self,
+        instrument: InstrumentIndex,
+        leverage: Decimal,
+    ) -> Self {
+        self.instrument_leverage.insert(instrument, leverage);
+        self
+    }
+
+    /// 返回 RiskManager 检查的名称。
+    pub fn name() -> &'static str {
+        "CheckInitialMargin"
+    }
+
+    /// 返回交易对使用的杠杆。
+    pub fn leverage(&self, instrument: &InstrumentIndex) -> Decimal {
+        self.instrument_leverage
+            .get(instrument)
+            .copied()
+            .unwrap_or(self.leverage)
+    }
+
+    /// 计算开仓请求所需的初始保证金及其结算资产。
+    ///
+    /// 对于现货、期权和 reduce-only 开仓请求，返回 `Ok(None)`。如果计算发生溢出或除零
+    /// （例如价格或杠杆为零），返回 [`CheckFailInitialMarginCalculation`]。
+    ///
+    /// # 参数
+    ///
+    /// - `instrument`: 开仓请求的交易对
+    /// - `leverage`: 交易对使用的杠杆
+    /// - `open`: 要计算的开仓请求
+    pub fn required_margin<InstrumentExchangeKey, ExchangeKey, InstrumentKey>(
+        instrument: &Instrument<InstrumentExchangeKey, AssetIndex>,
+        leverage: Decimal,
+        open: &OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    ) -> Result<Option<(AssetIndex, Decimal)>, CheckFailInitialMarginCalculation>
+    where
+        InstrumentKey: Clone,
+    {
+        if open.state.reduce_only {
+            return Ok(None);
+        }
+
+        let (contract_size, settlement_asset) = match &instrument.kind {
+            InstrumentKind::Perpetual(contract) => {
+                (contract.contract_size, contract.settlement_asset)
+            }
+            InstrumentKind::Future(contract) => (contract.contract_size, contract.settlement_asset),
+            InstrumentKind::Spot | InstrumentKind::Option(_) => return Ok(None),
+        };
+
+        let quantity = open.state.quantity.abs();
+        let notional_settlement = if settlement_asset == instrument.underlying.base {
+            quantity
+                .checked_mul(contract_size)
+                .and_then(|notional| notional.checked_div(open.state.price))
+        } else {
+            calculate_quote_notional(quantity, open.state.price, contract_size)
+        };
+
+        notional_settlement
+            .and_then(|notional| notional.checked_div(leverage))
+            .map(|required| Some((settlement_asset, required)))
+            .ok_or_else(|| CheckFailInitialMarginCalculation {
+                cid: open.key.cid.clone(),
+                price: open.state.price,
+                quantity: open.state.quantity,
+                leverage,
+            })
+    }
+
+    /// 检查所需初始保证金是否不超过可用余额。
+    ///
+    /// # 参数
+    ///
+    /// - `asset`: 保证金结算资产名称
+    /// - `available`: 结算资产的可用余额
+    /// - `required`: 开仓请求所需的初始保证金
+    /// - `open`: 要检查的开仓请求
+    pub fn check<ExchangeKey, InstrumentKey>(
+        &self,
+        asset: &AssetNameInternal,
+        available: Decimal,
+        required: Decimal,
+        open: &OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    ) -> Result<(), CheckFailInitialMargin> {
+        if required <= available {
+            Ok(())
+        } else {
+            Err(CheckFailInitialMargin {
+                cid: open.key.cid.clone(),
+                asset: asset.clone(),
+                required,
+                available,
+            })
+        }
+    }
+
+    /// 检查每个开仓请求，返回已批准和被拒绝的开仓请求。
+    ///
+    /// 同一批次中先前已批准的开仓请求会消耗可用余额，因此多个开仓请求合计所需的初始保证金
+    /// 不会超过可用余额。
+    ///
+    /// # 参数
+    ///
+    /// - `assets`: 资产状态集合
+    /// - `instruments`: 交易对状态集合
+    /// - `opens`: 要检查的开仓请求
+    ///
+    /// # 返回值
+    ///
+    /// 返回 `(已批准的开仓请求, 被拒绝的开仓请求)`。
+    pub fn check_opens<InstrumentData>(
+        &self,
+        assets: &AssetStates,
+        instruments: &InstrumentStates<InstrumentData>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        Vec<RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        Vec<RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    )
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        let mut available = FnvHashMap::<AssetIndex, Decimal>::default();
+
+        opens.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut approved, mut refused), open| {
+                let instrument = &instruments
+                    .instrument_index(&open.key.instrument)
+                    .instrument;
+
+                // 无法计算所需保证金的开仓请求会被拒绝（fail closed）
+                let (asset, required) = match Self::required_margin(
+                    instrument,
+                    self.leverage(&open.key.instrument),
+                    &open,
+                ) {
+                    Ok(Some(required)) => required,
+                    Ok(None) => {
+                        approved.push(RiskApproved::new(open));
+                        return (approved, refused);
+                    }
+                    Err(error) => {
+                        refused.push(RiskRefused::new(open, error.to_string()));
+                        return (approved, refused);
+                    }
+                };
+
+                let asset_state = assets.asset_index(&asset);
+                let remaining = available
+                    .entry(asset)
+                    .or_insert_with(|| asset_state.balance_available().unwrap_or(Decimal::ZERO));
+
+                match self.check(
+                    &asset_state.asset.name_internal,
+                    *remaining,
+                    required,
+                    &open,
+                ) {
+                    Ok(()) => {
+                        *remaining -= required;
+                        approved.push(RiskApproved::new(open));
+                    }
+                    Err(error) => {
+                        refused.push(RiskRefused::new(open, error.to_string()));
+                    }
+                }
+
+                (approved, refused)
+            },
+        )
+    }
+}
+
+/// [`CheckInitialMargin`] 失败时返回的错误。
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize, Constructor, Error,
+)]
+#[error(
+    "CheckInitialMargin failed: order {cid} requires {required} {asset} initial margin but only {available} is available"
+)]
+pub struct CheckFailInitialMargin {
+    /// 被检查的开仓请求的 ClientOrderId
+    pub cid: ClientOrderId,
+    /// 保证金结算资产
+    pub asset: AssetNameInternal,
+    /// 开仓请求所需的初始保证金
+    pub required: Decimal,
+    /// 结算资产的可用余额
+    pub available: Decimal,
+}
+
+/// [`CheckInitialMargin`] 无法计算开仓请求所需的初始保证金（溢出或除零）时返回的错误。
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize, Constructor, Error,
+)]
+#[error(
+    "CheckInitialMargin failed: order {cid} initial margin could not be calculated for quantity {quantity} @ {price} with leverage {leverage}"
+)]
+pub struct CheckFailInitialMarginCalculation {
+    /// 被检查的开仓请求的 ClientOrderId
+    pub cid: ClientOrderId,
+    /// 开仓请求的价格
+    pub price: Decimal,
+    /// 开仓请求的数量
+    pub quantity: Decimal,
+    /// 交易对使用的杠杆
+    pub leverage: Decimal,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{
+            EngineState, global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+        },
+        test_utils::engine_state,
+    };
+    use barter_execution::{
+        balance::{AssetBalance, Balance},
+        order::{
+            OrderKey, OrderKind, TimeInForce,
+            id::{ClientOrderId, StrategyId},
+            request::RequestOpen,
+        },
+    };
+    use barter_instrument::{
+        Side,
+        asset::Asset,
+        exchange::ExchangeId,
+        index::IndexedInstruments,
+        instrument::kind::perpetual::PerpetualContract,
+        test_utils::{asset, instrument},
+    };
+    use barter_integration::snapshot::Snapshot;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    type State = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn perpetual(contract_size: Decimal) -> Instrument<ExchangeId, Asset> {
+        Instrument {
+            kind: InstrumentKind::Perpetual(PerpetualContract {
+                contract_size,
+                settlement_asset: asset("usdt"),
+            }),
+            ..instrument(ExchangeId::BinanceFuturesUsd, "btc", "usdt")
+        }
+    }
+
+    fn state(contract_size: Decimal) -> (State, AssetIndex) {
+        let instruments = IndexedInstruments::new([perpetual(contract_size)]);
+        let usdt = instruments
+            .find_asset_index(
+                ExchangeId::BinanceFuturesUsd,
+                &AssetNameInternal::from("usdt"),
+            )
+            .unwrap();
+
+        let mut state = engine_state(&instruments);
+
+        state
+            .assets
+            .asset_index_mut(&usdt)
+            .update_from_balance(Snapshot(&AssetBalance::new(
+                usdt,
+                Balance::new(dec!(100), dec!(100)),
+                DateTime::<Utc>::MIN_UTC,
+            )));
+
+        (state, usdt)
+    }
+
+    fn open(cid: &str, side: Side, price: Decimal, quantity: Decimal) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side,
+                price,
+                quantity,
+                kind: OrderKind::Limit,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: false,
+            },
+        }
+    }
+
+    fn check(
+        check: &CheckInitialMargin,
+        state: &State,
+        opens: Vec<OrderRequestOpen>,
+    ) -> (Vec<String>, Vec<String>) {
+        let (approved, refused) = check.check_opens(&state.assets, &state.instruments, opens);
+
+        (
+            approved
+                .into_iter()
+                .map(|approved| approved.into_item().key.cid.to_string())
+                .collect(),
+            refused.into_iter().map(|refused| refused.reason).collect(),
+        )
+    }
+
+    #[test]
+    fn test_check_initial_margin_boundary_long_and_short() {
+        let (state, _) = state(dec!(1));
+        let margin = CheckInitialMargin::new(dec!(10));
+
+        for side in [Side::Buy, Side::Sell] {
+            // 1 contract @ 1000 with 10x leverage requires exactly 100 usdt
+            assert_eq!(
+                check(
+                    &margin,
+                    &state,
+                    vec![open("exact", side, dec!(1000), dec!(1))]
+                ),
+                (vec!["exact".to_string()], vec![])
+            );
+
+            // 1 contract @ 1000.1 with 10x leverage requires 100.01 usdt
+            assert_eq!(
+                check(
+                    &margin,
+                    &state,
+                    vec![open("short", side, dec!(1000.1), dec!(1))]
+                ),
+                (
+                    vec![],
+                    vec![
+                        "CheckInitialMargin failed: order short requires 100.010 usdt initial margin but only 100 is available"
+                            .to_string()
+                    ]
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_check_initial_margin_contract_size_leverage_and_batch() {
+        let (state, usdt) = state(dec!(0.01));
+
+        // 100 contracts of 0.01 @ 1000 = 1000 usdt notional, requiring 100 usdt at 10x
+        let margin =
+            CheckInitialMargin::new(dec!(5)).with_instrument_leverage(InstrumentIndex(0), dec!(10));
+        assert_eq!(margin.leverage(&InstrumentIndex(0)), dec!(10));
+        assert_eq!(
+            CheckInitialMargin::required_margin(
+                &state
+                    .instruments
+                    .instrument_index(&InstrumentIndex(0))
+                    .instrument,
+                dec!(10),
+                &open("size", Side::Sell, dec!(1000), dec!(-100)),
+            ),
+            Ok(Some((usdt, dec!(100))))
+        );
+
+        // Previously approved opens in the same batch consume available margin
+        let (approved, refused) = check(
+            &margin,
+            &state,
+            vec![
+                open("first", Side::Buy, dec!(1000), dec!(60)),
+                open("second", Side::Buy, dec!(1000), dec!(40)),
+                open("third", Side::Sell, dec!(1000), dec!(1)),
+            ],
+        );
+        assert_eq!(approved, vec!["first".to_string(), "second".to_string()]);
+        assert_eq!(refused.len(), 1);
+
+        // Opens with an uncalculable margin (overflow, or zero leverage) are refused
+        let zero_leverage = CheckInitialMargin::new(Decimal::ZERO);
+        for (margin, open) in [
+            (
+                &margin,
+                open("overflow", Side::Buy, Decimal::MAX, Decimal::MAX),
+            ),
+            (
+                &zero_leverage,
+                open("zero-leverage", Side::Buy, dec!(1000), dec!(1)),
+            ),
+        ] {
+            let (approved, refused) = check(margin, &state, vec![open]);
+            assert!(approved.is_empty());
+            assert_eq!(refused.len(), 1);
+            assert!(refused[0].contains("initial margin could not be calculated"));
+        }
+
+        // Reduce-only opens do not require margin
+        let reduce_only = OrderRequestOpen {
+            state: RequestOpen {
+                reduce_only: true,
+                ..open("reduce", Side::Sell, dec!(1000), dec!(1000)).state
+            },
+            ..open("reduce", Side::Sell, dec!(1000), dec!(1000))
+        };
+        assert_eq!(
+            check(&margin, &state, vec![reduce_only]),
+            (vec!["reduce".to_string()], vec![])
+        );
+    }
+}
@@ -6,6 +6,13 @@
 //!
 //! - **RiskCheck**: Trait，定义风险检查接口
 //! - **CheckHigherThan**: 检查值是否超过上限的简单实现
+//! - **CheckAggregateExposure**: 账户级别总敞口和净敞口限制检查
+//! - **CheckSelfTrade**: 自成交预防检查，拒绝会与自身挂单成交的开仓请求
+//! - **CheckReduceOnly**: Reduce-only 检查，确保 reduce-only 开仓请求只减少已有仓位
+//! - **CheckBalanceAvailable**: 可用余额检查，拒绝超过 `free` 减去在途预留余额的开仓请求
+//! - **CheckInitialMargin**: 初始保证金检查，拒绝所需初始保证金超过可用余额的杠杆开仓请求
+//! - **CheckOrderRate**: 开仓速率检查，限制每个交易对在滚动时间窗口内的开仓请求数
+//! - **CheckPriceSanity**: 价格合理性检查，拒绝偏离最优买卖价超过允许基点范围的限价开仓请求
 //! - **工具函数**: 计算名义价值、价格差异等
 
 use derive_more::Constructor;
@@ -17,6 +24,53 @@ use thiserror::Error;
 /// 例如，计算名义价值、价格差异等。
 pub mod util;
 
+/// 账户级别的聚合敞口检查。
+///
+/// 例如，[`CheckAggregateExposure`] 限制所有交易对的总敞口和净敞口。
+pub mod exposure;
+
+/// 自成交预防检查。
+///
+/// 例如，[`CheckSelfTrade`] 拒绝会穿过同一交易对上自身反方向挂单的开仓请求。
+pub mod self_trade;
+
+/// Reduce-only 检查。
+///
+/// 例如，[`CheckReduceOnly`] 拒绝没有仓位可减少的 reduce-only 开仓请求，并将超出仓位数量的
+/// reduce-only 开仓请求截断为仓位数量。
+pub mod reduce_only;
+
+/// 可用余额检查。
+///
+/// 例如，[`CheckBalanceAvailable`] 拒绝所需资金超过 `free` 余额减去在途开仓请求预留余额的
+/// 开仓请求。
+pub mod balance;
+
+/// 初始保证金检查。
+///
+/// 例如，[`CheckInitialMargin`] 根据杠杆和合约乘数计算永续合约和期货开仓请求所需的初始
+/// 保证金，并拒绝超过结算资产可用余额的开仓请求。
+pub mod margin;
+
+/// 开仓速率检查。
+///
+/// 例如，[`CheckOrderRate`] 拒绝在滚动时间窗口内超过每个交易对开仓请求数限制的开仓请求，
+/// 但从不限制取消请求。
+pub mod rate;
+
+/// 价格合理性（防乌龙指）检查。
+///
+/// 例如，[`CheckPriceSanity`] 拒绝价格偏离交易对最优买卖价超过可配置基点范围的限价开仓请求。
+pub mod price_sanity;
+
+pub use balance::CheckBalanceAvailable;
+pub use exposure::CheckAggregateExposure;
+pub use margin::CheckInitialMargin;
+pub use price_sanity::CheckPriceSanity;
+pub use rate::CheckOrderRate;
+pub use reduce_only::CheckReduceOnly;
+pub use self_trade::CheckSelfTrade;
+
 /// 实现简单 RiskManager 检查的通用接口。
 ///
 /// RiskCheck 定义了风险检查的标准接口，可以用于实现各种类型的风险检查。
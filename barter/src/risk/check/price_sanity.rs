@@ -0,0 +1,329 @@
+use crate::{
+    engine::state::instrument::{
+        InstrumentStates,
+        data::{InstrumentBboState, InstrumentDataState},
+    },
+    risk::{RiskApproved, RiskRefused},
+};
+use barter_data::subscription::book::Bbo;
+use barter_execution::order::{
+    OrderKind,
+    id::ClientOrderId,
+    request::{OrderRequestOpen, RequestOpen},
+};
+use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// 价格合理性（防乌龙指）风险检查。
+///
+/// 策略缺陷或输入错误可能生成价格离谱的限价开仓请求。`CheckPriceSanity` 将每个限价开仓
+/// 请求的价格与交易对数据维护的最优买卖价（[`InstrumentBboState::bbo`]）比较，拒绝偏离
+/// 参考价格超过 `band_bps` 个基点的开仓请求。
+///
+/// ## 参考价格
+///
+/// - 买入开仓：最优卖价（best ask）
+/// - 卖出开仓：最优买价（best bid）
+///
+/// 偏离按绝对值计算，即 `|price - reference| / reference * 10_000`。市价开仓请求不受限制。
+///
+/// 如果没有可用的订单簿（任意一侧为空），根据 `allow_no_book` 批准或拒绝开仓请求。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// // 拒绝偏离最优买卖价超过 5% 的限价开仓请求，没有订单簿时拒绝
+/// let check = CheckPriceSanity::new(dec!(500), false);
+/// let (approved, refused) = check.check_opens(&state.instruments, opens);
+/// ```
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
+)]
+pub struct CheckPriceSanity {
+    /// 允许限价开仓请求偏离参考价格的最大基点数（例如 `dec!(500)` 表示 5%）
+    pub band_bps: Decimal,
+    /// 没有可用的订单簿时是否批准限价开仓请求
+    pub allow_no_book: bool,
+}
+
+impl CheckPriceSanity {
+    /// 返回 RiskManager 检查的名称。
+    pub fn name() -> &'static str {
+        "CheckPriceSanity"
+    }
+
+    /// 检查提供的开仓请求价格是否在 [`Bbo`] 参考价格的允许范围内。
+    ///
+    /// # 参数
+    ///
+    /// - `bbo`: 开仓请求所属交易对的最优买卖价（如果可用）
+    /// - `cid`: 开仓请求的 [`ClientOrderId`]，用于错误信息
+    /// - `open`: 要检查的开仓请求
+    pub fn check(
+        &self,
+        bbo: Option<&Bbo>,
+        cid: &ClientOrderId,
+        open: &RequestOpen,
+    ) -> Result<(), CheckFailPriceSanity> {
+        if open.kind != OrderKind::Limit {
+            return Ok(());
+        }
+
+        let Some(bbo) = bbo else {
+            return if self.allow_no_book {
+                Ok(())
+            } else {
+                Err(CheckFailPriceSanity::NoBook {
+                    cid: cid.clone(),
+                    side: open.side,
+                    price: open.price,
+                })
+            };
+        };
+
+        let reference = match open.side {
+            Side::Buy => bbo.ask,
+            Side::Sell => bbo.bid,
+        };
+
+        if reference <= Decimal::ZERO {
+            return Ok(());
+        }
+
+        let deviation_bps = (open.price - reference).abs() / reference * Decimal::from(10_000);
+
+        if deviation_bps > self.band_bps {
+            Err(CheckFailPriceSanity::OutOfBand {
+                cid: cid.clone(),
+                side: open.side,
+                price: open.price,
+                reference,
+                band_bps: self.band_bps,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// 检查每个开仓请求的价格是否在参考价格的允许范围内，返回已批准和被拒绝的开仓请求。
+    ///
+    /// # 参数
+    ///
+    /// - `instruments`: 交易对状态集合，从其交易对数据读取最优买卖价
+    /// - `opens`: 要检查的开仓请求
+    ///
+    /// # 返回值
+    ///
+    /// 返回 `(已批准的开仓请求, 被拒绝的开仓请求)`，拒绝原因包含参考价格和允许的基点范围。
+    pub fn check_opens<InstrumentData>(
+        &self,
+        instruments: &InstrumentStates<InstrumentData>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        Vec<RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        Vec<RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    )
+    where
+        InstrumentData: InstrumentDataState + InstrumentBboState,
+    {
+        opens.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut approved, mut refused), open| {
+                let bbo = instruments
+                    .instrument_index(&open.key.instrument)
+                    .data
+                    .bbo();
+
+                match self.check(bbo.as_ref(), &open.key.cid, &open.state) {
+                    Ok(()) => approved.push(RiskApproved::new(open)),
+                    Err(error) => refused.push(RiskRefused::new(open, error.to_string())),
+                }
+
+                (approved, refused)
+            },
+        )
+    }
+}
+
+/// [`CheckPriceSanity`] 失败时返回的错误。
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize, Error)]
+pub enum CheckFailPriceSanity {
+    /// 限价开仓请求价格超出参考价格的允许范围。
+    #[error(
+        "CheckPriceSanity failed: {side} order {cid} @ {price} is outside the {band_bps} bps band around reference price {reference}"
+    )]
+    OutOfBand {
+        /// 被检查的开仓请求的 ClientOrderId
+        cid: ClientOrderId,
+        /// 被检查的开仓请求的方向
+        side: Side,
+        /// 被检查的开仓请求的价格
+        price: Decimal,
+        /// 参考价格（买入为最优卖价，卖出为最优买价）
+        reference: Decimal,
+        /// 允许偏离参考价格的最大基点数
+        band_bps: Decimal,
+    },
+
+    /// 没有可用的订单簿，且配置为拒绝开仓请求。
+    #[error(
+        "CheckPriceSanity failed: no order book available to check {side} order {cid} @ {price}"
+    )]
+    NoBook {
+        /// 被检查的开仓请求的 ClientOrderId
+        cid: ClientOrderId,
+        /// 被检查的开仓请求的方向
+        side: Side,
+        /// 被检查的开仓请求的价格
+        price: Decimal,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{
+            EngineState, global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+        },
+        test_utils::spot_engine_state,
+    };
+    use barter_data::{books::Level, subscription::book::OrderBookL1};
+    use barter_execution::order::{OrderKey, TimeInForce, id::StrategyId};
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn state() -> EngineState<DefaultGlobalData, DefaultInstrumentMarketData> {
+        let mut state = spot_engine_state(&["btc", "eth"]);
+
+        // btc book: bid @ 99, ask @ 100 (eth has no book)
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .data
+            .l1 = OrderBookL1::new(
+            DateTime::<Utc>::MIN_UTC,
+            Some(Level::new(dec!(99), dec!(1))),
+            Some(Level::new(dec!(100), dec!(1))),
+        );
+
+        state
+    }
+
+    fn open(cid: &str, instrument: usize, side: Side, price: Decimal) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(instrument),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side,
+                price,
+                quantity: dec!(1),
+                kind: OrderKind::Limit,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_check_price_sanity_check_opens() {
+        struct TestCase {
+            name: &'static str,
+            check: CheckPriceSanity,
+            opens: Vec<OrderRequestOpen>,
+            expected_approved: Vec<OrderRequestOpen>,
+            expected_refused: Vec<(OrderRequestOpen, &'static str)>,
+        }
+
+        // 5% band
+        let check = CheckPriceSanity::new(dec!(500), false);
+
+        let tests = vec![
+            TestCase {
+                name: "in-band buy & sell are approved",
+                check,
+                opens: vec![
+                    open("buy", 0, Side::Buy, dec!(104)),
+                    open("sell", 0, Side::Sell, dec!(95)),
+                ],
+                expected_approved: vec![
+                    open("buy", 0, Side::Buy, dec!(104)),
+                    open("sell", 0, Side::Sell, dec!(95)),
+                ],
+                expected_refused: vec![],
+            },
+            TestCase {
+                name: "out-of-band buy is refused with reference price & band",
+                check,
+                opens: vec![open("buy", 0, Side::Buy, dec!(1000))],
+                expected_approved: vec![],
+                expected_refused: vec![(
+                    open("buy", 0, Side::Buy, dec!(1000)),
+                    "CheckPriceSanity failed: buy order buy @ 1000 is outside the 500 bps band around reference price 100",
+                )],
+            },
+            TestCase {
+                name: "out-of-band sell is refused against the best bid",
+                check,
+                opens: vec![open("sell", 0, Side::Sell, dec!(90))],
+                expected_approved: vec![],
+                expected_refused: vec![(
+                    open("sell", 0, Side::Sell, dec!(90)),
+                    "CheckPriceSanity failed: sell order sell @ 90 is outside the 500 bps band around reference price 99",
+                )],
+            },
+            TestCase {
+                name: "open without a book is refused when configured to block",
+                check,
+                opens: vec![open("buy", 1, Side::Buy, dec!(10))],
+                expected_approved: vec![],
+                expected_refused: vec![(
+                    open("buy", 1, Side::Buy, dec!(10)),
+                    "CheckPriceSanity failed: no order book available to check buy order buy @ 10",
+                )],
+            },
+            TestCase {
+                name: "open without a book is approved when configured to allow",
+                check: CheckPriceSanity::new(dec!(500), true),
+                opens: vec![open("buy", 1, Side::Buy, dec!(10))],
+                expected_approved: vec![open("buy", 1, Side::Buy, dec!(10))],
+                expected_refused: vec![],
+            },
+        ];
+
+        let state = state();
+
+        for test in tests {
+            let (approved, refused) = test.check.check_opens(&state.instruments, test.opens);
+
+            assert_eq!(
+                approved
+                    .into_iter()
+                    .map(RiskApproved::into_item)
+                    .collect::<Vec<_>>(),
+                test.expected_approved,
+                "TC '{}' failed",
+                test.name
+            );
+            assert_eq!(
+                refused
+                    .into_iter()
+                    .map(|refused| (refused.item, refused.reason))
+                    .collect::<Vec<_>>(),
+                test.expected_refused
+                    .into_iter()
+                    .map(|(open, reason)| (open, reason.to_string()))
+                    .collect::<Vec<_>>(),
+                "TC '{}' failed",
+                test.name
+            );
+        }
+    }
+}
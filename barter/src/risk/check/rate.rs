@@ -0,0 +1,310 @@
+use crate::risk::{RiskApproved, RiskRefused};
+use barter_execution::order::{id::ClientOrderId, request::OrderRequestOpen};
+use barter_instrument::instrument::InstrumentIndex;
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use parking_lot::Mutex;
+use std::{collections::VecDeque, hash::Hash};
+use thiserror::Error;
+
+/// 按交易对限制开仓请求速率的风险检查。
+///
+/// 为每个交易对维护一个最近已记录的开仓请求时间戳环形队列，在滚动时间窗口 `window` 内
+/// 已记录 `limit` 个开仓请求后，拒绝该交易对的后续开仓请求，直到最早的时间戳滚出窗口。
+/// 检查本身不会记录开仓请求，只有通过所有风险检查后调用 [`record`](Self::record) 的开仓请求
+/// 才会计入速率。用于避免触发交易所 API 限速封禁，以及防止策略逻辑错误导致的失控下单循环。
+///
+/// 取消请求从不受此检查限制。
+///
+/// 时间戳队列使用内部可变性存储，因此可以在 [`RiskManager::check`](crate::risk::RiskManager::check)
+/// 的 `&self` 上下文中使用。时间应由调用方提供（例如 `EngineClock::time`），以便在回测中
+/// 使用历史时间。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// // 每个交易对每秒最多 5 个开仓请求
+/// let check = CheckOrderRate::new(5, TimeDelta::seconds(1));
+/// let (approved, refused) = check.check_opens(clock.time(), opens);
+///
+/// // ... 其余风险检查 ...
+///
+/// // 仅记录通过所有风险检查的开仓请求
+/// for open in &approved {
+///     check.record(clock.time(), &open.0);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CheckOrderRate<InstrumentKey = InstrumentIndex> {
+    /// 滚动时间窗口内每个交易对允许的最大开仓请求数。
+    pub limit: usize,
+    /// 滚动时间窗口的长度。
+    pub window: TimeDelta,
+    /// 每个交易对在窗口内已记录的开仓请求时间戳（按时间升序）。
+    history: Mutex<FnvHashMap<InstrumentKey, VecDeque<DateTime<Utc>>>>,
+}
+
+impl<InstrumentKey> CheckOrderRate<InstrumentKey> {
+    /// 使用提供的速率限制构造新的 [`CheckOrderRate`]。
+    ///
+    /// # 参数
+    ///
+    /// - `limit`: 滚动时间窗口内每个交易对允许的最大开仓请求数
+    /// - `window`: 滚动时间窗口的长度
+    pub fn new(limit: usize, window: TimeDelta) -> Self {
+        Self {
+            limit,
+            window,
+            history: Mutex::new(FnvHashMap::default()),
+        }
+    }
+
+    /// 返回 RiskManager 检查的名称。
+    pub fn name() -> &'static str {
+        "CheckOrderRate"
+    }
+}
+
+impl<InstrumentKey> CheckOrderRate<InstrumentKey>
+where
+    InstrumentKey: Eq + Hash + Clone,
+{
+    /// 检查交易对在 `time` 是否仍可发送开仓请求。
+    ///
+    /// 此方法不会记录开仓请求，调用方应在开仓请求通过所有风险检查后调用
+    /// [`record`](Self::record)，以免被其他检查拒绝的开仓请求占用速率额度。
+    ///
+    /// # 参数
+    ///
+    /// - `time`: 当前时间
+    /// - `open`: 要检查的开仓请求
+    pub fn check<ExchangeKey>(
+        &self,
+        time: DateTime<Utc>,
+        open: &OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    ) -> Result<(), CheckFailOrderRate> {
+        self.check_with_pending(time, open, 0)
+    }
+
+    /// 记录在 `time` 发送的开仓请求，使其计入该交易对的速率。
+    ///
+    /// 应仅对通过所有风险检查的开仓请求调用。
+    pub fn record<ExchangeKey>(
+        &self,
+        time: DateTime<Utc>,
+        open: &OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    ) {
+        self.history
+            .lock()
+            .entry(open.key.instrument.clone())
+            .or_default()
+            .push_back(time);
+    }
+
+    /// 检查每个开仓请求，返回已批准和被拒绝的开仓请求。
+    ///
+    /// 同一批次中先前已批准的开仓请求会计入速率，因此单个批次也不会超过限制。
+    /// 与 [`check`](Self::check) 相同，此方法不会记录已批准的开仓请求，调用方应在所有风险
+    /// 检查完成后对最终批准的开仓请求调用 [`record`](Self::record)。
+    ///
+    /// # 参数
+    ///
+    /// - `time`: 当前时间
+    /// - `opens`: 要检查的开仓请求
+    ///
+    /// # 返回值
+    ///
+    /// 返回 `(已批准的开仓请求, 被拒绝的开仓请求)`。
+    pub fn check_opens<ExchangeKey>(
+        &self,
+        time: DateTime<Utc>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeKey, InstrumentKey>>,
+    ) -> (
+        Vec<RiskApproved<OrderRequestOpen<ExchangeKey, InstrumentKey>>>,
+        Vec<RiskRefused<OrderRequestOpen<ExchangeKey, InstrumentKey>>>,
+    ) {
+        let mut pending = FnvHashMap::<InstrumentKey, usize>::default();
+
+        opens.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut approved, mut refused), open| {
+                let instrument_pending = pending.entry(open.key.instrument.clone()).or_default();
+                match self.check_with_pending(time, &open, *instrument_pending) {
+                    Ok(()) => {
+                        *instrument_pending += 1;
+                        approved.push(RiskApproved::new(open))
+                    }
+                    Err(error) => refused.push(RiskRefused::new(open, error.to_string())),
+                }
+                (approved, refused)
+            },
+        )
+    }
+
+    /// 检查开仓请求，将 `pending` 个尚未记录的开仓请求计入速率。
+    fn check_with_pending<ExchangeKey>(
+        &self,
+        time: DateTime<Utc>,
+        open: &OrderRequestOpen<ExchangeKey, InstrumentKey>,
+        pending: usize,
+    ) -> Result<(), CheckFailOrderRate> {
+        let rate = self.rate(time, &open.key.instrument) + pending;
+
+        if rate < self.limit {
+            Ok(())
+        } else {
+            Err(CheckFailOrderRate {
+                cid: open.key.cid.clone(),
+                rate: rate + 1,
+                limit: self.limit,
+                window: self.window,
+            })
+        }
+    }
+
+    /// 返回交易对在 `time` 的滚动时间窗口内已记录的开仓请求数。
+    fn rate(&self, time: DateTime<Utc>, instrument: &InstrumentKey) -> usize {
+        let mut history = self.history.lock();
+        let Some(times) = history.get_mut(instrument) else {
+            return 0;
+        };
+
+        // 移除已滚出窗口的时间戳
+        while times
+            .front()
+            .is_some_and(|oldest| time.signed_duration_since(*oldest) >= self.window)
+        {
+            times.pop_front();
+        }
+
+        times.len()
+    }
+}
+
+/// [`CheckOrderRate`] 失败时返回的错误，包含当前速率和限制。
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Constructor, Error)]
+#[error(
+    "CheckOrderRate failed: order {cid} would be open #{rate} within {window} but limit is {limit}"
+)]
+pub struct CheckFailOrderRate {
+    /// 被检查的开仓请求的 ClientOrderId
+    pub cid: ClientOrderId,
+    /// 包含此开仓请求在内，窗口内的开仓请求数
+    pub rate: usize,
+    /// 窗口内允许的最大开仓请求数
+    pub limit: usize,
+    /// 滚动时间窗口的长度
+    pub window: TimeDelta,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::order::{
+        OrderKey, OrderKind, TimeInForce, id::StrategyId, request::RequestOpen,
+    };
+    use barter_instrument::{Side, exchange::ExchangeIndex};
+    use rust_decimal_macros::dec;
+
+    fn open(instrument: usize, cid: &str) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(instrument),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                price: dec!(100),
+                quantity: dec!(1),
+                kind: OrderKind::Limit,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: false,
+            },
+        }
+    }
+
+    fn cids(
+        (approved, refused): (
+            Vec<RiskApproved<OrderRequestOpen>>,
+            Vec<RiskRefused<OrderRequestOpen>>,
+        ),
+    ) -> (Vec<String>, Vec<String>) {
+        (
+            approved
+                .into_iter()
+                .map(|approved| approved.into_item().key.cid.to_string())
+                .collect(),
+            refused
+                .into_iter()
+                .map(|refused| refused.into_item().key.cid.to_string())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_check_order_rate_refuses_excess_opens_until_window_rolls() {
+        let check = CheckOrderRate::new(2, TimeDelta::seconds(1));
+        let time = DateTime::<Utc>::MIN_UTC;
+
+        // Rapid opens on instrument 0 are capped at the limit, instrument 1 is independent
+        let (approved, refused) = check.check_opens(
+            time,
+            [open(0, "a"), open(0, "b"), open(0, "c"), open(1, "d")],
+        );
+        for approved in &approved {
+            check.record(time, &approved.0);
+        }
+        assert_eq!(
+            cids((approved, refused)),
+            (
+                vec!["a".to_string(), "b".to_string(), "d".to_string()],
+                vec!["c".to_string()]
+            )
+        );
+
+        // Still within the window
+        let time_within = time + TimeDelta::milliseconds(999);
+        let (_, refused) = check.check_opens(time_within, [open(0, "e")]);
+        assert_eq!(
+            refused[0].reason,
+            "CheckOrderRate failed: order e would be open #3 within PT1S but limit is 2"
+        );
+
+        // Window rolled, so opens are allowed again
+        let time_rolled = time + TimeDelta::seconds(1);
+        assert_eq!(
+            cids(check.check_opens(time_rolled, [open(0, "f"), open(0, "g"), open(0, "h")])),
+            (
+                vec!["f".to_string(), "g".to_string()],
+                vec!["h".to_string()]
+            )
+        );
+    }
+
+    #[test]
+    fn test_check_order_rate_only_counts_recorded_opens() {
+        let check = CheckOrderRate::new(1, TimeDelta::seconds(1));
+        let time = DateTime::<Utc>::MIN_UTC;
+
+        // Opens approved by this check but refused by a later check are never recorded, so
+        // they do not consume the rate limit
+        for cid in ["a", "b", "c"] {
+            assert!(check.check(time, &open(0, cid)).is_ok());
+        }
+
+        // Once an open passes every check and is recorded, subsequent opens are refused
+        check.record(time, &open(0, "d"));
+        assert_eq!(
+            check.check(time, &open(0, "e")),
+            Err(CheckFailOrderRate::new(
+                ClientOrderId::new("e"),
+                2,
+                1,
+                TimeDelta::seconds(1)
+            ))
+        );
+    }
+}
@@ -0,0 +1,326 @@
+use crate::{
+    engine::state::{
+        instrument::{InstrumentStates, data::InstrumentDataState},
+        position::PositionManager,
+    },
+    risk::{RiskApproved, RiskRefused},
+};
+use barter_execution::order::{id::ClientOrderId, request::OrderRequestOpen};
+use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Reduce-only 风险检查。
+///
+/// 设置了 `reduce_only` 的开仓请求只能减少（或平掉）已有仓位，永远不能开立新仓位或翻仓。
+/// `CheckReduceOnly` 将每个 reduce-only 开仓请求与交易对的当前仓位进行比较：
+///
+/// - 不存在反方向仓位可减少时，拒绝开仓请求
+/// - 开仓数量大于可减少的仓位数量时，将开仓数量截断为可减少的仓位数量
+///
+/// 非 reduce-only 的开仓请求不受影响。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let (approved, refused) = CheckReduceOnly.check_opens(&state.instruments, opens);
+/// ```
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub struct CheckReduceOnly;
+
+impl CheckReduceOnly {
+    /// 返回 RiskManager 检查的名称。
+    pub fn name() -> &'static str {
+        "CheckReduceOnly"
+    }
+
+    /// 返回 `side` 方向的 reduce-only 开仓请求可减少的仓位数量。
+    ///
+    /// 买入减少空头仓位，卖出减少多头仓位。同时适用于 `Netting` 和 `Hedging` 仓位模式。
+    pub fn reducible_quantity<InstrumentKey>(
+        position: &PositionManager<InstrumentKey>,
+        side: Side,
+    ) -> Decimal {
+        position
+            .positions()
+            .filter(|position| position.side != side)
+            .map(|position| position.quantity_abs)
+            .sum()
+    }
+
+    /// 检查提供的 reduce-only 开仓请求，返回允许的开仓数量。
+    ///
+    /// # 参数
+    ///
+    /// - `reducible`: 可减少的仓位数量
+    /// - `open`: 要检查的开仓请求
+    ///
+    /// # 返回值
+    ///
+    /// - `Ok(Decimal)`: 允许的开仓数量（非 reduce-only 开仓请求为原始数量）
+    /// - `Err(CheckFailReduceOnly)`: 不存在可减少的仓位
+    pub fn check<ExchangeKey, InstrumentKey>(
+        &self,
+        reducible: Decimal,
+        open: &OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    ) -> Result<Decimal, CheckFailReduceOnly> {
+        if !open.state.reduce_only {
+            return Ok(open.state.quantity);
+        }
+
+        if reducible <= Decimal::ZERO {
+            return Err(CheckFailReduceOnly {
+                cid: open.key.cid.clone(),
+                side: open.state.side,
+            });
+        }
+
+        Ok(open.state.quantity.abs().min(reducible))
+    }
+
+    /// 检查每个 reduce-only 开仓请求，返回已批准（数量可能被截断）和被拒绝的开仓请求。
+    ///
+    /// 同一批次中先前已批准的 reduce-only 开仓请求会消耗可减少的仓位数量，因此多个
+    /// reduce-only 开仓请求合计不会超过仓位数量。
+    ///
+    /// # 参数
+    ///
+    /// - `instruments`: 交易对状态集合
+    /// - `opens`: 要检查的开仓请求
+    ///
+    /// # 返回值
+    ///
+    /// 返回 `(已批准的开仓请求, 被拒绝的开仓请求)`。
+    pub fn check_opens<InstrumentData>(
+        &self,
+        instruments: &InstrumentStates<InstrumentData>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        Vec<RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        Vec<RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    )
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        let mut reducible = FnvHashMap::<(InstrumentIndex, Side), Decimal>::default();
+
+        opens.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut approved, mut refused), mut open| {
+                if !open.state.reduce_only {
+                    approved.push(RiskApproved::new(open));
+                    return (approved, refused);
+                }
+
+                let remaining = reducible
+                    .entry((open.key.instrument, open.state.side))
+                    .or_insert_with(|| {
+                        Self::reducible_quantity(
+                            &instruments.instrument_index(&open.key.instrument).position,
+                            open.state.side,
+                        )
+                    });
+
+                match self.check(*remaining, &open) {
+                    Ok(quantity) => {
+                        *remaining -= quantity;
+                        open.state.quantity = quantity;
+                        approved.push(RiskApproved::new(open));
+                    }
+                    Err(error) => {
+                        refused.push(RiskRefused::new(open, error.to_string()));
+                    }
+                }
+
+                (approved, refused)
+            },
+        )
+    }
+}
+
+/// [`CheckReduceOnly`] 失败时返回的错误。
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize, Constructor, Error,
+)]
+#[error("CheckReduceOnly failed: reduce-only {side} order {cid} has no position to reduce")]
+pub struct CheckFailReduceOnly {
+    /// 被检查的开仓请求的 ClientOrderId
+    pub cid: ClientOrderId,
+    /// 被检查的开仓请求的方向
+    pub side: Side,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{
+            EngineState, global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+            position::Position,
+        },
+        test_utils::spot_engine_state,
+    };
+    use barter_execution::{
+        order::{
+            OrderKey, OrderKind, TimeInForce,
+            id::{OrderId, StrategyId},
+            request::RequestOpen,
+        },
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn state() -> EngineState<DefaultGlobalData, DefaultInstrumentMarketData> {
+        let mut state = spot_engine_state(&["btc", "eth"]);
+
+        // LONG 2 btc, no eth Position
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .position
+            .current = Some(Position::from(&Trade {
+            id: TradeId::new("trade"),
+            order_id: OrderId::new("order"),
+            instrument: InstrumentIndex(0),
+            strategy: StrategyId::new("strategy"),
+            time_exchange: DateTime::<Utc>::MIN_UTC,
+            side: Side::Buy,
+            price: dec!(100),
+            quantity: dec!(2),
+            fees: AssetFees::quote_fees(dec!(0)),
+            reduce_only: false,
+        }));
+
+        state
+    }
+
+    fn open(
+        cid: &str,
+        instrument: usize,
+        side: Side,
+        quantity: Decimal,
+        reduce_only: bool,
+    ) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(instrument),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side,
+                price: dec!(100),
+                quantity,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only,
+            },
+        }
+    }
+
+    #[test]
+    fn test_check_reduce_only_check_opens() {
+        struct TestCase {
+            name: &'static str,
+            opens: Vec<OrderRequestOpen>,
+            expected_approved: Vec<OrderRequestOpen>,
+            expected_refused: Vec<(OrderRequestOpen, &'static str)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                name: "non reduce-only opens are unaffected",
+                opens: vec![
+                    open("buy", 0, Side::Buy, dec!(5), false),
+                    open("sell", 1, Side::Sell, dec!(5), false),
+                ],
+                expected_approved: vec![
+                    open("buy", 0, Side::Buy, dec!(5), false),
+                    open("sell", 1, Side::Sell, dec!(5), false),
+                ],
+                expected_refused: vec![],
+            },
+            TestCase {
+                name: "reduce-only sell smaller than LONG Position is approved unchanged",
+                opens: vec![open("sell", 0, Side::Sell, dec!(1), true)],
+                expected_approved: vec![open("sell", 0, Side::Sell, dec!(1), true)],
+                expected_refused: vec![],
+            },
+            TestCase {
+                name: "reduce-only sell larger than LONG Position is clamped to Position size",
+                opens: vec![open("sell", 0, Side::Sell, dec!(5), true)],
+                expected_approved: vec![open("sell", 0, Side::Sell, dec!(2), true)],
+                expected_refused: vec![],
+            },
+            TestCase {
+                name: "reduce-only opens in the same batch cannot exceed Position size",
+                opens: vec![
+                    open("sell-1", 0, Side::Sell, dec!(1.5), true),
+                    open("sell-2", 0, Side::Sell, dec!(1.5), true),
+                    open("sell-3", 0, Side::Sell, dec!(1.5), true),
+                ],
+                expected_approved: vec![
+                    open("sell-1", 0, Side::Sell, dec!(1.5), true),
+                    open("sell-2", 0, Side::Sell, dec!(0.5), true),
+                ],
+                expected_refused: vec![(
+                    open("sell-3", 0, Side::Sell, dec!(1.5), true),
+                    "CheckReduceOnly failed: reduce-only sell order sell-3 has no position to reduce",
+                )],
+            },
+            TestCase {
+                name: "reduce-only buy cannot increase a LONG Position",
+                opens: vec![open("buy", 0, Side::Buy, dec!(1), true)],
+                expected_approved: vec![],
+                expected_refused: vec![(
+                    open("buy", 0, Side::Buy, dec!(1), true),
+                    "CheckReduceOnly failed: reduce-only buy order buy has no position to reduce",
+                )],
+            },
+            TestCase {
+                name: "reduce-only sell with no Position is refused",
+                opens: vec![open("sell", 1, Side::Sell, dec!(1), true)],
+                expected_approved: vec![],
+                expected_refused: vec![(
+                    open("sell", 1, Side::Sell, dec!(1), true),
+                    "CheckReduceOnly failed: reduce-only sell order sell has no position to reduce",
+                )],
+            },
+        ];
+
+        let state = state();
+
+        for test in tests {
+            let (approved, refused) = CheckReduceOnly.check_opens(&state.instruments, test.opens);
+
+            assert_eq!(
+                approved
+                    .into_iter()
+                    .map(RiskApproved::into_item)
+                    .collect::<Vec<_>>(),
+                test.expected_approved,
+                "TC '{}' failed",
+                test.name
+            );
+            assert_eq!(
+                refused
+                    .into_iter()
+                    .map(|refused| (refused.item, refused.reason))
+                    .collect::<Vec<_>>(),
+                test.expected_refused
+                    .into_iter()
+                    .map(|(open, reason)| (open, reason.to_string()))
+                    .collect::<Vec<_>>(),
+                "TC '{}' failed",
+                test.name
+            );
+        }
+    }
+}
@@ -0,0 +1,450 @@
+use crate::{
+    engine::state::{
+        instrument::{InstrumentStates, data::InstrumentDataState},
+        order::Orders,
+    },
+    risk::{RiskApproved, RiskRefused},
+};
+use barter_execution::order::{
+    Order, OrderKind,
+    id::ClientOrderId,
+    request::{OrderRequestOpen, RequestOpen},
+    state::ActiveOrderState,
+};
+use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tracing::warn;
+
+/// [`CheckSelfTrade`] 检测到自成交时采取的动作。
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize,
+)]
+pub enum SelfTradeAction {
+    /// 拒绝会与自身挂单成交的开仓请求。
+    #[default]
+    Refuse,
+    /// 批准开仓请求，但记录一条警告日志。
+    Flag,
+}
+
+/// 自成交预防（Self-Trade Prevention）风险检查。
+///
+/// 如果已有一个挂在交易所的买单，而策略又生成了一个会穿过该买价的卖单，就会与自己成交，
+/// 白白支付手续费。`CheckSelfTrade` 将每个开仓请求与同一交易对上反方向的自身限价挂单
+/// （[`Orders`] 中的活跃订单）进行比较，拒绝（或标记）价格会交叉的开仓请求。
+///
+/// ## 交叉判定
+///
+/// - 买入开仓与价格 <= 买入价格的自身卖出限价挂单交叉（市价买入与任意卖出挂单交叉）
+/// - 卖出开仓与价格 >= 卖出价格的自身买入限价挂单交叉（市价卖出与任意买入挂单交叉）
+///
+/// 如果存在多个冲突挂单，报告价格最优（最先被成交）的挂单。拒绝原因包含冲突订单的
+/// [`ClientOrderId`]。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let check = CheckSelfTrade::default();
+/// let (approved, refused) = check.check_opens(&state.instruments, opens);
+/// ```
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+    Deserialize,
+    Serialize,
+    Constructor,
+)]
+pub struct CheckSelfTrade {
+    /// 检测到自成交时采取的动作
+    pub action: SelfTradeAction,
+}
+
+impl CheckSelfTrade {
+    /// 返回 RiskManager 检查的名称。
+    pub fn name() -> &'static str {
+        "CheckSelfTrade"
+    }
+
+    /// 查找提供的开仓请求会与之成交的自身挂单（如果有）。
+    ///
+    /// # 参数
+    ///
+    /// - `orders`: 开仓请求所属交易对的活跃订单
+    /// - `open`: 要检查的开仓请求
+    ///
+    /// # 返回值
+    ///
+    /// 返回价格最优的冲突挂单，如果不存在交叉则返回 `None`。
+    pub fn find_conflict<'a, ExchangeKey, InstrumentKey>(
+        orders: &'a Orders<ExchangeKey, InstrumentKey>,
+        open: &OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    ) -> Option<&'a Order<ExchangeKey, InstrumentKey, ActiveOrderState>>
+    where
+        ExchangeKey: PartialEq,
+        InstrumentKey: PartialEq,
+    {
+        orders
+            .active
+            .values()
+            .filter(|resting| {
+                resting.key.cid != open.key.cid
+                    && resting.key.exchange == open.key.exchange
+                    && resting.key.instrument == open.key.instrument
+                    && crosses(&open.state, resting.side, resting.kind, resting.price)
+            })
+            .min_by(|a, b| match open.state.side {
+                Side::Buy => a
+                    .price
+                    .cmp(&b.price)
+                    .then_with(|| a.key.cid.cmp(&b.key.cid)),
+                Side::Sell => b
+                    .price
+                    .cmp(&a.price)
+                    .then_with(|| a.key.cid.cmp(&b.key.cid)),
+            })
+    }
+
+    /// 检查提供的开仓请求是否会与 `orders` 中的自身挂单成交。
+    ///
+    /// 无论 [`SelfTradeAction`] 如何，检测到交叉时都会返回错误。
+    pub fn check<ExchangeKey, InstrumentKey>(
+        &self,
+        orders: &Orders<ExchangeKey, InstrumentKey>,
+        open: &OrderRequestOpen<ExchangeKey, InstrumentKey>,
+    ) -> Result<(), CheckFailSelfTrade>
+    where
+        ExchangeKey: PartialEq,
+        InstrumentKey: PartialEq,
+    {
+        match Self::find_conflict(orders, open) {
+            Some(resting) => Err(CheckFailSelfTrade {
+                cid: open.key.cid.clone(),
+                side: open.state.side,
+                price: open.state.price,
+                conflicting_cid: resting.key.cid.clone(),
+                conflicting_price: resting.price,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// 检查每个开仓请求是否会与自身挂单成交，返回已批准和被拒绝的开仓请求。
+    ///
+    /// 同一批次中先前已批准的限价开仓请求也被视为自身挂单。
+    ///
+    /// # 参数
+    ///
+    /// - `instruments`: 交易对状态集合
+    /// - `opens`: 要检查的开仓请求
+    ///
+    /// # 返回值
+    ///
+    /// 返回 `(已批准的开仓请求, 被拒绝的开仓请求)`。如果 `action` 为
+    /// [`SelfTradeAction::Flag`]，交叉的开仓请求会被批准并记录警告日志。
+    pub fn check_opens<InstrumentData>(
+        &self,
+        instruments: &InstrumentStates<InstrumentData>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) -> (
+        Vec<RiskApproved<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+        Vec<RiskRefused<OrderRequestOpen<ExchangeIndex, InstrumentIndex>>>,
+    )
+    where
+        InstrumentData: InstrumentDataState,
+    {
+        opens.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut approved, mut refused), open| {
+                let orders = &instruments.instrument_index(&open.key.instrument).orders;
+
+                let result = self.check(orders, &open).and_then(|()| {
+                    // Check against limit opens approved earlier in this batch
+                    approved
+                        .iter()
+                        .map(|approved: &RiskApproved<OrderRequestOpen>| &approved.0)
+                        .filter(|resting| {
+                            resting.key.cid != open.key.cid
+                                && resting.key.exchange == open.key.exchange
+                                && resting.key.instrument == open.key.instrument
+                                && crosses(
+                                    &open.state,
+                                    resting.state.side,
+                                    resting.state.kind,
+                                    resting.state.price,
+                                )
+                        })
+                        .map(|resting| CheckFailSelfTrade {
+                            cid: open.key.cid.clone(),
+                            side: open.state.side,
+                            price: open.state.price,
+                            conflicting_cid: resting.key.cid.clone(),
+                            conflicting_price: resting.state.price,
+                        })
+                        .next()
+                        .map_or(Ok(()), Err)
+                });
+
+                match (result, self.action) {
+                    (Ok(()), _) => approved.push(RiskApproved::new(open)),
+                    (Err(error), SelfTradeAction::Flag) => {
+                        warn!(
+                            %error,
+                            open = ?open,
+                            "CheckSelfTrade flagged an open that would cross a resting own order"
+                        );
+                        approved.push(RiskApproved::new(open));
+                    }
+                    (Err(error), SelfTradeAction::Refuse) => {
+                        refused.push(RiskRefused::new(open, error.to_string()));
+                    }
+                }
+
+                (approved, refused)
+            },
+        )
+    }
+}
+
+/// [`CheckSelfTrade`] 失败时返回的错误，包含冲突的自身挂单的 [`ClientOrderId`]。
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize, Constructor, Error,
+)]
+#[error(
+    "CheckSelfTrade failed: {side} order {cid} @ {price} would cross resting own order {conflicting_cid} @ {conflicting_price}"
+)]
+pub struct CheckFailSelfTrade {
+    /// 被检查的开仓请求的 ClientOrderId
+    pub cid: ClientOrderId,
+    /// 被检查的开仓请求的方向
+    pub side: Side,
+    /// 被检查的开仓请求的价格
+    pub price: Decimal,
+    /// 冲突的自身挂单的 ClientOrderId
+    pub conflicting_cid: ClientOrderId,
+    /// 冲突的自身挂单的价格
+    pub conflicting_price: Decimal,
+}
+
+/// 如果开仓请求会与反方向的限价（或 Peg）挂单成交，返回 `true`。
+///
+/// 止损（[`OrderKind::Stop`]）订单在触发前不在订单簿上，因此不会与挂单成交。
+fn crosses(
+    open: &RequestOpen,
+    resting_side: Side,
+    resting_kind: OrderKind,
+    resting_price: Decimal,
+) -> bool {
+    if resting_side == open.side
+        || matches!(resting_kind, OrderKind::Market | OrderKind::Stop { .. })
+    {
+        return false;
+    }
+
+    match (open.kind, open.side) {
+        (OrderKind::Market, _) => true,
+        (OrderKind::Stop { .. }, _) => false,
+        (OrderKind::Limit | OrderKind::Peg { .. }, Side::Buy) => open.price >= resting_price,
+        (OrderKind::Limit | OrderKind::Peg { .. }, Side::Sell) => open.price <= resting_price,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{
+            EngineState, global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+        },
+        test_utils::spot_engine_state,
+    };
+    use barter_execution::order::{
+        OrderKey, TimeInForce,
+        id::{OrderId, StrategyId},
+        state::Open,
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    fn state() -> EngineState<DefaultGlobalData, DefaultInstrumentMarketData> {
+        let mut state = spot_engine_state(&["btc", "eth"]);
+
+        // Resting own orders on btc: bid @ 99, ask @ 101 & ask @ 102
+        let orders = &mut state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .orders;
+        for (cid, side, price) in [
+            ("bid-99", Side::Buy, dec!(99)),
+            ("ask-101", Side::Sell, dec!(101)),
+            ("ask-102", Side::Sell, dec!(102)),
+        ] {
+            let order = resting(cid, side, price);
+            orders.active.insert(order.key.cid.clone(), order);
+        }
+
+        state
+    }
+
+    fn resting(
+        cid: &str,
+        side: Side,
+        price: Decimal,
+    ) -> Order<ExchangeIndex, InstrumentIndex, ActiveOrderState> {
+        Order {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            side,
+            price,
+            quantity: dec!(1),
+            kind: OrderKind::Limit,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+            reduce_only: false,
+            state: ActiveOrderState::Open(Open {
+                id: OrderId::new(cid),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                filled_quantity: dec!(0),
+            }),
+        }
+    }
+
+    fn open(
+        cid: &str,
+        instrument: usize,
+        side: Side,
+        kind: OrderKind,
+        price: Decimal,
+    ) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(instrument),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new(cid),
+            },
+            state: RequestOpen {
+                side,
+                price,
+                quantity: dec!(1),
+                kind,
+                time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                reduce_only: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_check_self_trade_check_opens() {
+        struct TestCase {
+            name: &'static str,
+            check: CheckSelfTrade,
+            opens: Vec<OrderRequestOpen>,
+            expected_approved: Vec<OrderRequestOpen>,
+            expected_refused: Vec<(OrderRequestOpen, &'static str)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                name: "non-crossing opens on both sides are approved",
+                check: CheckSelfTrade::default(),
+                opens: vec![
+                    open("buy", 0, Side::Buy, OrderKind::Limit, dec!(100)),
+                    open("sell", 0, Side::Sell, OrderKind::Limit, dec!(103)),
+                ],
+                expected_approved: vec![
+                    open("buy", 0, Side::Buy, OrderKind::Limit, dec!(100)),
+                    open("sell", 0, Side::Sell, OrderKind::Limit, dec!(103)),
+                ],
+                expected_refused: vec![],
+            },
+            TestCase {
+                name: "aggressive sell crossing own bid is refused naming the resting cid",
+                check: CheckSelfTrade::default(),
+                opens: vec![open("sell", 0, Side::Sell, OrderKind::Limit, dec!(98))],
+                expected_approved: vec![],
+                expected_refused: vec![(
+                    open("sell", 0, Side::Sell, OrderKind::Limit, dec!(98)),
+                    "CheckSelfTrade failed: sell order sell @ 98 would cross resting own order bid-99 @ 99",
+                )],
+            },
+            TestCase {
+                name: "market buy crosses the best priced own ask",
+                check: CheckSelfTrade::default(),
+                opens: vec![open("buy", 0, Side::Buy, OrderKind::Market, dec!(0))],
+                expected_approved: vec![],
+                expected_refused: vec![(
+                    open("buy", 0, Side::Buy, OrderKind::Market, dec!(0)),
+                    "CheckSelfTrade failed: buy order buy @ 0 would cross resting own order ask-101 @ 101",
+                )],
+            },
+            TestCase {
+                name: "opens on another instrument are unaffected",
+                check: CheckSelfTrade::default(),
+                opens: vec![open("sell", 1, Side::Sell, OrderKind::Limit, dec!(1))],
+                expected_approved: vec![open("sell", 1, Side::Sell, OrderKind::Limit, dec!(1))],
+                expected_refused: vec![],
+            },
+            TestCase {
+                name: "opens crossing an earlier approved open in the same batch are refused",
+                check: CheckSelfTrade::default(),
+                opens: vec![
+                    open("bid", 1, Side::Buy, OrderKind::Limit, dec!(10)),
+                    open("ask", 1, Side::Sell, OrderKind::Limit, dec!(10)),
+                ],
+                expected_approved: vec![open("bid", 1, Side::Buy, OrderKind::Limit, dec!(10))],
+                expected_refused: vec![(
+                    open("ask", 1, Side::Sell, OrderKind::Limit, dec!(10)),
+                    "CheckSelfTrade failed: sell order ask @ 10 would cross resting own order bid @ 10",
+                )],
+            },
+            TestCase {
+                name: "flag action approves crossing opens",
+                check: CheckSelfTrade::new(SelfTradeAction::Flag),
+                opens: vec![open("sell", 0, Side::Sell, OrderKind::Limit, dec!(98))],
+                expected_approved: vec![open("sell", 0, Side::Sell, OrderKind::Limit, dec!(98))],
+                expected_refused: vec![],
+            },
+        ];
+
+        let state = state();
+
+        for test in tests {
+            let (approved, refused) = test.check.check_opens(&state.instruments, test.opens);
+
+            assert_eq!(
+                approved
+                    .into_iter()
+                    .map(RiskApproved::into_item)
+                    .collect::<Vec<_>>(),
+                test.expected_approved,
+                "TC '{}' failed",
+                test.name
+            );
+            assert_eq!(
+                refused
+                    .into_iter()
+                    .map(|refused| (refused.item, refused.reason))
+                    .collect::<Vec<_>>(),
+                test.expected_refused
+                    .into_iter()
+                    .map(|(open, reason)| (open, reason.to_string()))
+                    .collect::<Vec<_>>(),
+                "TC '{}' failed",
+                test.name
+            );
+        }
+    }
+}
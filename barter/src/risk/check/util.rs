@@ -128,10 +128,5 @@ pub fn calculate_delta(
     side: Side,
     quantity_in_kind: Decimal,
 ) -> Decimal {
-    let delta = instrument_delta * (quantity_in_kind * contract_size);
-
-    match side {
-        Side::Buy => delta,
-        Side::Sell => -delta,
-    }
+    side.sign() * instrument_delta * (quantity_in_kind * contract_size)
 }
@@ -0,0 +1,192 @@
+//! 回撤熔断模块
+//!
+//! 本模块定义了 [`DrawdownCircuitBreaker`]，当交易会话的盈亏回撤超过配置的百分比时，
+//! 自动禁用交易，阻止 Engine 继续承担新的风险。
+//!
+//! # 工作原理
+//!
+//! 启用 `Engine::with_drawdown_circuit_breaker` 后，`Engine` 每处理一个事件都会：
+//!
+//! 1. **计算权益**: 权益 = 初始资金 + 会话盈亏（已平仓仓位的已实现盈亏，加上未平仓仓位的
+//!    已实现和未实现盈亏，参见 [`session_pnl`]）
+//! 2. **跟踪峰值**: 记录会话的权益峰值
+//! 3. **熔断**: 权益相对峰值的回撤超过 `max_drawdown` 时，生成一次 [`DrawdownBreached`]，并将
+//!    `TradingState` 转换为 `Disabled`，之后不再生成任何算法开仓请求
+//!
+//! 熔断后不会自动恢复：必须显式发送 `TradingState::Enabled`（或 `PostOnly`）更新重新启用交易，
+//! 此时权益峰值被重置为当前权益。
+
+use crate::engine::state::{
+    instrument::{InstrumentStates, filter::InstrumentFilter},
+    trading::TradingState,
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// 交易会话盈亏回撤熔断器。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// // 初始资金 100,000，权益回撤超过 5% 时禁用交易
+/// let engine = Engine::new(clock, state, execution_txs, strategy, risk)
+///     .with_drawdown_circuit_breaker(DrawdownCircuitBreaker::new(dec!(100_000), dec!(0.05)));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct DrawdownCircuitBreaker {
+    /// 交易会话的初始资金（报价资产），会话盈亏在此基础上计算权益
+    pub capital: Decimal,
+    /// 允许的最大权益回撤比例（例如 `dec!(0.05)` 表示 5%）
+    pub max_drawdown: Decimal,
+    /// 会话的权益峰值
+    pub equity_peak: Option<Decimal>,
+    /// 熔断器是否已触发，触发后需要显式重新启用交易
+    pub tripped: bool,
+}
+
+impl DrawdownCircuitBreaker {
+    /// 使用初始资金和允许的最大权益回撤比例构造新的 `DrawdownCircuitBreaker`。
+    pub fn new(capital: Decimal, max_drawdown: Decimal) -> Self {
+        Self {
+            capital,
+            max_drawdown,
+            equity_peak: None,
+            tripped: false,
+        }
+    }
+
+    /// 使用最新的会话盈亏更新熔断器，如果权益回撤超过上限则触发熔断并返回 [`DrawdownBreached`]。
+    ///
+    /// 已触发的熔断器在 `trading` 重新启用前保持触发状态，重新启用后权益峰值被重置为当前权益。
+    ///
+    /// # 参数
+    ///
+    /// - `pnl`: 当前会话盈亏
+    /// - `trading`: 当前 `TradingState`
+    /// - `time`: 当前 `EngineClock` 时间
+    pub fn update(
+        &mut self,
+        pnl: Decimal,
+        trading: TradingState,
+        time: DateTime<Utc>,
+    ) -> Option<DrawdownBreached> {
+        let equity = self.capital + pnl;
+
+        if self.tripped {
+            if trading != TradingState::Disabled {
+                self.tripped = false;
+                self.equity_peak = Some(equity);
+            }
+            return None;
+        }
+
+        let equity_peak = self
+            .equity_peak
+            .map_or(equity, |equity_peak| equity_peak.max(equity));
+        self.equity_peak = Some(equity_peak);
+
+        if equity_peak <= Decimal::ZERO {
+            return None;
+        }
+
+        let drawdown = (equity_peak - equity) / equity_peak;
+        if drawdown <= self.max_drawdown {
+            return None;
+        }
+
+        self.tripped = true;
+
+        Some(DrawdownBreached {
+            equity_peak,
+            equity,
+            drawdown,
+            max_drawdown: self.max_drawdown,
+            time,
+        })
+    }
+}
+
+/// 交易会话权益回撤超过 [`DrawdownCircuitBreaker`] 上限的信号。
+///
+/// 每次熔断生成一次，通过 `EngineOutput::DrawdownBreached` 出现在 Engine 审计中。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub struct DrawdownBreached {
+    /// 会话的权益峰值
+    pub equity_peak: Decimal,
+    /// 熔断时的权益
+    pub equity: Decimal,
+    /// 熔断时权益相对峰值的回撤比例
+    pub drawdown: Decimal,
+    /// 允许的最大权益回撤比例
+    pub max_drawdown: Decimal,
+    /// 触发熔断的 `EngineClock` 时间
+    pub time: DateTime<Utc>,
+}
+
+/// 计算所有交易对的交易会话盈亏。
+///
+/// 会话盈亏 = 已平仓仓位的已实现盈亏 + 未平仓仓位的已实现盈亏和未实现盈亏。
+///
+/// 注意：不同交易对的盈亏直接相加，因此假设所有交易对使用相同的报价资产。
+pub fn session_pnl<InstrumentData>(instruments: &InstrumentStates<InstrumentData>) -> Decimal {
+    instruments
+        .instruments(&InstrumentFilter::None)
+        .map(|state| {
+            state.tear_sheet.pnl_returns.pnl_raw
+                + state
+                    .position
+                    .positions()
+                    .map(|position| position.pnl_realised + position.pnl_unrealised)
+                    .sum::<Decimal>()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_drawdown_circuit_breaker_update() {
+        let time = DateTime::<Utc>::MIN_UTC;
+        let mut breaker = DrawdownCircuitBreaker::new(dec!(1000), dec!(0.1));
+
+        // TC0: equity rises to a new peak
+        assert_eq!(breaker.update(dec!(100), TradingState::Enabled, time), None);
+        assert_eq!(breaker.equity_peak, Some(dec!(1100)));
+
+        // TC1: drawdown equal to the limit does not trip
+        assert_eq!(breaker.update(dec!(-10), TradingState::Enabled, time), None);
+        assert!(!breaker.tripped);
+
+        // TC2: drawdown past the limit trips
+        assert_eq!(
+            breaker.update(dec!(-11), TradingState::Enabled, time),
+            Some(DrawdownBreached {
+                equity_peak: dec!(1100),
+                equity: dec!(989),
+                drawdown: dec!(111) / dec!(1100),
+                max_drawdown: dec!(0.1),
+                time,
+            })
+        );
+        assert!(breaker.tripped);
+
+        // TC3: tripped breaker stays tripped while trading is disabled
+        assert_eq!(
+            breaker.update(dec!(-500), TradingState::Disabled, time),
+            None
+        );
+        assert!(breaker.tripped);
+
+        // TC4: explicit re-enable resets the peak to the current equity
+        assert_eq!(
+            breaker.update(dec!(-500), TradingState::Enabled, time),
+            None
+        );
+        assert!(!breaker.tripped);
+        assert_eq!(breaker.equity_peak, Some(dec!(500)));
+    }
+}
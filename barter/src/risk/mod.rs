@@ -9,6 +9,8 @@
 //! - **RiskApproved**: 通过风险检查的订单请求
 //! - **RiskRefused**: 被风险管理系统拒绝的订单请求（包含拒绝原因）
 //! - **DefaultRiskManager**: 默认风险管理器（仅用于演示，不执行任何检查）
+//! - **AggregateExposureRiskManager**: 限制账户级别总敞口和净敞口的风险管理器
+//! - **DrawdownCircuitBreaker**: 会话权益回撤超过上限时自动禁用交易的熔断器
 //!
 //! # 风险管理功能
 //!
@@ -19,6 +21,10 @@
 //! - 过滤会穿越订单簿的订单
 //! - 等等
 
+use crate::{
+    engine::state::{EngineState, instrument::data::InstrumentDataState},
+    risk::check::CheckAggregateExposure,
+};
 use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
 use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
 use barter_integration::Unrecoverable;
@@ -29,6 +35,10 @@ use std::{fmt::Debug, hash::Hash, marker::PhantomData};
 /// RiskManager 检查和工具。
 pub mod check;
 
+/// 定义 [`DrawdownCircuitBreaker`](circuit_breaker::DrawdownCircuitBreaker)，会话权益回撤超过
+/// 上限时自动禁用交易。
+pub mod circuit_breaker;
+
 /// 审查并可选地过滤由 [`AlgoStrategy`](super::strategy::algo::AlgoStrategy) 生成的
 /// 取消和开仓订单请求的 RiskManager 接口。
 ///
@@ -316,3 +326,63 @@ impl<State, ExchangeKey, InstrumentKey> RiskManager<ExchangeKey, InstrumentKey>
         )
     }
 }
+
+/// 限制账户级别总敞口和净敞口的 [`RiskManager`]。
+///
+/// 所有取消请求都会被批准，开仓请求使用 [`CheckAggregateExposure`] 检查，
+/// 会突破总敞口或净敞口限制的开仓请求将被拒绝，拒绝原因包含被突破的限制以及超出的数量。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let risk_manager = AggregateExposureRiskManager::new(CheckAggregateExposure::new(
+///     dec!(100_000), // 总敞口上限
+///     dec!(50_000),  // 净敞口上限
+/// ));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AggregateExposureRiskManager<State> {
+    /// 聚合敞口检查。
+    pub check: CheckAggregateExposure,
+    /// 状态类型标记。
+    phantom: PhantomData<State>,
+}
+
+impl<State> AggregateExposureRiskManager<State> {
+    /// 使用提供的 [`CheckAggregateExposure`] 构造新的 `AggregateExposureRiskManager`。
+    pub fn new(check: CheckAggregateExposure) -> Self {
+        Self {
+            check,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<GlobalData, InstrumentData> RiskManager
+    for AggregateExposureRiskManager<EngineState<GlobalData, InstrumentData>>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel>,
+        opens: impl IntoIterator<Item = OrderRequestOpen>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen>>,
+    ) {
+        let (approved_opens, refused_opens) = self.check.check_opens(&state.instruments, opens);
+
+        (
+            cancels.into_iter().map(RiskApproved::new),
+            approved_opens,
+            std::iter::empty(),
+            refused_opens,
+        )
+    }
+}
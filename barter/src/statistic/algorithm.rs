@@ -8,6 +8,110 @@
 //! - **Welford Online 算法**: 单次遍历计算均值和方差的在线算法
 //! - **均值计算**: 增量更新均值
 //! - **方差计算**: 样本方差和总体方差
+//! - **WelfordAccumulator**: 封装 Welford Online 状态的增量累加器，每个新值 O(1) 更新
+//! - **Bootstrap**: 对 PnL 收益序列进行可复现的蒙特卡洛块自助法重采样，生成指标的置信区间
+
+use rust_decimal::{Decimal, MathematicalOps};
+use serde::{Deserialize, Serialize};
+
+/// 基于 [`welford_online`] 算法的增量均值/方差累加器。
+///
+/// 每个新值以 O(1) 更新均值和递推关系 M，无需存储或重新遍历整个数据序列，
+/// 并避免朴素 `E[x²] - E[x]²` 公式中的灾难性抵消（catastrophic cancellation）。
+///
+/// 用于 [`DataSetSummary`](super::summary::dataset::DataSetSummary) 维护 PnL 收益的
+/// 运行统计，Sharpe 和 Sortino 比率直接从中生成。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let mut welford = WelfordAccumulator::default();
+/// for value in returns {
+///     welford.update(value);
+/// }
+///
+/// let std_dev = welford.population_std_dev();
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Default, Deserialize, Serialize)]
+pub struct WelfordAccumulator {
+    /// 已累加的值数量。
+    pub count: Decimal,
+    /// 运行均值。
+    pub mean: Decimal,
+    /// Welford Online 递推关系 M。
+    pub recurrence_relation_m: Decimal,
+}
+
+impl WelfordAccumulator {
+    /// 从现有的 Welford Online 状态构造 [`WelfordAccumulator`]。
+    pub fn new(count: Decimal, mean: Decimal, recurrence_relation_m: Decimal) -> Self {
+        Self {
+            count,
+            mean,
+            recurrence_relation_m,
+        }
+    }
+
+    /// 以 O(1) 累加下一个值，更新计数、均值和递推关系 M。
+    ///
+    /// # 返回值
+    ///
+    /// 返回累加此值之前的均值。
+    pub fn update(&mut self, next_value: Decimal) -> Decimal {
+        let prev_mean = self.mean;
+
+        self.count += Decimal::ONE;
+        self.mean = welford_online::calculate_mean(self.mean, next_value, self.count);
+        self.recurrence_relation_m = welford_online::calculate_recurrence_relation_m(
+            self.recurrence_relation_m,
+            prev_mean,
+            next_value,
+            self.mean,
+        );
+
+        prev_mean
+    }
+
+    /// 无偏"样本"方差（Bessel 校正）。
+    pub fn sample_variance(&self) -> Decimal {
+        welford_online::calculate_sample_variance(self.recurrence_relation_m, self.count)
+    }
+
+    /// 有偏"总体"方差。
+    pub fn population_variance(&self) -> Decimal {
+        welford_online::calculate_population_variance(self.recurrence_relation_m, self.count)
+    }
+
+    /// "总体"标准差。
+    pub fn population_std_dev(&self) -> Decimal {
+        self.population_variance()
+            .abs()
+            .sqrt()
+            .expect("variance cannot be negative")
+    }
+}
+
+impl Extend<Decimal> for WelfordAccumulator {
+    fn extend<Iter>(&mut self, values: Iter)
+    where
+        Iter: IntoIterator<Item = Decimal>,
+    {
+        values.into_iter().for_each(|value| {
+            self.update(value);
+        });
+    }
+}
+
+impl FromIterator<Decimal> for WelfordAccumulator {
+    fn from_iter<Iter>(values: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = Decimal>,
+    {
+        let mut welford = Self::default();
+        welford.extend(values);
+        welford
+    }
+}
 
 /// [Welford Online](https://en.wikipedia.org/wiki/Algorithms_for_calculating_variance#Welford's_online_algorithm)
 /// 算法集合，用于单次遍历计算运行中的值，如均值和方差。
@@ -148,10 +252,153 @@ pub mod welford_online {
     }
 }
 
+/// 蒙特卡洛块自助法（Block Bootstrap）模块。
+///
+/// 通过对 PnL 收益序列（例如 [`PnLReturns::returns`](super::summary::pnl::PnLReturns::returns)）
+/// 进行有放回的块重采样，生成所选指标（例如 Sharpe 比率）的分布和百分位置信区间，
+/// 用于判断回测结果是否只是运气。
+///
+/// ## 块重采样
+///
+/// 使用循环移动块自助法（circular moving block bootstrap）：每个重采样序列由随机起点的
+/// 连续 `block_size` 个收益拼接而成（超出序列末尾时回绕到开头），直到达到原始序列长度。
+/// 保留块内的顺序可以保持收益的自相关性。`block_size` 为 1 时等价于普通 i.i.d. 自助法。
+///
+/// ## 可复现性
+///
+/// 随机数生成器 `ChaCha8Rng` 由 [`BootstrapConfig::seed`] 初始化，其输出在不同平台与版本间保持稳定，
+/// 因此相同的输入和配置总是产生相同的结果。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let config = BootstrapConfig::new(1_000, 5, dec!(0.95), 42);
+///
+/// let returns = pnl_returns.returns.iter().copied().collect::<Vec<_>>();
+/// let result = bootstrap::block_bootstrap(&returns, config, |returns| {
+///     returns.iter().sum::<Decimal>() / Decimal::from(returns.len())
+/// })
+/// .unwrap();
+///
+/// println!("95% CI: [{}, {}]", result.lower, result.upper);
+/// ```
+pub mod bootstrap {
+    use rand::{Rng, SeedableRng};
+    use rand_chacha::ChaCha8Rng;
+    use rust_decimal::{Decimal, prelude::ToPrimitive};
+    use serde::{Deserialize, Serialize};
+
+    /// 块自助法配置。
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+    pub struct BootstrapConfig {
+        /// 重采样次数（即指标分布的样本数）。
+        pub samples: usize,
+        /// 每个重采样块包含的连续收益数量。
+        pub block_size: usize,
+        /// 置信水平，例如 `0.95` 表示 95% 置信区间。
+        pub confidence: Decimal,
+        /// 随机数生成器种子。
+        pub seed: u64,
+    }
+
+    impl BootstrapConfig {
+        /// 构造新的 [`BootstrapConfig`]。
+        pub fn new(samples: usize, block_size: usize, confidence: Decimal, seed: u64) -> Self {
+            Self {
+                samples,
+                block_size,
+                confidence,
+                seed,
+            }
+        }
+    }
+
+    /// 块自助法结果。
+    #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+    pub struct BootstrapResult {
+        /// 在原始收益序列上计算的指标值。
+        pub estimate: Decimal,
+        /// 置信区间下界（`(1 - confidence) / 2` 百分位）。
+        pub lower: Decimal,
+        /// 置信区间上界（`(1 + confidence) / 2` 百分位）。
+        pub upper: Decimal,
+        /// 升序排列的重采样指标分布。
+        pub distribution: Vec<Decimal>,
+    }
+
+    /// 对收益序列执行循环移动块自助法，返回指标的分布和百分位置信区间。
+    ///
+    /// # 参数
+    ///
+    /// - `returns`: 收益序列
+    /// - `config`: 块自助法配置
+    /// - `metric`: 从（重采样的）收益序列计算指标的函数
+    ///
+    /// # 返回值
+    ///
+    /// 如果 `returns` 为空、`samples` 或 `block_size` 为 0，或 `confidence` 不在 `(0, 1)`
+    /// 区间内，返回 `None`。
+    pub fn block_bootstrap<FnMetric>(
+        returns: &[Decimal],
+        config: BootstrapConfig,
+        metric: FnMetric,
+    ) -> Option<BootstrapResult>
+    where
+        FnMetric: Fn(&[Decimal]) -> Decimal,
+    {
+        if returns.is_empty()
+            || config.samples == 0
+            || config.block_size == 0
+            || config.confidence <= Decimal::ZERO
+            || config.confidence >= Decimal::ONE
+        {
+            return None;
+        }
+
+        let mut rng = ChaCha8Rng::seed_from_u64(config.seed);
+        let mut resampled = Vec::with_capacity(returns.len());
+
+        let mut distribution = (0..config.samples)
+            .map(|_| {
+                resampled.clear();
+                while resampled.len() < returns.len() {
+                    let start = rng.random_range(0..returns.len());
+                    resampled.extend(
+                        (start..start + config.block_size)
+                            .map(|index| returns[index % returns.len()])
+                            .take(returns.len() - resampled.len()),
+                    );
+                }
+                metric(&resampled)
+            })
+            .collect::<Vec<_>>();
+
+        distribution.sort_unstable();
+
+        let tail = (Decimal::ONE - config.confidence) / Decimal::TWO;
+
+        Some(BootstrapResult {
+            estimate: metric(returns),
+            lower: percentile(&distribution, tail),
+            upper: percentile(&distribution, Decimal::ONE - tail),
+            distribution,
+        })
+    }
+
+    /// 使用最近秩（nearest-rank）方法返回升序分布的百分位值。
+    fn percentile(sorted: &[Decimal], quantile: Decimal) -> Decimal {
+        let rank = (quantile * Decimal::from(sorted.len()))
+            .ceil()
+            .to_usize()
+            .unwrap_or(0);
+
+        sorted[rank.clamp(1, sorted.len()) - 1]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use rust_decimal::Decimal;
     use rust_decimal_macros::dec;
     use std::str::FromStr;
 
@@ -357,4 +604,84 @@ mod tests {
             assert_eq!(actual_variance, expected, "TC{index} failed");
         }
     }
+
+    #[test]
+    fn welford_accumulator_matches_naive_batch() {
+        // Large series of PnL returns with a large offset to provoke catastrophic cancellation
+        // in the naive single-pass E[x²] - E[x]² formula
+        let values = (0..10_000_i64)
+            .map(|index| dec!(1000000) + Decimal::new((index * 7919) % 2001 - 1000, 4))
+            .collect::<Vec<_>>();
+
+        let welford = values.iter().copied().collect::<WelfordAccumulator>();
+
+        // Naive two-pass batch computation
+        let count = Decimal::from(values.len());
+        let mean = values.iter().sum::<Decimal>() / count;
+        let m = values
+            .iter()
+            .map(|value| (value - mean) * (value - mean))
+            .sum::<Decimal>();
+        let population_variance = m / count;
+        let sample_variance = m / (count - Decimal::ONE);
+
+        let tolerance = dec!(0.000000000001);
+        assert_eq!(welford.count, count);
+        assert!((welford.mean - mean).abs() <= tolerance);
+        assert!((welford.population_variance() - population_variance).abs() <= tolerance);
+        assert!((welford.sample_variance() - sample_variance).abs() <= tolerance);
+        assert!(
+            (welford.population_std_dev() - population_variance.sqrt().unwrap()).abs() <= tolerance
+        );
+    }
+
+    #[test]
+    fn test_block_bootstrap_is_deterministic_with_seed() {
+        use bootstrap::{BootstrapConfig, block_bootstrap};
+
+        // Synthetic series with positive drift
+        let returns = (0..50)
+            .map(|index| Decimal::new((index % 7) as i64 - 2, 2))
+            .collect::<Vec<_>>();
+
+        let mean =
+            |returns: &[Decimal]| returns.iter().sum::<Decimal>() / Decimal::from(returns.len());
+
+        let config = BootstrapConfig::new(500, 5, dec!(0.9), 42);
+        let result = block_bootstrap(&returns, config, mean).unwrap();
+
+        assert_eq!(result.estimate, dec!(0.0094));
+        assert_eq!(result.lower, dec!(0.0058));
+        assert_eq!(result.upper, dec!(0.0130));
+        assert_eq!(result.distribution.len(), 500);
+        assert!(result.lower <= result.estimate && result.estimate <= result.upper);
+
+        // Same seed produces an identical distribution
+        assert_eq!(block_bootstrap(&returns, config, mean).unwrap(), result);
+
+        // Invalid configurations
+        assert!(block_bootstrap(&[], config, mean).is_none());
+        assert!(
+            block_bootstrap(
+                &returns,
+                BootstrapConfig {
+                    block_size: 0,
+                    ..config
+                },
+                mean
+            )
+            .is_none()
+        );
+        assert!(
+            block_bootstrap(
+                &returns,
+                BootstrapConfig {
+                    confidence: Decimal::ONE,
+                    ..config
+                },
+                mean
+            )
+            .is_none()
+        );
+    }
 }
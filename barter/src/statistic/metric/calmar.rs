@@ -124,16 +124,12 @@ where
     where
         TargetInterval: TimeInterval,
     {
-        // 确定缩放因子：目标间隔与当前间隔比值的平方根
-        let target_secs = Decimal::from(target.interval().num_seconds());
-        let current_secs = Decimal::from(self.interval.interval().num_seconds());
-
-        let scale = target_secs
-            .abs()
-            .checked_div(current_secs.abs())
+        // 确定缩放因子：目标间隔包含的当前间隔周期数量的平方根
+        let scale = target
+            .periods(self.interval.interval())
             .unwrap_or(Decimal::MAX)
             .sqrt()
-            .expect("ensured seconds are Positive");
+            .expect("ensured periods are Positive");
 
         CalmarRatio {
             value: self.value.checked_mul(scale).unwrap_or(Decimal::MAX),
@@ -96,13 +96,9 @@ where
     where
         TargetInterval: TimeInterval,
     {
-        // 确定缩放因子：目标间隔与当前间隔的线性比例
-        let target_secs = Decimal::from(target.interval().num_seconds());
-        let current_secs = Decimal::from(self.interval.interval().num_seconds());
-
-        let scale = target_secs
-            .abs()
-            .checked_div(current_secs.abs())
+        // 确定缩放因子：目标间隔包含的当前间隔周期数量（线性比例）
+        let scale = target
+            .periods(self.interval.interval())
             .unwrap_or(Decimal::MAX);
 
         RateOfReturn {
@@ -12,7 +12,7 @@
 //!
 //! <https://www.investopedia.com/articles/07/sharpe_ratio.asp>
 
-use crate::statistic::time::TimeInterval;
+use crate::statistic::{algorithm::WelfordAccumulator, time::TimeInterval};
 use rust_decimal::{Decimal, MathematicalOps};
 use serde::{Deserialize, Serialize};
 
@@ -88,6 +88,28 @@ where
         }
     }
 
+    /// 从收益率的增量 [`WelfordAccumulator`] 计算 [`SharpeRatio`]，无需重新遍历收益序列。
+    ///
+    /// 使用累加器的均值和"总体"标准差，参见 [`SharpeRatio::calculate`]。
+    ///
+    /// # 参数
+    ///
+    /// - `risk_free_return`: 无风险收益率
+    /// - `returns`: 收益率的 Welford 累加器
+    /// - `returns_period`: 收益率的时间间隔
+    pub fn calculate_welford(
+        risk_free_return: Decimal,
+        returns: &WelfordAccumulator,
+        returns_period: Interval,
+    ) -> Self {
+        Self::calculate(
+            risk_free_return,
+            returns.mean,
+            returns.population_std_dev(),
+            returns_period,
+        )
+    }
+
     /// 将 [`SharpeRatio`] 从当前 [`TimeInterval`] 缩放到提供的 [`TimeInterval`]。
     ///
     /// 此缩放假设收益率是独立同分布（IID）的。
@@ -111,16 +133,12 @@ where
     where
         TargetInterval: TimeInterval,
     {
-        // 确定缩放因子：目标间隔与当前间隔比值的平方根
-        let target_secs = Decimal::from(target.interval().num_seconds());
-        let current_secs = Decimal::from(self.interval.interval().num_seconds());
-
-        let scale = target_secs
-            .abs()
-            .checked_div(current_secs.abs())
+        // 确定缩放因子：目标间隔包含的当前间隔周期数量的平方根
+        let scale = target
+            .periods(self.interval.interval())
             .unwrap_or(Decimal::MAX)
             .sqrt()
-            .expect("ensured seconds are Positive");
+            .expect("ensured periods are Positive");
 
         SharpeRatio {
             value: self.value.checked_mul(scale).unwrap_or(Decimal::MAX),
@@ -148,6 +166,21 @@ mod tests {
         assert_eq!(result.value, Decimal::MAX);
     }
 
+    #[test]
+    fn test_sharpe_ratio_calculate_welford() {
+        // Returns with mean 0.002 and population std dev 0.001
+        let returns = [dec!(0.001), dec!(0.003), dec!(0.001), dec!(0.003)]
+            .into_iter()
+            .collect::<WelfordAccumulator>();
+
+        let actual = SharpeRatio::calculate_welford(dec!(0.001), &returns, Daily);
+        assert_eq!(actual.value, dec!(1.0));
+        assert_eq!(
+            actual,
+            SharpeRatio::calculate(dec!(0.001), dec!(0.002), dec!(0.001), Daily)
+        );
+    }
+
     #[test]
     fn test_sharpe_ratio_calculate_with_custom_interval() {
         // Define custom interval returns statistics
@@ -14,7 +14,7 @@
 //! - Sortino Ratio 只使用下行波动率（负收益的标准差）
 //! - Sortino Ratio 更适合评估有偏收益分布的策略
 
-use crate::statistic::time::TimeInterval;
+use crate::statistic::{algorithm::WelfordAccumulator, time::TimeInterval};
 use rust_decimal::{Decimal, MathematicalOps};
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
@@ -96,6 +96,31 @@ where
         }
     }
 
+    /// 从收益率与亏损收益率的增量 [`WelfordAccumulator`] 计算 [`SortinoRatio`]，无需重新遍历
+    /// 收益序列。
+    ///
+    /// 使用全部收益率的均值和亏损收益率的"总体"标准差，参见 [`SortinoRatio::calculate`]。
+    ///
+    /// # 参数
+    ///
+    /// - `risk_free_return`: 无风险收益率
+    /// - `returns`: 全部收益率的 Welford 累加器
+    /// - `loss_returns`: 亏损收益率的 Welford 累加器
+    /// - `returns_period`: 收益率的时间间隔
+    pub fn calculate_welford(
+        risk_free_return: Decimal,
+        returns: &WelfordAccumulator,
+        loss_returns: &WelfordAccumulator,
+        returns_period: Interval,
+    ) -> Self {
+        Self::calculate(
+            risk_free_return,
+            returns.mean,
+            loss_returns.population_std_dev(),
+            returns_period,
+        )
+    }
+
     /// 将 [`SortinoRatio`] 从当前 [`TimeInterval`] 缩放到提供的 [`TimeInterval`]。
     ///
     /// 此缩放假设收益率是独立同分布（IID）的。然而，这个假设对于下行偏差可能不太合适。
@@ -119,16 +144,12 @@ where
     where
         TargetInterval: TimeInterval,
     {
-        // 确定缩放因子：目标间隔与当前间隔比值的平方根
-        let target_secs = Decimal::from(target.interval().num_seconds());
-        let current_secs = Decimal::from(self.interval.interval().num_seconds());
-
-        let scale = target_secs
-            .abs()
-            .checked_div(current_secs.abs())
+        // 确定缩放因子：目标间隔包含的当前间隔周期数量的平方根
+        let scale = target
+            .periods(self.interval.interval())
             .unwrap_or(Decimal::MAX)
             .sqrt()
-            .expect("ensured seconds are Positive");
+            .expect("ensured periods are Positive");
 
         SortinoRatio {
             value: self.value.checked_mul(scale).unwrap_or(Decimal::MAX),
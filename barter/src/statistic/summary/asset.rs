@@ -8,6 +8,7 @@ use crate::{
 };
 use barter_execution::balance::{AssetBalance, Balance};
 use barter_integration::snapshot::Snapshot;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// TearSheet summarising the trading session changes for an Asset.
@@ -26,6 +27,10 @@ pub struct TearSheetAssetGenerator {
     pub drawdown: DrawdownGenerator,
     pub drawdown_mean: MeanDrawdownGenerator,
     pub drawdown_max: MaxDrawdownGenerator,
+
+    /// Optional warm-up cutoff - [`AssetBalance`]s before this time only re-seed the generator.
+    #[serde(default)]
+    pub time_warmup_end: Option<DateTime<Utc>>,
 }
 
 impl TearSheetAssetGenerator {
@@ -36,11 +41,34 @@ impl TearSheetAssetGenerator {
             drawdown: DrawdownGenerator::init(Timed::new(initial.value.total, initial.time)),
             drawdown_mean: MeanDrawdownGenerator::default(),
             drawdown_max: MaxDrawdownGenerator::default(),
+            time_warmup_end: None,
+        }
+    }
+
+    /// Exclude [`AssetBalance`]s before `time_warmup_end` from drawdown statistics.
+    ///
+    /// Until the cutoff, each [`AssetBalance`] re-seeds the generator, so drawdowns are measured
+    /// from the last balance before the warm-up ended.
+    pub fn with_warmup(self, time_warmup_end: DateTime<Utc>) -> Self {
+        Self {
+            time_warmup_end: Some(time_warmup_end),
+            ..self
         }
     }
 
     /// Update the [`TearSheetAssetGenerator`] from the next [`Snapshot`] [`AssetBalance`].
     pub fn update_from_balance<AssetKey>(&mut self, balance: Snapshot<&AssetBalance<AssetKey>>) {
+        if let Some(time_warmup_end) = self.time_warmup_end
+            && balance.value().time_exchange < time_warmup_end
+        {
+            *self = Self::init(&Timed::new(
+                balance.value().balance,
+                balance.value().time_exchange,
+            ))
+            .with_warmup(time_warmup_end);
+            return;
+        }
+
         self.balance_now = Some(balance.value().balance);
 
         if let Some(next_drawdown) = self.drawdown.update(Timed::new(
@@ -123,6 +151,7 @@ mod tests {
                     )),
                     drawdown_mean: MeanDrawdownGenerator::default(),
                     drawdown_max: MaxDrawdownGenerator::default(),
+                    time_warmup_end: None,
                 },
             },
             // TC1: Balance decreased, so expect a current drawdown only
@@ -141,6 +170,7 @@ mod tests {
                     },
                     drawdown_mean: MeanDrawdownGenerator::default(),
                     drawdown_max: MaxDrawdownGenerator::default(),
+                    time_warmup_end: None,
                 },
             },
             // TC2: Further decrease - larger drawdown
@@ -159,6 +189,7 @@ mod tests {
                     },
                     drawdown_mean: MeanDrawdownGenerator::default(),
                     drawdown_max: MaxDrawdownGenerator::default(),
+                    time_warmup_end: None,
                 },
             },
             // TC3: Recovery above previous peak - should complete drawdown period
@@ -190,6 +221,7 @@ mod tests {
                             time_end: time_plus_days(base_time, 4),
                         })),
                     },
+                    time_warmup_end: None,
                 },
             },
             // TC4: Small drawdown after new peak (2.5 -> 2.4)
@@ -223,6 +255,7 @@ mod tests {
                             time_end: time_plus_days(base_time, 4),
                         })),
                     },
+                    time_warmup_end: None,
                 },
             },
             // TC5: Equal to previous value - drawdown continues
@@ -256,6 +289,7 @@ mod tests {
                             time_end: time_plus_days(base_time, 4),
                         })),
                     },
+                    time_warmup_end: None,
                 },
             },
             // TC6: Tiny change, but still in drawdown - retain max drawdown from current period
@@ -289,6 +323,7 @@ mod tests {
                             time_end: time_plus_days(base_time, 4),
                         })),
                     },
+                    time_warmup_end: None,
                 },
             },
             // TC7: recovery above previous peak - should complete drawdown period
@@ -323,6 +358,7 @@ mod tests {
                             time_end: time_plus_days(base_time, 4),
                         })),
                     },
+                    time_warmup_end: None,
                 },
             },
         ];
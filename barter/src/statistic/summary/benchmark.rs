@@ -0,0 +1,190 @@
+use crate::{
+    Timed,
+    statistic::{metric::sharpe::SharpeRatio, time::TimeInterval},
+};
+use barter_instrument::instrument::name::InstrumentNameInternal;
+use chrono::{DateTime, TimeDelta, Utc};
+use rust_decimal::{Decimal, MathematicalOps};
+use serde::{Deserialize, Serialize};
+
+/// Comparison of the trading session performance against a buy-and-hold baseline of an
+/// instrument over the same period.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct BenchmarkSummary<Interval> {
+    /// Baseline buy-and-hold instrument.
+    pub instrument: InstrumentNameInternal,
+
+    /// Buy-and-hold return of the baseline (eg/ 0.1 for a 10% price increase).
+    pub baseline_return: Decimal,
+
+    /// Sharpe Ratio of the baseline periodic returns.
+    pub baseline_sharpe_ratio: SharpeRatio<Interval>,
+
+    /// Return of the strategy equity relative to the initial capital.
+    pub strategy_return: Decimal,
+
+    /// Strategy return in excess of the baseline return.
+    pub excess_return: Decimal,
+
+    /// Mean periodic strategy return not explained by exposure to the baseline.
+    pub alpha: Decimal,
+
+    /// Sensitivity of the periodic strategy returns to the baseline returns.
+    pub beta: Decimal,
+}
+
+/// Generator for a [`BenchmarkSummary`].
+///
+/// Periodic returns are measured between consecutive baseline prices, with the strategy equity
+/// at each price time being the latest equity observation (or the initial `capital` if none).
+///
+/// Strategy equity observations are recorded by the
+/// [`TradingSummaryGenerator`](super::TradingSummaryGenerator) from each
+/// [`PositionExited`](crate::engine::state::position::PositionExited), but can also be provided
+/// directly via [`Self::update_equity`].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct BenchmarkGenerator {
+    /// Baseline buy-and-hold instrument.
+    pub instrument: InstrumentNameInternal,
+
+    /// Initial strategy capital, in the same asset as the PnL (eg/ usdt).
+    pub capital: Decimal,
+
+    /// Baseline price series, in ascending time order.
+    pub prices: Vec<Timed<Decimal>>,
+
+    /// Strategy equity observations, in ascending time order.
+    pub equity: Vec<Timed<Decimal>>,
+}
+
+impl BenchmarkGenerator {
+    /// Construct a new [`Self`] for the baseline instrument and initial strategy capital.
+    pub fn new(instrument: InstrumentNameInternal, capital: Decimal) -> Self {
+        Self {
+            instrument,
+            capital,
+            prices: Vec::new(),
+            equity: Vec::new(),
+        }
+    }
+
+    /// Provide the baseline price series (eg/ from historical market data).
+    pub fn with_prices<Prices>(mut self, prices: Prices) -> Self
+    where
+        Prices: IntoIterator<Item = Timed<Decimal>>,
+    {
+        self.prices.extend(prices);
+        self
+    }
+
+    /// Update the [`BenchmarkGenerator`] from the next baseline price.
+    pub fn update_price(&mut self, price: Timed<Decimal>) {
+        self.prices.push(price);
+    }
+
+    /// Update the [`BenchmarkGenerator`] from the next strategy equity observation.
+    pub fn update_equity(&mut self, equity: Timed<Decimal>) {
+        self.equity.push(equity);
+    }
+
+    /// Strategy equity at the provided time.
+    pub fn equity_at(&self, time: DateTime<Utc>) -> Decimal {
+        self.equity
+            .iter()
+            .rev()
+            .find(|equity| equity.time <= time)
+            .map(|equity| equity.value)
+            .unwrap_or(self.capital)
+    }
+
+    /// Generate the latest [`BenchmarkSummary`] at the specific [`TimeInterval`], ignoring
+    /// baseline prices before `time_start`.
+    ///
+    /// Returns `None` if there are fewer than two valid baseline prices.
+    pub fn generate<Interval>(
+        &self,
+        risk_free_return: Decimal,
+        time_start: DateTime<Utc>,
+        interval: Interval,
+    ) -> Option<BenchmarkSummary<Interval>>
+    where
+        Interval: TimeInterval,
+    {
+        let prices = self
+            .prices
+            .iter()
+            .filter(|price| price.time >= time_start && price.value > Decimal::ZERO)
+            .collect::<Vec<_>>();
+
+        let [first, .., last] = prices.as_slice() else {
+            return None;
+        };
+
+        let (baseline_returns, strategy_returns): (Vec<_>, Vec<_>) = prices
+            .windows(2)
+            .map(|window| {
+                let baseline = window[1].value / window[0].value - Decimal::ONE;
+                let strategy = simple_return(
+                    self.equity_at(window[0].time),
+                    self.equity_at(window[1].time),
+                );
+                (baseline, strategy)
+            })
+            .unzip();
+
+        let baseline_mean = mean(&baseline_returns);
+        let strategy_mean = mean(&strategy_returns);
+        let baseline_variance = covariance(&baseline_returns, &baseline_returns);
+        let beta = covariance(&baseline_returns, &strategy_returns)
+            .checked_div(baseline_variance)
+            .unwrap_or_default();
+
+        let returns_period =
+            ((last.time - first.time) / baseline_returns.len() as i32).max(TimeDelta::seconds(1));
+
+        let baseline_sharpe_ratio = SharpeRatio::calculate(
+            risk_free_return,
+            baseline_mean,
+            baseline_variance.sqrt().unwrap_or_default(),
+            returns_period,
+        )
+        .scale(interval);
+
+        let baseline_return = last.value / first.value - Decimal::ONE;
+        let strategy_return = simple_return(self.capital, self.equity_at(DateTime::<Utc>::MAX_UTC));
+
+        Some(BenchmarkSummary {
+            instrument: self.instrument.clone(),
+            baseline_return,
+            baseline_sharpe_ratio,
+            strategy_return,
+            excess_return: strategy_return - baseline_return,
+            alpha: strategy_mean - beta * baseline_mean,
+            beta,
+        })
+    }
+}
+
+fn simple_return(start: Decimal, end: Decimal) -> Decimal {
+    end.checked_div(start)
+        .map(|ratio| ratio - Decimal::ONE)
+        .unwrap_or_default()
+}
+
+fn mean(values: &[Decimal]) -> Decimal {
+    values
+        .iter()
+        .sum::<Decimal>()
+        .checked_div(Decimal::from(values.len()))
+        .unwrap_or_default()
+}
+
+fn covariance(x: &[Decimal], y: &[Decimal]) -> Decimal {
+    let (x_mean, y_mean) = (mean(x), mean(y));
+    x.iter()
+        .zip(y)
+        .map(|(x, y)| (x - x_mean) * (y - y_mean))
+        .sum::<Decimal>()
+        .checked_div(Decimal::from(x.len()))
+        .unwrap_or_default()
+}
@@ -1,5 +1,5 @@
-use crate::statistic::algorithm::welford_online;
-use rust_decimal::{Decimal, MathematicalOps};
+use crate::statistic::algorithm::{WelfordAccumulator, welford_online};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Representation of a dataset using measures of dispersion - range, variance & standard deviation.
@@ -21,27 +21,27 @@ impl Dispersion {
         new_value: Decimal,
         value_count: Decimal,
     ) {
-        // Update Range
-        self.range.update(new_value);
-
         // Update Welford Online recurrence relation M
-        self.recurrence_relation_m = welford_online::calculate_recurrence_relation_m(
+        let recurrence_relation_m = welford_online::calculate_recurrence_relation_m(
             self.recurrence_relation_m,
             prev_mean,
             new_value,
             new_mean,
         );
 
-        // Update Population Variance
-        self.variance =
-            welford_online::calculate_population_variance(self.recurrence_relation_m, value_count);
+        self.update_welford(
+            &WelfordAccumulator::new(value_count, new_mean, recurrence_relation_m),
+            new_value,
+        );
+    }
 
-        // Update Standard Deviation
-        self.std_dev = self
-            .variance
-            .abs()
-            .sqrt()
-            .expect("variance cannot be negative");
+    /// Update the [`Dispersion`] from an already updated [`WelfordAccumulator`] and the new value
+    /// it accumulated.
+    pub fn update_welford(&mut self, welford: &WelfordAccumulator, new_value: Decimal) {
+        self.range.update(new_value);
+        self.recurrence_relation_m = welford.recurrence_relation_m;
+        self.variance = welford.population_variance();
+        self.std_dev = welford.population_std_dev();
     }
 }
 
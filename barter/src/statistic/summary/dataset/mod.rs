@@ -1,4 +1,4 @@
-use crate::statistic::{algorithm::welford_online, summary::dataset::dispersion::Dispersion};
+use crate::statistic::{algorithm::WelfordAccumulator, summary::dataset::dispersion::Dispersion};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
@@ -59,19 +59,22 @@ impl DataSetSummary {
     /// 3. Recalculates the mean using Welford's algorithm
     /// 4. Updates dispersion measures (range, variance, and standard deviation)
     pub fn update(&mut self, next_value: Decimal) {
-        // Increment counter
-        self.count += Decimal::ONE;
-
         // Update Sum
         self.sum += next_value;
 
-        // Update Mean
-        let prev_mean = self.mean;
-        self.mean = welford_online::calculate_mean(self.mean, next_value, self.count);
+        // Increment counter & update Mean in O(1)
+        let mut welford = self.welford();
+        welford.update(next_value);
+        self.count = welford.count;
+        self.mean = welford.mean;
 
         // Update Dispersion
-        self.dispersion
-            .update(prev_mean, self.mean, next_value, self.count);
+        self.dispersion.update_welford(&welford, next_value);
+    }
+
+    /// Current [`WelfordAccumulator`] state of the dataset (count, mean, recurrence relation M).
+    pub fn welford(&self) -> WelfordAccumulator {
+        WelfordAccumulator::new(self.count, self.mean, self.dispersion.recurrence_relation_m)
     }
 }
 
@@ -1,5 +1,7 @@
 use crate::statistic::{
-    summary::{TradingSummary, asset::TearSheetAsset, instrument::TearSheet},
+    summary::{
+        TradingSummary, asset::TearSheetAsset, exchange::ExchangeTearSheet, instrument::TearSheet,
+    },
     time::TimeInterval,
 };
 use prettytable::{Cell, Row, Table};
@@ -13,6 +15,7 @@ where
         println!();
         self.title_table().printstd();
         self.instrument_table().printstd();
+        self.exchange_table().printstd();
         self.asset_table().printstd();
     }
     fn title_table(&self) -> Table {
@@ -118,6 +121,26 @@ where
                     .unwrap()
             )
         });
+        self.add_instrument_metric_row(&mut table, "Return Geometric Mean", |ts| {
+            if let Some(geometric_mean) = ts.pnl_return_geometric_mean {
+                format!(
+                    "{:.2}%",
+                    geometric_mean.checked_mul(Decimal::ONE_HUNDRED).unwrap()
+                )
+            } else {
+                "N/A".to_string()
+            }
+        });
+        self.add_instrument_metric_row(&mut table, &format!("Compound Growth {interval}"), |ts| {
+            if let Some(growth_rate) = ts.pnl_compound_growth_rate {
+                format!(
+                    "{:.2}%",
+                    growth_rate.checked_mul(Decimal::ONE_HUNDRED).unwrap()
+                )
+            } else {
+                "N/A".to_string()
+            }
+        });
         self.add_instrument_metric_row(&mut table, &format!("Sharpe {interval}"), |ts| {
             format_ratio(ts.sharpe_ratio.value)
         });
@@ -181,6 +204,20 @@ where
                 "N/A".to_string()
             }
         });
+        self.add_instrument_metric_row(&mut table, "VaR 95%", |ts| {
+            if let Some(var) = ts.pnl_value_at_risk {
+                format!("{:.2}%", var.checked_mul(Decimal::ONE_HUNDRED).unwrap())
+            } else {
+                "N/A".to_string()
+            }
+        });
+        self.add_instrument_metric_row(&mut table, "CVaR 95%", |ts| {
+            if let Some(cvar) = ts.pnl_conditional_value_at_risk {
+                format!("{:.2}%", cvar.checked_mul(Decimal::ONE_HUNDRED).unwrap())
+            } else {
+                "N/A".to_string()
+            }
+        });
 
         table
     }
@@ -196,6 +233,47 @@ where
         table.add_row(row);
     }
 
+    pub fn exchange_table(&self) -> Table {
+        let mut table = Table::new();
+
+        // Styling
+        table.set_format(*prettytable::format::consts::FORMAT_BOX_CHARS);
+
+        // Title row spanning all columns
+        let num_columns = self.exchanges.len() + 1;
+        let mut title_row = Row::new(vec![]);
+
+        let mut title_cell = Cell::new("Exchange TearSheets").style_spec("bcB");
+        title_cell.set_hspan(num_columns);
+        title_row.add_cell(title_cell);
+        table.add_row(title_row);
+
+        // Header row (eg/ Metric | binance_spot | okx | ...)
+        let mut header_row = Row::new(vec![Cell::new("").style_spec("bcB")]);
+        for exchange in self.exchanges.keys() {
+            header_row.add_cell(Cell::new(exchange.as_str()).style_spec("bcB"));
+        }
+        table.add_row(header_row);
+
+        // Add metric rows
+        self.add_exchange_metric_row(&mut table, "PnL", |ts| format!("{:.2}", ts.pnl));
+        self.add_exchange_metric_row(&mut table, "Fees", |ts| format!("{:.2}", ts.fees));
+        self.add_exchange_metric_row(&mut table, "Trades", |ts| ts.trades.to_string());
+
+        table
+    }
+
+    fn add_exchange_metric_row<F>(&self, table: &mut Table, label: &str, format_value: F)
+    where
+        F: Fn(&ExchangeTearSheet) -> String,
+    {
+        let mut row = Row::new(vec![Cell::new(label).style_spec("bcB")]);
+        for tear_sheet in self.exchanges.values() {
+            row.add_cell(Cell::new(&format_value(tear_sheet)));
+        }
+        table.add_row(row);
+    }
+
     pub fn asset_table(&self) -> Table {
         let mut table = Table::new();
 
@@ -0,0 +1,292 @@
+use crate::{
+    Timed,
+    statistic::{
+        metric::drawdown::{
+            Drawdown, DrawdownGenerator,
+            max::{MaxDrawdown, MaxDrawdownGenerator},
+            mean::{MeanDrawdown, MeanDrawdownGenerator},
+        },
+        summary::asset::TearSheetAssetGenerator,
+    },
+};
+use barter_execution::balance::AssetBalance;
+use barter_integration::snapshot::Snapshot;
+use chrono::{DateTime, Utc};
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::hash::Hash;
+
+/// TearSheet summarising the trading session account value, with every asset balance valued in
+/// a single reference currency.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct TearSheetEquity {
+    pub equity_start: Option<Timed<Decimal>>,
+    pub equity_end: Option<Timed<Decimal>>,
+
+    /// Return of the account value between `equity_start` and `equity_end`.
+    pub equity_return: Option<Decimal>,
+
+    pub drawdown: Option<Drawdown>,
+    pub drawdown_mean: Option<MeanDrawdown>,
+    pub drawdown_max: Option<MaxDrawdown>,
+}
+
+/// Generator for a [`TearSheetEquity`].
+///
+/// Rolls the latest total balance of each asset into a reference currency equity series using
+/// the latest reference exchange rate of each asset. The `reference` asset itself has an implied
+/// rate of one.
+///
+/// A new equity point is recorded after every balance or rate update, once every asset with a
+/// non-zero balance has a known rate.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TearSheetEquityGenerator<AssetKey>
+where
+    AssetKey: Eq + Hash,
+{
+    /// Reference currency asset the equity is denominated in.
+    pub reference: AssetKey,
+
+    /// Latest total balance of each asset.
+    pub balances: FnvHashMap<AssetKey, Decimal>,
+
+    /// Latest exchange rate of each asset, in reference currency units per asset unit.
+    pub rates: FnvHashMap<AssetKey, Decimal>,
+
+    /// Reference currency equity series, in ascending time order.
+    pub equity_curve: Vec<Timed<Decimal>>,
+
+    pub drawdown: DrawdownGenerator,
+    pub drawdown_mean: MeanDrawdownGenerator,
+    pub drawdown_max: MaxDrawdownGenerator,
+}
+
+impl<AssetKey> TearSheetEquityGenerator<AssetKey>
+where
+    AssetKey: Eq + Hash,
+{
+    /// Initialise a [`TearSheetEquityGenerator`] denominated in the `reference` asset.
+    pub fn init(reference: AssetKey) -> Self {
+        Self {
+            reference,
+            balances: FnvHashMap::default(),
+            rates: FnvHashMap::default(),
+            equity_curve: Vec::new(),
+            drawdown: DrawdownGenerator::default(),
+            drawdown_mean: MeanDrawdownGenerator::default(),
+            drawdown_max: MaxDrawdownGenerator::default(),
+        }
+    }
+
+    /// Initialise a [`TearSheetEquityGenerator`] denominated in the `reference` asset, seeding
+    /// the asset balances from the current balance of each [`TearSheetAssetGenerator`].
+    pub fn init_from_assets<'a>(
+        reference: AssetKey,
+        assets: impl IntoIterator<Item = (AssetKey, &'a TearSheetAssetGenerator)>,
+    ) -> Self {
+        let mut generator = Self::init(reference);
+        generator
+            .balances
+            .extend(assets.into_iter().filter_map(|(asset, generator)| {
+                generator.balance_now.map(|balance| (asset, balance.total))
+            }));
+        generator
+    }
+
+    /// Update the [`TearSheetEquityGenerator`] from the next [`Snapshot`] [`AssetBalance`].
+    pub fn update_from_balance(&mut self, balance: Snapshot<&AssetBalance<AssetKey>>)
+    where
+        AssetKey: Clone,
+    {
+        let AssetBalance {
+            asset,
+            balance,
+            time_exchange,
+        } = balance.value();
+
+        self.balances.insert(asset.clone(), balance.total);
+        self.update_equity(*time_exchange);
+    }
+
+    /// Update the [`TearSheetEquityGenerator`] from the next reference exchange rate of the
+    /// `asset`, in reference currency units per asset unit.
+    pub fn update_from_rate(&mut self, asset: AssetKey, rate: Timed<Decimal>) {
+        self.rates.insert(asset, rate.value);
+        self.update_equity(rate.time);
+    }
+
+    /// Current reference currency equity.
+    ///
+    /// Returns `None` if any asset with a non-zero balance does not yet have a known rate.
+    pub fn equity(&self) -> Option<Decimal> {
+        self.balances
+            .iter()
+            .filter(|(_, balance)| !balance.is_zero())
+            .try_fold(Decimal::ZERO, |equity, (asset, balance)| {
+                let rate = if asset == &self.reference {
+                    Decimal::ONE
+                } else {
+                    *self.rates.get(asset)?
+                };
+
+                equity.checked_add(balance.checked_mul(rate)?)
+            })
+    }
+
+    /// Record the current equity at `time`, if every held asset has a known rate.
+    fn update_equity(&mut self, time: DateTime<Utc>) {
+        let Some(equity) = self.equity() else {
+            return;
+        };
+
+        let point = Timed::new(equity, time);
+        self.equity_curve.push(point);
+
+        if let Some(next_drawdown) = self.drawdown.update(point) {
+            self.drawdown_mean.update(&next_drawdown);
+            self.drawdown_max.update(&next_drawdown);
+        }
+    }
+
+    /// Generate the latest [`TearSheetEquity`].
+    pub fn generate(&mut self) -> TearSheetEquity {
+        let current_drawdown = self.drawdown.generate();
+        if let Some(drawdown) = &current_drawdown {
+            self.drawdown_mean.update(drawdown);
+            self.drawdown_max.update(drawdown);
+        }
+
+        let equity_start = self.equity_curve.first().copied();
+        let equity_end = self.equity_curve.last().copied();
+
+        let equity_return = equity_start
+            .as_ref()
+            .zip(equity_end.as_ref())
+            .and_then(|(start, end)| (end.value - start.value).checked_div(start.value));
+
+        TearSheetEquity {
+            equity_start,
+            equity_end,
+            equity_return,
+            drawdown: current_drawdown,
+            drawdown_mean: self.drawdown_mean.generate(),
+            drawdown_max: self.drawdown_max.generate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::time_plus_days;
+    use barter_execution::balance::Balance;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_tear_sheet_equity_generator_aggregates_assets() {
+        enum Update {
+            Rate(&'static str, Decimal),
+            Balance(&'static str, Decimal),
+        }
+
+        struct TestCase {
+            input: Update,
+            expected_equity: Option<Decimal>,
+        }
+
+        let base_time = DateTime::<Utc>::MIN_UTC;
+
+        // Holding 1 BTC & 5 ETH, valued in USDT
+        let btc =
+            TearSheetAssetGenerator::init(&Timed::new(Balance::new(dec!(1), dec!(1)), base_time));
+        let eth =
+            TearSheetAssetGenerator::init(&Timed::new(Balance::new(dec!(5), dec!(5)), base_time));
+        let mut generator =
+            TearSheetEquityGenerator::init_from_assets("usdt", [("btc", &btc), ("eth", &eth)]);
+
+        let cases = vec![
+            TestCase {
+                // TC0: no ETH rate yet, so no equity
+                input: Update::Rate("btc", dec!(100)),
+                expected_equity: None,
+            },
+            TestCase {
+                // TC1: 1 * 100 + 5 * 10
+                input: Update::Rate("eth", dec!(10)),
+                expected_equity: Some(dec!(150)),
+            },
+            TestCase {
+                // TC2: 1 * 150 + 5 * 10 (peak)
+                input: Update::Rate("btc", dec!(150)),
+                expected_equity: Some(dec!(200)),
+            },
+            TestCase {
+                // TC3: 1 * 150 + 5 * 6 (drawdown 0.1)
+                input: Update::Rate("eth", dec!(6)),
+                expected_equity: Some(dec!(180)),
+            },
+            TestCase {
+                // TC4: 1 * 120 + 5 * 6 (drawdown 0.25)
+                input: Update::Rate("btc", dec!(120)),
+                expected_equity: Some(dec!(150)),
+            },
+            TestCase {
+                // TC5: 1 * 120 + 5 * 20 (recovery above peak)
+                input: Update::Rate("eth", dec!(20)),
+                expected_equity: Some(dec!(220)),
+            },
+            TestCase {
+                // TC6: ETH balance decreases, 1 * 120 + 4 * 20 (drawdown 0.0909...)
+                input: Update::Balance("eth", dec!(4)),
+                expected_equity: Some(dec!(200)),
+            },
+            TestCase {
+                // TC7: USDT reference balance has an implied rate of one, 200 + 40
+                input: Update::Balance("usdt", dec!(40)),
+                expected_equity: Some(dec!(240)),
+            },
+        ];
+
+        let mut expected_curve = Vec::new();
+        for (index, test) in cases.into_iter().enumerate() {
+            let time = time_plus_days(base_time, index as u64 + 1);
+
+            match test.input {
+                Update::Rate(asset, rate) => {
+                    generator.update_from_rate(asset, Timed::new(rate, time));
+                }
+                Update::Balance(asset, total) => {
+                    generator.update_from_balance(Snapshot(&AssetBalance {
+                        asset,
+                        balance: Balance::new(total, total),
+                        time_exchange: time,
+                    }));
+                }
+            }
+
+            assert_eq!(generator.equity(), test.expected_equity, "TC{index} failed");
+            if let Some(equity) = test.expected_equity {
+                expected_curve.push(Timed::new(equity, time));
+            }
+        }
+
+        assert_eq!(generator.equity_curve, expected_curve);
+
+        let tear_sheet = generator.generate();
+        assert_eq!(tear_sheet.equity_start, Some(expected_curve[0]));
+        assert_eq!(tear_sheet.equity_end, expected_curve.last().copied());
+        assert_eq!(tear_sheet.equity_return, Some(dec!(0.6))); // (240 - 150) / 150
+        assert_eq!(tear_sheet.drawdown, None);
+
+        // Largest drawdown from the 200 peak (TC2) to the 150 trough (TC4), recovered at TC5
+        assert_eq!(
+            tear_sheet.drawdown_max,
+            Some(MaxDrawdown(Drawdown {
+                value: dec!(0.25),
+                time_start: time_plus_days(base_time, 3),
+                time_end: time_plus_days(base_time, 6),
+            }))
+        );
+    }
+}
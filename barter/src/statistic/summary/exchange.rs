@@ -0,0 +1,28 @@
+use crate::statistic::summary::instrument::TearSheetGenerator;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// TearSheet summarising the trading session for all the Instruments of an Exchange.
+///
+/// PnL & fees are summed in each Instrument's own units (most likely the quote asset), so
+/// they are only meaningful when the Exchange Instruments share a common quote asset.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Default, Deserialize, Serialize)]
+pub struct ExchangeTearSheet {
+    /// Sum of the Exchange Instruments realised PnL.
+    pub pnl: Decimal,
+
+    /// Sum of the Exchange Instruments entry & exit fees.
+    pub fees: Decimal,
+
+    /// Number of trades executed across the Exchange Instruments.
+    pub trades: u64,
+}
+
+impl ExchangeTearSheet {
+    /// Add the statistics of the next Exchange Instrument [`TearSheetGenerator`].
+    pub fn update_from_instrument(&mut self, tear_sheet: &TearSheetGenerator) {
+        self.pnl += tear_sheet.pnl_returns.pnl_raw;
+        self.fees += tear_sheet.fees;
+        self.trades += tear_sheet.trades;
+    }
+}
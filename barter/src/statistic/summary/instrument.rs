@@ -22,6 +22,7 @@ use crate::{
 use chrono::{DateTime, TimeDelta, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// TearSheet summarising the trading performance related to an instrument.
 #[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
@@ -36,6 +37,99 @@ pub struct TearSheet<Interval> {
     pub pnl_drawdown_max: Option<MaxDrawdown>,
     pub win_rate: Option<WinRate>,
     pub profit_factor: Option<ProfitFactor>,
+
+    /// Downside deviation of PnL returns (see [`PnLReturns::downside_deviation`]).
+    #[serde(default)]
+    pub pnl_downside_deviation: Decimal,
+
+    /// Historical Value at Risk of PnL returns at [`TEAR_SHEET_VAR_CONFIDENCE`].
+    #[serde(default)]
+    pub pnl_value_at_risk: Option<Decimal>,
+
+    /// Historical Conditional Value at Risk of PnL returns at [`TEAR_SHEET_VAR_CONFIDENCE`].
+    #[serde(default)]
+    pub pnl_conditional_value_at_risk: Option<Decimal>,
+
+    /// Geometric mean of PnL returns (see [`PnLReturns::geometric_mean_return`]).
+    #[serde(default)]
+    pub pnl_return_geometric_mean: Option<Decimal>,
+
+    /// Compound growth rate of PnL returns over the [`TearSheet`] [`TimeInterval`], eg/ the
+    /// CAGR for an annual interval (see [`PnLReturns::compound_growth_rate`]).
+    #[serde(default)]
+    pub pnl_compound_growth_rate: Option<Decimal>,
+
+    /// Periodic [`TearSheetSnapshot`]s of the key metrics during the trading session (see
+    /// [`TearSheetGenerator::with_snapshots`]).
+    #[serde(default)]
+    pub snapshots: Vec<(DateTime<Utc>, TearSheetSnapshot<Interval>)>,
+}
+
+/// Snapshot of the key [`TearSheet`] metrics at a point during the trading session.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct TearSheetSnapshot<Interval> {
+    pub pnl: Decimal,
+    pub sharpe_ratio: SharpeRatio<Interval>,
+    pub pnl_drawdown: Option<Drawdown>,
+    pub pnl_drawdown_max: Option<MaxDrawdown>,
+    pub win_rate: Option<WinRate>,
+}
+
+/// Key [`TearSheetGenerator`] state retained at a snapshot time, from which a
+/// [`TearSheetSnapshot`] is generated for the requested [`TimeInterval`].
+#[derive(Debug, Clone, PartialEq, PartialOrd, Deserialize, Serialize)]
+pub struct TearSheetCheckpoint {
+    pub time: DateTime<Utc>,
+    pub trading_period: TimeDelta,
+    pub pnl: Decimal,
+    pub pnl_returns_mean: Decimal,
+    pub pnl_returns_std_dev: Decimal,
+    pub pnl_drawdown: Option<Drawdown>,
+    pub pnl_drawdown_max: Option<MaxDrawdown>,
+    pub win_rate: Option<WinRate>,
+}
+
+impl TearSheetCheckpoint {
+    /// Generate the [`TearSheetSnapshot`] at the specific [`TimeInterval`].
+    pub fn generate<Interval>(
+        &self,
+        risk_free_return: Decimal,
+        interval: Interval,
+    ) -> TearSheetSnapshot<Interval>
+    where
+        Interval: TimeInterval,
+    {
+        TearSheetSnapshot {
+            pnl: self.pnl,
+            sharpe_ratio: SharpeRatio::calculate(
+                risk_free_return,
+                self.pnl_returns_mean,
+                self.pnl_returns_std_dev,
+                self.trading_period,
+            )
+            .scale(interval),
+            pnl_drawdown: self.pnl_drawdown.clone(),
+            pnl_drawdown_max: self.pnl_drawdown_max.clone(),
+            win_rate: self.win_rate.clone(),
+        }
+    }
+}
+
+/// Confidence level used for the [`TearSheet`] VaR & CVaR (95%).
+pub const TEAR_SHEET_VAR_CONFIDENCE: Decimal = Decimal::from_parts(95, 0, 0, false, 2);
+
+/// Maximum number of [`TearSheetCheckpoint`]s retained by a single [`TearSheetGenerator`]
+/// update.
+///
+/// If more snapshot times elapsed since the previous update, only the most recent are retained,
+/// since every checkpoint in between captures the same state.
+pub const TEAR_SHEET_MAX_CATCH_UP_CHECKPOINTS: i64 = 1_000;
+
+/// Errors that can occur when configuring a [`TearSheetGenerator`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize, Error)]
+pub enum TearSheetError {
+    #[error("TearSheetGenerator snapshot_interval must be positive: {0}")]
+    SnapshotInterval(TimeDelta),
 }
 
 /// Generator for a [`TearSheet`].
@@ -51,6 +145,27 @@ pub struct TearSheetGenerator {
     pub pnl_drawdown: DrawdownGenerator,
     pub pnl_drawdown_mean: MeanDrawdownGenerator,
     pub pnl_drawdown_max: MaxDrawdownGenerator,
+
+    /// Optional warm-up cutoff - [`PositionExited`]s entered before this time are excluded.
+    #[serde(default)]
+    pub time_warmup_end: Option<DateTime<Utc>>,
+
+    /// Optional cadence at which [`TearSheetCheckpoint`]s are retained, measured from
+    /// `time_engine_start`.
+    #[serde(default)]
+    pub snapshot_interval: Option<TimeDelta>,
+
+    /// Retained [`TearSheetCheckpoint`]s, in ascending time order.
+    #[serde(default)]
+    pub checkpoints: Vec<TearSheetCheckpoint>,
+
+    /// Sum of the [`PositionExited`] entry & exit fees.
+    #[serde(default)]
+    pub fees: Decimal,
+
+    /// Number of trades executed across every [`PositionExited`].
+    #[serde(default)]
+    pub trades: u64,
 }
 
 impl TearSheetGenerator {
@@ -63,6 +178,38 @@ impl TearSheetGenerator {
             pnl_drawdown: DrawdownGenerator::default(),
             pnl_drawdown_mean: MeanDrawdownGenerator::default(),
             pnl_drawdown_max: MaxDrawdownGenerator::default(),
+            time_warmup_end: None,
+            snapshot_interval: None,
+            checkpoints: Vec::new(),
+            fees: Decimal::ZERO,
+            trades: 0,
+        }
+    }
+
+    /// Retain a snapshot of the key metrics every `snapshot_interval` of the trading session,
+    /// included in the generated [`TearSheet`] as a [`TearSheetSnapshot`] time series.
+    ///
+    /// Returns a [`TearSheetError`] if the `snapshot_interval` is not positive.
+    pub fn with_snapshots(self, snapshot_interval: TimeDelta) -> Result<Self, TearSheetError> {
+        if snapshot_interval <= TimeDelta::zero() {
+            return Err(TearSheetError::SnapshotInterval(snapshot_interval));
+        }
+
+        Ok(Self {
+            snapshot_interval: Some(snapshot_interval),
+            ..self
+        })
+    }
+
+    /// Exclude [`PositionExited`]s entered before `time_warmup_end` from the [`TearSheet`].
+    ///
+    /// The trading session is considered to start at `time_warmup_end`.
+    pub fn with_warmup(self, time_warmup_end: DateTime<Utc>) -> Self {
+        Self {
+            time_engine_start: time_warmup_end,
+            time_engine_now: self.time_engine_now.max(time_warmup_end),
+            time_warmup_end: Some(time_warmup_end),
+            ..self
         }
     }
 
@@ -71,8 +218,19 @@ impl TearSheetGenerator {
         &mut self,
         position: &PositionExited<AssetKey, InstrumentKey>,
     ) {
+        if self
+            .time_warmup_end
+            .is_some_and(|time_warmup_end| position.time_enter < time_warmup_end)
+        {
+            return;
+        }
+
+        self.update_checkpoints(position.time_exit);
+
         self.time_engine_now = position.time_exit;
         self.pnl_returns.update(position);
+        self.fees += position.fees_enter.fees + position.fees_exit.fees;
+        self.trades += position.trades.len() as u64;
 
         if let Some(next_drawdown) = self
             .pnl_drawdown
@@ -83,8 +241,76 @@ impl TearSheetGenerator {
         }
     }
 
+    /// Retain a [`TearSheetCheckpoint`] of the current state for every snapshot time up to and
+    /// including `time`, if snapshots are enabled.
+    ///
+    /// At most [`TEAR_SHEET_MAX_CATCH_UP_CHECKPOINTS`] of the most recent snapshot times are
+    /// retained.
+    fn update_checkpoints(&mut self, time: DateTime<Utc>) {
+        let Some(snapshot_interval) = self.snapshot_interval else {
+            return;
+        };
+
+        let interval_nanos = nanos(snapshot_interval);
+        let interval_count = |delta: TimeDelta| -> Option<i64> {
+            i64::try_from(nanos(delta).checked_div(interval_nanos)?).ok()
+        };
+
+        // Index of the last snapshot time up to and including `time`
+        let Some(last) = interval_count(time.signed_duration_since(self.time_engine_start)) else {
+            return;
+        };
+
+        // Index of the next snapshot time not yet retained
+        let next = self
+            .checkpoints
+            .last()
+            .and_then(|checkpoint| interval_count(checkpoint.trading_period))
+            .map_or(1, |index| index.saturating_add(1))
+            .max(last.saturating_sub(TEAR_SHEET_MAX_CATCH_UP_CHECKPOINTS - 1));
+
+        for index in next..=last {
+            let Some(time_checkpoint) = interval_nanos
+                .checked_mul(i128::from(index))
+                .and_then(nanos_to_delta)
+                .and_then(|offset| self.time_engine_start.checked_add_signed(offset))
+            else {
+                break;
+            };
+
+            let checkpoint = self.checkpoint(time_checkpoint);
+            self.checkpoints.push(checkpoint);
+        }
+    }
+
+    /// Generate a [`TearSheetCheckpoint`] of the current state at `time`.
+    fn checkpoint(&mut self, time: DateTime<Utc>) -> TearSheetCheckpoint {
+        let pnl_drawdown = self.pnl_drawdown.generate();
+        let mut pnl_drawdown_max = self.pnl_drawdown_max.clone();
+        if let Some(pnl_drawdown) = &pnl_drawdown {
+            pnl_drawdown_max.update(pnl_drawdown);
+        }
+
+        TearSheetCheckpoint {
+            time,
+            trading_period: time.signed_duration_since(self.time_engine_start),
+            pnl: self.pnl_returns.pnl_raw,
+            pnl_returns_mean: self.pnl_returns.total.mean,
+            pnl_returns_std_dev: self.pnl_returns.total.dispersion.std_dev,
+            pnl_drawdown,
+            pnl_drawdown_max: pnl_drawdown_max.generate(),
+            win_rate: WinRate::calculate(
+                self.pnl_returns.losses.count,
+                self.pnl_returns.total.count,
+            ),
+        }
+    }
+
     /// Generate the latest [`TearSheet`] at the specific [`TimeInterval`].
     ///
+    /// If snapshots are enabled, the [`TearSheetSnapshot`] time series ends with a snapshot of
+    /// the latest state at `time_engine_now`.
+    ///
     /// For example, pass [`Annual365`](super::super::time::Annual365) to generate a crypto-centric
     /// (24/7 trading) annualised [`TearSheet`].
     pub fn generate<Interval>(
@@ -100,22 +326,41 @@ impl TearSheetGenerator {
             .signed_duration_since(self.time_engine_start)
             .max(TimeDelta::seconds(1));
 
-        let sharpe_ratio = SharpeRatio::calculate(
-            risk_free_return,
-            self.pnl_returns.total.mean,
-            self.pnl_returns.total.dispersion.std_dev,
-            trading_period,
-        )
-        .scale(interval);
+        let returns = self.pnl_returns.total.welford();
+        let loss_returns = self.pnl_returns.losses.welford();
+
+        let sharpe_ratio =
+            SharpeRatio::calculate_welford(risk_free_return, &returns, trading_period)
+                .scale(interval);
 
-        let sortino_ratio = SortinoRatio::calculate(
+        let sortino_ratio = SortinoRatio::calculate_welford(
             risk_free_return,
-            self.pnl_returns.total.mean,
-            self.pnl_returns.losses.dispersion.std_dev,
+            &returns,
+            &loss_returns,
             trading_period,
         )
         .scale(interval);
 
+        // Trailing checkpoint of the latest state, replacing any at the same time
+        let snapshots = match self.snapshot_interval {
+            Some(_) => {
+                let time_now = self.time_engine_now;
+                let trailing = self.checkpoint(time_now);
+                self.checkpoints
+                    .iter()
+                    .filter(|checkpoint| checkpoint.time < time_now)
+                    .chain(std::iter::once(&trailing))
+                    .map(|checkpoint| {
+                        (
+                            checkpoint.time,
+                            checkpoint.generate(risk_free_return, interval),
+                        )
+                    })
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+
         let current_pnl_drawdown = self.pnl_drawdown.generate();
         if let Some(current_pnl_drawdown) = &current_pnl_drawdown {
             self.pnl_drawdown_mean.update(current_pnl_drawdown);
@@ -157,6 +402,16 @@ impl TearSheetGenerator {
             pnl_drawdown_max,
             win_rate,
             profit_factor,
+            pnl_downside_deviation: self.pnl_returns.downside_deviation(),
+            pnl_value_at_risk: self.pnl_returns.value_at_risk(TEAR_SHEET_VAR_CONFIDENCE),
+            pnl_conditional_value_at_risk: self
+                .pnl_returns
+                .conditional_value_at_risk(TEAR_SHEET_VAR_CONFIDENCE),
+            pnl_return_geometric_mean: self.pnl_returns.geometric_mean_return(),
+            pnl_compound_growth_rate: self
+                .pnl_returns
+                .compound_growth_rate(trading_period, interval),
+            snapshots,
         }
     }
 
@@ -165,3 +420,173 @@ impl TearSheetGenerator {
         *self = Self::init(time_engine_start);
     }
 }
+
+/// Total nanoseconds of a [`TimeDelta`], which cannot overflow an `i128`.
+fn nanos(delta: TimeDelta) -> i128 {
+    i128::from(delta.num_seconds()) * 1_000_000_000 + i128::from(delta.subsec_nanos())
+}
+
+/// Construct a [`TimeDelta`] from total nanoseconds, returning `None` if it is out of range.
+fn nanos_to_delta(nanos: i128) -> Option<TimeDelta> {
+    let secs = i64::try_from(nanos.div_euclid(1_000_000_000)).ok()?;
+    let subsec_nanos = u32::try_from(nanos.rem_euclid(1_000_000_000)).ok()?;
+    TimeDelta::new(secs, subsec_nanos)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{statistic::time::Daily, test_utils::time_plus_days};
+    use barter_execution::trade::AssetFees;
+    use barter_instrument::{Side, asset::QuoteAsset, instrument::InstrumentIndex};
+    use rust_decimal_macros::dec;
+
+    fn position_exited(
+        time_enter: DateTime<Utc>,
+        time_exit: DateTime<Utc>,
+        pnl_realised: Decimal,
+    ) -> PositionExited<QuoteAsset> {
+        PositionExited {
+            instrument: InstrumentIndex(0),
+            side: Side::Buy,
+            price_entry_average: dec!(100),
+            quantity_abs_max: dec!(1),
+            pnl_realised,
+            fees_enter: AssetFees::quote_fees(Decimal::ZERO),
+            fees_exit: AssetFees::quote_fees(Decimal::ZERO),
+            time_enter,
+            time_exit,
+            trades: vec![],
+        }
+    }
+
+    #[test]
+    fn test_tear_sheet_snapshots_time_series() {
+        let time_start = DateTime::<Utc>::MIN_UTC;
+        let mut generator = TearSheetGenerator::init(time_start)
+            .with_snapshots(TimeDelta::days(1))
+            .unwrap();
+
+        // Positions exit mid-way through days 0, 1, 1, 2 & 5
+        let exits = [
+            (0, dec!(10)),
+            (1, dec!(-5)),
+            (1, dec!(20)),
+            (2, dec!(-10)),
+            (5, dec!(5)),
+        ];
+        for (day, pnl) in exits {
+            let time_exit = time_plus_days(time_start, day) + TimeDelta::hours(12);
+            generator.update_from_position(&position_exited(time_start, time_exit, pnl));
+        }
+
+        let tear_sheet = generator.generate(Decimal::ZERO, Daily);
+
+        // One snapshot at each whole day boundary crossed (days 1 to 5), plus a trailing
+        // snapshot of the latest state
+        assert_eq!(tear_sheet.snapshots.len(), 6);
+        assert!(
+            tear_sheet
+                .snapshots
+                .windows(2)
+                .all(|window| window[0].0 < window[1].0)
+        );
+        assert_eq!(tear_sheet.snapshots[0].0, time_plus_days(time_start, 1));
+        assert_eq!(tear_sheet.snapshots[4].0, time_plus_days(time_start, 5));
+        assert_eq!(
+            tear_sheet.snapshots[5].0,
+            time_plus_days(time_start, 5) + TimeDelta::hours(12)
+        );
+
+        // Snapshot metrics reflect the Positions exited before each snapshot time
+        let pnls = tear_sheet
+            .snapshots
+            .iter()
+            .map(|(_, snapshot)| snapshot.pnl)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pnls,
+            vec![dec!(10), dec!(25), dec!(15), dec!(15), dec!(15), dec!(20)]
+        );
+        assert_eq!(tear_sheet.pnl, dec!(20));
+
+        let (_, snapshot) = &tear_sheet.snapshots[2];
+        assert_eq!(snapshot.win_rate, Some(WinRate { value: dec!(0.5) }));
+        assert!(snapshot.pnl_drawdown_max.is_some());
+    }
+
+    #[test]
+    fn test_tear_sheet_snapshots_disabled_by_default() {
+        let time_start = DateTime::<Utc>::MIN_UTC;
+        let mut generator = TearSheetGenerator::init(time_start);
+
+        generator.update_from_position(&position_exited(
+            time_start,
+            time_plus_days(time_start, 3),
+            dec!(10),
+        ));
+
+        assert!(
+            generator
+                .generate(Decimal::ZERO, Daily)
+                .snapshots
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_tear_sheet_snapshots_rejects_non_positive_interval() {
+        let generator = TearSheetGenerator::init(DateTime::<Utc>::MIN_UTC);
+
+        assert_eq!(
+            generator.clone().with_snapshots(TimeDelta::zero()),
+            Err(TearSheetError::SnapshotInterval(TimeDelta::zero()))
+        );
+        assert_eq!(
+            generator.with_snapshots(TimeDelta::seconds(-1)),
+            Err(TearSheetError::SnapshotInterval(TimeDelta::seconds(-1)))
+        );
+    }
+
+    #[test]
+    fn test_tear_sheet_snapshots_bounded_catch_up() {
+        // Tiny interval over a long gap only retains the most recent snapshot times
+        let time_start = DateTime::<Utc>::MIN_UTC;
+        let mut generator = TearSheetGenerator::init(time_start)
+            .with_snapshots(TimeDelta::nanoseconds(1))
+            .unwrap();
+
+        let time_exit = time_plus_days(time_start, 365);
+        generator.update_from_position(&position_exited(time_start, time_exit, dec!(10)));
+        generator.update_from_position(&position_exited(time_start, time_exit, dec!(10)));
+
+        assert_eq!(
+            generator.checkpoints.len() as i64,
+            TEAR_SHEET_MAX_CATCH_UP_CHECKPOINTS
+        );
+        assert_eq!(
+            generator
+                .checkpoints
+                .last()
+                .map(|checkpoint| checkpoint.time),
+            Some(time_exit)
+        );
+
+        // Snapshot times near the end of the representable time range do not overflow
+        let time_start = DateTime::<Utc>::MAX_UTC - TimeDelta::days(1);
+        let mut generator = TearSheetGenerator::init(time_start)
+            .with_snapshots(TimeDelta::MAX)
+            .unwrap();
+
+        generator.update_from_position(&position_exited(
+            time_start,
+            DateTime::<Utc>::MAX_UTC,
+            dec!(10),
+        ));
+        assert!(generator.checkpoints.is_empty());
+
+        let tear_sheet = generator.generate(Decimal::ZERO, Daily);
+        assert_eq!(tear_sheet.snapshots.len(), 1);
+        assert_eq!(tear_sheet.snapshots[0].0, DateTime::<Utc>::MAX_UTC);
+    }
+}
@@ -8,13 +8,19 @@
 //! - **TradingSummary**: 交易摘要，包含所有交易对和资产的统计信息
 //! - **TearSheet**: 交易对摘要，包含单个交易对的详细统计
 //! - **TearSheetAsset**: 资产摘要，包含单个资产的统计信息
+//! - **TearSheetEquity**: 账户权益摘要，按参考货币汇率汇总所有资产余额的权益曲线统计
+//! - **ExchangeTearSheet**: 交易所摘要，汇总单个交易所所有交易对的盈亏、手续费和成交数
 //! - **PnLReturns**: 盈亏收益率统计
+//! - **BenchmarkSummary**: 与买入持有基准的比较
 
 use crate::{
+    Timed,
     engine::state::{asset::AssetStates, instrument::InstrumentStates, position::PositionExited},
     statistic::{
         summary::{
             asset::{TearSheetAsset, TearSheetAssetGenerator},
+            benchmark::{BenchmarkGenerator, BenchmarkSummary},
+            exchange::ExchangeTearSheet,
             instrument::{TearSheet, TearSheetGenerator},
         },
         time::TimeInterval,
@@ -23,6 +29,7 @@ use crate::{
 use barter_execution::balance::AssetBalance;
 use barter_instrument::{
     asset::{AssetIndex, ExchangeAsset, name::AssetNameInternal},
+    exchange::ExchangeId,
     instrument::{InstrumentIndex, name::InstrumentNameInternal},
 };
 use barter_integration::{collection::FnvIndexMap, snapshot::Snapshot};
@@ -34,12 +41,21 @@ use serde::{Deserialize, Serialize};
 /// 资产摘要模块。
 pub mod asset;
 
+/// 买入持有基准比较模块。
+pub mod benchmark;
+
 /// 数据集统计模块。
 pub mod dataset;
 
 /// 显示格式化模块。
 pub mod display;
 
+/// 参考货币账户权益摘要模块。
+pub mod equity;
+
+/// 交易所摘要模块。
+pub mod exchange;
+
 /// 交易对摘要模块。
 pub mod instrument;
 
@@ -61,6 +77,8 @@ pub mod pnl;
 /// - **time_engine_end**: 交易会话结束时间
 /// - **instruments**: 交易对摘要映射
 /// - **assets**: 资产摘要映射
+/// - **exchanges**: 交易所摘要映射
+/// - **benchmark**: 可选的买入持有基准比较
 ///
 /// ## 注意事项
 ///
@@ -82,6 +100,14 @@ pub struct TradingSummary<Interval> {
 
     /// [`ExchangeAsset`] [`TearSheet`] 映射。
     pub assets: FnvIndexMap<ExchangeAsset<AssetNameInternal>, TearSheetAsset>,
+
+    /// [`ExchangeId`] [`ExchangeTearSheet`] 映射，汇总每个交易所所有交易对的统计信息。
+    #[serde(default)]
+    pub exchanges: FnvIndexMap<ExchangeId, ExchangeTearSheet>,
+
+    /// 可选的买入持有基准 [`BenchmarkSummary`]（参见 [`TradingSummaryGenerator::with_benchmark`]）。
+    #[serde(default)]
+    pub benchmark: Option<BenchmarkSummary<Interval>>,
 }
 
 impl<Interval> TradingSummary<Interval> {
@@ -107,7 +133,9 @@ impl<Interval> TradingSummary<Interval> {
 /// - **time_engine_start**: 交易会话开始时间
 /// - **time_engine_now**: 交易会话最新更新时间
 /// - **instruments**: 交易对摘要生成器映射
+/// - **instrument_exchanges**: 交易对所属交易所映射（用于生成交易所摘要）
 /// - **assets**: 资产摘要生成器映射
+/// - **benchmark**: 可选的买入持有基准生成器
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Constructor)]
 pub struct TradingSummaryGenerator {
     /// 零风险投资的理论收益率。
@@ -131,8 +159,16 @@ pub struct TradingSummaryGenerator {
     /// 将由不同的 [`TearSheet`] 汇总。
     pub instruments: FnvIndexMap<InstrumentNameInternal, TearSheetGenerator>,
 
+    /// 交易对 [`ExchangeId`] 映射，用于将交易对统计信息汇总为 [`ExchangeTearSheet`]。
+    #[serde(default)]
+    pub instrument_exchanges: FnvIndexMap<InstrumentNameInternal, ExchangeId>,
+
     /// [`ExchangeAsset`] [`TearSheetAssetGenerator`] 映射。
     pub assets: FnvIndexMap<ExchangeAsset<AssetNameInternal>, TearSheetAssetGenerator>,
+
+    /// 可选的买入持有基准 [`BenchmarkGenerator`]。
+    #[serde(default)]
+    pub benchmark: Option<BenchmarkGenerator>,
 }
 
 impl TradingSummaryGenerator {
@@ -159,11 +195,65 @@ impl TradingSummaryGenerator {
                     )
                 })
                 .collect(),
+            instrument_exchanges: instruments
+                .0
+                .values()
+                .map(|state| {
+                    // 交易所特定的报价资产标识了交易对所属的交易所
+                    let (quote, _) = assets
+                        .0
+                        .get_index(state.instrument.underlying.quote.index())
+                        .unwrap_or_else(|| {
+                            panic!(
+                                "AssetStates does not contain: {}",
+                                state.instrument.underlying.quote
+                            )
+                        });
+
+                    (state.instrument.name_internal.clone(), quote.exchange)
+                })
+                .collect(),
             assets: assets
                 .0
                 .iter()
                 .map(|(asset, state)| (asset.clone(), state.statistics.clone()))
                 .collect(),
+            benchmark: None,
+        }
+    }
+
+    /// 使用买入持有基准比较交易会话的绩效。
+    ///
+    /// 生成的 [`TradingSummary`] 将包含 [`BenchmarkSummary`]，其中包括基准收益率、
+    /// 基准 Sharpe Ratio、策略超额收益率以及 alpha 和 beta。
+    ///
+    /// # 参数
+    ///
+    /// - `benchmark`: 基准生成器（例如使用 [`BenchmarkGenerator::with_prices`] 提供历史价格序列）
+    pub fn with_benchmark(self, benchmark: BenchmarkGenerator) -> Self {
+        Self {
+            benchmark: Some(benchmark),
+            ..self
+        }
+    }
+
+    /// 使用下一个基准价格更新 [`TradingSummaryGenerator`]（如果配置了基准）。
+    pub fn update_benchmark_price(&mut self, price: Timed<Decimal>) {
+        if let Some(benchmark) = &mut self.benchmark {
+            benchmark.update_price(price);
+        }
+    }
+
+    /// Start the trading session at `time_warmup_end`, excluding the preceding warm-up period.
+    ///
+    /// Note that the instrument & asset generators must also be warmed up (eg/ via
+    /// [`EngineState::with_warmup`](crate::engine::state::EngineState::with_warmup)) to exclude
+    /// warm-up trades & balances from their statistics.
+    pub fn with_warmup(self, time_warmup_end: DateTime<Utc>) -> Self {
+        Self {
+            time_engine_start: time_warmup_end,
+            time_engine_now: self.time_engine_now.max(time_warmup_end),
+            ..self
         }
     }
 
@@ -184,7 +274,18 @@ impl TradingSummaryGenerator {
         }
 
         self.instrument_mut(&position.instrument)
-            .update_from_position(position)
+            .update_from_position(position);
+
+        // 记录基准比较使用的策略权益
+        if let Some(benchmark) = &mut self.benchmark {
+            let pnl = self
+                .instruments
+                .values()
+                .map(|tear_sheet| tear_sheet.pnl_returns.pnl_raw)
+                .sum::<Decimal>();
+
+            benchmark.update_equity(Timed::new(benchmark.capital + pnl, position.time_exit));
+        }
     }
 
     /// Update the [`TradingSummaryGenerator`] from the next [`Snapshot`] [`AssetBalance`].
@@ -225,11 +326,30 @@ impl TradingSummaryGenerator {
             .map(|(asset, tear_sheet)| (asset.clone(), tear_sheet.generate()))
             .collect();
 
+        let exchanges = self.instruments.iter().fold(
+            FnvIndexMap::<ExchangeId, ExchangeTearSheet>::default(),
+            |mut exchanges, (instrument, tear_sheet)| {
+                if let Some(exchange) = self.instrument_exchanges.get(instrument) {
+                    exchanges
+                        .entry(*exchange)
+                        .or_default()
+                        .update_from_instrument(tear_sheet);
+                }
+                exchanges
+            },
+        );
+
+        let benchmark = self.benchmark.as_ref().and_then(|benchmark| {
+            benchmark.generate(self.risk_free_return, self.time_engine_start, interval)
+        });
+
         TradingSummary {
             time_engine_start: self.time_engine_start,
             time_engine_end: self.time_engine_now,
             instruments,
             assets,
+            exchanges,
+            benchmark,
         }
     }
 }
@@ -306,3 +426,265 @@ impl AssetTearSheetManager<ExchangeAsset<AssetNameInternal>> for TradingSummaryG
             .unwrap_or_else(|| panic!("TradingSummaryGenerator does not contain: {key:?}"))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{
+            EngineState, global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+            position::PositionExited,
+        },
+        statistic::time::Daily,
+        test_utils::time_plus_days,
+    };
+    use barter_execution::{
+        AccountEvent, AccountEventKind,
+        order::id::{OrderId, StrategyId},
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use barter_instrument::{
+        Side,
+        asset::QuoteAsset,
+        exchange::{ExchangeId, ExchangeIndex},
+        index::IndexedInstruments,
+        test_utils::instrument,
+    };
+    use rust_decimal_macros::dec;
+
+    fn trade(day: u64, side: Side, price: Decimal) -> AccountEvent {
+        AccountEvent {
+            exchange: ExchangeIndex(0),
+            kind: AccountEventKind::Trade(Trade {
+                id: TradeId::new(format!("{day}")),
+                order_id: OrderId::new(format!("{day}")),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                time_exchange: time_plus_days(DateTime::<Utc>::MIN_UTC, day),
+                side,
+                price,
+                quantity: dec!(1),
+                fees: AssetFees::quote_fees(Decimal::ZERO),
+                reduce_only: false,
+            }),
+        }
+    }
+
+    fn position_exited(
+        exchange: ExchangeId,
+        instrument: &str,
+        day: u64,
+        pnl: Decimal,
+        fees: Decimal,
+    ) -> PositionExited<QuoteAsset, InstrumentNameInternal> {
+        PositionExited {
+            instrument: InstrumentNameInternal::new_from_exchange(exchange, instrument),
+            side: Side::Buy,
+            price_entry_average: dec!(100),
+            quantity_abs_max: dec!(1),
+            pnl_realised: pnl,
+            fees_enter: AssetFees::quote_fees(fees),
+            fees_exit: AssetFees::quote_fees(fees),
+            time_enter: time_plus_days(DateTime::<Utc>::MIN_UTC, day),
+            time_exit: time_plus_days(DateTime::<Utc>::MIN_UTC, day + 1),
+            trades: vec![
+                TradeId::new(format!("{exchange}-{instrument}-{day}-enter")),
+                TradeId::new(format!("{exchange}-{instrument}-{day}-exit")),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_trading_summary_aggregates_exchanges() {
+        let time_start = DateTime::<Utc>::MIN_UTC;
+
+        let instruments = IndexedInstruments::new([
+            instrument(ExchangeId::BinanceSpot, "btc", "usdt"),
+            instrument(ExchangeId::Okx, "btc", "usdt"),
+            instrument(ExchangeId::BinanceSpot, "eth", "usdt"),
+        ]);
+        let state = EngineState::builder(&instruments, DefaultGlobalData, |_| {
+            DefaultInstrumentMarketData::default()
+        })
+        .time_engine_start(time_start)
+        .build();
+
+        let mut generator = TradingSummaryGenerator::init(
+            Decimal::ZERO,
+            time_start,
+            time_start,
+            &state.instruments,
+            &state.assets,
+        );
+
+        // BinanceSpot btc_usdt: pnl +10, fees 2, 2 trades
+        generator.update_from_position(&position_exited(
+            ExchangeId::BinanceSpot,
+            "btc_usdt",
+            1,
+            dec!(10),
+            dec!(1),
+        ));
+        // Okx btc_usdt: pnl -3, fees 1, 2 trades
+        generator.update_from_position(&position_exited(
+            ExchangeId::Okx,
+            "btc_usdt",
+            1,
+            dec!(-3),
+            dec!(0.5),
+        ));
+        // BinanceSpot eth_usdt: pnl +5 & -1, fees 2 + 0.4, 4 trades
+        generator.update_from_position(&position_exited(
+            ExchangeId::BinanceSpot,
+            "eth_usdt",
+            2,
+            dec!(5),
+            dec!(1),
+        ));
+        generator.update_from_position(&position_exited(
+            ExchangeId::BinanceSpot,
+            "eth_usdt",
+            4,
+            dec!(-1),
+            dec!(0.2),
+        ));
+
+        let summary = generator.generate(Daily);
+
+        assert_eq!(summary.exchanges.len(), 2);
+        assert_eq!(
+            summary.exchanges.get(&ExchangeId::BinanceSpot).unwrap(),
+            &ExchangeTearSheet {
+                pnl: dec!(14),
+                fees: dec!(4.4),
+                trades: 6,
+            }
+        );
+        assert_eq!(
+            summary.exchanges.get(&ExchangeId::Okx).unwrap(),
+            &ExchangeTearSheet {
+                pnl: dec!(-3),
+                fees: dec!(1),
+                trades: 2,
+            }
+        );
+
+        // Exchange PnL sums to the total Instrument PnL
+        assert_eq!(
+            summary.exchanges.values().map(|ts| ts.pnl).sum::<Decimal>(),
+            summary
+                .instruments
+                .values()
+                .map(|ts| ts.pnl)
+                .sum::<Decimal>()
+        );
+    }
+
+    #[test]
+    fn test_trading_summary_excludes_warmup_positions() {
+        let time_start = DateTime::<Utc>::MIN_UTC;
+        let time_warmup_end = time_plus_days(time_start, 10);
+
+        let instruments =
+            IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]);
+        let mut state = EngineState::builder(&instruments, DefaultGlobalData, |_| {
+            DefaultInstrumentMarketData::default()
+        })
+        .time_engine_start(time_start)
+        .build()
+        .with_warmup(time_warmup_end);
+
+        // Position entered & exited during warm-up: pnl +10 (excluded)
+        state.update_from_account(&trade(1, Side::Buy, dec!(100)));
+        assert!(
+            state
+                .update_from_account(&trade(2, Side::Sell, dec!(110)))
+                .is_some()
+        );
+
+        // Position entered after warm-up: pnl -5 (included)
+        state.update_from_account(&trade(11, Side::Buy, dec!(100)));
+        assert!(
+            state
+                .update_from_account(&trade(12, Side::Sell, dec!(95)))
+                .is_some()
+        );
+
+        let summary = TradingSummaryGenerator::init(
+            Decimal::ZERO,
+            time_start,
+            time_plus_days(time_start, 20),
+            &state.instruments,
+            &state.assets,
+        )
+        .with_warmup(time_warmup_end)
+        .generate(Daily);
+
+        assert_eq!(summary.time_engine_start, time_warmup_end);
+        let tear_sheet = summary.instruments.values().next().unwrap();
+        assert_eq!(tear_sheet.pnl, dec!(-5));
+        assert_eq!(
+            state
+                .instruments
+                .instrument_index(&InstrumentIndex(0))
+                .tear_sheet
+                .pnl_returns
+                .total
+                .count,
+            dec!(1)
+        );
+    }
+
+    #[test]
+    fn test_trading_summary_benchmark_against_buy_and_hold() {
+        let time_start = DateTime::<Utc>::MIN_UTC;
+
+        let instruments =
+            IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]);
+        let mut state = EngineState::builder(&instruments, DefaultGlobalData, |_| {
+            DefaultInstrumentMarketData::default()
+        })
+        .time_engine_start(time_start)
+        .build();
+
+        // Baseline price path: 100 -> 100 -> 110 -> 121 (buy-and-hold return 21%)
+        let benchmark = BenchmarkGenerator::new(
+            InstrumentNameInternal::new("binance_spot-btc_usdt"),
+            dec!(1000),
+        )
+        .with_prices(
+            [dec!(100), dec!(100), dec!(110), dec!(121)]
+                .into_iter()
+                .enumerate()
+                .map(|(day, price)| Timed::new(price, time_plus_days(time_start, day as u64))),
+        );
+
+        let mut generator = TradingSummaryGenerator::init(
+            Decimal::ZERO,
+            time_start,
+            time_start,
+            &state.instruments,
+            &state.assets,
+        )
+        .with_benchmark(benchmark);
+
+        // Strategy buys on day 1 @ 100 and sells on day 2 @ 110: pnl +10 (equity 1000 -> 1010)
+        state.update_from_account(&trade(1, Side::Buy, dec!(100)));
+        let position = state
+            .update_from_account(&trade(2, Side::Sell, dec!(110)))
+            .unwrap();
+        generator.update_from_position(&position);
+        generator.update_time_now(time_plus_days(time_start, 3));
+
+        let benchmark = generator.generate(Daily).benchmark.unwrap();
+
+        assert_eq!(benchmark.baseline_return, dec!(0.21));
+        assert_eq!(benchmark.strategy_return, dec!(0.01));
+        assert_eq!(benchmark.excess_return, dec!(-0.20));
+
+        // Baseline returns [0, 0.1, 0.1] vs strategy returns [0, 0.01, 0]
+        assert_eq!(benchmark.beta.round_dp(10), dec!(0.05));
+        assert_eq!(benchmark.alpha.round_dp(10), dec!(0));
+        assert!(benchmark.baseline_sharpe_ratio.value > Decimal::ZERO);
+    }
+}
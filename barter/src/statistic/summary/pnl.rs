@@ -1,9 +1,15 @@
 use crate::{
     engine::state::position::{PositionExited, calculate_pnl_return},
-    statistic::summary::dataset::DataSetSummary,
+    statistic::{summary::dataset::DataSetSummary, time::TimeInterval},
 };
-use rust_decimal::Decimal;
+use chrono::TimeDelta;
+use rust_decimal::{Decimal, MathematicalOps, prelude::ToPrimitive};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Maximum number of the most recent PnL returns retained by [`PnLReturns`] for the empirical
+/// distribution of returns.
+pub const PNL_RETURNS_MAX_SAMPLES: usize = 10_000;
 
 /// Records Profit and Loss (PnL) data.
 ///
@@ -11,6 +17,9 @@ use serde::{Deserialize, Serialize};
 /// - Raw PnL.
 /// - Statistical summaries of returns for all closed positions (wins and losses combined)
 /// - Statistical summaries of returns for all losing closed positions (useful for downside risk analysis).
+/// - The compounded equity curve of all returns (geometric mean return & CAGR).
+/// - The empirical distribution of the most recent [`PNL_RETURNS_MAX_SAMPLES`] returns, used for
+///   historical Value at Risk (VaR) & Conditional Value at Risk (CVaR).
 ///
 /// # Asset Denomination
 /// The raw PnL values can be denominated in different assets depending on the context:
@@ -36,6 +45,32 @@ pub struct PnLReturns {
 
     /// PnL returns statistical summary for losses only.
     pub losses: DataSetSummary,
+
+    /// Compounded growth of the equity curve of every closed position, ie/ the product of
+    /// `1 + return` for every return.
+    ///
+    /// `None` if there are no returns, or the compounded growth overflowed.
+    #[serde(default)]
+    pub growth: Option<Decimal>,
+
+    /// PnL returns of the most recent [`PNL_RETURNS_MAX_SAMPLES`] closed positions, in the order
+    /// they were closed.
+    ///
+    /// Samples are serialised so the empirical distribution survives a round-trip. Only the most
+    /// recent [`PNL_RETURNS_MAX_SAMPLES`] samples are kept when deserialising.
+    #[serde(default, deserialize_with = "de_pnl_returns_samples")]
+    pub returns: VecDeque<Decimal>,
+}
+
+/// Deserialise [`PnLReturns::returns`], keeping only the most recent
+/// [`PNL_RETURNS_MAX_SAMPLES`] samples.
+fn de_pnl_returns_samples<'de, D>(deserializer: D) -> Result<VecDeque<Decimal>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let mut returns = VecDeque::<Decimal>::deserialize(deserializer)?;
+    returns.drain(..returns.len().saturating_sub(PNL_RETURNS_MAX_SAMPLES));
+    Ok(returns)
 }
 
 impl PnLReturns {
@@ -46,16 +81,335 @@ impl PnLReturns {
     ) {
         self.pnl_raw += position.pnl_realised;
 
-        let pnl_return = calculate_pnl_return(
+        self.update_return(calculate_pnl_return(
             position.pnl_realised,
             position.price_entry_average,
             position.quantity_abs_max,
-        );
+        ));
+    }
 
+    /// Update the `PnLReturns` from the next PnL return.
+    fn update_return(&mut self, pnl_return: Decimal) {
         self.total.update(pnl_return);
 
         if pnl_return.is_sign_negative() {
             self.losses.update(pnl_return)
         }
+
+        let factor = Decimal::ONE + pnl_return;
+        self.growth = if self.total.count == Decimal::ONE {
+            Some(factor)
+        } else {
+            self.growth.and_then(|growth| growth.checked_mul(factor))
+        };
+
+        if self.returns.len() >= PNL_RETURNS_MAX_SAMPLES {
+            self.returns.pop_front();
+        }
+        self.returns.push_back(pnl_return);
+    }
+
+    /// Downside deviation of PnL returns (standard deviation of losing returns only).
+    ///
+    /// This is the risk measure used by the [`SortinoRatio`](crate::statistic::metric::sortino::SortinoRatio).
+    pub fn downside_deviation(&self) -> Decimal {
+        self.losses.dispersion.std_dev
+    }
+
+    /// Compounded growth of the equity curve, ie/ the product of `1 + return` for every return.
+    ///
+    /// Returns `None` if there are no returns, or the compounded growth overflows.
+    pub fn compounded_growth(&self) -> Option<Decimal> {
+        self.growth
+    }
+
+    /// Geometric mean of PnL returns, ie/ the constant per-position return that compounds to the
+    /// same equity curve: `compounded_growth ^ (1 / n) - 1` for `n` returns.
+    ///
+    /// Unlike the arithmetic mean return, this does not overstate compounded performance.
+    ///
+    /// Returns `None` if there are no returns, or the equity curve is wiped out (ie/ compounded
+    /// growth is not positive).
+    pub fn geometric_mean_return(&self) -> Option<Decimal> {
+        let exponent = Decimal::ONE.checked_div(self.total.count)?;
+        self.compounded_return_scaled(exponent)
+    }
+
+    /// Compound growth rate of PnL returns realised over the `returns_period`, scaled to the
+    /// provided [`TimeInterval`]: `compounded_growth ^ periods - 1`, where `periods` is the number
+    /// of `returns_period`s in the interval (see [`TimeInterval::periods`]).
+    ///
+    /// For example, pass [`Annual365`](crate::statistic::time::Annual365) to calculate the
+    /// Compound Annual Growth Rate (CAGR).
+    ///
+    /// Returns `None` if there are no returns, the `returns_period` is not positive, or the equity
+    /// curve is wiped out (ie/ compounded growth is not positive).
+    pub fn compound_growth_rate<Interval>(
+        &self,
+        returns_period: TimeDelta,
+        interval: Interval,
+    ) -> Option<Decimal>
+    where
+        Interval: TimeInterval,
+    {
+        if returns_period <= TimeDelta::zero() {
+            return None;
+        }
+
+        let exponent = interval.periods(returns_period)?;
+        self.compounded_return_scaled(exponent)
+    }
+
+    /// Returns `compounded_growth ^ exponent - 1`.
+    fn compounded_return_scaled(&self, exponent: Decimal) -> Option<Decimal> {
+        let growth = self.compounded_growth()?;
+        if growth <= Decimal::ZERO {
+            return None;
+        }
+
+        growth
+            .checked_powd(exponent)
+            .map(|scaled| scaled - Decimal::ONE)
+    }
+
+    /// Historical Value at Risk (VaR) of PnL returns at the provided `confidence` (eg/ 0.95).
+    ///
+    /// VaR is the loss of the `k`th worst return, where `k = ceil((1 - confidence) * n)` for the
+    /// `n` most recent returns (see [`PNL_RETURNS_MAX_SAMPLES`]). It is expressed as a positive
+    /// value for a loss.
+    ///
+    /// Returns `None` if there are no returns, or `confidence` is not in the range (0, 1).
+    pub fn value_at_risk(&self, confidence: Decimal) -> Option<Decimal> {
+        self.tail(confidence)
+            .and_then(|tail| tail.last().copied())
+            .map(|var| -var)
+    }
+
+    /// Historical Conditional Value at Risk (CVaR, aka Expected Shortfall) of PnL returns at the
+    /// provided `confidence` (eg/ 0.95).
+    ///
+    /// CVaR is the mean loss of the `k` worst returns, where `k = ceil((1 - confidence) * n)` for
+    /// the `n` most recent returns (see [`PNL_RETURNS_MAX_SAMPLES`]). It is expressed as a
+    /// positive value for a loss.
+    ///
+    /// Returns `None` if there are no returns, or `confidence` is not in the range (0, 1).
+    pub fn conditional_value_at_risk(&self, confidence: Decimal) -> Option<Decimal> {
+        let tail = self.tail(confidence)?;
+        let sum = tail.iter().sum::<Decimal>();
+        sum.checked_div(Decimal::from(tail.len())).map(|cvar| -cvar)
+    }
+
+    /// Returns the `ceil((1 - confidence) * n)` worst returns, sorted from worst to best.
+    fn tail(&self, confidence: Decimal) -> Option<Vec<Decimal>> {
+        if self.returns.is_empty() || confidence <= Decimal::ZERO || confidence >= Decimal::ONE {
+            return None;
+        }
+
+        let len = ((Decimal::ONE - confidence) * Decimal::from(self.returns.len()))
+            .ceil()
+            .to_usize()?
+            .clamp(1, self.returns.len());
+
+        let mut tail = self.returns.iter().copied().collect::<Vec<_>>();
+        tail.select_nth_unstable(len - 1);
+        tail.truncate(len);
+        tail.sort_unstable();
+        Some(tail)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statistic::time::Annual365;
+    use rust_decimal_macros::dec;
+
+    fn pnl_returns(returns: impl IntoIterator<Item = Decimal>) -> PnLReturns {
+        let mut pnl_returns = PnLReturns::default();
+        for pnl_return in returns {
+            pnl_returns.update_return(pnl_return);
+        }
+        pnl_returns
+    }
+
+    #[test]
+    fn test_pnl_returns_value_at_risk() {
+        // 40 returns: 36 x 0.01, plus -0.02, -0.04, -0.06 & -0.08
+        // 95% tail: ceil(0.05 * 40) = 2 worst returns -> [-0.08, -0.06]
+        let returns = pnl_returns(std::iter::repeat_n(dec!(0.01), 36).chain([
+            dec!(-0.06),
+            dec!(-0.02),
+            dec!(-0.08),
+            dec!(-0.04),
+        ]));
+
+        assert_eq!(returns.value_at_risk(dec!(0.95)), Some(dec!(0.06)));
+        assert_eq!(
+            returns.conditional_value_at_risk(dec!(0.95)),
+            Some(dec!(0.07))
+        );
+
+        // 90% tail: ceil(0.1 * 40) = 4 worst returns -> [-0.08, -0.06, -0.04, -0.02]
+        assert_eq!(returns.value_at_risk(dec!(0.90)), Some(dec!(0.02)));
+        assert_eq!(
+            returns.conditional_value_at_risk(dec!(0.90)),
+            Some(dec!(0.05))
+        );
+
+        assert_eq!(
+            returns.downside_deviation(),
+            returns.losses.dispersion.std_dev
+        );
+    }
+
+    #[test]
+    fn test_pnl_returns_geometric_mean_return_and_cagr() {
+        struct TestCase {
+            returns: Vec<Decimal>,
+            returns_period: TimeDelta,
+            expected_growth: Option<Decimal>,
+            expected_geometric_mean: Option<Decimal>,
+            expected_cagr: Option<Decimal>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: +10%, -50%, +120% compounds to 1.1 * 0.5 * 2.2 = 1.21 over 2 years
+                // geometric mean = 1.21 ^ (1/3) - 1 = 0.0656...
+                // CAGR = 1.21 ^ (1/2) - 1 = 0.1
+                returns: vec![dec!(0.1), dec!(-0.5), dec!(1.2)],
+                returns_period: TimeDelta::days(730),
+                expected_growth: Some(dec!(1.21)),
+                expected_geometric_mean: Some(dec!(0.065602)),
+                expected_cagr: Some(dec!(0.1)),
+            },
+            TestCase {
+                // TC1: +20%, -20% compounds to a loss of 1.2 * 0.8 = 0.96 over half a year
+                // geometric mean = 0.96 ^ (1/2) - 1 = -0.020204...
+                // CAGR = 0.96 ^ 2 - 1 = -0.0784
+                returns: vec![dec!(0.2), dec!(-0.2)],
+                returns_period: TimeDelta::days(365) / 2,
+                expected_growth: Some(dec!(0.96)),
+                expected_geometric_mean: Some(dec!(-0.020204)),
+                expected_cagr: Some(dec!(-0.0784)),
+            },
+            TestCase {
+                // TC2: -100% return wipes out the equity curve
+                returns: vec![dec!(0.5), dec!(-1)],
+                returns_period: TimeDelta::days(365),
+                expected_growth: Some(Decimal::ZERO),
+                expected_geometric_mean: None,
+                expected_cagr: None,
+            },
+            TestCase {
+                // TC3: no returns
+                returns: vec![],
+                returns_period: TimeDelta::days(365),
+                expected_growth: None,
+                expected_geometric_mean: None,
+                expected_cagr: None,
+            },
+            TestCase {
+                // TC4: zero length returns period
+                returns: vec![dec!(0.1)],
+                returns_period: TimeDelta::zero(),
+                expected_growth: Some(dec!(1.1)),
+                expected_geometric_mean: Some(dec!(0.1)),
+                expected_cagr: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let returns = pnl_returns(test.returns);
+
+            assert_eq!(
+                returns.compounded_growth(),
+                test.expected_growth,
+                "TC{index} compounded growth failed"
+            );
+            assert_eq!(
+                returns
+                    .geometric_mean_return()
+                    .map(|value| value.round_dp(6)),
+                test.expected_geometric_mean,
+                "TC{index} geometric mean failed"
+            );
+            assert_eq!(
+                returns
+                    .compound_growth_rate(test.returns_period, Annual365)
+                    .map(|value| value.round_dp(6)),
+                test.expected_cagr,
+                "TC{index} CAGR failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_pnl_returns_value_at_risk_invalid() {
+        assert_eq!(PnLReturns::default().value_at_risk(dec!(0.95)), None);
+        assert_eq!(
+            PnLReturns::default().conditional_value_at_risk(dec!(0.95)),
+            None
+        );
+
+        let returns = pnl_returns([dec!(-0.01)]);
+        assert_eq!(returns.value_at_risk(Decimal::ONE), None);
+        assert_eq!(returns.value_at_risk(Decimal::ZERO), None);
+        assert_eq!(returns.value_at_risk(dec!(0.99)), Some(dec!(0.01)));
+    }
+
+    #[test]
+    fn test_pnl_returns_caps_samples_and_serialises_them() {
+        // Oldest return is the only loss, and is evicted from the samples
+        let returns = pnl_returns(
+            std::iter::once(dec!(-0.9))
+                .chain(std::iter::repeat_n(dec!(0.0001), PNL_RETURNS_MAX_SAMPLES)),
+        );
+
+        assert_eq!(returns.returns.len(), PNL_RETURNS_MAX_SAMPLES);
+        assert_eq!(returns.value_at_risk(dec!(0.95)), Some(dec!(-0.0001)));
+
+        // Compounded growth & geometric mean still include every return
+        let growth = returns.compounded_growth().unwrap();
+        assert!(growth < Decimal::ONE);
+        assert!(returns.geometric_mean_return().unwrap() < Decimal::ZERO);
+
+        let serialised = serde_json::to_string(&returns).unwrap();
+        let deserialised = serde_json::from_str::<PnLReturns>(&serialised).unwrap();
+        assert_eq!(deserialised, returns);
+        assert_eq!(
+            deserialised.value_at_risk(dec!(0.95)),
+            returns.value_at_risk(dec!(0.95))
+        );
+    }
+
+    #[test]
+    fn test_pnl_returns_deserialise_caps_samples() {
+        // Missing samples (eg/ serialised before samples were recorded) default to empty
+        let deserialised = serde_json::from_value::<PnLReturns>(serde_json::json!({
+            "pnl_raw": "0",
+            "total": DataSetSummary::default(),
+            "losses": DataSetSummary::default(),
+        }))
+        .unwrap();
+        assert!(deserialised.returns.is_empty());
+
+        // Oversized sample buffers keep only the most recent samples
+        let returns = PnLReturns {
+            returns: std::iter::once(dec!(-0.9))
+                .chain(std::iter::repeat_n(dec!(0.0001), PNL_RETURNS_MAX_SAMPLES))
+                .collect(),
+            ..PnLReturns::default()
+        };
+
+        let serialised = serde_json::to_string(&returns).unwrap();
+        let deserialised = serde_json::from_str::<PnLReturns>(&serialised).unwrap();
+        assert_eq!(deserialised.returns.len(), PNL_RETURNS_MAX_SAMPLES);
+        assert!(
+            deserialised
+                .returns
+                .iter()
+                .all(|value| *value == dec!(0.0001))
+        );
     }
 }
@@ -8,9 +8,12 @@
 //! - **TimeInterval**: Trait，定义时间间隔接口
 //! - **Annual365**: 365 天年化间隔（适用于加密货币等 24/7 交易）
 //! - **Annual252**: 252 天年化间隔（适用于传统市场，每年 252 个交易日）
+//! - **AnnualBusinessCalendar**: 按交易日历年化的间隔（适用于代币化股票等非 24/7 交易的交易对）
 //! - **Daily**: 日间隔
 
 use chrono::TimeDelta;
+use derive_more::Constructor;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use smol_str::{SmolStr, format_smolstr};
 use std::fmt::Debug;
@@ -60,6 +63,19 @@ pub trait TimeInterval: Debug + Copy {
     ///
     /// 返回 `TimeDelta`，表示时间间隔的持续时间。
     fn interval(&self) -> TimeDelta;
+
+    /// 返回此时间间隔包含的 `source` 周期数量，用于将按 `source` 周期计算的指标缩放到此
+    /// 时间间隔。
+    ///
+    /// 默认按自然时间计算，即 `interval / source`。
+    ///
+    /// # 返回值
+    ///
+    /// 如果 `source` 为零，返回 `None`。
+    fn periods(&self, source: TimeDelta) -> Option<Decimal> {
+        Decimal::from(self.interval().num_seconds().abs())
+            .checked_div(Decimal::from(source.num_seconds().abs()))
+    }
 }
 
 /// 365 天年化时间间隔。
@@ -98,6 +114,73 @@ impl TimeInterval for Annual252 {
     }
 }
 
+/// 按交易日历年化的时间间隔。
+///
+/// 适用于遵循交易所交易日历的交易对（例如代币化股票）。年化间隔为每年的交易日数量，
+/// 缩放时按交易时间而不是 24/7 的自然时间计算周期数量：
+///
+/// - 日及以上的周期（例如 [`Daily`]）：每个周期日对应一个交易日，因此日收益的夏普比率
+///   按 `sqrt(trading_days_per_year)` 年化
+/// - 日内周期（例如 1 小时）：只计入交易时间，每个交易日包含 `hours_per_day / 周期`
+///   个周期
+///
+/// `AnnualBusinessCalendar::new(252, dec!(24))` 与 [`Annual252`] 等价。
+///
+/// # 使用示例
+///
+/// ```rust
+/// use barter::statistic::time::{AnnualBusinessCalendar, TimeInterval};
+/// use chrono::TimeDelta;
+/// use rust_decimal_macros::dec;
+///
+/// // 美股交易日历：每年 252 个交易日，每个交易日 6.5 小时
+/// let calendar = AnnualBusinessCalendar::new(252, dec!(6.5));
+/// assert_eq!(calendar.name().as_str(), "Annual(252 x 6.5h)");
+/// assert_eq!(calendar.interval().num_days(), 252);
+///
+/// // 每年 252 个日周期，以及 252 * 6.5 个小时周期
+/// assert_eq!(calendar.periods(TimeDelta::days(1)), Some(dec!(252)));
+/// assert_eq!(calendar.periods(TimeDelta::hours(1)), Some(dec!(1638)));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Deserialize, Serialize, Constructor)]
+pub struct AnnualBusinessCalendar {
+    /// 每年的交易日数量
+    pub trading_days_per_year: u32,
+    /// 每个交易日的交易小时数
+    pub hours_per_day: Decimal,
+}
+
+impl TimeInterval for AnnualBusinessCalendar {
+    /// 返回 "Annual({trading_days_per_year} x {hours_per_day}h)"。
+    fn name(&self) -> SmolStr {
+        format_smolstr!(
+            "Annual({} x {}h)",
+            self.trading_days_per_year,
+            self.hours_per_day.normalize()
+        )
+    }
+
+    /// 返回 `trading_days_per_year` 天的 TimeDelta（每个交易日对应一个自然日）。
+    fn interval(&self) -> TimeDelta {
+        TimeDelta::days(i64::from(self.trading_days_per_year))
+    }
+
+    /// 返回每年包含的 `source` 周期数量，日内周期只计入交易时间。
+    fn periods(&self, source: TimeDelta) -> Option<Decimal> {
+        let source_secs = Decimal::from(source.num_seconds().abs());
+        let day_secs = Decimal::from(TimeDelta::days(1).num_seconds());
+        let trading_days = Decimal::from(self.trading_days_per_year);
+
+        if source_secs >= day_secs {
+            trading_days.checked_div(source_secs / day_secs)
+        } else {
+            trading_days
+                .checked_mul(self.hours_per_day.checked_mul(Decimal::from(3600))?)?
+                .checked_div(source_secs)
+        }
+    }
+}
+
 /// 日时间间隔。
 ///
 /// 表示单个交易日的时间间隔。
@@ -127,3 +210,54 @@ impl TimeInterval for TimeDelta {
         *self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::statistic::metric::{sharpe::SharpeRatio, sortino::SortinoRatio};
+    use rust_decimal::MathematicalOps;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_annual_business_calendar_annualisation_vs_annual_252() {
+        let daily_sharpe = SharpeRatio {
+            value: dec!(0.1),
+            interval: Daily,
+        };
+        let daily_sortino = SortinoRatio {
+            value: dec!(0.1),
+            interval: Daily,
+        };
+
+        // TC0: daily returns annualise by sqrt(252) for every 252 trading day calendar, however
+        // many hours are traded per day
+        for hours_per_day in [dec!(24), dec!(6.5)] {
+            let calendar = AnnualBusinessCalendar::new(252, hours_per_day);
+            assert_eq!(calendar.interval(), Annual252.interval());
+            assert_eq!(
+                daily_sharpe.clone().scale(calendar).value,
+                daily_sharpe.clone().scale(Annual252).value
+            );
+            assert_eq!(
+                daily_sortino.clone().scale(calendar).value,
+                daily_sortino.clone().scale(Annual252).value
+            );
+        }
+
+        // TC1: hourly returns of an equity-like calendar (252 x 6.5h) annualise over trading
+        // hours only, so by sqrt(252 * 6.5) rather than sqrt(252 * 24)
+        let hourly_sharpe = SharpeRatio {
+            value: dec!(0.1),
+            interval: TimeDelta::hours(1),
+        };
+        let calendar = AnnualBusinessCalendar::new(252, dec!(6.5));
+
+        let expected = dec!(0.1) * dec!(1638).sqrt().unwrap();
+        let actual = hourly_sharpe.clone().scale(calendar).value;
+        assert!((actual - expected).abs() < dec!(0.0000001));
+
+        let expected_ratio = (dec!(6.5) / dec!(24)).sqrt().unwrap();
+        let actual_ratio = actual / hourly_sharpe.scale(Annual252).value;
+        assert!((actual_ratio - expected_ratio).abs() < dec!(0.0000001));
+    }
+}
@@ -20,16 +20,14 @@
 use crate::engine::state::{
     EngineState,
     instrument::{InstrumentState, data::InstrumentDataState, filter::InstrumentFilter},
-    position::Position,
+    position::{Position, PositionMode},
 };
 use barter_execution::order::{
     OrderKey, OrderKind, TimeInForce,
     id::{ClientOrderId, StrategyId},
     request::{OrderRequestCancel, OrderRequestOpen, RequestOpen},
 };
-use barter_instrument::{
-    Side, asset::AssetIndex, exchange::ExchangeIndex, instrument::InstrumentIndex,
-};
+use barter_instrument::{asset::AssetIndex, exchange::ExchangeIndex, instrument::InstrumentIndex};
 use rust_decimal::Decimal;
 
 /// 生成用于平仓的开仓和取消订单请求的策略接口。
@@ -132,12 +130,13 @@ pub trait ClosePositionsStrategy<
 
 /// 使用市价单平仓的简单 `ClosePositionsStrategy` 逻辑。
 ///
-/// 此函数查找所有开放仓位，并生成相等但方向相反的 `Side` 市价单来平仓。
+/// 此函数查找所有开放仓位，并生成相等但方向相反的 `Side` 市价单来平仓。对冲模式
+/// （[`PositionMode::Hedging`]）下，多头和空头仓位分别生成一个 reduce-only 平仓订单。
 ///
 /// ## 工作原理
 ///
 /// 1. 根据过滤器筛选交易对
-///// 2. 对于每个有开放仓位的交易对，生成反向市价单
+/// 2. 对于每个有开放仓位的交易对，为每个仓位生成反向市价单
 /// 3. 使用 IOC（立即成交或取消）订单类型确保快速执行
 ///
 /// ## 注意事项
@@ -176,22 +175,34 @@ where
     AssetIndex: 'a,
     InstrumentIndex: 'a,
 {
-    // 为每个有开放仓位的交易对生成平仓订单
+    // 为每个有开放仓位的交易对的每个仓位（对冲模式下包括多头和空头两边）生成平仓订单
     let open_requests = state
         .instruments
         .instruments(filter)
-        .filter_map(move |state| {
-            // 只有当存在仓位且有市场数据时才生成订单
-            let position = state.position.current.as_ref()?;
-            let price = state.data.price()?;
+        .flat_map(move |state| {
+            // 只有当有市场数据时才生成订单
+            let price = state.data.price();
+
+            // 对冲模式下，平仓订单必须是 reduce-only，否则会开立反方向的新仓位
+            let reduce_only = state.position.mode == PositionMode::Hedging;
+
+            state.position.positions().filter_map(move |position| {
+                let order = build_ioc_market_order_to_close_position(
+                    state.instrument.exchange,
+                    position,
+                    strategy_id.clone(),
+                    price?,
+                    || gen_cid(state),
+                );
 
-            Some(build_ioc_market_order_to_close_position(
-                state.instrument.exchange,
-                position,
-                strategy_id.clone(),
-                price,
-                || gen_cid(state),
-            ))
+                Some(OrderRequestOpen {
+                    state: RequestOpen {
+                        reduce_only,
+                        ..order.state
+                    },
+                    ..order
+                })
+            })
         });
 
     (std::iter::empty(), open_requests)
@@ -260,15 +271,117 @@ where
         },
         state: RequestOpen {
             // 生成与仓位方向相反的订单方向
-            side: match position.side {
-                Side::Buy => Side::Sell,
-                Side::Sell => Side::Buy,
-            },
+            side: position.side.opposite(),
             price,
             // 使用仓位的绝对数量
             quantity: position.quantity_abs,
             kind: OrderKind::Market,
             time_in_force: TimeInForce::ImmediateOrCancel,
+            reduce_only: false,
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::state::{
+        global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData,
+    };
+    use barter_data::{books::Level, subscription::book::OrderBookL1};
+    use barter_execution::{
+        order::id::OrderId,
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use barter_instrument::{
+        Side, exchange::ExchangeId, index::IndexedInstruments, test_utils::instrument,
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    type State = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn state(mode: PositionMode) -> State {
+        let instruments =
+            IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]);
+
+        let mut state = EngineState::builder(&instruments, DefaultGlobalData, |_| {
+            DefaultInstrumentMarketData::default()
+        })
+        .time_engine_start(DateTime::<Utc>::MIN_UTC)
+        .position_mode(mode)
+        .build();
+
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .data
+            .l1 = OrderBookL1::new(
+            DateTime::<Utc>::MIN_UTC,
+            Some(Level::new(dec!(100), dec!(1))),
+            Some(Level::new(dec!(100), dec!(1))),
+        );
+
+        state
+    }
+
+    fn fill(state: &mut State, side: Side, quantity: Decimal) {
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .position
+            .update_from_trade(&Trade {
+                id: TradeId::new("trade"),
+                order_id: OrderId::new("order"),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("strategy"),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                side,
+                price: dec!(100),
+                quantity,
+                fees: AssetFees::quote_fees(Decimal::ZERO),
+                reduce_only: false,
+            });
+    }
+
+    fn close_requests(state: &State) -> Vec<(Side, Decimal, bool)> {
+        let strategy_id = StrategyId::new("strategy");
+        let (cancels, opens) = close_open_positions_with_market_orders(
+            &strategy_id,
+            state,
+            &InstrumentFilter::None,
+            |_| ClientOrderId::new("close"),
+        );
+        assert_eq!(cancels.into_iter().count(), 0);
+
+        opens
+            .into_iter()
+            .map(|order| {
+                (
+                    order.state.side,
+                    order.state.quantity,
+                    order.state.reduce_only,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_close_open_positions_with_market_orders_closes_every_hedged_leg() {
+        // Netting: Buy 2 then Sell 1 nets to a single LONG 1
+        let mut netting = state(PositionMode::Netting);
+        fill(&mut netting, Side::Buy, dec!(2));
+        fill(&mut netting, Side::Sell, dec!(1));
+        assert_eq!(close_requests(&netting), vec![(Side::Sell, dec!(1), false)]);
+
+        // Hedging: Buy 2 then Sell 1 opens independent LONG 2 & SHORT 1 legs, each closed by
+        // its own reduce-only order
+        let mut hedging = state(PositionMode::Hedging);
+        fill(&mut hedging, Side::Buy, dec!(2));
+        fill(&mut hedging, Side::Sell, dec!(1));
+        assert_eq!(
+            close_requests(&hedging),
+            vec![(Side::Sell, dec!(2), true), (Side::Buy, dec!(1), true)]
+        );
+    }
+}
@@ -0,0 +1,428 @@
+//! 定投（DCA）策略模块
+//!
+//! 本模块定义了 [`Dca`]，一个定期定额买入（dollar-cost averaging）的 [`AlgoStrategy`] 实现，
+//! 用于累积仓位的场景：无论价格如何，按固定时间间隔买入固定名义价值，直到累计成交名义价值
+//! 达到目标值。
+//!
+//! # 工作原理
+//!
+//! 1. 第 `k` 次买入计划在 `start + k * interval` 发出，`start` 为构造时的 [`EngineClock::time`]
+//! 2. 累计成交名义价值取自 [`EngineState`] 中该交易对多头仓位的入场名义价值
+//!    （`price_entry_average * quantity_abs`），而不是由调用方手动跟踪成交
+//! 3. 每当有新的买入到期时，生成一个 `ImmediateOrCancel` `Market` 买单，名义价值为到期买入的计划
+//!    累计名义价值（不超过目标值）减去累计成交名义价值（时钟跳跃时合并为一个订单）
+//! 4. 只有成交才会消耗买入计划：被拒绝或未成交的买入名义价值会计入下一次到期的买单
+//! 5. 一旦累计成交名义价值达到目标值，Dca 停止生成买单
+
+use crate::{
+    engine::{
+        clock::EngineClock,
+        state::{EngineState, instrument::data::InstrumentDataState, position::PositionManager},
+    },
+    strategy::algo::AlgoStrategy,
+};
+use barter_execution::order::{
+    OrderKey, OrderKind, TimeInForce,
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestCancel, OrderRequestOpen, RequestOpen},
+};
+use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{cell::Cell, marker::PhantomData};
+
+/// [`Dca`] 的定投参数。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// // 每天买入 1000 usdt 的 BTC，累计买入 10000 usdt 后停止
+/// let config = DcaConfig::new(InstrumentIndex(0), dec!(1000), TimeDelta::days(1), dec!(10000));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize, Constructor)]
+pub struct DcaConfig {
+    /// 买入的交易对。
+    pub instrument: InstrumentIndex,
+    /// 每次买入的名义价值（计价资产，正数）。
+    pub notional: Decimal,
+    /// 相邻两次买入之间的时间间隔。
+    pub interval: TimeDelta,
+    /// 目标累计成交名义价值，达到后停止买入。
+    pub target: Decimal,
+}
+
+/// 定期定额买入（DCA）[`AlgoStrategy`]。
+///
+/// 按 [`DcaConfig`] 的时间间隔发出 `ImmediateOrCancel` `Market` 买单，直到 [`EngineState`] 中
+/// 该交易对多头仓位的入场名义价值达到目标值。
+///
+/// Dca 假设它是该交易对多头仓位的唯一来源：已有的多头仓位会计入累计成交名义价值。
+///
+/// 如果交易对没有价格数据，或本策略在该交易对上仍有活跃订单（例如在途请求），则本次不生成买单。
+///
+/// ## 类型参数
+///
+/// - `Clock`: 用于确定买入时间的 [`EngineClock`]
+/// - `GlobalData`: 全局数据类型
+/// - `InstrumentData`: 交易对数据类型，提供当前价格
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let strategy = Dca::new(StrategyId::new("dca"), config, clock.clone());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dca<Clock, GlobalData, InstrumentData> {
+    /// 买单使用的策略 ID，用于成交归因。
+    pub strategy: StrategyId,
+    /// 定投参数。
+    pub config: DcaConfig,
+    /// 用于确定买入时间的 Engine 时钟。
+    pub clock: Clock,
+    /// 定投开始时间（第一次买入的计划时间）。
+    pub start: DateTime<Utc>,
+    buys_sent: Cell<usize>,
+    phantom: PhantomData<(GlobalData, InstrumentData)>,
+}
+
+impl<Clock, GlobalData, InstrumentData> Dca<Clock, GlobalData, InstrumentData>
+where
+    Clock: EngineClock,
+{
+    /// 构造新的 [`Dca`]，使用 [`EngineClock::time`] 作为定投开始时间。
+    ///
+    /// # 参数
+    ///
+    /// - `strategy`: 买单使用的策略 ID
+    /// - `config`: 定投参数
+    /// - `clock`: Engine 时钟
+    pub fn new(strategy: StrategyId, config: DcaConfig, clock: Clock) -> Self {
+        Self {
+            strategy,
+            config,
+            start: clock.time(),
+            clock,
+            buys_sent: Cell::new(0),
+            phantom: PhantomData,
+        }
+    }
+
+    /// 返回已生成买单的到期买入次数（时钟跳跃时合并的买入分别计数）。
+    pub fn buys_sent(&self) -> usize {
+        self.buys_sent.get()
+    }
+
+    /// 返回在给定时间已经到期的买入次数。
+    pub fn buys_due(&self, time: DateTime<Utc>) -> usize {
+        if time < self.start {
+            return 0;
+        }
+
+        let interval = self.config.interval.num_milliseconds();
+        if interval <= 0 {
+            return 1;
+        }
+
+        let elapsed = (time - self.start).num_milliseconds();
+        usize::try_from(elapsed / interval)
+            .unwrap_or(usize::MAX)
+            .saturating_add(1)
+    }
+
+    /// 返回给定次数的买入到期时计划的累计名义价值，不超过目标值。
+    pub fn notional_scheduled(&self, buys: usize) -> Decimal {
+        self.config
+            .notional
+            .checked_mul(Decimal::from(buys))
+            .map_or(self.config.target, |notional| {
+                notional.min(self.config.target)
+            })
+    }
+
+    /// 返回剩余的目标名义价值。
+    ///
+    /// # 参数
+    ///
+    /// - `notional_filled`: 累计成交名义价值（参见 [`notional_filled`]）
+    pub fn notional_remaining(&self, notional_filled: Decimal) -> Decimal {
+        (self.config.target - notional_filled).max(Decimal::ZERO)
+    }
+
+    /// 如果累计成交名义价值已达到目标值，返回 `true`。
+    pub fn is_finished(&self, notional_filled: Decimal) -> bool {
+        self.notional_remaining(notional_filled).is_zero()
+    }
+
+    /// 生成当前到期的买单。
+    ///
+    /// 买单名义价值为到期买入的计划累计名义价值减去累计成交名义价值，因此之前被拒绝或未成交的
+    /// 买入会计入本次买单。
+    ///
+    /// 如果没有新的买入到期、已达到计划名义价值或价格无效，返回 `None`。
+    ///
+    /// # 参数
+    ///
+    /// - `exchange`: 交易对的交易所
+    /// - `price`: 当前价格，用于将名义价值换算为数量
+    /// - `notional_filled`: 累计成交名义价值（参见 [`notional_filled`]）
+    /// - `gen_cid`: 生成客户端订单 ID 的函数
+    pub fn generate_buy(
+        &self,
+        exchange: ExchangeIndex,
+        price: Decimal,
+        notional_filled: Decimal,
+        gen_cid: impl Fn() -> ClientOrderId,
+    ) -> Option<OrderRequestOpen> {
+        if self.is_finished(notional_filled) || price <= Decimal::ZERO {
+            return None;
+        }
+
+        let buys_due = self.buys_due(self.clock.time());
+        if buys_due <= self.buys_sent.get() {
+            return None;
+        }
+        self.buys_sent.set(buys_due);
+
+        let notional = self.notional_scheduled(buys_due) - notional_filled;
+        if notional <= Decimal::ZERO {
+            return None;
+        }
+
+        Some(OrderRequestOpen {
+            key: OrderKey {
+                exchange,
+                instrument: self.config.instrument,
+                strategy: self.strategy.clone(),
+                cid: gen_cid(),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                price,
+                quantity: notional / price,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        })
+    }
+}
+
+/// 返回 [`PositionManager`] 中多头仓位的入场名义价值（`price_entry_average * quantity_abs`）。
+pub fn notional_filled(position: &PositionManager) -> Decimal {
+    position
+        .positions()
+        .filter(|position| position.side == Side::Buy)
+        .filter_map(|position| {
+            position
+                .price_entry_average
+                .checked_mul(position.quantity_abs)
+        })
+        .sum()
+}
+
+impl<Clock, GlobalData, InstrumentData> AlgoStrategy for Dca<Clock, GlobalData, InstrumentData>
+where
+    Clock: EngineClock,
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) {
+        let instrument = state.instruments.instrument_index(&self.config.instrument);
+
+        let has_active_orders = instrument
+            .orders
+            .active
+            .values()
+            .any(|order| order.key.strategy == self.strategy);
+
+        let opens = match instrument.data.price() {
+            Some(price) if !has_active_orders => self.generate_buy(
+                instrument.instrument.exchange,
+                price,
+                notional_filled(&instrument.position),
+                ClientOrderId::random,
+            ),
+            _ => None,
+        };
+
+        (std::iter::empty(), opens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData},
+        test_utils::{TestClock, spot_engine_state, trade_from_request},
+    };
+    use barter_data::{books::Level, subscription::book::OrderBookL1};
+    use barter_execution::order::{Order, state::ActiveOrderState, state::OpenInFlight};
+    use rust_decimal_macros::dec;
+
+    type State = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn state() -> State {
+        let mut state = spot_engine_state(&["btc"]);
+
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .data
+            .l1 = OrderBookL1::new(
+            DateTime::<Utc>::MIN_UTC,
+            Some(Level::new(dec!(99), dec!(1))),
+            Some(Level::new(dec!(101), dec!(1))),
+        );
+
+        state
+    }
+
+    fn generate(
+        strategy: &Dca<TestClock, DefaultGlobalData, DefaultInstrumentMarketData>,
+        state: &State,
+    ) -> Vec<OrderRequestOpen> {
+        let (cancels, opens) = strategy.generate_algo_orders(state);
+        assert_eq!(cancels.into_iter().count(), 0);
+        opens.into_iter().collect()
+    }
+
+    #[test]
+    fn test_dca_buys_fixed_notional_each_interval_until_target() {
+        struct TestCase {
+            advance: TimeDelta,
+            expected_buy: Option<Decimal>,
+            filled: bool,
+        }
+
+        let clock = TestClock::new(DateTime::<Utc>::MIN_UTC);
+        let mut state = state();
+        let strategy = Dca::new(
+            StrategyId::new("dca"),
+            DcaConfig::new(
+                InstrumentIndex(0),
+                dec!(1000),
+                TimeDelta::days(1),
+                dec!(2500),
+            ),
+            clock.clone(),
+        );
+
+        let cases = vec![
+            TestCase {
+                // TC0: first buy due at start, 1000 notional at price 100
+                advance: TimeDelta::zero(),
+                expected_buy: Some(dec!(10)),
+                filled: true,
+            },
+            TestCase {
+                // TC1: next buy not due yet
+                advance: TimeDelta::hours(12),
+                expected_buy: None,
+                filled: false,
+            },
+            TestCase {
+                // TC2: second buy due, but it is rejected so nothing fills
+                advance: TimeDelta::hours(12),
+                expected_buy: Some(dec!(10)),
+                filled: false,
+            },
+            TestCase {
+                // TC3: rejected buy is not retried before the next buy is due
+                advance: TimeDelta::hours(12),
+                expected_buy: None,
+                filled: false,
+            },
+            TestCase {
+                // TC4: third buy due, covering the unfilled second buy, capped at the target
+                // 2500 - 1000 filled
+                advance: TimeDelta::hours(12),
+                expected_buy: Some(dec!(15)),
+                filled: true,
+            },
+            TestCase {
+                // TC5: target reached, so no further buys
+                advance: TimeDelta::days(1),
+                expected_buy: None,
+                filled: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            clock.advance(test.advance);
+
+            let opens = generate(&strategy, &state);
+            let buy = opens.first();
+            assert_eq!(
+                buy.map(|buy| buy.state.quantity),
+                test.expected_buy,
+                "TC{index} failed"
+            );
+
+            if let Some(buy) = buy {
+                assert_eq!(buy.key.strategy, StrategyId::new("dca"), "TC{index} failed");
+                assert_eq!(buy.state.side, Side::Buy, "TC{index} failed");
+                assert_eq!(buy.state.kind, OrderKind::Market, "TC{index} failed");
+
+                if test.filled {
+                    state
+                        .instruments
+                        .instrument_index_mut(&InstrumentIndex(0))
+                        .position
+                        .update_from_trade(&trade_from_request(buy, buy.state.quantity));
+                }
+            }
+        }
+
+        let position = &state
+            .instruments
+            .instrument_index(&InstrumentIndex(0))
+            .position;
+        assert_eq!(notional_filled(position), dec!(2500));
+        assert!(strategy.is_finished(notional_filled(position)));
+
+        // Active orders of the strategy pause buying
+        let mut state = self::state();
+        let strategy = Dca::new(
+            StrategyId::new("dca"),
+            DcaConfig::new(
+                InstrumentIndex(0),
+                dec!(1000),
+                TimeDelta::days(1),
+                dec!(2500),
+            ),
+            clock.clone(),
+        );
+        let in_flight = Order {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("dca"),
+                cid: ClientOrderId::random(),
+            },
+            side: Side::Buy,
+            price: dec!(100),
+            quantity: dec!(10),
+            kind: OrderKind::Market,
+            time_in_force: TimeInForce::ImmediateOrCancel,
+            reduce_only: false,
+            state: ActiveOrderState::OpenInFlight(OpenInFlight),
+        };
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .orders
+            .active
+            .insert(in_flight.key.cid.clone(), in_flight);
+        assert!(generate(&strategy, &state).is_empty());
+    }
+}
@@ -0,0 +1,18 @@
+//! Strategy 执行算法模块
+//!
+//! 本模块提供将大额"父订单"拆分为多个"子订单"的执行算法，用于降低大额订单对市场的冲击。
+//! 执行算法本身不决定交易方向，它们只负责"如何"以及"何时"执行一个已经确定的父订单。
+//!
+//! # 核心概念
+//!
+//! - **Twap**: 时间加权平均价格（TWAP）执行算法，将父订单在给定时长内均匀拆分为多个子订单
+//!
+//! # 使用场景
+//!
+//! 执行算法通常被 [`AlgoStrategy`](super::algo::AlgoStrategy) 实现持有，在每次
+//! `generate_algo_orders` 调用时生成到期的子订单，并通过 `Trade` 事件跟踪已成交数量。
+
+pub use twap::{Twap, TwapParent};
+
+/// 时间加权平均价格（TWAP）执行算法。
+pub mod twap;
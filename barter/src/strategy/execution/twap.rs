@@ -0,0 +1,574 @@
+//! TWAP 执行算法模块
+//!
+//! 本模块定义了 [`Twap`] 时间加权平均价格执行算法。Twap 将一个大额父订单在给定时长内
+//! 拆分为固定数量的子订单（切片），并按照 [`EngineClock::time`] 提供的时间依次发出。
+//!
+//! # 工作原理
+//!
+//! 1. 父订单的时长被均匀划分为 `slices` 个时间段，第 `k` 个切片计划在
+//!    `start + k * slice_interval` 发出
+//! 2. 每次调用 [`Twap::generate_slice`] 时，Twap 检查当前时间有多少切片已经到期，
+//!    并为所有未发出的到期切片生成一个子订单
+//! 3. 通过 [`Twap::update_from_trade`] 跟踪 `Trade` 事件中的已成交数量
+//! 4. 子订单数量基于"剩余未成交数量 / 剩余切片数"计算，因此前一个切片的成交缺口会被
+//!    平均分摊到后续切片中，而不是一次性追单（降低市场冲击），最后一个切片会发出全部剩余数量
+//! 5. 剩余未成交数量会扣除 Twap 仍在途或挂单中的子订单的未成交数量，避免超额执行父订单
+//! 6. 只有实际发出子订单时，切片才被视为已发出；如果所有切片都已到期后仍有未成交且未在途的
+//!    数量（例如最后一个切片未成交即过期），Twap 会继续发出剩余数量，避免执行不足
+//! 7. 一旦父订单完全成交，Twap 会提前停止，不再生成子订单
+
+use crate::engine::{clock::EngineClock, state::order::Orders};
+use barter_execution::{
+    order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::{ClientOrderId, OrderId, StrategyId},
+        request::{OrderRequestOpen, RequestOpen},
+        state::ActiveOrderState,
+    },
+    trade::Trade,
+};
+use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use chrono::{DateTime, TimeDelta, Utc};
+use derive_more::Constructor;
+use fnv::FnvHashMap;
+use rust_decimal::Decimal;
+
+/// 由 [`Twap`] 执行的父订单。
+///
+/// ## 类型参数
+///
+/// - `ExchangeKey`: 交易所键类型（默认为 [`ExchangeIndex`]）
+/// - `InstrumentKey`: 交易对键类型（默认为 [`InstrumentIndex`]）
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// // 在 1 小时内分 12 个切片买入 10 BTC
+/// let parent = TwapParent::new(
+///     ExchangeIndex(0),
+///     InstrumentIndex(0),
+///     Side::Buy,
+///     dec!(10),
+///     TimeDelta::hours(1),
+///     12,
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Constructor)]
+pub struct TwapParent<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    /// 父订单的交易所。
+    pub exchange: ExchangeKey,
+    /// 父订单的交易对。
+    pub instrument: InstrumentKey,
+    /// 父订单的方向。
+    pub side: Side,
+    /// 父订单的总数量（正数）。
+    pub quantity: Decimal,
+    /// 执行父订单的总时长。
+    pub duration: TimeDelta,
+    /// 父订单被拆分的切片数量（子订单数量）。
+    pub slices: usize,
+}
+
+/// 时间加权平均价格（TWAP）执行算法。
+///
+/// Twap 将 [`TwapParent`] 在其时长内拆分为 `slices` 个 `ImmediateOrCancel` `Market` 子订单，
+/// 并通过 `Trade` 事件跟踪已成交数量，以避免过度执行或执行不足。
+///
+/// 所有子订单都携带 Twap 的 [`StrategyId`]，以便将成交归因到该执行算法。
+///
+/// ## 类型参数
+///
+/// - `ExchangeKey`: 交易所键类型（默认为 [`ExchangeIndex`]）
+/// - `InstrumentKey`: 交易对键类型（默认为 [`InstrumentIndex`]）
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let mut twap = Twap::new(StrategyId::new("twap"), parent, &clock);
+///
+/// // 在 AlgoStrategy::generate_algo_orders 中
+/// let opens = twap.generate_slice(&clock, &instrument_state.orders, price, ClientOrderId::random);
+///
+/// // 在处理 AccountEvent 时
+/// twap.update_from_trade(&trade);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Twap<ExchangeKey = ExchangeIndex, InstrumentKey = InstrumentIndex> {
+    /// 子订单使用的策略 ID，用于成交归因。
+    pub strategy: StrategyId,
+    /// 被执行的父订单。
+    pub parent: TwapParent<ExchangeKey, InstrumentKey>,
+    /// 执行开始时间（第一个切片的计划时间）。
+    pub start: DateTime<Utc>,
+    /// 已发出的切片数量。
+    pub slices_sent: usize,
+    /// 已成交数量（来自 `Trade` 事件）。
+    pub quantity_filled: Decimal,
+    /// 每个子订单的已成交数量（来自 `Trade` 事件），用于避免重复计算挂单中已成交的部分。
+    pub quantity_filled_by_order: FnvHashMap<OrderId, Decimal>,
+}
+
+impl<ExchangeKey, InstrumentKey> Twap<ExchangeKey, InstrumentKey> {
+    /// 构造新的 [`Twap`]，使用 [`EngineClock::time`] 作为执行开始时间。
+    ///
+    /// # 参数
+    ///
+    /// - `strategy`: 子订单使用的策略 ID
+    /// - `parent`: 要执行的父订单
+    /// - `clock`: Engine 时钟，用于确定执行开始时间
+    pub fn new(
+        strategy: StrategyId,
+        parent: TwapParent<ExchangeKey, InstrumentKey>,
+        clock: &impl EngineClock,
+    ) -> Self {
+        Self {
+            strategy,
+            parent,
+            start: clock.time(),
+            slices_sent: 0,
+            quantity_filled: Decimal::ZERO,
+            quantity_filled_by_order: FnvHashMap::default(),
+        }
+    }
+
+    /// 返回相邻两个切片之间的时间间隔。
+    pub fn slice_interval(&self) -> TimeDelta {
+        let slices = i32::try_from(self.parent.slices.max(1)).unwrap_or(i32::MAX);
+        self.parent.duration / slices
+    }
+
+    /// 返回第 `slice` 个切片（从 0 开始）的计划发出时间。
+    pub fn slice_time(&self, slice: usize) -> DateTime<Utc> {
+        let slice = i32::try_from(slice).unwrap_or(i32::MAX);
+        self.start + self.slice_interval() * slice
+    }
+
+    /// 返回在给定时间已经到期的切片数量（不超过切片总数）。
+    pub fn slices_due(&self, time: DateTime<Utc>) -> usize {
+        if time < self.start {
+            return 0;
+        }
+
+        let interval = self.slice_interval().num_milliseconds();
+        if interval <= 0 {
+            return self.parent.slices;
+        }
+
+        let elapsed = (time - self.start).num_milliseconds();
+        let due = usize::try_from(elapsed / interval)
+            .unwrap_or(usize::MAX)
+            .saturating_add(1);
+
+        due.min(self.parent.slices)
+    }
+
+    /// 返回父订单剩余的未成交数量。
+    pub fn quantity_remaining(&self) -> Decimal {
+        (self.parent.quantity - self.quantity_filled).max(Decimal::ZERO)
+    }
+
+    /// 返回 Twap 在 `orders` 中仍在途或挂单中的子订单的未成交数量。
+    ///
+    /// 只有与 Twap 的策略 ID、交易对和方向都匹配的订单会被计入。挂单的未成交数量基于该订单在
+    /// [`Self::quantity_filled`] 中已计入的成交数量计算，因此"已成交 + 在途"不会重复计算同一
+    /// 部分成交，也不会因订单状态与 `Trade` 事件到达顺序不同而遗漏。尚无 [`OrderId`] 的在途
+    /// 订单，以及撤单在途但未知订单状态的订单，按全部数量计入。
+    pub fn quantity_in_flight(&self, orders: &Orders<ExchangeKey, InstrumentKey>) -> Decimal
+    where
+        InstrumentKey: PartialEq,
+    {
+        orders
+            .active
+            .values()
+            .filter(|order| {
+                order.key.strategy == self.strategy
+                    && order.key.instrument == self.parent.instrument
+                    && order.side == self.parent.side
+            })
+            .map(|order| {
+                let open = match &order.state {
+                    ActiveOrderState::OpenInFlight(_) => None,
+                    ActiveOrderState::Open(open) => Some(open),
+                    ActiveOrderState::CancelInFlight(cancel) => cancel.order.as_ref(),
+                };
+                let filled = open
+                    .and_then(|open| self.quantity_filled_by_order.get(&open.id))
+                    .copied()
+                    .unwrap_or_default();
+
+                order.quantity - filled
+            })
+            .map(|quantity| quantity.max(Decimal::ZERO))
+            .sum()
+    }
+
+    /// 如果父订单已经完全成交，返回 `true`。
+    pub fn is_filled(&self) -> bool {
+        self.quantity_remaining().is_zero()
+    }
+
+    /// 如果 Twap 不会再生成任何子订单（父订单已完全成交），返回 `true`。
+    ///
+    /// 所有切片都已发出但父订单仍未完全成交时（例如最后一个切片未成交即过期），Twap 仍会
+    /// 发出剩余数量，因此不视为已结束。
+    pub fn is_finished(&self) -> bool {
+        self.is_filled()
+    }
+
+    /// 生成当前到期的子订单。
+    ///
+    /// 如果自上次调用以来有多个切片到期（例如时钟跳跃），它们会被合并为一个子订单。
+    /// 如果没有新的切片到期，或者父订单已经完全成交，返回 `None`。
+    ///
+    /// 子订单数量基于扣除在途和挂单数量（见 [`Self::quantity_in_flight`]）后的剩余数量计算。
+    /// 如果剩余数量已全部在途，不会发出子订单，且到期的切片不会被视为已发出，而是在下一次调用
+    /// 时与之后到期的切片合并。所有切片都已到期后，每次调用都会发出未成交且未在途的剩余数量。
+    ///
+    /// # 参数
+    ///
+    /// - `clock`: Engine 时钟，用于确定当前时间
+    /// - `orders`: 交易对当前的活跃订单，用于扣除在途和挂单数量
+    /// - `price`: 子订单的参考价格（通常为最新市场价格）
+    /// - `gen_cid`: 生成客户端订单 ID 的函数
+    ///
+    /// # 返回值
+    ///
+    /// 返回 `Some(OrderRequestOpen)` 表示应发送的 `ImmediateOrCancel` `Market` 子订单。
+    pub fn generate_slice(
+        &mut self,
+        clock: &impl EngineClock,
+        orders: &Orders<ExchangeKey, InstrumentKey>,
+        price: Decimal,
+        gen_cid: impl Fn() -> ClientOrderId,
+    ) -> Option<OrderRequestOpen<ExchangeKey, InstrumentKey>>
+    where
+        ExchangeKey: Clone,
+        InstrumentKey: Clone + PartialEq,
+    {
+        if self.is_finished() {
+            return None;
+        }
+
+        let slices_due = self.slices_due(clock.time());
+        let schedule_complete = slices_due >= self.parent.slices;
+        if slices_due <= self.slices_sent && !schedule_complete {
+            return None;
+        }
+
+        // 剩余未成交（且未在途）数量按剩余切片数平均分摊，最后一个切片发出全部剩余数量
+        let quantity_remaining =
+            (self.quantity_remaining() - self.quantity_in_flight(orders)).max(Decimal::ZERO);
+        let quantity = if schedule_complete {
+            quantity_remaining
+        } else {
+            let slices_new = Decimal::from(slices_due - self.slices_sent);
+            let slices_remaining = Decimal::from(self.parent.slices - self.slices_sent);
+            quantity_remaining * slices_new / slices_remaining
+        };
+
+        if quantity.is_zero() {
+            return None;
+        }
+
+        self.slices_sent = slices_due;
+
+        Some(OrderRequestOpen {
+            key: OrderKey {
+                exchange: self.parent.exchange.clone(),
+                instrument: self.parent.instrument.clone(),
+                strategy: self.strategy.clone(),
+                cid: gen_cid(),
+            },
+            state: RequestOpen {
+                side: self.parent.side,
+                price,
+                quantity,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        })
+    }
+
+    /// 从 [`Trade`] 更新已成交数量。
+    ///
+    /// 只有与 Twap 的策略 ID、交易对和方向都匹配的成交会被计入。
+    pub fn update_from_trade<AssetKey>(&mut self, trade: &Trade<AssetKey, InstrumentKey>)
+    where
+        InstrumentKey: PartialEq,
+    {
+        if trade.strategy == self.strategy
+            && trade.instrument == self.parent.instrument
+            && trade.side == self.parent.side
+        {
+            self.quantity_filled += trade.quantity.abs();
+            *self
+                .quantity_filled_by_order
+                .entry(trade.order_id.clone())
+                .or_default() += trade.quantity.abs();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{TestClock, trade_from_request};
+    use barter_execution::order::{
+        Order,
+        state::{Open, OpenInFlight},
+    };
+    use rust_decimal_macros::dec;
+
+    fn twap(quantity: Decimal, slices: usize, clock: &TestClock) -> Twap {
+        Twap::new(
+            StrategyId::new("twap"),
+            TwapParent::new(
+                ExchangeIndex(0),
+                InstrumentIndex(0),
+                Side::Buy,
+                quantity,
+                TimeDelta::minutes(10),
+                slices,
+            ),
+            clock,
+        )
+    }
+
+    #[test]
+    fn test_twap_slices_sum_to_parent_quantity_and_respect_schedule() {
+        let clock = TestClock::new(DateTime::<Utc>::MIN_UTC);
+        let orders = Orders::default();
+        let mut twap = twap(dec!(10), 5, &clock);
+
+        let mut slices = vec![];
+        let mut filled = Decimal::ZERO;
+
+        for slice in 0..5 {
+            assert_eq!(clock.time(), twap.slice_time(slice));
+
+            let request = twap
+                .generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+                .expect("slice should be due");
+            assert_eq!(request.key.strategy, StrategyId::new("twap"));
+
+            // Second slice only partially fills, with the shortfall spread over later slices
+            let quantity = if slice == 1 {
+                request.state.quantity / dec!(2)
+            } else {
+                request.state.quantity
+            };
+            twap.update_from_trade(&trade_from_request(&request, quantity));
+
+            // No further slice is due until the next slice time
+            assert!(
+                twap.generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+                    .is_none()
+            );
+
+            filled += quantity;
+            slices.push(request.state.quantity);
+            clock.advance(TimeDelta::minutes(2));
+        }
+
+        assert_eq!(slices[0], dec!(2));
+        assert_eq!(slices[1], dec!(2));
+        assert_eq!(slices[2], dec!(7) / dec!(3));
+        assert_eq!(filled, dec!(10));
+        assert!(twap.is_filled());
+        assert!(
+            twap.generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_twap_stops_early_when_parent_filled() {
+        let clock = TestClock::new(DateTime::<Utc>::MIN_UTC);
+        let orders = Orders::default();
+        let mut twap = twap(dec!(10), 5, &clock);
+
+        let request = twap
+            .generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+            .unwrap();
+
+        // Over-fill of the first slice completes the parent
+        twap.update_from_trade(&trade_from_request(&request, dec!(10)));
+        assert!(twap.is_filled());
+
+        clock.advance(TimeDelta::minutes(2));
+        assert!(
+            twap.generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_twap_merges_slices_due_after_clock_jump() {
+        let clock = TestClock::new(DateTime::<Utc>::MIN_UTC);
+        let orders = Orders::default();
+        let mut twap = twap(dec!(10), 5, &clock);
+
+        // Jump straight to the third slice time: slices 0, 1 & 2 are due
+        clock.advance(TimeDelta::minutes(4));
+        let request = twap
+            .generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+            .unwrap();
+        assert_eq!(request.state.quantity, dec!(6));
+        assert_eq!(twap.slices_sent, 3);
+
+        // Trades from other strategies are ignored
+        let mut other = trade_from_request(&request, dec!(6));
+        other.strategy = StrategyId::new("other");
+        twap.update_from_trade(&other);
+        assert_eq!(twap.quantity_filled, Decimal::ZERO);
+    }
+
+    #[test]
+    fn test_twap_subtracts_in_flight_and_open_quantity() {
+        let clock = TestClock::new(DateTime::<Utc>::MIN_UTC);
+        let mut orders = Orders::default();
+        let mut twap = twap(dec!(10), 5, &clock);
+
+        let active = |request: &OrderRequestOpen, state| Order {
+            key: request.key.clone(),
+            side: request.state.side,
+            price: request.state.price,
+            quantity: request.state.quantity,
+            kind: request.state.kind,
+            time_in_force: request.state.time_in_force,
+            reduce_only: request.state.reduce_only,
+            state,
+        };
+
+        // First slice is still in flight when the second slice is due
+        let first = twap
+            .generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+            .unwrap();
+        assert_eq!(first.state.quantity, dec!(2));
+        orders.active.insert(
+            first.key.cid.clone(),
+            active(&first, ActiveOrderState::OpenInFlight(OpenInFlight)),
+        );
+        assert_eq!(twap.quantity_in_flight(&orders), dec!(2));
+
+        // (10 - 2 in flight) / 4 remaining slices
+        clock.advance(TimeDelta::minutes(2));
+        let second = twap
+            .generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+            .unwrap();
+        assert_eq!(second.state.quantity, dec!(2));
+
+        // First slice is open with 0.5 filled, second slice is fully in flight
+        twap.update_from_trade(&trade_from_request(&first, dec!(0.5)));
+        orders.active.insert(
+            first.key.cid.clone(),
+            active(
+                &first,
+                ActiveOrderState::Open(Open::new(
+                    OrderId::new(&first.key.cid.0),
+                    DateTime::<Utc>::MIN_UTC,
+                    dec!(0.5),
+                )),
+            ),
+        );
+        orders.active.insert(
+            second.key.cid.clone(),
+            active(&second, ActiveOrderState::OpenInFlight(OpenInFlight)),
+        );
+        assert_eq!(twap.quantity_in_flight(&orders), dec!(3.5));
+
+        // Order state lagging behind the first slice Trade does not double count the fill
+        orders.active.insert(
+            first.key.cid.clone(),
+            active(
+                &first,
+                ActiveOrderState::Open(Open::new(
+                    OrderId::new(&first.key.cid.0),
+                    DateTime::<Utc>::MIN_UTC,
+                    Decimal::ZERO,
+                )),
+            ),
+        );
+        assert_eq!(twap.quantity_in_flight(&orders), dec!(3.5));
+
+        // (10 - 0.5 filled - 3.5 in flight) / 3 remaining slices
+        clock.advance(TimeDelta::minutes(2));
+        let third = twap
+            .generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+            .unwrap();
+        assert_eq!(third.state.quantity, dec!(2));
+
+        // Final slice only sends the quantity that is not already in flight
+        orders.active.insert(
+            third.key.cid.clone(),
+            active(&third, ActiveOrderState::OpenInFlight(OpenInFlight)),
+        );
+        clock.advance(TimeDelta::minutes(10));
+        let last = twap
+            .generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+            .unwrap();
+        assert_eq!(last.state.quantity, dec!(4));
+        assert_eq!(
+            twap.quantity_filled + twap.quantity_in_flight(&orders) + last.state.quantity,
+            dec!(10)
+        );
+    }
+
+    #[test]
+    fn test_twap_resends_remaining_quantity_when_last_slice_expires_unfilled() {
+        let clock = TestClock::new(DateTime::<Utc>::MIN_UTC);
+        let mut orders = Orders::default();
+        let mut twap = twap(dec!(10), 5, &clock);
+
+        // First four slices fill completely
+        for _ in 0..4 {
+            let request = twap
+                .generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+                .unwrap();
+            twap.update_from_trade(&trade_from_request(&request, request.state.quantity));
+            clock.advance(TimeDelta::minutes(2));
+        }
+        assert_eq!(twap.quantity_filled, dec!(8));
+
+        // Last slice is in flight, so no further slice is sent
+        let last = twap
+            .generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+            .unwrap();
+        assert_eq!(last.state.quantity, dec!(2));
+        assert_eq!(twap.slices_sent, 5);
+        orders.active.insert(
+            last.key.cid.clone(),
+            Order {
+                key: last.key.clone(),
+                side: last.state.side,
+                price: last.state.price,
+                quantity: last.state.quantity,
+                kind: last.state.kind,
+                time_in_force: last.state.time_in_force,
+                reduce_only: last.state.reduce_only,
+                state: ActiveOrderState::OpenInFlight(OpenInFlight),
+            },
+        );
+        clock.advance(TimeDelta::minutes(2));
+        assert!(
+            twap.generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+                .is_none()
+        );
+        assert!(!twap.is_finished());
+
+        // Last slice expires unfilled, so the remaining quantity is sent again
+        orders.active.remove(&last.key.cid);
+        let retry = twap
+            .generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+            .unwrap();
+        assert_eq!(retry.state.quantity, dec!(2));
+
+        twap.update_from_trade(&trade_from_request(&retry, retry.state.quantity));
+        assert!(twap.is_finished());
+        assert!(
+            twap.generate_slice(&clock, &orders, dec!(100), ClientOrderId::random)
+                .is_none()
+        );
+    }
+}
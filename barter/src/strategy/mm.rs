@@ -0,0 +1,434 @@
+//! 库存偏移做市策略模块
+//!
+//! 本模块定义了 [`InventoryMM`]，一个基于库存偏移（inventory skew）的双边做市
+//! [`AlgoStrategy`] 实现，用于减少从零构建做市策略所需的样板代码。
+//!
+//! # 工作原理
+//!
+//! 1. 从 [`InstrumentDataState::price`] 获取中间价（例如 `OrderBookL1` 的成交量加权中间价）
+//! 2. 计算当前净库存相对于目标库存的偏差
+//! 3. 将报价中心（保留价格）按偏差反向移动：
+//!    `reservation = mid * (1 - skew * (inventory - target))`
+//!    - 库存偏多时，买卖报价都下移，鼓励卖出
+//!    - 库存偏空时，买卖报价都上移，鼓励买入
+//! 4. 在保留价格两侧以 `mid * spread / 2` 报出双边报价
+//! 5. 库存达到 `inventory_max` 时停止继续增加库存方向的报价
+//! 6. 与当前 `Orders` 中本策略的活跃订单对比：取消过期报价，为新报价开仓
+//!
+//! # 注意事项
+//!
+//! 报价价格不会按交易对的价格精度取整，因此中间价的任何变化都会触发重新报价。
+
+use crate::{
+    engine::state::{
+        EngineState,
+        instrument::{InstrumentState, data::InstrumentDataState, filter::InstrumentFilter},
+    },
+    strategy::algo::AlgoStrategy,
+};
+use barter_execution::order::{
+    Order, OrderKey, OrderKind, TimeInForce,
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestCancel, OrderRequestOpen, RequestOpen},
+    state::ActiveOrderState,
+};
+use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+/// [`InventoryMM`] 的报价参数。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// // 10bps 基础价差，每单位库存偏差偏移 5bps，每笔报价 0.1，最大库存 1
+/// let config = InventoryMMConfig::new(dec!(0.001), dec!(0.0005), dec!(0.1), dec!(1), dec!(0));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize, Constructor)]
+pub struct InventoryMMConfig {
+    /// 基础价差，以中间价的比例表示（例如 `0.001` 表示 10bps）。
+    pub spread: Decimal,
+    /// 偏移系数，每单位库存偏差使保留价格移动的中间价比例。
+    pub skew: Decimal,
+    /// 每笔报价的数量（正数）。
+    pub quantity: Decimal,
+    /// 最大绝对库存，达到后停止继续增加库存方向的报价。
+    pub inventory_max: Decimal,
+    /// 目标库存，报价偏移会使库存向该值均值回归。
+    pub inventory_target: Decimal,
+}
+
+/// 单边报价。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize, Constructor)]
+pub struct Quote {
+    pub price: Decimal,
+    pub quantity: Decimal,
+}
+
+/// 由 [`InventoryMM`] 计算的双边报价。
+///
+/// 如果某一边因库存限制而不应报价，则为 `None`。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default, Deserialize, Serialize)]
+pub struct InventoryQuotes {
+    pub bid: Option<Quote>,
+    pub ask: Option<Quote>,
+}
+
+/// 库存偏移双边做市 [`AlgoStrategy`]。
+///
+/// 对于过滤器选中的每个有价格数据的交易对，`InventoryMM` 在中间价两侧报出
+/// `GoodUntilCancelled` `Limit` 双边报价，并根据当前净库存偏移报价，使库存向目标值均值回归。
+///
+/// 每次调用 `generate_algo_orders` 时，报价会与本策略（按 [`StrategyId`] 区分）的活跃订单对比：
+/// - 价格和数量与新报价一致的 `Open` 订单保持不变
+/// - 其他 `Open` 订单被取消
+/// - 如果某一边没有保持不变的订单，则为新报价生成开仓请求
+/// - 如果某一边存在在途请求（`OpenInFlight` 或 `CancelInFlight`），该边本次不做任何操作
+///
+/// ## 类型参数
+///
+/// - `GlobalData`: 全局数据类型
+/// - `InstrumentData`: 交易对数据类型，提供中间价
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let strategy = InventoryMM::new(StrategyId::new("mm"), config, InstrumentFilter::None);
+/// let (cancels, opens) = strategy.generate_algo_orders(&state);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct InventoryMM<GlobalData, InstrumentData> {
+    /// 报价使用的策略 ID，用于识别本策略的订单。
+    pub strategy: StrategyId,
+    /// 报价参数。
+    pub config: InventoryMMConfig,
+    /// 做市的交易对过滤器。
+    pub filter: InstrumentFilter,
+    phantom: PhantomData<(GlobalData, InstrumentData)>,
+}
+
+impl<GlobalData, InstrumentData> InventoryMM<GlobalData, InstrumentData> {
+    /// 构造新的 [`InventoryMM`]。
+    ///
+    /// # 参数
+    ///
+    /// - `strategy`: 报价使用的策略 ID
+    /// - `config`: 报价参数
+    /// - `filter`: 做市的交易对过滤器
+    pub fn new(strategy: StrategyId, config: InventoryMMConfig, filter: InstrumentFilter) -> Self {
+        Self {
+            strategy,
+            config,
+            filter,
+            phantom: PhantomData,
+        }
+    }
+
+    /// 基于中间价和当前净库存计算双边报价。
+    ///
+    /// # 参数
+    ///
+    /// - `mid`: 中间价
+    /// - `inventory`: 当前净库存（多头为正，空头为负）
+    pub fn quotes(&self, mid: Decimal, inventory: Decimal) -> InventoryQuotes {
+        let InventoryMMConfig {
+            spread,
+            skew,
+            quantity,
+            inventory_max,
+            inventory_target,
+        } = self.config;
+
+        let reservation = mid * (Decimal::ONE - skew * (inventory - inventory_target));
+        let half_spread = mid * spread / Decimal::TWO;
+
+        let bid_quantity = quantity.min(inventory_max - inventory);
+        let ask_quantity = quantity.min(inventory_max + inventory);
+
+        InventoryQuotes {
+            bid: (bid_quantity > Decimal::ZERO)
+                .then(|| Quote::new(reservation - half_spread, bid_quantity)),
+            ask: (ask_quantity > Decimal::ZERO)
+                .then(|| Quote::new(reservation + half_spread, ask_quantity)),
+        }
+    }
+
+    fn generate_instrument_orders(
+        &self,
+        state: &InstrumentState<InstrumentData>,
+        cancels: &mut Vec<OrderRequestCancel>,
+        opens: &mut Vec<OrderRequestOpen>,
+    ) where
+        InstrumentData: InstrumentDataState,
+    {
+        let Some(mid) = state.data.price() else {
+            return;
+        };
+
+        let quotes = self.quotes(mid, net_inventory(state));
+
+        for (side, quote) in [(Side::Buy, quotes.bid), (Side::Sell, quotes.ask)] {
+            let orders = state
+                .orders
+                .active
+                .values()
+                .filter(|order| order.key.strategy == self.strategy && order.side == side)
+                .collect::<Vec<_>>();
+
+            if orders
+                .iter()
+                .any(|order| !matches!(order.state, ActiveOrderState::Open(_)))
+            {
+                continue;
+            }
+
+            let mut quoted = false;
+            for order in orders {
+                if !quoted && quote.is_some_and(|quote| is_quote(order, &quote)) {
+                    quoted = true;
+                } else {
+                    cancels.extend(order.to_request_cancel());
+                }
+            }
+
+            if let Some(quote) = quote
+                && !quoted
+            {
+                opens.push(OrderRequestOpen {
+                    key: OrderKey {
+                        exchange: state.instrument.exchange,
+                        instrument: state.key,
+                        strategy: self.strategy.clone(),
+                        cid: ClientOrderId::random(),
+                    },
+                    state: RequestOpen {
+                        side,
+                        price: quote.price,
+                        quantity: quote.quantity,
+                        kind: OrderKind::Limit,
+                        time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+                        reduce_only: false,
+                    },
+                });
+            }
+        }
+    }
+}
+
+impl<GlobalData, InstrumentData> AlgoStrategy for InventoryMM<GlobalData, InstrumentData>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) {
+        let mut cancels = Vec::new();
+        let mut opens = Vec::new();
+
+        for instrument in state.instruments.instruments(&self.filter) {
+            self.generate_instrument_orders(instrument, &mut cancels, &mut opens);
+        }
+
+        (cancels, opens)
+    }
+}
+
+/// 返回交易对的当前净库存（多头为正，空头为负）。
+fn net_inventory<InstrumentData>(state: &InstrumentState<InstrumentData>) -> Decimal {
+    state
+        .position
+        .positions()
+        .map(|position| position.side.sign() * position.quantity_abs)
+        .sum()
+}
+
+fn is_quote(
+    order: &Order<ExchangeIndex, InstrumentIndex, ActiveOrderState>,
+    quote: &Quote,
+) -> bool {
+    order.price == quote.price && order.quantity == quote.quantity
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData},
+        test_utils::spot_engine_state,
+    };
+    use barter_data::{books::Level, subscription::book::OrderBookL1};
+    use barter_execution::{
+        order::{
+            id::OrderId,
+            state::{Open, OpenInFlight},
+        },
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    type State = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+    type Strategy = InventoryMM<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn strategy() -> Strategy {
+        InventoryMM::new(
+            StrategyId::new("mm"),
+            InventoryMMConfig::new(dec!(0.01), dec!(0.01), dec!(1), dec!(5), dec!(0)),
+            InstrumentFilter::None,
+        )
+    }
+
+    fn state(inventory: Decimal) -> State {
+        let mut state = spot_engine_state(&["btc"]);
+
+        let instrument = state.instruments.instrument_index_mut(&InstrumentIndex(0));
+        instrument.data.l1 = OrderBookL1::new(
+            DateTime::<Utc>::MIN_UTC,
+            Some(Level::new(dec!(99), dec!(1))),
+            Some(Level::new(dec!(101), dec!(1))),
+        );
+
+        if !inventory.is_zero() {
+            instrument.position.update_from_trade(&Trade {
+                id: TradeId::new("trade"),
+                order_id: OrderId::new("order"),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("mm"),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                side: if inventory.is_sign_positive() {
+                    Side::Buy
+                } else {
+                    Side::Sell
+                },
+                price: dec!(100),
+                quantity: inventory.abs(),
+                fees: AssetFees::quote_fees(Decimal::ZERO),
+                reduce_only: false,
+            });
+        }
+
+        state
+    }
+
+    fn order(
+        side: Side,
+        price: Decimal,
+        state: ActiveOrderState,
+    ) -> Order<ExchangeIndex, InstrumentIndex, ActiveOrderState> {
+        Order {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: StrategyId::new("mm"),
+                cid: ClientOrderId::random(),
+            },
+            side,
+            price,
+            quantity: dec!(1),
+            kind: OrderKind::Limit,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            reduce_only: false,
+            state,
+        }
+    }
+
+    fn open() -> ActiveOrderState {
+        ActiveOrderState::Open(Open::new(
+            OrderId::new("order"),
+            DateTime::<Utc>::MIN_UTC,
+            Decimal::ZERO,
+        ))
+    }
+
+    #[test]
+    fn test_inventory_mm_quotes_skew_against_inventory() {
+        let strategy = strategy();
+
+        let flat = strategy.quotes(dec!(100), dec!(0));
+        assert_eq!(flat.bid, Some(Quote::new(dec!(99.5), dec!(1))));
+        assert_eq!(flat.ask, Some(Quote::new(dec!(100.5), dec!(1))));
+
+        // Long inventory skews both quotes down to encourage selling
+        let long = strategy.quotes(dec!(100), dec!(2));
+        assert_eq!(long.bid, Some(Quote::new(dec!(97.5), dec!(1))));
+        assert_eq!(long.ask, Some(Quote::new(dec!(98.5), dec!(1))));
+
+        // Short inventory skews both quotes up to encourage buying
+        let short = strategy.quotes(dec!(100), dec!(-2));
+        assert_eq!(short.bid, Some(Quote::new(dec!(101.5), dec!(1))));
+        assert_eq!(short.ask, Some(Quote::new(dec!(102.5), dec!(1))));
+
+        // Skew grows with inventory
+        let longer = strategy.quotes(dec!(100), dec!(4));
+        assert!(longer.ask.unwrap().price < long.ask.unwrap().price);
+
+        // Quotes increasing inventory stop at max inventory
+        let max_long = strategy.quotes(dec!(100), dec!(5));
+        assert_eq!(max_long.bid, None);
+        assert!(max_long.ask.is_some());
+
+        let near_max_short = strategy.quotes(dec!(100), dec!(-4.5));
+        assert_eq!(near_max_short.ask.unwrap().quantity, dec!(0.5));
+    }
+
+    #[test]
+    fn test_inventory_mm_generate_algo_orders_diffs_against_orders() {
+        let strategy = strategy();
+
+        // No existing orders: open both quotes around the position-skewed mid
+        let mut state = state(dec!(2));
+        let (cancels, opens) = strategy.generate_algo_orders(&state);
+        let opens = opens.into_iter().collect::<Vec<_>>();
+        assert_eq!(cancels.into_iter().count(), 0);
+        assert_eq!(opens.len(), 2);
+        assert_eq!(opens[0].state.side, Side::Buy);
+        assert_eq!(opens[0].state.price, dec!(97.5));
+        assert_eq!(opens[1].state.side, Side::Sell);
+        assert_eq!(opens[1].state.price, dec!(98.5));
+
+        // Matching bid is kept, stale ask is cancelled & replaced
+        let bid = order(Side::Buy, dec!(97.5), open());
+        let ask = order(Side::Sell, dec!(100.5), open());
+        let orders = &mut state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .orders
+            .active;
+        orders.insert(bid.key.cid.clone(), bid);
+        orders.insert(ask.key.cid.clone(), ask.clone());
+
+        let (cancels, opens) = strategy.generate_algo_orders(&state);
+        let cancels = cancels.into_iter().collect::<Vec<_>>();
+        let opens = opens.into_iter().collect::<Vec<_>>();
+        assert_eq!(cancels.len(), 1);
+        assert_eq!(cancels[0].key.cid, ask.key.cid);
+        assert_eq!(opens.len(), 1);
+        assert_eq!(opens[0].state.side, Side::Sell);
+        assert_eq!(opens[0].state.price, dec!(98.5));
+
+        // Side with in-flight requests is left untouched
+        let in_flight = order(
+            Side::Sell,
+            dec!(98.5),
+            ActiveOrderState::OpenInFlight(OpenInFlight),
+        );
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .orders
+            .active
+            .insert(in_flight.key.cid.clone(), in_flight);
+
+        let (cancels, opens) = strategy.generate_algo_orders(&state);
+        assert_eq!(cancels.into_iter().count(), 0);
+        assert_eq!(opens.into_iter().count(), 0);
+    }
+}
@@ -9,6 +9,11 @@
 //! - **ClosePositionsStrategy**: 平仓策略，生成平仓订单请求
 //! - **OnDisconnectStrategy**: 断开连接处理策略
 //! - **OnTradingDisabled**: 交易禁用处理策略
+//! - **Twap**: 时间加权平均价格执行算法，将大额父订单拆分为子订单
+//! - **InventoryMM**: 库存偏移双边做市策略，根据当前仓位偏移报价
+//! - **Dca**: 定投策略，按固定时间间隔买入固定名义价值直到达到目标值
+//! - **RequoteGate**: 重新报价阈值，仅在参考价格变动超过阈值时才允许被包装的策略重新报价
+//! - **MultiStrategy**: 多策略组合，将订单请求归属到所属策略的 `StrategyId`
 //! - **DefaultStrategy**: 默认策略实现（仅用于演示）
 //!
 //! # 策略接口
@@ -50,6 +55,24 @@ pub mod algo;
 /// 定义生成用于平仓的开仓和取消订单请求的策略接口。
 pub mod close_positions;
 
+/// 按固定时间间隔买入固定名义价值直到达到目标值的定投策略（例如 [`Dca`](dca::Dca)）。
+pub mod dca;
+
+/// 将大额父订单拆分为子订单的执行算法（例如 TWAP）。
+pub mod execution;
+
+/// 基于库存偏移的双边做市策略（例如 [`InventoryMM`](mm::InventoryMM)）。
+pub mod mm;
+
+/// 将多个独立策略组合为单个策略（例如 [`MultiStrategy`](multi::MultiStrategy)）。
+pub mod multi;
+
+/// 基于协整价差 z-score 开平仓的配对交易策略（例如 [`PairsTrading`](pairs::PairsTrading)）。
+pub mod pairs;
+
+/// 仅在参考价格变动超过阈值时才允许重新报价的策略包装器（例如 [`RequoteGate`](requote::RequoteGate)）。
+pub mod requote;
+
 /// 定义在交易所断开连接时执行自定义 [`Engine`] 操作的策略接口。
 pub mod on_disconnect;
 
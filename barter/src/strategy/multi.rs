@@ -0,0 +1,653 @@
+//! 多策略组合模块
+//!
+//! 本模块定义了 [`MultiStrategy`]，它将多个独立策略组合为单个策略，使 [`Engine`](crate::engine::Engine)
+//! 可以同时运行多个策略，以及 [`MultiStrategyRisk`]，它为每个策略应用独立的风险限制。
+//!
+//! # 订单归属
+//!
+//! 每个策略都关联一个 [`StrategyId`]。[`MultiStrategy`] 依次调用每个策略的
+//! `generate_algo_orders`，并将生成的所有开仓请求的 `OrderKey::strategy` 设置为所属策略的
+//! [`StrategyId`]。取消请求不会被改写：策略只能取消自己的订单，针对其他策略订单的取消请求会被丢弃。
+//! 因此：
+//! - 交易（`Trade::strategy`）可以按策略进行 PnL 归属
+//! - [`MultiStrategyRisk`] 可以根据 `OrderKey::strategy` 应用按策略的风险限制
+//!
+//! # 注意事项
+//!
+//! 仓位按交易对跟踪，而不是按策略跟踪，因此平仓请求会扇出到每个策略，但每个交易对的每个方向
+//! 只保留第一个策略生成的平仓开仓请求，以避免重复平仓。
+
+use crate::{
+    engine::{Engine, state::instrument::filter::InstrumentFilter},
+    risk::{RiskApproved, RiskManager, RiskRefused},
+    strategy::{
+        algo::AlgoStrategy, close_positions::ClosePositionsStrategy,
+        on_disconnect::OnDisconnectStrategy, on_trading_disabled::OnTradingDisabled,
+    },
+};
+use barter_execution::order::{
+    id::StrategyId,
+    request::{OrderRequestCancel, OrderRequestOpen},
+};
+use barter_instrument::exchange::ExchangeId;
+use serde::{Deserialize, Serialize};
+
+/// 将多个独立策略组合为单个策略，并将生成的订单请求归属到所属策略的 [`StrategyId`]。
+///
+/// - `AlgoStrategy`: 依次调用每个策略，将开仓请求的 `OrderKey::strategy` 设置为所属策略的 ID，
+///   并丢弃针对其他策略订单的取消请求
+/// - `ClosePositionsStrategy`: 扇出到每个策略，每个交易对的每个方向只保留第一个平仓开仓请求
+/// - `OnDisconnectStrategy` 和 `OnTradingDisabled`: 扇出到每个策略，输出按策略顺序与其
+///   [`StrategyId`] 一起返回
+///
+/// 组合不同类型的策略时，可以使用实现了策略接口的枚举作为 `Strategy` 类型。
+///
+/// ## 类型参数
+///
+/// - `Strategy`: 被组合的策略类型
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let strategy = MultiStrategy::new([
+///     (StrategyId::new("mm_btc"), mm_btc),
+///     (StrategyId::new("mm_eth"), mm_eth),
+/// ]);
+///
+/// // 包装后在交易所断开连接时取消订单
+/// let strategy = CancelOrdersOnDisconnect::new(strategy);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MultiStrategy<Strategy> {
+    /// 被组合的策略及其 [`StrategyId`]，按调用顺序排列。
+    pub strategies: Vec<(StrategyId, Strategy)>,
+}
+
+impl<Strategy> MultiStrategy<Strategy> {
+    /// 使用提供的策略及其 [`StrategyId`] 构造新的 [`MultiStrategy`]。
+    pub fn new(strategies: impl IntoIterator<Item = (StrategyId, Strategy)>) -> Self {
+        Self {
+            strategies: strategies.into_iter().collect(),
+        }
+    }
+
+    /// 返回具有提供的 [`StrategyId`] 的策略（如果存在）。
+    pub fn strategy(&self, id: &StrategyId) -> Option<&Strategy> {
+        self.strategies
+            .iter()
+            .find_map(|(strategy_id, strategy)| (strategy_id == id).then_some(strategy))
+    }
+
+    /// 返回具有提供的 [`StrategyId`] 的策略的可变引用（如果存在）。
+    pub fn strategy_mut(&mut self, id: &StrategyId) -> Option<&mut Strategy> {
+        self.strategies
+            .iter_mut()
+            .find_map(|(strategy_id, strategy)| (strategy_id == id).then_some(strategy))
+    }
+}
+
+impl<Strategy, ExchangeKey, InstrumentKey> AlgoStrategy<ExchangeKey, InstrumentKey>
+    for MultiStrategy<Strategy>
+where
+    Strategy: AlgoStrategy<ExchangeKey, InstrumentKey>,
+{
+    type State = Strategy::State;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeKey, InstrumentKey>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeKey, InstrumentKey>>,
+    ) {
+        self.strategies.iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut cancels, mut opens), (id, strategy)| {
+                let (strategy_cancels, strategy_opens) = strategy.generate_algo_orders(state);
+
+                cancels.extend(
+                    strategy_cancels
+                        .into_iter()
+                        .filter(|cancel| &cancel.key.strategy == id),
+                );
+                opens.extend(strategy_opens.into_iter().map(|mut open| {
+                    open.key.strategy = id.clone();
+                    open
+                }));
+
+                (cancels, opens)
+            },
+        )
+    }
+}
+
+impl<Strategy, ExchangeKey, AssetKey, InstrumentKey>
+    ClosePositionsStrategy<ExchangeKey, AssetKey, InstrumentKey> for MultiStrategy<Strategy>
+where
+    Strategy: ClosePositionsStrategy<ExchangeKey, AssetKey, InstrumentKey>,
+    ExchangeKey: PartialEq,
+    InstrumentKey: PartialEq,
+{
+    type State = Strategy::State;
+
+    fn close_positions_requests<'a>(
+        &'a self,
+        state: &'a Self::State,
+        filter: &'a InstrumentFilter<ExchangeKey, AssetKey, InstrumentKey>,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeKey, InstrumentKey>> + 'a,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeKey, InstrumentKey>> + 'a,
+    )
+    where
+        ExchangeKey: 'a,
+        AssetKey: 'a,
+        InstrumentKey: 'a,
+    {
+        let mut cancels = Vec::new();
+        let mut opens = Vec::<OrderRequestOpen<ExchangeKey, InstrumentKey>>::new();
+
+        for (id, strategy) in &self.strategies {
+            let (strategy_cancels, strategy_opens) =
+                strategy.close_positions_requests(state, filter);
+
+            cancels.extend(
+                strategy_cancels
+                    .into_iter()
+                    .filter(|cancel| &cancel.key.strategy == id),
+            );
+
+            for mut open in strategy_opens {
+                let duplicate = opens.iter().any(|existing| {
+                    existing.key.exchange == open.key.exchange
+                        && existing.key.instrument == open.key.instrument
+                        && existing.state.side == open.state.side
+                });
+
+                if !duplicate {
+                    open.key.strategy = id.clone();
+                    opens.push(open);
+                }
+            }
+        }
+
+        (cancels, opens)
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk, Strategy>
+    OnDisconnectStrategy<Clock, State, ExecutionTxs, Risk> for MultiStrategy<Strategy>
+where
+    Strategy: OnDisconnectStrategy<Clock, State, ExecutionTxs, Risk>,
+{
+    type OnDisconnect = Vec<(StrategyId, Strategy::OnDisconnect)>;
+
+    fn on_disconnect(
+        engine: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+        exchange: ExchangeId,
+    ) -> Self::OnDisconnect {
+        fan_out(engine, |engine| Strategy::on_disconnect(engine, exchange))
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk, Strategy> OnTradingDisabled<Clock, State, ExecutionTxs, Risk>
+    for MultiStrategy<Strategy>
+where
+    Strategy: OnTradingDisabled<Clock, State, ExecutionTxs, Risk>,
+{
+    type OnTradingDisabled = Vec<(StrategyId, Strategy::OnTradingDisabled)>;
+
+    fn on_trading_disabled(
+        engine: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+    ) -> Self::OnTradingDisabled {
+        fan_out(engine, Strategy::on_trading_disabled)
+    }
+}
+
+/// 依次以 [`MultiStrategy`] 的每个策略作为 Engine 策略调用 `f`，返回每个策略的 [`StrategyId`]
+/// 及其输出。
+fn fan_out<Clock, State, ExecutionTxs, Risk, Strategy, Output>(
+    engine: &mut Engine<Clock, State, ExecutionTxs, MultiStrategy<Strategy>, Risk>,
+    mut f: impl FnMut(&mut Engine<Clock, State, ExecutionTxs, Strategy, Risk>) -> Output,
+) -> Vec<(StrategyId, Output)> {
+    (0..engine.strategy.strategies.len())
+        .map(|index| {
+            let id = engine.strategy.strategies[index].0.clone();
+            let output = engine.with_inner_strategy(
+                |mut multi| {
+                    let (id, strategy) = multi.strategies.remove(index);
+                    (strategy, (multi, id))
+                },
+                &mut f,
+                |strategy, (mut multi, id)| {
+                    multi.strategies.insert(index, (id, strategy));
+                    multi
+                },
+            );
+            (id, output)
+        })
+        .collect()
+}
+
+/// 按 [`StrategyId`] 路由订单请求的 [`RiskManager`]，为 [`MultiStrategy`] 的每个策略应用独立的
+/// 风险限制。
+///
+/// 每个订单请求按其 `OrderKey::strategy` 交给对应策略的风险管理器检查，没有配置风险管理器的
+/// 策略的订单请求交给 `default` 风险管理器检查。
+///
+/// ## 类型参数
+///
+/// - `Risk`: 每个策略使用的风险管理器类型
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let risk = MultiStrategyRisk::new(
+///     [(StrategyId::new("mm_btc"), mm_btc_risk)],
+///     default_risk,
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MultiStrategyRisk<Risk> {
+    /// 每个策略的风险管理器及其 [`StrategyId`]。
+    pub strategies: Vec<(StrategyId, Risk)>,
+    /// 没有配置风险管理器的策略使用的风险管理器。
+    pub default: Risk,
+}
+
+impl<Risk> MultiStrategyRisk<Risk> {
+    /// 使用提供的每个策略的风险管理器和默认风险管理器构造新的 [`MultiStrategyRisk`]。
+    pub fn new(strategies: impl IntoIterator<Item = (StrategyId, Risk)>, default: Risk) -> Self {
+        Self {
+            strategies: strategies.into_iter().collect(),
+            default,
+        }
+    }
+}
+
+impl<Risk, ExchangeKey, InstrumentKey> RiskManager<ExchangeKey, InstrumentKey>
+    for MultiStrategyRisk<Risk>
+where
+    Risk: RiskManager<ExchangeKey, InstrumentKey>,
+{
+    type State = Risk::State;
+
+    fn check(
+        &self,
+        state: &Self::State,
+        cancels: impl IntoIterator<Item = OrderRequestCancel<ExchangeKey, InstrumentKey>>,
+        opens: impl IntoIterator<Item = OrderRequestOpen<ExchangeKey, InstrumentKey>>,
+    ) -> (
+        impl IntoIterator<Item = RiskApproved<OrderRequestCancel<ExchangeKey, InstrumentKey>>>,
+        impl IntoIterator<Item = RiskApproved<OrderRequestOpen<ExchangeKey, InstrumentKey>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestCancel<ExchangeKey, InstrumentKey>>>,
+        impl IntoIterator<Item = RiskRefused<OrderRequestOpen<ExchangeKey, InstrumentKey>>>,
+    ) {
+        let mut cancels = cancels.into_iter().collect::<Vec<_>>();
+        let mut opens = opens.into_iter().collect::<Vec<_>>();
+        let mut output = (Vec::new(), Vec::new(), Vec::new(), Vec::new());
+
+        let mut check = |risk: &Risk, cancels, opens| {
+            let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+                risk.check(state, cancels, opens);
+
+            output.0.extend(approved_cancels);
+            output.1.extend(approved_opens);
+            output.2.extend(refused_cancels);
+            output.3.extend(refused_opens);
+        };
+
+        for (id, risk) in &self.strategies {
+            let (strategy_cancels, other_cancels) = cancels
+                .into_iter()
+                .partition::<Vec<_>, _>(|cancel| &cancel.key.strategy == id);
+            let (strategy_opens, other_opens) = opens
+                .into_iter()
+                .partition::<Vec<_>, _>(|open| &open.key.strategy == id);
+
+            check(risk, strategy_cancels, strategy_opens);
+            cancels = other_cancels;
+            opens = other_opens;
+        }
+
+        check(&self.default, cancels, opens);
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::clock::HistoricalClock;
+    use barter_execution::order::{
+        OrderKey, OrderKind, TimeInForce,
+        id::ClientOrderId,
+        request::{RequestCancel, RequestOpen},
+    };
+    use barter_instrument::{
+        Side, asset::AssetIndex, exchange::ExchangeIndex, instrument::InstrumentIndex,
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    /// Strategy that cancels the order it believes it owns & opens a single order on the
+    /// configured instrument.
+    #[derive(Debug)]
+    struct SingleOrder {
+        owner: StrategyId,
+        instrument: InstrumentIndex,
+        disconnects: usize,
+    }
+
+    impl SingleOrder {
+        fn new(owner: &str, instrument: usize) -> Self {
+            Self {
+                owner: StrategyId::new(owner),
+                instrument: InstrumentIndex(instrument),
+                disconnects: 0,
+            }
+        }
+
+        fn key(&self, strategy: StrategyId) -> OrderKey {
+            OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: self.instrument,
+                strategy,
+                cid: ClientOrderId::new(self.instrument.to_string()),
+            }
+        }
+
+        fn requests(&self, side: Side) -> ([OrderRequestCancel; 1], [OrderRequestOpen; 1]) {
+            let cancel = OrderRequestCancel {
+                key: self.key(self.owner.clone()),
+                state: RequestCancel { id: None },
+            };
+
+            let open = OrderRequestOpen {
+                key: self.key(StrategyId::unknown()),
+                state: RequestOpen {
+                    side,
+                    price: dec!(100),
+                    quantity: dec!(1),
+                    kind: OrderKind::Limit,
+                    time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+                    reduce_only: false,
+                },
+            };
+
+            ([cancel], [open])
+        }
+    }
+
+    impl AlgoStrategy for SingleOrder {
+        type State = ();
+
+        fn generate_algo_orders(
+            &self,
+            _: &Self::State,
+        ) -> (
+            impl IntoIterator<Item = OrderRequestCancel>,
+            impl IntoIterator<Item = OrderRequestOpen>,
+        ) {
+            self.requests(Side::Buy)
+        }
+    }
+
+    impl ClosePositionsStrategy for SingleOrder {
+        type State = ();
+
+        fn close_positions_requests<'a>(
+            &'a self,
+            _: &'a Self::State,
+            _: &'a InstrumentFilter<ExchangeIndex, AssetIndex, InstrumentIndex>,
+        ) -> (
+            impl IntoIterator<Item = OrderRequestCancel> + 'a,
+            impl IntoIterator<Item = OrderRequestOpen> + 'a,
+        )
+        where
+            ExchangeIndex: 'a,
+            AssetIndex: 'a,
+            InstrumentIndex: 'a,
+        {
+            self.requests(Side::Sell)
+        }
+    }
+
+    impl<Clock, State, ExecutionTxs, Risk> OnDisconnectStrategy<Clock, State, ExecutionTxs, Risk>
+        for SingleOrder
+    {
+        type OnDisconnect = (InstrumentIndex, usize);
+
+        fn on_disconnect(
+            engine: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+            _: ExchangeId,
+        ) -> Self::OnDisconnect {
+            engine.strategy.disconnects += 1;
+            (engine.strategy.instrument, engine.strategy.disconnects)
+        }
+    }
+
+    impl<Clock, State, ExecutionTxs, Risk> OnTradingDisabled<Clock, State, ExecutionTxs, Risk>
+        for SingleOrder
+    {
+        type OnTradingDisabled = InstrumentIndex;
+
+        fn on_trading_disabled(
+            engine: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+        ) -> Self::OnTradingDisabled {
+            engine.strategy.instrument
+        }
+    }
+
+    fn attribution(keys: impl IntoIterator<Item = OrderKey>) -> Vec<(InstrumentIndex, StrategyId)> {
+        keys.into_iter()
+            .map(|key| (key.instrument, key.strategy))
+            .collect()
+    }
+
+    #[test]
+    fn test_multi_strategy_attributes_orders_to_owning_strategy() {
+        let strategy = MultiStrategy::new([
+            (StrategyId::new("first"), SingleOrder::new("first", 0)),
+            // Cancels an order owned by another strategy
+            (StrategyId::new("second"), SingleOrder::new("other", 1)),
+        ]);
+
+        let (cancels, opens) = strategy.generate_algo_orders(&());
+
+        // Cancels are only kept for orders owned by the generating strategy
+        assert_eq!(
+            attribution(cancels.into_iter().map(|cancel| cancel.key)),
+            vec![(InstrumentIndex(0), StrategyId::new("first"))]
+        );
+        assert_eq!(
+            attribution(opens.into_iter().map(|open| open.key)),
+            vec![
+                (InstrumentIndex(0), StrategyId::new("first")),
+                (InstrumentIndex(1), StrategyId::new("second")),
+            ]
+        );
+
+        assert_eq!(
+            strategy
+                .strategy(&StrategyId::new("second"))
+                .map(|strategy| strategy.instrument),
+            Some(InstrumentIndex(1))
+        );
+        assert!(strategy.strategy(&StrategyId::new("third")).is_none());
+    }
+
+    #[test]
+    fn test_multi_strategy_close_positions_fans_out_without_duplicates() {
+        let strategy = MultiStrategy::new([
+            (StrategyId::new("first"), SingleOrder::new("first", 0)),
+            (StrategyId::new("second"), SingleOrder::new("second", 0)),
+            (StrategyId::new("third"), SingleOrder::new("third", 1)),
+        ]);
+
+        let (cancels, opens) = strategy.close_positions_requests(&(), &InstrumentFilter::None);
+
+        assert_eq!(
+            attribution(cancels.into_iter().map(|cancel| cancel.key)),
+            vec![
+                (InstrumentIndex(0), StrategyId::new("first")),
+                (InstrumentIndex(0), StrategyId::new("second")),
+                (InstrumentIndex(1), StrategyId::new("third")),
+            ]
+        );
+
+        // Positions are tracked per instrument, so only the first close of each is kept
+        assert_eq!(
+            attribution(opens.into_iter().map(|open| open.key)),
+            vec![
+                (InstrumentIndex(0), StrategyId::new("first")),
+                (InstrumentIndex(1), StrategyId::new("third")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multi_strategy_fans_out_on_disconnect_and_on_trading_disabled() {
+        let mut engine = Engine::new(
+            HistoricalClock::new(DateTime::<Utc>::MIN_UTC),
+            (),
+            (),
+            MultiStrategy::new([
+                (StrategyId::new("first"), SingleOrder::new("first", 0)),
+                (StrategyId::new("second"), SingleOrder::new("second", 1)),
+            ]),
+            (),
+        );
+
+        for disconnects in 1..=2 {
+            let output = <MultiStrategy<SingleOrder> as OnDisconnectStrategy<
+                HistoricalClock,
+                (),
+                (),
+                (),
+            >>::on_disconnect(&mut engine, ExchangeId::BinanceSpot);
+
+            assert_eq!(
+                output,
+                vec![
+                    (StrategyId::new("first"), (InstrumentIndex(0), disconnects)),
+                    (StrategyId::new("second"), (InstrumentIndex(1), disconnects)),
+                ]
+            );
+        }
+
+        let output = <MultiStrategy<SingleOrder> as OnTradingDisabled<
+            HistoricalClock,
+            (),
+            (),
+            (),
+        >>::on_trading_disabled(&mut engine);
+
+        assert_eq!(
+            output,
+            vec![
+                (StrategyId::new("first"), InstrumentIndex(0)),
+                (StrategyId::new("second"), InstrumentIndex(1)),
+            ]
+        );
+
+        // Strategies are restored in order
+        assert_eq!(
+            attribution(
+                engine
+                    .strategy
+                    .strategies
+                    .iter()
+                    .map(|(_, strategy)| strategy.key(strategy.owner.clone()))
+            ),
+            vec![
+                (InstrumentIndex(0), StrategyId::new("first")),
+                (InstrumentIndex(1), StrategyId::new("second")),
+            ]
+        );
+    }
+
+    /// RiskManager that either approves or refuses every request.
+    #[derive(Debug)]
+    enum TestRisk {
+        Approve,
+        Refuse,
+    }
+
+    impl RiskManager for TestRisk {
+        type State = ();
+
+        fn check(
+            &self,
+            _: &Self::State,
+            cancels: impl IntoIterator<Item = OrderRequestCancel>,
+            opens: impl IntoIterator<Item = OrderRequestOpen>,
+        ) -> (
+            impl IntoIterator<Item = RiskApproved<OrderRequestCancel>>,
+            impl IntoIterator<Item = RiskApproved<OrderRequestOpen>>,
+            impl IntoIterator<Item = RiskRefused<OrderRequestCancel>>,
+            impl IntoIterator<Item = RiskRefused<OrderRequestOpen>>,
+        ) {
+            let cancels = cancels.into_iter().collect::<Vec<_>>();
+            let opens = opens.into_iter().collect::<Vec<_>>();
+
+            match self {
+                Self::Approve => (
+                    cancels.into_iter().map(RiskApproved::new).collect(),
+                    opens.into_iter().map(RiskApproved::new).collect(),
+                    vec![],
+                    vec![],
+                ),
+                Self::Refuse => (
+                    vec![],
+                    vec![],
+                    cancels
+                        .into_iter()
+                        .map(|cancel| RiskRefused::new(cancel, "refused"))
+                        .collect(),
+                    opens
+                        .into_iter()
+                        .map(|open| RiskRefused::new(open, "refused"))
+                        .collect(),
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn test_multi_strategy_risk_checks_requests_with_owning_strategy_risk() {
+        let risk = MultiStrategyRisk::new(
+            [(StrategyId::new("refused"), TestRisk::Refuse)],
+            TestRisk::Approve,
+        );
+
+        let strategy = MultiStrategy::new([
+            (StrategyId::new("approved"), SingleOrder::new("approved", 0)),
+            (StrategyId::new("refused"), SingleOrder::new("refused", 1)),
+        ]);
+        let (cancels, opens) = strategy.generate_algo_orders(&());
+
+        let (approved_cancels, approved_opens, refused_cancels, refused_opens) =
+            risk.check(&(), cancels, opens);
+
+        let approved = vec![(InstrumentIndex(0), StrategyId::new("approved"))];
+        let refused = vec![(InstrumentIndex(1), StrategyId::new("refused"))];
+
+        assert_eq!(
+            attribution(approved_cancels.into_iter().map(|cancel| cancel.0.key)),
+            approved
+        );
+        assert_eq!(
+            attribution(approved_opens.into_iter().map(|open| open.0.key)),
+            approved
+        );
+        assert_eq!(
+            attribution(refused_cancels.into_iter().map(|cancel| cancel.item.key)),
+            refused
+        );
+        assert_eq!(
+            attribution(refused_opens.into_iter().map(|open| open.item.key)),
+            refused
+        );
+    }
+}
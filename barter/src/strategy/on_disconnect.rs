@@ -17,9 +17,36 @@
 //! - 设置 `TradingState::Disabled`
 //! - 记录日志和通知
 //! - 等等
+//!
+//! # 可复用实现
+//!
+//! - [`cancel_orders_on_disconnect`]: 取消断开连接交易所上所有未完成订单的辅助函数，
+//!   可在任何自定义 `OnDisconnectStrategy` 实现中调用
+//! - [`CancelOrdersOnDisconnect`]: 包装任意策略（例如 `DefaultStrategy`），在断开连接时
+//!   取消该交易所的所有未完成订单
 
-use crate::engine::Engine;
-use barter_instrument::exchange::ExchangeId;
+use crate::{
+    engine::{
+        Engine,
+        action::{cancel_orders::CancelOrders, send_requests::SendRequestsOutput},
+        execution_tx::ExecutionTxMap,
+        state::{
+            EngineState, instrument::filter::InstrumentFilter,
+            order::in_flight_recorder::InFlightRequestRecorder,
+        },
+    },
+    strategy::{
+        algo::AlgoStrategy, close_positions::ClosePositionsStrategy,
+        on_trading_disabled::OnTradingDisabled,
+    },
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen, RequestCancel};
+use barter_instrument::{
+    exchange::{ExchangeId, ExchangeIndex},
+    instrument::InstrumentIndex,
+};
+use barter_integration::collection::{none_one_or_many::NoneOneOrMany, one_or_many::OneOrMany};
+use serde::{Deserialize, Serialize};
 
 /// 定义 [`Engine`] 在 [`ExchangeId`] 连接断开后应执行的操作的策略接口。
 ///
@@ -105,3 +132,170 @@ where
         exchange: ExchangeId,
     ) -> Self::OnDisconnect;
 }
+
+/// 取消断开连接的 [`ExchangeId`] 上所有未完成订单。
+///
+/// 使用 [`InstrumentFilter::Exchanges`] 筛选该交易所的订单，并通过 [`CancelOrders`]
+/// 发送取消请求（绕过风险检查），其他交易所的订单不受影响。
+///
+/// 此函数可在任何自定义 [`OnDisconnectStrategy`] 实现中调用，以便在断开连接时降低风险敞口。
+///
+/// # 参数
+///
+/// - `engine`: Engine 实例（可变引用）
+/// - `exchange`: 断开连接的交易所 ID
+///
+/// # 返回值
+///
+/// 返回发送的取消请求及任何发送错误。如果 `EngineState` 未跟踪该交易所，返回空输出。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// fn on_disconnect(
+///     engine: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+///     exchange: ExchangeId,
+/// ) -> Self::OnDisconnect {
+///     cancel_orders_on_disconnect(engine, exchange)
+/// }
+/// ```
+pub fn cancel_orders_on_disconnect<
+    Clock,
+    GlobalData,
+    InstrumentData,
+    ExecutionTxs,
+    Strategy,
+    Risk,
+>(
+    engine: &mut Engine<
+        Clock,
+        EngineState<GlobalData, InstrumentData>,
+        ExecutionTxs,
+        Strategy,
+        Risk,
+    >,
+    exchange: ExchangeId,
+) -> SendRequestsOutput<RequestCancel, ExchangeIndex, InstrumentIndex>
+where
+    InstrumentData: InFlightRequestRecorder,
+    ExecutionTxs: ExecutionTxMap,
+{
+    // ConnectivityStates 按 ExchangeIndex 顺序存储交易所
+    let Some(index) = engine.state.connectivity.exchanges.get_index_of(&exchange) else {
+        return SendRequestsOutput::new(NoneOneOrMany::None, NoneOneOrMany::None);
+    };
+
+    engine.cancel_orders(&InstrumentFilter::Exchanges(OneOrMany::One(ExchangeIndex(
+        index,
+    ))))
+}
+
+/// 在交易所断开连接时取消该交易所所有未完成订单的策略包装器。
+///
+/// `AlgoStrategy`、`ClosePositionsStrategy` 和 `OnTradingDisabled` 委托给被包装的策略，
+/// `OnDisconnectStrategy` 使用 [`cancel_orders_on_disconnect`] 实现。
+///
+/// 因此 `CancelOrdersOnDisconnect<DefaultStrategy<State>>` 在 `DefaultStrategy` 的基础上
+/// 仅替换了断开连接时的无操作行为，被包装策略自定义的 `OnTradingDisabled` 逻辑保持不变。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let strategy = CancelOrdersOnDisconnect::new(DefaultStrategy::default());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CancelOrdersOnDisconnect<Strategy> {
+    /// 被包装的策略。
+    pub strategy: Strategy,
+}
+
+impl<Strategy> CancelOrdersOnDisconnect<Strategy> {
+    /// 使用被包装的策略构造新的 [`CancelOrdersOnDisconnect`]。
+    pub fn new(strategy: Strategy) -> Self {
+        Self { strategy }
+    }
+}
+
+impl<Strategy, ExchangeKey, InstrumentKey> AlgoStrategy<ExchangeKey, InstrumentKey>
+    for CancelOrdersOnDisconnect<Strategy>
+where
+    Strategy: AlgoStrategy<ExchangeKey, InstrumentKey>,
+{
+    type State = Strategy::State;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeKey, InstrumentKey>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeKey, InstrumentKey>>,
+    ) {
+        self.strategy.generate_algo_orders(state)
+    }
+}
+
+impl<Strategy, ExchangeKey, AssetKey, InstrumentKey>
+    ClosePositionsStrategy<ExchangeKey, AssetKey, InstrumentKey>
+    for CancelOrdersOnDisconnect<Strategy>
+where
+    Strategy: ClosePositionsStrategy<ExchangeKey, AssetKey, InstrumentKey>,
+{
+    type State = Strategy::State;
+
+    fn close_positions_requests<'a>(
+        &'a self,
+        state: &'a Self::State,
+        filter: &'a InstrumentFilter<ExchangeKey, AssetKey, InstrumentKey>,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeKey, InstrumentKey>> + 'a,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeKey, InstrumentKey>> + 'a,
+    )
+    where
+        ExchangeKey: 'a,
+        AssetKey: 'a,
+        InstrumentKey: 'a,
+    {
+        self.strategy.close_positions_requests(state, filter)
+    }
+}
+
+impl<Clock, GlobalData, InstrumentData, ExecutionTxs, Risk, Strategy>
+    OnDisconnectStrategy<Clock, EngineState<GlobalData, InstrumentData>, ExecutionTxs, Risk>
+    for CancelOrdersOnDisconnect<Strategy>
+where
+    InstrumentData: InFlightRequestRecorder,
+    ExecutionTxs: ExecutionTxMap,
+{
+    type OnDisconnect = SendRequestsOutput<RequestCancel, ExchangeIndex, InstrumentIndex>;
+
+    fn on_disconnect(
+        engine: &mut Engine<
+            Clock,
+            EngineState<GlobalData, InstrumentData>,
+            ExecutionTxs,
+            Self,
+            Risk,
+        >,
+        exchange: ExchangeId,
+    ) -> Self::OnDisconnect {
+        cancel_orders_on_disconnect(engine, exchange)
+    }
+}
+
+impl<Clock, State, ExecutionTxs, Risk, Strategy> OnTradingDisabled<Clock, State, ExecutionTxs, Risk>
+    for CancelOrdersOnDisconnect<Strategy>
+where
+    Strategy: OnTradingDisabled<Clock, State, ExecutionTxs, Risk>,
+{
+    type OnTradingDisabled = Strategy::OnTradingDisabled;
+
+    fn on_trading_disabled(
+        engine: &mut Engine<Clock, State, ExecutionTxs, Self, Risk>,
+    ) -> Self::OnTradingDisabled {
+        engine.with_inner_strategy(
+            |wrapper| (wrapper.strategy, ()),
+            Strategy::on_trading_disabled,
+            |strategy, ()| Self::new(strategy),
+        )
+    }
+}
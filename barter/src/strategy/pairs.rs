@@ -0,0 +1,462 @@
+//! 配对交易策略模块
+//!
+//! 本模块定义了 [`PairsTrading`]，一个基于协整价差 z-score 的配对交易 [`AlgoStrategy`] 脚手架，
+//! 用于减少从零构建统计套利策略所需的样板代码。
+//!
+//! # 工作原理
+//!
+//! 1. 从 [`InstrumentDataState::price`] 获取两条腿的价格，计算价差：
+//!    `spread = price_a - hedge_ratio * price_b`（`hedge_ratio` 为离线估计的协整系数）
+//! 2. 价格变化时将价差加入滚动窗口，使用 [`WelfordAccumulator`] 计算窗口的均值和标准差，
+//!    得到当前价差的 z-score：`z = (spread - mean) / std_dev`
+//! 3. 无持仓时：
+//!    - `z >= entry_z`：价差偏高，做空价差（卖出 A，买入 B）
+//!    - `z <= -entry_z`：价差偏低，做多价差（买入 A，卖出 B）
+//! 4. 持有价差仓位时，z-score 回归越过退出阈值后平掉两条腿：
+//!    - 做空价差：`z <= exit_z`
+//!    - 做多价差：`z >= -exit_z`
+//! 5. 两条腿各按 `notional / price` 确定数量，使仓位大致名义中性
+//!
+//! # 注意事项
+//!
+//! 价差仓位由两个交易对的当前持仓确定，因此这两个交易对不应同时被其他策略交易。
+
+use crate::{
+    engine::state::{
+        EngineState,
+        instrument::{InstrumentState, data::InstrumentDataState},
+    },
+    statistic::algorithm::WelfordAccumulator,
+    strategy::algo::AlgoStrategy,
+};
+use barter_execution::order::{
+    OrderKey, OrderKind, TimeInForce,
+    id::{ClientOrderId, StrategyId},
+    request::{OrderRequestCancel, OrderRequestOpen, RequestOpen},
+};
+use barter_instrument::{Side, exchange::ExchangeIndex, instrument::InstrumentIndex};
+use derive_more::Constructor;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    marker::PhantomData,
+};
+
+/// [`PairsTrading`] 的配对参数。
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// // 20 个价差样本的滚动窗口，|z| >= 2 开仓，回归到 0 平仓，每条腿 1000 usdt
+/// let config = PairsConfig::new(
+///     InstrumentIndex(0), InstrumentIndex(1), dec!(1), 20, dec!(2), dec!(0), dec!(1000),
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize, Constructor)]
+pub struct PairsConfig {
+    /// 价差的 A 腿交易对。
+    pub instrument_a: InstrumentIndex,
+    /// 价差的 B 腿交易对。
+    pub instrument_b: InstrumentIndex,
+    /// 对冲比率（协整系数），`spread = price_a - hedge_ratio * price_b`。
+    pub hedge_ratio: Decimal,
+    /// 计算价差均值和标准差的滚动窗口样本数，窗口填满前不生成信号。
+    pub window: usize,
+    /// 开仓 z-score 阈值（正数）。
+    pub entry_z: Decimal,
+    /// 平仓 z-score 阈值，价差 z-score 回归越过该阈值后平仓。
+    pub exit_z: Decimal,
+    /// 每条腿的名义价值（计价资产，正数）。
+    pub notional: Decimal,
+}
+
+/// 价差仓位方向。
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+pub enum SpreadSide {
+    /// 做多价差：买入 A，卖出 B。
+    Long,
+    /// 做空价差：卖出 A，买入 B。
+    Short,
+}
+
+impl SpreadSide {
+    /// 返回价差仓位方向对应的 A 腿和 B 腿订单方向。
+    pub fn legs(&self) -> (Side, Side) {
+        match self {
+            Self::Long => (Side::Buy, Side::Sell),
+            Self::Short => (Side::Sell, Side::Buy),
+        }
+    }
+}
+
+/// 基于价差 z-score 的配对交易 [`AlgoStrategy`]。
+///
+/// 每次调用 `generate_algo_orders` 时，如果任一腿的价格变化，则更新价差滚动窗口，并根据
+/// z-score 为两条腿生成 `ImmediateOrCancel` `Market` 开仓或平仓订单。
+///
+/// 如果任一腿没有价格数据，或本策略在任一腿上仍有活跃订单（例如在途请求），则本次不生成订单。
+///
+/// ## 类型参数
+///
+/// - `GlobalData`: 全局数据类型
+/// - `InstrumentData`: 交易对数据类型，提供当前价格
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// let strategy = PairsTrading::new(StrategyId::new("pairs"), config);
+/// let (cancels, opens) = strategy.generate_algo_orders(&state);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PairsTrading<GlobalData, InstrumentData> {
+    /// 两条腿订单共用的策略 ID。
+    pub strategy: StrategyId,
+    /// 配对参数。
+    pub config: PairsConfig,
+    spreads: RefCell<VecDeque<Decimal>>,
+    prices_last: Cell<Option<(Decimal, Decimal)>>,
+    phantom: PhantomData<(GlobalData, InstrumentData)>,
+}
+
+impl<GlobalData, InstrumentData> PairsTrading<GlobalData, InstrumentData> {
+    /// 构造新的 [`PairsTrading`]。
+    ///
+    /// # 参数
+    ///
+    /// - `strategy`: 两条腿订单共用的策略 ID
+    /// - `config`: 配对参数
+    pub fn new(strategy: StrategyId, config: PairsConfig) -> Self {
+        Self {
+            strategy,
+            config,
+            spreads: RefCell::new(VecDeque::with_capacity(config.window)),
+            prices_last: Cell::new(None),
+            phantom: PhantomData,
+        }
+    }
+
+    /// 使用两条腿的最新价格更新价差滚动窗口，并返回当前价差的 z-score。
+    ///
+    /// 价格与上次更新相同时不会加入新的价差样本。窗口填满前或窗口标准差为零时返回 `None`。
+    pub fn update_spread(&self, price_a: Decimal, price_b: Decimal) -> Option<Decimal> {
+        let mut spreads = self.spreads.borrow_mut();
+
+        if self.prices_last.get() != Some((price_a, price_b)) {
+            self.prices_last.set(Some((price_a, price_b)));
+
+            if spreads.len() == self.config.window {
+                spreads.pop_front();
+            }
+            spreads.push_back(price_a - self.config.hedge_ratio * price_b);
+        }
+
+        if self.config.window == 0 || spreads.len() < self.config.window {
+            return None;
+        }
+
+        let spread = *spreads.back()?;
+        let welford = spreads.iter().copied().collect::<WelfordAccumulator>();
+
+        (spread - welford.mean).checked_div(welford.population_std_dev())
+    }
+
+    /// 基于 z-score 返回需要开仓的价差方向，z-score 在开仓阈值内时返回 `None`。
+    pub fn entry_signal(&self, z_score: Decimal) -> Option<SpreadSide> {
+        if z_score >= self.config.entry_z {
+            Some(SpreadSide::Short)
+        } else if z_score <= -self.config.entry_z {
+            Some(SpreadSide::Long)
+        } else {
+            None
+        }
+    }
+
+    /// 如果 z-score 已回归越过当前价差仓位方向的平仓阈值，返回 `true`。
+    pub fn exit_signal(&self, z_score: Decimal, side: SpreadSide) -> bool {
+        match side {
+            SpreadSide::Long => z_score >= -self.config.exit_z,
+            SpreadSide::Short => z_score <= self.config.exit_z,
+        }
+    }
+
+    fn open_request(
+        &self,
+        state: &InstrumentState<InstrumentData>,
+        side: Side,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> OrderRequestOpen {
+        OrderRequestOpen {
+            key: OrderKey {
+                exchange: state.instrument.exchange,
+                instrument: state.key,
+                strategy: self.strategy.clone(),
+                cid: ClientOrderId::random(),
+            },
+            state: RequestOpen {
+                side,
+                price,
+                quantity,
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        }
+    }
+}
+
+impl<GlobalData, InstrumentData> AlgoStrategy for PairsTrading<GlobalData, InstrumentData>
+where
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) {
+        let leg_a = state
+            .instruments
+            .instrument_index(&self.config.instrument_a);
+        let leg_b = state
+            .instruments
+            .instrument_index(&self.config.instrument_b);
+
+        let (Some(price_a), Some(price_b)) = (leg_a.data.price(), leg_b.data.price()) else {
+            return (std::iter::empty(), Vec::new());
+        };
+
+        let Some(z_score) = self.update_spread(price_a, price_b) else {
+            return (std::iter::empty(), Vec::new());
+        };
+
+        let has_active_orders = [leg_a, leg_b].into_iter().any(|leg| {
+            leg.orders
+                .active
+                .values()
+                .any(|order| order.key.strategy == self.strategy)
+        });
+        if has_active_orders || price_a <= Decimal::ZERO || price_b <= Decimal::ZERO {
+            return (std::iter::empty(), Vec::new());
+        }
+
+        let inventory_a = net_inventory(leg_a);
+        let inventory_b = net_inventory(leg_b);
+
+        let opens = if inventory_a.is_zero() && inventory_b.is_zero() {
+            self.entry_signal(z_score)
+                .map(|side| {
+                    let (side_a, side_b) = side.legs();
+                    vec![
+                        self.open_request(leg_a, side_a, price_a, self.config.notional / price_a),
+                        self.open_request(leg_b, side_b, price_b, self.config.notional / price_b),
+                    ]
+                })
+                .unwrap_or_default()
+        } else {
+            let side = if inventory_a > Decimal::ZERO
+                || (inventory_a.is_zero() && inventory_b < Decimal::ZERO)
+            {
+                SpreadSide::Long
+            } else {
+                SpreadSide::Short
+            };
+
+            if self.exit_signal(z_score, side) {
+                [(leg_a, price_a, inventory_a), (leg_b, price_b, inventory_b)]
+                    .into_iter()
+                    .filter(|(_, _, inventory)| !inventory.is_zero())
+                    .map(|(leg, price, inventory)| {
+                        let side = if inventory.is_sign_positive() {
+                            Side::Sell
+                        } else {
+                            Side::Buy
+                        };
+                        self.open_request(leg, side, price, inventory.abs())
+                    })
+                    .collect()
+            } else {
+                Vec::new()
+            }
+        };
+
+        (std::iter::empty(), opens)
+    }
+}
+
+/// 返回交易对的当前净库存（多头为正，空头为负）。
+fn net_inventory<InstrumentData>(state: &InstrumentState<InstrumentData>) -> Decimal {
+    state
+        .position
+        .positions()
+        .map(|position| position.side.sign() * position.quantity_abs)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData},
+        test_utils::spot_engine_state,
+    };
+    use barter_data::{books::Level, subscription::book::OrderBookL1};
+    use barter_execution::{
+        order::id::OrderId,
+        trade::{AssetFees, Trade, TradeId},
+    };
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    type State = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn state() -> State {
+        spot_engine_state(&["btc", "eth"])
+    }
+
+    fn set_price(state: &mut State, instrument: InstrumentIndex, price: Decimal) {
+        state.instruments.instrument_index_mut(&instrument).data.l1 = OrderBookL1::new(
+            DateTime::<Utc>::MIN_UTC,
+            Some(Level::new(price, dec!(1))),
+            Some(Level::new(price, dec!(1))),
+        );
+    }
+
+    fn fill(state: &mut State, request: &OrderRequestOpen) {
+        state
+            .instruments
+            .instrument_index_mut(&request.key.instrument)
+            .position
+            .update_from_trade(&Trade {
+                id: TradeId::new("trade"),
+                order_id: OrderId::new("order"),
+                instrument: request.key.instrument,
+                strategy: request.key.strategy.clone(),
+                time_exchange: DateTime::<Utc>::MIN_UTC,
+                side: request.state.side,
+                price: request.state.price,
+                quantity: request.state.quantity,
+                fees: AssetFees::quote_fees(Decimal::ZERO),
+                reduce_only: false,
+            });
+    }
+
+    #[test]
+    fn test_pairs_trading_enters_at_band_and_exits_at_reversion() {
+        struct TestCase {
+            spread: Decimal,
+            expected: Vec<(InstrumentIndex, Side)>,
+        }
+
+        let a = InstrumentIndex(0);
+        let b = InstrumentIndex(1);
+        let strategy = PairsTrading::<DefaultGlobalData, DefaultInstrumentMarketData>::new(
+            StrategyId::new("pairs"),
+            PairsConfig::new(a, b, dec!(1), 5, dec!(1.5), dec!(0.25), dec!(1000)),
+        );
+        let mut state = state();
+
+        // Mean-reverting synthetic spread around zero, with B fixed at 100
+        let tests = vec![
+            TestCase {
+                // TC0: window warming up
+                spread: dec!(0),
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: window warming up
+                spread: dec!(1),
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: window warming up
+                spread: dec!(-1),
+                expected: vec![],
+            },
+            TestCase {
+                // TC3: window warming up
+                spread: dec!(0),
+                expected: vec![],
+            },
+            TestCase {
+                // TC4: z = 1.07 inside the entry band
+                spread: dec!(1),
+                expected: vec![],
+            },
+            TestCase {
+                // TC5: z = -1.12 inside the entry band
+                spread: dec!(-1),
+                expected: vec![],
+            },
+            TestCase {
+                // TC6: z = 0.27 inside the entry band
+                spread: dec!(0),
+                expected: vec![],
+            },
+            TestCase {
+                // TC7: z = 1.93 crosses the entry band, so short the spread
+                spread: dec!(6),
+                expected: vec![(a, Side::Sell), (b, Side::Buy)],
+            },
+            TestCase {
+                // TC8: z = 0.48 still above the exit band, so hold
+                spread: dec!(3),
+                expected: vec![],
+            },
+            TestCase {
+                // TC9: z = -0.48 reverted through the exit band, so close both legs
+                spread: dec!(0.5),
+                expected: vec![(a, Side::Buy), (b, Side::Sell)],
+            },
+        ];
+
+        let mut entry_quantities = Vec::new();
+        for (index, test) in tests.into_iter().enumerate() {
+            set_price(&mut state, a, dec!(100) + test.spread);
+            set_price(&mut state, b, dec!(100));
+
+            let (cancels, opens) = strategy.generate_algo_orders(&state);
+            let opens = opens.into_iter().collect::<Vec<_>>();
+            assert_eq!(cancels.into_iter().count(), 0, "TC{index} failed");
+
+            let actual = opens
+                .iter()
+                .map(|open| (open.key.instrument, open.state.side))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{index} failed");
+            assert!(
+                opens
+                    .iter()
+                    .all(|open| open.key.strategy == StrategyId::new("pairs")),
+                "TC{index} failed"
+            );
+
+            if index == 7 {
+                // Legs are sized to be notional neutral
+                assert_eq!(opens[0].state.quantity, dec!(1000) / dec!(106));
+                assert_eq!(opens[1].state.quantity, dec!(10));
+                entry_quantities = opens.iter().map(|open| open.state.quantity).collect();
+            }
+
+            if index == 9 {
+                // Closes the full entry quantity of each leg
+                let quantities = opens
+                    .iter()
+                    .map(|open| open.state.quantity)
+                    .collect::<Vec<_>>();
+                assert_eq!(quantities, entry_quantities);
+            }
+
+            opens.iter().for_each(|open| fill(&mut state, open));
+        }
+
+        // Both legs flat after the exit
+        assert!(net_inventory(state.instruments.instrument_index(&a)).is_zero());
+        assert!(net_inventory(state.instruments.instrument_index(&b)).is_zero());
+    }
+}
@@ -0,0 +1,270 @@
+//! 重新报价阈值模块
+//!
+//! 本模块定义了 [`RequoteGate`]，一个可与任意 [`AlgoStrategy`] 组合的包装器，仅当参考价格
+//! 相对于上次报价时的参考价格变动超过配置的阈值时，才允许被包装的策略重新生成报价。
+//!
+//! 做市策略（例如 [`InventoryMM`](super::mm::InventoryMM)）通常会在每次最小价格变动时
+//! 撤单并重新报价，这会产生大量的取消/开仓请求。`RequoteGate` 用于抑制这种报价抖动。
+//!
+//! # 工作原理
+//!
+//! 1. 从 [`InstrumentDataState::price`] 获取每个交易对的参考价格
+//! 2. 如果交易对从未报价，或参考价格相对于上次报价时的参考价格变动超过 [`RequoteThreshold`]，
+//!    则该交易对需要重新报价
+//! 3. 如果没有交易对需要重新报价，直接返回空的取消和开仓请求，不调用被包装的策略
+//! 4. 否则调用被包装的策略，仅保留需要重新报价的交易对的订单请求
+//! 5. 对于生成了订单请求的交易对，记录当前参考价格作为上次报价时的参考价格
+
+use crate::{
+    engine::state::{
+        EngineState,
+        instrument::{data::InstrumentDataState, filter::InstrumentFilter},
+    },
+    strategy::algo::AlgoStrategy,
+};
+use barter_execution::order::request::{OrderRequestCancel, OrderRequestOpen};
+use barter_instrument::{exchange::ExchangeIndex, instrument::InstrumentIndex};
+use fnv::{FnvHashMap, FnvHashSet};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+
+/// 触发重新报价所需的最小参考价格变动。
+///
+/// ## 阈值说明
+///
+/// - **Ticks**: 价格变动超过 `ticks` 个 `tick_size`
+/// - **Bps**: 价格变动超过上次报价参考价格的 `bps` 个基点（1bps = 0.01%）
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub enum RequoteThreshold {
+    /// 价格变动超过 `ticks * tick_size`。
+    Ticks { tick_size: Decimal, ticks: Decimal },
+
+    /// 价格变动超过上次报价参考价格的 `bps` 个基点。
+    Bps(Decimal),
+}
+
+impl RequoteThreshold {
+    /// 如果参考价格从 `last` 变动到 `price` 超过了阈值，返回 `true`。
+    pub fn exceeded(&self, last: Decimal, price: Decimal) -> bool {
+        let movement = (price - last).abs();
+
+        match *self {
+            Self::Ticks { tick_size, ticks } => movement > tick_size * ticks,
+            Self::Bps(bps) => {
+                if last.is_zero() {
+                    return !movement.is_zero();
+                }
+                movement / last.abs() * Decimal::from(10_000) > bps
+            }
+        }
+    }
+}
+
+/// 仅在参考价格变动超过 [`RequoteThreshold`] 时才允许被包装的 [`AlgoStrategy`] 重新报价。
+///
+/// 每个交易对上次报价时的参考价格在内部按 [`InstrumentIndex`] 存储。在阈值内的交易对的
+/// 订单请求会被丢弃，因此现有报价保持不变。
+///
+/// ## 类型参数
+///
+/// - `Strategy`: 被包装的策略类型
+///
+/// # 使用示例
+///
+/// ```rust,ignore
+/// // 参考价格变动超过 5bps 时才重新报价
+/// let strategy = RequoteGate::new(
+///     InventoryMM::new(StrategyId::new("mm"), config, InstrumentFilter::None),
+///     RequoteThreshold::Bps(dec!(5)),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RequoteGate<Strategy> {
+    /// 被包装的策略。
+    pub strategy: Strategy,
+    /// 触发重新报价所需的最小参考价格变动。
+    pub threshold: RequoteThreshold,
+    last_quoted: RefCell<FnvHashMap<InstrumentIndex, Decimal>>,
+}
+
+impl<Strategy> RequoteGate<Strategy> {
+    /// 使用提供的策略和 [`RequoteThreshold`] 构造新的 [`RequoteGate`]。
+    pub fn new(strategy: Strategy, threshold: RequoteThreshold) -> Self {
+        Self {
+            strategy,
+            threshold,
+            last_quoted: RefCell::new(FnvHashMap::default()),
+        }
+    }
+
+    /// 返回交易对上次报价时的参考价格（如果存在）。
+    pub fn last_quoted(&self, instrument: &InstrumentIndex) -> Option<Decimal> {
+        self.last_quoted.borrow().get(instrument).copied()
+    }
+
+    /// 清除所有上次报价时的参考价格，使下一次调用时所有交易对都重新报价。
+    pub fn reset(&self) {
+        self.last_quoted.borrow_mut().clear();
+    }
+
+    /// 如果交易对在参考价格 `price` 下需要重新报价，返回 `true`。
+    fn requote_due(&self, instrument: &InstrumentIndex, price: Decimal) -> bool {
+        self.last_quoted(instrument)
+            .is_none_or(|last| self.threshold.exceeded(last, price))
+    }
+}
+
+impl<Strategy, GlobalData, InstrumentData> AlgoStrategy for RequoteGate<Strategy>
+where
+    Strategy: AlgoStrategy<State = EngineState<GlobalData, InstrumentData>>,
+    InstrumentData: InstrumentDataState,
+{
+    type State = EngineState<GlobalData, InstrumentData>;
+
+    fn generate_algo_orders(
+        &self,
+        state: &Self::State,
+    ) -> (
+        impl IntoIterator<Item = OrderRequestCancel<ExchangeIndex, InstrumentIndex>>,
+        impl IntoIterator<Item = OrderRequestOpen<ExchangeIndex, InstrumentIndex>>,
+    ) {
+        let due = state
+            .instruments
+            .instruments(&InstrumentFilter::None)
+            .filter_map(|instrument| {
+                let price = instrument.data.price()?;
+                self.requote_due(&instrument.key, price)
+                    .then_some((instrument.key, price))
+            })
+            .collect::<FnvHashMap<_, _>>();
+
+        if due.is_empty() {
+            return (Vec::new(), Vec::new());
+        }
+
+        let (cancels, opens) = self.strategy.generate_algo_orders(state);
+
+        let cancels = cancels
+            .into_iter()
+            .filter(|cancel| due.contains_key(&cancel.key.instrument))
+            .collect::<Vec<_>>();
+        let opens = opens
+            .into_iter()
+            .filter(|open| due.contains_key(&open.key.instrument))
+            .collect::<Vec<_>>();
+
+        let quoted = cancels
+            .iter()
+            .map(|cancel| cancel.key.instrument)
+            .chain(opens.iter().map(|open| open.key.instrument))
+            .collect::<FnvHashSet<_>>();
+
+        let mut last_quoted = self.last_quoted.borrow_mut();
+        for instrument in quoted {
+            last_quoted.insert(instrument, due[&instrument]);
+        }
+
+        (cancels, opens)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        engine::state::{global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData},
+        strategy::mm::{InventoryMM, InventoryMMConfig},
+        test_utils::spot_engine_state,
+    };
+    use barter_data::{books::Level, subscription::book::OrderBookL1};
+    use barter_execution::order::id::StrategyId;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    type State = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    fn state() -> State {
+        let mut state = spot_engine_state(&["btc"]);
+
+        set_mid(&mut state, dec!(100));
+        state
+    }
+
+    fn set_mid(state: &mut State, mid: Decimal) {
+        state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(0))
+            .data
+            .l1 = OrderBookL1::new(
+            DateTime::<Utc>::MIN_UTC,
+            Some(Level::new(mid - dec!(1), dec!(1))),
+            Some(Level::new(mid + dec!(1), dec!(1))),
+        );
+    }
+
+    fn strategy(
+        threshold: RequoteThreshold,
+    ) -> RequoteGate<InventoryMM<DefaultGlobalData, DefaultInstrumentMarketData>> {
+        RequoteGate::new(
+            InventoryMM::new(
+                StrategyId::new("mm"),
+                InventoryMMConfig::new(dec!(0.01), dec!(0), dec!(1), dec!(5), dec!(0)),
+                InstrumentFilter::None,
+            ),
+            threshold,
+        )
+    }
+
+    fn num_requests(strategy: &impl AlgoStrategy<State = State>, state: &State) -> (usize, usize) {
+        let (cancels, opens) = strategy.generate_algo_orders(state);
+        (cancels.into_iter().count(), opens.into_iter().count())
+    }
+
+    #[test]
+    fn test_requote_threshold_exceeded() {
+        let ticks = RequoteThreshold::Ticks {
+            tick_size: dec!(0.1),
+            ticks: dec!(3),
+        };
+        assert!(!ticks.exceeded(dec!(100), dec!(100.3)));
+        assert!(!ticks.exceeded(dec!(100), dec!(99.7)));
+        assert!(ticks.exceeded(dec!(100), dec!(100.4)));
+        assert!(ticks.exceeded(dec!(100), dec!(99.6)));
+
+        let bps = RequoteThreshold::Bps(dec!(10));
+        assert!(!bps.exceeded(dec!(100), dec!(100.1)));
+        assert!(bps.exceeded(dec!(100), dec!(100.11)));
+        assert!(bps.exceeded(dec!(100), dec!(99.89)));
+    }
+
+    #[test]
+    fn test_requote_gate_requotes_only_once_threshold_exceeded() {
+        let strategy = strategy(RequoteThreshold::Bps(dec!(10)));
+        let mut state = state();
+
+        // Never quoted: quotes are generated & reference price is stored
+        assert_eq!(num_requests(&strategy, &state), (0, 2));
+        assert_eq!(strategy.last_quoted(&InstrumentIndex(0)), Some(dec!(100)));
+
+        // Within threshold: no re-quote, reference price unchanged
+        set_mid(&mut state, dec!(100.05));
+        assert_eq!(num_requests(&strategy, &state), (0, 0));
+        set_mid(&mut state, dec!(99.9));
+        assert_eq!(num_requests(&strategy, &state), (0, 0));
+        assert_eq!(strategy.last_quoted(&InstrumentIndex(0)), Some(dec!(100)));
+
+        // Threshold exceeded: re-quote & update reference price
+        set_mid(&mut state, dec!(100.2));
+        assert_eq!(num_requests(&strategy, &state), (0, 2));
+        assert_eq!(strategy.last_quoted(&InstrumentIndex(0)), Some(dec!(100.2)));
+
+        // Threshold is measured relative to the last quoted reference price
+        set_mid(&mut state, dec!(100.25));
+        assert_eq!(num_requests(&strategy, &state), (0, 0));
+
+        // Reset forces a re-quote
+        strategy.reset();
+        assert_eq!(num_requests(&strategy, &state), (0, 2));
+    }
+}
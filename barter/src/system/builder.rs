@@ -9,6 +9,7 @@
 //! - **SystemArgs**: 构建系统所需的参数
 //! - **SystemBuild**: 已构建但未初始化的系统
 //! - **EngineFeedMode**: Engine 事件处理模式（Iterator 或 Stream）
+//! - **EngineFeedPriority**: Engine 事件处理顺序（先进先出或优先处理控制事件）
 //! - **AuditMode**: 审计模式（启用或禁用）
 
 use crate::{
@@ -16,20 +17,30 @@ use crate::{
         Engine, Processor,
         audit::{Auditor, context::EngineContext},
         clock::EngineClock,
-        execution_tx::MultiExchangeTxMap,
+        execution_tx::{ExecutionTxMap, MultiExchangeTxMap},
+        feed::{EngineFeed, EngineFeedPriority, FeedPriority},
+        heartbeat::ExecutionHeartbeat,
         run::{async_run, async_run_with_audit, sync_run, sync_run_with_audit},
         state::{EngineState, builder::EngineStateBuilder, trading::TradingState},
+        swap::StrategySwap,
     },
     error::BarterError,
     execution::{
         AccountStreamEvent,
         builder::{ExecutionBuildFutures, ExecutionBuilder},
+        paper::PaperMarketFeed,
     },
     shutdown::SyncShutdown,
-    system::{System, SystemAuxillaryHandles, config::ExecutionConfig},
+    system::{
+        System, SystemAuxillaryHandles,
+        config::{ExecutionConfig, InitialBalances},
+    },
 };
 use barter_data::streams::reconnect::stream::ReconnectingStream;
-use barter_execution::balance::Balance;
+use barter_execution::{
+    AccountEvent, AccountEventKind, UnindexedAccountSnapshot, balance::Balance,
+    indexer::AccountEventIndexer, map::generate_execution_instrument_map,
+};
 use barter_instrument::{
     Keyed,
     asset::{AssetIndex, ExchangeAsset, name::AssetNameInternal},
@@ -46,7 +57,7 @@ use derive_more::Constructor;
 use fnv::FnvHashMap;
 use futures::Stream;
 use serde::{Deserialize, Serialize};
-use std::{fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, marker::PhantomData, sync::Arc, time::Duration};
 
 /// 定义 `Engine` 如何处理输入事件。
 ///
@@ -167,7 +178,7 @@ pub struct SystemArgs<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnIns
 /// ## 使用流程
 ///
 /// 1. 创建 SystemBuilder（使用 SystemArgs）
-/// 2. 可选配置（engine_feed_mode、audit_mode、trading_state、balances）
+/// 2. 可选配置（engine_feed_mode、audit_mode、trading_state、balance_coalescing、paper_market_feed、balances、initial_balances）
 /// 3. 调用 `build()` 构建系统
 /// 4. 调用 `init()` 初始化系统
 ///
@@ -202,6 +213,18 @@ pub struct SystemBuilder<'a, Clock, Strategy, Risk, MarketStream, GlobalData, Fn
     trading_state: Option<TradingState>,
     /// 初始交易所资产余额。
     balances: FnvHashMap<ExchangeAsset<AssetNameInternal>, Balance>,
+    /// 预先获取的初始账户快照（每个交易所一个）。
+    account_snapshots: Vec<UnindexedAccountSnapshot>,
+    /// 回测和模拟运行的初始"纸面"余额。
+    initial_balances: InitialBalances,
+    /// 可选的余额快照合并时间窗口。
+    balance_coalescing: Option<Duration>,
+    /// 可选的实盘纸面交易馈送。
+    paper_market_feed: Option<PaperMarketFeed>,
+    /// 可选的 Strategy 热替换，默认禁用。
+    strategy_swap: Option<StrategySwap<Strategy>>,
+    /// 可选的执行心跳间隔，默认禁用。
+    execution_heartbeat: Option<Duration>,
 }
 
 impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
@@ -227,6 +250,12 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
             audit_mode: None,
             trading_state: None,
             balances: FnvHashMap::default(),
+            account_snapshots: Vec::new(),
+            initial_balances: InitialBalances::default(),
+            balance_coalescing: None,
+            paper_market_feed: None,
+            strategy_swap: None,
+            execution_heartbeat: None,
         }
     }
 
@@ -248,6 +277,44 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
         }
     }
 
+    /// 可选配置执行心跳间隔（参见 [`ExecutionHeartbeat`]）。
+    ///
+    /// 启用后，`System` 在 Engine 运行期间按墙钟每隔 `value` 向所有 ExecutionManager 发送
+    /// [`ExecutionRequest::Heartbeat`](crate::execution::request::ExecutionRequest::Heartbeat)，
+    /// 与 Engine 是否在处理事件无关。启用死人开关时，`value` 应小于死人开关的 `timeout`。
+    ///
+    /// # 参数
+    ///
+    /// - `value`: 发送心跳的间隔
+    ///
+    /// # 返回值
+    ///
+    /// 返回更新后的 SystemBuilder。
+    pub fn execution_heartbeat(self, value: Duration) -> Self {
+        Self {
+            execution_heartbeat: Some(value),
+            ..self
+        }
+    }
+
+    /// 可选启用 Strategy 热替换（参见 [`Engine::with_strategy_swap`]）。
+    ///
+    /// 启用后可以通过 [`System::swap_strategy`] 在运行时
+    /// 替换 Strategy 实例。替换需要在运行时检查新 Strategy 的类型，因此要求 Strategy 为 `'static`。
+    ///
+    /// # 返回值
+    ///
+    /// 返回更新后的 SystemBuilder。
+    pub fn with_strategy_swap(self) -> Self
+    where
+        Strategy: 'static,
+    {
+        Self {
+            strategy_swap: Some(StrategySwap::new()),
+            ..self
+        }
+    }
+
     /// 可选配置 [`AuditMode`]（启用或禁用）。
     ///
     /// 控制 Engine 是否发送其产生的审计事件。
@@ -284,6 +351,45 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
         }
     }
 
+    /// 可选在提供的时间窗口内按资产合并账户流的余额快照。
+    ///
+    /// 减少高频余额更新造成的 `AssetState` 和统计更新。详见
+    /// [`ExecutionBuilder::with_balance_coalescing`]。
+    ///
+    /// # 参数
+    ///
+    /// - `window`: 合并时间窗口
+    ///
+    /// # 返回值
+    ///
+    /// 返回更新后的 SystemBuilder。
+    pub fn balance_coalescing(self, window: Duration) -> Self {
+        Self {
+            balance_coalescing: Some(window),
+            ..self
+        }
+    }
+
+    /// 可选提供实盘纸面交易 [`PaperMarketFeed`]，为以市场价格成交的模拟交易所提供实时
+    /// 市场价格。
+    ///
+    /// `market_stream` 应该是 [`PaperMarketFeed::forward`] 包装的实时市场数据流。详见
+    /// [`ExecutionBuilder::with_paper_market_feed`]。
+    ///
+    /// # 参数
+    ///
+    /// - `paper_market_feed`: 实盘纸面交易馈送
+    ///
+    /// # 返回值
+    ///
+    /// 返回更新后的 SystemBuilder。
+    pub fn paper_market_feed(self, paper_market_feed: PaperMarketFeed) -> Self {
+        Self {
+            paper_market_feed: Some(paper_market_feed),
+            ..self
+        }
+    }
+
     /// 可选提供初始交易所资产 `Balance`。
     ///
     /// 对于需要在 EngineState 中初始化初始 `Balance` 的回测场景很有用。
@@ -315,6 +421,59 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
         self
     }
 
+    /// 可选提供回测和模拟运行的初始"纸面"余额。
+    ///
+    /// 在构建时，初始余额同时用于初始化 EngineState 的 `AssetStates` 和每个
+    /// [`ExecutionConfig::Mock`] 的 `MockExchange` 内部余额，因此 PnL 和保证金检查从运行开始
+    /// 就基于真实的初始资金。
+    ///
+    /// 注意：通过 [`Self::balances`] 提供的相同 `ExchangeAsset` 余额优先。
+    ///
+    /// # 参数
+    ///
+    /// - `initial_balances`: 初始余额（参见 [`InitialBalances`]）
+    ///
+    /// # 返回值
+    ///
+    /// 返回更新后的 SystemBuilder。
+    pub fn initial_balances(mut self, initial_balances: InitialBalances) -> Self {
+        self.initial_balances = initial_balances;
+        self
+    }
+
+    /// 可选提供预先获取的初始 [`UnindexedAccountSnapshot`]（每个交易所一个）。
+    ///
+    /// 对于已通过 REST 请求获取当前账户状态的实盘场景很有用。在构建 Engine 之前，每个快照都会
+    /// 作为第一个 `AccountEvent` 应用到 EngineState，因此余额、订单和仓位从第一个事件起就是
+    /// 正确的，而无需等待第一个流式账户快照。
+    ///
+    /// 注意：同一交易所的多个快照会按提供顺序依次应用。
+    ///
+    /// # 参数
+    ///
+    /// - `snapshots`: 初始账户快照迭代器
+    ///
+    /// # 返回值
+    ///
+    /// 返回更新后的 SystemBuilder。
+    ///
+    /// # 使用示例
+    ///
+    /// ```rust,ignore
+    /// let snapshot = client.account_snapshot(&assets, &instruments).await?;
+    ///
+    /// let system = SystemBuilder::new(args)
+    ///     .account_snapshots([snapshot])
+    ///     .build::<EngineEvent, _>()?;
+    /// ```
+    pub fn account_snapshots<SnapshotIter>(mut self, snapshots: SnapshotIter) -> Self
+    where
+        SnapshotIter: IntoIterator<Item = UnindexedAccountSnapshot>,
+    {
+        self.account_snapshots.extend(snapshots);
+        self
+    }
+
     /// 使用配置的构建器设置构建 [`SystemBuild`]。
     ///
     /// 此方法构建所有系统组件，但不启动任何任务或流。
@@ -324,7 +483,7 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
     /// ## 构建流程
     ///
     /// 1. 构建执行基础设施
-    /// 2. 构建 EngineState
+    /// 2. 构建 EngineState，并应用任何预先获取的初始账户快照
     /// 3. 构造 Engine
     /// 4. 返回 SystemBuild
     ///
@@ -357,12 +516,14 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
         FnInstrumentData: Fn(
             &'a Keyed<InstrumentIndex, Instrument<Keyed<ExchangeIndex, ExchangeId>, AssetIndex>>,
         ) -> InstrumentData,
+        GlobalData: for<'b> Processor<&'b AccountEvent>,
+        InstrumentData: for<'b> Processor<&'b AccountEvent>,
     {
         let Self {
             args:
                 SystemArgs {
                     instruments,
-                    executions,
+                    mut executions,
                     clock,
                     strategy,
                     risk,
@@ -373,7 +534,13 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
             engine_feed_mode,
             audit_mode,
             trading_state,
-            balances,
+            mut balances,
+            account_snapshots,
+            initial_balances,
+            balance_coalescing,
+            paper_market_feed,
+            strategy_swap,
+            execution_heartbeat,
         } = self;
 
         // Default if not provided
@@ -381,21 +548,30 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
         let audit_mode = audit_mode.unwrap_or_default();
         let trading_state = trading_state.unwrap_or_default();
 
+        // Seed initial "paper" balances, with explicitly provided balances taking precedence
+        initial_balances.seed_executions(&mut executions, clock.time());
+        for Keyed { key, value } in initial_balances.engine_balances() {
+            balances.entry(key).or_insert(value);
+        }
+
         // Build Execution infrastructure
+        let execution_builder = match balance_coalescing {
+            Some(window) => ExecutionBuilder::new(instruments).with_balance_coalescing(window),
+            None => ExecutionBuilder::new(instruments),
+        };
+        let execution_builder = match paper_market_feed {
+            Some(feed) => execution_builder.with_paper_market_feed(feed),
+            None => execution_builder,
+        };
         let execution = executions
             .into_iter()
-            .try_fold(
-                ExecutionBuilder::new(instruments),
-                |builder, config| match config {
-                    ExecutionConfig::Mock(mock_config) => {
-                        builder.add_mock(mock_config, clock.clone())
-                    }
-                },
-            )?
+            .try_fold(execution_builder, |builder, config| match config {
+                ExecutionConfig::Mock(mock_config) => builder.add_mock(mock_config, clock.clone()),
+            })?
             .build();
 
         // Build EngineState
-        let state = EngineStateBuilder::new(instruments, global_data, instrument_data_init)
+        let mut state = EngineStateBuilder::new(instruments, global_data, instrument_data_init)
             .time_engine_start(clock.time())
             .trading_state(trading_state)
             .balances(
@@ -405,12 +581,38 @@ impl<'a, Clock, Strategy, Risk, MarketStream, GlobalData, FnInstrumentData>
             )
             .build();
 
+        // Apply any pre-fetched AccountSnapshots as the first AccountEvents
+        for snapshot in account_snapshots {
+            let indexer = AccountEventIndexer::new(Arc::new(generate_execution_instrument_map(
+                instruments,
+                snapshot.exchange,
+            )?));
+
+            let snapshot = indexer.snapshot(snapshot)?;
+            let _ = state.update_from_account(&AccountEvent {
+                exchange: snapshot.exchange,
+                kind: AccountEventKind::Snapshot(snapshot),
+            });
+        }
+
         // Construct Engine
-        let engine = Engine::new(clock, state, execution.execution_tx_map, strategy, risk);
+        // Heartbeat every ExecutionManager on a wall-clock interval while the Engine runs
+        let execution_heartbeat = execution_heartbeat.map(|interval| {
+            ExecutionHeartbeat::new(
+                interval,
+                execution.execution_tx_map.iter().cloned().collect(),
+            )
+        });
+
+        let mut engine = Engine::new(clock, state, execution.execution_tx_map, strategy, risk);
+        engine.strategy_swap = strategy_swap;
 
         Ok(SystemBuild {
             engine,
             engine_feed_mode,
+            engine_feed_priority: EngineFeedPriority::default(),
+            is_priority: |_| false,
+            execution_heartbeat,
             audit_mode,
             market_stream,
             account_channel: execution.account_channel,
@@ -446,6 +648,15 @@ pub struct SystemBuild<Engine, Event, MarketStream> {
     /// 选择的 [`EngineFeedMode`]。
     pub engine_feed_mode: EngineFeedMode,
 
+    /// 选择的 [`EngineFeedPriority`]，通过 [`SystemBuild::with_engine_feed_priority`] 配置。
+    engine_feed_priority: EngineFeedPriority,
+
+    /// 判断事件是否为控制事件的函数，仅在 [`EngineFeedPriority::Control`] 模式下使用。
+    is_priority: fn(&Event) -> bool,
+
+    /// 可选的执行心跳，在 Engine 运行期间按墙钟定期向所有 ExecutionManager 发送心跳。
+    execution_heartbeat: Option<ExecutionHeartbeat>,
+
     /// 选择的 [`AuditMode`]。
     pub audit_mode: AuditMode,
 
@@ -462,6 +673,24 @@ pub struct SystemBuild<Engine, Event, MarketStream> {
     phantom_event: PhantomData<Event>,
 }
 
+impl<Engine, Event, MarketStream> SystemBuild<Engine, Event, MarketStream>
+where
+    Event: FeedPriority,
+{
+    /// 配置 [`EngineFeedPriority`]（`Fifo` 或 `Control`）。
+    ///
+    /// 控制 Engine 是否优先处理 `Command`、`Shutdown` 和 `TradingStateUpdate` 等控制事件，
+    /// 避免其在行情剧烈波动期间排在大量市场事件之后。仅在此处要求 `Event` 实现
+    /// [`FeedPriority`]，默认的 `Fifo` 模式不要求。
+    pub fn with_engine_feed_priority(self, engine_feed_priority: EngineFeedPriority) -> Self {
+        Self {
+            engine_feed_priority,
+            is_priority: Event::is_priority,
+            ..self
+        }
+    }
+}
+
 impl<Engine, Event, MarketStream> SystemBuild<Engine, Event, MarketStream>
 where
     Engine: Processor<Event>
@@ -475,6 +704,8 @@ where
 {
     /// 从提供的组件构造新的 `SystemBuild`。
     ///
+    /// 使用默认的 [`EngineFeedPriority::Fifo`]，可通过 [`Self::with_engine_feed_priority`] 配置。
+    ///
     /// # 参数
     ///
     /// - `engine`: 已构建的 Engine 实例
@@ -498,6 +729,9 @@ where
         Self {
             engine,
             engine_feed_mode,
+            engine_feed_priority: EngineFeedPriority::default(),
+            is_priority: |_| false,
+            execution_heartbeat: None,
             audit_mode,
             market_stream,
             account_channel,
@@ -550,6 +784,9 @@ where
         let Self {
             mut engine,
             engine_feed_mode,
+            engine_feed_priority,
+            is_priority,
+            execution_heartbeat,
             audit_mode,
             market_stream,
             account_channel,
@@ -563,7 +800,8 @@ where
             .await?;
 
         // Initialise central Engine channel
-        let (feed_tx, mut feed_rx) = mpsc_unbounded();
+        let (feed_tx, feed_rx) = mpsc_unbounded();
+        let mut feed = EngineFeed::with_classifier(feed_rx, engine_feed_priority, is_priority);
 
         // Forward MarketStreamEvents to Engine feed
         let market_to_engine = runtime
@@ -587,8 +825,7 @@ where
                 };
 
                 let handle = runtime.spawn_blocking(move || {
-                    let shutdown_audit =
-                        sync_run_with_audit(&mut feed_rx, &mut engine, &mut audit_tx);
+                    let shutdown_audit = sync_run_with_audit(&mut feed, &mut engine, &mut audit_tx);
 
                     (engine, shutdown_audit)
                 });
@@ -597,7 +834,7 @@ where
             }
             (EngineFeedMode::Iterator, AuditMode::Disabled) => {
                 let handle = runtime.spawn_blocking(move || {
-                    let shutdown_audit = sync_run(&mut feed_rx, &mut engine);
+                    let shutdown_audit = sync_run(&mut feed, &mut engine);
                     (engine, shutdown_audit)
                 });
 
@@ -615,7 +852,7 @@ where
 
                 let handle = runtime.spawn(async move {
                     let shutdown_audit =
-                        async_run_with_audit(&mut feed_rx, &mut engine, &mut audit_tx).await;
+                        async_run_with_audit(&mut feed, &mut engine, &mut audit_tx).await;
                     (engine, shutdown_audit)
                 });
 
@@ -623,7 +860,7 @@ where
             }
             (EngineFeedMode::Stream, AuditMode::Disabled) => {
                 let handle = runtime.spawn(async move {
-                    let shutdown_audit = async_run(&mut feed_rx, &mut engine).await;
+                    let shutdown_audit = async_run(&mut feed, &mut engine).await;
                     (engine, shutdown_audit)
                 });
 
@@ -631,15 +868,187 @@ where
             }
         };
 
+        // Heartbeat every ExecutionManager on a wall-clock interval until the Engine task ends
+        let execution_heartbeat = execution_heartbeat
+            .map(|heartbeat| runtime.spawn(heartbeat.run(engine.abort_handle())));
+
         Ok(System {
             engine,
             handles: SystemAuxillaryHandles {
                 execution,
                 market_to_engine,
                 account_to_engine,
+                execution_heartbeat,
             },
             feed_tx,
             audit,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        EngineEvent,
+        engine::{
+            clock::HistoricalClock,
+            state::{global::DefaultGlobalData, instrument::data::DefaultInstrumentMarketData},
+        },
+        risk::DefaultRiskManager,
+        strategy::DefaultStrategy,
+    };
+    use barter_data::{event::DataKind, streams::consumer::MarketStreamEvent};
+    use barter_execution::{
+        AccountSnapshot, InstrumentAccountSnapshot,
+        balance::AssetBalance,
+        order::{
+            Order, OrderKey, OrderKind, TimeInForce,
+            id::{ClientOrderId, OrderId, StrategyId},
+            state::{Open, OrderState},
+        },
+    };
+    use barter_instrument::{Side, asset::name::AssetNameExchange, test_utils::instrument};
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    type State =
+        EngineState<crate::engine::state::global::DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    #[tokio::test]
+    async fn test_system_builder_applies_initial_account_snapshot() {
+        let time = DateTime::<Utc>::MIN_UTC;
+        let instruments =
+            IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]);
+
+        let snapshot = AccountSnapshot {
+            exchange: ExchangeId::BinanceSpot,
+            balances: vec![AssetBalance::new(
+                AssetNameExchange::from("usdt"),
+                Balance::new(dec!(1000), dec!(900)),
+                time,
+            )],
+            instruments: vec![InstrumentAccountSnapshot {
+                instrument: instruments.instruments()[0].value.name_exchange.clone(),
+                orders: vec![Order {
+                    key: OrderKey {
+                        exchange: ExchangeId::BinanceSpot,
+                        instrument: instruments.instruments()[0].value.name_exchange.clone(),
+                        strategy: StrategyId::new("strategy"),
+                        cid: ClientOrderId::new("cid"),
+                    },
+                    side: Side::Buy,
+                    price: dec!(100),
+                    quantity: dec!(1),
+                    kind: OrderKind::Limit,
+                    time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+                    reduce_only: false,
+                    state: OrderState::active(Open::new(OrderId::new("order"), time, dec!(0))),
+                }],
+            }],
+        };
+
+        let system = SystemBuilder::new(SystemArgs::new(
+            &instruments,
+            vec![],
+            HistoricalClock::new(time),
+            DefaultStrategy::<State>::default(),
+            DefaultRiskManager::<State>::default(),
+            futures::stream::empty::<MarketStreamEvent<InstrumentIndex, DataKind>>(),
+            DefaultGlobalData,
+            |_| DefaultInstrumentMarketData::default(),
+        ))
+        .account_snapshots([snapshot])
+        .build::<EngineEvent, _>()
+        .unwrap()
+        .init()
+        .await
+        .unwrap();
+
+        let (engine, _) = system.shutdown().await.unwrap();
+
+        let usdt = engine.state.assets.asset_index(
+            &instruments
+                .find_asset_index(ExchangeId::BinanceSpot, &"usdt".into())
+                .unwrap(),
+        );
+        assert_eq!(
+            usdt.balance.unwrap().value,
+            Balance::new(dec!(1000), dec!(900))
+        );
+
+        let orders = &engine
+            .state
+            .instruments
+            .instrument_index(&InstrumentIndex(0))
+            .orders;
+        assert_eq!(orders.active.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_system_builder_seeds_initial_balances() {
+        let time = DateTime::<Utc>::MIN_UTC;
+        let instruments =
+            IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]);
+
+        let system = SystemBuilder::new(SystemArgs::new(
+            &instruments,
+            vec![],
+            HistoricalClock::new(time),
+            DefaultStrategy::<State>::default(),
+            DefaultRiskManager::<State>::default(),
+            futures::stream::empty::<MarketStreamEvent<InstrumentIndex, DataKind>>(),
+            DefaultGlobalData,
+            |_| DefaultInstrumentMarketData::default(),
+        ))
+        .initial_balances(InitialBalances::default().with_balance(
+            ExchangeId::BinanceSpot,
+            "usdt",
+            dec!(1000),
+        ))
+        .build::<EngineEvent, _>()
+        .unwrap()
+        .init()
+        .await
+        .unwrap();
+
+        let (engine, _) = system.shutdown().await.unwrap();
+
+        let usdt = engine.state.assets.asset_index(
+            &instruments
+                .find_asset_index(ExchangeId::BinanceSpot, &"usdt".into())
+                .unwrap(),
+        );
+        assert_eq!(
+            usdt.balance.unwrap().value,
+            Balance::new(dec!(1000), dec!(1000))
+        );
+    }
+
+    #[test]
+    fn test_system_builder_strategy_swap_is_opt_in() {
+        let instruments =
+            IndexedInstruments::new([instrument(ExchangeId::BinanceSpot, "btc", "usdt")]);
+        let builder = || {
+            SystemBuilder::new(SystemArgs::new(
+                &instruments,
+                vec![],
+                HistoricalClock::new(DateTime::<Utc>::MIN_UTC),
+                DefaultStrategy::<State>::default(),
+                DefaultRiskManager::<State>::default(),
+                futures::stream::empty::<MarketStreamEvent<InstrumentIndex, DataKind>>(),
+                DefaultGlobalData,
+                |_| DefaultInstrumentMarketData::default(),
+            ))
+        };
+
+        let system = builder().build::<EngineEvent, _>().unwrap();
+        assert!(system.engine.strategy_swap.is_none());
+
+        let system = builder()
+            .with_strategy_swap()
+            .build::<EngineEvent, _>()
+            .unwrap();
+        assert!(system.engine.strategy_swap.is_some());
+    }
+}
@@ -8,11 +8,18 @@
 //! - **SystemConfig**: 完整交易系统的顶级配置
 //! - **InstrumentConfig**: 交易对配置
 //! - **ExecutionConfig**: 执行组件配置
+//! - **InitialBalances**: 回测和模拟运行的初始"纸面"余额
 
-use barter_execution::client::mock::MockExecutionConfig;
+use barter_execution::{
+    balance::{AssetBalance, Balance},
+    client::mock::MockExecutionConfig,
+};
 use barter_instrument::{
-    Underlying,
-    asset::{Asset, name::AssetNameExchange},
+    Keyed, Underlying,
+    asset::{
+        Asset, ExchangeAsset,
+        name::{AssetNameExchange, AssetNameInternal},
+    },
     exchange::ExchangeId,
     instrument::{
         Instrument,
@@ -25,8 +32,11 @@ use barter_instrument::{
         spec::{InstrumentSpec, InstrumentSpecQuantity, OrderQuantityUnits},
     },
 };
+use chrono::{DateTime, Utc};
 use derive_more::From;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// 完整交易系统的顶级配置。
 ///
@@ -36,6 +46,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// - **instruments**: 系统将跟踪的所有交易对的配置
 /// - **executions**: 所有执行组件的配置
+/// - **initial_balances**: 可选的初始"纸面"余额，用于回测和模拟运行（默认为空）
 ///
 /// # 使用示例
 ///
@@ -60,6 +71,12 @@ pub struct SystemConfig {
 
     /// 所有执行组件的配置。
     pub executions: Vec<ExecutionConfig>,
+
+    /// 回测和模拟运行的初始"纸面"余额。
+    ///
+    /// 参见 [`InitialBalances`]。
+    #[serde(default)]
+    pub initial_balances: InitialBalances,
 }
 
 /// 用于在启动时生成 [`Instrument`] 的便捷最小交易对配置。
@@ -139,6 +156,111 @@ pub enum ExecutionConfig {
     Mock(MockExecutionConfig),
 }
 
+/// 回测和模拟运行（dry-run）的初始"纸面"余额，以 `交易所 -> 资产 -> 数量` 映射配置。
+///
+/// 初始余额同时用于：
+///
+/// - 初始化 `EngineState` 的 `AssetStates`（参见 [`Self::engine_balances`]）
+/// - 初始化 `MockExchange` 的内部余额（参见 [`Self::seed_executions`]）
+///
+/// 因此 PnL 和保证金检查从运行开始就基于真实的初始资金。每个初始余额的 `total` 和 `free`
+/// 余额均为配置的数量。
+///
+/// # 使用示例
+///
+/// ```json
+/// "initial_balances": {
+///   "binance_spot": { "usdt": 10000, "btc": 0.1 }
+/// }
+/// ```
+///
+/// ```rust,ignore
+/// let initial_balances = InitialBalances::default()
+///     .with_balance(ExchangeId::BinanceSpot, "usdt", dec!(10000));
+///
+/// let system = SystemBuilder::new(args)
+///     .initial_balances(initial_balances)
+///     .build::<EngineEvent, _>()?;
+/// ```
+#[derive(
+    Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize, From,
+)]
+#[serde(transparent)]
+pub struct InitialBalances(pub BTreeMap<ExchangeId, BTreeMap<AssetNameExchange, Decimal>>);
+
+impl InitialBalances {
+    /// 添加交易所资产的初始余额，覆盖任何已存在的相同交易所资产的初始余额。
+    pub fn with_balance<Name>(mut self, exchange: ExchangeId, asset: Name, amount: Decimal) -> Self
+    where
+        Name: Into<AssetNameExchange>,
+    {
+        self.0
+            .entry(exchange)
+            .or_default()
+            .insert(asset.into(), amount);
+        self
+    }
+
+    /// 如果没有配置任何初始余额则返回 `true`。
+    pub fn is_empty(&self) -> bool {
+        self.0.values().all(BTreeMap::is_empty)
+    }
+
+    /// 返回用于初始化 `EngineState` 的 `AssetStates` 的初始余额。
+    ///
+    /// 可以直接提供给 `EngineStateBuilder::balances` 或 `SystemBuilder::balances`。
+    pub fn engine_balances(
+        &self,
+    ) -> impl Iterator<Item = Keyed<ExchangeAsset<AssetNameInternal>, Balance>> + '_ {
+        self.0.iter().flat_map(|(exchange, balances)| {
+            balances.iter().map(|(asset, amount)| {
+                Keyed::new(
+                    ExchangeAsset::new(
+                        *exchange,
+                        Asset::new_from_exchange(asset.clone()).name_internal,
+                    ),
+                    Balance::new(*amount, *amount),
+                )
+            })
+        })
+    }
+
+    /// 使用初始余额初始化每个 [`ExecutionConfig::Mock`] 的 `MockExchange` 内部余额。
+    ///
+    /// 已存在于 `MockExecutionConfig::initial_state` 中的相同资产余额会被覆盖。
+    ///
+    /// # 参数
+    ///
+    /// - `executions`: 执行配置
+    /// - `time_exchange`: 初始余额的交易所时间（通常为 Engine 开始时间）
+    pub fn seed_executions(
+        &self,
+        executions: &mut [ExecutionConfig],
+        time_exchange: DateTime<Utc>,
+    ) {
+        for ExecutionConfig::Mock(config) in executions.iter_mut() {
+            let Some(balances) = self.0.get(&config.mocked_exchange) else {
+                continue;
+            };
+
+            for (asset, amount) in balances {
+                let balance =
+                    AssetBalance::new(asset.clone(), Balance::new(*amount, *amount), time_exchange);
+
+                match config
+                    .initial_state
+                    .balances
+                    .iter_mut()
+                    .find(|existing| existing.asset == *asset)
+                {
+                    Some(existing) => *existing = balance,
+                    None => config.initial_state.balances.push(balance),
+                }
+            }
+        }
+    }
+}
+
 impl From<InstrumentConfig> for Instrument<ExchangeId, Asset> {
     fn from(value: InstrumentConfig) -> Self {
         Self {
@@ -194,3 +316,124 @@ impl From<InstrumentConfig> for Instrument<ExchangeId, Asset> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::{
+        UnindexedAccountSnapshot,
+        exchange::mock::MockExchange,
+        order::{
+            OrderKey, OrderKind, TimeInForce,
+            id::{ClientOrderId, StrategyId},
+            request::{OrderRequestOpen, RequestOpen},
+        },
+    };
+    use barter_instrument::Side;
+    use barter_integration::msgpack::{from_msgpack, to_msgpack};
+    use fnv::FnvHashMap;
+    use rust_decimal_macros::dec;
+    use tokio::sync::{broadcast, mpsc};
+
+    #[test]
+    fn test_system_config_json_msgpack_round_trip() {
+        let json = include_str!("../../examples/config/system_config.json");
+        let config = serde_json::from_str::<SystemConfig>(json).unwrap();
+        assert!(!config.instruments.is_empty());
+        assert!(!config.executions.is_empty());
+
+        let bytes = to_msgpack(&config).unwrap();
+        let from_msgpack = from_msgpack::<SystemConfig>(&bytes).unwrap();
+        assert_eq!(from_msgpack, config);
+
+        let from_json =
+            serde_json::from_str::<SystemConfig>(&serde_json::to_string(&config).unwrap()).unwrap();
+        assert_eq!(from_json, from_msgpack);
+    }
+
+    #[test]
+    fn test_initial_balances_seed_mock_exchange() {
+        let time = DateTime::<Utc>::MIN_UTC;
+        let initial_balances = InitialBalances::default()
+            .with_balance(ExchangeId::Mock, "usdt", dec!(1000))
+            .with_balance(ExchangeId::BinanceSpot, "usdt", dec!(5));
+
+        let mut executions = vec![ExecutionConfig::Mock(MockExecutionConfig {
+            mocked_exchange: ExchangeId::Mock,
+            initial_state: UnindexedAccountSnapshot {
+                exchange: ExchangeId::Mock,
+                balances: vec![AssetBalance::new(
+                    AssetNameExchange::from("usdt"),
+                    Balance::new(dec!(1), dec!(1)),
+                    time,
+                )],
+                instruments: vec![],
+            },
+            latency_ms: 0,
+            fees_percent: Some(Decimal::ZERO),
+            fees_maker_percent: None,
+            fee_tier: None,
+            fill_price: Default::default(),
+            position_mode: Default::default(),
+        })];
+
+        initial_balances.seed_executions(&mut executions, time);
+
+        let ExecutionConfig::Mock(config) = executions.remove(0);
+        assert_eq!(
+            config.initial_state.balances,
+            vec![AssetBalance::new(
+                AssetNameExchange::from("usdt"),
+                Balance::new(dec!(1000), dec!(1000)),
+                time,
+            )]
+        );
+
+        let instrument = Instrument::new(
+            ExchangeId::Mock,
+            "mock-btc_usdt",
+            "btc_usdt",
+            Underlying::new(
+                AssetNameExchange::from("btc"),
+                AssetNameExchange::from("usdt"),
+            ),
+            InstrumentQuoteAsset::UnderlyingQuote,
+            InstrumentKind::Spot,
+            None,
+        );
+
+        let mut exchange = MockExchange::new(
+            config,
+            mpsc::unbounded_channel().1,
+            broadcast::channel(1).0,
+            FnvHashMap::from_iter([(instrument.name_exchange.clone(), instrument)]),
+        );
+
+        // Buy 2 @ 100 debits the seeded usdt balance
+        let (order, _) = exchange.open_order(OrderRequestOpen {
+            key: OrderKey {
+                exchange: ExchangeId::Mock,
+                instrument: InstrumentNameExchange::new("btc_usdt"),
+                strategy: StrategyId::new("strategy"),
+                cid: ClientOrderId::new("cid"),
+            },
+            state: RequestOpen {
+                side: Side::Buy,
+                price: dec!(100),
+                quantity: dec!(2),
+                kind: OrderKind::Market,
+                time_in_force: TimeInForce::ImmediateOrCancel,
+                reduce_only: false,
+            },
+        });
+        assert!(order.state.is_ok());
+        assert_eq!(
+            exchange
+                .account
+                .balance_mut(&AssetNameExchange::from("usdt"))
+                .unwrap()
+                .balance,
+            Balance::new(dec!(800), dec!(800))
+        );
+    }
+}
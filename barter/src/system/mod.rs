@@ -24,7 +24,9 @@ use crate::{
         Processor,
         audit::{AuditTick, Auditor, context::EngineContext},
         command::Command,
+        query::{QueryKind, QueryResponse, QueryState},
         state::{instrument::filter::InstrumentFilter, trading::TradingState},
+        swap::SwapStrategy,
     },
     execution::builder::ExecutionHandles,
     shutdown::{AsyncShutdown, Shutdown},
@@ -36,7 +38,10 @@ use barter_integration::{
     snapshot::SnapUpdates,
 };
 use std::fmt::Debug;
-use tokio::task::{JoinError, JoinHandle};
+use tokio::{
+    sync::oneshot,
+    task::{JoinError, JoinHandle},
+};
 
 /// 提供用于构建 Barter 交易系统的 `SystemBuilder` 及相关类型。
 pub mod builder;
@@ -189,6 +194,7 @@ where
                     mut execution,
                     market_to_engine,
                     account_to_engine,
+                    execution_heartbeat,
                 },
             feed_tx,
             audit: _,
@@ -205,6 +211,9 @@ where
         let (engine, shutdown_audit) = engine.await?;
 
         account_to_engine.abort();
+        if let Some(execution_heartbeat) = execution_heartbeat {
+            execution_heartbeat.abort();
+        }
         execution.shutdown().await?;
 
         Ok((engine, shutdown_audit))
@@ -262,6 +271,41 @@ where
         self.send(Command::CancelOrders(filter))
     }
 
+    /// 向 `Engine` 发送 [`QueryState`] 请求，查询当前仓位、活跃订单或余额。
+    ///
+    /// # 参数
+    ///
+    /// - `kind`: 要查询的 `EngineState` 子集
+    ///
+    /// # 返回值
+    ///
+    /// 返回用于接收 [`QueryResponse`] 的一次性接收器。
+    pub fn query_state(&self, kind: QueryKind) -> oneshot::Receiver<QueryResponse>
+    where
+        Event: From<Command>,
+    {
+        let (query, reply_rx) = QueryState::new(kind);
+        self.send(Command::QueryState(query));
+        reply_rx
+    }
+
+    /// 指示 `Engine` 在运行时替换 Strategy 实例，保留 `EngineState`。
+    ///
+    /// 替换在 `Engine` 处理 [`Command::SwapStrategy`] 时完成，之后的算法订单由新的 Strategy
+    /// 生成。新的 Strategy 必须与 `Engine` 的 Strategy 类型相同，并且 `Engine` 必须启用了
+    /// Strategy 热替换（例如通过 `SystemBuilder::with_strategy_swap`），否则替换会被忽略。
+    ///
+    /// # 参数
+    ///
+    /// - `strategy`: 新的 Strategy 实例
+    pub fn swap_strategy<Strategy>(&self, strategy: Strategy)
+    where
+        Event: From<Command>,
+        Strategy: Send + 'static,
+    {
+        self.send(Command::SwapStrategy(SwapStrategy::new(strategy)))
+    }
+
     /// 更新 `Engine` 的算法 `TradingState`。
     ///
     /// # 参数
@@ -325,6 +369,9 @@ pub struct SystemAuxillaryHandles {
 
     /// 将账户事件转发到 Engine 的任务。
     pub account_to_engine: JoinHandle<()>,
+
+    /// 可选的执行心跳任务，在 Engine 运行期间按墙钟定期向所有 ExecutionManager 发送心跳。
+    pub execution_heartbeat: Option<JoinHandle<()>>,
 }
 
 impl AsyncShutdown for SystemAuxillaryHandles {
@@ -337,6 +384,9 @@ impl AsyncShutdown for SystemAuxillaryHandles {
         // 事件 -> Engine 任务不需要优雅关闭，直接中止
         self.market_to_engine.abort();
         self.account_to_engine.abort();
+        if let Some(execution_heartbeat) = &self.execution_heartbeat {
+            execution_heartbeat.abort();
+        }
 
         // 并发等待执行组件关闭
         self.execution.shutdown().await
@@ -352,6 +402,7 @@ impl SystemAuxillaryHandles {
             .into_iter()
             .chain(std::iter::once(self.market_to_engine))
             .chain(std::iter::once(self.account_to_engine))
+            .chain(self.execution_heartbeat)
             .for_each(|handle| handle.abort());
     }
 }
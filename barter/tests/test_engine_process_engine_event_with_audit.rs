@@ -6,17 +6,20 @@
 use barter::{
     EngineEvent, Sequence, Timed,
     engine::{
-        Engine, EngineOutput,
+        Engine, EngineOutput, Processor,
         action::{
             ActionOutput,
             generate_algo_orders::GenerateAlgoOrdersOutput,
             send_requests::{SendCancelsAndOpensOutput, SendRequestsOutput},
         },
-        audit::EngineAudit,
-        clock::HistoricalClock,
+        audit::{EngineAudit, ProcessAudit},
+        clock::{HistoricalClock, HistoricalClockMode},
         command::Command,
+        error::{EngineError, RecoverableEngineError},
         execution_tx::MultiExchangeTxMap,
         process_with_audit,
+        query::{QueryKind, QueryResponse, QueryState, QueryStateOutput},
+        rejection::{OrderRejection, OrderRejectionStage, RejectedOrder},
         state::{
             EngineState,
             asset::AssetStates,
@@ -24,18 +27,25 @@ use barter::{
             global::DefaultGlobalData,
             instrument::{
                 data::{DefaultInstrumentMarketData, InstrumentDataState},
+                expiry::InstrumentExpired,
                 filter::InstrumentFilter,
             },
+            order::in_flight_timeout::{InFlightRequestKind, InFlightTimeout},
             position::PositionExited,
             trading::TradingState,
         },
+        swap::{SwapStrategy, SwapStrategyOutput},
     },
     execution::{AccountStreamEvent, request::ExecutionRequest},
-    risk::DefaultRiskManager,
+    risk::{
+        DefaultRiskManager,
+        circuit_breaker::{DrawdownBreached, DrawdownCircuitBreaker},
+    },
     strategy::{
+        DefaultStrategy,
         algo::AlgoStrategy,
         close_positions::{ClosePositionsStrategy, close_open_positions_with_market_orders},
-        on_disconnect::OnDisconnectStrategy,
+        on_disconnect::{CancelOrdersOnDisconnect, OnDisconnectStrategy},
         on_trading_disabled::OnTradingDisabled,
     },
     test_utils::time_plus_days,
@@ -48,10 +58,11 @@ use barter_data::{
 use barter_execution::{
     AccountEvent, AccountEventKind, AccountSnapshot,
     balance::{AssetBalance, Balance},
+    error::{ApiError, OrderError},
     order::{
         Order, OrderKey, OrderKind, TimeInForce,
         id::{ClientOrderId, OrderId, StrategyId},
-        request::{OrderRequestCancel, OrderRequestOpen, RequestOpen},
+        request::{OrderRequestCancel, OrderRequestOpen, RequestCancel, RequestOpen},
         state::{ActiveOrderState, Open, OrderState},
     },
     trade::{AssetFees, Trade, TradeId},
@@ -63,6 +74,7 @@ use barter_instrument::{
     index::IndexedInstruments,
     instrument::{
         Instrument, InstrumentIndex,
+        kind::{InstrumentKind, future::FutureContract},
         spec::{
             InstrumentSpec, InstrumentSpecNotional, InstrumentSpecPrice, InstrumentSpecQuantity,
             OrderQuantityUnits,
@@ -70,14 +82,18 @@ use barter_instrument::{
     },
 };
 use barter_integration::{
-    channel::{UnboundedTx, mpsc_unbounded},
+    channel::{UnboundedRx, UnboundedTx, mpsc_unbounded},
     collection::{none_one_or_many::NoneOneOrMany, one_or_many::OneOrMany},
     snapshot::Snapshot,
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, TimeDelta, Utc};
 use fnv::FnvHashMap;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
+use std::{
+    num::NonZeroUsize,
+    sync::{Arc, Mutex},
+};
 
 // 测试常量定义
 /// 起始时间戳。
@@ -149,6 +165,7 @@ fn test_engine_process_engine_event_with_audit() {
             side: Side::Buy,
             kind: OrderKind::Market,
             time_in_force: TimeInForce::ImmediateOrCancel,
+            reduce_only: false,
             price: dec!(10_000),
             quantity: dec!(1),
         },
@@ -164,6 +181,7 @@ fn test_engine_process_engine_event_with_audit() {
             side: Side::Buy,
             kind: OrderKind::Market,
             time_in_force: TimeInForce::ImmediateOrCancel,
+            reduce_only: false,
             price: dec!(0.1),
             quantity: dec!(1),
         },
@@ -221,7 +239,7 @@ fn test_engine_process_engine_event_with_audit() {
             .instruments
             .instrument_index(&InstrumentIndex(0))
             .orders
-            .0
+            .active
             .is_empty()
     );
 
@@ -277,7 +295,7 @@ fn test_engine_process_engine_event_with_audit() {
             .instruments
             .instrument_index(&InstrumentIndex(1))
             .orders
-            .0
+            .active
             .is_empty()
     );
 
@@ -350,6 +368,7 @@ fn test_engine_process_engine_event_with_audit() {
             side: Side::Sell,
             kind: OrderKind::Market,
             time_in_force: TimeInForce::ImmediateOrCancel,
+            reduce_only: false,
             price: dec!(20_000),
             quantity: dec!(1),
         },
@@ -385,7 +404,7 @@ fn test_engine_process_engine_event_with_audit() {
             .instruments
             .instrument_index(&InstrumentIndex(0))
             .orders
-            .0
+            .active
             .is_empty()
     );
 
@@ -486,6 +505,7 @@ fn test_engine_process_engine_event_with_audit() {
             side: Side::Sell,
             kind: OrderKind::Limit,
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            reduce_only: false,
             price: dec!(0.05),
             quantity: dec!(1),
         },
@@ -523,7 +543,7 @@ fn test_engine_process_engine_event_with_audit() {
             .instruments
             .instrument_index(&InstrumentIndex(1))
             .orders
-            .0
+            .active
             .len(),
         1
     );
@@ -533,7 +553,7 @@ fn test_engine_process_engine_event_with_audit() {
             .instruments
             .instrument_index(&InstrumentIndex(1))
             .orders
-            .0
+            .active
             .get(&gen_cid(1))
             .unwrap(),
         &Order {
@@ -548,6 +568,7 @@ fn test_engine_process_engine_event_with_audit() {
             quantity: dec!(1),
             kind: OrderKind::Limit,
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            reduce_only: false,
             state: ActiveOrderState::Open(Open {
                 id: gen_order_id(1),
                 time_exchange: time_plus_days(STARTING_TIMESTAMP, 4),
@@ -589,6 +610,7 @@ fn test_engine_process_engine_event_with_audit() {
             quantity: dec!(1),
             kind: OrderKind::Limit,
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            reduce_only: false,
             state: OrderState::fully_filled(),
         })),
     }));
@@ -601,7 +623,7 @@ fn test_engine_process_engine_event_with_audit() {
             .instruments
             .instrument_index(&InstrumentIndex(1))
             .orders
-            .0
+            .active
             .is_empty()
     );
 
@@ -665,8 +687,986 @@ fn test_engine_process_engine_event_with_audit() {
     // Todo: Additional assertions + TradingSummary assertions once generated (to test TimeInterval)
 }
 
+/// 测试逐个事件处理与批处理收敛到相同的最终 EngineState。
+#[test]
+fn test_engine_process_batch_converges_with_per_event_process() {
+    let assets = build_engine(TradingState::Disabled, mpsc_unbounded().0)
+        .state
+        .assets;
+    let events = || {
+        vec![
+            account_event_snapshot(&assets),
+            market_event_trade(1, 0, 10_000.0),
+            market_event_trade(1, 1, 0.1),
+            EngineEvent::TradingStateUpdate(TradingState::Enabled),
+            account_event_balance(0, 2, 40_000.0, 40_000.0),
+            market_event_trade(2, 0, 10_000.0),
+            market_event_trade(2, 1, 0.1),
+        ]
+    };
+
+    // Per-event processing
+    let (per_event_tx, mut per_event_rx) = mpsc_unbounded();
+    let mut per_event = build_engine(TradingState::Disabled, per_event_tx);
+    for event in events() {
+        per_event.process(event);
+    }
+
+    // Batch processing, generating algo orders once at the end
+    let (batch_tx, mut batch_rx) = mpsc_unbounded();
+    let mut batch = build_engine(TradingState::Disabled, batch_tx);
+    let audits = batch.process_batch(events());
+
+    assert_eq!(audits.len(), events().len());
+    let generated_algo_orders = |audit: &EngineAudit<_, _>| match audit {
+        EngineAudit::Process(process) => process
+            .outputs
+            .iter()
+            .any(|output| matches!(output, EngineOutput::AlgoOrders(_))),
+        EngineAudit::FeedEnded => false,
+    };
+    let (last, intermediate) = audits.split_last().unwrap();
+    assert!(generated_algo_orders(last));
+    assert!(!intermediate.iter().any(generated_algo_orders));
+    assert_eq!(batch.state, per_event.state);
+
+    // Batch processing with a cadence
+    let (cadence_tx, mut cadence_rx) = mpsc_unbounded();
+    let mut cadence = build_engine(TradingState::Disabled, cadence_tx);
+    cadence.process_batch_with_cadence(events(), NonZeroUsize::new(3).unwrap());
+    assert_eq!(cadence.state, per_event.state);
+
+    // All modes sent the same ExecutionRequests
+    let per_event_requests =
+        std::iter::from_fn(|| per_event_rx.rx.try_recv().ok()).collect::<Vec<_>>();
+    assert_eq!(per_event_requests.len(), 2);
+    assert_eq!(
+        std::iter::from_fn(|| batch_rx.rx.try_recv().ok()).collect::<Vec<_>>(),
+        per_event_requests
+    );
+    assert_eq!(
+        std::iter::from_fn(|| cadence_rx.rx.try_recv().ok()).collect::<Vec<_>>(),
+        per_event_requests
+    );
+}
+
+#[test]
+fn test_engine_stamps_market_event_time_processed() {
+    let processed_market_event = |engine: &mut Engine<_, _, _, _, _>| {
+        let audit = engine.process(market_event_trade(1, 0, 10_000.0));
+        match audit {
+            EngineAudit::Process(ProcessAudit {
+                event: EngineEvent::Market(MarketStreamEvent::Item(event)),
+                ..
+            }) => event,
+            other => panic!("expected processed MarketEvent audit, got: {other:?}"),
+        }
+    };
+
+    // Stamping disabled by default
+    let mut engine = build_engine(TradingState::Disabled, mpsc_unbounded().0);
+    let event = processed_market_event(&mut engine);
+    assert_eq!(event.time_processed, None);
+    assert_eq!(event.latency_processing(), None);
+
+    // Stamping enabled
+    let mut engine =
+        build_engine(TradingState::Disabled, mpsc_unbounded().0).with_time_processed_stamping(true);
+    let event = processed_market_event(&mut engine);
+    let time_processed = event.time_processed.unwrap();
+    assert!(time_processed >= event.time_received && time_processed <= engine.time());
+    assert_eq!(
+        event.latency_transit(),
+        event.time_received - event.time_exchange
+    );
+    assert_eq!(
+        event.latency_processing(),
+        Some(time_processed - event.time_received)
+    );
+}
+
+#[test]
+fn test_engine_process_hooks_fire_once_per_event_in_order() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let audits = Arc::new(Mutex::new(Vec::new()));
+
+    let mut engine = build_engine(TradingState::Disabled, mpsc_unbounded().0)
+        .with_pre_process_hook({
+            let calls = Arc::clone(&calls);
+            let events = Arc::clone(&events);
+            move |event| {
+                calls.lock().unwrap().push("pre_process");
+                events.lock().unwrap().push(event.clone());
+            }
+        })
+        .with_post_process_hook({
+            let calls = Arc::clone(&calls);
+            let audits = Arc::clone(&audits);
+            move |audit| {
+                calls.lock().unwrap().push("post_process");
+                audits.lock().unwrap().push(audit.clone());
+            }
+        });
+
+    let inputs = vec![
+        market_event_trade(1, 0, 10_000.0),
+        EngineEvent::TradingStateUpdate(TradingState::Enabled),
+        market_event_trade(2, 1, 0.1),
+    ];
+
+    let outputs = inputs
+        .iter()
+        .cloned()
+        .map(|event| engine.process(event))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        ["pre_process", "post_process"].repeat(inputs.len())
+    );
+    assert_eq!(*events.lock().unwrap(), inputs);
+    assert_eq!(*audits.lock().unwrap(), outputs);
+}
+
+#[test]
+fn test_engine_process_hooks_fire_for_wrapped_strategy_and_batches() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let audits = Arc::new(Mutex::new(Vec::new()));
+
+    // PreProcess hook is kept when the Strategy is wrapped, PostProcess hook is set for the
+    // wrapped Strategy audit type
+    let mut engine = build_engine(TradingState::Enabled, mpsc_unbounded().0)
+        .with_pre_process_hook({
+            let calls = Arc::clone(&calls);
+            move |_| calls.lock().unwrap().push("pre_process")
+        })
+        .map_strategy(CancelOrdersOnDisconnect::new)
+        .with_post_process_hook({
+            let calls = Arc::clone(&calls);
+            let audits = Arc::clone(&audits);
+            move |audit| {
+                calls.lock().unwrap().push("post_process");
+                audits.lock().unwrap().push(audit.clone());
+            }
+        });
+
+    // TradingState::Disabled delegates OnTradingDisabled to the wrapped Strategy
+    let first = engine.process(EngineEvent::TradingStateUpdate(TradingState::Disabled));
+
+    // Hooks still fire for every event processed in a batch
+    let batch = engine.process_batch([
+        EngineEvent::TradingStateUpdate(TradingState::Enabled),
+        market_event_trade(1, 0, 10_000.0),
+    ]);
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        ["pre_process", "post_process"].repeat(3)
+    );
+    assert_eq!(
+        *audits.lock().unwrap(),
+        std::iter::once(first).chain(batch).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_engine_in_flight_open_timeout_fires_once() {
+    let (execution_tx, mut execution_rx) = mpsc_unbounded();
+    let mut engine = build_engine(TradingState::Enabled, execution_tx)
+        .with_in_flight_timeout(TimeDelta::days(2));
+    engine.clock = HistoricalClock::with_mode(STARTING_TIMESTAMP, HistoricalClockMode::EventTime);
+
+    let timeouts = |audit: EngineAudit<_, _>| match audit {
+        EngineAudit::Process(process) => process
+            .outputs
+            .into_iter()
+            .filter_map(|output| match output {
+                EngineOutput::InFlightTimeout(timeout) => Some(timeout),
+                _ => None,
+            })
+            .collect::<Vec<_>>(),
+        other => panic!("expected EngineAudit::Process, got: {other:?}"),
+    };
+
+    // Market price available, so Strategy sends an open which is never acknowledged
+    assert!(timeouts(engine.process(market_event_trade(1, 0, 10_000.0))).is_empty());
+    assert!(matches!(
+        execution_rx.rx.try_recv(),
+        Ok(ExecutionRequest::Open(_))
+    ));
+
+    // Not yet timed out
+    assert!(timeouts(engine.process(market_event_trade(2, 0, 10_000.0))).is_empty());
+
+    // Timed out
+    assert_eq!(
+        timeouts(engine.process(market_event_trade(3, 0, 10_000.0))),
+        vec![InFlightTimeout {
+            key: OrderKey {
+                exchange: ExchangeIndex(0),
+                instrument: InstrumentIndex(0),
+                strategy: strategy_id(),
+                cid: gen_cid(0),
+            },
+            kind: InFlightRequestKind::Open,
+            time_sent: time_plus_days(STARTING_TIMESTAMP, 1),
+            time_timeout: time_plus_days(STARTING_TIMESTAMP, 3),
+        }]
+    );
+
+    // Stuck signal only fires once
+    assert!(timeouts(engine.process(market_event_trade(4, 0, 10_000.0))).is_empty());
+    assert!(timeouts(engine.process(market_event_trade(5, 0, 10_000.0))).is_empty());
+}
+
+#[test]
+fn test_engine_stops_trading_expired_future() {
+    let (execution_tx, mut execution_rx) = mpsc_unbounded();
+    let mut engine = build_engine(TradingState::Enabled, execution_tx).with_expiry_handling(true);
+    engine.clock = HistoricalClock::with_mode(STARTING_TIMESTAMP, HistoricalClockMode::EventTime);
+
+    // Both instruments are dated futures which expire mid-backtest
+    let expiry = time_plus_days(STARTING_TIMESTAMP, 3);
+    engine.state.instruments.0.values_mut().for_each(|state| {
+        state.instrument.kind = InstrumentKind::Future(FutureContract {
+            contract_size: Decimal::ONE,
+            settlement_asset: state.instrument.underlying.quote,
+            expiry,
+        })
+    });
+
+    let process = |audit: EngineAudit<_, _>| match audit {
+        EngineAudit::Process(process) => process,
+        other => panic!("expected EngineAudit::Process, got: {other:?}"),
+    };
+    let expired = |process: &ProcessAudit<_, _>| {
+        process
+            .outputs
+            .iter()
+            .filter_map(|output| match output {
+                EngineOutput::InstrumentExpired(expired) => Some(expired.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    let opens_refused = |process: &ProcessAudit<_, _>| {
+        process
+            .outputs
+            .iter()
+            .filter_map(|output| match output {
+                EngineOutput::AlgoOrders(output) => Some(output.opens_refused.len()),
+                _ => None,
+            })
+            .sum::<usize>()
+    };
+
+    // Before expiry, Strategy trades the future
+    let audit = process(engine.process(market_event_trade(1, 0, 10_000.0)));
+    assert!(expired(&audit).is_empty());
+    assert!(matches!(
+        execution_rx.rx.try_recv(),
+        Ok(ExecutionRequest::Open(OrderRequestOpen { key, .. })) if key.instrument == InstrumentIndex(0)
+    ));
+
+    // At expiry, both instruments are flagged once and the Strategy open is refused
+    let audit = process(engine.process(market_event_trade(3, 1, 0.1)));
+    assert_eq!(
+        expired(&audit),
+        vec![
+            InstrumentExpired {
+                instrument: InstrumentIndex(0),
+                expiry,
+                time_expired: expiry,
+                positions: vec![],
+            },
+            InstrumentExpired {
+                instrument: InstrumentIndex(1),
+                expiry,
+                time_expired: expiry,
+                positions: vec![],
+            },
+        ]
+    );
+    assert_eq!(opens_refused(&audit), 1);
+    assert!(execution_rx.rx.try_recv().is_err());
+    assert!(
+        engine
+            .state
+            .instruments
+            .instrument_index(&InstrumentIndex(1))
+            .expired
+    );
+
+    // After expiry, trading remains stopped and expiry is not flagged again
+    let audit = process(engine.process(market_event_trade(4, 1, 0.1)));
+    assert!(expired(&audit).is_empty());
+    assert_eq!(opens_refused(&audit), 1);
+    assert!(execution_rx.rx.try_recv().is_err());
+}
+
+#[test]
+fn test_engine_flattens_position_older_than_max_position_age() {
+    let (execution_tx, mut execution_rx) = mpsc_unbounded();
+    let mut engine =
+        build_engine(TradingState::Enabled, execution_tx).with_max_position_age(TimeDelta::days(2));
+    engine.clock = HistoricalClock::with_mode(STARTING_TIMESTAMP, HistoricalClockMode::EventTime);
+
+    // Strategy does not generate algo orders, so only flattening orders are sent
+    engine.strategy.active = false;
+
+    let flattened = |audit: EngineAudit<_, _>| match audit {
+        EngineAudit::Process(process) => process
+            .outputs
+            .into_iter()
+            .filter_map(|output| match output {
+                EngineOutput::PositionAgeExceeded(output) => Some(output),
+                _ => None,
+            })
+            .collect::<Vec<_>>(),
+        other => panic!("expected EngineAudit::Process, got: {other:?}"),
+    };
+
+    // Open btc_usdt LONG position at day 1
+    assert!(flattened(engine.process(market_event_trade(1, 0, 10_000.0))).is_empty());
+    assert!(
+        flattened(engine.process(account_event_trade(0, 1, Side::Buy, 10_000.0, 1.0))).is_empty()
+    );
+
+    // Position age (1 day) is below the limit, so no flattening order is generated
+    assert!(flattened(engine.process(market_event_trade(2, 0, 10_000.0))).is_empty());
+    assert!(execution_rx.rx.try_recv().is_err());
+
+    // Position age (2 days) reaches the limit, so a market close order is generated
+    let outputs = flattened(engine.process(market_event_trade(3, 0, 10_000.0)));
+    assert_eq!(outputs.len(), 1);
+    assert!(outputs[0].cancels.is_empty());
+    assert_eq!(outputs[0].opens.sent.len(), 1);
+    assert!(matches!(
+        execution_rx.rx.try_recv(),
+        Ok(ExecutionRequest::Open(OrderRequestOpen { key, state }))
+            if key.instrument == InstrumentIndex(0)
+                && state.side == Side::Sell
+                && state.quantity == dec!(1)
+                && state.kind == OrderKind::Market
+    ));
+
+    // Flattening order is in flight, so it is not sent again
+    assert!(flattened(engine.process(market_event_trade(4, 0, 10_000.0))).is_empty());
+    assert!(execution_rx.rx.try_recv().is_err());
+
+    // Flattening order is acknowledged as Open but not yet filled, so it is still not sent again
+    assert!(
+        flattened(engine.process(account_event_order_response(
+            0,
+            4,
+            Side::Sell,
+            10_000.0,
+            1.0,
+            0.0
+        )))
+        .is_empty()
+    );
+    assert!(flattened(engine.process(market_event_trade(5, 0, 10_000.0))).is_empty());
+    assert!(execution_rx.rx.try_recv().is_err());
+}
+
+#[test]
+fn test_engine_max_position_age_overflow_never_flattens() {
+    let (execution_tx, mut execution_rx) = mpsc_unbounded();
+    let mut engine =
+        build_engine(TradingState::Enabled, execution_tx).with_max_position_age(TimeDelta::MAX);
+    engine.clock = HistoricalClock::with_mode(STARTING_TIMESTAMP, HistoricalClockMode::EventTime);
+    engine.strategy.active = false;
+
+    // Open btc_usdt LONG position at day 1
+    engine.process(market_event_trade(1, 0, 10_000.0));
+    engine.process(account_event_trade(0, 1, Side::Buy, 10_000.0, 1.0));
+
+    // Position entry time + max age overflows, so the position is never flattened
+    engine.process(market_event_trade(2, 0, 10_000.0));
+    assert!(execution_rx.rx.try_recv().is_err());
+}
+
+#[test]
+fn test_engine_drawdown_circuit_breaker_disables_trading() {
+    let (execution_tx, mut execution_rx) = mpsc_unbounded();
+    let mut engine = build_engine(TradingState::Enabled, execution_tx)
+        .with_drawdown_circuit_breaker(DrawdownCircuitBreaker::new(dec!(100_000), dec!(0.1)));
+
+    let process = |audit: EngineAudit<_, _>| match audit {
+        EngineAudit::Process(process) => process,
+        other => panic!("expected EngineAudit::Process, got: {other:?}"),
+    };
+    let breached = |process: &ProcessAudit<_, _>| {
+        process
+            .outputs
+            .iter()
+            .filter_map(|output| match output {
+                EngineOutput::DrawdownBreached(breached) => Some(*breached),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    let opens_sent = |execution_rx: &mut UnboundedRx<ExecutionRequest>| {
+        std::iter::from_fn(|| execution_rx.rx.try_recv().ok())
+            .filter_map(|request| match request {
+                ExecutionRequest::Open(open) => Some(open.key.instrument),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // Strategy opens btc_usdt, which fills to a LONG position
+    let audit = process(engine.process(market_event_trade(1, 0, 10_000.0)));
+    assert!(breached(&audit).is_empty());
+    assert_eq!(opens_sent(&mut execution_rx), vec![InstrumentIndex(0)]);
+
+    let audit = process(engine.process(account_event_trade(0, 1, Side::Buy, 10_000.0, 1.0)));
+    assert!(breached(&audit).is_empty());
+
+    // Price falls, but the drawdown is within the limit
+    let audit = process(engine.process(market_event_trade(2, 0, 5_000.0)));
+    assert!(breached(&audit).is_empty());
+    assert_eq!(engine.state.trading, TradingState::Enabled);
+
+    // Price falls further, drawdown breaches the limit, so trading disables
+    let audit = process(engine.process(market_event_trade(3, 0, 1_000.0)));
+    let breached_outputs = breached(&audit);
+    assert_eq!(breached_outputs.len(), 1);
+    assert!(matches!(
+        breached_outputs[0],
+        DrawdownBreached { equity_peak, drawdown, .. }
+            if equity_peak == dec!(100_000) && drawdown > dec!(0.1)
+    ));
+    assert!(
+        audit
+            .outputs
+            .iter()
+            .any(|output| matches!(output, EngineOutput::OnTradingDisabled(_)))
+    );
+    assert_eq!(engine.state.trading, TradingState::Disabled);
+
+    // New opportunity (eth_btc price available), but opens are refused while disabled
+    let audit = process(engine.process(market_event_trade(4, 1, 0.1)));
+    assert!(breached(&audit).is_empty());
+    assert!(opens_sent(&mut execution_rx).is_empty());
+    assert_eq!(engine.state.trading, TradingState::Disabled);
+
+    // Trading only resumes after an explicit re-enable
+    let audit = process(engine.process(EngineEvent::TradingStateUpdate(TradingState::Enabled)));
+    assert!(breached(&audit).is_empty());
+    assert_eq!(opens_sent(&mut execution_rx), vec![InstrumentIndex(1)]);
+    assert_eq!(engine.state.trading, TradingState::Enabled);
+}
+
+#[test]
+fn test_engine_sends_risk_and_exchange_order_rejections() {
+    let (execution_tx, mut execution_rx) = mpsc_unbounded();
+    let (rejection_tx, mut rejection_rx) = mpsc_unbounded();
+    let mut engine =
+        build_engine(TradingState::PostOnly, execution_tx).with_order_rejection_tx(rejection_tx);
+
+    let rejections = |rejection_rx: &mut UnboundedRx<OrderRejection>| {
+        std::iter::from_fn(|| rejection_rx.rx.try_recv().ok()).collect::<Vec<_>>()
+    };
+
+    // Strategy generates a Market open, which is refused while trading is PostOnly
+    engine.process(market_event_trade(1, 0, 10_000.0));
+    engine.strategy.active = false;
+
+    let risk = rejections(&mut rejection_rx);
+    assert_eq!(risk.len(), 1);
+    assert_eq!(risk[0].stage, OrderRejectionStage::Risk);
+    assert!(matches!(
+        &risk[0].order,
+        RejectedOrder::Open(open) if open.key.instrument == InstrumentIndex(0)
+    ));
+    assert!(execution_rx.rx.try_recv().is_err());
+
+    // Post-only open is sent, but the MockExchange rejects the unsupported Limit order
+    let open = OrderRequestOpen {
+        key: OrderKey {
+            exchange: ExchangeIndex(0),
+            instrument: InstrumentIndex(1),
+            strategy: strategy_id(),
+            cid: gen_cid(1),
+        },
+        state: RequestOpen {
+            side: Side::Buy,
+            kind: OrderKind::Limit,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            reduce_only: false,
+            price: dec!(0.05),
+            quantity: dec!(1),
+        },
+    };
+    engine.process(EngineEvent::Command(Command::SendOpenRequests(
+        OneOrMany::One(open.clone()),
+    )));
+    assert_eq!(
+        execution_rx.rx.try_recv().unwrap(),
+        ExecutionRequest::Open(open.clone())
+    );
+    assert!(rejections(&mut rejection_rx).is_empty());
+
+    let reason = "MockExchange does not supported OrderKind: Limit";
+    engine.process(EngineEvent::Account(AccountStreamEvent::Item(
+        AccountEvent {
+            exchange: ExchangeIndex(0),
+            kind: AccountEventKind::OrderSnapshot(Snapshot(Order {
+                key: open.key.clone(),
+                side: open.state.side,
+                price: open.state.price,
+                quantity: open.state.quantity,
+                kind: open.state.kind,
+                time_in_force: open.state.time_in_force,
+                reduce_only: open.state.reduce_only,
+                state: OrderState::inactive(OrderError::Rejected(ApiError::OrderRejected(
+                    reason.to_string(),
+                ))),
+            })),
+        },
+    )));
+
+    let exchange = rejections(&mut rejection_rx);
+    assert_eq!(exchange.len(), 1);
+    assert_eq!(exchange[0].stage, OrderRejectionStage::Exchange);
+    assert_eq!(exchange[0].order, RejectedOrder::Open(open));
+    assert!(exchange[0].reason.contains(reason));
+}
+
+#[test]
+fn test_engine_comparison_ignores_order_rejection_tx() {
+    let engine = Engine::new(barter::engine::clock::LiveClock, 0, (), (), ()).with_strategy_swap();
+
+    // Engines only differing by rejection channel are equal
+    let (rejection_tx_a, _rejection_rx_a) = mpsc_unbounded();
+    let (rejection_tx_b, _rejection_rx_b) = mpsc_unbounded();
+    assert_eq!(
+        engine.clone().with_order_rejection_tx(rejection_tx_a),
+        engine.clone().with_order_rejection_tx(rejection_tx_b)
+    );
+
+    // Engines with different state are not equal
+    let mut other = engine.clone();
+    other.state = 1;
+    assert_ne!(engine, other);
+}
+
+#[test]
+fn test_engine_rejects_open_request_with_colliding_cid() {
+    let (execution_tx, mut execution_rx) = mpsc_unbounded();
+    let mut engine = build_engine(TradingState::Disabled, execution_tx);
+
+    let open = |instrument: usize, cid: ClientOrderId| OrderRequestOpen {
+        key: OrderKey {
+            exchange: ExchangeIndex(0),
+            instrument: InstrumentIndex(instrument),
+            strategy: strategy_id(),
+            cid,
+        },
+        state: RequestOpen {
+            side: Side::Buy,
+            kind: OrderKind::Limit,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            reduce_only: false,
+            price: dec!(0.05),
+            quantity: dec!(1),
+        },
+    };
+
+    // Active order using gen_cid(0)
+    let active = open(0, gen_cid(0));
+    engine.process(EngineEvent::Command(Command::SendOpenRequests(
+        OneOrMany::One(active.clone()),
+    )));
+    assert_eq!(
+        execution_rx.rx.try_recv().unwrap(),
+        ExecutionRequest::Open(active)
+    );
+
+    // Reusing the active ClientOrderId (even on another instrument) is rejected before sending
+    let collision = open(1, gen_cid(0));
+    let fresh = open(1, gen_cid(1));
+    let event = EngineEvent::Command(Command::SendOpenRequests(OneOrMany::Many(vec![
+        collision.clone(),
+        fresh.clone(),
+    ])));
+    let audit = engine.process(event.clone());
+
+    assert_eq!(
+        audit,
+        EngineAudit::process_with_output(
+            event,
+            EngineOutput::Commanded(ActionOutput::OpenOrders(SendRequestsOutput {
+                sent: NoneOneOrMany::One(fresh.clone()),
+                errors: NoneOneOrMany::One((
+                    collision,
+                    EngineError::Recoverable(RecoverableEngineError::ClientOrderIdCollision(
+                        gen_cid(0)
+                    )),
+                )),
+            }))
+        )
+    );
+    assert_eq!(
+        execution_rx.rx.try_recv().unwrap(),
+        ExecutionRequest::Open(fresh)
+    );
+    assert!(execution_rx.rx.try_recv().is_err());
+
+    // The active order was not conflated with the rejected request
+    assert_eq!(
+        engine
+            .state
+            .instruments
+            .instrument_index(&InstrumentIndex(0))
+            .orders
+            .active
+            .get(&gen_cid(0))
+            .unwrap()
+            .key
+            .instrument,
+        InstrumentIndex(0)
+    );
+}
+
+#[test]
+fn test_engine_query_state_replies_with_filtered_positions() {
+    let (execution_tx, _execution_rx) = mpsc_unbounded();
+    let mut engine = build_engine(TradingState::Disabled, execution_tx);
+
+    // Open LONG positions on both instruments
+    engine.process(account_event_trade(0, 1, Side::Buy, 10_000.0, 1.0));
+    engine.process(account_event_trade(1, 1, Side::Buy, 0.05, 10.0));
+
+    // Query positions of instrument 0 only
+    let (query, mut reply_rx) = QueryState::new(QueryKind::Positions(
+        InstrumentFilter::Instruments(OneOrMany::One(InstrumentIndex(0))),
+    ));
+    let event = EngineEvent::Command(Command::QueryState(query));
+    let audit = engine.process(event.clone());
+
+    assert_eq!(
+        audit,
+        EngineAudit::process_with_output(
+            event,
+            EngineOutput::Commanded(ActionOutput::QueryState(QueryStateOutput { replied: true }))
+        )
+    );
+
+    let QueryResponse::Positions(positions) = reply_rx.try_recv().unwrap() else {
+        panic!("expected QueryResponse::Positions");
+    };
+    let expected = engine
+        .state
+        .instruments
+        .instrument_index(&InstrumentIndex(0))
+        .position
+        .current
+        .clone()
+        .unwrap();
+    assert_eq!(positions, vec![expected]);
+    assert_eq!(positions[0].instrument, InstrumentIndex(0));
+    assert_eq!(positions[0].side, Side::Buy);
+    assert_eq!(positions[0].quantity_abs, dec!(1));
+    assert_eq!(positions[0].price_entry_average, dec!(10_000));
+
+    // Reply is not sent if the receiver has been dropped
+    let (query, reply_rx) = QueryState::new(QueryKind::Orders(InstrumentFilter::None));
+    drop(reply_rx);
+    let event = EngineEvent::Command(Command::QueryState(query));
+    assert_eq!(
+        engine.process(event.clone()),
+        EngineAudit::process_with_output(
+            event,
+            EngineOutput::Commanded(ActionOutput::QueryState(QueryStateOutput {
+                replied: false
+            }))
+        )
+    );
+}
+
+#[test]
+fn test_engine_swap_strategy_preserves_state() {
+    let (execution_tx, mut execution_rx) = mpsc_unbounded();
+    let mut engine = build_engine(TradingState::Enabled, execution_tx).with_strategy_swap();
+    engine.strategy = TestBuyAndHoldStrategy {
+        id: strategy_id(),
+        active: false,
+    };
+
+    // No-op Strategy does not generate orders
+    engine.process(market_event_trade(1, 0, 10_000.0));
+    assert!(execution_rx.rx.try_recv().is_err());
+    assert!(
+        engine
+            .state
+            .instruments
+            .instrument_index(&InstrumentIndex(0))
+            .orders
+            .active
+            .is_empty()
+    );
+
+    // Swap with a Strategy of a different type is ignored
+    let event = EngineEvent::Command(Command::SwapStrategy(SwapStrategy::new(())));
+    assert_eq!(
+        engine.process(event.clone()),
+        EngineAudit::process_with_output(
+            event,
+            EngineOutput::Commanded(ActionOutput::SwapStrategy(SwapStrategyOutput {
+                swapped: false
+            }))
+        )
+    );
+    assert!(execution_rx.rx.try_recv().is_err());
+
+    // Swap to the order generating Strategy, which trades the preserved market data
+    let event = EngineEvent::Command(Command::SwapStrategy(SwapStrategy::new(
+        TestBuyAndHoldStrategy {
+            id: strategy_id(),
+            active: true,
+        },
+    )));
+    let audit = engine.process(event.clone());
+    assert!(engine.strategy.active);
+
+    let EngineAudit::Process(process) = audit else {
+        panic!("expected EngineAudit::Process, got: {audit:?}");
+    };
+    assert_eq!(process.event, event);
+    let outputs = process.outputs.into_vec();
+    assert_eq!(
+        outputs[0],
+        EngineOutput::Commanded(ActionOutput::SwapStrategy(SwapStrategyOutput {
+            swapped: true
+        }))
+    );
+    assert!(matches!(outputs[1], EngineOutput::AlgoOrders(_)));
+    assert!(matches!(
+        execution_rx.rx.try_recv(),
+        Ok(ExecutionRequest::Open(_))
+    ));
+
+    // Strategy instance is only swapped once
+    assert_eq!(
+        engine.process(event.clone()),
+        EngineAudit::process_with_output(
+            event,
+            EngineOutput::Commanded(ActionOutput::SwapStrategy(SwapStrategyOutput {
+                swapped: false
+            }))
+        )
+    );
+}
+
+#[test]
+fn test_engine_swap_strategy_ignored_when_disabled() {
+    let (execution_tx, _execution_rx) = mpsc_unbounded();
+    let mut engine = build_engine(TradingState::Enabled, execution_tx);
+    engine.strategy.active = false;
+
+    let event = EngineEvent::Command(Command::SwapStrategy(SwapStrategy::new(
+        TestBuyAndHoldStrategy {
+            id: strategy_id(),
+            active: true,
+        },
+    )));
+    assert_eq!(
+        engine.process(event.clone()),
+        EngineAudit::process_with_output(
+            event,
+            EngineOutput::Commanded(ActionOutput::SwapStrategy(SwapStrategyOutput {
+                swapped: false
+            }))
+        )
+    );
+    assert!(!engine.strategy.active);
+}
+
+#[test]
+fn test_engine_cancel_orders_on_account_disconnect() {
+    type State = EngineState<DefaultGlobalData, DefaultInstrumentMarketData>;
+
+    let instruments = IndexedInstruments::builder()
+        .add_instrument(Instrument::spot(
+            ExchangeId::BinanceSpot,
+            "binance_spot_btc_usdt",
+            "BTCUSDT",
+            Underlying::new("btc", "usdt"),
+            None,
+        ))
+        .add_instrument(Instrument::spot(
+            ExchangeId::Okx,
+            "okx_btc_usdt",
+            "BTC-USDT",
+            Underlying::new("btc", "usdt"),
+            None,
+        ))
+        .add_instrument(Instrument::spot(
+            ExchangeId::Okx,
+            "okx_eth_usdt",
+            "ETH-USDT",
+            Underlying::new("eth", "usdt"),
+            None,
+        ))
+        .build();
+
+    let state = EngineState::builder(&instruments, DefaultGlobalData, |_| {
+        DefaultInstrumentMarketData::default()
+    })
+    .time_engine_start(STARTING_TIMESTAMP)
+    .trading_state(TradingState::Enabled)
+    .build();
+
+    let (binance_tx, mut binance_rx) = mpsc_unbounded();
+    let (okx_tx, mut okx_rx) = mpsc_unbounded();
+    let execution_txs = MultiExchangeTxMap::from_iter([
+        (ExchangeId::BinanceSpot, Some(binance_tx)),
+        (ExchangeId::Okx, Some(okx_tx)),
+    ]);
+
+    let mut engine = Engine::new(
+        HistoricalClock::new(STARTING_TIMESTAMP),
+        state,
+        execution_txs,
+        CancelOrdersOnDisconnect::new(DefaultStrategy::<State>::default()),
+        DefaultRiskManager::<State>::default(),
+    );
+
+    // Open order on every instrument: InstrumentIndex(0) -> ExchangeIndex(0), others -> ExchangeIndex(1)
+    for (instrument, exchange) in [(0, 0), (1, 1), (2, 1)] {
+        let order = Order {
+            key: OrderKey {
+                exchange: ExchangeIndex(exchange),
+                instrument: InstrumentIndex(instrument),
+                strategy: strategy_id(),
+                cid: gen_cid(instrument),
+            },
+            side: Side::Buy,
+            price: dec!(1),
+            quantity: dec!(1),
+            kind: OrderKind::Limit,
+            time_in_force: TimeInForce::GoodUntilCancelled { post_only: false },
+            reduce_only: false,
+            state: ActiveOrderState::Open(Open {
+                id: gen_order_id(instrument),
+                time_exchange: STARTING_TIMESTAMP,
+                filled_quantity: dec!(0),
+            }),
+        };
+        engine
+            .state
+            .instruments
+            .instrument_index_mut(&InstrumentIndex(instrument))
+            .orders
+            .active
+            .insert(gen_cid(instrument), order);
+    }
+
+    let event = EngineEvent::Account(AccountStreamEvent::Reconnecting(ExchangeId::Okx));
+    let audit = engine.process(event.clone());
+
+    let expected_cancels = [1, 2].map(|instrument| OrderRequestCancel {
+        key: OrderKey {
+            exchange: ExchangeIndex(1),
+            instrument: InstrumentIndex(instrument),
+            strategy: strategy_id(),
+            cid: gen_cid(instrument),
+        },
+        state: RequestCancel {
+            id: Some(gen_order_id(instrument)),
+        },
+    });
+
+    let EngineAudit::Process(process) = audit else {
+        panic!("expected EngineAudit::Process, got: {audit:?}")
+    };
+    let NoneOneOrMany::One(EngineOutput::AccountDisconnect(output)) = &process.outputs else {
+        panic!("expected single AccountDisconnect output, got: {process:?}")
+    };
+    let mut sent = output.sent.iter().cloned().collect::<Vec<_>>();
+    sent.sort_by_key(|cancel| cancel.key.instrument);
+    assert_eq!(sent, expected_cancels);
+    assert!(output.errors.is_none());
+
+    // Only the disconnected exchange received cancel requests
+    let mut okx_requests = std::iter::from_fn(|| okx_rx.rx.try_recv().ok()).collect::<Vec<_>>();
+    okx_requests.sort_by_key(|request| match request {
+        ExecutionRequest::Cancel(cancel) => cancel.key.instrument,
+        _ => InstrumentIndex(usize::MAX),
+    });
+    assert_eq!(
+        okx_requests,
+        expected_cancels.map(ExecutionRequest::Cancel).to_vec()
+    );
+    assert!(binance_rx.rx.try_recv().is_err());
+    assert!(
+        engine
+            .state
+            .instruments
+            .instrument_index(&InstrumentIndex(0))
+            .orders
+            .active
+            .values()
+            .all(|order| matches!(order.state, ActiveOrderState::Open(_)))
+    );
+}
+
+#[test]
+fn test_engine_cancel_orders_on_disconnect_delegates_on_trading_disabled() {
+    let (execution_tx, _execution_rx) = mpsc_unbounded();
+    let mut engine = build_engine(TradingState::Enabled, execution_tx)
+        .map_strategy(CancelOrdersOnDisconnect::new);
+
+    // Wrapped strategy OnTradingDisabled output is forwarded to the audit
+    let event = EngineEvent::TradingStateUpdate(TradingState::Disabled);
+    assert_eq!(
+        engine.process(event.clone()),
+        EngineAudit::process_with_output(
+            event,
+            EngineOutput::OnTradingDisabled(OnTradingDisabledOutput)
+        )
+    );
+
+    // Wrapper is restored around the wrapped strategy
+    assert_eq!(engine.strategy.strategy.id, strategy_id());
+    assert!(engine.strategy.strategy.active);
+}
+
+#[test]
+fn test_engine_with_inner_strategy_unwinds_wrapped_strategy_panic() {
+    let (execution_tx, _execution_rx) = mpsc_unbounded();
+    let mut engine = build_engine(TradingState::Enabled, execution_tx)
+        .map_strategy(CancelOrdersOnDisconnect::new);
+
+    // Panic in the wrapped strategy unwinds to the caller rather than aborting
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        engine.with_inner_strategy(
+            |wrapper| (wrapper.strategy, ()),
+            |_| -> () { panic!("wrapped strategy panicked") },
+            |strategy, ()| CancelOrdersOnDisconnect::new(strategy),
+        )
+    }));
+    assert!(result.is_err());
+
+    // Wrapper is restored around the wrapped strategy, and the Engine keeps processing events
+    assert_eq!(engine.strategy.strategy.id, strategy_id());
+    let event = EngineEvent::TradingStateUpdate(TradingState::Disabled);
+    assert_eq!(
+        engine.process(event.clone()),
+        EngineAudit::process_with_output(
+            event,
+            EngineOutput::OnTradingDisabled(OnTradingDisabledOutput)
+        )
+    );
+}
+
 struct TestBuyAndHoldStrategy {
     id: StrategyId,
+    active: bool,
 }
 
 impl AlgoStrategy for TestBuyAndHoldStrategy {
@@ -682,6 +1682,7 @@ impl AlgoStrategy for TestBuyAndHoldStrategy {
         let opens = state
             .instruments
             .instruments(&InstrumentFilter::None)
+            .filter(|_| self.active)
             .filter_map(|state| {
                 // Don't open more if we have a Position already
                 if state.position.current.is_some() {
@@ -689,7 +1690,7 @@ impl AlgoStrategy for TestBuyAndHoldStrategy {
                 }
 
                 // Don't open more orders if there are already some InFlight
-                if !state.orders.0.is_empty() {
+                if !state.orders.active.is_empty() {
                     return None;
                 }
 
@@ -708,6 +1709,7 @@ impl AlgoStrategy for TestBuyAndHoldStrategy {
                         side: Side::Buy,
                         kind: OrderKind::Market,
                         time_in_force: TimeInForce::ImmediateOrCancel,
+                        reduce_only: false,
                         price,
                         quantity: dec!(1),
                     },
@@ -756,7 +1758,7 @@ impl ClosePositionsStrategy for TestBuyAndHoldStrategy {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 struct OnDisconnectOutput;
 impl
     OnDisconnectStrategy<
@@ -782,7 +1784,7 @@ impl
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 struct OnTradingDisabledOutput;
 impl
     OnTradingDisabled<
@@ -870,7 +1872,10 @@ fn build_engine(
         clock,
         state,
         execution_txs,
-        TestBuyAndHoldStrategy { id: strategy_id() },
+        TestBuyAndHoldStrategy {
+            id: strategy_id(),
+            active: true,
+        },
         DefaultRiskManager::default(),
     )
 }
@@ -899,12 +1904,14 @@ fn market_event_trade(time_plus: u64, instrument: usize, price: f64) -> EngineEv
     EngineEvent::Market(MarketStreamEvent::Item(MarketEvent {
         time_exchange: time_plus_days(STARTING_TIMESTAMP, time_plus),
         time_received: time_plus_days(STARTING_TIMESTAMP, time_plus),
+        time_processed: None,
+        sequence: None,
         exchange: ExchangeId::BinanceSpot,
         instrument: InstrumentIndex(instrument),
         kind: DataKind::Trade(PublicTrade {
             id: time_plus.to_string(),
-            price,
-            amount: 1.0,
+            price: Decimal::try_from(price).unwrap(),
+            amount: dec!(1),
             side: Side::Buy,
         }),
     }))
@@ -932,6 +1939,7 @@ fn account_event_order_response(
             quantity: Decimal::try_from(quantity).unwrap(),
             kind: OrderKind::Market,
             time_in_force: TimeInForce::GoodUntilCancelled { post_only: true },
+            reduce_only: false,
             state: OrderState::active(Open {
                 id: gen_order_id(instrument),
                 time_exchange: time_plus_days(STARTING_TIMESTAMP, time_plus),
@@ -981,6 +1989,7 @@ fn account_event_trade(
             fees: AssetFees::quote_fees(
                 Decimal::try_from(price * quantity * QUOTE_FEES_PERCENT).unwrap(),
             ),
+            reduce_only: false,
         }),
     }))
 }